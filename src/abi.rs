@@ -98,7 +98,13 @@ pub const ELFOSABI_FENIXOS: u8 = 16;
 pub const ELFOSABI_CLOUDABI: u8 = 17;
 /// Stratus Technologies OpenVOS
 pub const ELFOSABI_OPENVOS: u8 = 18;
-/// 64-255 Architecture-specific value range
+// 64-255 Architecture-specific value range
+/// ARM EABI (EM_ARM specific)
+pub const ELFOSABI_ARM_AEABI: u8 = 64;
+/// ARM (EM_ARM specific)
+pub const ELFOSABI_ARM: u8 = 97;
+/// Standalone (embedded) application
+pub const ELFOSABI_STANDALONE: u8 = 255;
 
 // ET_* define constants for the ELF File Header's e_type field.
 // Represented as Elf32_Half in Elf32_Ehdr and Elf64_Half in Elf64_Ehdr which
@@ -502,6 +508,8 @@ pub const EM_AMDGPU: u16 = 224;
 pub const EM_RISCV: u16 = 243;
 /// Linux BPF
 pub const EM_BPF: u16 = 247;
+/// LoongArch
+pub const EM_LOONGARCH: u16 = 258;
 
 // EV_* define constants for the ELF File Header's e_version field.
 // Represented as Elf32_Word in Elf32_Ehdr and Elf64_Word in Elf64_Ehdr which
@@ -614,6 +622,8 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended symbol table section index
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// Section data contains relative relocation entries in the compact RELR format
+pub const SHT_RELR: u32 = 19;
 /// Values in [SHT_LOOS, SHT_HIOS] are reserved for operating system-specific semantics.
 pub const SHT_LOOS: u32 = 0x60000000;
 /// Object attributes
@@ -646,6 +656,9 @@ pub const SHT_HIUSER: u32 = 0x8fffffff;
 /// This value marks an undefined, missing, irrelevant, or otherwise meaningless
 /// section reference.
 pub const SHN_UNDEF: u16 = 0;
+/// The lower bound of the range of reserved section indexes. Section header tables with at least
+/// this many entries store their size in the sh_size of the section header at index 0.
+pub const SHN_LORESERVE: u16 = 0xff00;
 /// Symbols with st_shndx=SHN_ABS are absolute and are not affected by relocation.
 pub const SHN_ABS: u16 = 0xfff1;
 /// Symbols with st_shndx=SHN_COMMON are sometimes used for unallocated C external variables.
@@ -728,6 +741,28 @@ pub const SHF_MASKOS: u32 = 0x0ff00000;
 /// Masked bits are reserved for processor-specific semantics.
 pub const SHF_MASKPROC: u32 = 0xf0000000;
 
+// GRP_* define flags for the flags word at the start of SHT_GROUP sections.
+
+/// This is a COMDAT group. It may duplicate another COMDAT group in another object file,
+/// where duplicate means having the same group signature. In such cases, only one of the
+/// duplicate groups may be retained by the linker, and the members of the remaining groups
+/// must be discarded.
+pub const GRP_COMDAT: u32 = 0x1;
+/// Masked bits are reserved for operating system-specific semantics.
+pub const GRP_MASKOS: u32 = 0x0ff00000;
+/// Masked bits are reserved for processor-specific semantics.
+pub const GRP_MASKPROC: u32 = 0xf0000000;
+
+// Build attributes sections (e.g. SHT_ARM_ATTRIBUTES, SHT_RISCV_ATTRIBUTES) start with this
+// format-version byte, followed by a subsection for each vendor.
+pub const ATTR_FORMAT_VERSION_A: u8 = b'A';
+
+// TAG_FILE, TAG_SECTION and TAG_SYMBOL define the scope of the attributes in a build attributes
+// sub-subsection: the whole file, or just the listed sections or symbols.
+pub const TAG_FILE: u64 = 1;
+pub const TAG_SECTION: u64 = 2;
+pub const TAG_SYMBOL: u64 = 3;
+
 // STT_* define constants for the ELF Symbol's st_type (encoded in the st_info field).
 
 /// Unspecified symbol type
@@ -946,6 +981,14 @@ pub const DT_PREINIT_ARRAYSZ: i64 = 33;
 /// This element holds the address of the SHT_SYMTAB_SHNDX section associated
 /// with the dynamic symbol table referenced by the DT_SYMTAB element.
 pub const DT_SYMTAB_SHNDX: i64 = 34;
+/// This element holds the total size, in bytes, of the DT_RELR relocation table.
+pub const DT_RELRSZ: i64 = 35;
+/// This element holds the address of a relocation table, similar to DT_RELA,
+/// except its table contains compact relative relocation entries (see
+/// [RelrIterator](crate::relocation::RelrIterator)).
+pub const DT_RELR: i64 = 36;
+/// This element holds the size, in bytes, of the DT_RELR relocation entry.
+pub const DT_RELRENT: i64 = 37;
 /// Guile offset of GC roots
 pub const DT_GUILE_GC_ROOT: i64 = 0x37146000;
 /// Guile size in machine words of GC roots
@@ -1134,6 +1177,46 @@ pub const VER_FLG_BASE: u16 = 0x1;
 pub const VER_FLG_WEAK: u16 = 0x2;
 pub const VER_FLG_INFO: u16 = 0x4;
 
+// DW_EH_PE_* define the pointer encodings used in the .eh_frame and .eh_frame_hdr sections.
+// The low 4 bits of an encoding give the format of the value, and the upper bits say what
+// the value is relative to.
+// (see: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html)
+
+/// The value is a pointer-sized absolute address (as a format), or is not relative to
+/// anything (as an application).
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+/// The value is an unsigned LEB128.
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+/// The value is an unsigned 2-byte integer.
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+/// The value is an unsigned 4-byte integer.
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+/// The value is an unsigned 8-byte integer.
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+/// The value is a signed LEB128.
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+/// The value is a signed 2-byte integer.
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+/// The value is a signed 4-byte integer.
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+/// The value is a signed 8-byte integer.
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+/// The value is relative to the address of the encoded value itself.
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+/// The value is relative to the start of the text section.
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+/// The value is relative to the start of the data section (for .eh_frame_hdr, the start
+/// of the .eh_frame_hdr section).
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+/// The value is relative to the start of the function.
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+/// The value is aligned to the pointer size.
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+/// The value is the address at which the real value is stored.
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+/// There is no value.
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
 /// ZLIB/DEFLATE
 pub const ELFCOMPRESS_ZLIB: u32 = 1;
 /// zstd algorithm
@@ -1149,6 +1232,8 @@ pub const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
 
 /// GNU-extension notes have this name
 pub const ELF_NOTE_GNU: &[u8] = b"GNU\0";
+/// Core file notes (such as NT_PRSTATUS) have this name
+pub const ELF_NOTE_CORE: &[u8] = b"CORE\0";
 
 // Note header descriptor types constants (n_type)
 
@@ -1273,12 +1358,108 @@ pub const NT_GNU_GOLD_VERSION: u64 = 4;
 /// Program property note which describes special handling requirements for linker and run-time loader.
 pub const NT_GNU_PROPERTY_TYPE_0: u64 = 5;
 
+// GNU_PROPERTY_* define the pr_type of the properties in an NT_GNU_PROPERTY_TYPE_0 note.
+// (see: https://raw.githubusercontent.com/wiki/hjl-tools/linux-abi/linux-abi-draft.pdf)
+/// Stack size
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+/// No copy relocation on protected data symbol
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+/// A 4-byte bitmask of features which are only enabled if all input objects have them set
+pub const GNU_PROPERTY_UINT32_AND_LO: u32 = 0xb0000000;
+pub const GNU_PROPERTY_UINT32_AND_HI: u32 = 0xb0007fff;
+/// A 4-byte bitmask of features which are enabled if any input object has them set
+pub const GNU_PROPERTY_UINT32_OR_LO: u32 = 0xb0008000;
+pub const GNU_PROPERTY_UINT32_OR_HI: u32 = 0xb000ffff;
+/// The features required to run the object, a bitmask of GNU_PROPERTY_1_NEEDED_*
+pub const GNU_PROPERTY_1_NEEDED: u32 = GNU_PROPERTY_UINT32_OR_LO;
+/// Pointer equality for indirect extern function addresses isn't needed
+pub const GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS: u32 = 1;
+/// Processor-specific property types
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+/// Application-specific property types
+pub const GNU_PROPERTY_LOUSER: u32 = 0xe0000000;
+pub const GNU_PROPERTY_HIUSER: u32 = 0xffffffff;
+
 // These values can appear in word 0 of an NT_GNU_ABI_TAG note section entry.
 pub const ELF_NOTE_GNU_ABI_TAG_OS_LINUX: u32 = 0;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_GNU: u32 = 1;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_SOLARIS2: u32 = 2;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_FREEBSD: u32 = 3;
 
+// AT_* define the entry types (a_type) of the auxiliary vector, which the kernel passes to a
+// program on its stack and which core files contain a copy of in their NT_AUXV note.
+/// End of the auxiliary vector
+pub const AT_NULL: u64 = 0;
+/// Entry should be ignored
+pub const AT_IGNORE: u64 = 1;
+/// File descriptor of the program
+pub const AT_EXECFD: u64 = 2;
+/// Address of the program's program headers
+pub const AT_PHDR: u64 = 3;
+/// Size of a program header entry
+pub const AT_PHENT: u64 = 4;
+/// Number of program headers
+pub const AT_PHNUM: u64 = 5;
+/// System page size
+pub const AT_PAGESZ: u64 = 6;
+/// Base address of the interpreter
+pub const AT_BASE: u64 = 7;
+/// Flags
+pub const AT_FLAGS: u64 = 8;
+/// Entry point of the program
+pub const AT_ENTRY: u64 = 9;
+/// Program is not ELF
+pub const AT_NOTELF: u64 = 10;
+/// Real uid
+pub const AT_UID: u64 = 11;
+/// Effective uid
+pub const AT_EUID: u64 = 12;
+/// Real gid
+pub const AT_GID: u64 = 13;
+/// Effective gid
+pub const AT_EGID: u64 = 14;
+/// Address of a string identifying the cpu, for optimizations
+pub const AT_PLATFORM: u64 = 15;
+/// Machine-dependent hints about processor capabilities
+pub const AT_HWCAP: u64 = 16;
+/// Frequency of times()
+pub const AT_CLKTCK: u64 = 17;
+/// Used FPU control word
+pub const AT_FPUCW: u64 = 18;
+/// Data cache block size
+pub const AT_DCACHEBSIZE: u64 = 19;
+/// Instruction cache block size
+pub const AT_ICACHEBSIZE: u64 = 20;
+/// Unified cache block size
+pub const AT_UCACHEBSIZE: u64 = 21;
+/// Entry should be ignored (PowerPC)
+pub const AT_IGNOREPPC: u64 = 22;
+/// Boolean, was exec setuid-like?
+pub const AT_SECURE: u64 = 23;
+/// Address of a string identifying the real platform, which may differ from AT_PLATFORM
+pub const AT_BASE_PLATFORM: u64 = 24;
+/// Address of 16 random bytes
+pub const AT_RANDOM: u64 = 25;
+/// More machine-dependent hints about processor capabilities
+pub const AT_HWCAP2: u64 = 26;
+/// rseq supported feature size
+pub const AT_RSEQ_FEATURE_SIZE: u64 = 27;
+/// rseq allocation alignment
+pub const AT_RSEQ_ALIGN: u64 = 28;
+/// Extension of AT_HWCAP
+pub const AT_HWCAP3: u64 = 29;
+/// Extension of AT_HWCAP
+pub const AT_HWCAP4: u64 = 30;
+/// Address of the filename of the program
+pub const AT_EXECFN: u64 = 31;
+/// Address of the vsyscall entry point
+pub const AT_SYSINFO: u64 = 32;
+/// Address of the vDSO's ELF header
+pub const AT_SYSINFO_EHDR: u64 = 33;
+/// Minimal stack size for signal delivery
+pub const AT_MINSIGSTKSZ: u64 = 51;
+
 //     _    ____  __  __
 //    / \  |  _ \|  \/  |
 //   / _ \ | |_) | |\/| |
@@ -1330,6 +1511,90 @@ pub const SHT_ARM_DEBUGOVERLAY: u32 = 0x70000004;
 /// See <https://github.com/ARM-software/abi-aa/blob/main/dbgovl32/dbgovl32.rst>
 pub const SHT_ARM_OVERLAYSECTION: u32 = 0x70000005;
 
+// ARM EABI build attribute tags, found in the "aeabi" subsection of SHT_ARM_ATTRIBUTES sections.
+// Tags above 32 have string values if they're odd and integer values if they're even.
+// (see: https://github.com/ARM-software/abi-aa/blob/main/addenda32/addenda32.rst)
+/// The raw name of the target CPU, as given by the user (string)
+pub const TAG_CPU_RAW_NAME: u64 = 4;
+/// The name of the target CPU, e.g. "7-A" or "cortex-m4" (string)
+pub const TAG_CPU_NAME: u64 = 5;
+/// The target architecture version, one of the TAG_CPU_ARCH_* values
+pub const TAG_CPU_ARCH: u64 = 6;
+/// The target architecture profile: 'A'pplication, 'R'eal-time, 'M'icrocontroller, 'S'ystem, or 0
+pub const TAG_CPU_ARCH_PROFILE: u64 = 7;
+/// Whether use of A32 instructions was permitted
+pub const TAG_ARM_ISA_USE: u64 = 8;
+/// Whether use of T32 instructions was permitted (1: 16-bit Thumb, 2: Thumb-2, 3: architecture-defined)
+pub const TAG_THUMB_ISA_USE: u64 = 9;
+/// The floating point architecture used, e.g. 3 for VFPv3
+pub const TAG_FP_ARCH: u64 = 10;
+pub const TAG_WMMX_ARCH: u64 = 11;
+/// The Advanced SIMD (Neon) architecture used
+pub const TAG_ADVANCED_SIMD_ARCH: u64 = 12;
+pub const TAG_PCS_CONFIG: u64 = 13;
+pub const TAG_ABI_PCS_R9_USE: u64 = 14;
+pub const TAG_ABI_PCS_RW_DATA: u64 = 15;
+pub const TAG_ABI_PCS_RO_DATA: u64 = 16;
+pub const TAG_ABI_PCS_GOT_USE: u64 = 17;
+/// The size of wchar_t in bytes, or 0 if it isn't used
+pub const TAG_ABI_PCS_WCHAR_T: u64 = 18;
+pub const TAG_ABI_FP_ROUNDING: u64 = 19;
+pub const TAG_ABI_FP_DENORMAL: u64 = 20;
+pub const TAG_ABI_FP_EXCEPTIONS: u64 = 21;
+pub const TAG_ABI_FP_USER_EXCEPTIONS: u64 = 22;
+pub const TAG_ABI_FP_NUMBER_MODEL: u64 = 23;
+pub const TAG_ABI_ALIGN_NEEDED: u64 = 24;
+pub const TAG_ABI_ALIGN_PRESERVED: u64 = 25;
+pub const TAG_ABI_ENUM_SIZE: u64 = 26;
+pub const TAG_ABI_HARDFP_USE: u64 = 27;
+/// Whether floating point arguments are passed in VFP registers (hard-float) or core registers (soft-float)
+pub const TAG_ABI_VFP_ARGS: u64 = 28;
+pub const TAG_ABI_WMMX_ARGS: u64 = 29;
+pub const TAG_ABI_OPTIMIZATION_GOALS: u64 = 30;
+pub const TAG_ABI_FP_OPTIMIZATION_GOALS: u64 = 31;
+/// A flag (integer) followed by the name of the toolchain (string) the object is compatible with
+pub const TAG_COMPATIBILITY: u64 = 32;
+pub const TAG_CPU_UNALIGNED_ACCESS: u64 = 34;
+pub const TAG_FP_HP_EXTENSION: u64 = 36;
+pub const TAG_ABI_FP_16BIT_FORMAT: u64 = 38;
+pub const TAG_MPEXTENSION_USE: u64 = 42;
+pub const TAG_DIV_USE: u64 = 44;
+pub const TAG_DSP_EXTENSION: u64 = 46;
+pub const TAG_MVE_ARCH: u64 = 48;
+pub const TAG_PAC_EXTENSION: u64 = 50;
+pub const TAG_BTI_EXTENSION: u64 = 52;
+pub const TAG_NODEFAULTS: u64 = 64;
+pub const TAG_ALSO_COMPATIBLE_WITH: u64 = 65;
+pub const TAG_T2EE_USE: u64 = 66;
+/// The version of the ABI the object conforms to, e.g. "2.09" (string)
+pub const TAG_CONFORMANCE: u64 = 67;
+pub const TAG_VIRTUALIZATION_USE: u64 = 68;
+pub const TAG_FRAMEPOINTER_USE: u64 = 72;
+pub const TAG_BTI_USE: u64 = 74;
+pub const TAG_PACRET_USE: u64 = 76;
+
+// Values for TAG_CPU_ARCH
+pub const TAG_CPU_ARCH_PRE_V4: u64 = 0;
+pub const TAG_CPU_ARCH_V4: u64 = 1;
+pub const TAG_CPU_ARCH_V4T: u64 = 2;
+pub const TAG_CPU_ARCH_V5T: u64 = 3;
+pub const TAG_CPU_ARCH_V5TE: u64 = 4;
+pub const TAG_CPU_ARCH_V5TEJ: u64 = 5;
+pub const TAG_CPU_ARCH_V6: u64 = 6;
+pub const TAG_CPU_ARCH_V6KZ: u64 = 7;
+pub const TAG_CPU_ARCH_V6T2: u64 = 8;
+pub const TAG_CPU_ARCH_V6K: u64 = 9;
+pub const TAG_CPU_ARCH_V7: u64 = 10;
+pub const TAG_CPU_ARCH_V6_M: u64 = 11;
+pub const TAG_CPU_ARCH_V6S_M: u64 = 12;
+pub const TAG_CPU_ARCH_V7E_M: u64 = 13;
+pub const TAG_CPU_ARCH_V8_A: u64 = 14;
+pub const TAG_CPU_ARCH_V8_R: u64 = 15;
+pub const TAG_CPU_ARCH_V8_M_BASE: u64 = 16;
+pub const TAG_CPU_ARCH_V8_M_MAIN: u64 = 17;
+pub const TAG_CPU_ARCH_V8_1_M_MAIN: u64 = 21;
+pub const TAG_CPU_ARCH_V9_A: u64 = 22;
+
 /// The contents of this section contains only program instructions and no program data.
 ///
 /// If any section contained by a segment does not have the SHF_ARM_PURECODE
@@ -1684,8 +1949,13 @@ pub const PT_AARCH64_MEMTAG_MTE: u32 = 0x70000002;
 /// Found in Symbol's st_other field
 pub const STO_AARCH64_VARIANT_PCS: u8 = 0x80;
 
+/// The AArch64 features used by the object, a bitmask of GNU_PROPERTY_AARCH64_FEATURE_1_*
+/// which are only set if every input object supports them.
+/// Found in NT_GNU_PROPERTY_TYPE_0 notes
 pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+/// Compatible with Branch Target Identification
 pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x1;
+/// Compatible with Pointer Authentication (of return addresses)
 pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x2;
 
 // AArch64 specific values for the Dyn d_tag field.
@@ -1964,6 +2234,816 @@ pub const R_AARCH64_TLSDESC: u32 = 1031;
 /// STT_GNU_IFUNC relocation.
 pub const R_AARCH64_IRELATIVE: u32 = 1032;
 
+//     ___     ______
+//    / \ \   / /  _ \
+//   / _ \ \ / /| |_) |
+//  / ___ \ V / |  _ <
+// /_/   \_\_/  |_| \_\
+//
+// See: https://sourceware.org/git/?p=binutils-gdb.git;a=blob;f=include/elf/avr.h
+
+/// Mask for the architecture (avr1, avr5, avrxmega2, ...) bits in e_flags
+pub const EF_AVR_MACH: u32 = 0x7F;
+/// The object was assembled with linker relaxation in mind, so its relocations can be relaxed
+pub const EF_AVR_LINKRELAX_PREPARED: u32 = 0x80;
+
+/// Assembler only cores without SRAM, e.g. the AT90S1200
+pub const E_AVR_MACH_AVR1: u32 = 1;
+/// Classic cores with up to 8 KiB of program memory
+pub const E_AVR_MACH_AVR2: u32 = 2;
+/// Classic cores with up to 8 KiB of program memory and the MOVW instruction
+pub const E_AVR_MACH_AVR25: u32 = 25;
+/// Classic cores with 16 KiB up to 64 KiB of program memory
+pub const E_AVR_MACH_AVR3: u32 = 3;
+/// Classic cores with 128 KiB of program memory
+pub const E_AVR_MACH_AVR31: u32 = 31;
+/// Classic cores with 16 KiB up to 64 KiB of program memory and the MOVW instruction
+pub const E_AVR_MACH_AVR35: u32 = 35;
+/// Enhanced cores with up to 8 KiB of program memory
+pub const E_AVR_MACH_AVR4: u32 = 4;
+/// Enhanced cores with 16 KiB up to 64 KiB of program memory
+pub const E_AVR_MACH_AVR5: u32 = 5;
+/// Enhanced cores with 128 KiB of program memory
+pub const E_AVR_MACH_AVR51: u32 = 51;
+/// Enhanced cores with a 3-byte program counter
+pub const E_AVR_MACH_AVR6: u32 = 6;
+/// Reduced Tiny cores with 16 registers
+pub const E_AVR_MACH_AVRTINY: u32 = 100;
+/// XMEGA cores (reserved)
+pub const E_AVR_MACH_XMEGA1: u32 = 101;
+/// XMEGA cores with up to 64 KiB of program memory and up to 64 KiB of RAM
+pub const E_AVR_MACH_XMEGA2: u32 = 102;
+/// XMEGA cores with program memory visible in the RAM address space
+pub const E_AVR_MACH_XMEGA3: u32 = 103;
+/// XMEGA cores with up to 64 KiB of program memory and up to 64 KiB of RAM
+pub const E_AVR_MACH_XMEGA4: u32 = 104;
+/// XMEGA cores with up to 64 KiB of program memory and more than 64 KiB of RAM
+pub const E_AVR_MACH_XMEGA5: u32 = 105;
+/// XMEGA cores with more than 64 KiB of program memory and up to 64 KiB of RAM
+pub const E_AVR_MACH_XMEGA6: u32 = 106;
+/// XMEGA cores with more than 64 KiB of program memory and more than 64 KiB of RAM
+pub const E_AVR_MACH_XMEGA7: u32 = 107;
+
+// AVR relocation types
+/// No reloc
+pub const R_AVR_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_AVR_32: u32 = 1;
+/// 7 bit PC relative, for conditional branches
+pub const R_AVR_7_PCREL: u32 = 2;
+/// 13 bit PC relative, for rjmp and rcall
+pub const R_AVR_13_PCREL: u32 = 3;
+/// Direct 16 bit
+pub const R_AVR_16: u32 = 4;
+/// 16 bit program memory (word) address
+pub const R_AVR_16_PM: u32 = 5;
+/// Low 8 bits of an address, for ldi
+pub const R_AVR_LO8_LDI: u32 = 6;
+/// High 8 bits of a 16 bit address, for ldi
+pub const R_AVR_HI8_LDI: u32 = 7;
+/// Bits 16..23 of an address, for ldi
+pub const R_AVR_HH8_LDI: u32 = 8;
+/// Low 8 bits of a negated address, for ldi
+pub const R_AVR_LO8_LDI_NEG: u32 = 9;
+/// High 8 bits of a negated 16 bit address, for ldi
+pub const R_AVR_HI8_LDI_NEG: u32 = 10;
+/// Bits 16..23 of a negated address, for ldi
+pub const R_AVR_HH8_LDI_NEG: u32 = 11;
+/// Low 8 bits of a program memory (word) address, for ldi
+pub const R_AVR_LO8_LDI_PM: u32 = 12;
+/// High 8 bits of a program memory (word) address, for ldi
+pub const R_AVR_HI8_LDI_PM: u32 = 13;
+/// Bits 16..23 of a program memory (word) address, for ldi
+pub const R_AVR_HH8_LDI_PM: u32 = 14;
+/// Low 8 bits of a negated program memory (word) address, for ldi
+pub const R_AVR_LO8_LDI_PM_NEG: u32 = 15;
+/// High 8 bits of a negated program memory (word) address, for ldi
+pub const R_AVR_HI8_LDI_PM_NEG: u32 = 16;
+/// Bits 16..23 of a negated program memory (word) address, for ldi
+pub const R_AVR_HH8_LDI_PM_NEG: u32 = 17;
+/// 22 bit program memory (word) address, for call and jmp
+pub const R_AVR_CALL: u32 = 18;
+/// 8 bit value, for ldi
+pub const R_AVR_LDI: u32 = 19;
+/// 6 bit displacement, for ldd and std
+pub const R_AVR_6: u32 = 20;
+/// 6 bit value, for adiw and sbiw
+pub const R_AVR_6_ADIW: u32 = 21;
+/// Bits 24..31 of an address, for ldi
+pub const R_AVR_MS8_LDI: u32 = 22;
+/// Bits 24..31 of a negated address, for ldi
+pub const R_AVR_MS8_LDI_NEG: u32 = 23;
+/// Low 8 bits of a program memory (word) address, possibly via a stub
+pub const R_AVR_LO8_LDI_GS: u32 = 24;
+/// High 8 bits of a program memory (word) address, possibly via a stub
+pub const R_AVR_HI8_LDI_GS: u32 = 25;
+/// Direct 8 bit
+pub const R_AVR_8: u32 = 26;
+/// Low 8 bits of an address
+pub const R_AVR_8_LO8: u32 = 27;
+/// High 8 bits of a 16 bit address
+pub const R_AVR_8_HI8: u32 = 28;
+/// Bits 16..23 of an address
+pub const R_AVR_8_HLO8: u32 = 29;
+/// 8 bit difference between two symbols, for linker relaxation
+pub const R_AVR_DIFF8: u32 = 30;
+/// 16 bit difference between two symbols, for linker relaxation
+pub const R_AVR_DIFF16: u32 = 31;
+/// 32 bit difference between two symbols, for linker relaxation
+pub const R_AVR_DIFF32: u32 = 32;
+/// 7 bit address, for the reduced Tiny cores' lds and sts
+pub const R_AVR_LDS_STS_16: u32 = 33;
+/// 6 bit I/O address, for in and out
+pub const R_AVR_PORT6: u32 = 34;
+/// 5 bit I/O address, for cbi, sbi, sbic, and sbis
+pub const R_AVR_PORT5: u32 = 35;
+/// 32 bit PC relative
+pub const R_AVR_32_PCREL: u32 = 36;
+
+//  _                                _             _
+// | |    ___   ___  _ __   __ _    / \   _ __ ___| |__
+// | |   / _ \ / _ \| '_ \ / _` |  / _ \ | '__/ __| '_ \
+// | |__| (_) | (_) | | | | (_| | / ___ \| | | (__| | | |
+// |_____\___/ \___/|_| |_|\__, |/_/   \_\_|  \___|_| |_|
+//                         |___/
+//
+// See: https://github.com/loongson/la-abi-specs/blob/release/laelf.adoc
+
+/// Mask for the base ABI modifier bits in e_flags, which select the floating point calling
+/// convention. The base ABI's integer part (LP64 or ILP32) follows from the ELF class.
+pub const EF_LARCH_ABI_MODIFIER_MASK: u32 = 0x07;
+/// Floating point arguments are passed in integer registers (LP64S/ILP32S)
+pub const EF_LARCH_ABI_SOFT_FLOAT: u32 = 0x01;
+/// 32-bit floating point arguments are passed in float registers (LP64F/ILP32F)
+pub const EF_LARCH_ABI_SINGLE_FLOAT: u32 = 0x02;
+/// Floating point arguments up to 64 bits are passed in float registers (LP64D/ILP32D)
+pub const EF_LARCH_ABI_DOUBLE_FLOAT: u32 = 0x03;
+/// Mask for the object file ABI version bits in e_flags
+pub const EF_LARCH_OBJABI_MASK: u32 = 0xC0;
+/// The object follows version 1 of the object file ABI, which drops the stack-based
+/// R_LARCH_SOP_* relocations
+pub const EF_LARCH_OBJABI_V1: u32 = 0x40;
+
+// LoongArch relocation types
+/// No reloc
+pub const R_LARCH_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_LARCH_32: u32 = 1;
+/// Direct 64 bit
+pub const R_LARCH_64: u32 = 2;
+/// Adjust by program base
+pub const R_LARCH_RELATIVE: u32 = 3;
+/// Copy symbol at runtime
+pub const R_LARCH_COPY: u32 = 4;
+/// Create PLT entry
+pub const R_LARCH_JUMP_SLOT: u32 = 5;
+/// 32 bit ID of module containing symbol
+pub const R_LARCH_TLS_DTPMOD32: u32 = 6;
+/// 64 bit ID of module containing symbol
+pub const R_LARCH_TLS_DTPMOD64: u32 = 7;
+/// 32 bit offset in TLS block
+pub const R_LARCH_TLS_DTPREL32: u32 = 8;
+/// 64 bit offset in TLS block
+pub const R_LARCH_TLS_DTPREL64: u32 = 9;
+/// 32 bit offset relative to the thread pointer
+pub const R_LARCH_TLS_TPREL32: u32 = 10;
+/// 64 bit offset relative to the thread pointer
+pub const R_LARCH_TLS_TPREL64: u32 = 11;
+/// Adjust indirectly by program base
+pub const R_LARCH_IRELATIVE: u32 = 12;
+/// 32 bit TLS descriptor
+pub const R_LARCH_TLS_DESC32: u32 = 13;
+/// 64 bit TLS descriptor
+pub const R_LARCH_TLS_DESC64: u32 = 14;
+// R_LARCH_MARK_LA through R_LARCH_SOP_POP_32_U are the stack-based relocations of
+// object file ABI version 0, and are reserved in version 1.
+pub const R_LARCH_MARK_LA: u32 = 20;
+pub const R_LARCH_MARK_PCREL: u32 = 21;
+pub const R_LARCH_SOP_PUSH_PCREL: u32 = 22;
+pub const R_LARCH_SOP_PUSH_ABSOLUTE: u32 = 23;
+pub const R_LARCH_SOP_PUSH_DUP: u32 = 24;
+pub const R_LARCH_SOP_PUSH_GPREL: u32 = 25;
+pub const R_LARCH_SOP_PUSH_TLS_TPREL: u32 = 26;
+pub const R_LARCH_SOP_PUSH_TLS_GOT: u32 = 27;
+pub const R_LARCH_SOP_PUSH_TLS_GD: u32 = 28;
+pub const R_LARCH_SOP_PUSH_PLT_PCREL: u32 = 29;
+pub const R_LARCH_SOP_ASSERT: u32 = 30;
+pub const R_LARCH_SOP_NOT: u32 = 31;
+pub const R_LARCH_SOP_SUB: u32 = 32;
+pub const R_LARCH_SOP_SL: u32 = 33;
+pub const R_LARCH_SOP_SR: u32 = 34;
+pub const R_LARCH_SOP_ADD: u32 = 35;
+pub const R_LARCH_SOP_AND: u32 = 36;
+pub const R_LARCH_SOP_IF_ELSE: u32 = 37;
+pub const R_LARCH_SOP_POP_32_S_10_5: u32 = 38;
+pub const R_LARCH_SOP_POP_32_U_10_12: u32 = 39;
+pub const R_LARCH_SOP_POP_32_S_10_12: u32 = 40;
+pub const R_LARCH_SOP_POP_32_S_10_16: u32 = 41;
+pub const R_LARCH_SOP_POP_32_S_10_16_S2: u32 = 42;
+pub const R_LARCH_SOP_POP_32_S_5_20: u32 = 43;
+pub const R_LARCH_SOP_POP_32_S_0_5_10_16_S2: u32 = 44;
+pub const R_LARCH_SOP_POP_32_S_0_10_10_16_S2: u32 = 45;
+pub const R_LARCH_SOP_POP_32_U: u32 = 46;
+/// 8 bit in-place addition
+pub const R_LARCH_ADD8: u32 = 47;
+/// 16 bit in-place addition
+pub const R_LARCH_ADD16: u32 = 48;
+/// 24 bit in-place addition
+pub const R_LARCH_ADD24: u32 = 49;
+/// 32 bit in-place addition
+pub const R_LARCH_ADD32: u32 = 50;
+/// 64 bit in-place addition
+pub const R_LARCH_ADD64: u32 = 51;
+/// 8 bit in-place subtraction
+pub const R_LARCH_SUB8: u32 = 52;
+/// 16 bit in-place subtraction
+pub const R_LARCH_SUB16: u32 = 53;
+/// 24 bit in-place subtraction
+pub const R_LARCH_SUB24: u32 = 54;
+/// 32 bit in-place subtraction
+pub const R_LARCH_SUB32: u32 = 55;
+/// 64 bit in-place subtraction
+pub const R_LARCH_SUB64: u32 = 56;
+/// GNU C++ vtable hierarchy
+pub const R_LARCH_GNU_VTINHERIT: u32 = 57;
+/// GNU C++ vtable member usage
+pub const R_LARCH_GNU_VTENTRY: u32 = 58;
+/// 18 bit PC-relative jump, shifted by 2
+pub const R_LARCH_B16: u32 = 59;
+/// 23 bit PC-relative jump, shifted by 2
+pub const R_LARCH_B21: u32 = 60;
+/// 28 bit PC-relative jump, shifted by 2
+pub const R_LARCH_B26: u32 = 61;
+/// Bits 31:12 of 32/64 bit absolute address
+pub const R_LARCH_ABS_HI20: u32 = 62;
+/// Bits 11:0 of 32/64 bit absolute address
+pub const R_LARCH_ABS_LO12: u32 = 63;
+/// Bits 51:32 of 64 bit absolute address
+pub const R_LARCH_ABS64_LO20: u32 = 64;
+/// Bits 63:52 of 64 bit absolute address
+pub const R_LARCH_ABS64_HI12: u32 = 65;
+/// Bits 31:12 of the 4K page offset to the symbol
+pub const R_LARCH_PCALA_HI20: u32 = 66;
+/// Bits 11:0 of the symbol's address
+pub const R_LARCH_PCALA_LO12: u32 = 67;
+/// Bits 51:32 of the 64 bit offset to the symbol's page
+pub const R_LARCH_PCALA64_LO20: u32 = 68;
+/// Bits 63:52 of the 64 bit offset to the symbol's page
+pub const R_LARCH_PCALA64_HI12: u32 = 69;
+/// Bits 31:12 of the 4K page offset to the GOT entry
+pub const R_LARCH_GOT_PC_HI20: u32 = 70;
+/// Bits 11:0 of the GOT entry's address
+pub const R_LARCH_GOT_PC_LO12: u32 = 71;
+/// Bits 51:32 of the 64 bit offset to the GOT entry's page
+pub const R_LARCH_GOT64_PC_LO20: u32 = 72;
+/// Bits 63:52 of the 64 bit offset to the GOT entry's page
+pub const R_LARCH_GOT64_PC_HI12: u32 = 73;
+/// Bits 31:12 of the GOT entry's absolute address
+pub const R_LARCH_GOT_HI20: u32 = 74;
+/// Bits 11:0 of the GOT entry's absolute address
+pub const R_LARCH_GOT_LO12: u32 = 75;
+/// Bits 51:32 of the GOT entry's absolute address
+pub const R_LARCH_GOT64_LO20: u32 = 76;
+/// Bits 63:52 of the GOT entry's absolute address
+pub const R_LARCH_GOT64_HI12: u32 = 77;
+/// Bits 31:12 of the TLS LE 32/64 bit offset from the thread pointer
+pub const R_LARCH_TLS_LE_HI20: u32 = 78;
+/// Bits 11:0 of the TLS LE 32/64 bit offset from the thread pointer
+pub const R_LARCH_TLS_LE_LO12: u32 = 79;
+/// Bits 51:32 of the TLS LE 64 bit offset from the thread pointer
+pub const R_LARCH_TLS_LE64_LO20: u32 = 80;
+/// Bits 63:52 of the TLS LE 64 bit offset from the thread pointer
+pub const R_LARCH_TLS_LE64_HI12: u32 = 81;
+/// Bits 31:12 of the 4K page offset to the TLS IE GOT entry
+pub const R_LARCH_TLS_IE_PC_HI20: u32 = 82;
+/// Bits 11:0 of the TLS IE GOT entry's address
+pub const R_LARCH_TLS_IE_PC_LO12: u32 = 83;
+/// Bits 51:32 of the 64 bit offset to the TLS IE GOT entry's page
+pub const R_LARCH_TLS_IE64_PC_LO20: u32 = 84;
+/// Bits 63:52 of the 64 bit offset to the TLS IE GOT entry's page
+pub const R_LARCH_TLS_IE64_PC_HI12: u32 = 85;
+/// Bits 31:12 of the TLS IE GOT entry's absolute address
+pub const R_LARCH_TLS_IE_HI20: u32 = 86;
+/// Bits 11:0 of the TLS IE GOT entry's absolute address
+pub const R_LARCH_TLS_IE_LO12: u32 = 87;
+/// Bits 51:32 of the TLS IE GOT entry's absolute address
+pub const R_LARCH_TLS_IE64_LO20: u32 = 88;
+/// Bits 63:52 of the TLS IE GOT entry's absolute address
+pub const R_LARCH_TLS_IE64_HI12: u32 = 89;
+/// Bits 31:12 of the 4K page offset to the TLS LD GOT entry
+pub const R_LARCH_TLS_LD_PC_HI20: u32 = 90;
+/// Bits 31:12 of the TLS LD GOT entry's absolute address
+pub const R_LARCH_TLS_LD_HI20: u32 = 91;
+/// Bits 31:12 of the 4K page offset to the TLS GD GOT entry
+pub const R_LARCH_TLS_GD_PC_HI20: u32 = 92;
+/// Bits 31:12 of the TLS GD GOT entry's absolute address
+pub const R_LARCH_TLS_GD_HI20: u32 = 93;
+/// 32 bit PC-relative offset
+pub const R_LARCH_32_PCREL: u32 = 94;
+/// The instruction can be relaxed, paired with the preceding relocation
+pub const R_LARCH_RELAX: u32 = 95;
+/// Alignment requirement for linker relaxation
+pub const R_LARCH_ALIGN: u32 = 97;
+/// 22 bit PC-relative offset, shifted by 2
+pub const R_LARCH_PCREL20_S2: u32 = 98;
+/// 6 bit in-place addition
+pub const R_LARCH_ADD6: u32 = 100;
+/// 6 bit in-place subtraction
+pub const R_LARCH_SUB6: u32 = 101;
+/// LEB128 in-place addition
+pub const R_LARCH_ADD_ULEB128: u32 = 102;
+/// LEB128 in-place subtraction
+pub const R_LARCH_SUB_ULEB128: u32 = 103;
+/// 64 bit PC-relative offset
+pub const R_LARCH_64_PCREL: u32 = 104;
+/// 38 bit PC-relative call using a pcaddu18i and jirl pair, shifted by 2
+pub const R_LARCH_CALL36: u32 = 105;
+/// Bits 31:12 of the 4K page offset to the TLS descriptor
+pub const R_LARCH_TLS_DESC_PC_HI20: u32 = 106;
+/// Bits 11:0 of the TLS descriptor's address
+pub const R_LARCH_TLS_DESC_PC_LO12: u32 = 107;
+/// Bits 51:32 of the 64 bit offset to the TLS descriptor's page
+pub const R_LARCH_TLS_DESC64_PC_LO20: u32 = 108;
+/// Bits 63:52 of the 64 bit offset to the TLS descriptor's page
+pub const R_LARCH_TLS_DESC64_PC_HI12: u32 = 109;
+/// Bits 31:12 of the TLS descriptor's absolute address
+pub const R_LARCH_TLS_DESC_HI20: u32 = 110;
+/// Bits 11:0 of the TLS descriptor's absolute address
+pub const R_LARCH_TLS_DESC_LO12: u32 = 111;
+/// Bits 51:32 of the TLS descriptor's absolute address
+pub const R_LARCH_TLS_DESC64_LO20: u32 = 112;
+/// Bits 63:52 of the TLS descriptor's absolute address
+pub const R_LARCH_TLS_DESC64_HI12: u32 = 113;
+/// Marks the load of the TLS descriptor's resolver function
+pub const R_LARCH_TLS_DESC_LD: u32 = 114;
+/// Marks the call to the TLS descriptor's resolver function
+pub const R_LARCH_TLS_DESC_CALL: u32 = 115;
+/// Bits 31:12 of the TLS LE offset, for relaxation
+pub const R_LARCH_TLS_LE_HI20_R: u32 = 116;
+/// Marks the thread pointer add of a TLS LE sequence, for relaxation
+pub const R_LARCH_TLS_LE_ADD_R: u32 = 117;
+/// Bits 11:0 of the TLS LE offset, for relaxation
+pub const R_LARCH_TLS_LE_LO12_R: u32 = 118;
+/// 22 bit PC-relative offset to the TLS LD GOT entry, shifted by 2
+pub const R_LARCH_TLS_LD_PCREL20_S2: u32 = 119;
+/// 22 bit PC-relative offset to the TLS GD GOT entry, shifted by 2
+pub const R_LARCH_TLS_GD_PCREL20_S2: u32 = 120;
+/// 22 bit PC-relative offset to the TLS descriptor, shifted by 2
+pub const R_LARCH_TLS_DESC_PCREL20_S2: u32 = 121;
+
+//  __  __ ___ ____  ____
+// |  \/  |_ _|  _ \/ ___|
+// | |\/| || || |_) \___ \
+// | |  | || ||  __/ ___) |
+// |_|  |_|___|_|   |____/
+//
+// See: <https://refspecs.linuxfoundation.org/elf/mipsabi.pdf>
+// and the MIPS definitions in glibc's elf.h
+
+/// Mask for the ISA level bits in e_flags
+pub const EF_MIPS_ARCH: u32 = 0xf0000000;
+/// -mips1 code
+pub const EF_MIPS_ARCH_1: u32 = 0x00000000;
+/// -mips2 code
+pub const EF_MIPS_ARCH_2: u32 = 0x10000000;
+/// -mips3 code
+pub const EF_MIPS_ARCH_3: u32 = 0x20000000;
+/// -mips4 code
+pub const EF_MIPS_ARCH_4: u32 = 0x30000000;
+/// -mips5 code
+pub const EF_MIPS_ARCH_5: u32 = 0x40000000;
+/// MIPS32 code
+pub const EF_MIPS_ARCH_32: u32 = 0x50000000;
+/// MIPS64 code
+pub const EF_MIPS_ARCH_64: u32 = 0x60000000;
+/// MIPS32r2 code
+pub const EF_MIPS_ARCH_32R2: u32 = 0x70000000;
+/// MIPS64r2 code
+pub const EF_MIPS_ARCH_64R2: u32 = 0x80000000;
+/// MIPS32r6 code
+pub const EF_MIPS_ARCH_32R6: u32 = 0x90000000;
+/// MIPS64r6 code
+pub const EF_MIPS_ARCH_64R6: u32 = 0xa0000000;
+/// A .noreorder directive was used
+pub const EF_MIPS_NOREORDER: u32 = 0x1;
+/// Contains position independent code
+pub const EF_MIPS_PIC: u32 = 0x2;
+/// Uses PIC calling sequences
+pub const EF_MIPS_CPIC: u32 = 0x4;
+pub const EF_MIPS_XGOT: u32 = 0x8;
+pub const EF_MIPS_UCODE: u32 = 0x10;
+/// n32 ABI
+pub const EF_MIPS_ABI2: u32 = 0x20;
+pub const EF_MIPS_ABI_ON32: u32 = 0x40;
+/// Uses FP64 (12 callee-saved)
+pub const EF_MIPS_FP64: u32 = 0x200;
+/// Uses IEEE 754-2008 NaN encoding
+pub const EF_MIPS_NAN2008: u32 = 0x400;
+/// Mask for the ABI bits in e_flags. The n32 ABI is marked by [EF_MIPS_ABI2] instead, and n64
+/// objects are ELFCLASS64 with none of these bits set.
+pub const EF_MIPS_ABI: u32 = 0x0000f000;
+/// The original 32-bit ABI
+pub const E_MIPS_ABI_O32: u32 = 0x00001000;
+/// o32 extended for 64-bit registers
+pub const E_MIPS_ABI_O64: u32 = 0x00002000;
+/// The 32-bit embedded ABI
+pub const E_MIPS_ABI_EABI32: u32 = 0x00003000;
+/// The 64-bit embedded ABI
+pub const E_MIPS_ABI_EABI64: u32 = 0x00004000;
+/// Mask for the application specific extension (ASE) bits in e_flags
+pub const EF_MIPS_ARCH_ASE: u32 = 0x0f000000;
+/// Uses the MDMX multimedia extensions
+pub const EF_MIPS_ARCH_ASE_MDMX: u32 = 0x08000000;
+/// Uses MIPS-16 instructions
+pub const EF_MIPS_ARCH_ASE_M16: u32 = 0x04000000;
+/// Uses microMIPS instructions
+pub const EF_MIPS_ARCH_ASE_MICROMIPS: u32 = 0x02000000;
+
+// MIPS relocation types
+
+/// No reloc
+pub const R_MIPS_NONE: u32 = 0;
+/// Direct 16 bit
+pub const R_MIPS_16: u32 = 1;
+/// Direct 32 bit
+pub const R_MIPS_32: u32 = 2;
+/// 32 bit, adjusted by the load address in dynamic objects
+pub const R_MIPS_REL32: u32 = 3;
+/// Direct 26 bit shifted
+pub const R_MIPS_26: u32 = 4;
+/// High 16 bit
+pub const R_MIPS_HI16: u32 = 5;
+/// Low 16 bit
+pub const R_MIPS_LO16: u32 = 6;
+/// GP relative 16 bit
+pub const R_MIPS_GPREL16: u32 = 7;
+/// 16 bit literal entry
+pub const R_MIPS_LITERAL: u32 = 8;
+/// 16 bit GOT entry
+pub const R_MIPS_GOT16: u32 = 9;
+/// PC relative 16 bit
+pub const R_MIPS_PC16: u32 = 10;
+/// 16 bit GOT entry for function
+pub const R_MIPS_CALL16: u32 = 11;
+/// GP relative 32 bit
+pub const R_MIPS_GPREL32: u32 = 12;
+pub const R_MIPS_SHIFT5: u32 = 16;
+pub const R_MIPS_SHIFT6: u32 = 17;
+pub const R_MIPS_64: u32 = 18;
+pub const R_MIPS_GOT_DISP: u32 = 19;
+pub const R_MIPS_GOT_PAGE: u32 = 20;
+pub const R_MIPS_GOT_OFST: u32 = 21;
+pub const R_MIPS_GOT_HI16: u32 = 22;
+pub const R_MIPS_GOT_LO16: u32 = 23;
+pub const R_MIPS_SUB: u32 = 24;
+pub const R_MIPS_INSERT_A: u32 = 25;
+pub const R_MIPS_INSERT_B: u32 = 26;
+pub const R_MIPS_DELETE: u32 = 27;
+pub const R_MIPS_HIGHER: u32 = 28;
+pub const R_MIPS_HIGHEST: u32 = 29;
+pub const R_MIPS_CALL_HI16: u32 = 30;
+pub const R_MIPS_CALL_LO16: u32 = 31;
+pub const R_MIPS_SCN_DISP: u32 = 32;
+pub const R_MIPS_REL16: u32 = 33;
+pub const R_MIPS_ADD_IMMEDIATE: u32 = 34;
+pub const R_MIPS_PJUMP: u32 = 35;
+pub const R_MIPS_RELGOT: u32 = 36;
+pub const R_MIPS_JALR: u32 = 37;
+/// Module number 32 bit
+pub const R_MIPS_TLS_DTPMOD32: u32 = 38;
+/// Module-relative offset 32 bit
+pub const R_MIPS_TLS_DTPREL32: u32 = 39;
+/// Module number 64 bit
+pub const R_MIPS_TLS_DTPMOD64: u32 = 40;
+/// Module-relative offset 64 bit
+pub const R_MIPS_TLS_DTPREL64: u32 = 41;
+/// 16 bit GOT offset for GD
+pub const R_MIPS_TLS_GD: u32 = 42;
+/// 16 bit GOT offset for LDM
+pub const R_MIPS_TLS_LDM: u32 = 43;
+/// Module-relative offset, high 16 bits
+pub const R_MIPS_TLS_DTPREL_HI16: u32 = 44;
+/// Module-relative offset, low 16 bits
+pub const R_MIPS_TLS_DTPREL_LO16: u32 = 45;
+/// 16 bit GOT offset for IE
+pub const R_MIPS_TLS_GOTTPREL: u32 = 46;
+/// TP-relative offset, 32 bit
+pub const R_MIPS_TLS_TPREL32: u32 = 47;
+/// TP-relative offset, 64 bit
+pub const R_MIPS_TLS_TPREL64: u32 = 48;
+/// TP-relative offset, high 16 bits
+pub const R_MIPS_TLS_TPREL_HI16: u32 = 49;
+/// TP-relative offset, low 16 bits
+pub const R_MIPS_TLS_TPREL_LO16: u32 = 50;
+pub const R_MIPS_GLOB_DAT: u32 = 51;
+/// PC relative 21 bit, shifted by 2 (MIPS32r6/MIPS64r6)
+pub const R_MIPS_PC21_S2: u32 = 60;
+/// PC relative 26 bit, shifted by 2 (MIPS32r6/MIPS64r6)
+pub const R_MIPS_PC26_S2: u32 = 61;
+/// PC relative 18 bit, shifted by 3 (MIPS64r6)
+pub const R_MIPS_PC18_S3: u32 = 62;
+/// PC relative 19 bit, shifted by 2 (MIPS32r6/MIPS64r6)
+pub const R_MIPS_PC19_S2: u32 = 63;
+/// PC relative high 16 bit (MIPS32r6/MIPS64r6)
+pub const R_MIPS_PCHI16: u32 = 64;
+/// PC relative low 16 bit (MIPS32r6/MIPS64r6)
+pub const R_MIPS_PCLO16: u32 = 65;
+pub const R_MIPS_COPY: u32 = 126;
+pub const R_MIPS_JUMP_SLOT: u32 = 127;
+
+/// Register usage information: an Elf32_RegInfo structure
+pub const PT_MIPS_REGINFO: u32 = 0x70000000;
+/// Runtime procedure table
+pub const PT_MIPS_RTPROC: u32 = 0x70000001;
+/// .MIPS.options section
+pub const PT_MIPS_OPTIONS: u32 = 0x70000002;
+/// .MIPS.abiflags section
+pub const PT_MIPS_ABIFLAGS: u32 = 0x70000003;
+
+/// Shared objects used in link
+pub const SHT_MIPS_LIBLIST: u32 = 0x70000000;
+pub const SHT_MIPS_MSYM: u32 = 0x70000001;
+/// Conflicting symbols
+pub const SHT_MIPS_CONFLICT: u32 = 0x70000002;
+/// Global data area sizes
+pub const SHT_MIPS_GPTAB: u32 = 0x70000003;
+/// Reserved for SGI/MIPS compilers
+pub const SHT_MIPS_UCODE: u32 = 0x70000004;
+/// MIPS ECOFF debugging info
+pub const SHT_MIPS_DEBUG: u32 = 0x70000005;
+/// Register usage information
+pub const SHT_MIPS_REGINFO: u32 = 0x70000006;
+pub const SHT_MIPS_PACKAGE: u32 = 0x70000007;
+pub const SHT_MIPS_PACKSYM: u32 = 0x70000008;
+pub const SHT_MIPS_RELD: u32 = 0x70000009;
+pub const SHT_MIPS_IFACE: u32 = 0x7000000b;
+pub const SHT_MIPS_CONTENT: u32 = 0x7000000c;
+/// Miscellaneous options
+pub const SHT_MIPS_OPTIONS: u32 = 0x7000000d;
+pub const SHT_MIPS_SHDR: u32 = 0x70000010;
+pub const SHT_MIPS_FDESC: u32 = 0x70000011;
+pub const SHT_MIPS_EXTSYM: u32 = 0x70000012;
+pub const SHT_MIPS_DENSE: u32 = 0x70000013;
+pub const SHT_MIPS_PDESC: u32 = 0x70000014;
+pub const SHT_MIPS_LOCSYM: u32 = 0x70000015;
+pub const SHT_MIPS_AUXSYM: u32 = 0x70000016;
+pub const SHT_MIPS_OPTSYM: u32 = 0x70000017;
+pub const SHT_MIPS_LOCSTR: u32 = 0x70000018;
+pub const SHT_MIPS_LINE: u32 = 0x70000019;
+pub const SHT_MIPS_RFDESC: u32 = 0x7000001a;
+/// Delta C++: symbol table
+pub const SHT_MIPS_DELTASYM: u32 = 0x7000001b;
+/// Delta C++: instance table
+pub const SHT_MIPS_DELTAINST: u32 = 0x7000001c;
+/// Delta C++: class table
+pub const SHT_MIPS_DELTACLASS: u32 = 0x7000001d;
+/// DWARF debugging information
+pub const SHT_MIPS_DWARF: u32 = 0x7000001e;
+/// Delta C++: declarations
+pub const SHT_MIPS_DELTADECL: u32 = 0x7000001f;
+/// Symbol-to-library mapping
+pub const SHT_MIPS_SYMBOL_LIB: u32 = 0x70000020;
+/// Event section
+pub const SHT_MIPS_EVENTS: u32 = 0x70000021;
+pub const SHT_MIPS_TRANSLATE: u32 = 0x70000022;
+/// Special pixie sections
+pub const SHT_MIPS_PIXIE: u32 = 0x70000023;
+/// Address translation table
+pub const SHT_MIPS_XLATE: u32 = 0x70000024;
+/// SGI internal address translation table
+pub const SHT_MIPS_XLATE_DEBUG: u32 = 0x70000025;
+/// Intermediate code
+pub const SHT_MIPS_WHIRL: u32 = 0x70000026;
+/// C++ exception handling region info
+pub const SHT_MIPS_EH_REGION: u32 = 0x70000027;
+pub const SHT_MIPS_XLATE_OLD: u32 = 0x70000028;
+/// Runtime procedure descriptor table exception information
+pub const SHT_MIPS_PDR_EXCEPTION: u32 = 0x70000029;
+/// ABI related flags (the .MIPS.abiflags section)
+pub const SHT_MIPS_ABIFLAGS: u32 = 0x7000002a;
+/// GNU style symbol hash table with xlat
+pub const SHT_MIPS_XHASH: u32 = 0x7000002b;
+
+/// The only defined version of the .MIPS.abiflags structure
+pub const MIPS_ABIFLAGS_VERSION: u16 = 0;
+
+// MIPS_AFL_REG_* define the register sizes in the .MIPS.abiflags gpr_size, cpr1_size and
+// cpr2_size fields.
+/// No registers
+pub const MIPS_AFL_REG_NONE: u8 = 0x00;
+/// 32-bit registers
+pub const MIPS_AFL_REG_32: u8 = 0x01;
+/// 64-bit registers
+pub const MIPS_AFL_REG_64: u8 = 0x02;
+/// 128-bit registers
+pub const MIPS_AFL_REG_128: u8 = 0x03;
+
+// MIPS_AFL_ASE_* define the bits in the .MIPS.abiflags ases field.
+/// DSP ASE
+pub const MIPS_AFL_ASE_DSP: u32 = 0x00000001;
+/// DSP R2 ASE
+pub const MIPS_AFL_ASE_DSPR2: u32 = 0x00000002;
+/// Enhanced VA Scheme
+pub const MIPS_AFL_ASE_EVA: u32 = 0x00000004;
+/// MCU (MicroController) ASE
+pub const MIPS_AFL_ASE_MCU: u32 = 0x00000008;
+/// MDMX ASE
+pub const MIPS_AFL_ASE_MDMX: u32 = 0x00000010;
+/// MIPS-3D ASE
+pub const MIPS_AFL_ASE_MIPS3D: u32 = 0x00000020;
+/// MT ASE
+pub const MIPS_AFL_ASE_MT: u32 = 0x00000040;
+/// SmartMIPS ASE
+pub const MIPS_AFL_ASE_SMARTMIPS: u32 = 0x00000080;
+/// VZ ASE
+pub const MIPS_AFL_ASE_VIRT: u32 = 0x00000100;
+/// MSA ASE
+pub const MIPS_AFL_ASE_MSA: u32 = 0x00000200;
+/// MIPS16 ASE
+pub const MIPS_AFL_ASE_MIPS16: u32 = 0x00000400;
+/// MICROMIPS ASE
+pub const MIPS_AFL_ASE_MICROMIPS: u32 = 0x00000800;
+/// XPA ASE
+pub const MIPS_AFL_ASE_XPA: u32 = 0x00001000;
+/// All the MIPS_AFL_ASE_* bits
+pub const MIPS_AFL_ASE_MASK: u32 = 0x00001fff;
+
+// MIPS_AFL_EXT_* define the processor-specific extension values for the .MIPS.abiflags
+// isa_ext field.
+/// RMI Xlr instruction
+pub const MIPS_AFL_EXT_XLR: u32 = 1;
+/// Cavium Networks Octeon2
+pub const MIPS_AFL_EXT_OCTEON2: u32 = 2;
+/// Cavium Networks OcteonP
+pub const MIPS_AFL_EXT_OCTEONP: u32 = 3;
+/// Loongson 3A
+pub const MIPS_AFL_EXT_LOONGSON_3A: u32 = 4;
+/// Cavium Networks Octeon
+pub const MIPS_AFL_EXT_OCTEON: u32 = 5;
+/// MIPS R5900 instruction
+pub const MIPS_AFL_EXT_5900: u32 = 6;
+/// MIPS R4650 instruction
+pub const MIPS_AFL_EXT_4650: u32 = 7;
+/// LSI R4010 instruction
+pub const MIPS_AFL_EXT_4010: u32 = 8;
+/// NEC VR4100 instruction
+pub const MIPS_AFL_EXT_4100: u32 = 9;
+/// Toshiba R3900 instruction
+pub const MIPS_AFL_EXT_3900: u32 = 10;
+/// MIPS R10000 instruction
+pub const MIPS_AFL_EXT_10000: u32 = 11;
+/// Broadcom SB-1 instruction
+pub const MIPS_AFL_EXT_SB1: u32 = 12;
+/// NEC VR4111/VR4181 instruction
+pub const MIPS_AFL_EXT_4111: u32 = 13;
+/// NEC VR4120 instruction
+pub const MIPS_AFL_EXT_4120: u32 = 14;
+/// NEC VR5400 instruction
+pub const MIPS_AFL_EXT_5400: u32 = 15;
+/// NEC VR5500 instruction
+pub const MIPS_AFL_EXT_5500: u32 = 16;
+/// ST Microelectronics Loongson 2E
+pub const MIPS_AFL_EXT_LOONGSON_2E: u32 = 17;
+/// ST Microelectronics Loongson 2F
+pub const MIPS_AFL_EXT_LOONGSON_2F: u32 = 18;
+
+/// Uses odd single-precision registers: a bit in the .MIPS.abiflags flags1 field
+pub const MIPS_AFL_FLAGS1_ODDSPREG: u32 = 1;
+
+// Val_GNU_MIPS_ABI_FP_* define the floating point ABIs in the .MIPS.abiflags fp_abi field.
+/// Not tagged or not using any ABIs affected by the differences
+pub const VAL_GNU_MIPS_ABI_FP_ANY: u8 = 0;
+/// Using hard-float -mdouble-float
+pub const VAL_GNU_MIPS_ABI_FP_DOUBLE: u8 = 1;
+/// Using hard-float -msingle-float
+pub const VAL_GNU_MIPS_ABI_FP_SINGLE: u8 = 2;
+/// Using soft-float
+pub const VAL_GNU_MIPS_ABI_FP_SOFT: u8 = 3;
+/// Using -mips32r2 -mfp64
+pub const VAL_GNU_MIPS_ABI_FP_OLD_64: u8 = 4;
+/// Using -mfpxx
+pub const VAL_GNU_MIPS_ABI_FP_XX: u8 = 5;
+/// Using -mips32r2 -mfp64
+pub const VAL_GNU_MIPS_ABI_FP_64: u8 = 6;
+/// Using -mips32r2 -mfp64 -mno-odd-spreg
+pub const VAL_GNU_MIPS_ABI_FP_64A: u8 = 7;
+
+//  __  __ ____  ____  _  _  _____  ___
+// |  \/  / ___||  _ \| || ||___ / / _ \
+// | |\/| \___ \| |_) | || |_ |_ \| | | |
+// | |  | |___) |  __/|__   _|__) | |_| |
+// |_|  |_|____/|_|      |_||____/ \___/
+//
+// See: https://sourceware.org/git/?p=binutils-gdb.git;a=blob;f=include/elf/msp430.h
+
+/// Mask for the MCU family bits in e_flags
+pub const EF_MSP430_MACH: u32 = 0xff;
+
+pub const E_MSP430_MACH_MSP430X11: u32 = 11;
+pub const E_MSP430_MACH_MSP430X11X1: u32 = 110;
+pub const E_MSP430_MACH_MSP430X12: u32 = 12;
+pub const E_MSP430_MACH_MSP430X13: u32 = 13;
+pub const E_MSP430_MACH_MSP430X14: u32 = 14;
+pub const E_MSP430_MACH_MSP430X15: u32 = 15;
+pub const E_MSP430_MACH_MSP430X16: u32 = 16;
+pub const E_MSP430_MACH_MSP430X20: u32 = 20;
+pub const E_MSP430_MACH_MSP430X22: u32 = 22;
+pub const E_MSP430_MACH_MSP430X23: u32 = 23;
+pub const E_MSP430_MACH_MSP430X24: u32 = 24;
+pub const E_MSP430_MACH_MSP430X26: u32 = 26;
+pub const E_MSP430_MACH_MSP430X31: u32 = 31;
+pub const E_MSP430_MACH_MSP430X32: u32 = 32;
+pub const E_MSP430_MACH_MSP430X33: u32 = 33;
+pub const E_MSP430_MACH_MSP430X41: u32 = 41;
+pub const E_MSP430_MACH_MSP430X42: u32 = 42;
+pub const E_MSP430_MACH_MSP430X43: u32 = 43;
+pub const E_MSP430_MACH_MSP430X44: u32 = 44;
+pub const E_MSP430_MACH_MSP430X46: u32 = 46;
+pub const E_MSP430_MACH_MSP430X47: u32 = 47;
+pub const E_MSP430_MACH_MSP430X54: u32 = 54;
+/// The MSP430X architecture, whose 20-bit addressing uses the R_MSP430X_* relocation set
+pub const E_MSP430_MACH_MSP430X: u32 = 45;
+
+// MSP430 relocation types
+//
+// Objects for the original MSP430 architecture use these. Objects for E_MSP430_MACH_MSP430X
+// or with an EI_OSABI of ELFOSABI_STANDALONE use the MSP430X set below instead, whose values
+// overlap with these.
+/// No reloc
+pub const R_MSP430_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_MSP430_32: u32 = 1;
+/// 10 bit PC relative, for jumps
+pub const R_MSP430_10_PCREL: u32 = 2;
+/// Direct 16 bit
+pub const R_MSP430_16: u32 = 3;
+/// 16 bit PC relative
+pub const R_MSP430_16_PCREL: u32 = 4;
+/// Direct 16 bit, which may be unaligned
+pub const R_MSP430_16_BYTE: u32 = 5;
+/// 16 bit PC relative, which may be unaligned
+pub const R_MSP430_16_PCREL_BYTE: u32 = 6;
+/// 10 bit PC relative, for a relaxed pair of jumps
+pub const R_MSP430_2X_PCREL: u32 = 7;
+/// 16 bit PC relative, for a relaxable branch
+pub const R_MSP430_RL_PCREL: u32 = 8;
+/// Direct 8 bit
+pub const R_MSP430_8: u32 = 9;
+/// Marks the subtrahend of a difference between two symbols
+pub const R_MSP430_SYM_DIFF: u32 = 10;
+/// Sets a ULEB128 value, paired with R_MSP430_GNU_SUB_ULEB128
+pub const R_MSP430_GNU_SET_ULEB128: u32 = 11;
+/// Subtracts from a ULEB128 value
+pub const R_MSP430_GNU_SUB_ULEB128: u32 = 12;
+
+// MSP430X relocation types
+/// Direct 32 bit
+pub const R_MSP430_ABS32: u32 = 1;
+/// Direct 16 bit
+pub const R_MSP430_ABS16: u32 = 2;
+/// Direct 8 bit
+pub const R_MSP430_ABS8: u32 = 3;
+/// 16 bit PC relative
+pub const R_MSP430_PCR16: u32 = 4;
+/// 20 bit PC relative source operand of an extended instruction
+pub const R_MSP430X_PCR20_EXT_SRC: u32 = 5;
+/// 20 bit PC relative destination operand of an extended instruction
+pub const R_MSP430X_PCR20_EXT_DST: u32 = 6;
+/// 20 bit PC relative destination operand of an extended instruction with a source operand
+pub const R_MSP430X_PCR20_EXT_ODST: u32 = 7;
+/// 20 bit absolute source operand of an extended instruction
+pub const R_MSP430X_ABS20_EXT_SRC: u32 = 8;
+/// 20 bit absolute destination operand of an extended instruction
+pub const R_MSP430X_ABS20_EXT_DST: u32 = 9;
+/// 20 bit absolute destination operand of an extended instruction with a source operand
+pub const R_MSP430X_ABS20_EXT_ODST: u32 = 10;
+/// 20 bit absolute source operand of an address instruction
+pub const R_MSP430X_ABS20_ADR_SRC: u32 = 11;
+/// 20 bit absolute destination operand of an address instruction
+pub const R_MSP430X_ABS20_ADR_DST: u32 = 12;
+/// 16 bit PC relative
+pub const R_MSP430X_PCR16: u32 = 13;
+/// 20 bit PC relative, for calla
+pub const R_MSP430X_PCR20_CALL: u32 = 14;
+/// Direct 16 bit
+pub const R_MSP430X_ABS16: u32 = 15;
+/// High 16 bits of a 32 bit address
+pub const R_MSP430_ABS_HI16: u32 = 16;
+/// 31 bit PC relative, for exception tables
+pub const R_MSP430_PREL31: u32 = 17;
+/// Exception handling type info
+pub const R_MSP430_EHTYPE: u32 = 18;
+/// 10 bit PC relative, for jumps
+pub const R_MSP430X_10_PCREL: u32 = 19;
+/// 10 bit PC relative, for a relaxed pair of jumps
+pub const R_MSP430X_2X_PCREL: u32 = 20;
+/// Marks the subtrahend of a difference between two symbols
+pub const R_MSP430X_SYM_DIFF: u32 = 21;
+/// Sets a ULEB128 value, paired with R_MSP430X_GNU_SUB_ULEB128
+pub const R_MSP430X_GNU_SET_ULEB128: u32 = 22;
+/// Subtracts from a ULEB128 value
+pub const R_MSP430X_GNU_SUB_ULEB128: u32 = 23;
+
 //  ____                        ____   ____
 // |  _ \ _____      _____ _ __|  _ \ / ___|
 // | |_) / _ \ \ /\ / / _ \ '__| |_) | |
@@ -2316,7 +3396,7 @@ pub const R_PPC64_DTPMOD64: u32 = 68;
 /// `@tprel`
 pub const R_PPC64_TPREL16: u32 = 69;
 /// `#lo(@tprel)`
-pub const R_PPC64_TPREL16_LO: u32 = 60;
+pub const R_PPC64_TPREL16_LO: u32 = 70;
 /// `#hi(@tprel)`
 pub const R_PPC64_TPREL16_HI: u32 = 71;
 /// `#ha(@tprel)`
@@ -2440,6 +3520,26 @@ pub const SHT_RISCV_ATTRIBUTES_SECTION_NAME: &str = ".riscv.attributes";
 
 pub const PT_RISCV_ATTRIBUTES: u32 = 0x70000003;
 
+// RISC-V build attribute tags, found in the "riscv" subsection of SHT_RISCV_ATTRIBUTES sections.
+// Odd tags have string values and even tags have integer values.
+// (see: https://github.com/riscv-non-isa/riscv-elf-psabi-doc/blob/master/riscv-elf.adoc#attributes)
+/// The stack alignment in bytes which the object requires
+pub const TAG_RISCV_STACK_ALIGN: u64 = 4;
+/// The target ISA, e.g. "rv64i2p1_m2p0_a2p1_c2p0_zicsr2p0" (string)
+pub const TAG_RISCV_ARCH: u64 = 5;
+/// Whether the object may make unaligned memory accesses (0: no, 1: yes)
+pub const TAG_RISCV_UNALIGNED_ACCESS: u64 = 6;
+/// The major version of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC: u64 = 8;
+/// The minor version of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC_MINOR: u64 = 10;
+/// The revision version of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC_REVISION: u64 = 12;
+/// The atomics ABI, i.e. the mapping of C11/C++11 atomics to instructions
+pub const TAG_RISCV_ATOMIC_ABI: u64 = 14;
+/// How the x3 (gp) register is used
+pub const TAG_RISCV_X3_REG_USAGE: u64 = 16;
+
 /// Any functions that use registers in a way that is incompatible with the
 /// calling convention of the ABI in use must be annotated with STO_RISCV_VARIANT_CC
 pub const STO_RISCV_VARIANT_CC: u8 = 0x80;
@@ -2484,6 +3584,8 @@ pub const R_RISCV_TLS_DTPREL64: u32 = 9;
 pub const R_RISCV_TLS_TPREL32: u32 = 10;
 /// `S + A + TLSOFFSET`
 pub const R_RISCV_TLS_TPREL64: u32 = 11;
+/// Dynamic TLS descriptor for the symbol
+pub const R_RISCV_TLSDESC: u32 = 12;
 /// 12-bit PC-relative branch offset `S + A - P`
 pub const R_RISCV_BRANCH: u32 = 16;
 /// 20-bit PC-relative jump offset `S + A - P`
@@ -2534,6 +3636,10 @@ pub const R_RISCV_SUB16: u32 = 38;
 pub const R_RISCV_SUB32: u32 = 39;
 /// 64-bit label subtraction: `V - S - A`
 pub const R_RISCV_SUB64: u32 = 40;
+/// GNU C++ vtable hierarchy
+pub const R_RISCV_GNU_VTINHERIT: u32 = 41;
+/// GNU C++ vtable member usage
+pub const R_RISCV_GNU_VTENTRY: u32 = 42;
 /// Alignment statement. The addend indicates the number of bytes occupied by
 /// nop instructions at the relocation offset. The alignment boundary is
 /// specified by the addend rounded up to the next power of two.
@@ -2560,6 +3666,171 @@ pub const R_RISCV_SET32: u32 = 56;
 pub const R_RISCV_32_PCREL: u32 = 57;
 /// Relocation against a non-preemptible ifunc symbolifunc_resolver: `(B + A)`
 pub const R_RISCV_IRELATIVE: u32 = 58;
+/// 32-bit relative offset to a function or its PLT entry: `S + A - P`
+pub const R_RISCV_PLT32: u32 = 59;
+/// Must be placed immediately before R_RISCV_SUB_ULEB128 with the same offset.
+/// Local label assignment: `S + A`
+pub const R_RISCV_SET_ULEB128: u32 = 60;
+/// Must be placed immediately after R_RISCV_SET_ULEB128 with the same offset.
+/// Local label subtraction: `V - S - A`
+pub const R_RISCV_SUB_ULEB128: u32 = 61;
+/// High 20 bits of a 32-bit PC-relative offset into a TLS descriptor entry,
+/// `%tlsdesc_hi(symbol)`
+pub const R_RISCV_TLSDESC_HI20: u32 = 62;
+/// Low 12 bits of a 32-bit PC-relative offset into a TLS descriptor entry,
+/// `%tlsdesc_load_lo(address of %tlsdesc_hi)`
+pub const R_RISCV_TLSDESC_LOAD_LO12: u32 = 63;
+/// Low 12 bits of a 32-bit PC-relative offset into a TLS descriptor entry,
+/// `%tlsdesc_add_lo(address of %tlsdesc_hi)`
+pub const R_RISCV_TLSDESC_ADD_LO12: u32 = 64;
+/// Annotates the TLS descriptor resolver call, `%tlsdesc_call(address of %tlsdesc_hi)`
+pub const R_RISCV_TLSDESC_CALL: u32 = 65;
+
+//  ____ _____  ___   ___
+// / ___|___ / / _ \ / _ \__  __
+// \___ \ |_ \| (_) | | | \ \/ /
+//  ___) |__) |\__, | |_| |>  <
+// |____/____/   /_/ \___//_/\_\
+//
+// See: https://github.com/IBM/s390x-abi
+
+/// High GPRs kernel facility needed
+pub const EF_S390_HIGH_GPRS: u32 = 0x00000001;
+
+// s390 and s390x relocation types
+
+/// No reloc
+pub const R_390_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_390_8: u32 = 1;
+/// Direct 12 bit
+pub const R_390_12: u32 = 2;
+/// Direct 16 bit
+pub const R_390_16: u32 = 3;
+/// Direct 32 bit
+pub const R_390_32: u32 = 4;
+/// PC relative 32 bit
+pub const R_390_PC32: u32 = 5;
+/// 12 bit GOT offset
+pub const R_390_GOT12: u32 = 6;
+/// 32 bit GOT offset
+pub const R_390_GOT32: u32 = 7;
+/// 32 bit PC relative PLT address
+pub const R_390_PLT32: u32 = 8;
+/// Copy symbol at runtime
+pub const R_390_COPY: u32 = 9;
+/// Create GOT entry
+pub const R_390_GLOB_DAT: u32 = 10;
+/// Create PLT entry
+pub const R_390_JMP_SLOT: u32 = 11;
+/// Adjust by program base
+pub const R_390_RELATIVE: u32 = 12;
+/// 32 bit offset to GOT
+pub const R_390_GOTOFF32: u32 = 13;
+/// 32 bit PC relative offset to GOT
+pub const R_390_GOTPC: u32 = 14;
+/// 16 bit GOT offset
+pub const R_390_GOT16: u32 = 15;
+/// PC relative 16 bit
+pub const R_390_PC16: u32 = 16;
+/// PC relative 16 bit shifted by 1
+pub const R_390_PC16DBL: u32 = 17;
+/// 16 bit PC rel. PLT shifted by 1
+pub const R_390_PLT16DBL: u32 = 18;
+/// PC relative 32 bit shifted by 1
+pub const R_390_PC32DBL: u32 = 19;
+/// 32 bit PC rel. PLT shifted by 1
+pub const R_390_PLT32DBL: u32 = 20;
+/// 32 bit PC rel. GOT shifted by 1
+pub const R_390_GOTPCDBL: u32 = 21;
+/// Direct 64 bit
+pub const R_390_64: u32 = 22;
+/// PC relative 64 bit
+pub const R_390_PC64: u32 = 23;
+/// 64 bit GOT offset
+pub const R_390_GOT64: u32 = 24;
+/// 64 bit PC relative PLT address
+pub const R_390_PLT64: u32 = 25;
+/// 32 bit PC rel. to GOT entry >> 1
+pub const R_390_GOTENT: u32 = 26;
+/// 16 bit offset to GOT
+pub const R_390_GOTOFF16: u32 = 27;
+/// 64 bit offset to GOT
+pub const R_390_GOTOFF64: u32 = 28;
+/// 12 bit offset to jump slot
+pub const R_390_GOTPLT12: u32 = 29;
+/// 16 bit offset to jump slot
+pub const R_390_GOTPLT16: u32 = 30;
+/// 32 bit offset to jump slot
+pub const R_390_GOTPLT32: u32 = 31;
+/// 64 bit offset to jump slot
+pub const R_390_GOTPLT64: u32 = 32;
+/// 32 bit rel. offset to jump slot
+pub const R_390_GOTPLTENT: u32 = 33;
+/// 16 bit offset from GOT to PLT
+pub const R_390_PLTOFF16: u32 = 34;
+/// 32 bit offset from GOT to PLT
+pub const R_390_PLTOFF32: u32 = 35;
+/// 64 bit offset from GOT to PLT
+pub const R_390_PLTOFF64: u32 = 36;
+/// Tag for load insn in TLS code
+pub const R_390_TLS_LOAD: u32 = 37;
+/// Tag for function call in general dynamic TLS code
+pub const R_390_TLS_GDCALL: u32 = 38;
+/// Tag for function call in local dynamic TLS code
+pub const R_390_TLS_LDCALL: u32 = 39;
+/// Direct 32 bit for general dynamic thread local data
+pub const R_390_TLS_GD32: u32 = 40;
+/// Direct 64 bit for general dynamic thread local data
+pub const R_390_TLS_GD64: u32 = 41;
+/// 12 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE12: u32 = 42;
+/// 32 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE32: u32 = 43;
+/// 64 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE64: u32 = 44;
+/// Direct 32 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM32: u32 = 45;
+/// Direct 64 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM64: u32 = 46;
+/// 32 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE32: u32 = 47;
+/// 64 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE64: u32 = 48;
+/// 32 bit rel. offset to GOT entry for negated static TLS block offset
+pub const R_390_TLS_IEENT: u32 = 49;
+/// 32 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE32: u32 = 50;
+/// 64 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE64: u32 = 51;
+/// 32 bit offset relative to TLS block
+pub const R_390_TLS_LDO32: u32 = 52;
+/// 64 bit offset relative to TLS block
+pub const R_390_TLS_LDO64: u32 = 53;
+/// ID of module containing symbol
+pub const R_390_TLS_DTPMOD: u32 = 54;
+/// Offset in TLS block
+pub const R_390_TLS_DTPOFF: u32 = 55;
+/// Negated offset in static TLS block
+pub const R_390_TLS_TPOFF: u32 = 56;
+/// Direct 20 bit
+pub const R_390_20: u32 = 57;
+/// 20 bit GOT offset
+pub const R_390_GOT20: u32 = 58;
+/// 20 bit offset to jump slot
+pub const R_390_GOTPLT20: u32 = 59;
+/// 20 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE20: u32 = 60;
+/// STT_GNU_IFUNC relocation
+pub const R_390_IRELATIVE: u32 = 61;
+/// PC relative 12 bit shifted by 1
+pub const R_390_PC12DBL: u32 = 62;
+/// 12 bit PC rel. PLT shifted by 1
+pub const R_390_PLT12DBL: u32 = 63;
+/// PC relative 24 bit shifted by 1
+pub const R_390_PC24DBL: u32 = 64;
+/// 24 bit PC rel. PLT shifted by 1
+pub const R_390_PLT24DBL: u32 = 65;
 
 //       ___   __      __   _  _
 // __  _( _ ) / /_    / /_ | || |
@@ -2577,6 +3848,42 @@ pub const SHF_X86_64_LARGE: u64 = 0x10000000;
 /// This section contains unwind function table entries for stack unwinding.
 pub const SHT_X86_64_UNWIND: u32 = 0x70000001; // SHT_LOPROC + 1;
 
+// x86 GNU property types, found in NT_GNU_PROPERTY_TYPE_0 notes
+/// The x86 features used by the object, a bitmask of GNU_PROPERTY_X86_FEATURE_1_*
+/// which are only set if every input object supports them.
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+/// The x86 ISA levels needed to run the object, a bitmask of GNU_PROPERTY_X86_ISA_1_*
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+/// The x86 ISA levels used by the object, a bitmask of GNU_PROPERTY_X86_ISA_1_*
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+/// The x86 processor features needed to run the object, a bitmask of GNU_PROPERTY_X86_FEATURE_2_*
+pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = 0xc0008001;
+/// The x86 processor features used by the object, a bitmask of GNU_PROPERTY_X86_FEATURE_2_*
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xc0010001;
+
+/// Compatible with Intel CET Indirect Branch Tracking
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x1;
+/// Compatible with Intel CET Shadow Stack
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x2;
+
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 0x1;
+pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 0x2;
+pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 0x4;
+pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 0x8;
+
+pub const GNU_PROPERTY_X86_FEATURE_2_X86: u32 = 0x1;
+pub const GNU_PROPERTY_X86_FEATURE_2_X87: u32 = 0x2;
+pub const GNU_PROPERTY_X86_FEATURE_2_MMX: u32 = 0x4;
+pub const GNU_PROPERTY_X86_FEATURE_2_XMM: u32 = 0x8;
+pub const GNU_PROPERTY_X86_FEATURE_2_YMM: u32 = 0x10;
+pub const GNU_PROPERTY_X86_FEATURE_2_ZMM: u32 = 0x20;
+pub const GNU_PROPERTY_X86_FEATURE_2_FXSR: u32 = 0x40;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVE: u32 = 0x80;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT: u32 = 0x100;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEC: u32 = 0x200;
+pub const GNU_PROPERTY_X86_FEATURE_2_TMM: u32 = 0x400;
+pub const GNU_PROPERTY_X86_FEATURE_2_MASK: u32 = 0x800;
+
 // x86_64 reloc types
 //
 // A Represents the addend used to compute the value of the relocatable field.
@@ -2641,6 +3948,8 @@ pub const R_X86_64_GOT64: u32 = 27;
 pub const R_X86_64_GOTPCREL64: u32 = 28;
 /// `GOT - P + A`
 pub const R_X86_64_GOTPC64: u32 = 29;
+/// `G + A`
+pub const R_X86_64_GOTPLT64: u32 = 30;
 /// `L - GOT + A`
 pub const R_X86_64_PLTOFF64: u32 = 31;
 /// `Z + A`
@@ -2658,3 +3967,120 @@ pub const R_X86_64_RELATIVE64: u32 = 38;
 pub const R_X86_64_GOTPCRELX: u32 = 41;
 /// `G + GOT + A - P`
 pub const R_X86_64_REX_GOTPCRELX: u32 = 42;
+/// `G + GOT + A - P`
+pub const R_X86_64_CODE_4_GOTPCRELX: u32 = 43;
+pub const R_X86_64_CODE_4_GOTTPOFF: u32 = 44;
+pub const R_X86_64_CODE_4_GOTPC32_TLSDESC: u32 = 45;
+
+// __  ___
+// \ \/ / |_ ___ _ __  ___  __ _
+//  \  /| __/ _ \ '_ \/ __|/ _` |
+//  /  \| ||  __/ | | \__ \ (_| |
+// /_/\_\\__\___|_| |_|___/\__,_|
+//
+// See: https://sourceware.org/git/?p=binutils-gdb.git;a=blob;f=include/elf/xtensa.h
+
+/// Mask for the machine bits in e_flags
+pub const EF_XTENSA_MACH: u32 = 0x0000000f;
+/// The only machine value defined so far
+pub const E_XTENSA_MACH: u32 = 0x00000000;
+/// The object describes its instructions in `.xt.insn` property tables
+pub const EF_XTENSA_XT_INSN: u32 = 0x00000100;
+/// The object describes its literals in `.xt.lit` property tables
+pub const EF_XTENSA_XT_LIT: u32 = 0x00000200;
+
+// Xtensa relocation types
+/// No reloc
+pub const R_XTENSA_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_XTENSA_32: u32 = 1;
+/// Dynamic linker internal relocation
+pub const R_XTENSA_RTLD: u32 = 2;
+/// Create GOT entry
+pub const R_XTENSA_GLOB_DAT: u32 = 3;
+/// Create PLT entry
+pub const R_XTENSA_JMP_SLOT: u32 = 4;
+/// Adjust by program base
+pub const R_XTENSA_RELATIVE: u32 = 5;
+/// 32 bit address of the symbol's PLT entry
+pub const R_XTENSA_PLT: u32 = 6;
+/// Operand 0 of an instruction (deprecated, use R_XTENSA_SLOT0_OP)
+pub const R_XTENSA_OP0: u32 = 8;
+/// Operand 1 of an instruction (deprecated)
+pub const R_XTENSA_OP1: u32 = 9;
+/// Operand 2 of an instruction (deprecated)
+pub const R_XTENSA_OP2: u32 = 10;
+/// Marks an assembler-expanded instruction sequence which may be relaxed
+pub const R_XTENSA_ASM_EXPAND: u32 = 11;
+/// Marks an instruction which the linker should simplify
+pub const R_XTENSA_ASM_SIMPLIFY: u32 = 12;
+/// 32 bit PC relative
+pub const R_XTENSA_32_PCREL: u32 = 14;
+/// GNU C++ vtable hierarchy
+pub const R_XTENSA_GNU_VTINHERIT: u32 = 15;
+/// GNU C++ vtable member usage
+pub const R_XTENSA_GNU_VTENTRY: u32 = 16;
+/// 8 bit difference between two symbols, for linker relaxation
+pub const R_XTENSA_DIFF8: u32 = 17;
+/// 16 bit difference between two symbols, for linker relaxation
+pub const R_XTENSA_DIFF16: u32 = 18;
+/// 32 bit difference between two symbols, for linker relaxation
+pub const R_XTENSA_DIFF32: u32 = 19;
+/// PC relative operand of the instruction in slot 0 of a FLIX bundle
+pub const R_XTENSA_SLOT0_OP: u32 = 20;
+pub const R_XTENSA_SLOT1_OP: u32 = 21;
+pub const R_XTENSA_SLOT2_OP: u32 = 22;
+pub const R_XTENSA_SLOT3_OP: u32 = 23;
+pub const R_XTENSA_SLOT4_OP: u32 = 24;
+pub const R_XTENSA_SLOT5_OP: u32 = 25;
+pub const R_XTENSA_SLOT6_OP: u32 = 26;
+pub const R_XTENSA_SLOT7_OP: u32 = 27;
+pub const R_XTENSA_SLOT8_OP: u32 = 28;
+pub const R_XTENSA_SLOT9_OP: u32 = 29;
+pub const R_XTENSA_SLOT10_OP: u32 = 30;
+pub const R_XTENSA_SLOT11_OP: u32 = 31;
+pub const R_XTENSA_SLOT12_OP: u32 = 32;
+pub const R_XTENSA_SLOT13_OP: u32 = 33;
+pub const R_XTENSA_SLOT14_OP: u32 = 34;
+/// Alternate relocation for the instruction in slot 0 of a FLIX bundle
+pub const R_XTENSA_SLOT0_ALT: u32 = 35;
+pub const R_XTENSA_SLOT1_ALT: u32 = 36;
+pub const R_XTENSA_SLOT2_ALT: u32 = 37;
+pub const R_XTENSA_SLOT3_ALT: u32 = 38;
+pub const R_XTENSA_SLOT4_ALT: u32 = 39;
+pub const R_XTENSA_SLOT5_ALT: u32 = 40;
+pub const R_XTENSA_SLOT6_ALT: u32 = 41;
+pub const R_XTENSA_SLOT7_ALT: u32 = 42;
+pub const R_XTENSA_SLOT8_ALT: u32 = 43;
+pub const R_XTENSA_SLOT9_ALT: u32 = 44;
+pub const R_XTENSA_SLOT10_ALT: u32 = 45;
+pub const R_XTENSA_SLOT11_ALT: u32 = 46;
+pub const R_XTENSA_SLOT12_ALT: u32 = 47;
+pub const R_XTENSA_SLOT13_ALT: u32 = 48;
+pub const R_XTENSA_SLOT14_ALT: u32 = 49;
+/// TLS descriptor resolver function
+pub const R_XTENSA_TLSDESC_FN: u32 = 50;
+/// TLS descriptor argument
+pub const R_XTENSA_TLSDESC_ARG: u32 = 51;
+/// Offset in TLS block
+pub const R_XTENSA_TLS_DTPOFF: u32 = 52;
+/// Offset relative to the thread pointer
+pub const R_XTENSA_TLS_TPOFF: u32 = 53;
+/// Marks the load of a TLS descriptor's resolver function
+pub const R_XTENSA_TLS_FUNC: u32 = 54;
+/// Marks the load of a TLS descriptor's argument
+pub const R_XTENSA_TLS_ARG: u32 = 55;
+/// Marks the call to a TLS descriptor's resolver function
+pub const R_XTENSA_TLS_CALL: u32 = 56;
+/// 8 bit positive difference between two symbols
+pub const R_XTENSA_PDIFF8: u32 = 57;
+/// 16 bit positive difference between two symbols
+pub const R_XTENSA_PDIFF16: u32 = 58;
+/// 32 bit positive difference between two symbols
+pub const R_XTENSA_PDIFF32: u32 = 59;
+/// 8 bit negative difference between two symbols
+pub const R_XTENSA_NDIFF8: u32 = 60;
+/// 16 bit negative difference between two symbols
+pub const R_XTENSA_NDIFF16: u32 = 61;
+/// 32 bit negative difference between two symbols
+pub const R_XTENSA_NDIFF32: u32 = 62;
@@ -0,0 +1,133 @@
+//! Decoding 32-bit ARM specific header data: the
+//! [e_flags](crate::file::FileHeader#structfield.e_flags) of objects built for
+//! [EM_ARM](crate::abi::EM_ARM)
+//!
+//! The flags record which version of the ARM EABI the object conforms to, which floating point
+//! procedure-call standard an executable was built for, and whether it contains BE-8 code.
+//! Cortex-M and A32 firmware images are usually EABI version 5, where a hard-float object
+//! can't be linked or loaded alongside soft-float ones.
+//!
+//! See [FileHeader::arm_flags](crate::file::FileHeader::arm_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The floating point procedure-call standard selected by the
+/// [EF_ARM_ABI_FLOAT_SOFT](abi::EF_ARM_ABI_FLOAT_SOFT) and
+/// [EF_ARM_ABI_FLOAT_HARD](abi::EF_ARM_ABI_FLOAT_HARD) bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmFloatAbi {
+    /// Floating point arguments are passed in integer registers
+    Soft,
+    /// Floating point arguments are passed in VFP registers
+    Hard,
+}
+
+/// The decoded e_flags of a 32-bit ARM object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmFlags {
+    /// The version of the ARM EABI the object conforms to, from the
+    /// [EF_ARM_EABIMASK](abi::EF_ARM_EABIMASK) bits. Zero means unknown conformance, which
+    /// is what legacy (pre EABI) gcc toolchains produce.
+    pub eabi_version: u8,
+    /// The floating point procedure-call standard the object was built for, if it says.
+    ///
+    /// This is only decoded for EABI version 5 and later, as legacy toolchains used the same
+    /// bits for other purposes.
+    pub float_abi: Option<ArmFloatAbi>,
+    /// The object contains BE-8 code for an Arm v6 or later processor
+    /// ([EF_ARM_BE8](abi::EF_ARM_BE8))
+    pub be8: bool,
+}
+
+impl ArmFlags {
+    /// Decode the given e_flags value. Bits which aren't defined by the EABI are ignored.
+    pub fn from_e_flags(e_flags: u32) -> Self {
+        let eabi_version = ((e_flags & abi::EF_ARM_EABIMASK) >> 24) as u8;
+        let float_abi = if eabi_version < 5 {
+            None
+        } else if e_flags & abi::EF_ARM_ABI_FLOAT_HARD != 0 {
+            Some(ArmFloatAbi::Hard)
+        } else if e_flags & abi::EF_ARM_ABI_FLOAT_SOFT != 0 {
+            Some(ArmFloatAbi::Soft)
+        } else {
+            None
+        };
+        ArmFlags {
+            eabi_version,
+            float_abi,
+            be8: e_flags & abi::EF_ARM_BE8 != 0,
+        }
+    }
+}
+
+impl EFlagsDecoder for ArmFlags {
+    const E_MACHINE: u16 = abi::EM_ARM;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        ArmFlags::from_e_flags(ehdr.e_flags)
+    }
+}
+
+/// Formats the flags like readelf, e.g. "Version5 EABI, hard-float ABI"
+impl core::fmt::Display for ArmFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.eabi_version {
+            0 => write!(f, "GNU EABI")?,
+            version => write!(f, "Version{version} EABI")?,
+        }
+        match self.float_abi {
+            Some(ArmFloatAbi::Soft) => write!(f, ", soft-float ABI")?,
+            Some(ArmFloatAbi::Hard) => write!(f, ", hard-float ABI")?,
+            None => {}
+        }
+        if self.be8 {
+            write!(f, ", BE8")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            ArmFlags::from_e_flags(abi::EF_ARM_EABI_VER5 | abi::EF_ARM_ABI_FLOAT_HARD),
+            ArmFlags {
+                eabi_version: 5,
+                float_abi: Some(ArmFloatAbi::Hard),
+                be8: false,
+            }
+        );
+        assert_eq!(
+            ArmFlags::from_e_flags(
+                abi::EF_ARM_EABI_VER5 | abi::EF_ARM_ABI_FLOAT_SOFT | abi::EF_ARM_BE8
+            ),
+            ArmFlags {
+                eabi_version: 5,
+                float_abi: Some(ArmFloatAbi::Soft),
+                be8: true,
+            }
+        );
+        // Legacy toolchains used the float bits for other purposes
+        assert_eq!(
+            ArmFlags::from_e_flags(abi::EF_ARM_EABI_VER4 | abi::EF_ARM_SOFT_FLOAT),
+            ArmFlags {
+                eabi_version: 4,
+                float_abi: None,
+                be8: false,
+            }
+        );
+    }
+
+    #[test]
+    fn display_matches_readelf() {
+        let flags = ArmFlags::from_e_flags(0x5000400);
+        assert_eq!(flags.to_string(), "Version5 EABI, hard-float ABI");
+        let flags = ArmFlags::from_e_flags(abi::EF_ARM_EABI_VER4 | abi::EF_ARM_BE8);
+        assert_eq!(flags.to_string(), "Version4 EABI, BE8");
+    }
+}
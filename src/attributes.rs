@@ -0,0 +1,832 @@
+//! Parsing build attributes sections: [SHT_ARM_ATTRIBUTES](crate::abi::SHT_ARM_ATTRIBUTES),
+//! [SHT_RISCV_ATTRIBUTES](crate::abi::SHT_RISCV_ATTRIBUTES), [SHT_GNU_ATTRIBUTES](crate::abi::SHT_GNU_ATTRIBUTES)
+//!
+//! Build attributes record what an object was built for, e.g. the target CPU architecture,
+//! FPU, and calling convention variant, so that linkers and loaders can check that objects are
+//! compatible with each other and with the system they're run on.
+//!
+//! The section starts with a format-version byte ([ATTR_FORMAT_VERSION_A](abi::ATTR_FORMAT_VERSION_A))
+//! followed by a subsection for each vendor (e.g. "aeabi" for the ARM EABI attributes). Each
+//! subsection contains one or more [AttributesScope]s, which say whether their attributes apply
+//! to the whole file or just some of its sections or symbols. Each attribute is a ULEB128 tag
+//! followed by either a ULEB128 integer or a NUL-terminated string value.
+//!
+//! (see: <https://github.com/ARM-software/abi-aa/blob/main/addenda32/addenda32.rst>)
+//!
+//! See [RiscvAttributes] for RISC-V objects' attributes.
+//!
+//! Example for finding an ARM object's target architecture:
+//! ```
+//! use elf::abi;
+//! use elf::attributes::AttributeValue;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let attrs = file
+//!     .build_attributes()
+//!     .expect("section table should be parseable")
+//!     .expect("file should have a .ARM.attributes section");
+//! let cpu_arch = attrs
+//!     .file_attribute(b"aeabi", abi::TAG_CPU_ARCH)
+//!     .expect("attributes should parse");
+//! assert_eq!(cpu_arch, Some(AttributeValue::Integer(abi::TAG_CPU_ARCH_V7)));
+//! ```
+use crate::abi;
+use crate::ehframe::parse_uleb128_at;
+use crate::endian::EndianParse;
+use crate::parse::{ParseError, ReadBytesExt};
+use core::str::from_utf8;
+
+/// The contents of a build attributes section.
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesSection<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributesSection<'data, E> {
+    /// Interpret the given section data as a build attributes section.
+    ///
+    /// Returns [ParseError::UnsupportedVersion] if the section doesn't start with the
+    /// [ATTR_FORMAT_VERSION_A](abi::ATTR_FORMAT_VERSION_A) format-version byte.
+    pub fn new(endian: E, data: &'data [u8]) -> Result<Self, ParseError> {
+        let version = endian.parse_u8_at(&mut 0, data)?;
+        if version != abi::ATTR_FORMAT_VERSION_A {
+            return Err(ParseError::UnsupportedVersion((
+                version as u64,
+                abi::ATTR_FORMAT_VERSION_A as u64,
+            )));
+        }
+        Ok(AttributesSection {
+            endian,
+            data: data.get_bytes(1..data.len())?,
+        })
+    }
+
+    /// Iterate over the section's vendor subsections
+    pub fn subsections(&self) -> AttributesSubsectionIterator<'data, E> {
+        AttributesSubsectionIterator {
+            endian: self.endian,
+            data: self.data,
+            offset: 0,
+        }
+    }
+
+    /// Find the value of the given file-scope attribute in the given vendor's subsection,
+    /// e.g. [TAG_CPU_ARCH](abi::TAG_CPU_ARCH) in the `b"aeabi"` subsection.
+    pub fn file_attribute(
+        &self,
+        vendor: &[u8],
+        tag: u64,
+    ) -> Result<Option<AttributeValue<'data>>, ParseError> {
+        for subsection in self.subsections() {
+            let subsection = subsection?;
+            if subsection.vendor != vendor {
+                continue;
+            }
+            for scope in subsection.scopes() {
+                let scope = scope?;
+                if scope.tag != abi::TAG_FILE {
+                    continue;
+                }
+                for attr in scope.attributes() {
+                    let attr = attr?;
+                    if attr.tag == tag {
+                        return Ok(Some(attr.value));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Iterator over the vendor subsections in an [AttributesSection]
+#[derive(Debug)]
+pub struct AttributesSubsectionIterator<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for AttributesSubsectionIterator<'data, E> {
+    type Item = Result<AttributesSubsection<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = parse_length_prefixed(self.endian, &mut self.offset, self.data).and_then(
+            |(start, data)| {
+                let vendor_len = data
+                    .iter()
+                    .position(|&b| b == 0)
+                    .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+                Ok(AttributesSubsection {
+                    endian: self.endian,
+                    vendor: &data[..vendor_len],
+                    data: &data[vendor_len + 1..],
+                })
+            },
+        );
+        if result.is_err() {
+            // Don't keep trying to parse subsections after a bad length
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// The attributes defined by a single vendor, e.g. "aeabi" for the ARM EABI attributes
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesSubsection<'data, E: EndianParse> {
+    endian: E,
+    /// The name of the vendor which defines the meaning of these attributes
+    pub vendor: &'data [u8],
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributesSubsection<'data, E> {
+    /// Parses the vendor name bytes as a utf8 sequence
+    pub fn vendor_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.vendor)?)
+    }
+
+    /// Iterate over the subsection's groups of file, section, or symbol attributes
+    pub fn scopes(&self) -> AttributesScopeIterator<'data, E> {
+        AttributesScopeIterator {
+            endian: self.endian,
+            vendor: self.vendor,
+            data: self.data,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over the [AttributesScope]s in an [AttributesSubsection]
+#[derive(Debug)]
+pub struct AttributesScopeIterator<'data, E: EndianParse> {
+    endian: E,
+    vendor: &'data [u8],
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for AttributesScopeIterator<'data, E> {
+    type Item = Result<AttributesScope<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = self.parse_scope();
+        if result.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+impl<'data, E: EndianParse> AttributesScopeIterator<'data, E> {
+    fn parse_scope(&mut self) -> Result<AttributesScope<'data>, ParseError> {
+        let start = self.offset;
+        let mut offset = start;
+        let tag = parse_uleb128_at(&mut offset, self.data)?;
+        let size = self.endian.parse_u32_at(&mut offset, self.data)? as usize;
+        // The size includes the tag and size fields
+        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+        if end < offset {
+            return Err(ParseError::SliceReadError((start, offset)));
+        }
+        let data = self.data.get_bytes(offset..end)?;
+        self.offset = end;
+
+        // Section and symbol scopes start with a zero-terminated list of ULEB128 indexes
+        let mut attrs_start = 0;
+        if tag == abi::TAG_SECTION || tag == abi::TAG_SYMBOL {
+            while parse_uleb128_at(&mut attrs_start, data)? != 0 {}
+        }
+
+        Ok(AttributesScope {
+            tag,
+            vendor: self.vendor,
+            indexes: &data[..attrs_start],
+            data: &data[attrs_start..],
+        })
+    }
+}
+
+/// A group of attributes which apply to the whole file ([TAG_FILE](abi::TAG_FILE)), or to
+/// just the listed sections ([TAG_SECTION](abi::TAG_SECTION)) or symbols ([TAG_SYMBOL](abi::TAG_SYMBOL)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributesScope<'data> {
+    /// The scope's tag: [TAG_FILE](abi::TAG_FILE), [TAG_SECTION](abi::TAG_SECTION), or [TAG_SYMBOL](abi::TAG_SYMBOL)
+    pub tag: u64,
+    vendor: &'data [u8],
+    indexes: &'data [u8],
+    data: &'data [u8],
+}
+
+impl<'data> AttributesScope<'data> {
+    /// Iterate over the section or symbol indexes that the attributes apply to.
+    /// This is empty for file scopes.
+    pub fn indexes(&self) -> AttributesIndexIterator<'data> {
+        AttributesIndexIterator {
+            data: self.indexes,
+            offset: 0,
+        }
+    }
+
+    /// Iterate over the attributes in this scope
+    pub fn attributes(&self) -> AttributeIterator<'data> {
+        AttributeIterator {
+            vendor: self.vendor,
+            data: self.data,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over the section or symbol indexes of an [AttributesScope]
+#[derive(Debug)]
+pub struct AttributesIndexIterator<'data> {
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data> Iterator for AttributesIndexIterator<'data> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The list was already validated when parsing the scope, and ends with a 0
+        match parse_uleb128_at(&mut self.offset, self.data) {
+            Ok(0) | Err(_) => None,
+            Ok(idx) => Some(idx),
+        }
+    }
+}
+
+/// A single build attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attribute<'data> {
+    /// The attribute's tag, e.g. [TAG_CPU_ARCH](abi::TAG_CPU_ARCH). Its meaning depends on the vendor.
+    pub tag: u64,
+    pub value: AttributeValue<'data>,
+}
+
+/// The value of a build attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeValue<'data> {
+    Integer(u64),
+    /// A string value, without its NUL terminator
+    String(&'data [u8]),
+    /// An integer followed by a string, as used by [TAG_COMPATIBILITY](abi::TAG_COMPATIBILITY)
+    IntegerAndString(u64, &'data [u8]),
+}
+
+#[derive(Clone, Copy)]
+enum ValueKind {
+    Integer,
+    String,
+    IntegerAndString,
+}
+
+/// Which kind of value the given vendor's attribute tag has
+fn value_kind(vendor: &[u8], tag: u64) -> ValueKind {
+    if vendor == b"aeabi" {
+        match tag {
+            abi::TAG_CPU_RAW_NAME | abi::TAG_CPU_NAME => return ValueKind::String,
+            abi::TAG_COMPATIBILITY => return ValueKind::IntegerAndString,
+            tag if tag < 32 => return ValueKind::Integer,
+            _ => {}
+        }
+    }
+
+    // Otherwise, odd tags have string values and even tags have integer values
+    if tag % 2 == 1 {
+        ValueKind::String
+    } else {
+        ValueKind::Integer
+    }
+}
+
+/// Iterator over the [Attribute]s in an [AttributesScope]
+#[derive(Debug)]
+pub struct AttributeIterator<'data> {
+    vendor: &'data [u8],
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data> AttributeIterator<'data> {
+    fn parse_string(&mut self) -> Result<&'data [u8], ParseError> {
+        let start = self.offset;
+        let len = self
+            .data
+            .get(start..)
+            .and_then(|rest| rest.iter().position(|&b| b == 0))
+            .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+        self.offset = start + len + 1;
+        Ok(&self.data[start..start + len])
+    }
+
+    fn parse_attribute(&mut self) -> Result<Attribute<'data>, ParseError> {
+        let tag = parse_uleb128_at(&mut self.offset, self.data)?;
+        let value = match value_kind(self.vendor, tag) {
+            ValueKind::Integer => {
+                AttributeValue::Integer(parse_uleb128_at(&mut self.offset, self.data)?)
+            }
+            ValueKind::String => AttributeValue::String(self.parse_string()?),
+            ValueKind::IntegerAndString => {
+                let flag = parse_uleb128_at(&mut self.offset, self.data)?;
+                AttributeValue::IntegerAndString(flag, self.parse_string()?)
+            }
+        };
+        Ok(Attribute { tag, value })
+    }
+}
+
+impl<'data> Iterator for AttributeIterator<'data> {
+    type Item = Result<Attribute<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = self.parse_attribute();
+        if result.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// The file-scope attributes from the "riscv" subsection of a RISC-V object's
+/// [SHT_RISCV_ATTRIBUTES](abi::SHT_RISCV_ATTRIBUTES) section.
+///
+/// ```
+/// use elf::attributes::RiscvAttributes;
+/// use elf::endian::AnyEndian;
+/// use elf::ElfBytes;
+///
+/// let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+/// let file_data = std::fs::read(path).expect("Could not read file.");
+/// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+///
+/// let attrs = file
+///     .build_attributes()
+///     .expect("section table should be parseable")
+///     .expect("file should have a .riscv.attributes section");
+/// let riscv = RiscvAttributes::parse(&attrs).expect("attributes should parse");
+/// let arch = riscv.arch().expect("should have an ISA string");
+/// assert_eq!(arch.xlen, 64);
+/// assert!(arch.has_extension("d"));
+/// assert!(!arch.has_extension("v"));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RiscvAttributes<'data> {
+    /// [TAG_RISCV_STACK_ALIGN](abi::TAG_RISCV_STACK_ALIGN)
+    pub stack_align: Option<u64>,
+    /// [TAG_RISCV_ARCH](abi::TAG_RISCV_ARCH): the raw ISA string
+    pub arch: Option<&'data [u8]>,
+    /// [TAG_RISCV_UNALIGNED_ACCESS](abi::TAG_RISCV_UNALIGNED_ACCESS)
+    pub unaligned_access: Option<bool>,
+    /// [TAG_RISCV_PRIV_SPEC], [TAG_RISCV_PRIV_SPEC_MINOR], and [TAG_RISCV_PRIV_SPEC_REVISION]
+    /// as (major, minor, revision), if any of them were present
+    ///
+    /// [TAG_RISCV_PRIV_SPEC]: abi::TAG_RISCV_PRIV_SPEC
+    /// [TAG_RISCV_PRIV_SPEC_MINOR]: abi::TAG_RISCV_PRIV_SPEC_MINOR
+    /// [TAG_RISCV_PRIV_SPEC_REVISION]: abi::TAG_RISCV_PRIV_SPEC_REVISION
+    pub priv_spec: Option<(u64, u64, u64)>,
+    /// [TAG_RISCV_ATOMIC_ABI](abi::TAG_RISCV_ATOMIC_ABI)
+    pub atomic_abi: Option<u64>,
+    /// [TAG_RISCV_X3_REG_USAGE](abi::TAG_RISCV_X3_REG_USAGE)
+    pub x3_reg_usage: Option<u64>,
+}
+
+impl<'data> RiscvAttributes<'data> {
+    /// Collect the file-scope attributes from the section's "riscv" subsection.
+    /// Unknown tags are ignored.
+    pub fn parse<E: EndianParse>(
+        section: &AttributesSection<'data, E>,
+    ) -> Result<Self, ParseError> {
+        let mut result = RiscvAttributes::default();
+        for subsection in section.subsections() {
+            let subsection = subsection?;
+            if subsection.vendor != b"riscv" {
+                continue;
+            }
+            for scope in subsection.scopes() {
+                let scope = scope?;
+                if scope.tag != abi::TAG_FILE {
+                    continue;
+                }
+                for attr in scope.attributes() {
+                    result.set(attr?);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn set(&mut self, attr: Attribute<'data>) {
+        match (attr.tag, attr.value) {
+            (abi::TAG_RISCV_STACK_ALIGN, AttributeValue::Integer(val)) => {
+                self.stack_align = Some(val)
+            }
+            (abi::TAG_RISCV_ARCH, AttributeValue::String(arch)) => self.arch = Some(arch),
+            (abi::TAG_RISCV_UNALIGNED_ACCESS, AttributeValue::Integer(val)) => {
+                self.unaligned_access = Some(val != 0)
+            }
+            (abi::TAG_RISCV_PRIV_SPEC, AttributeValue::Integer(val)) => {
+                self.priv_spec.get_or_insert((0, 0, 0)).0 = val
+            }
+            (abi::TAG_RISCV_PRIV_SPEC_MINOR, AttributeValue::Integer(val)) => {
+                self.priv_spec.get_or_insert((0, 0, 0)).1 = val
+            }
+            (abi::TAG_RISCV_PRIV_SPEC_REVISION, AttributeValue::Integer(val)) => {
+                self.priv_spec.get_or_insert((0, 0, 0)).2 = val
+            }
+            (abi::TAG_RISCV_ATOMIC_ABI, AttributeValue::Integer(val)) => {
+                self.atomic_abi = Some(val)
+            }
+            (abi::TAG_RISCV_X3_REG_USAGE, AttributeValue::Integer(val)) => {
+                self.x3_reg_usage = Some(val)
+            }
+            _ => {}
+        }
+    }
+
+    /// Parse the ISA string, returning None if there isn't one or it isn't a valid ISA string.
+    pub fn arch(&self) -> Option<RiscvArch<'data>> {
+        RiscvArch::parse(from_utf8(self.arch?).ok()?)
+    }
+}
+
+/// A parsed RISC-V ISA string, such as "rv64i2p1_m2p0_zicsr2p0" or "rv32imac".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RiscvArch<'data> {
+    /// The base integer register width: 32, 64, or 128
+    pub xlen: u32,
+    extensions: &'data str,
+}
+
+impl<'data> RiscvArch<'data> {
+    /// Parse an ISA string, returning None if it doesn't start with "rv32", "rv64", or "rv128",
+    /// or if it contains non-ASCII characters.
+    pub fn parse(arch: &'data str) -> Option<Self> {
+        if !arch.is_ascii() {
+            return None;
+        }
+        let rest = arch.strip_prefix("rv")?;
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let xlen = match &rest[..digits] {
+            "32" => 32,
+            "64" => 64,
+            "128" => 128,
+            _ => return None,
+        };
+        Some(RiscvArch {
+            xlen,
+            extensions: &rest[digits..],
+        })
+    }
+
+    /// Iterate over the ISA's extensions, including the base ISA ("i" or "e")
+    pub fn extensions(&self) -> RiscvExtensionIterator<'data> {
+        RiscvExtensionIterator {
+            rest: self.extensions,
+        }
+    }
+
+    /// Returns true if the ISA includes the given extension, e.g. "m" or "zicsr"
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions().any(|ext| ext.name == name)
+    }
+}
+
+/// An extension from a [RiscvArch]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RiscvExtension<'data> {
+    /// The extension's name, e.g. "m" or "zicsr"
+    pub name: &'data str,
+    /// The extension's (major, minor) version, if given
+    pub version: Option<(u32, u32)>,
+}
+
+/// Iterator over the extensions in a [RiscvArch]
+#[derive(Debug)]
+pub struct RiscvExtensionIterator<'data> {
+    rest: &'data str,
+}
+
+impl<'data> Iterator for RiscvExtensionIterator<'data> {
+    type Item = RiscvExtension<'data>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.trim_start_matches('_');
+        let first = rest.bytes().next()?;
+
+        // Multi-letter extensions (e.g. zicsr2p0) run to the next '_', and single-letter
+        // extensions (e.g. m2p0) can be run together without '_' separators.
+        let (token, remaining) = if matches!(first, b'z' | b's' | b'x') {
+            rest.split_at(rest.find('_').unwrap_or(rest.len()))
+        } else {
+            let len = 1 + version_len(&rest[1..]);
+            rest.split_at(len)
+        };
+        self.rest = remaining;
+
+        // The version is a trailing <major>[p<minor>]
+        let name_len = token.len() - trailing_version_len(token);
+        let (name, version) = token.split_at(name_len);
+        Some(RiscvExtension {
+            name,
+            version: parse_version(version),
+        })
+    }
+}
+
+/// Length of the <major>[p<minor>] version at the start of s
+fn version_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let major = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    if major == 0 {
+        return 0;
+    }
+    match bytes.get(major) {
+        Some(b'p') if bytes.get(major + 1).map_or(false, u8::is_ascii_digit) => {
+            major
+                + 1
+                + bytes[major + 1..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit())
+                    .count()
+        }
+        _ => major,
+    }
+}
+
+/// Length of the <major>[p<minor>] version at the end of a multi-letter extension token
+fn trailing_version_len(token: &str) -> usize {
+    let bytes = token.as_bytes();
+    let digits = |end: usize| {
+        bytes[..end]
+            .iter()
+            .rev()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let minor = digits(bytes.len());
+    if minor == 0 {
+        return 0;
+    }
+    let p = bytes.len() - minor;
+    if p >= 2 && bytes[p - 1] == b'p' {
+        let major = digits(p - 1);
+        // Leave at least one character for the extension's name
+        if major > 0 && major < p - 1 {
+            return minor + 1 + major;
+        }
+    }
+    if minor < bytes.len() {
+        minor
+    } else {
+        0
+    }
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    if version.is_empty() {
+        return None;
+    }
+    let (major, minor) = version.split_once('p').unwrap_or((version, "0"));
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Parse a u32 length-prefixed block (where the length includes the length field itself),
+/// returning its offset and the data after the length field.
+fn parse_length_prefixed<'data, E: EndianParse>(
+    endian: E,
+    offset: &mut usize,
+    data: &'data [u8],
+) -> Result<(usize, &'data [u8]), ParseError> {
+    let start = *offset;
+    let len = endian.parse_u32_at(offset, data)? as usize;
+    let end = start.checked_add(len).ok_or(ParseError::IntegerOverflow)?;
+    if end < *offset {
+        return Err(ParseError::SliceReadError((start, *offset)));
+    }
+    let block = data.get_bytes(*offset..end)?;
+    *offset = end;
+    Ok((start, block))
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[rustfmt::skip]
+    const AEABI: [u8; 38] = [
+        b'A',
+        // subsection length, vendor
+        0x25, 0x00, 0x00, 0x00, b'a', b'e', b'a', b'b', b'i', 0x00,
+        // Tag_File, size
+        0x01, 0x0b, 0x00, 0x00, 0x00,
+        // Tag_CPU_name "M4", Tag_CPU_arch v7E-M
+        0x05, b'M', b'4', 0x00, 0x06, 0x0d,
+        // Tag_Section, size, sections 3 and 4
+        0x02, 0x10, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00,
+        // Tag_compatibility 1 "gnu", Tag_DIV_use 2
+        0x20, 0x01, b'g', b'n', b'u', 0x00, 0x2c, 0x02,
+    ];
+
+    #[test]
+    fn parse_aeabi_attributes() {
+        let attrs = AttributesSection::new(LittleEndian, &AEABI).expect("should parse");
+        let subsections: Vec<_> = attrs.subsections().collect();
+        assert_eq!(subsections.len(), 1);
+        let subsection = subsections[0].as_ref().expect("should parse");
+        assert_eq!(subsection.vendor_str().expect("should be utf8"), "aeabi");
+
+        let scopes: Vec<AttributesScope<'_>> = subsection
+            .scopes()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(scopes.len(), 2);
+
+        assert_eq!(scopes[0].tag, abi::TAG_FILE);
+        assert_eq!(scopes[0].indexes().count(), 0);
+        let file_attrs: Vec<Attribute<'_>> = scopes[0]
+            .attributes()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            file_attrs,
+            [
+                Attribute {
+                    tag: abi::TAG_CPU_NAME,
+                    value: AttributeValue::String(b"M4"),
+                },
+                Attribute {
+                    tag: abi::TAG_CPU_ARCH,
+                    value: AttributeValue::Integer(abi::TAG_CPU_ARCH_V7E_M),
+                },
+            ]
+        );
+
+        assert_eq!(scopes[1].tag, abi::TAG_SECTION);
+        assert_eq!(scopes[1].indexes().collect::<Vec<u64>>(), [3, 4]);
+        let section_attrs: Vec<Attribute<'_>> = scopes[1]
+            .attributes()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            section_attrs,
+            [
+                Attribute {
+                    tag: abi::TAG_COMPATIBILITY,
+                    value: AttributeValue::IntegerAndString(1, b"gnu"),
+                },
+                Attribute {
+                    tag: abi::TAG_DIV_USE,
+                    value: AttributeValue::Integer(2),
+                },
+            ]
+        );
+
+        // Only file-scope attributes are found
+        let file_attribute = |vendor: &[u8], tag| {
+            attrs
+                .file_attribute(vendor, tag)
+                .expect("attributes should parse")
+        };
+        assert_eq!(
+            file_attribute(b"aeabi", abi::TAG_CPU_NAME),
+            Some(AttributeValue::String(b"M4"))
+        );
+        assert_eq!(file_attribute(b"aeabi", abi::TAG_DIV_USE), None);
+        assert_eq!(file_attribute(b"gnu", abi::TAG_CPU_NAME), None);
+    }
+
+    #[test]
+    fn parse_riscv_attributes() {
+        #[rustfmt::skip]
+        let data = [
+            b'A',
+            0x2a, 0x00, 0x00, 0x00, b'r', b'i', b's', b'c', b'v', 0x00,
+            0x01, 0x20, 0x00, 0x00, 0x00,
+            // Tag_RISCV_stack_align 16, Tag_RISCV_unaligned_access 0
+            0x04, 0x10, 0x06, 0x00,
+            // Tag_RISCV_arch
+            0x05, b'r', b'v', b'3', b'2', b'i', b'm', b'a', b'c', b'_',
+            b'z', b'i', b'c', b's', b'r', b'2', b'p', b'0', 0x00,
+            // Tag_RISCV_priv_spec 1, Tag_RISCV_priv_spec_minor 11
+            0x08, 0x01, 0x0a, 0x0b,
+        ];
+        let section = AttributesSection::new(LittleEndian, &data).expect("should parse");
+        let attrs = RiscvAttributes::parse(&section).expect("should parse");
+        assert_eq!(
+            attrs,
+            RiscvAttributes {
+                stack_align: Some(16),
+                arch: Some(b"rv32imac_zicsr2p0"),
+                unaligned_access: Some(false),
+                priv_spec: Some((1, 11, 0)),
+                atomic_abi: None,
+                x3_reg_usage: None,
+            }
+        );
+
+        let arch = attrs.arch().expect("should parse");
+        assert_eq!(arch.xlen, 32);
+        let names: Vec<&str> = arch.extensions().map(|ext| ext.name).collect();
+        assert_eq!(names, ["i", "m", "a", "c", "zicsr"]);
+        assert!(arch.has_extension("zicsr"));
+        assert!(!arch.has_extension("zifencei"));
+    }
+
+    #[test]
+    fn parse_riscv_arch_versions() {
+        let arch = RiscvArch::parse("rv64i2p1_m2p0_zve32x1p0_zvl128b_xfoo2").expect("should parse");
+        assert_eq!(arch.xlen, 64);
+        let extensions: Vec<RiscvExtension<'_>> = arch.extensions().collect();
+        assert_eq!(
+            extensions,
+            [
+                RiscvExtension {
+                    name: "i",
+                    version: Some((2, 1)),
+                },
+                RiscvExtension {
+                    name: "m",
+                    version: Some((2, 0)),
+                },
+                RiscvExtension {
+                    name: "zve32x",
+                    version: Some((1, 0)),
+                },
+                RiscvExtension {
+                    name: "zvl128b",
+                    version: None,
+                },
+                RiscvExtension {
+                    name: "xfoo",
+                    version: Some((2, 0)),
+                },
+            ]
+        );
+
+        assert_eq!(RiscvArch::parse("rv16i"), None);
+        assert_eq!(RiscvArch::parse("x86_64"), None);
+        assert_eq!(RiscvArch::parse("rv64é"), None);
+        assert_eq!(RiscvArch::parse("rv64i_zé"), None);
+    }
+
+    #[test]
+    fn parse_bad_version_errors() {
+        let err =
+            AttributesSection::new(LittleEndian, &[b'B', 0, 0, 0, 0]).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedVersion((0x42, 0x41))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_subsection_length_too_big_errors() {
+        let data = [b'A', 0x00, 0x00, 0x00, 0x20, b'x', 0x00];
+        let attrs = AttributesSection::new(BigEndian, &data).expect("should parse");
+        let mut subsections = attrs.subsections();
+        let err = subsections
+            .next()
+            .expect("should have a subsection")
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((4, 32))),
+            "Unexpected Error type found: {err}"
+        );
+        assert!(subsections.next().is_none());
+    }
+
+    #[test]
+    fn parse_attribute_string_missing_nul_errors() {
+        #[rustfmt::skip]
+        let data = [
+            b'A',
+            0x0e, 0x00, 0x00, 0x00, b'x', 0x00,
+            0x01, 0x08, 0x00, 0x00, 0x00, 0x05, b'a', b'b',
+        ];
+        let attrs = AttributesSection::new(LittleEndian, &data).expect("should parse");
+        let err = attrs
+            .file_attribute(b"x", abi::TAG_CPU_NAME)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(1)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Decoding AVR specific header data: the [e_flags](crate::file::FileHeader#structfield.e_flags)
+//! of objects built for [EM_AVR](crate::abi::EM_AVR)
+//!
+//! The flags record which AVR architecture (avr5, avrxmega2, avrtiny, ...) the object was
+//! compiled for, which determines the instruction set, the size of the program counter, and
+//! how program memory is addressed. They also record whether the object was prepared for
+//! linker relaxation.
+//!
+//! See [FileHeader::avr_flags](crate::file::FileHeader::avr_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The architecture selected by the [EF_AVR_MACH](abi::EF_AVR_MACH) bits, named like gcc's
+/// `-mmcu` architecture options
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvrArch {
+    Avr1,
+    Avr2,
+    Avr25,
+    Avr3,
+    Avr31,
+    Avr35,
+    Avr4,
+    Avr5,
+    Avr51,
+    Avr6,
+    AvrTiny,
+    Xmega1,
+    Xmega2,
+    Xmega3,
+    Xmega4,
+    Xmega5,
+    Xmega6,
+    Xmega7,
+    /// An architecture not known to this crate. Contains the EF_AVR_MACH bits.
+    Unknown(u32),
+}
+
+/// The decoded e_flags of an AVR object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvrFlags {
+    /// The architecture the object was built for
+    pub arch: AvrArch,
+    /// The object was prepared for linker relaxation
+    /// ([EF_AVR_LINKRELAX_PREPARED](abi::EF_AVR_LINKRELAX_PREPARED))
+    pub link_relax_prepared: bool,
+}
+
+impl AvrFlags {
+    /// Decode the given e_flags value. Bits which aren't defined by binutils are ignored.
+    pub fn from_e_flags(e_flags: u32) -> Self {
+        let arch = match e_flags & abi::EF_AVR_MACH {
+            abi::E_AVR_MACH_AVR1 => AvrArch::Avr1,
+            abi::E_AVR_MACH_AVR2 => AvrArch::Avr2,
+            abi::E_AVR_MACH_AVR25 => AvrArch::Avr25,
+            abi::E_AVR_MACH_AVR3 => AvrArch::Avr3,
+            abi::E_AVR_MACH_AVR31 => AvrArch::Avr31,
+            abi::E_AVR_MACH_AVR35 => AvrArch::Avr35,
+            abi::E_AVR_MACH_AVR4 => AvrArch::Avr4,
+            abi::E_AVR_MACH_AVR5 => AvrArch::Avr5,
+            abi::E_AVR_MACH_AVR51 => AvrArch::Avr51,
+            abi::E_AVR_MACH_AVR6 => AvrArch::Avr6,
+            abi::E_AVR_MACH_AVRTINY => AvrArch::AvrTiny,
+            abi::E_AVR_MACH_XMEGA1 => AvrArch::Xmega1,
+            abi::E_AVR_MACH_XMEGA2 => AvrArch::Xmega2,
+            abi::E_AVR_MACH_XMEGA3 => AvrArch::Xmega3,
+            abi::E_AVR_MACH_XMEGA4 => AvrArch::Xmega4,
+            abi::E_AVR_MACH_XMEGA5 => AvrArch::Xmega5,
+            abi::E_AVR_MACH_XMEGA6 => AvrArch::Xmega6,
+            abi::E_AVR_MACH_XMEGA7 => AvrArch::Xmega7,
+            arch => AvrArch::Unknown(arch),
+        };
+        AvrFlags {
+            arch,
+            link_relax_prepared: e_flags & abi::EF_AVR_LINKRELAX_PREPARED != 0,
+        }
+    }
+}
+
+impl EFlagsDecoder for AvrFlags {
+    const E_MACHINE: u16 = abi::EM_AVR;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        AvrFlags::from_e_flags(ehdr.e_flags)
+    }
+}
+
+/// Formats the flags, e.g. "avr5, link-relax"
+impl core::fmt::Display for AvrFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.arch {
+            AvrArch::Avr1 => write!(f, "avr1")?,
+            AvrArch::Avr2 => write!(f, "avr2")?,
+            AvrArch::Avr25 => write!(f, "avr25")?,
+            AvrArch::Avr3 => write!(f, "avr3")?,
+            AvrArch::Avr31 => write!(f, "avr31")?,
+            AvrArch::Avr35 => write!(f, "avr35")?,
+            AvrArch::Avr4 => write!(f, "avr4")?,
+            AvrArch::Avr5 => write!(f, "avr5")?,
+            AvrArch::Avr51 => write!(f, "avr51")?,
+            AvrArch::Avr6 => write!(f, "avr6")?,
+            AvrArch::AvrTiny => write!(f, "avrtiny")?,
+            AvrArch::Xmega1 => write!(f, "avrxmega1")?,
+            AvrArch::Xmega2 => write!(f, "avrxmega2")?,
+            AvrArch::Xmega3 => write!(f, "avrxmega3")?,
+            AvrArch::Xmega4 => write!(f, "avrxmega4")?,
+            AvrArch::Xmega5 => write!(f, "avrxmega5")?,
+            AvrArch::Xmega6 => write!(f, "avrxmega6")?,
+            AvrArch::Xmega7 => write!(f, "avrxmega7")?,
+            AvrArch::Unknown(arch) => write!(f, "unknown architecture {arch:#x}")?,
+        }
+        if self.link_relax_prepared {
+            write!(f, ", link-relax")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            AvrFlags::from_e_flags(abi::E_AVR_MACH_AVR5 | abi::EF_AVR_LINKRELAX_PREPARED),
+            AvrFlags {
+                arch: AvrArch::Avr5,
+                link_relax_prepared: true,
+            }
+        );
+        assert_eq!(
+            AvrFlags::from_e_flags(abi::E_AVR_MACH_XMEGA3 | 0xff00),
+            AvrFlags {
+                arch: AvrArch::Xmega3,
+                link_relax_prepared: false,
+            }
+        );
+        assert_eq!(AvrFlags::from_e_flags(0x7f).arch, AvrArch::Unknown(0x7f));
+    }
+
+    #[test]
+    fn display() {
+        let flags = AvrFlags::from_e_flags(0x85);
+        assert_eq!(flags.to_string(), "avr5, link-relax");
+        let flags = AvrFlags::from_e_flags(abi::E_AVR_MACH_AVRTINY);
+        assert_eq!(flags.to_string(), "avrtiny");
+    }
+}
@@ -0,0 +1,601 @@
+//! `relf`: a small readelf work-alike built on this crate
+//!
+//! Supports the `-h` (file header), `-S` (section headers), `-l` (program headers), `-s`
+//! (symbols), `-r` (relocations with addends), `-d` (dynamic section), and `-n` (notes) flags,
+//! which can be combined as in `relf -hSl <file>`. Output is laid out like `readelf -W`.
+//!
+//! Build with `cargo build --features cli --bin relf`.
+use std::fmt::Write;
+use std::process::ExitCode;
+
+use elf::abi;
+use elf::display::{
+    write_program_headers, write_relas_with_machines, write_section_headers, write_symbols,
+};
+use elf::endian::{AnyEndian, EndianParse};
+use elf::file::{Class, DecodedFlags, FileHeader};
+use elf::machine::MachineRegistry;
+use elf::note::Note;
+use elf::parse::ParseError;
+use elf::ppc64::Ppc64Abi;
+use elf::to_str;
+use elf::{ElfBytes, OpenOptions};
+
+const USAGE: &str = "usage: relf [-hSlsrdn] <file>...";
+
+/// Which parts of the file to display
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Options {
+    file_header: bool,
+    section_headers: bool,
+    program_headers: bool,
+    symbols: bool,
+    relocations: bool,
+    dynamic: bool,
+    notes: bool,
+}
+
+/// Split the command line arguments into the display options and the paths to display.
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options::default();
+    let mut paths = Vec::new();
+    for arg in args {
+        let flags = match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() => flags,
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+        for flag in flags.chars() {
+            match flag {
+                'h' => options.file_header = true,
+                'S' => options.section_headers = true,
+                'l' => options.program_headers = true,
+                's' => options.symbols = true,
+                'r' => options.relocations = true,
+                'd' => options.dynamic = true,
+                'n' => options.notes = true,
+                _ => return Err(format!("relf: unrecognized option '-{flag}'")),
+            }
+        }
+    }
+    if options == Options::default() || paths.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    Ok((options, paths))
+}
+
+/// Decode the e_flags of the machines which this crate knows about
+fn machine_flags<E: EndianParse>(ehdr: &FileHeader<E>) -> Option<String> {
+    match ehdr.decoded_flags() {
+        DecodedFlags::Ppc64(Ppc64Abi::Unspecified) => None,
+        DecodedFlags::Other(e_flags)
+            if ehdr.e_machine == abi::EM_S390 && e_flags & abi::EF_S390_HIGH_GPRS != 0 =>
+        {
+            Some("highgprs".to_string())
+        }
+        DecodedFlags::Other(_) => None,
+        flags => Some(flags.to_string()),
+    }
+}
+
+fn write_file_header<E: EndianParse>(
+    out: &mut String,
+    file: &ElfBytes<'_, E>,
+    machines: &MachineRegistry,
+) -> std::fmt::Result {
+    let ehdr = &file.ehdr;
+    let e_type = match to_str::e_type_to_human_str(ehdr.e_type) {
+        Some(human) => format!(
+            "{} ({human})",
+            to_str::e_type_to_string(ehdr.e_type).trim_start_matches("ET_")
+        ),
+        None => to_str::e_type_to_string(ehdr.e_type),
+    };
+    let machine = machines.e_machine_to_human_str(ehdr.e_machine).map_or_else(
+        || machines.e_machine_to_string(ehdr.e_machine),
+        String::from,
+    );
+
+    writeln!(out, "ELF Header:")?;
+    let class = match ehdr.class {
+        Class::ELF32 => "ELF32",
+        Class::ELF64 => "ELF64",
+    };
+    let data = if ehdr.endianness.is_little() {
+        "2's complement, little endian"
+    } else {
+        "2's complement, big endian"
+    };
+    let fields: [(&str, String); 17] = [
+        ("Class", class.to_string()),
+        ("Data", data.to_string()),
+        ("OS/ABI", to_str::e_osabi_to_string(ehdr.osabi)),
+        ("ABI Version", ehdr.abiversion.to_string()),
+        ("Type", e_type),
+        ("Machine", machine),
+        ("Version", format!("{:#x}", ehdr.version)),
+        ("Entry point address", format!("{:#x}", ehdr.e_entry)),
+        (
+            "Start of program headers",
+            format!("{} (bytes into file)", ehdr.e_phoff),
+        ),
+        (
+            "Start of section headers",
+            format!("{} (bytes into file)", ehdr.e_shoff),
+        ),
+        (
+            "Flags",
+            match machine_flags(ehdr) {
+                Some(flags) => format!("{:#x}, {flags}", ehdr.e_flags),
+                None => format!("{:#x}", ehdr.e_flags),
+            },
+        ),
+        ("Size of this header", format!("{} (bytes)", ehdr.e_ehsize)),
+        (
+            "Size of program headers",
+            format!("{} (bytes)", ehdr.e_phentsize),
+        ),
+        ("Number of program headers", ehdr.e_phnum.to_string()),
+        (
+            "Size of section headers",
+            format!("{} (bytes)", ehdr.e_shentsize),
+        ),
+        ("Number of section headers", ehdr.e_shnum.to_string()),
+        (
+            "Section header string table index",
+            ehdr.e_shstrndx.to_string(),
+        ),
+    ];
+    for (name, value) in fields {
+        writeln!(out, "  {:<35}{value}", format!("{name}:"))?;
+    }
+    Ok(())
+}
+
+fn write_dynamic<E: EndianParse>(
+    out: &mut String,
+    file: &ElfBytes<'_, E>,
+) -> Result<(), ParseError> {
+    let table = match file.dynamic()? {
+        Some(table) => table,
+        None => {
+            let _ = writeln!(out, "There is no dynamic section in this file.");
+            return Ok(());
+        }
+    };
+    let strtab = file.dynamic_strtab()?;
+
+    // Like readelf, count the entries up to and including the terminating DT_NULL
+    let len = table
+        .iter()
+        .position(|dyn_| dyn_.d_tag == abi::DT_NULL)
+        .map_or(table.len(), |index| index + 1);
+    let _ = writeln!(out, "Dynamic section contains {len} entries:");
+    let _ = writeln!(out, "  Tag                Type                 Name/Value");
+    for dyn_ in table.iter() {
+        let d_type = to_str::d_tag_to_str(dyn_.d_tag).map_or_else(
+            || format!("{:#x}", dyn_.d_tag),
+            |s| s.trim_start_matches("DT_").to_string(),
+        );
+        let value = match (dyn_.d_tag, &strtab) {
+            (abi::DT_NEEDED, Some(strtab)) => {
+                format!("Shared library: [{}]", strtab.get(dyn_.d_val() as usize)?)
+            }
+            (abi::DT_SONAME, Some(strtab)) => {
+                format!("Library soname: [{}]", strtab.get(dyn_.d_val() as usize)?)
+            }
+            (abi::DT_RPATH, Some(strtab)) => {
+                format!("Library rpath: [{}]", strtab.get(dyn_.d_val() as usize)?)
+            }
+            (abi::DT_RUNPATH, Some(strtab)) => {
+                format!("Library runpath: [{}]", strtab.get(dyn_.d_val() as usize)?)
+            }
+            _ => format!("{:#x}", dyn_.d_val()),
+        };
+        let _ = writeln!(
+            out,
+            " {:#018x} {:<20} {value}",
+            dyn_.d_tag,
+            format!("({d_type})")
+        );
+        if dyn_.d_tag == abi::DT_NULL {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Write every SHT_RELA section's relocations, resolving their symbols in the linked symbol table.
+fn write_relocations<E: EndianParse>(
+    out: &mut String,
+    file: &ElfBytes<'_, E>,
+    machines: &MachineRegistry,
+) -> Result<(), ParseError> {
+    let (shdrs, strtab) = match file.section_headers_with_strtab()? {
+        (Some(shdrs), strtab) => (shdrs, strtab),
+        (None, _) => {
+            let _ = writeln!(out, "There are no relocations in this file.");
+            return Ok(());
+        }
+    };
+    let dynsyms = file.dynamic_symbol_table()?;
+    let symtab = file.symbol_table()?;
+
+    for shdr in shdrs.iter().filter(|shdr| shdr.sh_type == abi::SHT_RELA) {
+        let name = match &strtab {
+            Some(strtab) => strtab.get(shdr.sh_name as usize)?,
+            None => "",
+        };
+        let symbols = match shdrs.get(shdr.sh_link as usize).map(|link| link.sh_type) {
+            Ok(abi::SHT_DYNSYM) => dynsyms.as_ref(),
+            Ok(abi::SHT_SYMTAB) => symtab.as_ref(),
+            _ => None,
+        };
+        let relas: Vec<_> = file.section_data_as_relas(&shdr)?.collect();
+
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let entries = if relas.len() == 1 { "entry" } else { "entries" };
+        let _ = writeln!(
+            out,
+            "Relocation section '{name}' at offset {:#x} contains {} {entries}:",
+            shdr.sh_offset,
+            relas.len()
+        );
+        let _ = write_relas_with_machines(
+            out,
+            file.ehdr.class,
+            file.ehdr.e_machine,
+            machines,
+            relas,
+            symbols.map(|(symtab, strtab)| (symtab, strtab)),
+        );
+    }
+    if out.is_empty() {
+        let _ = writeln!(out, "There are no relocations in this file.");
+    }
+    Ok(())
+}
+
+fn describe_note(note: &Note<'_>) -> (String, String) {
+    match note {
+        Note::GnuAbiTag(tag) => {
+            let os = to_str::note_abi_tag_os_to_str(tag.os).unwrap_or("Unknown");
+            (
+                "GNU".to_string(),
+                format!(
+                    "NT_GNU_ABI_TAG (ABI version tag)\tOS: {os}, ABI: {}.{}.{}",
+                    tag.major, tag.minor, tag.subminor
+                ),
+            )
+        }
+        Note::GnuBuildId(build_id) => (
+            "GNU".to_string(),
+            format!("NT_GNU_BUILD_ID (unique build ID bitstring)\tBuild ID: {build_id}"),
+        ),
+        Note::GnuProperty(_) => (
+            "GNU".to_string(),
+            "NT_GNU_PROPERTY_TYPE_0 (property note)".to_string(),
+        ),
+        Note::PrStatus(_) => (
+            "CORE".to_string(),
+            "NT_PRSTATUS (prstatus structure)".to_string(),
+        ),
+        Note::PrPsInfo(_) => (
+            "CORE".to_string(),
+            "NT_PRPSINFO (prpsinfo structure)".to_string(),
+        ),
+        Note::File(_) => ("CORE".to_string(), "NT_FILE (mapped files)".to_string()),
+        Note::Auxv(_) => ("CORE".to_string(), "NT_AUXV (auxiliary vector)".to_string()),
+        Note::Unknown(any) => (
+            any.name_str().unwrap_or("?").to_string(),
+            format!("Unknown note type: ({:#010x})", any.n_type),
+        ),
+    }
+}
+
+fn write_notes<E: EndianParse>(out: &mut String, file: &ElfBytes<'_, E>) -> Result<(), ParseError> {
+    let mut write_group = |location: String, notes: Vec<Note<'_>>| {
+        let _ = writeln!(out, "Displaying notes found in: {location}");
+        let _ = writeln!(out, "  Owner                Description");
+        for note in notes {
+            let (owner, description) = describe_note(&note);
+            let _ = writeln!(out, "  {owner:<20} {description}");
+        }
+    };
+
+    if let (Some(shdrs), strtab) = file.section_headers_with_strtab()? {
+        for shdr in shdrs.iter().filter(|shdr| shdr.sh_type == abi::SHT_NOTE) {
+            let name = match &strtab {
+                Some(strtab) => strtab.get(shdr.sh_name as usize)?,
+                None => "",
+            };
+            write_group(
+                name.to_string(),
+                file.section_data_as_notes(&shdr)?.collect(),
+            );
+        }
+    } else if let Some(phdrs) = file.segments() {
+        for phdr in phdrs.iter().filter(|phdr| phdr.p_type == abi::PT_NOTE) {
+            write_group(
+                format!("segment at offset {:#x}", phdr.p_offset),
+                file.segment_data_as_notes(&phdr)?.collect(),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Write the requested parts of the ELF object in `data` to `out`, naming machines with the
+/// open options' [MachineRegistry].
+fn display(
+    options: Options,
+    open_options: &OpenOptions,
+    data: &[u8],
+    out: &mut String,
+) -> Result<(), ParseError> {
+    let file = open_options.open_slice::<AnyEndian>(data)?;
+    let class = file.ehdr.class;
+    let machines = open_options.machine_registry();
+
+    let mut sections = Vec::new();
+    if options.file_header {
+        sections.push(String::new());
+        let _ = write_file_header(sections.last_mut().unwrap(), &file, machines);
+    }
+    if options.section_headers {
+        let mut s = String::new();
+        match file.section_headers_with_strtab()? {
+            (Some(shdrs), strtab) => {
+                let _ = writeln!(s, "Section Headers:");
+                let _ = write_section_headers(&mut s, class, shdrs, strtab.as_ref());
+            }
+            (None, _) => {
+                let _ = writeln!(s, "There are no sections in this file.");
+            }
+        }
+        sections.push(s);
+    }
+    if options.program_headers {
+        let mut s = String::new();
+        match file.segments() {
+            Some(phdrs) => {
+                let _ = writeln!(s, "Program Headers:");
+                let _ = write_program_headers(&mut s, class, phdrs);
+            }
+            None => {
+                let _ = writeln!(s, "There are no program headers in this file.");
+            }
+        }
+        sections.push(s);
+    }
+    if options.symbols {
+        let mut s = String::new();
+        let tables = [
+            (".dynsym", file.dynamic_symbol_table()?),
+            (".symtab", file.symbol_table()?),
+        ];
+        for (name, table) in tables {
+            if let Some((symtab, strtab)) = table {
+                if !s.is_empty() {
+                    s.push('\n');
+                }
+                let _ = writeln!(
+                    s,
+                    "Symbol table '{name}' contains {} entries:",
+                    symtab.len()
+                );
+                let _ = write_symbols(&mut s, class, symtab.iter(), Some(&strtab));
+            }
+        }
+        sections.push(s);
+    }
+    if options.relocations {
+        let mut s = String::new();
+        write_relocations(&mut s, &file, machines)?;
+        sections.push(s);
+    }
+    if options.dynamic {
+        let mut s = String::new();
+        write_dynamic(&mut s, &file)?;
+        sections.push(s);
+    }
+    if options.notes {
+        let mut s = String::new();
+        write_notes(&mut s, &file)?;
+        sections.push(s);
+    }
+
+    out.push_str(&sections.join("\n"));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let (options, paths) = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let open_options = OpenOptions::new();
+    let mut status = ExitCode::SUCCESS;
+    for path in paths {
+        let data = match std::fs::read(&path) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("relf: {path}: {err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let mut out = String::new();
+        match display(options, &open_options, &data, &mut out) {
+            Ok(()) => print!("{out}"),
+            Err(err) => {
+                eprintln!("relf: {path}: {err}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+
+    fn relf(args: &[&str]) -> String {
+        let (options, paths) =
+            parse_args(args.iter().map(|arg| arg.to_string())).expect("args should parse");
+        let data = std::fs::read(&paths[0]).expect("Could not read file.");
+        let mut out = String::new();
+        display(options, &OpenOptions::new(), &data, &mut out).expect("file should parse");
+        out
+    }
+
+    #[test]
+    fn parse_args_combined_flags() {
+        let (options, paths) =
+            parse_args(["-hS".to_string(), "-n".to_string(), "a.out".to_string()]).unwrap();
+        assert!(options.file_header && options.section_headers && options.notes);
+        assert!(!options.symbols);
+        assert_eq!(paths, ["a.out"]);
+
+        assert_eq!(parse_args(["a.out".to_string()]), Err(USAGE.to_string()));
+        assert_eq!(
+            parse_args(["-x".to_string(), "a.out".to_string()]),
+            Err("relf: unrecognized option '-x'".to_string())
+        );
+    }
+
+    #[test]
+    fn file_header() {
+        let out = relf(&["-h", "sample-objects/basic.x86_64"]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "ELF Header:");
+        assert_eq!(
+            lines[5],
+            "  Type:                              EXEC (Executable file)"
+        );
+        assert_eq!(
+            lines[6],
+            "  Machine:                           AMD x86-64 architecture"
+        );
+        assert_eq!(lines[8], "  Entry point address:               0x4003f0");
+        assert_eq!(lines[17], "  Section header string table index: 28");
+
+        let out = relf(&["-h", "sample-objects/symver.riscv64.so"]);
+        assert!(out.contains("\n  Flags:                             0x5, RVC, double-float ABI\n"));
+
+        let out = relf(&["-h", "sample-objects/symver.armhf.so"]);
+        assert!(out.contains(
+            "\n  Flags:                             0x5000400, Version5 EABI, hard-float ABI\n"
+        ));
+
+        let out = relf(&["-h", "sample-objects/abiflags.mips.o"]);
+        assert!(out.contains(
+            "\n  Flags:                             0x70001007, noreorder, pic, cpic, o32, mips32r2\n"
+        ));
+
+        let out = relf(&["-h", "sample-objects/symver.powerpc64.so"]);
+        assert!(out.contains("\n  Flags:                             0x1, abiv1\n"));
+    }
+
+    #[test]
+    fn dynamic_and_notes() {
+        let out = relf(&["-dn", "sample-objects/basic.x86_64"]);
+        assert!(
+            out.contains(" 0x0000000000000001 (NEEDED)             Shared library: [libc.so.6]\n")
+        );
+        assert!(out.contains(" 0x000000000000000c (INIT)               0x400390\n"));
+        assert!(out.contains("Displaying notes found in: .note.gnu.build-id\n"));
+        assert!(out.contains(
+            "  GNU                  NT_GNU_BUILD_ID (unique build ID bitstring)\tBuild ID: 77419f0da510830c57a7c8ccb0ee855feed376a3\n"
+        ));
+    }
+
+    #[test]
+    fn symbols_and_headers() {
+        let out = relf(&["-Sls", "sample-objects/basic.x86_64"]);
+        assert!(out.starts_with("Section Headers:\n  [Nr] Name"));
+        assert!(out.contains("\nProgram Headers:\n  Type           Offset"));
+        assert!(out.contains("\nSymbol table '.dynsym' contains 4 entries:\n"));
+        assert!(out.contains("\nSymbol table '.symtab' contains 69 entries:\n"));
+    }
+
+    #[test]
+    fn relocations() {
+        let out = relf(&["-r", "sample-objects/basic.x86_64"]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Relocation section '.rela.dyn' at offset 0x348 contains 1 entry:"
+        );
+        assert_eq!(
+            lines[2],
+            "00000000006008a8  0000000300000006 R_X86_64_GLOB_DAT      0000000000000000 __gmon_start__ + 0"
+        );
+        assert_eq!(
+            lines[4],
+            "Relocation section '.rela.plt' at offset 0x360 contains 2 entries:"
+        );
+        assert_eq!(lines.len(), 8);
+
+        let out = relf(&["-r", "sample-objects/group.x86_64.o"]);
+        assert!(out.contains(
+            "0000000000000012  0000000800000004 R_X86_64_PLT32         0000000000000000 _Z5twiceIiET_S0_ - 4\n"
+        ));
+
+        let out = relf(&["-r", "sample-objects/symver.aarch64.so"]);
+        assert!(out.contains(
+            "0000000000010dc0  0000000000000403 R_AARCH64_RELATIVE                        710\n"
+        ));
+        assert!(out.contains(
+            "0000000000010fd8  0000000600000401 R_AARCH64_GLOB_DAT     0000000000000000 __gmon_start__ + 0\n"
+        ));
+
+        let out = relf(&["-r", "sample-objects/symver.riscv64.so"]);
+        assert!(out.contains(
+            "0000000000002028  0000000200000002 R_RISCV_64             0000000000000000 _ITM_deregisterTMCloneTable + 0\n"
+        ));
+
+        let out = relf(&["-r", "sample-objects/symver.powerpc64le.so"]);
+        assert!(out.contains(
+            "000000000001ff08  0000000600000026 R_PPC64_ADDR64         0000000000000000 __gmon_start__ + 0\n"
+        ));
+    }
+
+    #[test]
+    fn registered_machine_names() {
+        const EM_TEST: u16 = 0x4000;
+        let mut data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        data[18..20].copy_from_slice(&EM_TEST.to_le_bytes());
+
+        let mut machines = MachineRegistry::new();
+        machines.register(
+            EM_TEST,
+            elf::machine::Machine {
+                name: "EM_TEST",
+                description: "Test machine",
+                relocations: &[(7, "R_TEST_JUMP_SLOT")],
+            },
+        );
+        let mut open_options = OpenOptions::new();
+        open_options.machines(machines);
+
+        let (options, _) = parse_args(["-hr".to_string(), "a.out".to_string()]).unwrap();
+        let mut out = String::new();
+        display(options, &open_options, &data, &mut out).expect("file should parse");
+        assert!(out.contains("\n  Machine:                           Test machine\n"));
+        assert!(out.contains(
+            "00000000006008c8  0000000100000007 R_TEST_JUMP_SLOT       0000000000000000 memset + 0\n"
+        ));
+        // Types which aren't registered fall back to the numeric names
+        assert!(out.contains("00000000006008a8  0000000300000006 r_type(0x6)"));
+    }
+}
@@ -0,0 +1,1316 @@
+//! Constructing new ELF objects from scratch: [ElfBuilder], and core dumps: [CoreBuilder]
+//!
+//! The builder takes care of the parts of an ELF image that are tedious to get right by hand:
+//! it places the program header table, section data, and section header table in the file,
+//! assigns addresses to the sections which are loaded by a segment, fills in the segments'
+//! offsets and sizes, and generates the `.shstrtab` section name string table.
+//!
+//! Relocatable objects can be built too, with [ElfBuilder::add_symbol_table] and
+//! [ElfBuilder::add_rela_section]. Since the machine number and relocation types are written
+//! as given, this works for custom architectures which aren't in the gABI's `EM_*` table.
+//!
+//! Example for building a tiny x86_64 Linux executable which exits with status 42:
+//! ```
+//! use elf::abi;
+//! use elf::builder::ElfBuilder;
+//! use elf::endian::LittleEndian;
+//! use elf::file::Class;
+//! use elf::ElfBytes;
+//!
+//! let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+//! let code = vec![
+//!     0xb8, 0x3c, 0x00, 0x00, 0x00, // mov eax, 60 (exit)
+//!     0xbf, 0x2a, 0x00, 0x00, 0x00, // mov edi, 42
+//!     0x0f, 0x05,                   // syscall
+//! ];
+//! let text = builder.add_section(
+//!     ".text",
+//!     abi::SHT_PROGBITS,
+//!     (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+//!     code,
+//! );
+//! builder.add_segment(abi::PT_LOAD, abi::PF_R | abi::PF_X, 0x401000, 0x1000, &[text]);
+//! builder.entry(0x401000);
+//!
+//! let image = builder
+//!     .build()
+//!     .expect("layout should succeed")
+//!     .to_bytes()
+//!     .expect("image should be writable");
+//!
+//! let file = ElfBytes::<LittleEndian>::minimal_parse(&image).expect("Open image");
+//! let shdr = file
+//!     .section_header_by_name(".text")
+//!     .expect("section table should be parseable")
+//!     .expect("image should have a .text section");
+//! assert_eq!(shdr.sh_addr, 0x401000);
+//! assert_eq!(shdr.sh_offset % 0x1000, 0);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{Class, FileHeader, ELF32_EHDR_TAILSIZE, ELF64_EHDR_TAILSIZE};
+use crate::note::{AuxvEntry, NotePrPsInfo, NotePrStatus};
+use crate::parse::{ParseAt, ParseError};
+use crate::relocation::{Rel, Rela};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::symbol::Symbol;
+use crate::writer::{
+    align_up, encode_note, encode_u16, encode_u32, encode_u64, ElfFile, Encode, Section, Segment,
+    StringTableBuilder,
+};
+
+/// The alignment of a core dump's memory regions in the file
+const CORE_PAGE_SIZE: u64 = 0x1000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SegmentSpec {
+    phdr: ProgramHeader,
+    sections: Vec<usize>,
+}
+
+/// A symbol to add with [ElfBuilder::add_symbol_table]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolSpec {
+    /// The symbol's name
+    pub name: String,
+    /// The symbol's binding, e.g. [STB_GLOBAL](abi::STB_GLOBAL)
+    pub bind: u8,
+    /// The symbol's type, e.g. [STT_FUNC](abi::STT_FUNC)
+    pub symtype: u8,
+    /// The symbol's visibility, e.g. [STV_HIDDEN](abi::STV_HIDDEN)
+    pub visibility: u8,
+    /// The section header table index of the section the symbol is defined in, as returned by
+    /// [ElfBuilder::add_section], or a special index like [SHN_UNDEF](abi::SHN_UNDEF) or
+    /// [SHN_ABS](abi::SHN_ABS)
+    pub shndx: u16,
+    /// The symbol's value, which in relocatable objects is its offset in its section
+    pub value: u64,
+    /// The symbol's size
+    pub size: u64,
+}
+
+/// Builds a new ELF object, computing its file layout automatically.
+///
+/// Sections and segments are added in the order they should appear in the output, and the
+/// indexes returned by [ElfBuilder::add_section] are the sections' final section header
+/// table indexes, so they can be used for the `sh_link` and `sh_info` fields of other sections.
+/// The null section at index 0 and the trailing `.shstrtab` section are added by the builder.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfBuilder<E: EndianParse> {
+    ehdr: FileHeader<E>,
+    sections: Vec<Section>,
+    segments: Vec<SegmentSpec>,
+}
+
+impl<E: EndianParse> ElfBuilder<E> {
+    /// Create a builder for an object of the given class, byte order, type (e.g.
+    /// [ET_EXEC](abi::ET_EXEC)), and machine (e.g. [EM_X86_64](abi::EM_X86_64)).
+    pub fn new(class: Class, endianness: E, e_type: u16, e_machine: u16) -> Self {
+        let tail_size = match class {
+            Class::ELF32 => ELF32_EHDR_TAILSIZE,
+            Class::ELF64 => ELF64_EHDR_TAILSIZE,
+        };
+        ElfBuilder {
+            ehdr: FileHeader {
+                class,
+                endianness,
+                version: abi::EV_CURRENT as u32,
+                osabi: abi::ELFOSABI_NONE,
+                abiversion: 0,
+                ident_pad: [0u8; abi::EI_NIDENT - abi::EI_PAD],
+                e_type,
+                e_machine,
+                e_entry: 0,
+                e_phoff: 0,
+                e_shoff: 0,
+                e_flags: 0,
+                e_ehsize: (abi::EI_NIDENT + tail_size) as u16,
+                e_phentsize: 0,
+                e_phnum: 0,
+                e_shentsize: 0,
+                e_shnum: 0,
+                e_shstrndx: abi::SHN_UNDEF,
+            },
+            sections: Vec::new(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Set the OS ABI and its version.
+    ///
+    /// Default: [ELFOSABI_NONE](abi::ELFOSABI_NONE), version 0
+    pub fn osabi(&mut self, osabi: u8, abiversion: u8) -> &mut Self {
+        self.ehdr.osabi = osabi;
+        self.ehdr.abiversion = abiversion;
+        self
+    }
+
+    /// Set the virtual address of the program entry point.
+    ///
+    /// Default: 0
+    pub fn entry(&mut self, e_entry: u64) -> &mut Self {
+        self.ehdr.e_entry = e_entry;
+        self
+    }
+
+    /// Set the processor-specific flags.
+    ///
+    /// Default: 0
+    pub fn flags(&mut self, e_flags: u32) -> &mut Self {
+        self.ehdr.e_flags = e_flags;
+        self
+    }
+
+    /// Append a section with the given name, type, flags, and contents, and get its section
+    /// header table index.
+    ///
+    /// The section has no alignment constraint. The rest of its header can be set with
+    /// [ElfBuilder::section_mut].
+    pub fn add_section(&mut self, name: &str, sh_type: u32, sh_flags: u64, data: Vec<u8>) -> usize {
+        self.sections
+            .push(Section::new(name, sh_type, sh_flags, data));
+        self.sections.len()
+    }
+
+    /// Append an [SHT_NOBITS](abi::SHT_NOBITS) section (such as `.bss`) which occupies `size`
+    /// bytes of memory but no space in the file, and get its section header table index.
+    pub fn add_nobits_section(&mut self, name: &str, sh_flags: u64, size: u64) -> usize {
+        let index = self.add_section(name, abi::SHT_NOBITS, sh_flags, Vec::new());
+        self.sections[index - 1].shdr.sh_size = size;
+        index
+    }
+
+    /// Get a previously added section by its section header table index, e.g. to set its
+    /// alignment, entry size, link, or info fields.
+    ///
+    /// The section's offset and name are always computed by [ElfBuilder::build], as is its
+    /// address if it's part of a segment. Its size is taken from its data unless it's an
+    /// [SHT_NOBITS](abi::SHT_NOBITS) section.
+    pub fn section_mut(&mut self, index: usize) -> Option<&mut Section> {
+        self.sections.get_mut(index.checked_sub(1)?)
+    }
+
+    /// Append a `.symtab` symbol table with the given symbols, along with its `.strtab` string
+    /// table, and get the symbol table's section header table index.
+    ///
+    /// The null symbol is added at index 0, so `symbols[i]` ends up at symbol index `i + 1`,
+    /// which is the index to use in relocations' `r_sym`. As the gABI requires, all
+    /// [STB_LOCAL](abi::STB_LOCAL) symbols must come before the others. Returns
+    /// [ParseError::BadOffset] with the symbol index of the first local symbol which doesn't.
+    pub fn add_symbol_table(&mut self, symbols: &[SymbolSpec]) -> Result<usize, ParseError> {
+        let endian = self.ehdr.endianness;
+        let class = self.ehdr.class;
+        let mut names = StringTableBuilder::new();
+        // The null symbol is all zeroes
+        let mut data = vec![0; Symbol::size_for(class)];
+        let mut first_global = None;
+        for (index, spec) in symbols.iter().enumerate() {
+            let index = index + 1;
+            if spec.bind != abi::STB_LOCAL {
+                first_global.get_or_insert(index);
+            } else if first_global.is_some() {
+                return Err(ParseError::BadOffset(index as u64));
+            }
+            let symbol = Symbol {
+                st_name: names.add(&spec.name),
+                st_shndx: spec.shndx,
+                st_info: (spec.bind << 4) | (spec.symtype & 0xf),
+                st_other: spec.visibility & 0x3,
+                st_value: spec.value,
+                st_size: spec.size,
+            };
+            symbol.encode(endian, class, &mut data)?;
+        }
+
+        let symtab = self.add_section(".symtab", abi::SHT_SYMTAB, 0, data);
+        let strtab = self.add_section(".strtab", abi::SHT_STRTAB, 0, names.into_data());
+        let shdr = &mut self.sections[symtab - 1].shdr;
+        shdr.sh_link = strtab as u32;
+        shdr.sh_info = first_global.unwrap_or(symbols.len() + 1) as u32;
+        shdr.sh_addralign = word_size(class);
+        shdr.sh_entsize = Symbol::size_for(class) as u64;
+        Ok(symtab)
+    }
+
+    /// Append an [SHT_RELA](abi::SHT_RELA) section named `.rela<target name>` with relocations
+    /// for the `target` section against the symbols in the `symtab` section, and get its section
+    /// header table index.
+    ///
+    /// Relocation types are written as given, so they can be any machine-specific (or custom)
+    /// values. Returns [ParseError::BadOffset] if `target` or `symtab` isn't an added section,
+    /// or [ParseError::IntegerOverflow] if a symbol index or type doesn't fit in an ELF32
+    /// `r_info`.
+    pub fn add_rela_section(
+        &mut self,
+        target: usize,
+        symtab: usize,
+        relas: &[Rela],
+    ) -> Result<usize, ParseError> {
+        let mut data = Vec::with_capacity(Rela::size_for(self.ehdr.class) * relas.len());
+        for rela in relas {
+            rela.encode(self.ehdr.endianness, self.ehdr.class, &mut data)?;
+        }
+        self.add_relocation_section(
+            ".rela",
+            abi::SHT_RELA,
+            Rela::size_for(self.ehdr.class),
+            target,
+            symtab,
+            data,
+        )
+    }
+
+    /// Append an [SHT_REL](abi::SHT_REL) section named `.rel<target name>` with relocations for
+    /// the `target` section against the symbols in the `symtab` section, and get its section
+    /// header table index.
+    ///
+    /// See [ElfBuilder::add_rela_section].
+    pub fn add_rel_section(
+        &mut self,
+        target: usize,
+        symtab: usize,
+        rels: &[Rel],
+    ) -> Result<usize, ParseError> {
+        let mut data = Vec::with_capacity(Rel::size_for(self.ehdr.class) * rels.len());
+        for rel in rels {
+            rel.encode(self.ehdr.endianness, self.ehdr.class, &mut data)?;
+        }
+        self.add_relocation_section(
+            ".rel",
+            abi::SHT_REL,
+            Rel::size_for(self.ehdr.class),
+            target,
+            symtab,
+            data,
+        )
+    }
+
+    fn add_relocation_section(
+        &mut self,
+        prefix: &str,
+        sh_type: u32,
+        entsize: usize,
+        target: usize,
+        symtab: usize,
+        data: Vec<u8>,
+    ) -> Result<usize, ParseError> {
+        for index in [target, symtab] {
+            if self.section_mut(index).is_none() {
+                return Err(ParseError::BadOffset(index as u64));
+            }
+        }
+        let name = format!("{prefix}{}", self.sections[target - 1].name);
+        let index = self.add_section(&name, sh_type, abi::SHF_INFO_LINK as u64, data);
+        let shdr = &mut self.sections[index - 1].shdr;
+        shdr.sh_link = symtab as u32;
+        shdr.sh_info = target as u32;
+        shdr.sh_addralign = word_size(self.ehdr.class);
+        shdr.sh_entsize = entsize as u64;
+        Ok(index)
+    }
+
+    /// Append a segment made up of the given sections (by section header table index), and get
+    /// its program header table index.
+    ///
+    /// The first time a section is added to a segment, it gets placed in the file right after
+    /// the previous section in that segment, at an address relative to the segment's `vaddr`.
+    /// The segment's file offset is chosen to be congruent to `vaddr` modulo `align`, as
+    /// required for [PT_LOAD](abi::PT_LOAD) segments. Sections which were already placed by an
+    /// earlier segment keep their location, which is how segments such as
+    /// [PT_NOTE](abi::PT_NOTE) or [PT_TLS](abi::PT_TLS) can cover part of a [PT_LOAD](abi::PT_LOAD).
+    ///
+    /// [SHT_NOBITS](abi::SHT_NOBITS) sections take up memory but not file space, so they should
+    /// come last in their segment. Segments with no sections (e.g.
+    /// [PT_GNU_STACK](abi::PT_GNU_STACK)) are emitted with the given vaddr and a size of 0.
+    pub fn add_segment(
+        &mut self,
+        p_type: u32,
+        p_flags: u32,
+        vaddr: u64,
+        align: u64,
+        sections: &[usize],
+    ) -> usize {
+        self.segments.push(SegmentSpec {
+            phdr: ProgramHeader {
+                p_type,
+                p_offset: 0,
+                p_vaddr: vaddr,
+                p_paddr: vaddr,
+                p_filesz: 0,
+                p_memsz: 0,
+                p_flags,
+                p_align: align,
+            },
+            sections: sections.to_vec(),
+        });
+        self.segments.len() - 1
+    }
+
+    /// Lay out the object and get it as an [ElfFile], which can then be written out with
+    /// [ElfFile::write].
+    ///
+    /// Returns [ParseError::BadOffset] if a segment refers to a section index which wasn't
+    /// added, [ParseError::UnexpectedAlignment] if a segment with sections has an alignment
+    /// which isn't a power of two, or [ParseError::IntegerOverflow] if the layout doesn't fit in
+    /// the address space.
+    pub fn build(&self) -> Result<ElfFile<E>, ParseError> {
+        let class = self.ehdr.class;
+        let word_align = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        let mut ehdr = self.ehdr;
+        let mut sections = Vec::with_capacity(self.sections.len() + 2);
+        sections.push(Section {
+            name: String::new(),
+            shdr: SectionHeader {
+                sh_name: 0,
+                sh_type: abi::SHT_NULL,
+                sh_flags: 0,
+                sh_addr: 0,
+                sh_offset: 0,
+                sh_size: 0,
+                sh_link: 0,
+                sh_info: 0,
+                sh_addralign: 0,
+                sh_entsize: 0,
+            },
+            data: Vec::new(),
+        });
+        sections.extend(self.sections.iter().cloned());
+
+        let mut names = StringTableBuilder::new();
+        for section in sections.iter_mut() {
+            section.shdr.sh_name = names.add(&section.name);
+            if section.shdr.sh_type != abi::SHT_NOBITS {
+                section.shdr.sh_size = section.data.len() as u64;
+            }
+        }
+        let shstrndx = sections.len();
+        let shstrtab_name = names.add(".shstrtab");
+        let shstrtab = names.into_data();
+        sections.push(Section {
+            name: ".shstrtab".to_string(),
+            shdr: SectionHeader {
+                sh_name: shstrtab_name,
+                sh_type: abi::SHT_STRTAB,
+                sh_flags: 0,
+                sh_addr: 0,
+                sh_offset: 0,
+                sh_size: shstrtab.len() as u64,
+                sh_link: 0,
+                sh_info: 0,
+                sh_addralign: 1,
+                sh_entsize: 0,
+            },
+            data: shstrtab,
+        });
+
+        let mut offset = ehdr.e_ehsize as u64;
+        if !self.segments.is_empty() {
+            ehdr.e_phoff = align_up(offset, word_align)?;
+            let table_size = ProgramHeader::size_for(class) * self.segments.len();
+            offset = ehdr.e_phoff + table_size as u64;
+        }
+
+        // Place the sections which are part of a segment, in segment order
+        let mut placed = vec![false; sections.len()];
+        placed[0] = true;
+        let mut segments = Vec::with_capacity(self.segments.len());
+        for spec in self.segments.iter() {
+            // The (offset, address) that the segment's addresses are relative to
+            let mut base: Option<(u64, u64)> = None;
+            for &index in spec.sections.iter() {
+                if index == 0 || index >= shstrndx {
+                    return Err(ParseError::BadOffset(index as u64));
+                }
+                let shdr = &mut sections[index].shdr;
+                if placed[index] {
+                    base.get_or_insert((shdr.sh_offset, shdr.sh_addr));
+                    continue;
+                }
+
+                let sh_offset = align_up(offset, shdr.sh_addralign)?;
+                let (base_offset, base_addr) = match base {
+                    Some(base) => base,
+                    None => {
+                        let sh_offset =
+                            congruent_offset(sh_offset, spec.phdr.p_vaddr, spec.phdr.p_align)?;
+                        *base.insert((sh_offset, spec.phdr.p_vaddr))
+                    }
+                };
+                let sh_offset = sh_offset.max(base_offset);
+                shdr.sh_offset = sh_offset;
+                shdr.sh_addr = base_addr
+                    .checked_add(sh_offset - base_offset)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                offset = sh_offset
+                    .checked_add(file_size(shdr))
+                    .ok_or(ParseError::IntegerOverflow)?;
+                placed[index] = true;
+            }
+
+            let mut phdr = spec.phdr;
+            if !spec.sections.is_empty() {
+                let (mut start, mut end) = (u64::MAX, 0);
+                let (mut vaddr, mut mem_end) = (u64::MAX, 0);
+                for shdr in spec.sections.iter().map(|index| &sections[*index].shdr) {
+                    let shdr_end = shdr
+                        .sh_offset
+                        .checked_add(file_size(shdr))
+                        .ok_or(ParseError::IntegerOverflow)?;
+                    let shdr_mem_end = shdr
+                        .sh_addr
+                        .checked_add(shdr.sh_size)
+                        .ok_or(ParseError::IntegerOverflow)?;
+                    start = start.min(shdr.sh_offset);
+                    end = end.max(shdr_end);
+                    vaddr = vaddr.min(shdr.sh_addr);
+                    mem_end = mem_end.max(shdr_mem_end);
+                }
+                phdr.p_offset = start;
+                phdr.p_vaddr = vaddr;
+                phdr.p_paddr = vaddr;
+                phdr.p_filesz = end - start;
+                phdr.p_memsz = mem_end - vaddr;
+            }
+            segments.push(Segment {
+                phdr,
+                data: Vec::new(),
+            });
+        }
+
+        // ...followed by everything else, which isn't loaded and so has no address
+        for (section, placed) in sections.iter_mut().zip(placed) {
+            if placed {
+                continue;
+            }
+            let shdr = &mut section.shdr;
+            shdr.sh_offset = align_up(offset, shdr.sh_addralign)?;
+            offset = shdr
+                .sh_offset
+                .checked_add(file_size(shdr))
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+        ehdr.e_shoff = align_up(offset, word_align)?;
+
+        // Indexes that don't fit in the file header are stored in the null section's header
+        if sections.len() >= abi::SHN_LORESERVE as usize {
+            sections[0].shdr.sh_size = sections.len() as u64;
+        }
+        if shstrndx >= abi::SHN_LORESERVE as usize {
+            ehdr.e_shstrndx = abi::SHN_XINDEX;
+            sections[0].shdr.sh_link = shstrndx as u32;
+        } else {
+            ehdr.e_shstrndx = shstrndx as u16;
+        }
+        if segments.len() >= abi::PN_XNUM as usize {
+            sections[0].shdr.sh_info = segments.len() as u32;
+        }
+
+        Ok(ElfFile {
+            ehdr,
+            segments,
+            sections,
+        })
+    }
+}
+
+/// Builds an [ET_CORE](abi::ET_CORE) core dump: a [PT_NOTE](abi::PT_NOTE) segment holding
+/// the process and thread status notes, followed by a [PT_LOAD](abi::PT_LOAD) segment for each
+/// of the process's memory regions. This is the layout that Linux writes and that debuggers
+/// like gdb expect.
+///
+/// Core files have no section header table.
+///
+/// Example for building a core dump of a single-threaded x86_64 process:
+/// ```
+/// use elf::abi;
+/// use elf::builder::CoreBuilder;
+/// use elf::endian::LittleEndian;
+/// use elf::file::Class;
+/// use elf::note::{NotePrStatus, X86_64Registers};
+/// use elf::writer::Encode;
+///
+/// let regs = X86_64Registers {
+///     rip: 0x401000,
+///     rsp: 0x7ffff000,
+///     ..Default::default()
+/// };
+/// let mut pr_reg = Vec::new();
+/// regs.encode(LittleEndian, Class::ELF64, &mut pr_reg)
+///     .expect("registers should encode");
+/// let mut prstatus = NotePrStatus::new(Class::ELF64, &pr_reg);
+/// prstatus.signo = 11;
+/// prstatus.cursig = 11;
+/// prstatus.pid = 1234;
+///
+/// let mut builder = CoreBuilder::new(Class::ELF64, LittleEndian, abi::EM_X86_64);
+/// builder
+///     .add_prstatus(&prstatus)
+///     .expect("prstatus should encode");
+/// builder.add_memory_region(0x401000, abi::PF_R | abi::PF_X, 0x1000, vec![0xcc; 0x1000]);
+/// builder.add_memory_region(0x7fffe000, abi::PF_R | abi::PF_W, 0x2000, vec![0; 0x2000]);
+///
+/// let core = builder
+///     .build()
+///     .expect("layout should succeed")
+///     .to_bytes()
+///     .expect("core should be writable");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoreBuilder<E: EndianParse> {
+    ehdr: FileHeader<E>,
+    notes: Vec<u8>,
+    regions: Vec<Segment>,
+}
+
+impl<E: EndianParse> CoreBuilder<E> {
+    /// Create a builder for a core dump of a process with the given class, byte order, and
+    /// machine (e.g. [EM_X86_64](abi::EM_X86_64)).
+    pub fn new(class: Class, endianness: E, e_machine: u16) -> Self {
+        CoreBuilder {
+            ehdr: ElfBuilder::new(class, endianness, abi::ET_CORE, e_machine).ehdr,
+            notes: Vec::new(),
+            regions: Vec::new(),
+        }
+    }
+
+    /// Set the OS ABI and its version.
+    ///
+    /// Default: [ELFOSABI_NONE](abi::ELFOSABI_NONE), version 0
+    pub fn osabi(&mut self, osabi: u8, abiversion: u8) -> &mut Self {
+        self.ehdr.osabi = osabi;
+        self.ehdr.abiversion = abiversion;
+        self
+    }
+
+    /// Append a note with the given name (including its NUL terminator, e.g.
+    /// [ELF_NOTE_CORE](abi::ELF_NOTE_CORE)), type, and contents to the PT_NOTE segment.
+    ///
+    /// Notes are written in the order they're added. Debuggers expect each thread's
+    /// NT_PRSTATUS note to be followed by that thread's other register notes (e.g.
+    /// NT_FPREGSET), with the crashing thread first.
+    pub fn add_note(
+        &mut self,
+        name: &[u8],
+        n_type: u32,
+        desc: &[u8],
+    ) -> Result<&mut Self, ParseError> {
+        encode_note(self.ehdr.endianness, name, n_type, desc, &mut self.notes)?;
+        Ok(self)
+    }
+
+    /// Append a thread's NT_PRSTATUS note.
+    ///
+    /// Returns [ParseError::TryFromIntError] if a field's value doesn't fit in an ELF32 core's
+    /// fields.
+    pub fn add_prstatus(&mut self, prstatus: &NotePrStatus<'_>) -> Result<&mut Self, ParseError> {
+        let mut desc = Vec::new();
+        prstatus.encode(self.ehdr.endianness, self.ehdr.class, &mut desc)?;
+        self.add_note(abi::ELF_NOTE_CORE, abi::NT_PRSTATUS as u32, &desc)
+    }
+
+    /// Append the process's NT_PRPSINFO note.
+    ///
+    /// The executable name and arguments are truncated to fit their 16 and 80 byte fields.
+    /// Returns [ParseError::TryFromIntError] if a field's value doesn't fit in an ELF32 core's
+    /// fields, which for some machines (e.g. i386 and ARM) includes 16-bit uids and gids.
+    pub fn add_prpsinfo(&mut self, prpsinfo: &NotePrPsInfo<'_>) -> Result<&mut Self, ParseError> {
+        let endian = self.ehdr.endianness;
+        let mut desc = vec![
+            prpsinfo.state,
+            prpsinfo.sname,
+            prpsinfo.zomb as u8,
+            prpsinfo.nice as u8,
+        ];
+        match self.ehdr.class {
+            Class::ELF32 => {
+                encode_u32(endian, &mut desc, u32::try_from(prpsinfo.flag)?);
+                let uid16 = matches!(
+                    self.ehdr.e_machine,
+                    abi::EM_386 | abi::EM_ARM | abi::EM_68K | abi::EM_SH
+                );
+                if uid16 {
+                    encode_u16(endian, &mut desc, u16::try_from(prpsinfo.uid)?);
+                    encode_u16(endian, &mut desc, u16::try_from(prpsinfo.gid)?);
+                } else {
+                    encode_u32(endian, &mut desc, prpsinfo.uid);
+                    encode_u32(endian, &mut desc, prpsinfo.gid);
+                }
+            }
+            Class::ELF64 => {
+                // padding to align the native long
+                encode_u32(endian, &mut desc, 0);
+                encode_u64(endian, &mut desc, prpsinfo.flag);
+                encode_u32(endian, &mut desc, prpsinfo.uid);
+                encode_u32(endian, &mut desc, prpsinfo.gid);
+            }
+        }
+        for id in [prpsinfo.pid, prpsinfo.ppid, prpsinfo.pgrp, prpsinfo.sid] {
+            encode_u32(endian, &mut desc, id as u32);
+        }
+        for (field, size) in [(prpsinfo.fname, 16), (prpsinfo.psargs, 80)] {
+            let len = field.len().min(size);
+            desc.extend_from_slice(&field[..len]);
+            desc.resize(desc.len() + size - len, 0);
+        }
+        self.add_note(abi::ELF_NOTE_CORE, abi::NT_PRPSINFO as u32, &desc)
+    }
+
+    /// Append the process's NT_AUXV auxiliary vector note. The terminating
+    /// [AT_NULL](abi::AT_NULL) entry is added if `entries` doesn't end with one.
+    pub fn add_auxv(&mut self, entries: &[AuxvEntry]) -> Result<&mut Self, ParseError> {
+        let mut desc = Vec::new();
+        for entry in entries {
+            entry.encode(self.ehdr.endianness, self.ehdr.class, &mut desc)?;
+        }
+        if entries.last().map(|entry| entry.a_type) != Some(abi::AT_NULL) {
+            let null = AuxvEntry {
+                a_type: abi::AT_NULL,
+                a_val: 0,
+            };
+            null.encode(self.ehdr.endianness, self.ehdr.class, &mut desc)?;
+        }
+        self.add_note(abi::ELF_NOTE_CORE, abi::NT_AUXV as u32, &desc)
+    }
+
+    /// Append a memory region of `memsz` bytes at `vaddr` with the given `PF_*` permissions,
+    /// whose contents are `data`.
+    ///
+    /// `data` can be shorter than the region (or empty) if only part of the region's memory
+    /// was dumped, as Linux does for e.g. unmodified file-backed mappings. Regions should be
+    /// added in ascending address order.
+    pub fn add_memory_region(
+        &mut self,
+        vaddr: u64,
+        p_flags: u32,
+        memsz: u64,
+        data: Vec<u8>,
+    ) -> &mut Self {
+        self.regions.push(Segment {
+            phdr: ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_offset: 0,
+                p_vaddr: vaddr,
+                p_paddr: 0,
+                p_filesz: data.len() as u64,
+                p_memsz: memsz.max(data.len() as u64),
+                p_flags,
+                p_align: CORE_PAGE_SIZE,
+            },
+            data,
+        });
+        self
+    }
+
+    /// Lay out the core dump and get it as an [ElfFile], which can then be written out with
+    /// [ElfFile::write].
+    ///
+    /// Returns [ParseError::IntegerOverflow] if the layout doesn't fit in the file.
+    pub fn build(&self) -> Result<ElfFile<E>, ParseError> {
+        let class = self.ehdr.class;
+        let word_align = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        let mut ehdr = self.ehdr;
+        let mut segments = Vec::with_capacity(self.regions.len() + 1);
+        if !self.notes.is_empty() {
+            segments.push(Segment {
+                phdr: ProgramHeader {
+                    p_type: abi::PT_NOTE,
+                    p_offset: 0,
+                    p_vaddr: 0,
+                    p_paddr: 0,
+                    p_filesz: self.notes.len() as u64,
+                    p_memsz: 0,
+                    p_flags: 0,
+                    p_align: 4,
+                },
+                data: self.notes.clone(),
+            });
+        }
+        segments.extend(self.regions.iter().cloned());
+
+        let mut offset = ehdr.e_ehsize as u64;
+        if !segments.is_empty() {
+            ehdr.e_phoff = align_up(offset, word_align)?;
+            let table_size = ProgramHeader::size_for(class) * segments.len();
+            offset = ehdr.e_phoff + table_size as u64;
+        }
+        for segment in segments.iter_mut() {
+            let phdr = &mut segment.phdr;
+            phdr.p_offset = align_up(offset, phdr.p_align)?;
+            offset = phdr
+                .p_offset
+                .checked_add(phdr.p_filesz)
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+
+        // Segment counts that don't fit in the file header need a null section to hold them
+        let mut sections = Vec::new();
+        if segments.len() >= abi::PN_XNUM as usize {
+            let mut null = Section::new("", abi::SHT_NULL, 0, Vec::new());
+            null.shdr.sh_addralign = 0;
+            null.shdr.sh_info = segments.len() as u32;
+            sections.push(null);
+            ehdr.e_shoff = align_up(offset, word_align)?;
+        }
+
+        Ok(ElfFile {
+            ehdr,
+            segments,
+            sections,
+        })
+    }
+}
+
+/// The size of a native word, which is the alignment of tables like the symbol table
+fn word_size(class: Class) -> u64 {
+    match class {
+        Class::ELF32 => 4,
+        Class::ELF64 => 8,
+    }
+}
+
+/// The number of bytes a section occupies in the file
+fn file_size(shdr: &SectionHeader) -> u64 {
+    if shdr.sh_type == abi::SHT_NOBITS {
+        0
+    } else {
+        shdr.sh_size
+    }
+}
+
+/// Bump `offset` up to the next offset which is congruent to `vaddr` modulo `align`, which must
+/// be a power of two
+fn congruent_offset(offset: u64, vaddr: u64, align: u64) -> Result<u64, ParseError> {
+    if align <= 1 {
+        return Ok(offset);
+    }
+    if !align.is_power_of_two() {
+        return Err(ParseError::UnexpectedAlignment(align as usize));
+    }
+    let delta = (vaddr % align + align - offset % align) % align;
+    offset.checked_add(delta).ok_or(ParseError::IntegerOverflow)
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::note::Note;
+    use crate::ElfBytes;
+
+    #[test]
+    fn build_executable() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let text = builder.add_section(
+            ".text",
+            abi::SHT_PROGBITS,
+            (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            vec![0xc3; 0x21],
+        );
+        builder.section_mut(text).unwrap().shdr.sh_addralign = 16;
+        let rodata = builder.add_section(
+            ".rodata",
+            abi::SHT_PROGBITS,
+            abi::SHF_ALLOC as u64,
+            b"hello\0".to_vec(),
+        );
+        builder.section_mut(rodata).unwrap().shdr.sh_addralign = 8;
+        let data = builder.add_section(
+            ".data",
+            abi::SHT_PROGBITS,
+            (abi::SHF_ALLOC | abi::SHF_WRITE) as u64,
+            vec![1, 2, 3, 4],
+        );
+        let bss =
+            builder.add_nobits_section(".bss", (abi::SHF_ALLOC | abi::SHF_WRITE) as u64, 0x100);
+        let comment = builder.add_section(
+            ".comment",
+            abi::SHT_PROGBITS,
+            (abi::SHF_MERGE | abi::SHF_STRINGS) as u64,
+            b"builder\0".to_vec(),
+        );
+        builder.add_segment(
+            abi::PT_LOAD,
+            abi::PF_R | abi::PF_X,
+            0x400000,
+            0x1000,
+            &[text, rodata],
+        );
+        builder.add_segment(
+            abi::PT_LOAD,
+            abi::PF_R | abi::PF_W,
+            0x600000,
+            0x1000,
+            &[data, bss],
+        );
+        builder.add_segment(abi::PT_GNU_STACK, abi::PF_R | abi::PF_W, 0, 16, &[]);
+        builder.entry(0x400000);
+
+        let image = builder
+            .build()
+            .expect("layout should succeed")
+            .to_bytes()
+            .expect("image should be writable");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&image).expect("Open image");
+        assert_eq!(file.ehdr.e_entry, 0x400000);
+        assert_eq!(file.ehdr.e_shnum, 7);
+        assert_eq!(file.ehdr.e_shstrndx, 6);
+
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("section table should be parseable");
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let names: Vec<&str> = shdrs
+            .iter()
+            .map(|shdr| strtab.get(shdr.sh_name as usize).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "",
+                ".text",
+                ".rodata",
+                ".data",
+                ".bss",
+                ".comment",
+                ".shstrtab"
+            ]
+        );
+
+        let text_shdr = shdrs.get(text).unwrap();
+        assert_eq!(text_shdr.sh_offset, 0x1000);
+        assert_eq!(text_shdr.sh_addr, 0x400000);
+        let rodata_shdr = shdrs.get(rodata).unwrap();
+        assert_eq!(rodata_shdr.sh_offset, 0x1028);
+        assert_eq!(rodata_shdr.sh_addr, 0x400028);
+        let data_shdr = shdrs.get(data).unwrap();
+        assert_eq!(data_shdr.sh_offset, 0x2000);
+        assert_eq!(data_shdr.sh_addr, 0x600000);
+        let bss_shdr = shdrs.get(bss).unwrap();
+        assert_eq!(bss_shdr.sh_addr, 0x600004);
+        assert_eq!(bss_shdr.sh_size, 0x100);
+        let comment_shdr = shdrs.get(comment).unwrap();
+        assert_eq!(comment_shdr.sh_addr, 0);
+        assert_eq!(comment_shdr.sh_offset, 0x2004);
+        assert_eq!(
+            file.section_data(&comment_shdr).unwrap().0,
+            b"builder\0".as_slice()
+        );
+
+        let phdrs: Vec<ProgramHeader> = file.segments().unwrap().iter().collect();
+        assert_eq!(
+            phdrs[0],
+            ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_offset: 0x1000,
+                p_vaddr: 0x400000,
+                p_paddr: 0x400000,
+                p_filesz: 0x2e,
+                p_memsz: 0x2e,
+                p_flags: abi::PF_R | abi::PF_X,
+                p_align: 0x1000,
+            }
+        );
+        assert_eq!(phdrs[1].p_offset, 0x2000);
+        assert_eq!(phdrs[1].p_filesz, 4);
+        assert_eq!(phdrs[1].p_memsz, 0x104);
+        assert_eq!(phdrs[2].p_type, abi::PT_GNU_STACK);
+        assert_eq!(phdrs[2].p_memsz, 0);
+        assert_eq!(file.segment_data(&phdrs[0]).unwrap()[..0x21], [0xc3; 0x21]);
+    }
+
+    #[test]
+    fn build_overlapping_segments() {
+        let mut builder = ElfBuilder::new(Class::ELF32, BigEndian, abi::ET_DYN, abi::EM_PPC);
+        let note = builder.add_section(
+            ".note.test",
+            abi::SHT_NOTE,
+            abi::SHF_ALLOC as u64,
+            vec![0; 16],
+        );
+        builder.section_mut(note).unwrap().shdr.sh_addralign = 4;
+        builder.add_segment(abi::PT_LOAD, abi::PF_R, 0x10000, 0x10000, &[note]);
+        builder.add_segment(abi::PT_NOTE, abi::PF_R, 0, 4, &[note]);
+
+        let elf_file = builder.build().expect("layout should succeed");
+        assert_eq!(elf_file.ehdr.e_phoff, 52);
+        let (load, note) = (elf_file.segments[0].phdr, elf_file.segments[1].phdr);
+        assert_eq!(load.p_offset, 0x10000);
+        assert_eq!(load.p_vaddr, 0x10000);
+        assert_eq!(note.p_offset, load.p_offset);
+        assert_eq!(note.p_vaddr, load.p_vaddr);
+        assert_eq!(note.p_filesz, 16);
+
+        let image = elf_file.to_bytes().expect("image should be writable");
+        let file = ElfBytes::<BigEndian>::minimal_parse(&image).expect("Open image");
+        assert_eq!(file.ehdr.class, Class::ELF32);
+        assert_eq!(file.ehdr.e_machine, abi::EM_PPC);
+        assert_eq!(file.segments().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn build_without_segments() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_REL, abi::EM_RISCV);
+        builder.flags(abi::EF_RISCV_FLOAT_ABI_DOUBLE);
+        builder.add_section(".text", abi::SHT_PROGBITS, 0, vec![0x13, 0, 0, 0]);
+
+        let image = builder
+            .build()
+            .expect("layout should succeed")
+            .to_bytes()
+            .expect("image should be writable");
+        let file = ElfBytes::<LittleEndian>::minimal_parse(&image).expect("Open image");
+        assert!(file.segments().is_none());
+        assert_eq!(file.ehdr.e_phoff, 0);
+        assert_eq!(file.ehdr.e_flags, abi::EF_RISCV_FLOAT_ABI_DOUBLE);
+        let shdr = file
+            .section_header_by_name(".text")
+            .expect("section table should be parseable")
+            .expect("should have a .text section");
+        assert_eq!(shdr.sh_offset, 64);
+    }
+
+    #[test]
+    fn build_bad_segment_section_index_errors() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        builder.add_section(".text", abi::SHT_PROGBITS, 0, vec![]);
+        builder.add_segment(abi::PT_LOAD, abi::PF_R, 0, 0x1000, &[2]);
+        let err = builder.build().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(2)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn build_huge_nobits_section_errors() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let bss =
+            builder.add_nobits_section(".bss", (abi::SHF_ALLOC | abi::SHF_WRITE) as u64, u64::MAX);
+        builder.add_segment(
+            abi::PT_LOAD,
+            abi::PF_R | abi::PF_W,
+            0x600000,
+            0x1000,
+            &[bss],
+        );
+        let err = builder.build().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn build_bad_segment_alignment_errors() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let text = builder.add_section(".text", abi::SHT_PROGBITS, 0, vec![0xc3]);
+        builder.add_segment(abi::PT_LOAD, abi::PF_R, 0x401001, u64::MAX, &[text]);
+        let err = builder.build().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnexpectedAlignment(_)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // The largest power of two is fine
+        builder.segments[0].phdr.p_align = 1 << 63;
+        let elf_file = builder.build().expect("layout should succeed");
+        let phdr = elf_file.segments[0].phdr;
+        assert_eq!(phdr.p_offset % (1 << 63), 0x401001);
+    }
+
+    #[test]
+    fn build_core_from_parsed_core() {
+        let file_data = std::fs::read("sample-objects/core.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let phdrs: Vec<ProgramHeader> = file.segments().unwrap().iter().collect();
+        let notes: Vec<Note<'_>> = file.segment_data_as_notes(&phdrs[0]).unwrap().collect();
+
+        let mut builder = CoreBuilder::new(Class::ELF64, AnyEndian::Little, abi::EM_X86_64);
+        for note in notes.iter() {
+            match note {
+                Note::PrStatus(prstatus) => builder.add_prstatus(prstatus).unwrap(),
+                Note::PrPsInfo(prpsinfo) => builder.add_prpsinfo(prpsinfo).unwrap(),
+                Note::Auxv(auxv) => {
+                    let entries: Vec<AuxvEntry> = auxv.iter(AnyEndian::Little).collect();
+                    builder.add_auxv(&entries).unwrap()
+                }
+                _ => &mut builder,
+            };
+        }
+        for phdr in phdrs.iter().skip(1) {
+            builder.add_memory_region(
+                phdr.p_vaddr,
+                phdr.p_flags,
+                phdr.p_memsz,
+                file.segment_data(phdr).unwrap().to_vec(),
+            );
+        }
+
+        let image = builder
+            .build()
+            .expect("layout should succeed")
+            .to_bytes()
+            .expect("core should be writable");
+        let core = ElfBytes::<AnyEndian>::minimal_parse(&image).expect("Open core");
+        assert_eq!(core.ehdr.e_type, abi::ET_CORE);
+        assert_eq!(core.ehdr.e_machine, abi::EM_X86_64);
+        assert!(core.section_headers().is_none());
+
+        let core_phdrs: Vec<ProgramHeader> = core.segments().unwrap().iter().collect();
+        assert_eq!(core_phdrs.len(), phdrs.len());
+        assert_eq!(core_phdrs[0].p_type, abi::PT_NOTE);
+        for (core_phdr, phdr) in core_phdrs.iter().zip(phdrs.iter()).skip(1) {
+            assert_eq!(
+                (core_phdr.p_vaddr, core_phdr.p_filesz, core_phdr.p_memsz),
+                (phdr.p_vaddr, phdr.p_filesz, phdr.p_memsz)
+            );
+            assert_eq!(core_phdr.p_offset % 0x1000, 0);
+            assert_eq!(
+                core.segment_data(core_phdr).unwrap(),
+                file.segment_data(phdr).unwrap()
+            );
+        }
+
+        // All of the notes we know how to write come back out the same
+        let core_notes: Vec<Note<'_>> = core
+            .segment_data_as_notes(&core_phdrs[0])
+            .unwrap()
+            .collect();
+        let known: Vec<&Note<'_>> = notes
+            .iter()
+            .filter(|note| matches!(note, Note::PrStatus(_) | Note::PrPsInfo(_) | Note::Auxv(_)))
+            .collect();
+        assert_eq!(core_notes.len(), 3);
+        assert_eq!(core_notes.iter().collect::<Vec<_>>(), known);
+    }
+
+    #[test]
+    fn build_core_elf32_prpsinfo() {
+        let prpsinfo = NotePrPsInfo {
+            state: 0,
+            sname: b'R',
+            zomb: false,
+            nice: -1,
+            flag: 0x400600,
+            uid: 1000,
+            gid: 1000,
+            pid: 42,
+            ppid: 1,
+            pgrp: 42,
+            sid: 42,
+            fname: b"a-very-long-executable-name",
+            psargs: b"./a.out",
+        };
+        for (e_machine, size) in [(abi::EM_386, 124), (abi::EM_PPC, 128)] {
+            let mut builder = CoreBuilder::new(Class::ELF32, BigEndian, e_machine);
+            builder.add_prpsinfo(&prpsinfo).expect("should encode");
+            let image = builder.build().unwrap().to_bytes().unwrap();
+            let core = ElfBytes::<BigEndian>::minimal_parse(&image).expect("Open core");
+            let phdr = core.segments().unwrap().get(0).unwrap();
+            assert_eq!(phdr.p_filesz, 20 + size);
+            let note = core.segment_data_as_notes(&phdr).unwrap().next().unwrap();
+            match note {
+                Note::PrPsInfo(parsed) => {
+                    assert_eq!(parsed.fname, b"a-very-long-exec");
+                    assert_eq!(
+                        NotePrPsInfo {
+                            fname: prpsinfo.fname,
+                            ..parsed
+                        },
+                        prpsinfo
+                    );
+                }
+                note => panic!("Unexpected note: {note:?}"),
+            }
+        }
+
+        let mut builder = CoreBuilder::new(Class::ELF32, BigEndian, abi::EM_ARM);
+        let err = builder
+            .add_prpsinfo(&NotePrPsInfo {
+                uid: 0x10000,
+                ..prpsinfo
+            })
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::TryFromIntError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    fn symbol(name: &str, bind: u8, symtype: u8, shndx: u16, value: u64) -> SymbolSpec {
+        SymbolSpec {
+            name: name.to_string(),
+            bind,
+            symtype,
+            visibility: abi::STV_DEFAULT,
+            shndx,
+            value,
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn build_relocatable_for_custom_machine() {
+        // A machine which isn't in the gABI's EM table, with its own relocation types
+        const EM_CUSTOM: u16 = 0x5641;
+        const R_CUSTOM_ABS32: u32 = 0x41;
+        const R_CUSTOM_CALL: u32 = 0x42;
+
+        for class in [Class::ELF32, Class::ELF64] {
+            let mut builder = ElfBuilder::new(class, LittleEndian, abi::ET_REL, EM_CUSTOM);
+            let text = builder.add_section(
+                ".text",
+                abi::SHT_PROGBITS,
+                (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+                vec![0; 16],
+            );
+            let data = builder.add_section(
+                ".data",
+                abi::SHT_PROGBITS,
+                (abi::SHF_ALLOC | abi::SHF_WRITE) as u64,
+                vec![0; 8],
+            );
+            let symtab = builder
+                .add_symbol_table(&[
+                    symbol("", abi::STB_LOCAL, abi::STT_SECTION, data as u16, 0),
+                    symbol("main", abi::STB_GLOBAL, abi::STT_FUNC, text as u16, 0),
+                    symbol("puts", abi::STB_GLOBAL, abi::STT_NOTYPE, abi::SHN_UNDEF, 0),
+                ])
+                .expect("symbols should encode");
+            let rela_text = builder
+                .add_rela_section(
+                    text,
+                    symtab,
+                    &[
+                        Rela {
+                            r_offset: 4,
+                            r_sym: 3,
+                            r_type: R_CUSTOM_CALL,
+                            r_addend: -4,
+                        },
+                        Rela {
+                            r_offset: 8,
+                            r_sym: 1,
+                            r_type: R_CUSTOM_ABS32,
+                            r_addend: 4,
+                        },
+                    ],
+                )
+                .expect("relocations should encode");
+            assert_eq!((symtab, rela_text), (3, 5));
+
+            let image = builder
+                .build()
+                .expect("layout should succeed")
+                .to_bytes()
+                .expect("object should be writable");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&image).expect("Open object");
+            assert_eq!(file.ehdr.e_machine, EM_CUSTOM);
+            assert_eq!(file.ehdr.e_type, abi::ET_REL);
+
+            let symtab_shdr = file.section_header_by_name(".symtab").unwrap().unwrap();
+            assert_eq!(symtab_shdr.sh_info, 2);
+            let (symbols, strtab) = file.symbol_table().unwrap().unwrap();
+            let symbols: Vec<(&str, u8, u16)> = symbols
+                .iter()
+                .map(|sym| {
+                    let name = strtab.get(sym.st_name as usize).unwrap();
+                    (name, sym.st_bind(), sym.st_shndx)
+                })
+                .collect();
+            assert_eq!(
+                symbols,
+                [
+                    ("", abi::STB_LOCAL, 0),
+                    ("", abi::STB_LOCAL, 2),
+                    ("main", abi::STB_GLOBAL, 1),
+                    ("puts", abi::STB_GLOBAL, 0),
+                ]
+            );
+
+            let shdr = file.section_header_by_name(".rela.text").unwrap().unwrap();
+            assert_eq!((shdr.sh_link, shdr.sh_info), (3, 1));
+            let relas: Vec<(u64, u32, u32, i64)> = file
+                .section_data_as_relas(&shdr)
+                .unwrap()
+                .map(|rela| (rela.r_offset, rela.r_sym, rela.r_type, rela.r_addend))
+                .collect();
+            assert_eq!(
+                relas,
+                [(4, 3, R_CUSTOM_CALL, -4), (8, 1, R_CUSTOM_ABS32, 4)]
+            );
+        }
+    }
+
+    #[test]
+    fn build_rel_section() {
+        let mut builder = ElfBuilder::new(Class::ELF32, BigEndian, abi::ET_REL, abi::EM_PPC);
+        let text = builder.add_section(".text", abi::SHT_PROGBITS, 0, vec![0; 8]);
+        let symtab = builder
+            .add_symbol_table(&[symbol("f", abi::STB_GLOBAL, abi::STT_FUNC, 1, 0)])
+            .unwrap();
+        let rel = Rel {
+            r_offset: 4,
+            r_sym: 1,
+            r_type: 0x100,
+        };
+        let err = builder
+            .add_rel_section(text, symtab, std::slice::from_ref(&rel))
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+
+        let rel = Rel { r_type: 1, ..rel };
+        let index = builder
+            .add_rel_section(text, symtab, std::slice::from_ref(&rel))
+            .unwrap();
+        let image = builder.build().unwrap().to_bytes().unwrap();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&image).expect("Open object");
+        let shdr = file.section_headers().unwrap().get(index).unwrap();
+        assert_eq!(shdr.sh_type, abi::SHT_REL);
+        let rels: Vec<Rel> = file.section_data_as_rels(&shdr).unwrap().collect();
+        assert_eq!(rels, [rel]);
+
+        let err = builder
+            .add_rel_section(7, symtab, &[])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(7)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn build_symbol_table_locals_first() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_REL, abi::EM_X86_64);
+        let err = builder
+            .add_symbol_table(&[
+                symbol("g", abi::STB_GLOBAL, abi::STT_FUNC, abi::SHN_ABS, 0),
+                symbol("l", abi::STB_LOCAL, abi::STT_FUNC, abi::SHN_ABS, 0),
+            ])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(2)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // All locals
+        let symtab = builder
+            .add_symbol_table(&[symbol("l", abi::STB_LOCAL, abi::STT_FUNC, abi::SHN_ABS, 0)])
+            .unwrap();
+        assert_eq!(builder.section_mut(symtab).unwrap().shdr.sh_info, 2);
+    }
+}
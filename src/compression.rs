@@ -1,14 +1,20 @@
 //! Parsing [CompressionHeader] from compressed ELF sections
 //!
-//! Note: This library does not provide any decompression functionality, but
+//! Note: By default, this library does not provide any decompression functionality, but
 //! does expose parsed ELF compression headers alongside the raw compressed data.
 //!
 //! It is up to users of the library to choose the decompression library of
-//! their choice when dealing with compressed section contents.
+//! their choice when dealing with compressed section contents. Alternatively, the
+//! `zlib` and `zstd` cargo features enable [decompress] for [ELFCOMPRESS_ZLIB](crate::abi::ELFCOMPRESS_ZLIB)
+//! and [ELFCOMPRESS_ZSTD](crate::abi::ELFCOMPRESS_ZSTD) sections using the pure-rust `miniz_oxide`
+//! and `ruzstd` crates, respectively.
 use crate::endian::EndianParse;
 use crate::file::Class;
 use crate::parse::{ParseAt, ParseError};
 
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use crate::abi;
+
 /// C-style 32-bit ELF Compression Header definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -33,6 +39,7 @@ pub struct Elf64_Chdr {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CompressionHeader {
     pub ch_type: u32,
     pub ch_size: u64,
@@ -73,6 +80,45 @@ impl ParseAt for CompressionHeader {
     }
 }
 
+/// Decompress the `compressed` section data described by the given [CompressionHeader].
+///
+/// Returns [ParseError::UnsupportedCompression] if the `ch_type` isn't one whose support has
+/// been enabled, and [ParseError::DecompressionError] if the data is corrupt or doesn't
+/// decompress to exactly `ch_size` bytes.
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+pub fn decompress(chdr: &CompressionHeader, compressed: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let size: usize = chdr.ch_size.try_into()?;
+    let data = match chdr.ch_type {
+        #[cfg(feature = "zlib")]
+        abi::ELFCOMPRESS_ZLIB => {
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(compressed, size)
+                .map_err(|_| ParseError::DecompressionError)?
+        }
+        #[cfg(feature = "zstd")]
+        abi::ELFCOMPRESS_ZSTD => {
+            use std::io::Read;
+            let decoder = ruzstd::StreamingDecoder::new(compressed)
+                .map_err(|_| ParseError::DecompressionError)?;
+            // Read one byte past the expected size so that oversized contents get detected.
+            // ch_size comes from the file, so let the buffer grow rather than preallocating it.
+            let mut data = Vec::new();
+            decoder
+                .take((size as u64).saturating_add(1))
+                .read_to_end(&mut data)
+                .map_err(|_| ParseError::DecompressionError)?;
+            data
+        }
+        ch_type => {
+            return Err(ParseError::UnsupportedCompression(ch_type));
+        }
+    };
+
+    if data.len() != size {
+        return Err(ParseError::DecompressionError);
+    }
+    Ok(data)
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
@@ -150,4 +196,112 @@ mod parse_tests {
     fn parse_chdr64_msb_fuzz_too_short() {
         test_parse_fuzz_too_short::<_, CompressionHeader>(BigEndian, Class::ELF64);
     }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn decompress_zlib() {
+        // zlib stream for b"hello hello hello\n"
+        let compressed = [
+            0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0x40, 0x90, 0x5c, 0x00, 0x40,
+            0xb5, 0x06, 0x87,
+        ];
+        let chdr = CompressionHeader {
+            ch_type: crate::abi::ELFCOMPRESS_ZLIB,
+            ch_size: 18,
+            ch_addralign: 1,
+        };
+        assert_eq!(
+            decompress(&chdr, &compressed).expect("should decompress"),
+            b"hello hello hello\n"
+        );
+
+        // The header's size doesn't match the decompressed contents
+        let chdr = CompressionHeader {
+            ch_size: 17,
+            ..chdr
+        };
+        let err = decompress(&chdr, &compressed).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+
+        // Corrupted stream
+        let chdr = CompressionHeader {
+            ch_size: 18,
+            ..chdr
+        };
+        let err = decompress(&chdr, &compressed[..8]).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn decompress_zstd() {
+        // zstd frame for b"hello hello hello\n"
+        let compressed = [
+            0x28, 0xb5, 0x2f, 0xfd, 0x04, 0x58, 0x6d, 0x00, 0x00, 0x38, 0x68, 0x65, 0x6c, 0x6c,
+            0x6f, 0x20, 0x0a, 0x01, 0x00, 0x31, 0x4a, 0x11, 0xa3, 0xaa, 0x74, 0xce,
+        ];
+        let chdr = CompressionHeader {
+            ch_type: crate::abi::ELFCOMPRESS_ZSTD,
+            ch_size: 18,
+            ch_addralign: 1,
+        };
+        assert_eq!(
+            decompress(&chdr, &compressed).expect("should decompress"),
+            b"hello hello hello\n"
+        );
+
+        // The header's size doesn't match the decompressed contents
+        let chdr = CompressionHeader {
+            ch_size: 17,
+            ..chdr
+        };
+        let err = decompress(&chdr, &compressed).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+
+        // Not a zstd frame
+        let chdr = CompressionHeader {
+            ch_size: 18,
+            ..chdr
+        };
+        let err = decompress(&chdr, b"hello hello hello\n").expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+
+        // A huge ch_size doesn't get allocated up front
+        let chdr = CompressionHeader {
+            ch_size: 0x3fff_ffff_ffff_ffef,
+            ..chdr
+        };
+        let err = decompress(&chdr, &compressed).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    #[test]
+    fn decompress_unsupported_type() {
+        let chdr = CompressionHeader {
+            ch_type: 0x60000000,
+            ch_size: 18,
+            ch_addralign: 1,
+        };
+        let err = decompress(&chdr, &[]).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedCompression(0x60000000)),
+            "Unexpected Error type found: {err}"
+        );
+    }
 }
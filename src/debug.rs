@@ -0,0 +1,318 @@
+//! Finding DWARF debug sections: `.debug_*` and GNU-style compressed `.zdebug_*` sections
+//!
+//! DWARF consumers usually want to look up a debug section by its canonical name (e.g.
+//! `.debug_info`) regardless of how the producer stored it. Debug sections can be stored
+//! uncompressed, compressed with [SHF_COMPRESSED](crate::abi::SHF_COMPRESSED) under their
+//! normal names, or compressed under the legacy GNU `.zdebug_*` names with a `ZLIB` header.
+//!
+//! The `zlib` and `zstd` cargo features enable [DebugSection::decompressed] for getting at the
+//! uncompressed contents of any of these.
+//!
+//! Example for finding the DWARF line number program section:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/zdebug.x86_64.o");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let line = file
+//!     .debug_section(".debug_line")
+//!     .expect("section table should be parseable")
+//!     .expect("file should have a .debug_line section");
+//! assert_eq!(line.name, ".zdebug_line");
+//! assert_eq!(line.id(), "line");
+//! assert_eq!(line.uncompressed_size(), 79);
+//! ```
+use crate::abi;
+use crate::compression::CompressionHeader;
+use crate::endian::{BigEndian, EndianParse};
+use crate::file::Class;
+use crate::parse::{ParseAt, ParseError, ParsingIterator, ReadBytesExt};
+use crate::section::SectionHeader;
+use crate::string_table::StringTable;
+
+/// The magic bytes at the start of a GNU-style compressed `.zdebug_*` section's data
+pub const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
+
+/// Get a debug section's id: its name without the `.debug_` or `.zdebug_` prefix, e.g. "info"
+/// for both `.debug_info` and `.zdebug_info`.
+///
+/// Returns None if the name isn't a debug section name.
+pub fn debug_section_id(name: &str) -> Option<&str> {
+    let id = name
+        .strip_prefix(".debug_")
+        .or_else(|| name.strip_prefix(".zdebug_"))?;
+    if id.is_empty() {
+        return None;
+    }
+    Some(id)
+}
+
+/// Describes how a [DebugSection]'s data is compressed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugCompression {
+    /// The section data is not compressed
+    None,
+    /// The section has the [SHF_COMPRESSED](abi::SHF_COMPRESSED) flag set and its data is
+    /// described by the given ELF [CompressionHeader]
+    Elf(CompressionHeader),
+    /// The section is a GNU-style `.zdebug_*` section holding a zlib stream which decompresses
+    /// to the given size
+    Gnu(u64),
+}
+
+/// A DWARF debug section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugSection<'data> {
+    /// The section's name as it appears in the object, e.g. `.zdebug_info`
+    pub name: &'data str,
+    /// The section's header
+    pub shdr: SectionHeader,
+    /// How the section data is compressed (if at all)
+    pub compression: DebugCompression,
+    /// The section's data, without any compression header
+    pub data: &'data [u8],
+}
+
+impl<'data> DebugSection<'data> {
+    /// Parse a debug section from its name, header, and the section's bytes from the file.
+    pub fn parse<E: EndianParse>(
+        endian: E,
+        class: Class,
+        name: &'data str,
+        shdr: SectionHeader,
+        section_data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let compression = if shdr.sh_flags & abi::SHF_COMPRESSED as u64 != 0 {
+            let chdr = CompressionHeader::parse_at(endian, class, &mut offset, section_data)?;
+            DebugCompression::Elf(chdr)
+        } else if name.starts_with(".zdebug_") && section_data.starts_with(ZDEBUG_MAGIC) {
+            offset = ZDEBUG_MAGIC.len();
+            // The uncompressed size is always big-endian, regardless of the object's endianness
+            let size = BigEndian.parse_u64_at(&mut offset, section_data)?;
+            DebugCompression::Gnu(size)
+        } else {
+            DebugCompression::None
+        };
+
+        Ok(DebugSection {
+            name,
+            shdr,
+            compression,
+            data: section_data.get_bytes(offset..section_data.len())?,
+        })
+    }
+
+    /// The section's id: its name without the `.debug_` or `.zdebug_` prefix, e.g. "info".
+    pub fn id(&self) -> &'data str {
+        debug_section_id(self.name).unwrap_or(self.name)
+    }
+
+    /// Returns true if this section has the given canonical name (e.g. `.debug_info`), or is
+    /// a `.zdebug_*` section for it.
+    pub fn is_named(&self, name: &str) -> bool {
+        debug_section_id(name) == Some(self.id())
+    }
+
+    /// The size of the section's data once decompressed.
+    pub fn uncompressed_size(&self) -> u64 {
+        match &self.compression {
+            DebugCompression::None => self.data.len() as u64,
+            DebugCompression::Elf(chdr) => chdr.ch_size,
+            DebugCompression::Gnu(size) => *size,
+        }
+    }
+
+    /// Get the section's data, decompressing it if it is compressed.
+    ///
+    /// Uncompressed section data is returned as-is, without being copied. Returns
+    /// [ParseError::UnsupportedCompression] for compression types whose cargo feature
+    /// (`zlib` or `zstd`) isn't enabled. GNU-style `.zdebug_*` sections need the `zlib` feature.
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn decompressed(&self) -> Result<std::borrow::Cow<'data, [u8]>, ParseError> {
+        match &self.compression {
+            DebugCompression::None => Ok(std::borrow::Cow::Borrowed(self.data)),
+            DebugCompression::Elf(chdr) => Ok(std::borrow::Cow::Owned(
+                crate::compression::decompress(chdr, self.data)?,
+            )),
+            DebugCompression::Gnu(size) => {
+                let chdr = CompressionHeader {
+                    ch_type: abi::ELFCOMPRESS_ZLIB,
+                    ch_size: *size,
+                    ch_addralign: 1,
+                };
+                Ok(std::borrow::Cow::Owned(crate::compression::decompress(
+                    &chdr, self.data,
+                )?))
+            }
+        }
+    }
+}
+
+/// Iterator over the DWARF debug sections in an object's section header table.
+///
+/// See [ElfBytes::debug_sections](crate::ElfBytes::debug_sections).
+#[derive(Debug)]
+pub struct DebugSectionIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    shdrs: ParsingIterator<'data, E, SectionHeader>,
+    strtab: StringTable<'data>,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> DebugSectionIterator<'data, E> {
+    pub(crate) fn new(
+        endian: E,
+        class: Class,
+        shdrs: ParsingIterator<'data, E, SectionHeader>,
+        strtab: StringTable<'data>,
+        data: &'data [u8],
+    ) -> Self {
+        DebugSectionIterator {
+            endian,
+            class,
+            shdrs,
+            strtab,
+            data,
+        }
+    }
+
+    fn parse_section(
+        &self,
+        name: &'data str,
+        shdr: SectionHeader,
+    ) -> Result<DebugSection<'data>, ParseError> {
+        let buf = if shdr.sh_type == abi::SHT_NOBITS {
+            &[]
+        } else {
+            let (start, end) = shdr.get_data_range()?;
+            self.data.get_bytes(start..end)?
+        };
+        DebugSection::parse(self.endian, self.class, name, shdr, buf)
+    }
+
+    /// Find the debug section with the given canonical name. Only the matching section gets
+    /// parsed, so malformed sections with other names don't cause errors.
+    pub(crate) fn find_named(
+        mut self,
+        name: &str,
+    ) -> Result<Option<DebugSection<'data>>, ParseError> {
+        let id = match debug_section_id(name) {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        while let Some(shdr) = self.shdrs.next() {
+            // A section whose name can't be read can't be the one we're looking for
+            let section_name = match self.strtab.get(shdr.sh_name as usize) {
+                Ok(section_name) => section_name,
+                Err(_) => continue,
+            };
+            if debug_section_id(section_name) == Some(id) {
+                return self.parse_section(section_name, shdr).map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for DebugSectionIterator<'data, E> {
+    type Item = Result<DebugSection<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let shdr = self.shdrs.next()?;
+            let name = match self.strtab.get(shdr.sh_name as usize) {
+                Ok(name) => name,
+                Err(err) => return Some(Err(err)),
+            };
+            if debug_section_id(name).is_some() {
+                return Some(self.parse_section(name, shdr));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+
+    fn debug_shdr(sh_flags: u64, sh_size: u64) -> SectionHeader {
+        SectionHeader {
+            sh_name: 0,
+            sh_type: abi::SHT_PROGBITS,
+            sh_flags,
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 1,
+            sh_entsize: 0,
+        }
+    }
+
+    #[test]
+    fn debug_section_ids() {
+        assert_eq!(debug_section_id(".debug_info"), Some("info"));
+        assert_eq!(debug_section_id(".zdebug_info"), Some("info"));
+        assert_eq!(debug_section_id(".debug_info.dwo"), Some("info.dwo"));
+        assert_eq!(debug_section_id(".debug_"), None);
+        assert_eq!(debug_section_id(".text"), None);
+    }
+
+    #[test]
+    fn parse_uncompressed() {
+        let data = [1u8, 2, 3];
+        let section = DebugSection::parse(
+            LittleEndian,
+            Class::ELF64,
+            ".debug_str",
+            debug_shdr(0, 3),
+            &data,
+        )
+        .expect("should parse");
+        assert_eq!(section.compression, DebugCompression::None);
+        assert_eq!(section.data, [1, 2, 3]);
+        assert_eq!(section.uncompressed_size(), 3);
+        assert!(section.is_named(".debug_str"));
+        assert!(!section.is_named(".debug_info"));
+    }
+
+    #[test]
+    fn parse_zdebug_header() {
+        let data = [b'Z', b'L', b'I', b'B', 0, 0, 0, 0, 0, 0, 1, 0, 0x78, 0x9c];
+        let section = DebugSection::parse(
+            LittleEndian,
+            Class::ELF64,
+            ".zdebug_info",
+            debug_shdr(0, 14),
+            &data,
+        )
+        .expect("should parse");
+        assert_eq!(section.compression, DebugCompression::Gnu(0x100));
+        assert_eq!(section.data, [0x78, 0x9c]);
+        assert!(section.is_named(".debug_info"));
+        assert!(section.is_named(".zdebug_info"));
+    }
+
+    #[test]
+    fn parse_zdebug_truncated_header_errors() {
+        let data = [b'Z', b'L', b'I', b'B', 0, 0, 0];
+        let err = DebugSection::parse(
+            LittleEndian,
+            Class::ELF64,
+            ".zdebug_info",
+            debug_shdr(0, 7),
+            &data,
+        )
+        .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Parsing the `.gnu_debuglink` section and matching separate debug files to stripped objects
+//!
+//! Stripped binaries commonly have their debug info split out into a separate file. The two
+//! files can be paired back up either by their GNU build-id notes (if present in both), or by
+//! the CRC32 checksum of the debug file which gets recorded in the stripped binary's
+//! `.gnu_debuglink` section.
+//!
+//! (see: <https://sourceware.org/gdb/current/onlinedocs/gdb.html/Separate-Debug-Files.html>)
+use crate::endian::EndianParse;
+use crate::parse::ParseError;
+use core::str::from_utf8;
+
+/// Contents of a `.gnu_debuglink` section.
+///
+/// The section contains a NUL-terminated file name for the separate debug file, zero-padded
+/// to 4-byte alignment, followed by a 4-byte CRC32 checksum of the debug file's contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugLink<'data> {
+    /// The file name (not the path) of the separate debug file, without the terminating NUL.
+    pub file_name: &'data [u8],
+    /// The CRC32 checksum of the full contents of the separate debug file.
+    pub crc: u32,
+}
+
+impl<'data> DebugLink<'data> {
+    /// Parse the contents of a `.gnu_debuglink` section.
+    pub fn parse<E: EndianParse>(endian: E, data: &'data [u8]) -> Result<Self, ParseError> {
+        let name_len = data
+            .iter()
+            .position(|&b| b == 0u8)
+            .ok_or(ParseError::StringTableMissingNul(0))?;
+        let file_name = data.split_at(name_len).0;
+
+        // The name's NUL terminator is followed by zero padding up to 4-byte alignment
+        let mut offset = name_len.checked_add(4).ok_or(ParseError::IntegerOverflow)? & !3;
+        let crc = endian.parse_u32_at(&mut offset, data)?;
+        Ok(DebugLink { file_name, crc })
+    }
+
+    /// Parses the debug file name bytes as a utf8 sequence.
+    pub fn file_name_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.file_name)?)
+    }
+
+    /// Returns true if the CRC32 of the given candidate debug file contents matches the
+    /// CRC recorded in the debuglink.
+    pub fn verify(&self, debug_file_data: &[u8]) -> bool {
+        gnu_debuglink_crc32(0, debug_file_data) == self.crc
+    }
+
+    /// Like [DebugLink::verify], but computes the CRC32 incrementally over the contents read
+    /// from `reader`, so the candidate debug file doesn't need to be read into memory all at once.
+    #[cfg(feature = "std")]
+    pub fn verify_reader<R: std::io::Read>(&self, mut reader: R) -> Result<bool, ParseError> {
+        let mut buf = [0u8; 8192];
+        let mut crc = 0;
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => return Ok(crc == self.crc),
+                Ok(len) => crc = gnu_debuglink_crc32(crc, &buf[..len]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(ParseError::IOError(err)),
+            }
+        }
+    }
+}
+
+/// Generated lookup table for the CRC32 (IEEE 802.3) polynomial used by `.gnu_debuglink`.
+const CRC32_TABLE: [u32; 256] = make_crc32_table();
+
+const fn make_crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb88320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+/// Calculate the checksum recorded in `.gnu_debuglink` sections.
+///
+/// This mirrors gdb's `gnu_debuglink_crc32()`, so it can be computed incrementally over chunks
+/// of a file by passing in the previous result as `crc`. Pass zero as the initial `crc`.
+pub fn gnu_debuglink_crc32(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &b in data {
+        crc = CRC32_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// The outcome of comparing a stripped object with a candidate separate debug file.
+///
+/// See [ElfBytes::matches_debug_file](crate::ElfBytes::matches_debug_file).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugFileMatch {
+    /// Both objects have GNU build-id notes and the build-ids are identical.
+    BuildId,
+    /// The stripped object's `.gnu_debuglink` CRC matches the contents of the debug file.
+    DebugLinkCrc,
+    /// The objects have different ELF classes (32-bit vs 64-bit).
+    ClassMismatch,
+    /// The objects are for different target machines (`e_machine`).
+    MachineMismatch((u16, u16)),
+    /// Both objects have GNU build-id notes, but they differ.
+    BuildIdMismatch,
+    /// The CRC recorded in the stripped object's `.gnu_debuglink` (first) differs from
+    /// the CRC computed over the debug file's contents (second).
+    DebugLinkCrcMismatch((u32, u32)),
+    /// Neither a pair of build-ids nor a `.gnu_debuglink` was found to compare.
+    NoIdentifiers,
+}
+
+impl DebugFileMatch {
+    /// Returns true if the debug file was positively identified as belonging to the stripped object.
+    pub fn is_match(&self) -> bool {
+        matches!(self, DebugFileMatch::BuildId | DebugFileMatch::DebugLinkCrc)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn crc32_check_value() {
+        // The standard CRC-32 check value over the ascii digits
+        assert_eq!(gnu_debuglink_crc32(0, b"123456789"), 0xCBF43926);
+        assert_eq!(gnu_debuglink_crc32(0, &[]), 0);
+    }
+
+    #[test]
+    fn crc32_incremental() {
+        let (first, second) = b"123456789".split_at(4);
+        let crc = gnu_debuglink_crc32(0, first);
+        assert_eq!(gnu_debuglink_crc32(crc, second), 0xCBF43926);
+    }
+
+    #[test]
+    fn parse_debuglink_lsb() {
+        let data = [
+            0x61, 0x62, 0x63, 0x00, // "abc\0"
+            0x78, 0x56, 0x34, 0x12, // crc
+        ];
+        let link = DebugLink::parse(LittleEndian, &data).expect("should parse");
+        assert_eq!(link.file_name, b"abc");
+        assert_eq!(link.file_name_str().unwrap(), "abc");
+        assert_eq!(link.crc, 0x12345678);
+    }
+
+    #[test]
+    fn parse_debuglink_msb_with_padding() {
+        let data = [
+            0x61, 0x62, 0x63, 0x64, 0x00, 0x00, 0x00, 0x00, // "abcd\0" + padding
+            0x12, 0x34, 0x56, 0x78, // crc
+        ];
+        let link = DebugLink::parse(BigEndian, &data).expect("should parse");
+        assert_eq!(link.file_name, b"abcd");
+        assert_eq!(link.crc, 0x12345678);
+    }
+
+    #[test]
+    fn verify_crc() {
+        let link = DebugLink {
+            file_name: b"abc.debug",
+            crc: 0xCBF43926,
+        };
+        assert!(link.verify(b"123456789"));
+        assert!(!link.verify(b"12345678"));
+        assert!(link
+            .verify_reader(&b"123456789"[..])
+            .expect("reading a slice shouldn't fail"));
+        assert!(!link
+            .verify_reader(&b""[..])
+            .expect("reading a slice shouldn't fail"));
+    }
+
+    #[test]
+    fn parse_debuglink_missing_nul_errors() {
+        let data = [0x61, 0x62, 0x63, 0x64];
+        let err = DebugLink::parse(LittleEndian, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(0)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_debuglink_truncated_crc_errors() {
+        let data = [0x61, 0x62, 0x63, 0x00, 0x78, 0x56];
+        let err = DebugLink::parse(LittleEndian, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((4, 8))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Demangling Rust and C++ symbol names
+//!
+//! Compilers encode a function's path and signature into its symbol name, which makes the raw
+//! names from a symbol table hard to read: `_ZN4core3fmt5write17h0123456789abcdefE`. This
+//! recognizes Rust's legacy and v0 (`_R`) manglings and the Itanium C++ ABI mangling (`_Z`), and
+//! leaves all other names as they are.
+//!
+//! This module is only available with the `demangle` cargo feature.
+//!
+//! Example for listing an object's demangled function names:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::abi;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let (symtab, strtab) = file
+//!     .symbol_table()
+//!     .expect("symtab should be parseable")
+//!     .expect("file should have a symtab");
+//! for sym in symtab.iter().filter(|sym| sym.st_symtype() == abi::STT_FUNC) {
+//!     let name = sym
+//!         .demangled_name(&strtab)
+//!         .expect("symbol name should be parseable");
+//!     println!("{:#x}: {name}", sym.st_value);
+//! }
+//! ```
+use std::borrow::Cow;
+
+/// Demangle a Rust or C++ symbol name.
+///
+/// Rust names are rendered without their trailing hash (e.g. `core::fmt::write`). Names which
+/// aren't mangled, or which fail to demangle, are returned unchanged and without being copied.
+pub fn demangle(name: &str) -> Cow<'_, str> {
+    if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+        return Cow::Owned(format!("{demangled:#}"));
+    }
+
+    if name.starts_with("_Z") {
+        if let Ok(symbol) = cpp_demangle::Symbol::new(name) {
+            if let Ok(demangled) = symbol.demangle(&cpp_demangle::DemangleOptions::default()) {
+                return Cow::Owned(demangled);
+            }
+        }
+    }
+
+    Cow::Borrowed(name)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn demangle_rust_legacy() {
+        assert_eq!(
+            demangle("_ZN4core3fmt5write17h0123456789abcdefE"),
+            "core::fmt::write"
+        );
+    }
+
+    #[test]
+    fn demangle_rust_v0() {
+        assert_eq!(
+            demangle("_RNvNtCs1234_7mycrate3foo3bar"),
+            "mycrate::foo::bar"
+        );
+    }
+
+    #[test]
+    fn demangle_cpp() {
+        assert_eq!(demangle("_ZN3foo3barEi"), "foo::bar(int)");
+        assert_eq!(
+            demangle("_ZNSt6vectorIiSaIiEE9push_backERKi"),
+            "std::vector<int, std::allocator<int> >::push_back(int const&)"
+        );
+    }
+
+    #[test]
+    fn demangle_leaves_other_names_alone() {
+        assert!(matches!(demangle("memset"), Cow::Borrowed("memset")));
+        assert!(matches!(demangle("_Zgarbage"), Cow::Borrowed("_Zgarbage")));
+        assert!(matches!(demangle(""), Cow::Borrowed("")));
+    }
+}
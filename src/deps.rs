@@ -0,0 +1,691 @@
+//! Resolving an object's shared library dependencies to files: [DependencyResolver]
+//!
+//! Like `ldd`, but without running the dynamic linker: the [DT_NEEDED](crate::abi::DT_NEEDED)
+//! entries of an object and, transitively, those of the libraries they name are looked up in
+//! the same places and order as glibc's dynamic linker does:
+//!
+//! 1. Names containing a `/` are used as paths as-is.
+//! 2. The [DT_RPATH](crate::abi::DT_RPATH) of the object needing the library and of the objects
+//!    which loaded it, unless the object needing the library has a
+//!    [DT_RUNPATH](crate::abi::DT_RUNPATH).
+//! 3. The directories added with [DependencyResolver::search_dir], which stand in for
+//!    `LD_LIBRARY_PATH`.
+//! 4. The DT_RUNPATH of the object needing the library.
+//! 5. The directories listed in the `ld.so.conf` files read with
+//!    [DependencyResolver::ld_so_conf], which stand in for the `ld.so.cache`.
+//! 6. The default library directories (`/lib`, `/usr/lib`, and their `lib64` counterparts
+//!    for 64-bit objects).
+//!
+//! Files which aren't ELF objects of the same class, byte order, and machine as the object
+//! being resolved are skipped, as are libraries which were already loaded under the same
+//! name, [DT_SONAME](crate::abi::DT_SONAME), or path. The result is a [DependencyTree] with
+//! every object in load order, from which the missing libraries and dependency cycles can be
+//! read off.
+//!
+//! This module is only available with the `std` cargo feature.
+//!
+//! ```
+//! use elf::deps::DependencyResolver;
+//!
+//! let tree = DependencyResolver::new()
+//!     .default_dirs(false)
+//!     .resolve("sample-objects/needed.x86_64.so")
+//!     .expect("object should be parseable");
+//!
+//! let missing: Vec<&str> = tree.missing().map(|(_, name)| name).collect();
+//! assert_eq!(missing, ["libm.so.6", "libc.so.6"]);
+//! assert!(tree.libraries().is_empty());
+//! ```
+use crate::dynamic::{SearchPath, SearchPathTokens};
+use crate::endian::AnyEndian;
+use crate::file::Class;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// How deeply `include` directives in `ld.so.conf` files get followed. This stops include
+/// loops, which ldconfig doesn't guard against either.
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Configures where shared libraries get looked up, and resolves an object's dependencies.
+///
+/// See the [module documentation](self) for the search order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyResolver {
+    search_dirs: Vec<PathBuf>,
+    conf_dirs: Vec<PathBuf>,
+    default_dirs: bool,
+    lib: Option<String>,
+    platform: Option<String>,
+}
+
+impl Default for DependencyResolver {
+    fn default() -> Self {
+        DependencyResolver {
+            search_dirs: Vec::new(),
+            conf_dirs: Vec::new(),
+            default_dirs: true,
+            lib: None,
+            platform: None,
+        }
+    }
+}
+
+impl DependencyResolver {
+    /// Create a resolver which only searches the DT_RPATH and DT_RUNPATH directories and the
+    /// default library directories.
+    pub fn new() -> Self {
+        DependencyResolver::default()
+    }
+
+    /// Add a directory to search after the DT_RPATH directories and before the DT_RUNPATH
+    /// ones, like an entry of `LD_LIBRARY_PATH`. Directories are searched in the order they're
+    /// added.
+    pub fn search_dir<P: Into<PathBuf>>(&mut self, dir: P) -> &mut Self {
+        self.search_dirs.push(dir.into());
+        self
+    }
+
+    /// Add the directories listed in the given `ld.so.conf` file, e.g. `/etc/ld.so.conf`, to
+    /// the ones searched after the DT_RUNPATH directories.
+    ///
+    /// `include` directives are followed, with relative patterns taken relative to the
+    /// including file's directory. Only the file name part of an included pattern may contain
+    /// `*` or `?` wildcards, as in the usual `include /etc/ld.so.conf.d/*.conf`. `hwcap`
+    /// directives are ignored.
+    ///
+    /// Returns [ParseError::IOError] if the file or one of the files it includes can't be read.
+    pub fn ld_so_conf<P: AsRef<Path>>(&mut self, path: P) -> Result<&mut Self, ParseError> {
+        self.read_ld_so_conf(path.as_ref(), 0)?;
+        Ok(self)
+    }
+
+    /// Search the default library directories after all others.
+    ///
+    /// Disable this to resolve against a sysroot or a fixed set of directories only.
+    ///
+    /// Default: true
+    pub fn default_dirs(&mut self, enabled: bool) -> &mut Self {
+        self.default_dirs = enabled;
+        self
+    }
+
+    /// The system's library directory name (e.g. `lib64`), used to expand `$LIB` in
+    /// DT_RPATH and DT_RUNPATH entries.
+    ///
+    /// Default: none, leaving `$LIB` unexpanded
+    pub fn lib(&mut self, lib: &str) -> &mut Self {
+        self.lib = Some(lib.to_string());
+        self
+    }
+
+    /// The system's platform name (e.g. `x86_64`), used to expand `$PLATFORM` in DT_RPATH and
+    /// DT_RUNPATH entries.
+    ///
+    /// Default: none, leaving `$PLATFORM` unexpanded
+    pub fn platform(&mut self, platform: &str) -> &mut Self {
+        self.platform = Some(platform.to_string());
+        self
+    }
+
+    /// Resolve the dependencies of the object at the given path, transitively.
+    ///
+    /// Libraries which can't be found are recorded as missing in the returned tree rather than
+    /// failing the resolution. Returns an error only if the object itself can't be read or
+    /// parsed.
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> Result<DependencyTree, ParseError> {
+        let path = path.as_ref();
+        let root = ParsedObject::read(path)?;
+        let target = (root.class, root.endianness, root.e_machine);
+
+        let mut objects = Vec::new();
+        let mut by_name = HashMap::new();
+        let mut by_path = HashMap::new();
+        let mut pending = VecDeque::new();
+
+        let rpath_chain = self.add_object(
+            path.to_path_buf(),
+            &root,
+            &[],
+            &mut objects,
+            &mut by_name,
+            &mut by_path,
+        );
+        pending.push_back((0, root, rpath_chain));
+
+        while let Some((index, object, rpath_chain)) = pending.pop_front() {
+            let runpath = object
+                .runpath
+                .as_deref()
+                .map(|runpath| self.expand(runpath, &objects[index].path))
+                .unwrap_or_default();
+            let mut dirs: Vec<&Path> = Vec::new();
+            if object.runpath.is_none() {
+                dirs.extend(rpath_chain.iter().map(PathBuf::as_path));
+            }
+            dirs.extend(self.search_dirs.iter().map(PathBuf::as_path));
+            dirs.extend(runpath.iter().map(PathBuf::as_path));
+            dirs.extend(self.conf_dirs.iter().map(PathBuf::as_path));
+            if self.default_dirs {
+                if object.class == Class::ELF64 {
+                    dirs.extend([Path::new("/lib64"), Path::new("/usr/lib64")]);
+                }
+                dirs.extend([Path::new("/lib"), Path::new("/usr/lib")]);
+            }
+
+            for name in &object.needed {
+                let resolved = match by_name.get(name) {
+                    Some(&loaded) => Some(loaded),
+                    None => match find_library(name, &dirs, target) {
+                        Some((found_path, found)) => {
+                            let canonical = std::fs::canonicalize(&found_path)
+                                .unwrap_or_else(|_| found_path.clone());
+                            let loaded = match by_path.get(&canonical) {
+                                Some(&loaded) => loaded,
+                                None => {
+                                    let chain = self.add_object(
+                                        found_path,
+                                        &found,
+                                        &rpath_chain,
+                                        &mut objects,
+                                        &mut by_name,
+                                        &mut by_path,
+                                    );
+                                    pending.push_back((objects.len() - 1, found, chain));
+                                    objects.len() - 1
+                                }
+                            };
+                            by_name.insert(name.clone(), loaded);
+                            Some(loaded)
+                        }
+                        None => None,
+                    },
+                };
+                objects[index].needed.push(Dependency {
+                    name: name.clone(),
+                    object: resolved,
+                });
+            }
+        }
+
+        Ok(DependencyTree { objects })
+    }
+
+    /// Record a newly loaded object, returning the DT_RPATH directories to search for its
+    /// dependencies: its own followed by those of the objects which loaded it.
+    fn add_object(
+        &self,
+        path: PathBuf,
+        object: &ParsedObject,
+        loader_rpath_chain: &[PathBuf],
+        objects: &mut Vec<ResolvedObject>,
+        by_name: &mut HashMap<String, usize>,
+        by_path: &mut HashMap<PathBuf, usize>,
+    ) -> Vec<PathBuf> {
+        let index = objects.len();
+        if let Some(soname) = &object.soname {
+            by_name.entry(soname.clone()).or_insert(index);
+        }
+        let canonical = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+        by_path.entry(canonical).or_insert(index);
+
+        // The dynamic linker ignores an object's DT_RPATH if it also has a DT_RUNPATH
+        let mut rpath_chain = match (&object.rpath, &object.runpath) {
+            (Some(rpath), None) => self.expand(rpath, &path),
+            _ => Vec::new(),
+        };
+        rpath_chain.extend_from_slice(loader_rpath_chain);
+
+        objects.push(ResolvedObject {
+            path,
+            soname: object.soname.clone(),
+            needed: Vec::new(),
+        });
+        rpath_chain
+    }
+
+    /// Expand the entries of a DT_RPATH or DT_RUNPATH for the object at the given path.
+    fn expand(&self, path: &str, object_path: &Path) -> Vec<PathBuf> {
+        let object_path = object_path.to_string_lossy();
+        let tokens = SearchPathTokens {
+            lib: self.lib.as_deref(),
+            platform: self.platform.as_deref(),
+            ..SearchPathTokens::for_object(&object_path)
+        };
+        SearchPath { path }
+            .expand(&tokens)
+            .into_iter()
+            // An empty entry means the current working directory
+            .map(|dir| PathBuf::from(if dir.is_empty() { "." } else { dir.as_str() }))
+            .collect()
+    }
+
+    fn read_ld_so_conf(&mut self, path: &Path, depth: usize) -> Result<(), ParseError> {
+        let contents = std::fs::read_to_string(path)?;
+        let conf_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let mut words = line.split_whitespace();
+            match words.next() {
+                None | Some("hwcap") => {}
+                Some("include") => {
+                    if depth >= MAX_INCLUDE_DEPTH {
+                        continue;
+                    }
+                    for pattern in words {
+                        for included in glob_file_names(&conf_dir.join(pattern))? {
+                            self.read_ld_so_conf(&included, depth + 1)?;
+                        }
+                    }
+                }
+                Some(_) => {
+                    let dirs = line
+                        .split(|c: char| c == ':' || c == ',' || c.is_whitespace())
+                        .filter(|dir| !dir.is_empty());
+                    for dir in dirs {
+                        let dir = PathBuf::from(dir);
+                        if !self.conf_dirs.contains(&dir) {
+                            self.conf_dirs.push(dir);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The resolved dependencies of an object, as found by [DependencyResolver::resolve]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyTree {
+    /// Every object which would get loaded, in load order (breadth-first, like `ldd` lists
+    /// them). The first one is the object whose dependencies were resolved.
+    pub objects: Vec<ResolvedObject>,
+}
+
+/// An object loaded while resolving dependencies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedObject {
+    /// The path the object was found at
+    pub path: PathBuf,
+    /// The object's [DT_SONAME](crate::abi::DT_SONAME), if any
+    pub soname: Option<String>,
+    /// The object's [DT_NEEDED](crate::abi::DT_NEEDED) entries, in order
+    pub needed: Vec<Dependency>,
+}
+
+/// A [DT_NEEDED](crate::abi::DT_NEEDED) entry and the object it resolved to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    /// The name of the needed library, e.g. `libc.so.6`
+    pub name: String,
+    /// The index of the library in [DependencyTree::objects], or None if it wasn't found
+    pub object: Option<usize>,
+}
+
+impl DependencyTree {
+    /// The object whose dependencies were resolved
+    pub fn root(&self) -> &ResolvedObject {
+        &self.objects[0]
+    }
+
+    /// The libraries the root object depends on, directly or indirectly, in load order
+    pub fn libraries(&self) -> &[ResolvedObject] {
+        &self.objects[1..]
+    }
+
+    /// Iterate over the libraries which couldn't be found, as the object needing each one
+    /// and the name of the missing library.
+    pub fn missing(&self) -> impl Iterator<Item = (&ResolvedObject, &str)> {
+        self.objects.iter().flat_map(|object| {
+            object
+                .needed
+                .iter()
+                .filter(|dep| dep.object.is_none())
+                .map(move |dep| (object, dep.name.as_str()))
+        })
+    }
+
+    /// Find the dependency cycles, as lists of indices into [DependencyTree::objects] where each
+    /// object needs the next one and the last one needs the first.
+    ///
+    /// The dynamic linker loads each library only once, so cycles don't stop an object from
+    /// loading, but they make the order its libraries get initialized in unspecified. Each
+    /// cycle found by a depth-first walk from the root object is returned once; a library
+    /// which needs itself is a cycle of one.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+
+        let mut cycles = Vec::new();
+        let mut state = vec![State::Unvisited; self.objects.len()];
+        let mut path = Vec::new();
+        // A manual stack of (object, index of its next dependency to visit), rather than
+        // recursion, so that deep dependency chains can't overflow the call stack
+        let mut stack = vec![(0, 0)];
+        state[0] = State::OnStack;
+        path.push(0);
+
+        while let Some((object, next)) = stack.last_mut() {
+            let object = *object;
+            let dep = self.objects[object].needed.get(*next);
+            *next += 1;
+            match dep {
+                Some(Dependency {
+                    object: Some(dep), ..
+                }) => match state[*dep] {
+                    State::Unvisited => {
+                        state[*dep] = State::OnStack;
+                        path.push(*dep);
+                        stack.push((*dep, 0));
+                    }
+                    State::OnStack => {
+                        let start = path.iter().position(|index| index == dep).unwrap_or(0);
+                        cycles.push(path[start..].to_vec());
+                    }
+                    State::Done => {}
+                },
+                Some(_) => {}
+                None => {
+                    state[object] = State::Done;
+                    path.pop();
+                    stack.pop();
+                }
+            }
+        }
+        cycles
+    }
+}
+
+/// The parts of an object's headers and dynamic table which dependency resolution needs
+struct ParsedObject {
+    class: Class,
+    endianness: AnyEndian,
+    e_machine: u16,
+    soname: Option<String>,
+    needed: Vec<String>,
+    rpath: Option<String>,
+    runpath: Option<String>,
+}
+
+impl ParsedObject {
+    fn read(path: &Path) -> Result<Self, ParseError> {
+        let data = std::fs::read(path)?;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data)?;
+        let needed = match file.needed_libraries()? {
+            Some(needed) => needed
+                .map(|name| name.map(str::to_string))
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        Ok(ParsedObject {
+            class: file.ehdr.class,
+            endianness: file.ehdr.endianness,
+            e_machine: file.ehdr.e_machine,
+            soname: file.soname()?.map(str::to_string),
+            needed,
+            rpath: file.rpath()?.map(|rpath| rpath.path.to_string()),
+            runpath: file.runpath()?.map(|runpath| runpath.path.to_string()),
+        })
+    }
+}
+
+/// Look for the named library in the given directories, skipping files which aren't objects
+/// for the given (class, endianness, machine).
+fn find_library(
+    name: &str,
+    dirs: &[&Path],
+    target: (Class, AnyEndian, u16),
+) -> Option<(PathBuf, ParsedObject)> {
+    let candidates: Vec<PathBuf> = if name.contains('/') {
+        vec![PathBuf::from(name)]
+    } else {
+        dirs.iter().map(|dir| dir.join(name)).collect()
+    };
+    candidates.into_iter().find_map(|candidate| {
+        let object = ParsedObject::read(&candidate).ok()?;
+        let compatible = (object.class, object.endianness, object.e_machine) == target;
+        compatible.then_some((candidate, object))
+    })
+}
+
+/// Get the existing files matching a path whose file name may contain `*` and `?` wildcards,
+/// sorted by name.
+fn glob_file_names(pattern: &Path) -> Result<Vec<PathBuf>, ParseError> {
+    let (dir, file_pattern) = match (pattern.parent(), pattern.file_name()) {
+        (Some(dir), Some(file_pattern)) => (dir, file_pattern.to_string_lossy()),
+        _ => return Ok(Vec::new()),
+    };
+    if !file_pattern.contains(&['*', '?'][..]) {
+        return Ok(if pattern.is_file() {
+            vec![pattern.to_path_buf()]
+        } else {
+            Vec::new()
+        });
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut matches = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        if wildcard_match(file_pattern.as_bytes(), name.to_string_lossy().as_bytes()) {
+            matches.push(entry.path());
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+/// Match a file name against a pattern where `*` matches any run of characters and `?` any
+/// single one. Like glob(3), wildcards don't match a leading `.`.
+fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+    if name.first() == Some(&b'.') && pattern.first() != Some(&b'.') {
+        return false;
+    }
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern, and of the name when it was reached
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                // Let the last `*` match one more character and retry from there
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+
+    const NEEDED: &str = "sample-objects/needed.x86_64.so";
+
+    /// A fresh directory under the system's temp directory, removed when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("elf-deps-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).expect("should create temp dir");
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn missing_libraries() {
+        let tree = DependencyResolver::new()
+            .default_dirs(false)
+            .resolve(NEEDED)
+            .expect("object should be parseable");
+        assert_eq!(tree.root().path, Path::new(NEEDED));
+        assert_eq!(tree.root().soname.as_deref(), Some("libneeded.so.1"));
+        assert_eq!(
+            tree.root().needed,
+            [
+                Dependency {
+                    name: "libm.so.6".to_string(),
+                    object: None,
+                },
+                Dependency {
+                    name: "libc.so.6".to_string(),
+                    object: None,
+                },
+            ]
+        );
+        assert!(tree.cycles().is_empty());
+    }
+
+    #[test]
+    fn resolve_through_search_dir_with_cycles() {
+        // Both "libraries" are copies of the sample object, so each needs itself and the other
+        let tmp = TempDir::new("cycles");
+        for name in ["libm.so.6", "libc.so.6"] {
+            std::fs::copy(NEEDED, tmp.0.join(name)).expect("should copy");
+        }
+        // Files which aren't objects for the same machine are skipped
+        let other = tmp.0.join("other");
+        std::fs::create_dir(&other).expect("should create dir");
+        std::fs::write(other.join("libm.so.6"), b"not an object").expect("should write");
+        std::fs::copy("sample-objects/symver.aarch64.so", other.join("libc.so.6"))
+            .expect("should copy");
+
+        let tree = DependencyResolver::new()
+            .default_dirs(false)
+            .search_dir(&other)
+            .search_dir(&tmp.0)
+            .resolve(NEEDED)
+            .expect("object should be parseable");
+
+        let paths: Vec<&Path> = tree
+            .libraries()
+            .iter()
+            .map(|lib| lib.path.as_path())
+            .collect();
+        assert_eq!(paths, [tmp.0.join("libm.so.6"), tmp.0.join("libc.so.6")]);
+        assert_eq!(tree.missing().count(), 0);
+        let libm = &tree.objects[1];
+        assert_eq!(
+            libm.needed.iter().map(|dep| dep.object).collect::<Vec<_>>(),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(tree.cycles(), [vec![1], vec![1, 2], vec![2]]);
+    }
+
+    #[test]
+    fn resolve_through_runpath() {
+        // needed.x86_64.so has a DT_RUNPATH of $ORIGIN/../$LIB:/opt/${PLATFORM}/lib
+        let tmp = TempDir::new("runpath");
+        std::fs::create_dir(tmp.0.join("bin")).expect("should create dir");
+        std::fs::create_dir(tmp.0.join("lib64")).expect("should create dir");
+        let root = tmp.0.join("bin/needed.so");
+        std::fs::copy(NEEDED, &root).expect("should copy");
+        std::fs::copy(NEEDED, tmp.0.join("lib64/libc.so.6")).expect("should copy");
+
+        let tree = DependencyResolver::new()
+            .default_dirs(false)
+            .lib("lib64")
+            .resolve(&root)
+            .expect("object should be parseable");
+        assert_eq!(tree.libraries().len(), 1);
+        assert_eq!(
+            tree.libraries()[0].path,
+            tmp.0.join("bin/../lib64/libc.so.6")
+        );
+        let missing: Vec<(&Path, &str)> = tree
+            .missing()
+            .map(|(object, name)| (object.path.as_path(), name))
+            .collect();
+        assert_eq!(
+            missing,
+            [
+                (root.as_path(), "libm.so.6"),
+                (tree.libraries()[0].path.as_path(), "libm.so.6"),
+            ]
+        );
+
+        // Without $LIB, the DT_RUNPATH directory doesn't exist
+        let tree = DependencyResolver::new()
+            .default_dirs(false)
+            .resolve(&root)
+            .expect("object should be parseable");
+        assert!(tree.libraries().is_empty());
+    }
+
+    #[test]
+    fn ld_so_conf_includes() {
+        let tmp = TempDir::new("conf");
+        std::fs::create_dir(tmp.0.join("conf.d")).expect("should create dir");
+        std::fs::write(
+            tmp.0.join("ld.so.conf"),
+            "# comment\ninclude conf.d/*.conf\n/opt/first\nhwcap 0 nosegneg\n",
+        )
+        .expect("should write");
+        std::fs::write(tmp.0.join("conf.d/b.conf"), "/opt/b1:/opt/b2 # trailing\n")
+            .expect("should write");
+        std::fs::write(tmp.0.join("conf.d/a.conf"), "/opt/a\n/opt/first\n").expect("should write");
+        std::fs::write(tmp.0.join("conf.d/a.conf.bak"), "/opt/bak\n").expect("should write");
+        // Include loops stop at the maximum include depth
+        std::fs::write(tmp.0.join("conf.d/loop.conf"), "include loop.conf\n")
+            .expect("should write");
+
+        let mut resolver = DependencyResolver::new();
+        resolver
+            .ld_so_conf(tmp.0.join("ld.so.conf"))
+            .expect("should read config");
+        let dirs: Vec<&Path> = resolver.conf_dirs.iter().map(PathBuf::as_path).collect();
+        assert_eq!(
+            dirs,
+            ["/opt/a", "/opt/first", "/opt/b1", "/opt/b2"].map(Path::new)
+        );
+
+        let err = resolver
+            .ld_so_conf(tmp.0.join("missing.conf"))
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IOError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match(b"*.conf", b"libc.conf"));
+        assert!(wildcard_match(b"lib?.conf", b"libc.conf"));
+        assert!(wildcard_match(b"*c*.conf", b"libc.conf"));
+        assert!(!wildcard_match(b"*.conf", b"libc.conf.bak"));
+        assert!(!wildcard_match(b"*.conf", b".hidden.conf"));
+        assert!(wildcard_match(b"*", b"anything"));
+    }
+}
@@ -0,0 +1,541 @@
+//! Formatting section, segment, and symbol tables as aligned columns like `readelf -W`
+//!
+//! Each `write_*` function writes a header line followed by one line per entry to any
+//! [core::fmt::Write], such as a [String], with the column layout that `readelf -S`, `-l`,
+//! `-s`, and `-r` use in wide mode. Type, binding, and visibility names are decoded the same
+//! way, e.g. `PROGBITS`, `GNU_STACK`, `GLOBAL` or `R_X86_64_JUMP_SLOT`.
+//!
+//! This module is only available with the `to_str` cargo feature.
+//!
+//! Example for printing an object's program headers:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let mut out = String::new();
+//! elf::display::write_program_headers(
+//!     &mut out,
+//!     file.ehdr.class,
+//!     file.segments().expect("file should have segments"),
+//! )
+//! .unwrap();
+//! assert_eq!(
+//!     out.lines().nth(3),
+//!     Some("  LOAD           0x000000 0x0000000000400000 0x0000000000400000 0x0006bc 0x0006bc R E 0x200000")
+//! );
+//! ```
+use core::fmt::{Result, Write};
+
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::machine::MachineRegistry;
+use crate::relocation::Rela;
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolBind, SymbolTable, SymbolType};
+use crate::to_str::{
+    p_flags_to_string, p_type_to_string, sh_flags_to_string, sh_type_to_string, st_bind_to_string,
+    st_symtype_to_string, st_vis_to_string,
+};
+
+/// Strip a constant name's prefix, e.g. "SHT_PROGBITS" -> "PROGBITS". Names of unknown values
+/// (e.g. "sh_type(0x60000001)") are left as-is.
+fn strip_prefix(name: String, prefix: &str) -> String {
+    match name.strip_prefix(prefix) {
+        Some(stripped) => stripped.to_string(),
+        None => name,
+    }
+}
+
+/// Width in hex digits of an address for the given class
+fn addr_width(class: Class) -> usize {
+    match class {
+        Class::ELF32 => 8,
+        Class::ELF64 => 16,
+    }
+}
+
+/// Write a section header table like `readelf -SW`.
+///
+/// Section names are looked up in `strtab` (the section header string table) when it is given.
+pub fn write_section_headers<W: Write>(
+    w: &mut W,
+    class: Class,
+    shdrs: impl IntoIterator<Item = SectionHeader>,
+    strtab: Option<&StringTable<'_>>,
+) -> Result {
+    let addr = match class {
+        Class::ELF32 => "Addr    ",
+        Class::ELF64 => "Address         ",
+    };
+    writeln!(
+        w,
+        "  [Nr] Name              Type            {addr} Off    Size   ES Flg Lk Inf Al"
+    )?;
+
+    let width = addr_width(class);
+    for (index, shdr) in shdrs.into_iter().enumerate() {
+        let name = strtab
+            .and_then(|strtab| strtab.get(shdr.sh_name as usize).ok())
+            .unwrap_or("");
+        let sh_type = match shdr.sh_type {
+            // readelf drops the GNU_ from the symbol versioning section types
+            abi::SHT_GNU_VERDEF => "VERDEF".to_string(),
+            abi::SHT_GNU_VERNEED => "VERNEED".to_string(),
+            abi::SHT_GNU_VERSYM => "VERSYM".to_string(),
+            sh_type => strip_prefix(sh_type_to_string(sh_type), "SHT_"),
+        };
+        writeln!(
+            w,
+            "  [{index:2}] {name:<17} {sh_type:<15} {:0width$x} {:06x} {:06x} {:02x} {:>3} {:2} {:3} {:2}",
+            shdr.sh_addr,
+            shdr.sh_offset,
+            shdr.sh_size,
+            shdr.sh_entsize,
+            sh_flags_to_string(shdr.sh_flags),
+            shdr.sh_link,
+            shdr.sh_info,
+            shdr.sh_addralign,
+        )?;
+    }
+    Ok(())
+}
+
+/// Write a program header table like `readelf -lW`.
+pub fn write_program_headers<W: Write>(
+    w: &mut W,
+    class: Class,
+    phdrs: impl IntoIterator<Item = ProgramHeader>,
+) -> Result {
+    let (header, width, size_width) = match class {
+        Class::ELF32 => (
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align",
+            8,
+            5,
+        ),
+        Class::ELF64 => (
+            "  Type           Offset   VirtAddr           PhysAddr           FileSiz  MemSiz   Flg Align",
+            16,
+            6,
+        ),
+    };
+    writeln!(w, "{header}")?;
+
+    for phdr in phdrs {
+        writeln!(
+            w,
+            "  {:<14} {:#08x} {:#0addr$x} {:#0addr$x} {:#0size$x} {:#0size$x} {:<3} {:#x}",
+            strip_prefix(p_type_to_string(phdr.p_type), "PT_"),
+            phdr.p_offset,
+            phdr.p_vaddr,
+            phdr.p_paddr,
+            phdr.p_filesz,
+            phdr.p_memsz,
+            p_flags_to_string(phdr.p_flags),
+            phdr.p_align,
+            addr = width + 2,
+            size = size_width + 2,
+        )?;
+    }
+    Ok(())
+}
+
+/// Write a symbol table like `readelf -sW`.
+///
+/// Symbol names are looked up in `strtab` (the symbol table's string table) when it is given.
+/// Unlike readelf, section symbols are shown with their own (usually empty) names rather than
+/// their section's name.
+pub fn write_symbols<W: Write>(
+    w: &mut W,
+    class: Class,
+    symbols: impl IntoIterator<Item = Symbol>,
+    strtab: Option<&StringTable<'_>>,
+) -> Result {
+    let value = match class {
+        Class::ELF32 => "   Value",
+        Class::ELF64 => "   Value        ",
+    };
+    writeln!(w, "   Num: {value}  Size Type    Bind   Vis      Ndx Name")?;
+
+    let width = addr_width(class);
+    for (index, sym) in symbols.into_iter().enumerate() {
+        let name = strtab
+            .and_then(|strtab| strtab.get(sym.st_name as usize).ok())
+            .unwrap_or("");
+        let ndx = match sym.st_shndx {
+            abi::SHN_UNDEF => "UND".to_string(),
+            abi::SHN_ABS => "ABS".to_string(),
+            abi::SHN_COMMON => "COM".to_string(),
+            shndx => shndx.to_string(),
+        };
+        // readelf drops the GNU_ prefix from the GNU extensions' names too
+        let symtype = match sym.symbol_type() {
+            SymbolType::GnuIfunc => "IFUNC".to_string(),
+            _ => strip_prefix(st_symtype_to_string(sym.st_symtype()), "STT_"),
+        };
+        let bind = match sym.symbol_bind() {
+            SymbolBind::GnuUnique => "UNIQUE".to_string(),
+            _ => strip_prefix(st_bind_to_string(sym.st_bind()), "STB_"),
+        };
+        writeln!(
+            w,
+            "{index:6}: {:0width$x} {:5} {:<7} {:<6} {:<8} {ndx:>3} {name}",
+            sym.st_value,
+            sym.st_size,
+            symtype,
+            bind,
+            strip_prefix(st_vis_to_string(sym.st_vis()), "STV_"),
+        )?;
+    }
+    Ok(())
+}
+
+/// Write a table of relocations with addends like `readelf -rW`.
+///
+/// Relocation types are named according to `e_machine` (see
+/// [r_type_to_str](crate::to_str::r_type_to_str)). Symbol values and names are looked up in
+/// `symbols` (the relocation section's linked symbol table and its string table) when it is
+/// given. Unlike readelf, symbol names aren't suffixed with their versions and section symbols
+/// are shown with their own (usually empty) names.
+pub fn write_relas<W: Write, E: EndianParse>(
+    w: &mut W,
+    class: Class,
+    e_machine: u16,
+    relas: impl IntoIterator<Item = Rela>,
+    symbols: Option<(&SymbolTable<'_, E>, &StringTable<'_>)>,
+) -> Result {
+    write_relas_with_machines(w, class, e_machine, &MachineRegistry::new(), relas, symbols)
+}
+
+/// Like [write_relas], but naming relocation types with the given [MachineRegistry], so that
+/// machines which this crate doesn't know about can have their relocation types named.
+pub fn write_relas_with_machines<W: Write, E: EndianParse>(
+    w: &mut W,
+    class: Class,
+    e_machine: u16,
+    machines: &MachineRegistry,
+    relas: impl IntoIterator<Item = Rela>,
+    symbols: Option<(&SymbolTable<'_, E>, &StringTable<'_>)>,
+) -> Result {
+    // readelf puts more space between a symbol's value and its name for 32-bit objects
+    let (header, name_sep) = match class {
+        Class::ELF32 => (
+            " Offset     Info    Type                Sym. Value  Symbol's Name + Addend",
+            "   ",
+        ),
+        Class::ELF64 => (
+            "    Offset             Info             Type               Symbol's Value  Symbol's Name + Addend",
+            " ",
+        ),
+    };
+    writeln!(w, "{header}")?;
+
+    let width = addr_width(class);
+    for rela in relas {
+        let r_info = match class {
+            Class::ELF32 => ((rela.r_sym as u64) << 8) | (rela.r_type as u64 & 0xff),
+            Class::ELF64 => ((rela.r_sym as u64) << 32) | rela.r_type as u64,
+        };
+        write!(
+            w,
+            "{:0width$x}  {r_info:0width$x} {:<22} ",
+            rela.r_offset,
+            machines.r_type_to_string(e_machine, rela.r_type),
+        )?;
+
+        let sign = if rela.r_addend < 0 { "-" } else { "+" };
+        let addend = rela.r_addend.unsigned_abs();
+        let symbol = match symbols {
+            Some((symtab, strtab)) if rela.r_sym != 0 => symtab
+                .get(rela.r_sym as usize)
+                .ok()
+                .map(|sym| (sym.st_value, strtab.get(sym.st_name as usize).unwrap_or(""))),
+            _ => None,
+        };
+        match symbol {
+            Some((value, name)) => {
+                writeln!(w, "{value:0width$x}{name_sep}{name} {sign} {addend:x}")?;
+            }
+            None => {
+                let sign = if sign == "-" { sign } else { "" };
+                writeln!(w, "{:width$}   {sign}{addend:x}", "")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::ElfBytes;
+
+    fn basic() -> Vec<u8> {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    #[test]
+    fn section_headers_match_readelf() {
+        let file_data = basic();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("section table should be parseable");
+
+        let mut out = String::new();
+        write_section_headers(&mut out, file.ehdr.class, shdrs.unwrap(), strtab.as_ref()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 32);
+        assert_eq!(
+            lines[0],
+            "  [Nr] Name              Type            Address          Off    Size   ES Flg Lk Inf Al"
+        );
+        assert_eq!(
+            lines[1],
+            "  [ 0]                   NULL            0000000000000000 000000 000000 00      0   0  0"
+        );
+        assert_eq!(
+            lines[4],
+            "  [ 3] .note.gnu.build-id NOTE            000000000040023c 00023c 000024 00   A  0   0  4"
+        );
+        assert_eq!(
+            lines[8],
+            "  [ 7] .gnu.version      VERSYM          0000000000400320 000320 000008 02   A  5   0  2"
+        );
+        assert_eq!(
+            lines[30],
+            "  [29] .symtab           SYMTAB          0000000000000000 000920 000678 18     30  48  8"
+        );
+    }
+
+    #[test]
+    fn program_headers_match_readelf() {
+        let file_data = basic();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let mut out = String::new();
+        write_program_headers(&mut out, file.ehdr.class, file.segments().unwrap()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(
+            lines[4],
+            "  LOAD           0x0006c0 0x00000000006006c0 0x00000000006006c0 0x000228 0x040260 RW  0x200000"
+        );
+        assert_eq!(
+            lines[8],
+            "  GNU_STACK      0x000000 0x0000000000000000 0x0000000000000000 0x000000 0x000000 RW  0x10"
+        );
+    }
+
+    #[test]
+    fn program_headers_match_readelf32() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let mut out = String::new();
+        write_program_headers(&mut out, file.ehdr.class, file.segments().unwrap()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align"
+        );
+        assert_eq!(
+            lines[2],
+            "  LOAD           0x000f00 0x00010f00 0x00010f00 0x0012c 0x00130 RW  0x10000"
+        );
+    }
+
+    #[test]
+    fn symbols_match_readelf() {
+        let file_data = basic();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let (symtab, strtab) = file
+            .dynamic_symbol_table()
+            .expect("dynsym should be parseable")
+            .expect("file should have a dynsym");
+
+        let mut out = String::new();
+        write_symbols(&mut out, file.ehdr.class, symtab.iter(), Some(&strtab)).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "   Num:    Value          Size Type    Bind   Vis      Ndx Name",
+                "     0: 0000000000000000     0 NOTYPE  LOCAL  DEFAULT  UND ",
+                "     1: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND memset",
+                "     2: 0000000000000000     0 FUNC    GLOBAL DEFAULT  UND __libc_start_main",
+                "     3: 0000000000000000     0 NOTYPE  WEAK   DEFAULT  UND __gmon_start__",
+            ]
+        );
+    }
+
+    #[test]
+    fn symbols_with_gnu_extensions() {
+        let ifunc = Symbol {
+            st_name: 0,
+            st_value: 0x1130,
+            st_size: 11,
+            st_shndx: 14,
+            st_info: (abi::STB_GLOBAL << 4) | abi::STT_GNU_IFUNC,
+            st_other: 0,
+        };
+        let unique = Symbol {
+            st_info: (abi::STB_GNU_UNIQUE << 4) | abi::STT_OBJECT,
+            ..ifunc.clone()
+        };
+
+        let mut out = String::new();
+        write_symbols(&mut out, Class::ELF64, [ifunc, unique], None).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[1..],
+            [
+                "     0: 0000000000001130    11 IFUNC   GLOBAL DEFAULT   14 ",
+                "     1: 0000000000001130    11 OBJECT  UNIQUE DEFAULT   14 ",
+            ]
+        );
+    }
+
+    #[test]
+    fn relas_match_readelf() {
+        let file_data = basic();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".rela.plt")
+            .expect("section table should be parseable")
+            .expect("file should have a .rela.plt");
+        let (symtab, strtab) = file
+            .dynamic_symbol_table()
+            .expect("dynsym should be parseable")
+            .expect("file should have a dynsym");
+
+        let mut out = String::new();
+        write_relas(
+            &mut out,
+            file.ehdr.class,
+            file.ehdr.e_machine,
+            file.section_data_as_relas(&shdr).unwrap(),
+            Some((&symtab, &strtab)),
+        )
+        .unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "    Offset             Info             Type               Symbol's Value  Symbol's Name + Addend",
+                "00000000006008c8  0000000100000007 R_X86_64_JUMP_SLOT     0000000000000000 memset + 0",
+                "00000000006008d0  0000000200000007 R_X86_64_JUMP_SLOT     0000000000000000 __libc_start_main + 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn relas_without_symbols_match_readelf() {
+        let path = std::path::PathBuf::from("sample-objects/stripped.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".rela.dyn")
+            .expect("section table should be parseable")
+            .expect("file should have a .rela.dyn");
+
+        let mut out = String::new();
+        write_relas::<_, AnyEndian>(
+            &mut out,
+            file.ehdr.class,
+            file.ehdr.e_machine,
+            file.section_data_as_relas(&shdr).unwrap(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            out.lines().nth(1),
+            Some(
+                "0000000000003de0  0000000000000008 R_X86_64_RELATIVE                         1110"
+            )
+        );
+
+        let mut out = String::new();
+        write_relas::<_, AnyEndian>(
+            &mut out,
+            Class::ELF64,
+            abi::EM_S390,
+            [Rela {
+                r_offset: 0x1ff8,
+                r_sym: 0,
+                r_type: abi::R_390_RELATIVE,
+                r_addend: 0x7c0,
+            }],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            out.lines().nth(1),
+            Some(
+                "0000000000001ff8  000000000000000c R_390_RELATIVE                            7c0"
+            )
+        );
+
+        // Relocation types of other machines aren't named yet
+        let mut out = String::new();
+        write_relas::<_, AnyEndian>(
+            &mut out,
+            Class::ELF32,
+            abi::EM_68K,
+            [Rela {
+                r_offset: 0x10f00,
+                r_sym: 0,
+                r_type: 0x17,
+                r_addend: -4,
+            }],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            out.lines().nth(1),
+            Some("00010f00  00000017 r_type(0x17)                      -4")
+        );
+    }
+
+    #[test]
+    fn relas_with_registered_machine() {
+        let mut machines = MachineRegistry::new();
+        machines.register(
+            0x4000,
+            crate::machine::Machine {
+                name: "EM_TEST",
+                description: "Test machine",
+                relocations: &[(3, "R_TEST_RELATIVE")],
+            },
+        );
+
+        let mut out = String::new();
+        write_relas_with_machines::<_, AnyEndian>(
+            &mut out,
+            Class::ELF32,
+            0x4000,
+            &machines,
+            [Rela {
+                r_offset: 0x2000,
+                r_sym: 0,
+                r_type: 3,
+                r_addend: 0x40,
+            }],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            out.lines().nth(1),
+            Some("00002000  00000003 R_TEST_RELATIVE                   40")
+        );
+    }
+}
@@ -1,10 +1,162 @@
 //! Parsing `.dynamic` section or [PT_DYNAMIC](crate::abi::PT_DYNAMIC) segment contents
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::parse::{ParseAt, ParseError, ParsingIterator, ParsingTable};
+use crate::string_table::StringTable;
+use core::iter::TakeWhile;
 
 pub type DynamicTable<'data, E> = ParsingTable<'data, E, Dyn>;
 
+/// Iterator over the entries of a [DynamicTable] which stops at the terminating
+/// [DT_NULL](abi::DT_NULL) entry.
+pub type DynIterator<'data, E> = TakeWhile<ParsingIterator<'data, E, Dyn>, fn(&Dyn) -> bool>;
+
+impl<'data, E: EndianParse> DynamicTable<'data, E> {
+    /// Get a lazy-parsing iterator over the table's entries which stops at the
+    /// [DT_NULL](abi::DT_NULL) entry that marks the end of the table.
+    ///
+    /// The `.dynamic` section is often padded out with extra DT_NULL entries past the
+    /// end marker, which this skips (unlike [ParsingTable::iter]).
+    pub fn iter_until_null(&self) -> DynIterator<'data, E> {
+        let not_null: fn(&Dyn) -> bool = |entry| entry.d_tag != abi::DT_NULL;
+        self.iter().take_while(not_null)
+    }
+
+    /// Find the first entry with the given `d_tag`, if any.
+    ///
+    /// This is useful for tags which occur at most once, like [DT_SONAME](abi::DT_SONAME)
+    /// or [DT_STRTAB](abi::DT_STRTAB). Use [DynamicTable::iter_until_null] and filter on the tag
+    /// for tags which can occur multiple times, like [DT_NEEDED](abi::DT_NEEDED).
+    pub fn find(&self, d_tag: i64) -> Option<Dyn> {
+        self.iter_until_null().find(|entry| entry.d_tag == d_tag)
+    }
+}
+
+/// Iterator over the string values of the entries with a given `d_tag` in a [DynamicTable],
+/// e.g. the [DT_NEEDED](abi::DT_NEEDED) library names, resolved through the dynamic string table.
+///
+/// See [ElfBytes::needed_libraries](crate::ElfBytes::needed_libraries).
+#[derive(Debug)]
+pub struct DynamicStringIterator<'data, E: EndianParse> {
+    entries: DynIterator<'data, E>,
+    d_tag: i64,
+    strtab: StringTable<'data>,
+}
+
+impl<'data, E: EndianParse> DynamicStringIterator<'data, E> {
+    pub fn new(table: &DynamicTable<'data, E>, d_tag: i64, strtab: StringTable<'data>) -> Self {
+        DynamicStringIterator {
+            entries: table.iter_until_null(),
+            d_tag,
+            strtab,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for DynamicStringIterator<'data, E> {
+    type Item = Result<&'data str, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let d_tag = self.d_tag;
+        let entry = self.entries.find(|entry| entry.d_tag == d_tag)?;
+        Some(self.strtab.get(entry.d_val() as usize))
+    }
+}
+
+/// A colon-separated library search path from a [DT_RPATH](abi::DT_RPATH) or
+/// [DT_RUNPATH](abi::DT_RUNPATH) entry.
+///
+/// See [ElfBytes::rpath](crate::ElfBytes::rpath) and [ElfBytes::runpath](crate::ElfBytes::runpath).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchPath<'data> {
+    /// The raw search path string, e.g. `$ORIGIN/../lib:/opt/lib`
+    pub path: &'data str,
+}
+
+impl<'data> SearchPath<'data> {
+    /// Iterate over the search path's entries, without any expansion.
+    ///
+    /// Note that an empty entry means the current working directory to the dynamic linker.
+    pub fn iter(&self) -> core::str::Split<'data, char> {
+        self.path.split(':')
+    }
+
+    /// Get the search path's entries with the `$ORIGIN`, `$LIB`, and `$PLATFORM` dynamic string
+    /// tokens (and their `${...}` forms) expanded using the given values.
+    ///
+    /// Tokens whose value isn't given are left as-is.
+    #[cfg(feature = "std")]
+    pub fn expand(&self, tokens: &SearchPathTokens<'_>) -> Vec<String> {
+        self.iter().map(|entry| tokens.expand(entry)).collect()
+    }
+}
+
+/// Values for the dynamic string tokens which can appear in a [SearchPath].
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SearchPathTokens<'a> {
+    /// The directory containing the object, used for `$ORIGIN`
+    pub origin: Option<&'a str>,
+    /// The system's library directory name (e.g. `lib64`), used for `$LIB`
+    pub lib: Option<&'a str>,
+    /// The system's platform name (e.g. `x86_64`), used for `$PLATFORM`
+    pub platform: Option<&'a str>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> SearchPathTokens<'a> {
+    /// Get the tokens for an object at the given path, with `$ORIGIN` set to the directory
+    /// containing it.
+    pub fn for_object(path: &'a str) -> Self {
+        let origin = match path.rfind('/') {
+            Some(0) => "/",
+            Some(idx) => &path[..idx],
+            None => ".",
+        };
+        SearchPathTokens {
+            origin: Some(origin),
+            ..Default::default()
+        }
+    }
+
+    fn expand(&self, entry: &str) -> String {
+        let tokens = [
+            ("ORIGIN", self.origin),
+            ("LIB", self.lib),
+            ("PLATFORM", self.platform),
+        ];
+
+        let mut result = String::with_capacity(entry.len());
+        let mut rest = entry;
+        while let Some(idx) = rest.find('$') {
+            result.push_str(&rest[..idx]);
+            rest = &rest[idx + 1..];
+
+            let expansion = tokens.iter().find_map(|(name, value)| {
+                let value = (*value)?;
+                if let Some(after) = rest.strip_prefix(name) {
+                    // $ORIGIN must not run into more identifier characters, e.g. $ORIGINAL
+                    let boundary =
+                        !after.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+                    boundary.then_some((value, after))
+                } else {
+                    let after = rest.strip_prefix('{')?.strip_prefix(name)?;
+                    Some((value, after.strip_prefix('}')?))
+                }
+            });
+            match expansion {
+                Some((value, after)) => {
+                    result.push_str(value);
+                    rest = after;
+                }
+                None => result.push('$'),
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
 /// C-style 32-bit ELF Dynamic section entry definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -28,6 +180,7 @@ pub struct Elf64_Dyn {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dyn {
     pub d_tag: i64,
     pub(super) d_un: u64,
@@ -77,6 +230,103 @@ mod parse_tests {
     use crate::endian::{BigEndian, LittleEndian};
     use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
 
+    #[test]
+    fn dynamic_string_iterator() {
+        #[rustfmt::skip]
+        let data = [
+            // DT_NEEDED
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+            // DT_SONAME
+            0x0e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+            // DT_NEEDED
+            0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+            // DT_NULL
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // DT_NEEDED, past the end of the table
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ];
+        let table = DynamicTable::new(LittleEndian, Class::ELF32, &data);
+        let strtab = StringTable::new(b"\0libc\0libm\0");
+
+        let needed: Vec<&str> = DynamicStringIterator::new(&table, abi::DT_NEEDED, strtab)
+            .collect::<Result<_, _>>()
+            .expect("should resolve");
+        assert_eq!(needed, ["libc", "libm"]);
+
+        let mut rpath = DynamicStringIterator::new(&table, abi::DT_RPATH, strtab);
+        assert!(rpath.next().is_none());
+    }
+
+    #[test]
+    fn dynamic_string_iterator_bad_offset_errors() {
+        let data = [0x01u8, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00];
+        let table = DynamicTable::new(LittleEndian, Class::ELF32, &data);
+        let strtab = StringTable::new(b"\0libc\0");
+        let err = DynamicStringIterator::new(&table, abi::DT_NEEDED, strtab)
+            .next()
+            .expect("should have an entry")
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0xff)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn search_path_iter() {
+        let path = SearchPath {
+            path: "$ORIGIN/../lib::/opt/lib",
+        };
+        let entries: Vec<&str> = path.iter().collect();
+        assert_eq!(entries, ["$ORIGIN/../lib", "", "/opt/lib"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_path_expand() {
+        let path = SearchPath {
+            path: "$ORIGIN/../$LIB:/opt/${PLATFORM}/lib:${ORIGIN}:$ORIGINAL:$HOME/lib",
+        };
+        let tokens = SearchPathTokens {
+            lib: Some("lib64"),
+            platform: Some("x86_64"),
+            ..SearchPathTokens::for_object("/usr/bin/app")
+        };
+        assert_eq!(
+            path.expand(&tokens),
+            [
+                "/usr/bin/../lib64",
+                "/opt/x86_64/lib",
+                "/usr/bin",
+                "$ORIGINAL",
+                "$HOME/lib",
+            ]
+        );
+
+        // Tokens without values are left alone
+        let tokens = SearchPathTokens::for_object("app");
+        assert_eq!(
+            path.expand(&tokens),
+            [
+                "./../$LIB",
+                "/opt/${PLATFORM}/lib",
+                ".",
+                "$ORIGINAL",
+                "$HOME/lib"
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_path_tokens_for_object() {
+        assert_eq!(SearchPathTokens::for_object("/app").origin, Some("/"));
+        assert_eq!(
+            SearchPathTokens::for_object("lib/libfoo.so").origin,
+            Some("lib")
+        );
+    }
+
     #[test]
     fn test_d_val_and_d_ptr() {
         let val = Dyn {
@@ -154,4 +404,34 @@ mod parse_tests {
     fn parse_dyn64_msb_fuzz_too_short() {
         test_parse_fuzz_too_short::<_, Dyn>(BigEndian, Class::ELF64);
     }
+
+    #[test]
+    fn dynamic_table_stops_at_null() {
+        #[rustfmt::skip]
+        let data = [
+            // DT_NEEDED
+            0x01, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00,
+            // DT_NEEDED
+            0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+            // DT_SONAME
+            0x0E, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x00, 0x00,
+            // DT_NULL
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // DT_RPATH, past the end of the table
+            0x0F, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+        ];
+        let table = DynamicTable::new(LittleEndian, Class::ELF32, &data);
+
+        let needed: Vec<u64> = table
+            .iter_until_null()
+            .filter(|entry| entry.d_tag == abi::DT_NEEDED)
+            .map(|entry| entry.d_val())
+            .collect();
+        assert_eq!(needed, [0x0A, 0x14]);
+        assert_eq!(table.iter_until_null().count(), 3);
+
+        assert_eq!(table.find(abi::DT_SONAME).unwrap().d_val(), 0x1E);
+        assert!(table.find(abi::DT_RPATH).is_none());
+        assert!(table.find(abi::DT_NULL).is_none());
+    }
 }
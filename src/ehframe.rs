@@ -0,0 +1,930 @@
+//! Parsing the `.eh_frame` section: call frame information (CFI) used for stack unwinding
+//!
+//! The section holds a sequence of Common Information Entries (CIEs) and Frame Description
+//! Entries (FDEs). Each [Fde] describes how to unwind a single function's address range, and
+//! refers back to a [Cie] which holds the information shared between many FDEs, such as how
+//! the FDE's pointers are encoded. Since `.eh_frame` has to be kept for exception handling,
+//! it is commonly still present in stripped binaries, which makes it a useful source of
+//! function address ranges.
+//!
+//! The call frame instructions themselves are left as raw bytes.
+//!
+//! Linked objects usually also have an `.eh_frame_hdr` section ([EhFrameHdr]), which holds a
+//! table of the FDEs sorted by address so that the FDE for a given address can be found
+//! with a binary search instead of a scan through `.eh_frame`. See [EhFrame::find_fde].
+//!
+//! (see: <https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html>)
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// The contents of an `.eh_frame` section.
+///
+/// See [ElfBytes::eh_frame](crate::ElfBytes::eh_frame).
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrame<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    address: u64,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> EhFrame<'data, E> {
+    /// Construct an EhFrame from the section's data and the virtual address it's loaded
+    /// at (its sh_addr), which pc-relative pointers are resolved against.
+    pub fn new(endian: E, class: Class, address: u64, data: &'data [u8]) -> Self {
+        EhFrame {
+            endian,
+            class,
+            address,
+            data,
+        }
+    }
+
+    /// Get an iterator over the CIEs and FDEs in the section, in order.
+    pub fn iter(&self) -> EhFrameIterator<'data, E> {
+        EhFrameIterator {
+            eh_frame: *self,
+            offset: 0,
+        }
+    }
+
+    /// Parse the CIE at the given offset into the section, e.g. an [Fde::cie_offset].
+    pub fn cie_at(&self, offset: usize) -> Result<Cie<'data>, ParseError> {
+        // Only parse CIEs here: FDEs parse their CIE, so an FDE pointing at an FDE would recurse
+        match self.entry_header_at(offset)? {
+            Some(header) if header.id == 0 => {
+                self.parse_cie(offset, header.body_offset, header.data)
+            }
+            _ => Err(ParseError::BadOffset(offset as u64)),
+        }
+    }
+
+    /// Parse the FDE at the given offset into the section.
+    pub fn fde_at(&self, offset: usize) -> Result<Fde<'data>, ParseError> {
+        match self.entry_at(offset)? {
+            Some((_, EhFrameEntry::Fde(fde))) => Ok(fde),
+            _ => Err(ParseError::BadOffset(offset as u64)),
+        }
+    }
+
+    /// Find the FDE whose address range contains the given address, using the sorted
+    /// lookup table from this object's `.eh_frame_hdr`.
+    pub fn find_fde(
+        &self,
+        eh_frame_hdr: &EhFrameHdr<'_, E>,
+        pc: u64,
+    ) -> Result<Option<Fde<'data>>, ParseError> {
+        let fde_address = match eh_frame_hdr.lookup(pc)? {
+            Some(fde_address) => fde_address,
+            None => return Ok(None),
+        };
+
+        let offset = fde_address
+            .checked_sub(self.address)
+            .ok_or(ParseError::BadOffset(fde_address))?;
+        let fde = self.fde_at(offset.try_into()?)?;
+        Ok(if fde.contains(pc) { Some(fde) } else { None })
+    }
+
+    fn pointer_reader(&self) -> PointerReader<E> {
+        PointerReader {
+            endian: self.endian,
+            class: self.class,
+            address: self.address,
+            data_base: None,
+        }
+    }
+
+    /// Parse the length and CIE id or pointer of the entry at the given offset. Returns None
+    /// for the zero-length terminator entry.
+    fn entry_header_at(&self, offset: usize) -> Result<Option<EntryHeader<'data>>, ParseError> {
+        let mut off = offset;
+        let (length, is_64) = match self.endian.parse_u32_at(&mut off, self.data)? {
+            0 => return Ok(None),
+            // The 64-bit DWARF format has its real length in the following 8 bytes
+            0xffffffff => (self.endian.parse_u64_at(&mut off, self.data)?, true),
+            length => (length as u64, false),
+        };
+        let end = off
+            .checked_add(length.try_into()?)
+            .ok_or(ParseError::IntegerOverflow)?;
+
+        // Keep offsets absolute (pc-relative pointers need them), but don't read past the entry
+        let data = self.data.get_bytes(0..end)?;
+
+        let id_offset = off;
+        let id = if is_64 {
+            self.endian.parse_u64_at(&mut off, data)?
+        } else {
+            self.endian.parse_u32_at(&mut off, data)? as u64
+        };
+
+        Ok(Some(EntryHeader {
+            end,
+            id_offset,
+            id,
+            body_offset: off,
+            data,
+        }))
+    }
+
+    /// Parse the entry at the given offset, returning it alongside the offset of the next
+    /// entry. Returns None for the zero-length terminator entry.
+    fn entry_at(&self, offset: usize) -> Result<Option<(usize, EhFrameEntry<'data>)>, ParseError> {
+        let header = match self.entry_header_at(offset)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        let entry = if header.id == 0 {
+            EhFrameEntry::Cie(self.parse_cie(offset, header.body_offset, header.data)?)
+        } else {
+            // The CIE pointer is the distance back to the CIE from the pointer itself
+            let cie_offset = header
+                .id_offset
+                .checked_sub(header.id.try_into()?)
+                .ok_or(ParseError::BadOffset(header.id))?;
+            if cie_offset == offset {
+                return Err(ParseError::BadOffset(cie_offset as u64));
+            }
+            EhFrameEntry::Fde(self.parse_fde(
+                offset,
+                cie_offset,
+                header.body_offset,
+                header.data,
+            )?)
+        };
+        Ok(Some((header.end, entry)))
+    }
+
+    fn parse_cie(
+        &self,
+        entry_offset: usize,
+        mut off: usize,
+        data: &'data [u8],
+    ) -> Result<Cie<'data>, ParseError> {
+        let version = self.endian.parse_u8_at(&mut off, data)?;
+        if !matches!(version, 1 | 3 | 4) {
+            return Err(ParseError::UnsupportedVersion((version as u64, 1)));
+        }
+
+        let aug_len = data
+            .get(off..)
+            .unwrap_or_default()
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ParseError::StringTableMissingNul(off as u64))?;
+        let augmentation = data.get_bytes(off..off + aug_len)?;
+        off += aug_len + 1;
+
+        // Old GCC versions emitted a pointer-sized "eh_data" field for the "eh" augmentation
+        if augmentation.starts_with(b"eh") {
+            off += match self.class {
+                Class::ELF32 => 4,
+                Class::ELF64 => 8,
+            };
+        }
+
+        // Version 4 added the address_size and segment_selector_size fields
+        if version >= 4 {
+            self.endian.parse_u8_at(&mut off, data)?;
+            self.endian.parse_u8_at(&mut off, data)?;
+        }
+
+        let code_alignment_factor = parse_uleb128_at(&mut off, data)?;
+        let data_alignment_factor = parse_sleb128_at(&mut off, data)?;
+        let return_address_register = if version == 1 {
+            self.endian.parse_u8_at(&mut off, data)? as u64
+        } else {
+            parse_uleb128_at(&mut off, data)?
+        };
+
+        let mut cie = Cie {
+            offset: entry_offset,
+            version,
+            augmentation,
+            code_alignment_factor,
+            data_alignment_factor,
+            return_address_register,
+            fde_pointer_encoding: abi::DW_EH_PE_ABSPTR,
+            lsda_encoding: None,
+            personality: None,
+            is_signal_frame: false,
+            initial_instructions: &[],
+        };
+
+        if let Some((b'z', aug_chars)) = augmentation.split_first() {
+            let aug_data_len: usize = parse_uleb128_at(&mut off, data)?.try_into()?;
+            let aug_data_end = off
+                .checked_add(aug_data_len)
+                .ok_or(ParseError::IntegerOverflow)?;
+            let reader = self.pointer_reader();
+            for c in aug_chars {
+                match c {
+                    b'L' => cie.lsda_encoding = Some(self.endian.parse_u8_at(&mut off, data)?),
+                    b'R' => cie.fde_pointer_encoding = self.endian.parse_u8_at(&mut off, data)?,
+                    b'P' => {
+                        let encoding = self.endian.parse_u8_at(&mut off, data)?;
+                        let routine = reader.read(encoding, &mut off, data)?;
+                        cie.personality = Some((encoding, routine));
+                    }
+                    b'S' => cie.is_signal_frame = true,
+                    // The augmentation data length lets us skip over anything we don't know
+                    _ => break,
+                }
+            }
+            off = aug_data_end;
+        }
+
+        cie.initial_instructions = data.get_bytes(off..data.len())?;
+        Ok(cie)
+    }
+
+    fn parse_fde(
+        &self,
+        entry_offset: usize,
+        cie_offset: usize,
+        mut off: usize,
+        data: &'data [u8],
+    ) -> Result<Fde<'data>, ParseError> {
+        let cie = self.cie_at(cie_offset)?;
+        let reader = self.pointer_reader();
+
+        let pc_begin = reader.read(cie.fde_pointer_encoding, &mut off, data)?;
+        // The range is a length, so only the value's format applies to it
+        let pc_range = reader.read(cie.fde_pointer_encoding & 0x0f, &mut off, data)?;
+
+        let mut lsda = None;
+        if cie.augmentation.starts_with(b"z") {
+            let aug_data_len: usize = parse_uleb128_at(&mut off, data)?.try_into()?;
+            let aug_data_end = off
+                .checked_add(aug_data_len)
+                .ok_or(ParseError::IntegerOverflow)?;
+            if let Some(encoding) = cie.lsda_encoding {
+                if encoding != abi::DW_EH_PE_OMIT {
+                    lsda = Some(reader.read(encoding, &mut off, data)?);
+                }
+            }
+            off = aug_data_end;
+        }
+
+        Ok(Fde {
+            offset: entry_offset,
+            cie_offset,
+            pc_begin,
+            pc_range,
+            lsda,
+            instructions: data.get_bytes(off..data.len())?,
+        })
+    }
+}
+
+/// The length and CIE id (or, for an FDE, CIE pointer) fields common to CIEs and FDEs
+struct EntryHeader<'data> {
+    /// The offset of the entry following this one
+    end: usize,
+    /// The offset of the id field, which CIE pointers are relative to
+    id_offset: usize,
+    id: u64,
+    /// The offset of the rest of the entry, following the id field
+    body_offset: usize,
+    /// The section data, up to the end of this entry
+    data: &'data [u8],
+}
+
+/// The contents of an `.eh_frame_hdr` section.
+///
+/// See [ElfBytes::eh_frame_hdr](crate::ElfBytes::eh_frame_hdr).
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrameHdr<'data, E: EndianParse> {
+    /// The format version of the header. Only version 1 is defined.
+    pub version: u8,
+    /// The address of the `.eh_frame` section
+    pub eh_frame_ptr: u64,
+    /// The number of entries in the binary search table (zero if the table is omitted)
+    pub fde_count: u64,
+    /// The encoding of the binary search table's entries
+    pub table_encoding: u8,
+    reader: PointerReader<E>,
+    table: &'data [u8],
+    table_offset: usize,
+}
+
+impl<'data, E: EndianParse> EhFrameHdr<'data, E> {
+    /// Parse an `.eh_frame_hdr` section from its data and the virtual address it's loaded
+    /// at (its sh_addr), which its pointers are resolved against.
+    pub fn parse(
+        endian: E,
+        class: Class,
+        address: u64,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let version = endian.parse_u8_at(&mut offset, data)?;
+        if version != 1 {
+            return Err(ParseError::UnsupportedVersion((version as u64, 1)));
+        }
+        let eh_frame_ptr_encoding = endian.parse_u8_at(&mut offset, data)?;
+        let fde_count_encoding = endian.parse_u8_at(&mut offset, data)?;
+        let table_encoding = endian.parse_u8_at(&mut offset, data)?;
+
+        // The table's entries are relative to the start of the .eh_frame_hdr section
+        let reader = PointerReader {
+            endian,
+            class,
+            address,
+            data_base: Some(address),
+        };
+        let eh_frame_ptr = reader.read(eh_frame_ptr_encoding, &mut offset, data)?;
+
+        let mut fde_count = 0;
+        if fde_count_encoding != abi::DW_EH_PE_OMIT && table_encoding != abi::DW_EH_PE_OMIT {
+            fde_count = reader.read(fde_count_encoding, &mut offset, data)?;
+        }
+
+        Ok(EhFrameHdr {
+            version,
+            eh_frame_ptr,
+            fde_count,
+            table_encoding,
+            reader,
+            table: data,
+            table_offset: offset,
+        })
+    }
+
+    /// The size of each of the table's values. The table can only be binary searched if
+    /// its values all have the same fixed size.
+    fn table_value_size(&self) -> Result<usize, ParseError> {
+        match self.table_encoding & 0x0f {
+            abi::DW_EH_PE_UDATA2 | abi::DW_EH_PE_SDATA2 => Ok(2),
+            abi::DW_EH_PE_UDATA4 | abi::DW_EH_PE_SDATA4 => Ok(4),
+            abi::DW_EH_PE_UDATA8 | abi::DW_EH_PE_SDATA8 => Ok(8),
+            abi::DW_EH_PE_ABSPTR => Ok(match self.reader.class {
+                Class::ELF32 => 4,
+                Class::ELF64 => 8,
+            }),
+            _ => Err(ParseError::UnsupportedPointerEncoding(self.table_encoding)),
+        }
+    }
+
+    /// Get the table entry at the given index, as a pair of
+    /// (initial location of the FDE's address range, address of the FDE).
+    pub fn get(&self, index: usize) -> Result<(u64, u64), ParseError> {
+        if index as u64 >= self.fde_count {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        let entry_size = self.table_value_size()? * 2;
+        let mut offset = index
+            .checked_mul(entry_size)
+            .and_then(|offset| offset.checked_add(self.table_offset))
+            .ok_or(ParseError::IntegerOverflow)?;
+        let initial_location = self
+            .reader
+            .read(self.table_encoding, &mut offset, self.table)?;
+        let fde_address = self
+            .reader
+            .read(self.table_encoding, &mut offset, self.table)?;
+        Ok((initial_location, fde_address))
+    }
+
+    /// Binary search the table for the address of the FDE whose range could contain the
+    /// given address: the one with the greatest initial location that's not above it.
+    ///
+    /// The FDE still needs to be checked to see if its range actually contains the address,
+    /// as there may be gaps between FDEs.
+    pub fn lookup(&self, pc: u64) -> Result<Option<u64>, ParseError> {
+        let (mut low, mut high) = (0u64, self.fde_count);
+        let mut found = None;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (initial_location, fde_address) = self.get(mid.try_into()?)?;
+            if initial_location <= pc {
+                found = Some(fde_address);
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(found)
+    }
+}
+
+/// An entry in the `.eh_frame` section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EhFrameEntry<'data> {
+    Cie(Cie<'data>),
+    Fde(Fde<'data>),
+}
+
+/// A Common Information Entry, which holds the information shared between the FDEs which
+/// refer to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cie<'data> {
+    /// The offset of this entry from the start of the section
+    pub offset: usize,
+    pub version: u8,
+    /// The augmentation string (without its NUL terminator), which says which of the
+    /// optional fields below are present, e.g. "zR" or "zPLR"
+    pub augmentation: &'data [u8],
+    /// The factor that all advance location instructions are multiplied by
+    pub code_alignment_factor: u64,
+    /// The factor that all offset instructions are multiplied by
+    pub data_alignment_factor: i64,
+    /// The column in the rule table which holds the return address
+    pub return_address_register: u64,
+    /// The encoding of the pointers in this CIE's FDEs ('R' augmentation).
+    /// [DW_EH_PE_ABSPTR](abi::DW_EH_PE_ABSPTR) if not present.
+    pub fde_pointer_encoding: u8,
+    /// The encoding of the LSDA pointers in this CIE's FDEs ('L' augmentation)
+    pub lsda_encoding: Option<u8>,
+    /// The encoding and address of the personality routine ('P' augmentation).
+    ///
+    /// If the encoding includes [DW_EH_PE_INDIRECT](abi::DW_EH_PE_INDIRECT), as is typical,
+    /// then the address is where the pointer to the personality routine is stored.
+    pub personality: Option<(u8, u64)>,
+    /// Whether this CIE describes signal handler frames ('S' augmentation)
+    pub is_signal_frame: bool,
+    /// The call frame instructions which set up the initial rules for this CIE's FDEs
+    pub initial_instructions: &'data [u8],
+}
+
+/// A Frame Description Entry, which describes how to unwind a single function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fde<'data> {
+    /// The offset of this entry from the start of the section
+    pub offset: usize,
+    /// The offset of this FDE's CIE from the start of the section
+    pub cie_offset: usize,
+    /// The address of the first instruction covered by this FDE
+    pub pc_begin: u64,
+    /// The number of bytes of instructions covered by this FDE
+    pub pc_range: u64,
+    /// The address of the Language Specific Data Area (e.g. C++ exception tables), if any
+    pub lsda: Option<u64>,
+    /// The call frame instructions for this FDE's address range
+    pub instructions: &'data [u8],
+}
+
+impl<'data> Fde<'data> {
+    /// The address just past the last instruction covered by this FDE
+    pub fn pc_end(&self) -> u64 {
+        self.pc_begin.wrapping_add(self.pc_range)
+    }
+
+    /// Returns true if the given address falls within this FDE's address range
+    pub fn contains(&self, pc: u64) -> bool {
+        self.pc_begin <= pc && pc < self.pc_end()
+    }
+}
+
+/// Iterator over the entries in an `.eh_frame` section.
+///
+/// Iteration ends at the end of the section or at a zero-length terminator entry. An entry
+/// which fails to parse is yielded as an error, after which iteration ends.
+#[derive(Debug)]
+pub struct EhFrameIterator<'data, E: EndianParse> {
+    eh_frame: EhFrame<'data, E>,
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for EhFrameIterator<'data, E> {
+    type Item = Result<EhFrameEntry<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.eh_frame.data.len() {
+            return None;
+        }
+
+        match self.eh_frame.entry_at(self.offset) {
+            Ok(Some((next, entry))) => {
+                self.offset = next;
+                Some(Ok(entry))
+            }
+            Ok(None) => {
+                self.offset = self.eh_frame.data.len();
+                None
+            }
+            Err(err) => {
+                self.offset = self.eh_frame.data.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Decodes DW_EH_PE_* encoded pointers.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PointerReader<E: EndianParse> {
+    pub endian: E,
+    pub class: Class,
+    /// The address of the start of the data being read, for pc-relative pointers
+    pub address: u64,
+    /// The base address for data-relative pointers, if they're allowed
+    pub data_base: Option<u64>,
+}
+
+impl<E: EndianParse> PointerReader<E> {
+    /// Read a pointer in the given encoding.
+    ///
+    /// The [DW_EH_PE_INDIRECT](abi::DW_EH_PE_INDIRECT) flag is ignored, as there is no memory
+    /// image to read the real pointer from.
+    pub fn read(&self, encoding: u8, offset: &mut usize, data: &[u8]) -> Result<u64, ParseError> {
+        let position = *offset as u64;
+        let base = match encoding & 0x70 {
+            abi::DW_EH_PE_ABSPTR => 0,
+            abi::DW_EH_PE_PCREL => self.address.wrapping_add(position),
+            abi::DW_EH_PE_DATAREL => self
+                .data_base
+                .ok_or(ParseError::UnsupportedPointerEncoding(encoding))?,
+            _ => return Err(ParseError::UnsupportedPointerEncoding(encoding)),
+        };
+
+        let value = match encoding & 0x0f {
+            abi::DW_EH_PE_ABSPTR => match self.class {
+                Class::ELF32 => self.endian.parse_u32_at(offset, data)? as u64,
+                Class::ELF64 => self.endian.parse_u64_at(offset, data)?,
+            },
+            abi::DW_EH_PE_ULEB128 => parse_uleb128_at(offset, data)?,
+            abi::DW_EH_PE_UDATA2 => self.endian.parse_u16_at(offset, data)? as u64,
+            abi::DW_EH_PE_UDATA4 => self.endian.parse_u32_at(offset, data)? as u64,
+            abi::DW_EH_PE_UDATA8 => self.endian.parse_u64_at(offset, data)?,
+            abi::DW_EH_PE_SLEB128 => parse_sleb128_at(offset, data)? as u64,
+            abi::DW_EH_PE_SDATA2 => self.endian.parse_u16_at(offset, data)? as i16 as u64,
+            abi::DW_EH_PE_SDATA4 => self.endian.parse_i32_at(offset, data)? as u64,
+            abi::DW_EH_PE_SDATA8 => self.endian.parse_i64_at(offset, data)? as u64,
+            _ => return Err(ParseError::UnsupportedPointerEncoding(encoding)),
+        };
+
+        let pointer = base.wrapping_add(value);
+        Ok(match self.class {
+            Class::ELF32 => pointer & 0xffffffff,
+            Class::ELF64 => pointer,
+        })
+    }
+}
+
+/// Parse an unsigned LEB128 encoded integer.
+pub(crate) fn parse_uleb128_at(offset: &mut usize, data: &[u8]) -> Result<u64, ParseError> {
+    let mut result = 0u64;
+    let mut shift = 0u32;
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or(ParseError::SliceReadError((*offset, *offset + 1)))?;
+        *offset += 1;
+
+        let bits = (byte & 0x7f) as u64;
+        if shift < 64 {
+            if shift == 63 && bits > 1 {
+                return Err(ParseError::IntegerOverflow);
+            }
+            result |= bits << shift;
+        } else if bits != 0 {
+            return Err(ParseError::IntegerOverflow);
+        }
+
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Parse a signed LEB128 encoded integer.
+pub(crate) fn parse_sleb128_at(offset: &mut usize, data: &[u8]) -> Result<i64, ParseError> {
+    let mut result = 0i64;
+    let mut shift = 0u32;
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or(ParseError::SliceReadError((*offset, *offset + 1)))?;
+        *offset += 1;
+
+        if shift < 64 {
+            result |= ((byte & 0x7f) as i64) << shift;
+        }
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            // Sign extend from the last byte's sign bit
+            if shift < 64 && byte & 0x40 != 0 {
+                result |= -1i64 << shift;
+            }
+            return Ok(result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn uleb128() {
+        let data = [0x02, 0x7f, 0x80, 0x01, 0xe5, 0x8e, 0x26];
+        let mut offset = 0;
+        assert_eq!(parse_uleb128_at(&mut offset, &data).unwrap(), 2);
+        assert_eq!(parse_uleb128_at(&mut offset, &data).unwrap(), 127);
+        assert_eq!(parse_uleb128_at(&mut offset, &data).unwrap(), 128);
+        assert_eq!(parse_uleb128_at(&mut offset, &data).unwrap(), 624485);
+        assert_eq!(offset, data.len());
+
+        let err = parse_uleb128_at(&mut offset, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((7, 8))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn uleb128_overflow_errors() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        let err = parse_uleb128_at(&mut 0, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        assert_eq!(parse_uleb128_at(&mut 0, &data).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn sleb128() {
+        let data = [0x02, 0x7e, 0xff, 0x00, 0x81, 0x7f, 0x80, 0x7f, 0x78];
+        let mut offset = 0;
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), 2);
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), -2);
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), 127);
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), -127);
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), -128);
+        assert_eq!(parse_sleb128_at(&mut offset, &data).unwrap(), -8);
+        assert_eq!(offset, data.len());
+    }
+
+    #[test]
+    fn read_pointers() {
+        let reader = PointerReader {
+            endian: LittleEndian,
+            class: Class::ELF64,
+            address: 0x1000,
+            data_base: None,
+        };
+        let data = [0xf0, 0xff, 0xff, 0xff];
+
+        let mut offset = 0;
+        let ptr = reader
+            .read(abi::DW_EH_PE_UDATA4, &mut offset, &data)
+            .unwrap();
+        assert_eq!(ptr, 0xfffffff0);
+        assert_eq!(offset, 4);
+
+        let encoding = abi::DW_EH_PE_PCREL | abi::DW_EH_PE_SDATA4;
+        let ptr = reader.read(encoding, &mut 0, &data).unwrap();
+        assert_eq!(ptr, 0xff0);
+
+        let encoding = abi::DW_EH_PE_DATAREL | abi::DW_EH_PE_SDATA4;
+        let err = reader
+            .read(encoding, &mut 0, &data)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedPointerEncoding(0x3b)),
+            "Unexpected Error type found: {err}"
+        );
+        let reader = PointerReader {
+            data_base: Some(0x2000),
+            ..reader
+        };
+        assert_eq!(reader.read(encoding, &mut 0, &data).unwrap(), 0x1ff0);
+
+        let err = reader
+            .read(abi::DW_EH_PE_TEXTREL, &mut 0, &data)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedPointerEncoding(0x20)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_cie_and_fde_with_augmentations() {
+        #[rustfmt::skip]
+        let data = [
+            // CIE
+            0x18, 0x00, 0x00, 0x00, // length
+            0x00, 0x00, 0x00, 0x00, // CIE id
+            0x01, // version
+            b'z', b'P', b'L', b'R', 0x00, // augmentation
+            0x01, // code alignment factor
+            0x7c, // data alignment factor: -4
+            0x08, // return address register
+            0x07, // augmentation data length
+            0x03, 0x00, 0x20, 0x00, 0x00, // personality: udata4 0x2000
+            0x00, // lsda encoding: absptr
+            0x1b, // fde encoding: pcrel | sdata4
+            0x0c, 0x04, 0x04, // initial instructions
+            // FDE
+            0x14, 0x00, 0x00, 0x00, // length
+            0x20, 0x00, 0x00, 0x00, // CIE pointer
+            0xdc, 0xff, 0xff, 0xff, // pc begin: -0x24 from 0x1024
+            0x10, 0x00, 0x00, 0x00, // pc range
+            0x04, // augmentation data length
+            0x00, 0x30, 0x00, 0x00, // lsda
+            0x41, 0x0e, 0x08, // instructions
+            // terminator
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF32, 0x1000, &data);
+        let entries: Vec<EhFrameEntry<'_>> = eh_frame
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(entries.len(), 2);
+
+        let cie = Cie {
+            offset: 0,
+            version: 1,
+            augmentation: b"zPLR",
+            code_alignment_factor: 1,
+            data_alignment_factor: -4,
+            return_address_register: 8,
+            fde_pointer_encoding: 0x1b,
+            lsda_encoding: Some(abi::DW_EH_PE_ABSPTR),
+            personality: Some((abi::DW_EH_PE_UDATA4, 0x2000)),
+            is_signal_frame: false,
+            initial_instructions: &[0x0c, 0x04, 0x04],
+        };
+        assert_eq!(entries[0], EhFrameEntry::Cie(cie.clone()));
+
+        let fde = Fde {
+            offset: 0x1c,
+            cie_offset: 0,
+            pc_begin: 0x1000,
+            pc_range: 0x10,
+            lsda: Some(0x3000),
+            instructions: &[0x41, 0x0e, 0x08],
+        };
+        assert_eq!(entries[1], EhFrameEntry::Fde(fde.clone()));
+        assert!(fde.contains(0x100f));
+        assert!(!fde.contains(0x1010));
+
+        assert_eq!(eh_frame.cie_at(0).unwrap(), cie);
+        assert_eq!(eh_frame.fde_at(0x1c).unwrap(), fde);
+        let err = eh_frame.cie_at(0x1c).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x1c)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_cie_msb_without_augmentation() {
+        #[rustfmt::skip]
+        let data = [
+            0x00, 0x00, 0x00, 0x0c, // length
+            0x00, 0x00, 0x00, 0x00, // CIE id
+            0x03, // version
+            0x00, // augmentation
+            0x04, // code alignment factor
+            0x78, // data alignment factor: -8
+            0x41, // return address register
+            0x0c, 0x01, 0x00, // initial instructions
+        ];
+        let eh_frame = EhFrame::new(BigEndian, Class::ELF64, 0, &data);
+        let cie = eh_frame.cie_at(0).expect("should parse");
+        assert_eq!(cie.version, 3);
+        assert_eq!(cie.augmentation, b"");
+        assert_eq!(cie.code_alignment_factor, 4);
+        assert_eq!(cie.data_alignment_factor, -8);
+        assert_eq!(cie.return_address_register, 0x41);
+        assert_eq!(cie.fde_pointer_encoding, abi::DW_EH_PE_ABSPTR);
+        assert_eq!(cie.initial_instructions, [0x0c, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn parse_truncated_entry_errors() {
+        let data = [0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0, &data);
+        let mut iter = eh_frame.iter();
+        let err = iter.next().unwrap().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((0, 0x24))),
+            "Unexpected Error type found: {err}"
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn parse_fde_pointing_at_itself_errors() {
+        #[rustfmt::skip]
+        let data = [
+            0x0c, 0x00, 0x00, 0x00, // length
+            0x04, 0x00, 0x00, 0x00, // CIE pointer: back to offset 0, this FDE
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0, &data);
+        let err = eh_frame.fde_at(0).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+        let err = eh_frame.iter().next().unwrap().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_fde_pointing_at_fde_errors() {
+        #[rustfmt::skip]
+        let data = [
+            // An FDE whose CIE pointer is bogus
+            0x0c, 0x00, 0x00, 0x00, // length
+            0x04, 0x00, 0x00, 0x00, // CIE pointer: offset 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // An FDE pointing at the first FDE rather than a CIE
+            0x0c, 0x00, 0x00, 0x00, // length
+            0x14, 0x00, 0x00, 0x00, // CIE pointer: offset 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0, &data);
+        let err = eh_frame.fde_at(0x10).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+        let err = eh_frame.cie_at(0x10).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x10)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_eh_frame_hdr() {
+        #[rustfmt::skip]
+        let data = [
+            0x01, // version
+            0x1b, // eh_frame_ptr encoding: pcrel | sdata4
+            0x03, // fde_count encoding: udata4
+            0x3b, // table encoding: datarel | sdata4
+            0x10, 0x00, 0x00, 0x00, // eh_frame_ptr
+            0x02, 0x00, 0x00, 0x00, // fde_count
+            0x00, 0xf0, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00,
+            0x00, 0xf1, 0xff, 0xff, 0x40, 0x00, 0x00, 0x00,
+        ];
+        let hdr = EhFrameHdr::parse(LittleEndian, Class::ELF64, 0x2000, &data).unwrap();
+        assert_eq!(hdr.version, 1);
+        assert_eq!(hdr.eh_frame_ptr, 0x2014);
+        assert_eq!(hdr.fde_count, 2);
+        assert_eq!(hdr.get(0).unwrap(), (0x1000, 0x2020));
+        assert_eq!(hdr.get(1).unwrap(), (0x1100, 0x2040));
+        assert!(matches!(hdr.get(2), Err(ParseError::BadOffset(2))));
+
+        assert_eq!(hdr.lookup(0xfff).unwrap(), None);
+        assert_eq!(hdr.lookup(0x1000).unwrap(), Some(0x2020));
+        assert_eq!(hdr.lookup(0x10ff).unwrap(), Some(0x2020));
+        assert_eq!(hdr.lookup(0x1100).unwrap(), Some(0x2040));
+        assert_eq!(hdr.lookup(0xffffffff).unwrap(), Some(0x2040));
+    }
+
+    #[test]
+    fn parse_eh_frame_hdr_without_table() {
+        let data = [0x01, 0x1b, 0xff, 0xff, 0x10, 0x00, 0x00, 0x00];
+        let hdr = EhFrameHdr::parse(BigEndian, Class::ELF32, 0x2000, &data).unwrap();
+        assert_eq!(hdr.eh_frame_ptr, 0x10002004);
+        assert_eq!(hdr.fde_count, 0);
+        assert_eq!(hdr.lookup(0x1000).unwrap(), None);
+    }
+
+    #[test]
+    fn eh_frame_hdr_leb128_table_errors() {
+        let data = [
+            0x01, 0x1b, 0x03, 0x01, 0x10, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0x01,
+        ];
+        let hdr = EhFrameHdr::parse(LittleEndian, Class::ELF64, 0, &data).unwrap();
+        let err = hdr.get(0).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedPointerEncoding(0x01)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_bad_version_errors() {
+        let data = [
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0, &data);
+        let err = eh_frame.cie_at(0).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedVersion((2, 1))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -1,19 +1,36 @@
 use crate::abi;
+use crate::attributes::AttributesSection;
 use crate::compression::CompressionHeader;
-use crate::dynamic::{Dyn, DynamicTable};
+use crate::debug::{DebugSection, DebugSectionIterator};
+use crate::debuglink::{gnu_debuglink_crc32, DebugFileMatch, DebugLink};
+use crate::dynamic::{Dyn, DynamicStringIterator, DynamicTable, SearchPath};
+use crate::ehframe::{EhFrame, EhFrameHdr};
 use crate::endian::EndianParse;
-use crate::file::{parse_ident, Class, FileHeader};
+use crate::file::{parse_ident, Class, FileHeader, FileKind, ObjectFileType};
 use crate::gnu_symver::{
-    SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
+    ExportIterator, ImportIterator, SymbolVersionTable, VerDefIterator, VerNeedIterator,
+    VersionIndex, VersionIndexTable, VersionedSymbolIterator,
 };
+use crate::gopclntab::GoPcLnTab;
+use crate::group::{SectionGroup, SectionGroupIterator};
 use crate::hash::{GnuHashTable, SysVHashTable};
-use crate::note::NoteIterator;
+#[cfg(feature = "std")]
+use crate::image::MemoryImage;
+use crate::init_array::FunctionArray;
+use crate::language::{self, LanguageGuess};
+#[cfg(feature = "xz")]
+use crate::minidebuginfo::MiniDebugInfo;
+use crate::mips::{MipsAbiFlags, MipsRegInfo};
+use crate::note::{Note, NoteGnuAbiTag, NoteGnuBuildId, NoteGnuProperty, NoteIterator};
 use crate::parse::{ParseAt, ParseError, ReadBytesExt};
-use crate::relocation::{RelIterator, RelaIterator};
+use crate::plt::{PltEntryIterator, PltLayout};
+use crate::relocation::{RelIterator, RelaIterator, RelrIterator};
 use crate::section::{SectionHeader, SectionHeaderTable};
-use crate::segment::{ProgramHeader, SegmentTable};
-use crate::string_table::StringTable;
-use crate::symbol::{Symbol, SymbolTable};
+#[cfg(feature = "std")]
+use crate::segment::LoadableSegment;
+use crate::segment::{ProgramHeader, SegmentTable, TlsTemplate};
+use crate::string_table::{StringTable, StringTableIterator};
+use crate::symbol::{Symbol, SymbolShndxTable, SymbolTable};
 
 //  _____ _     _____ ____        _
 // | ____| |   |  ___| __ ) _   _| |_ ___  ___
@@ -78,10 +95,13 @@ fn find_shdrs<'data, E: EndianParse>(
     data: &'data [u8],
 ) -> Result<Option<SectionHeaderTable<'data, E>>, ParseError> {
     // It's Ok to have no section headers
-    if ehdr.e_shoff == 0 {
+    if ehdr.e_shoff == 0 && ehdr.e_shnum == 0 {
         return Ok(None);
     }
 
+    // ...but a corrupted offset must not point the table back into the file header itself
+    ehdr.validate_table_offset(ehdr.e_shoff)?;
+
     // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
     // e_shnum is zero and the actual number of section header table entries
     // is contained in the sh_size field of the section header at index 0.
@@ -94,16 +114,17 @@ fn find_shdrs<'data, E: EndianParse>(
     }
 
     // Validate shentsize before trying to read the table so that we can error early for corrupted files
-    let entsize = SectionHeader::validate_entsize(ehdr.class, ehdr.e_shentsize as usize)?;
+    let entsize = SectionHeader::validate_stride(ehdr.class, ehdr.e_shentsize as usize)?;
 
     let size = entsize
         .checked_mul(shnum)
         .ok_or(ParseError::IntegerOverflow)?;
     let end = shoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
     let buf = data.get_bytes(shoff..end)?;
-    Ok(Some(SectionHeaderTable::new(
+    Ok(Some(SectionHeaderTable::with_entsize(
         ehdr.endianness,
         ehdr.class,
+        entsize,
         buf,
     )))
 }
@@ -115,15 +136,21 @@ fn find_phdrs<'data, E: EndianParse>(
     data: &'data [u8],
 ) -> Result<Option<SegmentTable<'data, E>>, ParseError> {
     // It's Ok to have no program headers
-    if ehdr.e_phoff == 0 {
+    if ehdr.e_phoff == 0 && ehdr.e_phnum == 0 {
         return Ok(None);
     }
 
+    // ...but a corrupted offset must not point the table back into the file header itself
+    if ehdr.e_phnum != 0 {
+        ehdr.validate_table_offset(ehdr.e_phoff)?;
+    }
+
     // If the number of segments is greater than or equal to PN_XNUM (0xffff),
     // e_phnum is set to PN_XNUM, and the actual number of program header table
     // entries is contained in the sh_info field of the section header at index 0.
     let mut phnum = ehdr.e_phnum as usize;
     if phnum == abi::PN_XNUM as usize {
+        ehdr.validate_table_offset(ehdr.e_shoff)?;
         let shoff: usize = ehdr.e_shoff.try_into()?;
         let mut offset = shoff;
         let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
@@ -131,7 +158,7 @@ fn find_phdrs<'data, E: EndianParse>(
     }
 
     // Validate phentsize before trying to read the table so that we can error early for corrupted files
-    let entsize = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize)?;
+    let entsize = ProgramHeader::validate_stride(ehdr.class, ehdr.e_phentsize as usize)?;
 
     let phoff: usize = ehdr.e_phoff.try_into()?;
     let size = entsize
@@ -139,7 +166,12 @@ fn find_phdrs<'data, E: EndianParse>(
         .ok_or(ParseError::IntegerOverflow)?;
     let end = phoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
     let buf = data.get_bytes(phoff..end)?;
-    Ok(Some(SegmentTable::new(ehdr.endianness, ehdr.class, buf)))
+    Ok(Some(SegmentTable::with_entsize(
+        ehdr.endianness,
+        ehdr.class,
+        entsize,
+        buf,
+    )))
 }
 
 /// This struct collects the common sections found in ELF objects
@@ -195,6 +227,12 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         })
     }
 
+    /// The whole object's data, as passed to [minimal_parse](ElfBytes::minimal_parse)
+    #[cfg(feature = "std")]
+    pub(crate) fn data(&self) -> &'data [u8] {
+        self.data
+    }
+
     /// Get this Elf object's zero-alloc lazy-parsing [SegmentTable] (if any).
     ///
     /// This table parses [ProgramHeader]s on demand and does not make any internal heap allocations
@@ -351,6 +389,102 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }))
     }
 
+    /// Get the given section's name from the section header string table.
+    ///
+    /// The name is looked up on demand rather than copied out, and borrows from the file data.
+    /// Returns Ok(None) if the object has no section header string table, and a [ParseError]
+    /// if e_shstrndx or the section's [sh_name](SectionHeader#structfield.sh_name) is out of
+    /// range.
+    pub fn section_name(&self, shdr: &SectionHeader) -> Result<Option<&'data str>, ParseError> {
+        match self.section_headers_with_strtab()? {
+            (_, Some(strtab)) => Ok(Some(strtab.get(shdr.sh_name as usize)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get an iterator over the [SectionHeader]s with the given [sh_type](SectionHeader#structfield.sh_type).
+    ///
+    /// This is useful for finding sections such as [SHT_NOTE](abi::SHT_NOTE) in objects whose
+    /// section names were stripped or renamed. Objects without a section header table yield
+    /// nothing.
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::abi;
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let notes: Vec<u64> = file
+    ///     .section_headers_by_type(abi::SHT_NOTE)
+    ///     .map(|shdr| shdr.sh_addr)
+    ///     .collect();
+    /// assert_eq!(notes, [0x40021c, 0x40023c]);
+    /// ```
+    pub fn section_headers_by_type(
+        &self,
+        sh_type: u32,
+    ) -> impl Iterator<Item = SectionHeader> + 'data
+    where
+        E: 'data,
+    {
+        self.shdrs
+            .into_iter()
+            .flat_map(|shdrs| shdrs.iter())
+            .filter(move |shdr| shdr.sh_type == sh_type)
+    }
+
+    /// Get the first [SectionHeader] with the given [sh_type](SectionHeader#structfield.sh_type) (if any).
+    pub fn section_header_by_type(&self, sh_type: u32) -> Option<SectionHeader> {
+        self.section_headers_by_type(sh_type).next()
+    }
+
+    /// Get an iterator over the [SectionHeader]s which lie within the given segment, as
+    /// determined by [ProgramHeader::contains_section].
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::abi;
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let phdr = file
+    ///     .segments()
+    ///     .expect("File should have a segment table")
+    ///     .iter()
+    ///     .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
+    ///     .expect("File should have a PT_DYNAMIC");
+    /// let shdrs: Vec<u32> = file.sections_in_segment(&phdr).map(|shdr| shdr.sh_type).collect();
+    /// assert_eq!(shdrs, [abi::SHT_DYNAMIC]);
+    /// ```
+    pub fn sections_in_segment(
+        &self,
+        phdr: &ProgramHeader,
+    ) -> impl Iterator<Item = SectionHeader> + 'data
+    where
+        E: 'data,
+    {
+        let phdr = *phdr;
+        self.shdrs
+            .into_iter()
+            .flat_map(|shdrs| shdrs.iter())
+            .filter(move |shdr| phdr.contains_section(shdr))
+    }
+
+    /// Get the [PT_LOAD](abi::PT_LOAD) segment which the given section is loaded by (if any).
+    pub fn segment_for_section(&self, shdr: &SectionHeader) -> Option<ProgramHeader> {
+        self.phdrs?
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_LOAD && phdr.contains_section(shdr))
+    }
+
     /// Efficiently locate the set of common sections found in ELF files by doing a single iteration
     /// over the SectionHeaders table.
     ///
@@ -435,18 +569,17 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     ///
     /// Users who wish to work with compressed sections must pick their compression library of choice
     /// and do the decompression themselves. The only two options supported by the ELF spec for section
-    /// compression are: [abi::ELFCOMPRESS_ZLIB] and [abi::ELFCOMPRESS_ZSTD].
+    /// compression are: [abi::ELFCOMPRESS_ZLIB] and [abi::ELFCOMPRESS_ZSTD]. Alternatively, the `zlib`
+    /// and `zstd` cargo features provide [ElfBytes::section_data_decompressed].
     pub fn section_data(
         &self,
         shdr: &SectionHeader,
     ) -> Result<(&'data [u8], Option<CompressionHeader>), ParseError> {
+        let buf = self.section_raw_data(shdr)?;
         if shdr.sh_type == abi::SHT_NOBITS {
-            return Ok((&[], None));
+            return Ok((buf, None));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.data.get_bytes(start..end)?;
-
         if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
             Ok((buf, None))
         } else {
@@ -465,6 +598,67 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }
     }
 
+    /// Get the section's bytes from the file as-is, including any compression header.
+    /// SHT_NOBITS sections have no data in the file.
+    pub(crate) fn section_raw_data(&self, shdr: &SectionHeader) -> Result<&'data [u8], ParseError> {
+        if shdr.sh_type == abi::SHT_NOBITS {
+            return Ok(&[]);
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        self.data.get_bytes(start..end)
+    }
+
+    /// Get the section data for a given [SectionHeader], decompressing it if it is compressed.
+    ///
+    /// Uncompressed section data is returned as-is, without being copied. Returns
+    /// [ParseError::UnsupportedCompression] for compression types whose cargo feature
+    /// (`zlib` or `zstd`) isn't enabled. See [compression::decompress](crate::compression::decompress).
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn section_data_decompressed(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<std::borrow::Cow<'data, [u8]>, ParseError> {
+        match self.section_data(shdr)? {
+            (buf, None) => Ok(std::borrow::Cow::Borrowed(buf)),
+            (buf, Some(chdr)) => Ok(std::borrow::Cow::Owned(crate::compression::decompress(
+                &chdr, buf,
+            )?)),
+        }
+    }
+
+    /// Get an iterator over the object's DWARF debug sections: those named `.debug_*` or
+    /// `.zdebug_*`.
+    ///
+    /// Returns Ok(None) if the object has no section headers or section name string table.
+    pub fn debug_sections(&self) -> Result<Option<DebugSectionIterator<'data, E>>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => {
+                return Ok(None);
+            }
+        };
+        Ok(Some(DebugSectionIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdrs.iter(),
+            strtab,
+            self.data,
+        )))
+    }
+
+    /// Find the DWARF debug section with the given canonical name (e.g. `.debug_info`), which
+    /// may be stored as either a `.debug_*` or a GNU-style compressed `.zdebug_*` section.
+    ///
+    /// Only the matching section is parsed, so this returns an error if that section is
+    /// malformed but not if other debug sections are. See [debug](crate::debug) for an example.
+    pub fn debug_section(&self, name: &str) -> Result<Option<DebugSection<'data>>, ParseError> {
+        match self.debug_sections()? {
+            Some(sections) => sections.find_named(name),
+            None => Ok(None),
+        }
+    }
+
     /// Get the section data for a given [SectionHeader], and interpret it as a [StringTable]
     ///
     /// Returns a ParseError if the section is not of type [abi::SHT_STRTAB]
@@ -525,6 +719,327 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         ))
     }
 
+    /// Get the section data for a given [SectionHeader], and interpret it as an
+    /// iterator over the relocated offsets in a compact relative relocation table
+    /// [RelrIterator](crate::relocation::RelrIterator)
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_RELR]
+    pub fn section_data_as_relrs(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<RelrIterator<'data, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_RELR {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_RELR,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(RelrIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Get an iterator over the relocated offsets in the RELR table referenced by the dynamic
+    /// table's [DT_RELR](abi::DT_RELR) and [DT_RELRSZ](abi::DT_RELRSZ) entries (if any).
+    ///
+    /// The table is located through its virtual address range in the PT_LOAD segments, so
+    /// this works for objects without section headers.
+    ///
+    /// Returns Ok(None) if the object has no dynamic table or no DT_RELR/DT_RELRSZ entries, and
+    /// a [ParseError::BadEntsize] if DT_RELRENT isn't the size of an address.
+    pub fn relr_relocations(&self) -> Result<Option<RelrIterator<'data, E>>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => {
+                return Ok(None);
+            }
+        };
+        let (addr, size) = match (dynamic.find(abi::DT_RELR), dynamic.find(abi::DT_RELRSZ)) {
+            (Some(relr), Some(relrsz)) => (relr.d_ptr(), relrsz.d_val()),
+            _ => {
+                return Ok(None);
+            }
+        };
+        let word_size = match self.ehdr.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        if let Some(relrent) = dynamic.find(abi::DT_RELRENT) {
+            if relrent.d_val() != word_size {
+                return Err(ParseError::BadEntsize((relrent.d_val(), word_size)));
+            }
+        }
+
+        Ok(self
+            .load_segment_data_at(addr, size)?
+            .map(|buf| RelrIterator::new(self.ehdr.endianness, self.ehdr.class, buf)))
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as an
+    /// [AttributesSection]
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_GNU_ATTRIBUTES] or a
+    /// processor-specific attributes section type for the object's machine
+    /// (e.g. [abi::SHT_ARM_ATTRIBUTES]).
+    pub fn section_data_as_attributes(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<AttributesSection<'data, E>, ParseError> {
+        if !self.is_attributes_section(shdr) {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                self.proc_attributes_section_type()
+                    .unwrap_or(abi::SHT_GNU_ATTRIBUTES),
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        AttributesSection::new(self.ehdr.endianness, buf)
+    }
+
+    /// Find and parse the object's build attributes section (if any), e.g. `.ARM.attributes`
+    /// or `.riscv.attributes`.
+    ///
+    /// See [attributes](crate::attributes) for an example.
+    pub fn build_attributes(&self) -> Result<Option<AttributesSection<'data, E>>, ParseError> {
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        match shdrs.iter().find(|shdr| self.is_attributes_section(shdr)) {
+            Some(shdr) => Ok(Some(self.section_data_as_attributes(&shdr)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Internal helper to get the processor-specific build attributes section type (if any)
+    /// for the object's machine.
+    fn proc_attributes_section_type(&self) -> Option<u32> {
+        match self.ehdr.e_machine {
+            abi::EM_ARM => Some(abi::SHT_ARM_ATTRIBUTES),
+            abi::EM_RISCV => Some(abi::SHT_RISCV_ATTRIBUTES),
+            _ => None,
+        }
+    }
+
+    fn is_attributes_section(&self, shdr: &SectionHeader) -> bool {
+        shdr.sh_type == abi::SHT_GNU_ATTRIBUTES
+            || Some(shdr.sh_type) == self.proc_attributes_section_type()
+    }
+
+    /// Find and parse the MIPS ABI flags from the object's `.MIPS.abiflags` section, or its
+    /// [PT_MIPS_ABIFLAGS](abi::PT_MIPS_ABIFLAGS) segment if it has no section headers.
+    ///
+    /// Returns Ok(None) for objects which aren't MIPS objects or which have no ABI flags.
+    pub fn mips_abiflags(&self) -> Result<Option<MipsAbiFlags>, ParseError> {
+        match self.mips_data(abi::SHT_MIPS_ABIFLAGS, abi::PT_MIPS_ABIFLAGS)? {
+            Some(buf) => Ok(Some(MipsAbiFlags::parse(self.ehdr.endianness, buf)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Find and parse the MIPS register usage information from the object's `.reginfo`
+    /// section, or its [PT_MIPS_REGINFO](abi::PT_MIPS_REGINFO) segment if it has no section
+    /// headers.
+    ///
+    /// Returns Ok(None) for objects which aren't MIPS objects or which have no register info.
+    pub fn mips_reginfo(&self) -> Result<Option<MipsRegInfo>, ParseError> {
+        match self.mips_data(abi::SHT_MIPS_REGINFO, abi::PT_MIPS_REGINFO)? {
+            Some(buf) => {
+                let mut offset = 0;
+                Ok(Some(MipsRegInfo::parse_at(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    &mut offset,
+                    buf,
+                )?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Internal helper to get the data for the first section of type sh_type, or the first
+    /// segment of type p_type if there are no section headers, in a MIPS object.
+    fn mips_data(&self, sh_type: u32, p_type: u32) -> Result<Option<&'data [u8]>, ParseError> {
+        if !matches!(self.ehdr.e_machine, abi::EM_MIPS | abi::EM_MIPS_RS3_LE) {
+            return Ok(None);
+        }
+
+        let (start, end) = if let Some(shdrs) = self.section_headers() {
+            match shdrs.iter().find(|shdr| shdr.sh_type == sh_type) {
+                Some(shdr) => shdr.get_data_range()?,
+                None => return Ok(None),
+            }
+        } else if let Some(phdrs) = self.segments() {
+            match phdrs.iter().find(|phdr| phdr.p_type == p_type) {
+                Some(phdr) => phdr.get_file_data_range()?,
+                None => return Ok(None),
+            }
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(self.data.get_bytes(start..end)?))
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as a
+    /// [SectionGroup](crate::group::SectionGroup)
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_GROUP]
+    pub fn section_data_as_group(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<SectionGroup<'data, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_GROUP {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_GROUP,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        SectionGroup::parse(self.ehdr.endianness, self.ehdr.class, shdr, buf)
+    }
+
+    /// Get an iterator over all the section groups (such as COMDAT groups) in the object.
+    ///
+    /// Section groups only appear in relocatable objects. Returns an empty Option if the
+    /// object has no section headers.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let (symtab, strtab) = file.symbol_table().unwrap().unwrap();
+    /// for group in file.section_groups().unwrap() {
+    ///     let group = group.unwrap();
+    ///     let sym = symtab.get(group.signature_symbol as usize).unwrap();
+    ///     println!(
+    ///         "{}: {} member(s)",
+    ///         strtab.get(sym.st_name as usize).unwrap(),
+    ///         group.members.len()
+    ///     );
+    /// }
+    /// ```
+    pub fn section_groups(&self) -> Option<SectionGroupIterator<'data, E>> {
+        let shdrs = self.section_headers()?;
+        Some(SectionGroupIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdrs.iter(),
+            self.data,
+        ))
+    }
+
+    /// Get the thread-local storage initialization template from the object's
+    /// [PT_TLS](abi::PT_TLS) segment (if any).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/tls.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let tls = file
+    ///     .tls_template()
+    ///     .expect("segment table should be parseable")
+    ///     .expect("file should have a PT_TLS segment");
+    /// let (dynsyms, strtab) = file
+    ///     .dynamic_symbol_table()
+    ///     .expect("dynsym should be parseable")
+    ///     .expect("file should have a dynsym");
+    ///
+    /// for sym in dynsyms.iter().filter(|sym| sym.is_tls()) {
+    ///     // st_value is an offset into each thread's TLS block, not an address
+    ///     let name = strtab.get(sym.st_name as usize).expect("name should be parseable");
+    ///     println!("{name}: offset {} initial value {:?}", sym.st_value, tls.symbol_image(&sym));
+    /// }
+    /// ```
+    pub fn tls_template(&self) -> Result<Option<TlsTemplate<'data>>, ParseError> {
+        let phdrs = match self.segments() {
+            Some(phdrs) => phdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        match phdrs.iter().find(|phdr| phdr.p_type == abi::PT_TLS) {
+            Some(phdr) => Ok(Some(TlsTemplate::new(&phdr, self.segment_data(&phdr)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as a
+    /// [FunctionArray] of constructor or destructor pointers
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_INIT_ARRAY],
+    /// [abi::SHT_FINI_ARRAY], or [abi::SHT_PREINIT_ARRAY]
+    pub fn section_data_as_function_array(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<FunctionArray<'data, E>, ParseError> {
+        if !matches!(
+            shdr.sh_type,
+            abi::SHT_INIT_ARRAY | abi::SHT_FINI_ARRAY | abi::SHT_PREINIT_ARRAY
+        ) {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_INIT_ARRAY,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(FunctionArray::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Get the constructors in the object's [SHT_INIT_ARRAY](abi::SHT_INIT_ARRAY) section (if any).
+    ///
+    /// See [init_array](crate::init_array) for an example.
+    pub fn init_array(&self) -> Result<Option<FunctionArray<'data, E>>, ParseError> {
+        self.function_array(abi::SHT_INIT_ARRAY)
+    }
+
+    /// Get the destructors in the object's [SHT_FINI_ARRAY](abi::SHT_FINI_ARRAY) section (if any).
+    pub fn fini_array(&self) -> Result<Option<FunctionArray<'data, E>>, ParseError> {
+        self.function_array(abi::SHT_FINI_ARRAY)
+    }
+
+    /// Get the pre-constructors in the object's [SHT_PREINIT_ARRAY](abi::SHT_PREINIT_ARRAY)
+    /// section (if any).
+    pub fn preinit_array(&self) -> Result<Option<FunctionArray<'data, E>>, ParseError> {
+        self.function_array(abi::SHT_PREINIT_ARRAY)
+    }
+
+    fn function_array(&self, sh_type: u32) -> Result<Option<FunctionArray<'data, E>>, ParseError> {
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        match shdrs.iter().find(|shdr| shdr.sh_type == sh_type) {
+            Some(shdr) => Ok(Some(self.section_data_as_function_array(&shdr)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Get the section data for a given [SectionHeader], and interpret it as an
     /// iterator over [Note](crate::note::Note)s
     ///
@@ -580,6 +1095,20 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         self.data.get_bytes(start..end)
     }
 
+    /// Get the segment's data as it is laid out in memory for a given [ProgramHeader]: its
+    /// file data followed by zeros up to [p_memsz](ProgramHeader#structfield.p_memsz)
+    /// (e.g. for a `.bss`).
+    ///
+    /// Segments with no zero-filled part are returned as-is, without being copied. Otherwise,
+    /// this allocates p_memsz bytes, and returns [ParseError::AllocationFailed] if that fails.
+    #[cfg(feature = "std")]
+    pub fn segment_data_zero_extended(
+        &self,
+        phdr: &ProgramHeader,
+    ) -> Result<std::borrow::Cow<'data, [u8]>, ParseError> {
+        phdr.zero_extend(self.segment_data(phdr)?)
+    }
+
     /// Get the segment's file data for a given [ProgramHeader], and interpret it as an
     /// iterator over [Note](crate::note::Note)s
     ///
@@ -604,16 +1133,171 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         ))
     }
 
-    /// Get the .dynamic section or [abi::PT_DYNAMIC] segment contents.
-    pub fn dynamic(&self) -> Result<Option<DynamicTable<'data, E>>, ParseError> {
-        // If we have section headers, look for the SHT_DYNAMIC section
-        if let Some(shdrs) = self.section_headers() {
-            if let Some(shdr) = shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC) {
-                return Ok(Some(self.section_data_as_dynamic(&shdr)?));
+    /// Get the path of the program interpreter (dynamic loader) requested by the object, if any.
+    ///
+    /// This reads the [abi::PT_INTERP] segment or, if there are no program headers, the
+    /// `.interp` section, and returns its contents without the terminating NUL.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let interp = file.interpreter().expect("interp should parse");
+    /// assert_eq!(interp, Some("/lib64/ld-linux-x86-64.so.2"));
+    /// ```
+    pub fn interpreter(&self) -> Result<Option<&'data str>, ParseError> {
+        let (start, end) = if let Some(phdrs) = self.segments() {
+            match phdrs.iter().find(|phdr| phdr.p_type == abi::PT_INTERP) {
+                Some(phdr) => phdr.get_file_data_range()?,
+                None => return Ok(None),
             }
-        // Otherwise, look up the PT_DYNAMIC segment (if any)
-        } else if let Some(phdrs) = self.segments() {
-            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
+        } else {
+            match self.section_header_by_name(".interp")? {
+                Some(shdr) => shdr.get_data_range()?,
+                None => return Ok(None),
+            }
+        };
+
+        let buf = self.data.get_bytes(start..end)?;
+        let len = buf
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+        Ok(Some(core::str::from_utf8(&buf[..len])?))
+    }
+
+    /// Returns true if the object needs an executable stack.
+    ///
+    /// This is decided by the flags of the [PT_GNU_STACK](abi::PT_GNU_STACK) segment or, for
+    /// relocatable objects, of the `.note.GNU-stack` section. Objects without either get the
+    /// historical default for their machine: an executable stack, except on AArch64, RISC-V,
+    /// and LoongArch whose stacks have been non-executable by default from the start.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// assert!(!file.stack_is_executable().expect("section table should be parseable"));
+    /// ```
+    pub fn stack_is_executable(&self) -> Result<bool, ParseError> {
+        if let Some(phdrs) = self.segments() {
+            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_GNU_STACK) {
+                return Ok(phdr.p_flags & abi::PF_X != 0);
+            }
+        }
+        if self.ehdr.e_type == abi::ET_REL {
+            if let Some(shdr) = self.section_header_by_name(".note.GNU-stack")? {
+                return Ok(shdr.sh_flags & abi::SHF_EXECINSTR as u64 != 0);
+            }
+        }
+        Ok(!matches!(
+            self.ehdr.e_machine,
+            abi::EM_AARCH64 | abi::EM_RISCV | abi::EM_LOONGARCH
+        ))
+    }
+
+    /// Classify the object as a static, dynamically linked, or position-independent executable,
+    /// a shared library, etc.
+    ///
+    /// ET_DYN objects are both position-independent executables and shared libraries. They're
+    /// classified as executables if their [DT_FLAGS_1](abi::DT_FLAGS_1) has
+    /// [DF_1_PIE](abi::DF_1_PIE) set, or, for objects from linkers which predate that flag, if
+    /// they have a [PT_INTERP](abi::PT_INTERP) segment but no [DT_SONAME](abi::DT_SONAME). That
+    /// keeps libraries which can also be run, like glibc's `libc.so.6`, classified as shared
+    /// libraries.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::file::FileKind;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// assert_eq!(file.ehdr.e_type, elf::abi::ET_DYN);
+    /// assert_eq!(file.kind().expect("dynamic table should be parseable"), FileKind::Pie);
+    /// ```
+    pub fn kind(&self) -> Result<FileKind, ParseError> {
+        let has_interp = self.segments().map_or(false, |phdrs| {
+            phdrs.iter().any(|phdr| phdr.p_type == abi::PT_INTERP)
+        });
+
+        let kind = match self.ehdr.file_type() {
+            ObjectFileType::Relocatable => FileKind::Relocatable,
+            ObjectFileType::Executable if has_interp => FileKind::DynamicExecutable,
+            ObjectFileType::Executable => FileKind::StaticExecutable,
+            ObjectFileType::SharedObject => {
+                let dynamic = self.dynamic()?;
+                let flagged_pie = dynamic
+                    .as_ref()
+                    .and_then(|dynamic| dynamic.find(abi::DT_FLAGS_1))
+                    .map_or(false, |flags| flags.d_val() & abi::DF_1_PIE as u64 != 0);
+                let has_soname = dynamic
+                    .as_ref()
+                    .map_or(false, |dynamic| dynamic.find(abi::DT_SONAME).is_some());
+                match (flagged_pie, has_interp) {
+                    (true, true) => FileKind::Pie,
+                    (true, false) => FileKind::StaticPie,
+                    (false, true) if !has_soname => FileKind::Pie,
+                    (false, _) => FileKind::SharedObject,
+                }
+            }
+            ObjectFileType::Core => FileKind::Core,
+            other => FileKind::Other(other),
+        };
+        Ok(kind)
+    }
+
+    /// Get an iterator over the compiler and linker identification strings in the object's
+    /// `.comment` section (if any).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let comments: Vec<&str> = file
+    ///     .comments()
+    ///     .expect("section table should be parseable")
+    ///     .expect("file should have a .comment section")
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("comments should be utf8");
+    /// assert_eq!(comments, ["GCC: (GNU) 6.1.1 20160501", "GCC: (GNU) 6.1.1 20160602"]);
+    /// ```
+    pub fn comments(&self) -> Result<Option<StringTableIterator<'data>>, ParseError> {
+        let shdr = match self.section_header_by_name(".comment")? {
+            Some(shdr) => shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(StringTable::new(buf).iter()))
+    }
+
+    /// Get the .dynamic section or [abi::PT_DYNAMIC] segment contents.
+    pub fn dynamic(&self) -> Result<Option<DynamicTable<'data, E>>, ParseError> {
+        // If we have section headers, look for the SHT_DYNAMIC section
+        if let Some(shdrs) = self.section_headers() {
+            if let Some(shdr) = shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC) {
+                return Ok(Some(self.section_data_as_dynamic(&shdr)?));
+            }
+        // Otherwise, look up the PT_DYNAMIC segment (if any)
+        } else if let Some(phdrs) = self.segments() {
+            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
                 let (start, end) = phdr.get_file_data_range()?;
                 let buf = self.data.get_bytes(start..end)?;
                 return Ok(Some(DynamicTable::new(
@@ -627,6 +1311,300 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         Ok(None)
     }
 
+    /// Get the string table referenced by the dynamic table's [DT_STRTAB](abi::DT_STRTAB) (if any).
+    ///
+    /// This is the SHT_DYNAMIC section's linked string table if there are section headers.
+    /// Otherwise, it is located through the DT_STRTAB and DT_STRSZ entries' virtual address
+    /// range in the PT_LOAD segments.
+    pub fn dynamic_strtab(&self) -> Result<Option<StringTable<'data>>, ParseError> {
+        if let Some(shdrs) = self.section_headers() {
+            return match shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC) {
+                Some(shdr) => {
+                    let strtab_shdr = shdrs.get(shdr.sh_link as usize)?;
+                    Ok(Some(self.section_data_as_strtab(&strtab_shdr)?))
+                }
+                None => Ok(None),
+            };
+        }
+
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => {
+                return Ok(None);
+            }
+        };
+        let (addr, size) = match (dynamic.find(abi::DT_STRTAB), dynamic.find(abi::DT_STRSZ)) {
+            (Some(strtab), Some(strsz)) => (strtab.d_ptr(), strsz.d_val()),
+            _ => {
+                return Ok(None);
+            }
+        };
+        Ok(self.load_segment_data_at(addr, size)?.map(StringTable::new))
+    }
+
+    /// Translate a virtual address to its offset in the file, using the PT_LOAD segment which
+    /// maps it (see [ProgramHeader::vaddr_to_offset]).
+    ///
+    /// Returns None if no PT_LOAD segment's file data backs the address, e.g. for a `.bss`.
+    pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        self.phdrs?
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .find_map(|phdr| phdr.vaddr_to_offset(vaddr))
+    }
+
+    /// Translate a file offset to the virtual address it gets loaded at, using the PT_LOAD
+    /// segment which contains it (see [ProgramHeader::offset_to_vaddr]).
+    ///
+    /// Returns None if the offset isn't part of any PT_LOAD segment's file data.
+    pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+        self.phdrs?
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .find_map(|phdr| phdr.offset_to_vaddr(offset))
+    }
+
+    /// Get the range of virtual addresses spanned by the memory images of the
+    /// [PT_LOAD](abi::PT_LOAD) segments, from the lowest p_vaddr to the highest p_vaddr + p_memsz.
+    ///
+    /// Returns None if the object has no non-empty PT_LOAD segments.
+    pub fn loaded_range(&self) -> Option<core::ops::Range<u64>> {
+        self.phdrs?
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD && phdr.p_memsz != 0)
+            .map(|phdr| phdr.p_vaddr..phdr.p_vaddr.saturating_add(phdr.p_memsz))
+            .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+    }
+
+    /// Get the [PT_LOAD](abi::PT_LOAD) segments with their file data, sorted by ascending
+    /// virtual address.
+    ///
+    /// This is what a loader needs to map the object: each segment's addresses, permissions,
+    /// alignment, and the data to initialize it with. Segments with a zero p_memsz are left out.
+    ///
+    /// Returns a ParseError if a segment's file data isn't within the file.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// for segment in file.loadable_segments().expect("segments should be parseable") {
+    ///     println!(
+    ///         "{:#x} {} {} bytes + {} zeros",
+    ///         segment.vaddr,
+    ///         segment.perms,
+    ///         segment.data.len(),
+    ///         segment.zero_fill_size()
+    ///     );
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn loadable_segments(&self) -> Result<Vec<LoadableSegment<'data>>, ParseError> {
+        let phdrs = self.segments().into_iter().flat_map(|phdrs| phdrs.iter());
+        let mut segments = phdrs
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD && phdr.p_memsz != 0)
+            .map(|phdr| Ok(LoadableSegment::new(&phdr, self.segment_data(&phdr)?)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        segments.sort_by_key(|segment| segment.vaddr);
+        Ok(segments)
+    }
+
+    /// Lay out the [PT_LOAD](abi::PT_LOAD) segments at their virtual addresses within the given
+    /// range, as a flat [MemoryImage] along with the object's entry point.
+    ///
+    /// Each segment's file data is copied to its p_vaddr, and the rest of its p_memsz as well as
+    /// any gaps between segments are zero-filled. Parts of segments outside the range are left
+    /// out, so a range from [ElfBytes::loaded_range] gets everything while a narrower one gets
+    /// just that window of memory. Later segments overwrite earlier ones where they overlap.
+    ///
+    /// This allocates the whole range. The segments' sizes come from the file, so returns
+    /// [ParseError::AllocationFailed] rather than aborting if the range can't be allocated.
+    ///
+    /// Returns a ParseError if a segment's file data isn't within the file.
+    #[cfg(feature = "std")]
+    pub fn memory_image(&self, range: core::ops::Range<u64>) -> Result<MemoryImage, ParseError> {
+        let len = range.end.saturating_sub(range.start);
+        let size: usize = len.try_into()?;
+        let mut data = Vec::new();
+        data.try_reserve_exact(size)
+            .map_err(|_| ParseError::AllocationFailed(len))?;
+        data.resize(size, 0);
+        let phdrs = self.segments().into_iter().flat_map(|phdrs| phdrs.iter());
+        for phdr in phdrs.filter(|phdr| phdr.p_type == abi::PT_LOAD) {
+            let segment = LoadableSegment::new(&phdr, self.segment_data(&phdr)?);
+            let seg_end = segment.vaddr.saturating_add(segment.data.len() as u64);
+            let start = segment.vaddr.max(range.start);
+            let end = seg_end.min(range.end);
+            if start >= end {
+                continue;
+            }
+            let src = (start - segment.vaddr) as usize..(end - segment.vaddr) as usize;
+            let dst = (start - range.start) as usize..(end - range.start) as usize;
+            data[dst].copy_from_slice(&segment.data[src]);
+        }
+        Ok(MemoryImage {
+            base: range.start,
+            data,
+            entry: self.ehdr.e_entry,
+        })
+    }
+
+    /// Internal helper to get the file data for the virtual address range [addr, addr + size)
+    /// from the PT_LOAD segment which contains it (if any).
+    fn load_segment_data_at(
+        &self,
+        addr: u64,
+        size: u64,
+    ) -> Result<Option<&'data [u8]>, ParseError> {
+        let phdrs = match self.segments() {
+            Some(phdrs) => phdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let end = addr.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+        for phdr in phdrs.iter().filter(|phdr| phdr.p_type == abi::PT_LOAD) {
+            let seg_end = phdr
+                .p_vaddr
+                .checked_add(phdr.p_filesz)
+                .ok_or(ParseError::IntegerOverflow)?;
+            if phdr.p_vaddr <= addr && end <= seg_end {
+                let start: usize = phdr
+                    .p_offset
+                    .checked_add(addr - phdr.p_vaddr)
+                    .ok_or(ParseError::IntegerOverflow)?
+                    .try_into()?;
+                let end = start
+                    .checked_add(size.try_into()?)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                return Ok(Some(self.data.get_bytes(start..end)?));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get an iterator over the names of the shared libraries this object depends on: its
+    /// [DT_NEEDED](abi::DT_NEEDED) entries resolved through the dynamic string table.
+    ///
+    /// Returns Ok(None) if the object has no dynamic table or dynamic string table.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let needed: Vec<&str> = file
+    ///     .needed_libraries()
+    ///     .expect("dynamic table should be parseable")
+    ///     .expect("file should have a dynamic table")
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("library names should be parseable");
+    /// assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+    /// ```
+    pub fn needed_libraries(&self) -> Result<Option<DynamicStringIterator<'data, E>>, ParseError> {
+        self.dynamic_strings(abi::DT_NEEDED)
+    }
+
+    /// Get the object's shared object name from its [DT_SONAME](abi::DT_SONAME) entry (if any).
+    ///
+    /// Executables normally don't have a DT_SONAME, so this returns Ok(None) for them.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let soname = file.soname().expect("dynamic table should be parseable");
+    /// assert_eq!(soname, Some("libneeded.so.1"));
+    /// ```
+    pub fn soname(&self) -> Result<Option<&'data str>, ParseError> {
+        match self
+            .dynamic_strings(abi::DT_SONAME)?
+            .and_then(|mut strings| strings.next())
+        {
+            Some(soname) => Ok(Some(soname?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the object's [DT_RPATH](abi::DT_RPATH) library search path (if any).
+    ///
+    /// DT_RPATH is deprecated in favor of [DT_RUNPATH](abi::DT_RUNPATH), and is ignored by the
+    /// dynamic linker if the object also has a DT_RUNPATH entry. See [ElfBytes::runpath].
+    pub fn rpath(&self) -> Result<Option<SearchPath<'data>>, ParseError> {
+        self.search_path(abi::DT_RPATH)
+    }
+
+    /// Get the object's [DT_RUNPATH](abi::DT_RUNPATH) library search path (if any).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::dynamic::SearchPathTokens;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = "sample-objects/needed.x86_64.so";
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let runpath = file
+    ///     .runpath()
+    ///     .expect("dynamic table should be parseable")
+    ///     .expect("file should have a DT_RUNPATH");
+    /// assert_eq!(runpath.path, "$ORIGIN/../$LIB:/opt/${PLATFORM}/lib");
+    ///
+    /// let tokens = SearchPathTokens {
+    ///     lib: Some("lib64"),
+    ///     platform: Some("x86_64"),
+    ///     ..SearchPathTokens::for_object(path)
+    /// };
+    /// assert_eq!(
+    ///     runpath.expand(&tokens),
+    ///     ["sample-objects/../lib64", "/opt/x86_64/lib"]
+    /// );
+    /// ```
+    pub fn runpath(&self) -> Result<Option<SearchPath<'data>>, ParseError> {
+        self.search_path(abi::DT_RUNPATH)
+    }
+
+    fn search_path(&self, d_tag: i64) -> Result<Option<SearchPath<'data>>, ParseError> {
+        match self
+            .dynamic_strings(d_tag)?
+            .and_then(|mut strings| strings.next())
+        {
+            Some(path) => Ok(Some(SearchPath { path: path? })),
+            None => Ok(None),
+        }
+    }
+
+    /// Internal helper to get an iterator over the dynamic table entries with the given d_tag
+    /// resolved through the dynamic string table.
+    fn dynamic_strings(
+        &self,
+        d_tag: i64,
+    ) -> Result<Option<DynamicStringIterator<'data, E>>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => {
+                return Ok(None);
+            }
+        };
+        match self.dynamic_strtab()? {
+            Some(strtab) => Ok(Some(DynamicStringIterator::new(&dynamic, d_tag, strtab))),
+            None => Ok(None),
+        }
+    }
+
     /// Helper method to get the section data for a given pair of [SectionHeader] for the symbol
     /// table and its linked strtab, and interpret them as [SymbolTable] and [StringTable].
     fn section_data_as_symbol_table(
@@ -652,6 +1630,67 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         Ok((symtab, strtab))
     }
 
+    /// Get an iterator which maps the object's PLT stubs to the imported symbols they call.
+    ///
+    /// This pairs up the `.rela.plt` or `.rel.plt` jump slot relocations with the stubs in the
+    /// `.plt` section (or the `.plt.sec` section on x86 with IBT) using the machine's PLT layout,
+    /// see [plt](crate::plt). Returns Ok(None) if the object doesn't have these sections or
+    /// its machine's PLT layout isn't known.
+    pub fn plt_entries(&self) -> Result<Option<PltEntryIterator<'data, E>>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => {
+                return Ok(None);
+            }
+        };
+
+        let mut rel_shdr = None;
+        let mut plt = None;
+        let mut plt_sec = None;
+        for shdr in shdrs.iter() {
+            match strtab.get(shdr.sh_name as usize)? {
+                ".rela.plt" | ".rel.plt" => rel_shdr = Some(shdr),
+                ".plt" if shdr.sh_type == abi::SHT_PROGBITS => plt = Some(shdr),
+                ".plt.sec" => plt_sec = Some(shdr),
+                _ => {}
+            }
+        }
+
+        let (plt_addr, layout) = match (plt_sec, plt) {
+            (Some(plt_sec), _) => (plt_sec.sh_addr, PltLayout::PLT_SEC),
+            (None, Some(plt)) => match PltLayout::for_machine(self.ehdr.e_machine) {
+                Some(layout) => (plt.sh_addr, layout),
+                None => return Ok(None),
+            },
+            (None, None) => return Ok(None),
+        };
+        let rel_shdr = match rel_shdr {
+            Some(rel_shdr) => rel_shdr,
+            None => return Ok(None),
+        };
+
+        let symtab_shdr = shdrs.get(rel_shdr.sh_link as usize)?;
+        let strtab_shdr = shdrs.get(symtab_shdr.sh_link as usize)?;
+        let (symtab, strtab) = self.section_data_as_symbol_table(&symtab_shdr, &strtab_shdr)?;
+        Ok(Some(if rel_shdr.sh_type == abi::SHT_RELA {
+            PltEntryIterator::with_relas(
+                self.section_data_as_relas(&rel_shdr)?,
+                plt_addr,
+                layout,
+                symtab,
+                strtab,
+            )
+        } else {
+            PltEntryIterator::with_rels(
+                self.section_data_as_rels(&rel_shdr)?,
+                plt_addr,
+                layout,
+                symtab,
+                strtab,
+            )
+        }))
+    }
+
     /// Get the ELF file's `.symtab` and associated strtab (if any)
     pub fn symbol_table(
         &self,
@@ -678,6 +1717,78 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         )?))
     }
 
+    /// Get the ELF file's `.symtab_shndx` (if any), which holds the extended section indexes
+    /// for the `.symtab` symbols whose st_shndx is [SHN_XINDEX](abi::SHN_XINDEX).
+    ///
+    /// Objects only need this section when they have more than SHN_LORESERVE (0xff00) sections.
+    /// See [Symbol::section_index].
+    pub fn symbol_table_shndx(&self) -> Result<Option<SymbolShndxTable<'data, E>>, ParseError> {
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let symtab_idx = match shdrs
+            .iter()
+            .position(|shdr| shdr.sh_type == abi::SHT_SYMTAB)
+        {
+            Some(idx) => idx,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        // The SHT_SYMTAB_SHNDX section's sh_link points to the symbol table it extends
+        let shndx_shdr = match shdrs.iter().find(|shdr| {
+            shdr.sh_type == abi::SHT_SYMTAB_SHNDX && shdr.sh_link as usize == symtab_idx
+        }) {
+            Some(shdr) => shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let (start, end) = shndx_shdr.get_data_range()?;
+        let buf = self.data.get_bytes(start..end)?;
+        Ok(Some(SymbolShndxTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        )))
+    }
+
+    /// Find the symbol which the given address belongs to, returning the symbol, its name, and
+    /// the address's offset from the symbol's start. See [SymbolTable::symbolize].
+    ///
+    /// This uses the `.symtab` if the object has one, and its `.dynsym` otherwise.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let (_, name, offset) = file
+    ///     .symbolize(0x4004e8)
+    ///     .expect("symbols should be parseable")
+    ///     .expect("address should have a symbol");
+    /// assert_eq!((name, offset), ("main", 2));
+    /// ```
+    pub fn symbolize(&self, addr: u64) -> Result<Option<(Symbol, &'data str, u64)>, ParseError> {
+        let tables = match self.symbol_table()? {
+            Some(tables) => Some(tables),
+            None => self.dynamic_symbol_table()?,
+        };
+        match tables {
+            Some((symtab, strtab)) => symtab.symbolize(addr, &strtab),
+            None => Ok(None),
+        }
+    }
+
     /// Get the ELF file's `.dynsym` and associated strtab (if any)
     pub fn dynamic_symbol_table(
         &self,
@@ -704,27 +1815,134 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         )?))
     }
 
-    /// Locate the section data for the various GNU Symbol Versioning sections (if any)
-    /// and return them in a [SymbolVersionTable] that which can interpret them in-place to
-    /// yield [SymbolRequirement](crate::gnu_symver::SymbolRequirement)s
-    /// and [SymbolDefinition](crate::gnu_symver::SymbolDefinition)s
+    /// Get an iterator over the object's dynamic symbols joined with their names and GNU symbol
+    /// versions (if any), e.g. `memset@GLIBC_2.2.5`.
     ///
-    /// This is a GNU extension and not all objects use symbol versioning.
-    /// Returns an empty Option if the object does not use symbol versioning.
-    pub fn symbol_version_table(&self) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
-        // No sections means no GNU symbol versioning sections, which is ok
-        let shdrs = match self.section_headers() {
-            Some(shdrs) => shdrs,
+    /// This combines the `.dynsym`, `.gnu.version`, `.gnu.version_r`, and `.gnu.version_d`
+    /// sections. Returns Ok(None) if the object has no dynamic symbol table.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let imports: Vec<String> = file
+    ///     .dynamic_symbols()
+    ///     .expect("dynsym should be parseable")
+    ///     .expect("file should have a dynsym")
+    ///     .filter_map(|sym| sym.ok())
+    ///     .filter(|sym| sym.is_undefined() && !sym.is_weak() && !sym.name.is_empty())
+    ///     .map(|sym| sym.to_string())
+    ///     .collect();
+    /// assert_eq!(imports, ["memset@GLIBC_2.2.5"]);
+    /// ```
+    pub fn dynamic_symbols(&self) -> Result<Option<VersionedSymbolIterator<'data, E>>, ParseError> {
+        let (symtab, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
             None => {
                 return Ok(None);
             }
         };
+        let versions = self.symbol_version_table()?;
+        Ok(Some(VersionedSymbolIterator::new(symtab, strtab, versions)))
+    }
 
-        let mut versym_opt: Option<SectionHeader> = None;
-        let mut needs_opt: Option<SectionHeader> = None;
-        let mut defs_opt: Option<SectionHeader> = None;
-        // Find the GNU Symbol versioning sections (if any)
-        for shdr in shdrs.iter() {
+    /// Get an iterator over the dynamic symbols which the object exports for other objects to
+    /// link against: the defined, non-local symbols which aren't hidden (see
+    /// [VersionedSymbol::is_exported](crate::gnu_symver::VersionedSymbol::is_exported)).
+    ///
+    /// Each symbol comes with its name and version, and its address, size, and type can be read
+    /// from its [Symbol]. Returns Ok(None) if the object has no dynamic symbol table.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let exports: Vec<(String, u64, u64)> = file
+    ///     .exports()
+    ///     .expect("dynsym should be parseable")
+    ///     .expect("file should have a dynsym")
+    ///     .map(|sym| sym.map(|sym| (sym.to_string(), sym.symbol.st_value, sym.symbol.st_size)))
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("symbols should be parseable");
+    /// assert_eq!(
+    ///     exports,
+    ///     [
+    ///         ("use_memset_v2@HELLO_1.42".to_string(), 0x1145, 41),
+    ///         ("use_memset@HELLO_1.0".to_string(), 0x1119, 44),
+    ///     ]
+    /// );
+    /// ```
+    pub fn exports(&self) -> Result<Option<ExportIterator<'data, E>>, ParseError> {
+        Ok(self.dynamic_symbols()?.map(ExportIterator::new))
+    }
+
+    /// Get an iterator over the dynamic symbols which the object needs other objects to define:
+    /// the undefined, non-local symbols (see
+    /// [VersionedSymbol::is_imported](crate::gnu_symver::VersionedSymbol::is_imported)).
+    ///
+    /// Each symbol comes with its name and required version (if any). Weak imports
+    /// ([VersionedSymbol::is_weak](crate::gnu_symver::VersionedSymbol::is_weak)) may be left
+    /// unresolved at runtime. Returns Ok(None) if the object has no dynamic symbol table.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let imports: Vec<(String, bool)> = file
+    ///     .imports()
+    ///     .expect("dynsym should be parseable")
+    ///     .expect("file should have a dynsym")
+    ///     .map(|sym| sym.map(|sym| (sym.to_string(), sym.is_weak())))
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("symbols should be parseable");
+    /// assert_eq!(
+    ///     imports,
+    ///     [
+    ///         ("_ITM_deregisterTMCloneTable".to_string(), true),
+    ///         ("memset@GLIBC_2.2.5".to_string(), false),
+    ///         ("__gmon_start__".to_string(), true),
+    ///         ("_ITM_registerTMCloneTable".to_string(), true),
+    ///         ("__cxa_finalize@GLIBC_2.2.5".to_string(), true),
+    ///     ]
+    /// );
+    /// ```
+    pub fn imports(&self) -> Result<Option<ImportIterator<'data, E>>, ParseError> {
+        Ok(self.dynamic_symbols()?.map(ImportIterator::new))
+    }
+
+    /// Locate the section data for the various GNU Symbol Versioning sections (if any)
+    /// and return them in a [SymbolVersionTable] that which can interpret them in-place to
+    /// yield [SymbolRequirement](crate::gnu_symver::SymbolRequirement)s
+    /// and [SymbolDefinition](crate::gnu_symver::SymbolDefinition)s
+    ///
+    /// This is a GNU extension and not all objects use symbol versioning.
+    /// Returns an empty Option if the object does not use symbol versioning.
+    pub fn symbol_version_table(&self) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
+        // No sections means no GNU symbol versioning sections, which is ok
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let mut versym_opt: Option<SectionHeader> = None;
+        let mut needs_opt: Option<SectionHeader> = None;
+        let mut defs_opt: Option<SectionHeader> = None;
+        // Find the GNU Symbol versioning sections (if any)
+        for shdr in shdrs.iter() {
             if shdr.sh_type == abi::SHT_GNU_VERSYM {
                 versym_opt = Some(shdr);
             } else if shdr.sh_type == abi::SHT_GNU_VERNEED {
@@ -816,6 +2034,388 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             verdefs,
         )))
     }
+
+    /// Find the object's GNU build-id note, if any.
+    ///
+    /// This searches the [abi::SHT_NOTE] sections (usually `.note.gnu.build-id`) or, if
+    /// there are no section headers, the [abi::PT_NOTE] segments. The returned note
+    /// [Display](core::fmt::Display)s as the lowercase hex string of the build-id bytes.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let build_id = file.build_id().expect("notes should parse").unwrap();
+    /// assert_eq!(build_id.0.len(), 20);
+    /// assert_eq!(build_id.to_string(), "8c331317dd5ad783a90dd2b7d74dd8afa76e03d1");
+    /// ```
+    pub fn build_id(&self) -> Result<Option<NoteGnuBuildId<'data>>, ParseError> {
+        self.find_note(|note| match note {
+            Note::GnuBuildId(build_id) => Some(build_id),
+            _ => None,
+        })
+    }
+
+    /// Find the object's GNU ABI-tag note, if any, which gives the OS and the earliest
+    /// kernel version the object is compatible with.
+    ///
+    /// Like [ElfBytes::build_id], this searches the [abi::SHT_NOTE] sections (usually
+    /// `.note.ABI-tag`) or, if there are no section headers, the [abi::PT_NOTE] segments.
+    ///
+    /// ```
+    /// use elf::abi;
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let abi_tag = file.abi_tag().expect("notes should parse").unwrap();
+    /// assert_eq!(abi_tag.os, abi::ELF_NOTE_GNU_ABI_TAG_OS_LINUX);
+    /// assert_eq!(abi_tag.kernel_version(), (2, 6, 32));
+    /// ```
+    pub fn abi_tag(&self) -> Result<Option<NoteGnuAbiTag>, ParseError> {
+        self.find_note(|note| match note {
+            Note::GnuAbiTag(abi_tag) => Some(abi_tag),
+            _ => None,
+        })
+    }
+
+    /// Find the object's GNU program property note, if any, which describes features like
+    /// x86 CET (IBT and SHSTK) or AArch64 BTI/PAC support. See [NoteGnuProperty::get_u32].
+    ///
+    /// Like [ElfBytes::build_id], this searches the [abi::SHT_NOTE] sections (usually
+    /// `.note.gnu.property`) or, if there are no section headers, the [abi::PT_NOTE] segments.
+    pub fn gnu_property(&self) -> Result<Option<NoteGnuProperty<'data>>, ParseError> {
+        self.find_note(|note| match note {
+            Note::GnuProperty(property) => Some(property),
+            _ => None,
+        })
+    }
+
+    /// Internal helper to find the first note that `f` maps to `Some`, searching the
+    /// [abi::SHT_NOTE] sections or, if there are no section headers, the [abi::PT_NOTE] segments.
+    fn find_note<T, F: FnMut(Note<'data>) -> Option<T>>(
+        &self,
+        mut f: F,
+    ) -> Result<Option<T>, ParseError> {
+        if let Some(shdrs) = self.section_headers() {
+            for shdr in shdrs.iter().filter(|shdr| shdr.sh_type == abi::SHT_NOTE) {
+                if let Some(found) = self.section_data_as_notes(&shdr)?.find_map(&mut f) {
+                    return Ok(Some(found));
+                }
+            }
+        } else if let Some(phdrs) = self.segments() {
+            for phdr in phdrs.iter().filter(|phdr| phdr.p_type == abi::PT_NOTE) {
+                if let Some(found) = self.segment_data_as_notes(&phdr)?.find_map(&mut f) {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the parsed `.gnu_debuglink` section contents (if any): the file name of this object's
+    /// separate debug file and the CRC32 of that file's contents.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let stripped_data = std::fs::read("sample-objects/debuglink.x86_64").unwrap();
+    /// let stripped = ElfBytes::<AnyEndian>::minimal_parse(stripped_data.as_slice()).unwrap();
+    ///
+    /// let link = stripped.debuglink().expect("should parse").unwrap();
+    /// assert_eq!(link.file_name_str().unwrap(), "basic.x86_64.debug");
+    ///
+    /// let debug_data = std::fs::read("sample-objects/basic.x86_64.debug").unwrap();
+    /// assert!(link.verify(&debug_data));
+    /// ```
+    pub fn debuglink(&self) -> Result<Option<DebugLink<'data>>, ParseError> {
+        let shdr = match self.section_header_by_name(".gnu_debuglink")? {
+            Some(shdr) => shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(DebugLink::parse(self.ehdr.endianness, buf)?))
+    }
+
+    /// Check whether the given `debug` object is the separate debug file for this (stripped) object.
+    ///
+    /// The objects must have the same ELF class and machine. If both objects contain a GNU
+    /// build-id note, then the build-ids are compared. Otherwise, if this object has a
+    /// `.gnu_debuglink` section, its recorded CRC is compared against the CRC32 of the full
+    /// contents of `debug`.
+    ///
+    /// The returned [DebugFileMatch] describes which identifier was used to match the files,
+    /// or why they were found not to match.
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::debuglink::DebugFileMatch;
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let stripped_data = std::fs::read("sample-objects/debuglink.x86_64").unwrap();
+    /// let stripped = ElfBytes::<AnyEndian>::minimal_parse(stripped_data.as_slice()).unwrap();
+    ///
+    /// let debug_data = std::fs::read("sample-objects/basic.x86_64.debug").unwrap();
+    /// let debug = ElfBytes::<AnyEndian>::minimal_parse(debug_data.as_slice()).unwrap();
+    ///
+    /// let result = stripped.matches_debug_file(&debug).unwrap();
+    /// assert_eq!(result, DebugFileMatch::DebugLinkCrc);
+    /// assert!(result.is_match());
+    /// ```
+    pub fn matches_debug_file<E2: EndianParse>(
+        &self,
+        debug: &ElfBytes<'_, E2>,
+    ) -> Result<DebugFileMatch, ParseError> {
+        if self.ehdr.class != debug.ehdr.class {
+            return Ok(DebugFileMatch::ClassMismatch);
+        }
+
+        if self.ehdr.e_machine != debug.ehdr.e_machine {
+            return Ok(DebugFileMatch::MachineMismatch((
+                self.ehdr.e_machine,
+                debug.ehdr.e_machine,
+            )));
+        }
+
+        if let (Some(ours), Some(theirs)) = (self.build_id()?, debug.build_id()?) {
+            return match ours.0 == theirs.0 {
+                true => Ok(DebugFileMatch::BuildId),
+                false => Ok(DebugFileMatch::BuildIdMismatch),
+            };
+        }
+
+        if let Some(link) = self.debuglink()? {
+            let crc = gnu_debuglink_crc32(0, debug.data);
+            return match link.crc == crc {
+                true => Ok(DebugFileMatch::DebugLinkCrc),
+                false => Ok(DebugFileMatch::DebugLinkCrcMismatch((link.crc, crc))),
+            };
+        }
+
+        Ok(DebugFileMatch::NoIdentifiers)
+    }
+
+    /// Decompress the object's MiniDebugInfo: the xz-compressed ELF object embedded in its
+    /// `.gnu_debugdata` section (if any). Requires the `xz` cargo feature.
+    ///
+    /// The section data comes from the file, so the decompressed object's size is bounded by
+    /// `max_size` to guard against decompression bombs (see [decompress_xz](crate::minidebuginfo::decompress_xz)).
+    ///
+    /// See [ElfBytes::symbols_with_mini_debug_info] for getting at its symbols.
+    #[cfg(feature = "xz")]
+    pub fn mini_debug_info(&self, max_size: usize) -> Result<Option<MiniDebugInfo>, ParseError> {
+        let shdr = match self.section_header_by_name(".gnu_debugdata")? {
+            Some(shdr) => shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(MiniDebugInfo::new(buf, max_size)?))
+    }
+
+    /// Get the defined symbols from this object's symbol table merged with those from the
+    /// `.symtab` of its MiniDebugInfo (if any). Requires the `xz` cargo feature.
+    ///
+    /// This object's `.symtab` is used if it has one, otherwise its `.dynsym`. Undefined and
+    /// unnamed symbols are skipped. The returned symbols are sorted by address, with duplicate
+    /// (address, name) pairs removed. The MiniDebugInfo is decompressed to at most `max_size`
+    /// bytes (see [ElfBytes::mini_debug_info]).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// // The static function `helper` is only named in the MiniDebugInfo
+    /// let symbols = file.symbols_with_mini_debug_info(1 << 20).expect("symbols should parse");
+    /// let names: Vec<&str> = symbols.iter().map(|(_, name)| name.as_str()).collect();
+    /// assert!(names.contains(&"helper"));
+    /// assert!(names.contains(&"main"));
+    /// ```
+    #[cfg(feature = "xz")]
+    pub fn symbols_with_mini_debug_info(
+        &self,
+        max_size: usize,
+    ) -> Result<Vec<(Symbol, String)>, ParseError> {
+        fn collect_defined<E: EndianParse>(
+            file: &ElfBytes<'_, E>,
+            symbols: &mut Vec<(Symbol, String)>,
+        ) -> Result<(), ParseError> {
+            let tables = match file.symbol_table()? {
+                Some(tables) => Some(tables),
+                None => file.dynamic_symbol_table()?,
+            };
+            if let Some((symtab, strtab)) = tables {
+                for sym in symtab.iter().filter(|sym| !sym.is_undefined()) {
+                    let name = strtab.get(sym.st_name as usize)?;
+                    if !name.is_empty() {
+                        symbols.push((sym, name.to_string()));
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut symbols = Vec::new();
+        collect_defined(self, &mut symbols)?;
+        if let Some(mini_debug_info) = self.mini_debug_info(max_size)? {
+            collect_defined(&mini_debug_info.elf()?, &mut symbols)?;
+        }
+
+        symbols.sort_by(|(a, a_name), (b, b_name)| {
+            a.st_value.cmp(&b.st_value).then_with(|| a_name.cmp(b_name))
+        });
+        symbols.dedup_by(|(a, a_name), (b, b_name)| a.st_value == b.st_value && a_name == b_name);
+        Ok(symbols)
+    }
+
+    /// Heuristically guess which source language this object was compiled from.
+    ///
+    /// This looks for toolchain-specific section names (e.g. `.gopclntab`, `.note.rustc`)
+    /// and language-specific symbol mangling in the `.symtab` and `.dynsym` symbol tables.
+    /// See [LanguageGuess] for the signals used for each language and how conflicting
+    /// signals are resolved. Returns [LanguageGuess::Unknown] if no signal was found.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::language::LanguageGuess;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// assert_eq!(file.guess_language().unwrap(), LanguageGuess::C);
+    /// ```
+    pub fn guess_language(&self) -> Result<LanguageGuess, ParseError> {
+        let mut guess = LanguageGuess::Unknown;
+
+        if let (Some(shdrs), Some(strtab)) = self.section_headers_with_strtab()? {
+            for shdr in shdrs.iter() {
+                let name = strtab.get_raw(shdr.sh_name as usize)?;
+                guess = guess.max(language::guess_from_section_name(name));
+            }
+        }
+
+        let tables = [self.symbol_table()?, self.dynamic_symbol_table()?];
+        for (symtab, strtab) in tables.into_iter().flatten() {
+            // Go has no symbol signals, so there's nothing that could change the answer
+            if guess == LanguageGuess::Go {
+                break;
+            }
+
+            for sym in symtab.iter().filter(|sym| sym.st_name != 0) {
+                let name = strtab.get_raw(sym.st_name as usize)?;
+                guess = guess.max(language::guess_from_symbol_name(name));
+                if sym.st_symtype() == abi::STT_FUNC && !sym.is_undefined() {
+                    guess = guess.max(LanguageGuess::C);
+                }
+            }
+        }
+
+        Ok(guess)
+    }
+
+    /// Get the Go runtime's function table from the `.gopclntab` section, if any.
+    ///
+    /// This maps function entry addresses to names for Go binaries, even if they have
+    /// been stripped of their `.symtab`. Returns a [ParseError] if the section's table
+    /// format isn't one that [GoPcLnTab] supports.
+    pub fn go_pclntab(&self) -> Result<Option<GoPcLnTab<'data, E>>, ParseError> {
+        let shdr = match self.section_header_by_name(".gopclntab")? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(GoPcLnTab::parse(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        )?))
+    }
+
+    /// Get the object's `.eh_frame` call frame information (if any).
+    ///
+    /// The FDEs in it give the address ranges of functions, even in stripped binaries:
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::ehframe::EhFrameEntry;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let eh_frame = file.eh_frame().unwrap().unwrap();
+    /// for entry in eh_frame.iter() {
+    ///     if let EhFrameEntry::Fde(fde) = entry.unwrap() {
+    ///         println!("{:#x}..{:#x}", fde.pc_begin, fde.pc_end());
+    ///     }
+    /// }
+    /// ```
+    pub fn eh_frame(&self) -> Result<Option<EhFrame<'data, E>>, ParseError> {
+        let shdr = match self.section_header_by_name(".eh_frame")? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(EhFrame::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_addr,
+            buf,
+        )))
+    }
+
+    /// Get the object's `.eh_frame_hdr` (if any), which holds a sorted lookup table of the
+    /// `.eh_frame` FDEs.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let eh_frame = file.eh_frame().unwrap().unwrap();
+    /// let eh_frame_hdr = file.eh_frame_hdr().unwrap().unwrap();
+    /// let fde = eh_frame.find_fde(&eh_frame_hdr, 0x4004f0).unwrap().unwrap();
+    /// assert_eq!((fde.pc_begin, fde.pc_end()), (0x4004e6, 0x400506));
+    /// ```
+    pub fn eh_frame_hdr(&self) -> Result<Option<EhFrameHdr<'data, E>>, ParseError> {
+        let shdr = match self.section_header_by_name(".eh_frame_hdr")? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(EhFrameHdr::parse(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_addr,
+            buf,
+        )?))
+    }
 }
 
 //  _            _
@@ -829,10 +2429,16 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
 mod interface_tests {
     use super::*;
     use crate::abi::{SHT_GNU_HASH, SHT_NOBITS, SHT_NOTE, SHT_NULL, SHT_REL, SHT_RELA, SHT_STRTAB};
+    use crate::attributes::{AttributeValue, RiscvAttributes};
+    use crate::debug::DebugCompression;
+    use crate::ehframe::{Cie, EhFrameEntry};
     use crate::endian::AnyEndian;
+    use crate::gnu_symver::{SymbolVersion, VersionedSymbol};
     use crate::hash::sysv_hash;
-    use crate::note::{Note, NoteGnuAbiTag, NoteGnuBuildId};
+    use crate::init_array::FunctionPointer;
+    use crate::note::{Note, NoteFileMapping, NoteGnuAbiTag, NoteGnuBuildId, X86_64Registers};
     use crate::relocation::Rela;
+    use crate::segment::Perms;
 
     #[test]
     fn simultaenous_segments_parsing() {
@@ -929,6 +2535,78 @@ mod interface_tests {
         );
     }
 
+    #[test]
+    fn header_table_offsets_into_ehdr_error() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+
+        // e_phoff = 0, e_phnum = 3
+        let mut data = file_data.clone();
+        data[32..40].copy_from_slice(&0u64.to_le_bytes());
+        data[56..58].copy_from_slice(&3u16.to_le_bytes());
+        let err =
+            ElfBytes::<AnyEndian>::minimal_parse(data.as_slice()).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // e_shoff = 16, pointing into the middle of the ehdr
+        let mut data = file_data;
+        data[40..48].copy_from_slice(&16u64.to_le_bytes());
+        let err =
+            ElfBytes::<AnyEndian>::minimal_parse(data.as_slice()).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(16)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn header_tables_with_padded_entsize() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let segments: Vec<ProgramHeader> = file.segments().unwrap().iter().collect();
+        let shdrs: Vec<SectionHeader> = file.section_headers().unwrap().iter().collect();
+
+        // Copy both tables to the end of the file with 8 bytes of padding after each entry
+        let mut data = file_data.clone();
+        let phoff = data.len();
+        for chunk in file_data[64..64 + 8 * 56].chunks(56) {
+            data.extend_from_slice(chunk);
+            data.extend_from_slice(&[0xff; 8]);
+        }
+        let shoff = data.len();
+        for chunk in file_data[4800..4800 + 31 * 64].chunks(64) {
+            data.extend_from_slice(chunk);
+            data.extend_from_slice(&[0xff; 8]);
+        }
+        data[32..40].copy_from_slice(&(phoff as u64).to_le_bytes());
+        data[40..48].copy_from_slice(&(shoff as u64).to_le_bytes());
+        data[54..56].copy_from_slice(&64u16.to_le_bytes());
+        data[58..60].copy_from_slice(&72u16.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open padded");
+        let padded_segments = file.segments().expect("File should have a segment table");
+        assert_eq!(padded_segments.len(), 8);
+        assert_eq!(padded_segments.get(7).unwrap(), segments[7]);
+        assert_eq!(padded_segments.iter().collect::<Vec<_>>(), segments);
+        let padded_shdrs = file
+            .section_headers()
+            .expect("File should have a section table");
+        assert_eq!(padded_shdrs.len(), 31);
+        assert_eq!(padded_shdrs.iter().collect::<Vec<_>>(), shdrs);
+
+        // An entsize smaller than a program header can't be parsed
+        data[54..56].copy_from_slice(&48u16.to_le_bytes());
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&data).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadEntsize((48, 56))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
     #[test]
     fn section_headers() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -999,240 +2677,1827 @@ mod interface_tests {
     }
 
     #[test]
-    fn section_header_by_name() {
-        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    fn symbol_table_shndx() {
+        let path = std::path::PathBuf::from("sample-objects/shnum.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
         let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
 
-        let shdr = file
-            .section_header_by_name(".gnu.hash")
+        let (symtab, _) = file
+            .symbol_table()
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let shndx_table = file
+            .symbol_table_shndx()
+            .expect("Failed to read symtab shndx")
+            .expect("Failed to find symtab shndx");
+        assert_eq!(shndx_table.len(), symtab.len());
+
+        for (idx, sym) in symtab.iter().enumerate() {
+            let shndx = sym
+                .section_index(idx, &shndx_table)
+                .expect("Failed to get section index");
+            assert_eq!(shndx, sym.st_shndx as u32);
+        }
+
+        // basic.x86_64 has too few sections to need one
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).unwrap();
+        assert!(file
+            .symbol_table_shndx()
+            .expect("Failed to read symtab shndx")
+            .is_none());
+    }
+
+    #[test]
+    fn symbol_table_shndx_resolves_xindex() {
+        let path = std::path::PathBuf::from("sample-objects/shnum.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // None of the sample's symbols are in sections past SHN_LORESERVE, so move main (symbol
+        // 37, in .text) to section 65296 by setting its st_shndx to SHN_XINDEX and storing the
+        // real index in its .symtab_shndx entry.
+        let (symtab_off, shndx_off) = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).unwrap();
+            let symtab = file.section_header_by_name(".symtab").unwrap().unwrap();
+            let shndx = file
+                .section_header_by_name(".symtab_shndx")
+                .unwrap()
+                .unwrap();
+            (symtab.sh_offset as usize, shndx.sh_offset as usize)
+        };
+        let st_shndx_off = symtab_off + 37 * 24 + 6;
+        file_data[st_shndx_off..st_shndx_off + 2].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        let xindex_off = shndx_off + 37 * 4;
+        file_data[xindex_off..xindex_off + 4].copy_from_slice(&65296u32.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).unwrap();
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let shndx_table = file
+            .symbol_table_shndx()
+            .expect("Failed to read symtab shndx")
+            .expect("Failed to find symtab shndx");
+
+        let sym = symtab.get(37).expect("Failed to get symbol");
+        assert_eq!(strtab.get(sym.st_name as usize).unwrap(), "main");
+        assert_eq!(sym.st_shndx, abi::SHN_XINDEX);
+        let shndx = sym
+            .section_index(37, &shndx_table)
+            .expect("Failed to get section index");
+        assert_eq!(shndx, 65296);
+
+        let (shdrs, shstrtab) = file.section_headers_with_strtab().unwrap();
+        let shdr = shdrs.unwrap().get(shndx as usize).unwrap();
+        assert_eq!(
+            shstrtab.unwrap().get(shdr.sh_name as usize).unwrap(),
+            "65280"
+        );
+
+        // Symbols without SHN_XINDEX keep their st_shndx
+        let sym = symtab.get(34).expect("Failed to get symbol");
+        assert_eq!(sym.section_index(34, &shndx_table).unwrap(), 14);
+    }
+
+    #[test]
+    fn eh_frame() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let eh_frame = file
+            .eh_frame()
+            .expect("Failed to read .eh_frame")
+            .expect("File should have .eh_frame");
+        let entries: Vec<EhFrameEntry<'_>> = eh_frame
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("Failed to parse .eh_frame entries");
+
+        let cies: Vec<&Cie<'_>> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                EhFrameEntry::Cie(cie) => Some(cie),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(cies.len(), 2);
+        assert_eq!(cies[1].offset, 0x30);
+        assert_eq!(cies[1].augmentation, b"zR");
+        assert_eq!(cies[1].code_alignment_factor, 1);
+        assert_eq!(cies[1].data_alignment_factor, -8);
+        assert_eq!(cies[1].return_address_register, 16);
+        assert_eq!(cies[1].fde_pointer_encoding, 0x1b);
+
+        let ranges: Vec<(usize, u64, u64)> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                EhFrameEntry::Fde(fde) => Some((fde.cie_offset, fde.pc_begin, fde.pc_end())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (0x00, 0x4003f0, 0x40041a),
+                (0x30, 0x4003b0, 0x4003e0),
+                (0x30, 0x4004e6, 0x400506),
+                (0x30, 0x400510, 0x400575),
+                (0x30, 0x400580, 0x400582),
+            ]
+        );
+
+        let eh_frame_hdr = file
+            .eh_frame_hdr()
+            .expect("Failed to read .eh_frame_hdr")
+            .expect("File should have .eh_frame_hdr");
+        assert_eq!(eh_frame_hdr.eh_frame_ptr, 0x4005c8);
+        assert_eq!(eh_frame_hdr.fde_count, 5);
+        assert_eq!(eh_frame_hdr.get(0).unwrap(), (0x4003b0, 0x4005c8 + 0x48));
+        for (_, pc_begin, pc_end) in ranges {
+            let fde = eh_frame
+                .find_fde(&eh_frame_hdr, pc_end - 1)
+                .expect("Failed to find FDE")
+                .expect("FDE should cover address");
+            assert_eq!(fde.pc_begin, pc_begin);
+        }
+        // Between _start and frame_dummy, and before the first FDE
+        assert!(eh_frame
+            .find_fde(&eh_frame_hdr, 0x40041a)
+            .expect("Failed to find FDE")
+            .is_none());
+        assert!(eh_frame
+            .find_fde(&eh_frame_hdr, 0x400000)
+            .expect("Failed to find FDE")
+            .is_none());
+
+        // main()
+        let fde = eh_frame.fde_at(0x70).expect("Failed to parse FDE");
+        assert!(fde.contains(0x4004e6));
+        assert_eq!(fde.instructions[..3], [0x41, 0x0e, 0x10]);
+    }
+
+    #[test]
+    fn phnum_in_missing_shdr0_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut data = std::fs::read(path).expect("Could not read file.");
+        // e_phnum says to look in shdr[0], but there's no section header table to look in
+        data[0x38..0x3A].copy_from_slice(&abi::PN_XNUM.to_le_bytes());
+        data[0x28..0x30].copy_from_slice(&0u64.to_le_bytes());
+        data[0x3C..0x3E].copy_from_slice(&0u16.to_le_bytes());
+        let err = ElfBytes::<AnyEndian>::minimal_parse(data.as_slice()).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn section_header_by_name() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".gnu.hash")
             .expect("section table should be parseable")
             .expect("file should have .gnu.hash section");
 
         assert_eq!(shdr.sh_type, SHT_GNU_HASH);
 
-        let shdr = file
-            .section_header_by_name(".not.found")
-            .expect("section table should be parseable");
+        let shdr = file
+            .section_header_by_name(".not.found")
+            .expect("section table should be parseable");
+
+        assert_eq!(shdr, None);
+    }
+
+    #[test]
+    fn section_name() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let shdr = file.section_headers().unwrap().get(3).unwrap();
+        assert_eq!(
+            file.section_name(&shdr).expect("name should be parseable"),
+            Some(".note.gnu.build-id")
+        );
+
+        let mut bad_shdr = shdr;
+        bad_shdr.sh_name = 0x1000;
+        let err = file.section_name(&bad_shdr).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x1000)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // A corrupted e_shstrndx is an error rather than a panic
+        file_data[62..64].copy_from_slice(&99u16.to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let err = file.section_name(&shdr).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(99)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn find_common_data() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let elf_scns = file.find_common_data().expect("file should parse");
+
+        // hello.so should find everything
+        assert!(elf_scns.symtab.is_some());
+        assert!(elf_scns.symtab_strs.is_some());
+        assert!(elf_scns.dynsyms.is_some());
+        assert!(elf_scns.dynsyms_strs.is_some());
+        assert!(elf_scns.dynamic.is_some());
+        assert!(elf_scns.sysv_hash.is_some());
+        assert!(elf_scns.gnu_hash.is_some());
+    }
+
+    #[test]
+    fn vaddr_offset_translation() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        assert_eq!(file.vaddr_to_offset(0x400594), Some(0x594));
+        assert_eq!(file.vaddr_to_offset(0x6006d8), Some(0x6d8));
+        // .bss
+        assert_eq!(file.vaddr_to_offset(0x600900), None);
+        assert_eq!(file.vaddr_to_offset(0x1000), None);
+
+        assert_eq!(file.offset_to_vaddr(0x594), Some(0x400594));
+        assert_eq!(file.offset_to_vaddr(0x6d8), Some(0x6006d8));
+        // .symtab isn't loaded
+        assert_eq!(file.offset_to_vaddr(0x920), None);
+    }
+
+    #[test]
+    fn loadable_segments() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let segments = file.loadable_segments().expect("should parse segments");
+        assert_eq!(segments.len(), 2);
+        assert_eq!(
+            segments[0],
+            LoadableSegment {
+                vaddr: 0x400000,
+                paddr: 0x400000,
+                data: &file_data[..0x6bc],
+                memsz: 0x6bc,
+                perms: Perms {
+                    r: true,
+                    w: false,
+                    x: true,
+                },
+                align: 0x200000,
+            }
+        );
+        assert_eq!(segments[1].vaddr, 0x6006c0);
+        assert_eq!(segments[1].perms.to_string(), "rw-");
+        assert_eq!(segments[1].zero_fill_size(), 0x40038);
+        let data = segments[1]
+            .data_zero_extended()
+            .expect("should zero extend");
+        assert_eq!(data.len(), 0x40260);
+        assert_eq!(data[..0x228], file_data[0x6c0..0x8e8]);
+        assert!(data[0x228..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn memory_image_huge_memsz_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Set the second PT_LOAD's p_memsz to something far too big to allocate
+        let memsz_offset = 64 + 3 * 56 + 40;
+        file_data[memsz_offset..memsz_offset + 8].copy_from_slice(&(1u64 << 62).to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let range = file
+            .loaded_range()
+            .expect("file should have PT_LOAD segments");
+        let err = file.memory_image(range).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::AllocationFailed(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn loadable_segments_huge_memsz_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Set the second PT_LOAD's p_memsz to something far too big to allocate
+        let memsz_offset = 64 + 3 * 56 + 40;
+        file_data[memsz_offset..memsz_offset + 8].copy_from_slice(&(1u64 << 62).to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let segments = file.loadable_segments().expect("should parse segments");
+        assert_eq!(segments[1].memsz, 1 << 62);
+        let err = segments[1].data_zero_extended().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::AllocationFailed(0x4000000000000000)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn memory_image() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let range = file
+            .loaded_range()
+            .expect("file should have PT_LOAD segments");
+        assert_eq!(range, 0x400000..0x640920);
+        let image = file
+            .memory_image(range.clone())
+            .expect("should build image");
+        assert_eq!(image.range(), range);
+        assert_eq!(image.entry, 0x4003f0);
+        // Both segments' file data is at its virtual address
+        assert_eq!(image.get(0x400000, 0x6bc), Some(&file_data[..0x6bc]));
+        assert_eq!(image.get(0x6006c0, 0x228), Some(&file_data[0x6c0..0x8e8]));
+        // The gap between them and the .bss are zero-filled
+        assert!(image
+            .get(0x4006bc, 0x200004)
+            .unwrap()
+            .iter()
+            .all(|b| *b == 0));
+        assert!(image
+            .get(0x6008e8, 0x40038)
+            .unwrap()
+            .iter()
+            .all(|b| *b == 0));
+        assert_eq!(image.get(0x640920, 1), None);
+
+        // A window of memory only gets the parts of segments within it
+        let image = file
+            .memory_image(0x400690..0x400700)
+            .expect("should build image");
+        assert_eq!(image.data[..0x2c], file_data[0x690..0x6bc]);
+        assert!(image.data[0x2c..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn section_data() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(26)
+            .expect("shdr should be parsable");
+
+        assert_eq!(shdr.sh_type, SHT_NOBITS);
+
+        let (data, chdr) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+
+        assert_eq!(chdr, None);
+        assert_eq!(data, &[]);
+    }
+
+    #[test]
+    fn section_and_segment_data_borrow_input() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let input = slice.as_ptr_range();
+
+        let shdr = file
+            .section_header_by_name(".text")
+            .expect("section table should be parseable")
+            .expect("file should have .text");
+        let (data, _) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+        assert_eq!(data.as_ptr(), slice[shdr.sh_offset as usize..].as_ptr());
+        assert!(input.contains(&data.as_ptr()));
+
+        let phdr = file
+            .segments()
+            .expect("File should have a segment table")
+            .get(0)
+            .expect("phdr should be parsable");
+        let data = file
+            .segment_data(&phdr)
+            .expect("Failed to get segment data");
+        assert_eq!(data.as_ptr(), slice[phdr.p_offset as usize..].as_ptr());
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn section_data_decompressed() {
+        let path = std::path::PathBuf::from("sample-objects/zlib.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+        let (_, chdr) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+        let chdr = chdr.expect("section should be compressed");
+        assert_eq!(chdr.ch_type, abi::ELFCOMPRESS_ZLIB);
+
+        let data = file
+            .section_data_decompressed(&shdr)
+            .expect("Failed to decompress section data");
+        assert_eq!(data.len() as u64, chdr.ch_size);
+        // DWARF 5 compilation unit header: unit_length, version
+        assert_eq!(&data[..6], [0xeb, 0, 0, 0, 5, 0]);
+
+        // Uncompressed sections are passed through as-is
+        let shdr = file
+            .section_header_by_name(".text")
+            .expect("section table should be parseable")
+            .expect("file should have .text");
+        let data = file
+            .section_data_decompressed(&shdr)
+            .expect("Failed to get section data");
+        assert!(matches!(data, std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn section_data_decompressed_zstd() {
+        let path = std::path::PathBuf::from("sample-objects/zstd.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+        let (_, chdr) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+        let chdr = chdr.expect("section should be compressed");
+        assert_eq!(chdr.ch_type, abi::ELFCOMPRESS_ZSTD);
+
+        let data = file
+            .section_data_decompressed(&shdr)
+            .expect("Failed to decompress section data");
+        assert_eq!(data.len() as u64, chdr.ch_size);
+        // DWARF 5 compilation unit header: unit_length, version
+        assert_eq!(&data[..6], [0xeb, 0, 0, 0, 5, 0]);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn section_data_decompressed_huge_ch_size_errors() {
+        let path = std::path::PathBuf::from("sample-objects/zstd.x86_64.o");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+
+        // Claim the section decompresses to ~4 EiB
+        let ch_size_offset = shdr.sh_offset as usize + 8;
+        file_data[ch_size_offset..ch_size_offset + 8]
+            .copy_from_slice(&0x3fff_ffff_ffff_ffefu64.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let err = file
+            .section_data_decompressed(&shdr)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    // Test all the different section_data_as* with a section of the wrong type
+    #[test]
+    fn section_data_as_wrong_type() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // Section 0 is SHT_NULL, so all of the section_data_as* should error on it
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(0)
+            .expect("shdr should be parsable");
+
+        let err = file
+            .section_data_as_strtab(&shdr)
+            .expect_err("shdr0 should be the wrong type");
+        assert!(
+            matches!(
+                err,
+                ParseError::UnexpectedSectionType((SHT_NULL, SHT_STRTAB))
+            ),
+            "Unexpected Error type found: {err}"
+        );
+
+        let err = file
+            .section_data_as_rels(&shdr)
+            .expect_err("shdr0 should be the wrong type");
+        assert!(
+            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_REL))),
+            "Unexpected Error type found: {err}"
+        );
+
+        let err = file
+            .section_data_as_relas(&shdr)
+            .expect_err("shdr0 should be the wrong type");
+        assert!(
+            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_RELA))),
+            "Unexpected Error type found: {err}"
+        );
+
+        let err = file
+            .section_data_as_notes(&shdr)
+            .expect_err("shdr0 should be the wrong type");
+        assert!(
+            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_NOTE))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn section_data_as_strtab() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(file.ehdr.e_shstrndx as usize)
+            .expect("shdr should be parsable");
+
+        let strtab = file
+            .section_data_as_strtab(&shdr)
+            .expect("Failed to read strtab");
+
+        assert_eq!(
+            strtab.get(1).expect("Failed to get strtab entry"),
+            ".symtab"
+        );
+    }
+
+    #[test]
+    fn section_data_as_relas() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(10)
+            .expect("Failed to get rela shdr");
+
+        let mut relas = file
+            .section_data_as_relas(&shdr)
+            .expect("Failed to read relas section");
+        assert_eq!(
+            relas.next().expect("Failed to get rela entry"),
+            Rela {
+                r_offset: 6293704,
+                r_sym: 1,
+                r_type: 7,
+                r_addend: 0,
+            }
+        );
+        assert_eq!(
+            relas.next().expect("Failed to get rela entry"),
+            Rela {
+                r_offset: 6293712,
+                r_sym: 2,
+                r_type: 7,
+                r_addend: 0,
+            }
+        );
+        assert!(relas.next().is_none());
+    }
+
+    #[test]
+    fn section_data_as_relrs() {
+        let path = std::path::PathBuf::from("sample-objects/relr.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".relr.dyn")
+            .expect("section table should be parseable")
+            .expect("file should have a .relr.dyn section");
+
+        let relrs: Vec<u64> = file
+            .section_data_as_relrs(&shdr)
+            .expect("Failed to read relrs section")
+            .collect();
+        let expected: Vec<u64> = [0x3e30, 0x3e38, 0x4000, 0x4020]
+            .into_iter()
+            .chain((0x4040..=0x4088).step_by(8))
+            .collect();
+        assert_eq!(relrs, expected);
+    }
+
+    #[test]
+    fn relr_relocations() {
+        let path = std::path::PathBuf::from("sample-objects/relr.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let relrs: Vec<u64> = file
+            .relr_relocations()
+            .expect("Failed to read relrs")
+            .expect("file should have a DT_RELR table")
+            .collect();
+        let expected: Vec<u64> = [0x3e30, 0x3e38, 0x4000, 0x4020]
+            .into_iter()
+            .chain((0x4040..=0x4088).step_by(8))
+            .collect();
+        assert_eq!(relrs, expected);
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        assert!(file
+            .relr_relocations()
+            .expect("Failed to read relrs")
+            .is_none());
+    }
+
+    #[test]
+    fn section_data_as_group() {
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdrs = file
+            .section_headers()
+            .expect("File should have section table");
+        let shdr = shdrs.get(1).expect("shdr should be parsable");
+        let group = file
+            .section_data_as_group(&shdr)
+            .expect("Failed to read group section");
+        assert!(group.is_comdat());
+        assert_eq!(group.members.iter().collect::<Vec<u32>>(), [8]);
+
+        let member = shdrs.get(8).expect("shdr should be parsable");
+        assert_ne!(member.sh_flags & abi::SHF_GROUP as u64, 0);
+    }
+
+    #[test]
+    fn section_groups() {
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let groups: Vec<(&str, Vec<u32>)> = file
+            .section_groups()
+            .expect("File should have section table")
+            .map(|group| {
+                let group = group.expect("Failed to parse group");
+                assert!(group.is_comdat());
+                let sym = symtab
+                    .get(group.signature_symbol as usize)
+                    .expect("Failed to get signature symbol");
+                let name = strtab
+                    .get(sym.st_name as usize)
+                    .expect("Failed to get signature name");
+                (name, group.members.iter().collect())
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ("_Z6answerv", vec![8]),
+                ("_Z5twiceIiET_S0_", vec![9]),
+                ("_Z5twiceIlET_S0_", vec![10]),
+            ]
+        );
+
+        // Linked objects have no groups
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(file.section_groups().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn section_data_as_notes() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(2)
+            .expect("Failed to get note shdr");
+
+        let mut notes = file
+            .section_data_as_notes(&shdr)
+            .expect("Failed to read note section");
+        assert_eq!(
+            notes.next().expect("Failed to get first note"),
+            Note::GnuAbiTag(NoteGnuAbiTag {
+                os: 0,
+                major: 2,
+                minor: 6,
+                subminor: 32
+            })
+        );
+        assert!(notes.next().is_none());
+    }
+
+    #[test]
+    fn segment_data_zero_extended() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        // The second PT_LOAD holds .data followed by .bss
+        let phdr = file.segments().unwrap().get(3).unwrap();
+        let image = file
+            .segment_data_zero_extended(&phdr)
+            .expect("Failed to get segment data");
+        assert_eq!(image.len() as u64, phdr.p_memsz);
+
+        // A p_memsz far too big to allocate
+        let memsz_offset = file.ehdr.e_phoff as usize + 3 * file.ehdr.e_phentsize as usize + 40;
+        file_data[memsz_offset..memsz_offset + 8].copy_from_slice(&(1u64 << 62).to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let phdr = file.segments().unwrap().get(3).unwrap();
+        let err = file
+            .segment_data_zero_extended(&phdr)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::AllocationFailed(0x4000000000000000)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn segment_data_as_notes() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let phdr = file
+            .segments()
+            .expect("File should have segmetn table")
+            .get(5)
+            .expect("Failed to get notes phdr");
+
+        let mut notes = file
+            .segment_data_as_notes(&phdr)
+            .expect("Failed to read notes segment");
+        assert_eq!(
+            notes.next().expect("Failed to get first note"),
+            Note::GnuAbiTag(NoteGnuAbiTag {
+                os: 0,
+                major: 2,
+                minor: 6,
+                subminor: 32
+            })
+        );
+        assert_eq!(
+            notes.next().expect("Failed to get second note"),
+            Note::GnuBuildId(NoteGnuBuildId(&[
+                119, 65, 159, 13, 165, 16, 131, 12, 87, 167, 200, 204, 176, 238, 133, 95, 238, 211,
+                118, 163
+            ]))
+        );
+        assert!(notes.next().is_none());
+    }
+
+    #[test]
+    fn build_attributes_arm() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let attrs = file
+            .build_attributes()
+            .expect("Failed to parse attributes")
+            .expect("Failed to find attributes");
+
+        let file_attribute = |tag| {
+            attrs
+                .file_attribute(b"aeabi", tag)
+                .expect("Failed to parse attributes")
+        };
+        assert_eq!(
+            file_attribute(abi::TAG_CPU_NAME),
+            Some(AttributeValue::String(b"7-A"))
+        );
+        assert_eq!(
+            file_attribute(abi::TAG_CPU_ARCH),
+            Some(AttributeValue::Integer(abi::TAG_CPU_ARCH_V7))
+        );
+        assert_eq!(
+            file_attribute(abi::TAG_CPU_ARCH_PROFILE),
+            Some(AttributeValue::Integer(b'A' as u64))
+        );
+        // VFPv3-D16
+        assert_eq!(
+            file_attribute(abi::TAG_FP_ARCH),
+            Some(AttributeValue::Integer(4))
+        );
+        // Hard-float calling convention
+        assert_eq!(
+            file_attribute(abi::TAG_ABI_VFP_ARGS),
+            Some(AttributeValue::Integer(1))
+        );
+        assert_eq!(
+            file_attribute(abi::TAG_CPU_UNALIGNED_ACCESS),
+            Some(AttributeValue::Integer(1))
+        );
+        assert_eq!(file_attribute(abi::TAG_DIV_USE), None);
+
+        let subsection = attrs
+            .subsections()
+            .next()
+            .expect("Should have a subsection")
+            .expect("Failed to parse subsection");
+        let scope = subsection
+            .scopes()
+            .next()
+            .expect("Should have a scope")
+            .expect("Failed to parse scope");
+        assert_eq!(scope.attributes().count(), 15);
+    }
+
+    #[test]
+    fn build_attributes_riscv() {
+        let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let attrs = file
+            .build_attributes()
+            .expect("Failed to parse attributes")
+            .expect("Failed to find attributes");
+        let riscv = RiscvAttributes::parse(&attrs).expect("Failed to parse attributes");
+        assert_eq!(
+            riscv.arch,
+            Some(b"rv64i2p0_m2p0_a2p0_f2p0_d2p0_c2p0".as_slice())
+        );
+        assert_eq!(riscv.stack_align, None);
+
+        let arch = riscv.arch().expect("Failed to parse ISA string");
+        assert_eq!(arch.xlen, 64);
+        let names: Vec<&str> = arch.extensions().map(|ext| ext.name).collect();
+        assert_eq!(names, ["i", "m", "a", "f", "d", "c"]);
+        assert!(arch.extensions().all(|ext| ext.version == Some((2, 0))));
+    }
+
+    #[test]
+    fn needed_libraries() {
+        let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let needed: Vec<&str> = file
+            .needed_libraries()
+            .expect("Failed to parse dynamic table")
+            .expect("Failed to find dynamic table")
+            .collect::<Result<_, _>>()
+            .expect("Failed to resolve names");
+        assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+
+        // Objects without a dynamic table have no dependencies
+        let path = std::path::PathBuf::from("sample-objects/zdebug.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .needed_libraries()
+            .expect("Failed to parse dynamic table")
+            .is_none());
+    }
+
+    #[test]
+    fn plt_entries() {
+        fn plt_stubs(path: &str) -> Vec<(u64, String)> {
+            let file_data = std::fs::read(path).expect("Could not read file.");
+            let file =
+                ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+            file.plt_entries()
+                .expect("Failed to parse section headers")
+                .expect("Failed to find plt")
+                .map(|entry| entry.map(|entry| (entry.addr, entry.name.to_string())))
+                .collect::<Result<_, _>>()
+                .expect("Failed to parse plt entries")
+        }
+
+        assert_eq!(
+            plt_stubs("sample-objects/symver.x86_64.so"),
+            [(0x1050, "memset".to_string())]
+        );
+        assert_eq!(
+            plt_stubs("sample-objects/symver.aarch64.so"),
+            [
+                (0x600, "__cxa_finalize".to_string()),
+                (0x610, "memset".to_string()),
+                (0x620, "__gmon_start__".to_string()),
+            ]
+        );
+        assert_eq!(
+            plt_stubs("sample-objects/symver.armhf.so"),
+            [
+                (0x3f0, "__cxa_finalize".to_string()),
+                (0x3fc, "__gmon_start__".to_string()),
+                (0x408, "memset".to_string()),
+            ]
+        );
+        assert_eq!(
+            plt_stubs("sample-objects/symver.riscv64.so"),
+            [(0x550, "memset".to_string())]
+        );
+        assert_eq!(
+            plt_stubs("sample-objects/symver.m68k.so"),
+            [
+                (0x48c, "__cxa_finalize".to_string()),
+                (0x4a0, "__gmon_start__".to_string()),
+                (0x4b4, "memset".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn plt_entries_unknown_layout_is_none() {
+        // ppc64's .plt is a NOBITS table of function descriptors rather than stubs
+        let path = std::path::PathBuf::from("sample-objects/symver.powerpc64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .plt_entries()
+            .expect("Failed to parse section headers")
+            .is_none());
+    }
+
+    #[test]
+    fn dynamic_symbols() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let syms: Vec<VersionedSymbol<'_>> = file
+            .dynamic_symbols()
+            .expect("Failed to parse dynsym")
+            .expect("Failed to find dynsym")
+            .collect::<Result<_, _>>()
+            .expect("Failed to parse versioned symbols");
+
+        let names: Vec<String> = syms.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(
+            names,
+            [
+                "",
+                "_ITM_deregisterTMCloneTable",
+                "memset@GLIBC_2.2.5",
+                "__gmon_start__",
+                "_ITM_registerTMCloneTable",
+                "__cxa_finalize@GLIBC_2.2.5",
+                "HELLO_1.0@@HELLO_1.0",
+                "use_memset_v2@HELLO_1.42",
+                "HELLO_1.42@@HELLO_1.42",
+                "use_memset@HELLO_1.0",
+            ]
+        );
+
+        let cxa_finalize = &syms[5];
+        assert_eq!(cxa_finalize.index, 5);
+        assert!(cxa_finalize.is_undefined());
+        assert!(cxa_finalize.is_weak());
+        assert!(!cxa_finalize.is_default_version());
+
+        let use_memset = &syms[9];
+        assert!(!use_memset.is_undefined());
+        assert!(!use_memset.is_weak());
+        assert_eq!(
+            use_memset.version,
+            Some(SymbolVersion {
+                name: "HELLO_1.0",
+                hidden: true
+            })
+        );
+    }
+
+    #[test]
+    fn dynamic_symbols_without_versioning() {
+        let path = std::path::PathBuf::from("sample-objects/tls.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .symbol_version_table()
+            .expect("Failed to parse versions")
+            .is_none());
+        let counter = file
+            .dynamic_symbols()
+            .expect("Failed to parse dynsym")
+            .expect("Failed to find dynsym")
+            .map(|sym| sym.expect("Failed to parse symbol"))
+            .find(|sym| sym.name == "counter")
+            .expect("Failed to find counter");
+        assert_eq!(counter.version, None);
+        assert_eq!(counter.to_string(), "counter");
+    }
+
+    #[test]
+    fn exports() {
+        let path = std::path::PathBuf::from("sample-objects/tls.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let exports: Vec<(&str, u8, u64)> = file
+            .exports()
+            .expect("Failed to parse dynsym")
+            .expect("Failed to find dynsym")
+            .map(|sym| sym.expect("Failed to parse symbol"))
+            .map(|sym| (sym.name, sym.symbol.st_symtype(), sym.symbol.st_size))
+            .collect();
+        assert_eq!(
+            exports,
+            [
+                ("bump", abi::STT_FUNC, 65),
+                ("buffer", abi::STT_TLS, 32),
+                ("counter", abi::STT_TLS, 4),
+            ]
+        );
+
+        // Relocatable objects have no dynamic symbol table
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.exports().expect("Failed to parse dynsym").is_none());
+    }
+
+    #[test]
+    fn imports() {
+        let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let imports: Vec<(&str, Option<&str>, bool)> = file
+            .imports()
+            .expect("Failed to parse dynsym")
+            .expect("Failed to find dynsym")
+            .map(|sym| sym.expect("Failed to parse symbol"))
+            .map(|sym| (sym.name, sym.version.map(|v| v.name), sym.is_weak()))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("_ITM_deregisterTMCloneTable", None, true),
+                ("__gmon_start__", None, true),
+                ("_ITM_registerTMCloneTable", None, true),
+                ("sqrt", Some("GLIBC_2.2.5"), false),
+                ("__cxa_finalize", Some("GLIBC_2.2.5"), true),
+            ]
+        );
+
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.imports().expect("Failed to parse dynsym").is_none());
+    }
+
+    #[test]
+    fn stack_is_executable() {
+        fn executable(name: &str) -> bool {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            let file_data = std::fs::read(path).expect("Could not read file.");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+            file.stack_is_executable()
+                .expect("Failed to parse section headers")
+        }
+        assert!(!executable("basic.x86_64"));
+        assert!(!executable("symver.aarch64.so"));
+        // From the .note.GNU-stack section
+        assert!(!executable("group.x86_64.o"));
+        // Neither a PT_GNU_STACK segment nor a .note.GNU-stack section
+        assert!(executable("abiflags.mips.o"));
+    }
+
+    #[test]
+    fn kind() {
+        fn kind_of(name: &str) -> FileKind {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            let file_data = std::fs::read(path).expect("Could not read file.");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+            file.kind().expect("Failed to parse dynamic table")
+        }
+        assert_eq!(kind_of("group.x86_64.o"), FileKind::Relocatable);
+        assert_eq!(kind_of("basic.x86_64"), FileKind::DynamicExecutable);
+        assert_eq!(kind_of("minidebuginfo.x86_64"), FileKind::Pie);
+        assert_eq!(kind_of("needed.x86_64.so"), FileKind::SharedObject);
+        assert_eq!(kind_of("core.x86_64"), FileKind::Core);
+
+        /// Turn the object's PT_INTERP segment into a PT_NULL one
+        fn without_interp(name: &str) -> FileKind {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            let mut file_data = std::fs::read(path).expect("Could not read file.");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+            let index = file
+                .segments()
+                .expect("Failed to find segments")
+                .iter()
+                .position(|phdr| phdr.p_type == abi::PT_INTERP)
+                .expect("Failed to find PT_INTERP");
+            let offset = file.ehdr.e_phoff as usize + index * file.ehdr.e_phentsize as usize;
+            file_data[offset..offset + 4].copy_from_slice(&abi::PT_NULL.to_le_bytes());
+
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+            file.kind().expect("Failed to parse dynamic table")
+        }
+        assert_eq!(without_interp("basic.x86_64"), FileKind::StaticExecutable);
+        assert_eq!(without_interp("minidebuginfo.x86_64"), FileKind::StaticPie);
+    }
+
+    #[test]
+    fn soname() {
+        let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(
+            file.soname().expect("Failed to parse dynamic table"),
+            Some("libneeded.so.1")
+        );
+
+        // Executables don't have a soname
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(file.soname().expect("Failed to parse dynamic table"), None);
+    }
+
+    #[test]
+    fn rpath_and_runpath() {
+        let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let runpath = file
+            .runpath()
+            .expect("Failed to parse dynamic table")
+            .expect("Failed to find DT_RUNPATH");
+        assert_eq!(
+            runpath.iter().collect::<Vec<&str>>(),
+            ["$ORIGIN/../$LIB", "/opt/${PLATFORM}/lib"]
+        );
+        assert!(file
+            .rpath()
+            .expect("Failed to parse dynamic table")
+            .is_none());
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .runpath()
+            .expect("Failed to parse dynamic table")
+            .is_none());
+    }
+
+    #[test]
+    fn needed_libraries_without_section_headers() {
+        let path = std::path::PathBuf::from("sample-objects/needed.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Remove the section headers: e_shoff, e_shnum, and e_shstrndx
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x40].fill(0);
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.section_headers().is_none());
+        let strtab = file
+            .dynamic_strtab()
+            .expect("Failed to parse dynamic table")
+            .expect("Failed to find dynamic strtab");
+        assert_eq!(
+            strtab.get(1).expect("Failed to get string"),
+            "__gmon_start__"
+        );
+
+        let needed: Vec<&str> = file
+            .needed_libraries()
+            .expect("Failed to parse dynamic table")
+            .expect("Failed to find dynamic table")
+            .collect::<Result<_, _>>()
+            .expect("Failed to resolve names");
+        assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+    }
+
+    #[test]
+    fn debug_sections() {
+        let path = std::path::PathBuf::from("sample-objects/zdebug.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let ids: Vec<&str> = file
+            .debug_sections()
+            .expect("Failed to parse section headers")
+            .expect("Failed to find section headers")
+            .map(|section| section.map(|section| section.id()))
+            .collect::<Result<_, _>>()
+            .expect("Failed to parse debug sections");
+        assert_eq!(
+            ids,
+            ["info", "abbrev", "aranges", "line", "str", "line_str"]
+        );
+
+        let info = file
+            .debug_section(".debug_info")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .debug_info");
+        assert_eq!(info.name, ".zdebug_info");
+        assert_eq!(info.compression, DebugCompression::Gnu(0x6f));
+
+        let abbrev = file
+            .debug_section(".zdebug_abbrev")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .debug_abbrev");
+        assert_eq!(abbrev.name, ".debug_abbrev");
+        assert_eq!(abbrev.compression, DebugCompression::None);
+        assert_eq!(abbrev.uncompressed_size(), 0x4f);
+
+        assert!(file
+            .debug_section(".debug_ranges")
+            .expect("Failed to parse section headers")
+            .is_none());
+    }
+
+    #[test]
+    fn debug_section_skips_other_malformed_sections() {
+        let path = std::path::PathBuf::from("sample-objects/zdebug.x86_64.o");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Point .debug_abbrev's data past the end of the file
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("Failed to parse section headers");
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let index = shdrs
+            .iter()
+            .position(|shdr| strtab.get(shdr.sh_name as usize).ok() == Some(".debug_abbrev"))
+            .expect("file should have .debug_abbrev");
+        let sh_offset = file.ehdr.e_shoff as usize + index * file.ehdr.e_shentsize as usize + 24;
+        file_data[sh_offset..sh_offset + 8].copy_from_slice(&0xffff_ffffu64.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let line = file
+            .debug_section(".debug_line")
+            .expect("Failed to parse .debug_line")
+            .expect("Failed to find .debug_line");
+        assert_eq!(line.name, ".zdebug_line");
+
+        let err = file
+            .debug_section(".debug_abbrev")
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn debug_section_decompressed() {
+        let path = std::path::PathBuf::from("sample-objects/zdebug.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let line = file
+            .debug_section(".debug_line")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .debug_line");
+        let data = line.decompressed().expect("Failed to decompress");
+        assert_eq!(data.len(), 0x4f);
+        // DWARF 5 line program header: unit_length then version
+        assert_eq!(&data[..6], [0x4b, 0x00, 0x00, 0x00, 0x05, 0x00]);
+
+        let path = std::path::PathBuf::from("sample-objects/zlib.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let info = file
+            .debug_section(".debug_info")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .debug_info");
+        assert!(matches!(info.compression, DebugCompression::Elf(_)));
+        assert_eq!(
+            info.decompressed().expect("Failed to decompress").len() as u64,
+            info.uncompressed_size()
+        );
+    }
+
+    #[test]
+    fn tls_template() {
+        let path = std::path::PathBuf::from("sample-objects/tls.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let tls = file
+            .tls_template()
+            .expect("Failed to parse segments")
+            .expect("Failed to find PT_TLS");
+        assert_eq!(tls.vaddr, 0x3e90);
+        assert_eq!(tls.image, [0x34, 0x12, 0x00, 0x00]);
+        assert_eq!(tls.memsz, 0x30);
+        assert_eq!(tls.align, 0x10);
+        assert_eq!(tls.zero_fill_size(), 0x2c);
+
+        let (symtab, strtab) = file
+            .dynamic_symbol_table()
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let find = |name: &str| {
+            symtab
+                .iter()
+                .find(|sym| strtab.get(sym.st_name as usize).unwrap() == name)
+                .expect("Failed to find symbol")
+        };
+
+        let counter = find("counter");
+        assert!(counter.is_tls());
+        assert_eq!(counter.st_value, 0);
+        assert_eq!(
+            tls.symbol_image(&counter),
+            Some([0x34, 0x12, 0, 0].as_slice())
+        );
+
+        // buffer lives in .tbss, so it has no initialization image
+        let buffer = find("buffer");
+        assert!(buffer.is_tls());
+        assert_eq!(buffer.st_value, 0x10);
+        assert_eq!(tls.symbol_image(&buffer), None);
 
-        assert_eq!(shdr, None);
+        let bump = find("bump");
+        assert!(!bump.is_tls());
+        assert_eq!(tls.symbol_image(&bump), None);
     }
 
     #[test]
-    fn find_common_data() {
-        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    fn tls_template_none() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        let elf_scns = file.find_common_data().expect("file should parse");
-
-        // hello.so should find everything
-        assert!(elf_scns.symtab.is_some());
-        assert!(elf_scns.symtab_strs.is_some());
-        assert!(elf_scns.dynsyms.is_some());
-        assert!(elf_scns.dynsyms_strs.is_some());
-        assert!(elf_scns.dynamic.is_some());
-        assert!(elf_scns.sysv_hash.is_some());
-        assert!(elf_scns.gnu_hash.is_some());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.tls_template().expect("Failed to parse").is_none());
     }
 
     #[test]
-    fn section_data() {
+    fn init_and_fini_arrays() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(26)
-            .expect("shdr should be parsable");
-
-        assert_eq!(shdr.sh_type, SHT_NOBITS);
-
-        let (data, chdr) = file
-            .section_data(&shdr)
-            .expect("Failed to get section data");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
 
-        assert_eq!(chdr, None);
-        assert_eq!(data, &[]);
+        let init_array = file
+            .init_array()
+            .expect("Failed to parse .init_array")
+            .expect("Failed to find .init_array");
+        let funcs: Vec<FunctionPointer> = init_array.iter().collect();
+        assert_eq!(funcs, [FunctionPointer { addr: 0x4004c0 }]);
+        let (sym, name) = funcs[0]
+            .resolve(&symtab, &strtab)
+            .expect("Failed to resolve")
+            .expect("Failed to find symbol");
+        assert_eq!(name, "frame_dummy");
+        assert_eq!(sym.st_symtype(), abi::STT_FUNC);
+
+        let fini_array = file
+            .fini_array()
+            .expect("Failed to parse .fini_array")
+            .expect("Failed to find .fini_array");
+        assert_eq!(fini_array.len(), 1);
+        let func = fini_array.get(0).expect("Failed to get entry");
+        let (_, name) = func
+            .resolve(&symtab, &strtab)
+            .expect("Failed to resolve")
+            .expect("Failed to find symbol");
+        assert_eq!(name, "__do_global_dtors_aux");
+
+        assert!(file
+            .preinit_array()
+            .expect("Failed to parse .preinit_array")
+            .is_none());
+
+        assert!(FunctionPointer { addr: 0 }
+            .resolve(&symtab, &strtab)
+            .expect("Failed to resolve")
+            .is_none());
     }
 
-    // Test all the different section_data_as* with a section of the wrong type
     #[test]
-    fn section_data_as_wrong_type() {
+    fn section_data_as_function_array_wrong_type_errors() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        // Section 0 is SHT_NULL, so all of the section_data_as* should error on it
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
         let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(0)
-            .expect("shdr should be parsable");
-
+            .section_header_by_name(".text")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .text");
         let err = file
-            .section_data_as_strtab(&shdr)
-            .expect_err("shdr0 should be the wrong type");
+            .section_data_as_function_array(&shdr)
+            .expect_err("Expected an error");
         assert!(
             matches!(
                 err,
-                ParseError::UnexpectedSectionType((SHT_NULL, SHT_STRTAB))
+                ParseError::UnexpectedSectionType((abi::SHT_PROGBITS, abi::SHT_INIT_ARRAY))
             ),
             "Unexpected Error type found: {err}"
         );
+    }
 
-        let err = file
-            .section_data_as_rels(&shdr)
-            .expect_err("shdr0 should be the wrong type");
-        assert!(
-            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_REL))),
-            "Unexpected Error type found: {err}"
+    #[test]
+    fn mips_abiflags_and_reginfo() {
+        let path = std::path::PathBuf::from("sample-objects/abiflags.mips.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let flags = file
+            .mips_abiflags()
+            .expect("Failed to parse abiflags")
+            .expect("Failed to find abiflags");
+        assert_eq!(
+            flags,
+            MipsAbiFlags {
+                version: 0,
+                isa_level: 32,
+                isa_rev: 2,
+                gpr_size: abi::MIPS_AFL_REG_32,
+                cpr1_size: abi::MIPS_AFL_REG_32,
+                cpr2_size: abi::MIPS_AFL_REG_NONE,
+                fp_abi: abi::VAL_GNU_MIPS_ABI_FP_DOUBLE,
+                isa_ext: 0,
+                ases: abi::MIPS_AFL_ASE_DSP | abi::MIPS_AFL_ASE_MSA,
+                flags1: abi::MIPS_AFL_FLAGS1_ODDSPREG,
+                flags2: 0,
+            }
         );
 
-        let err = file
-            .section_data_as_relas(&shdr)
-            .expect_err("shdr0 should be the wrong type");
-        assert!(
-            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_RELA))),
-            "Unexpected Error type found: {err}"
+        let reginfo = file
+            .mips_reginfo()
+            .expect("Failed to parse reginfo")
+            .expect("Failed to find reginfo");
+        assert_eq!(
+            reginfo,
+            MipsRegInfo {
+                gprmask: 0xf00000f6,
+                cprmask: [0; 4],
+                gp_value: 0x418ff0,
+            }
         );
+    }
+
+    #[test]
+    fn mips_abiflags_non_mips_is_none() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.mips_abiflags().expect("should parse").is_none());
+        assert!(file.mips_reginfo().expect("should parse").is_none());
+    }
 
+    #[test]
+    fn section_data_as_attributes_wrong_type_errors() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".dynsym")
+            .expect("Failed to parse section headers")
+            .expect("Failed to find .dynsym");
         let err = file
-            .section_data_as_notes(&shdr)
-            .expect_err("shdr0 should be the wrong type");
+            .section_data_as_attributes(&shdr)
+            .expect_err("Expected an error");
         assert!(
-            matches!(err, ParseError::UnexpectedSectionType((SHT_NULL, SHT_NOTE))),
+            matches!(
+                err,
+                ParseError::UnexpectedSectionType((abi::SHT_DYNSYM, abi::SHT_ARM_ATTRIBUTES))
+            ),
             "Unexpected Error type found: {err}"
         );
+
+        // Objects without an attributes section
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .build_attributes()
+            .expect("Failed to parse attributes")
+            .is_none());
     }
 
     #[test]
-    fn section_data_as_strtab() {
+    fn comments() {
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let comments: Vec<&str> = file
+            .comments()
+            .expect("Failed to get .comment")
+            .expect("Failed to find .comment")
+            .collect::<Result<_, _>>()
+            .expect("Failed to parse .comment");
+        // The leading empty string of relocatable objects' .comment is skipped
+        assert_eq!(comments, ["GCC: (Debian 12.2.0-14+deb12u1) 12.2.0"]);
+
+        let path = std::path::PathBuf::from("sample-objects/core.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file.comments().expect("Failed to get .comment").is_none());
+    }
+
+    #[test]
+    fn interpreter() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(
+            file.interpreter().expect("Failed to parse interp"),
+            Some("/lib64/ld-linux-x86-64.so.2")
+        );
 
-        let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(file.ehdr.e_shstrndx as usize)
-            .expect("shdr should be parsable");
+        // Shared objects don't request an interpreter
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(file.interpreter().expect("Failed to parse interp"), None);
+    }
 
-        let strtab = file
-            .section_data_as_strtab(&shdr)
-            .expect("Failed to read strtab");
+    #[test]
+    fn interpreter_missing_nul_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        // Clobber the PT_INTERP path's NUL terminator
+        file_data[0x200 + 0x1b] = b'!';
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let err = file.interpreter().expect_err("Expected missing NUL error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(0x200)),
+            "Unexpected Error type found: {err}"
+        );
+    }
 
+    #[test]
+    fn abi_tag() {
+        let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
         assert_eq!(
-            strtab.get(1).expect("Failed to get strtab entry"),
-            ".symtab"
+            file.abi_tag().expect("Failed to parse notes"),
+            Some(NoteGnuAbiTag {
+                os: abi::ELF_NOTE_GNU_ABI_TAG_OS_LINUX,
+                major: 3,
+                minor: 2,
+                subminor: 0
+            })
         );
+
+        let path = std::path::PathBuf::from("sample-objects/stripped.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert_eq!(file.abi_tag().expect("Failed to parse notes"), None);
     }
 
     #[test]
-    fn section_data_as_relas() {
-        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    fn gnu_property() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(10)
-            .expect("Failed to get rela shdr");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let endian = file.ehdr.endianness;
+
+        let property = file
+            .gnu_property()
+            .expect("Failed to parse notes")
+            .expect("Failed to find property note");
+        let features = property
+            .get_u32(endian, abi::GNU_PROPERTY_X86_FEATURE_1_AND)
+            .expect("Failed to parse property");
+        assert_eq!(
+            features,
+            abi::GNU_PROPERTY_X86_FEATURE_1_IBT | abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK
+        );
 
-        let mut relas = file
-            .section_data_as_relas(&shdr)
-            .expect("Failed to read relas section");
+        let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        let property = file
+            .gnu_property()
+            .expect("Failed to parse notes")
+            .expect("Failed to find property note");
         assert_eq!(
-            relas.next().expect("Failed to get rela entry"),
-            Rela {
-                r_offset: 6293704,
-                r_sym: 1,
-                r_type: 7,
-                r_addend: 0,
-            }
+            property
+                .get_u32(endian, abi::GNU_PROPERTY_X86_ISA_1_NEEDED)
+                .expect("Failed to parse property"),
+            abi::GNU_PROPERTY_X86_ISA_1_BASELINE
         );
         assert_eq!(
-            relas.next().expect("Failed to get rela entry"),
-            Rela {
-                r_offset: 6293712,
-                r_sym: 2,
-                r_type: 7,
-                r_addend: 0,
-            }
+            property
+                .get_u32(endian, abi::GNU_PROPERTY_X86_FEATURE_1_AND)
+                .expect("Failed to parse property"),
+            0
         );
-        assert!(relas.next().is_none());
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .gnu_property()
+            .expect("Failed to parse notes")
+            .is_none());
     }
 
+    #[cfg(feature = "xz")]
     #[test]
-    fn section_data_as_notes() {
-        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    fn mini_debug_info() {
+        let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
         let slice = file_data.as_slice();
         let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(2)
-            .expect("Failed to get note shdr");
-
-        let mut notes = file
-            .section_data_as_notes(&shdr)
-            .expect("Failed to read note section");
-        assert_eq!(
-            notes.next().expect("Failed to get first note"),
-            Note::GnuAbiTag(NoteGnuAbiTag {
-                os: 0,
-                major: 2,
-                minor: 6,
-                subminor: 32
-            })
+        assert!(file.symbol_table().expect("should parse").is_none());
+
+        let mini_debug_info = file
+            .mini_debug_info(3392)
+            .expect("Failed to decompress .gnu_debugdata")
+            .expect("Failed to find .gnu_debugdata");
+        assert_eq!(mini_debug_info.data().len(), 3392);
+        let err = file.mini_debug_info(3391).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
         );
-        assert!(notes.next().is_none());
+        let mini_elf = mini_debug_info.elf().expect("Failed to parse embedded ELF");
+        assert_eq!(mini_elf.ehdr.e_machine, abi::EM_X86_64);
+
+        let symbols = file
+            .symbols_with_mini_debug_info(1 << 20)
+            .expect("Failed to get merged symbols");
+        let find = |name: &str| {
+            symbols
+                .iter()
+                .find(|(_, sym_name)| sym_name == name)
+                .map(|(sym, _)| sym.st_value)
+        };
+        // From the .dynsym
+        assert_eq!(find("exported_fn"), Some(0x114e));
+        assert_eq!(find("main"), Some(0x1168));
+        // From the MiniDebugInfo's .symtab
+        assert_eq!(find("helper"), Some(0x1139));
+        assert_eq!(find("frame_dummy"), Some(0x1130));
+        assert!(symbols
+            .windows(2)
+            .all(|pair| pair[0].0.st_value <= pair[1].0.st_value));
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+        assert!(file
+            .mini_debug_info(1 << 20)
+            .expect("should parse")
+            .is_none());
     }
 
     #[test]
-    fn segment_data_as_notes() {
-        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    fn segment_data_as_notes_core() {
+        let path = std::path::PathBuf::from("sample-objects/core.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
         let slice = file_data.as_slice();
         let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        assert_eq!(file.ehdr.e_type, abi::ET_CORE);
 
         let phdr = file
             .segments()
-            .expect("File should have segmetn table")
-            .get(5)
+            .expect("File should have segment table")
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_NOTE)
             .expect("Failed to get notes phdr");
-
-        let mut notes = file
+        let notes: Vec<Note<'_>> = file
             .segment_data_as_notes(&phdr)
-            .expect("Failed to read notes segment");
-        assert_eq!(
-            notes.next().expect("Failed to get first note"),
-            Note::GnuAbiTag(NoteGnuAbiTag {
-                os: 0,
-                major: 2,
-                minor: 6,
-                subminor: 32
+            .expect("Failed to read notes segment")
+            .collect();
+
+        let prstatus = notes
+            .iter()
+            .find_map(|note| match note {
+                Note::PrStatus(prstatus) => Some(prstatus),
+                _ => None,
+            })
+            .expect("Should have a NT_PRSTATUS note");
+        assert_eq!(prstatus.signo, 6);
+        assert_eq!(prstatus.cursig, 6);
+        assert_eq!(prstatus.pid, 27124);
+        assert_eq!(prstatus.ppid, 27123);
+        assert_eq!(prstatus.pgrp, 27123);
+        assert_eq!(prstatus.sid, 27118);
+        assert!(prstatus.fpvalid);
+        let regs: X86_64Registers = prstatus
+            .registers(file.ehdr.endianness)
+            .expect("Failed to parse registers");
+        assert_eq!(regs.rip, 0x7f4f58e4aeec);
+        assert_eq!(regs.rsp, 0x7ffc7be1f1b0);
+        assert_eq!(regs.rbp, 0x7ffc7be1f210);
+        assert_eq!(regs.orig_rax, 0xea);
+        assert_eq!(regs.cs, 0x33);
+        assert_eq!(regs.fs_base, 0x7f4f58dbd740);
+
+        let prpsinfo = notes
+            .iter()
+            .find_map(|note| match note {
+                Note::PrPsInfo(prpsinfo) => Some(prpsinfo),
+                _ => None,
             })
+            .expect("Should have a NT_PRPSINFO note");
+        assert_eq!(prpsinfo.sname, b'R');
+        assert_eq!(prpsinfo.flag, 0x400600);
+        assert_eq!(prpsinfo.pid, 27124);
+        assert_eq!(prpsinfo.fname_str().expect("should be utf8"), "crash");
+        assert_eq!(
+            prpsinfo.psargs_str().expect("should be utf8"),
+            "./crash arg1 arg2 "
         );
+
+        let nt_file = notes
+            .iter()
+            .find_map(|note| match note {
+                Note::File(nt_file) => Some(nt_file),
+                _ => None,
+            })
+            .expect("Should have a NT_FILE note");
+        assert_eq!(nt_file.count, 15);
+        assert_eq!(nt_file.page_size, 4096);
+        let mappings: Vec<_> = nt_file.mappings(file.ehdr.endianness).collect();
+        assert_eq!(mappings.len(), 15);
         assert_eq!(
-            notes.next().expect("Failed to get second note"),
-            Note::GnuBuildId(NoteGnuBuildId(&[
-                119, 65, 159, 13, 165, 16, 131, 12, 87, 167, 200, 204, 176, 238, 133, 95, 238, 211,
-                118, 163
-            ]))
+            mappings[0],
+            NoteFileMapping {
+                start: 0x55b486803000,
+                end: 0x55b486804000,
+                page_offset: 0,
+                path: b"/tmp/coretest/crash",
+            }
         );
-        assert!(notes.next().is_none());
+        let libc = mappings
+            .iter()
+            .find(|mapping| mapping.path == b"/usr/lib/x86_64-linux-gnu/libc.so.6")
+            .expect("Should have mapped libc");
+        assert_eq!(libc.start, 0x7f4f58dc0000);
+        assert_eq!(libc.page_offset, 0);
+
+        let auxv = notes
+            .iter()
+            .find_map(|note| match note {
+                Note::Auxv(auxv) => Some(auxv),
+                _ => None,
+            })
+            .expect("Should have a NT_AUXV note");
+        let endian = file.ehdr.endianness;
+        assert_eq!(auxv.get(endian, abi::AT_SYSINFO_EHDR), Some(0x7f4f58fb2000));
+        assert_eq!(auxv.get(endian, abi::AT_PAGESZ), Some(0x1000));
+        assert_eq!(auxv.get(endian, abi::AT_PHDR), Some(0x55b486803040));
+        assert_eq!(auxv.get(endian, abi::AT_PHENT), Some(0x38));
+        assert_eq!(auxv.get(endian, abi::AT_PHNUM), Some(0xd));
+        assert_eq!(auxv.get(endian, abi::AT_BASE), Some(0x7f4f58fb4000));
+        assert_eq!(auxv.get(endian, abi::AT_ENTRY), Some(0x55b486804050));
+        assert!(auxv.iter(endian).all(|entry| entry.a_type != abi::AT_NULL));
     }
 
     #[test]
@@ -1378,6 +4643,41 @@ mod interface_tests {
         assert!(def.hidden);
         let def_names: Vec<&str> = def.names.map(|res| res.expect("should parse")).collect();
         assert_eq!(def_names, &["HELLO_1.42"]);
+
+        let versions: Vec<Option<SymbolVersion<'_>>> = (0..10)
+            .map(|idx| vst.get_version(idx).expect("Failed to parse version"))
+            .collect();
+        let glibc = Some(SymbolVersion {
+            name: "GLIBC_2.2.5",
+            hidden: false,
+        });
+        let hello_1_0 = |hidden| {
+            Some(SymbolVersion {
+                name: "HELLO_1.0",
+                hidden,
+            })
+        };
+        let hello_1_42 = |hidden| {
+            Some(SymbolVersion {
+                name: "HELLO_1.42",
+                hidden,
+            })
+        };
+        assert_eq!(
+            versions,
+            [
+                None,
+                None,
+                glibc,
+                None,
+                None,
+                glibc,
+                hello_1_0(false),
+                hello_1_42(true),
+                hello_1_42(false),
+                hello_1_0(true),
+            ]
+        );
     }
 
     #[test]
@@ -1405,6 +4705,10 @@ mod interface_tests {
         assert_eq!(sysv_hash(b"__gmon_start__") % 3, 0);
         assert_eq!(sysv_hash(b"memset") % 3, 0);
 
+        // The chains are sized to match the symbol table
+        assert_eq!(hash_table.buckets().len(), 3);
+        assert_eq!(hash_table.chains().len(), symtab.len());
+
         // Use the hash table to find a given symbol in it.
         let (sym_idx, sym) = hash_table
             .find(b"memset", &symtab, &strtab)
@@ -1448,6 +4752,61 @@ mod interface_tests {
             symtab.get(sym_idx).expect("Failed to get expected sym")
         );
     }
+
+    #[test]
+    fn matches_debug_file() {
+        let read = |name: &str| {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            std::fs::read(path).expect("Could not read file.")
+        };
+        let stripped_so = read("stripped.x86_64.so");
+        let symver_so = read("symver.x86_64.so");
+        let symver_aarch64 = read("symver.aarch64.so");
+        let symver_armhf = read("symver.armhf.so");
+        let basic = read("basic.x86_64");
+        let basic_debug = read("basic.x86_64.debug");
+        let debuglink = read("debuglink.x86_64");
+
+        fn parse(data: &[u8]) -> ElfBytes<'_, AnyEndian> {
+            ElfBytes::<AnyEndian>::minimal_parse(data).expect("Open test1")
+        }
+        let stripped_so = parse(&stripped_so);
+        let symver_so = parse(&symver_so);
+        let symver_aarch64 = parse(&symver_aarch64);
+        let symver_armhf = parse(&symver_armhf);
+        let basic = parse(&basic);
+        let basic_debug = parse(&basic_debug);
+        let debuglink = parse(&debuglink);
+
+        assert_eq!(
+            stripped_so.matches_debug_file(&symver_so).unwrap(),
+            DebugFileMatch::BuildId
+        );
+        assert_eq!(
+            symver_so.matches_debug_file(&basic).unwrap(),
+            DebugFileMatch::BuildIdMismatch
+        );
+        assert_eq!(
+            symver_so.matches_debug_file(&symver_aarch64).unwrap(),
+            DebugFileMatch::MachineMismatch((abi::EM_X86_64, abi::EM_AARCH64))
+        );
+        assert_eq!(
+            symver_so.matches_debug_file(&symver_armhf).unwrap(),
+            DebugFileMatch::ClassMismatch
+        );
+        assert_eq!(
+            debuglink.matches_debug_file(&basic_debug).unwrap(),
+            DebugFileMatch::DebugLinkCrc
+        );
+        assert_eq!(
+            debuglink.matches_debug_file(&basic).unwrap(),
+            DebugFileMatch::DebugLinkCrcMismatch((0x42FD95F6, gnu_debuglink_crc32(0, basic.data)))
+        );
+        assert_eq!(
+            basic_debug.matches_debug_file(&debuglink).unwrap(),
+            DebugFileMatch::NoIdentifiers
+        );
+    }
 }
 
 #[cfg(test)]
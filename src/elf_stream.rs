@@ -1,21 +1,29 @@
 use core::ops::Range;
 use std::collections::HashMap;
 use std::io::{Read, Seek, SeekFrom};
+#[cfg(feature = "parallel")]
+use std::path::Path;
 
 use crate::abi;
+#[cfg(all(feature = "parallel", any(feature = "zlib", feature = "zstd")))]
+use crate::compression::decompress as decompress_section;
 use crate::compression::CompressionHeader;
+use crate::debuglink::DebugLink;
 use crate::dynamic::DynamicTable;
+#[cfg(feature = "parallel")]
+use crate::endian::AnyEndian;
 use crate::endian::EndianParse;
 use crate::file::{parse_ident, Class};
 use crate::gnu_symver::{
     SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
 };
+use crate::group::SectionGroup;
 use crate::note::NoteIterator;
-use crate::parse::{ParseAt, ParseError};
-use crate::relocation::{RelIterator, RelaIterator};
-use crate::section::{SectionHeader, SectionHeaderTable};
+use crate::options::OpenOptions;
+use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::relocation::{RelIterator, RelaIterator, RelrIterator};
+use crate::section::SectionHeader;
 use crate::segment::ProgramHeader;
-use crate::segment::SegmentTable;
 use crate::string_table::StringTable;
 use crate::symbol::{Symbol, SymbolTable};
 
@@ -31,86 +39,190 @@ pub struct ElfStream<E: EndianParse, S: std::io::Read + std::io::Seek> {
     reader: CachingReader<S>,
 }
 
-/// Read the stream bytes backing the section headers table and parse them all into their Rust native type.
+/// Which header table a [HeaderTable] describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeaderTableKind {
+    Sections,
+    Segments,
+}
+
+/// Internal helper which works out where the section or program header table is from the
+/// [FileHeader] (and, for counts too large for the file header, the first section header).
 ///
-/// Returns a [ParseError] if the data bytes for the section table cannot be read.
-/// i.e. if the ELF [FileHeader]'s e_shnum, e_shoff, e_shentsize are invalid and point
-/// to a range in the file data that does not actually exist, or if any of the headers failed to parse.
-fn parse_section_headers<E: EndianParse, S: Read + Seek>(
-    ehdr: &FileHeader<E>,
-    reader: &mut CachingReader<S>,
-) -> Result<Vec<SectionHeader>, ParseError> {
-    // It's Ok to have no section headers
-    if ehdr.e_shoff == 0 {
-        return Ok(Vec::default());
-    }
-
-    // Validate shentsize before trying to read the table so that we can error early for corrupted files
-    let entsize = SectionHeader::validate_entsize(ehdr.class, ehdr.e_shentsize as usize)?;
-
-    // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
-    // e_shnum is zero and the actual number of section header table entries
-    // is contained in the sh_size field of the section header at index 0.
-    let shoff: usize = ehdr.e_shoff.try_into()?;
-    let mut shnum = ehdr.e_shnum as usize;
-    if shnum == 0 {
-        let end = shoff
-            .checked_add(entsize)
-            .ok_or(ParseError::IntegerOverflow)?;
+/// This is shared by [ElfStream] and [AsyncElfStream](crate::AsyncElfStream) so that they only
+/// differ in how they read the file data:
+/// 1. [HeaderTable::new] validates the file header's fields for the table.
+/// 2. If [HeaderTable::shdr0_range] is Some, read it and pass it to [HeaderTable::set_shdr0].
+/// 3. Read [HeaderTable::table_range] and pass it to [HeaderTable::parse].
+#[derive(Debug)]
+pub(crate) struct HeaderTable {
+    kind: HeaderTableKind,
+    offset: usize,
+    entsize: usize,
+    count: usize,
+    /// The file range of the first section header, if the count has to be read from it
+    shdr0: Option<Range<usize>>,
+}
+
+impl HeaderTable {
+    /// Validate the file header's fields for the given table, or get None if there's no table.
+    pub(crate) fn new<E: EndianParse>(
+        kind: HeaderTableKind,
+        ehdr: &FileHeader<E>,
+    ) -> Result<Option<Self>, ParseError> {
+        match kind {
+            HeaderTableKind::Sections => {
+                // It's Ok to have no section headers
+                if ehdr.e_shoff == 0 && ehdr.e_shnum == 0 {
+                    return Ok(None);
+                }
+
+                // ...but a corrupted offset must not point the table back into the file header itself
+                ehdr.validate_table_offset(ehdr.e_shoff)?;
+
+                // Validate shentsize before trying to read the table so that we can error early for corrupted files
+                let entsize =
+                    SectionHeader::validate_stride(ehdr.class, ehdr.e_shentsize as usize)?;
+
+                // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
+                // e_shnum is zero and the actual number of section header table entries
+                // is contained in the sh_size field of the section header at index 0.
+                let offset: usize = ehdr.e_shoff.try_into()?;
+                let shdr0 = match ehdr.e_shnum {
+                    0 => Some(
+                        offset
+                            ..offset
+                                .checked_add(entsize)
+                                .ok_or(ParseError::IntegerOverflow)?,
+                    ),
+                    _ => None,
+                };
+                Ok(Some(HeaderTable {
+                    kind,
+                    offset,
+                    entsize,
+                    count: ehdr.e_shnum as usize,
+                    shdr0,
+                }))
+            }
+            HeaderTableKind::Segments => {
+                // It's Ok to have no program headers
+                if ehdr.e_phoff == 0 && ehdr.e_phnum == 0 {
+                    return Ok(None);
+                }
+
+                // ...but a corrupted offset must not point the table back into the file header itself
+                if ehdr.e_phnum != 0 {
+                    ehdr.validate_table_offset(ehdr.e_phoff)?;
+                }
+
+                // If the number of segments is greater than or equal to PN_XNUM (0xffff),
+                // e_phnum is set to PN_XNUM, and the actual number of program header table
+                // entries is contained in the sh_info field of the section header at index 0.
+                let shdr0 = if ehdr.e_phnum == abi::PN_XNUM {
+                    ehdr.validate_table_offset(ehdr.e_shoff)?;
+                    let shoff: usize = ehdr.e_shoff.try_into()?;
+                    let end = shoff
+                        .checked_add(SectionHeader::size_for(ehdr.class))
+                        .ok_or(ParseError::IntegerOverflow)?;
+                    Some(shoff..end)
+                } else {
+                    None
+                };
+                Ok(Some(HeaderTable {
+                    kind,
+                    offset: ehdr.e_phoff.try_into()?,
+                    // Validated once the count is known, see table_range()
+                    entsize: ehdr.e_phentsize as usize,
+                    count: ehdr.e_phnum as usize,
+                    shdr0,
+                }))
+            }
+        }
+    }
+
+    /// The file range of the first section header, if the table's entry count is stored there.
+    pub(crate) fn shdr0_range(&self) -> Option<Range<usize>> {
+        self.shdr0.clone()
+    }
+
+    /// Take the table's entry count from the first section header's bytes.
+    pub(crate) fn set_shdr0<E: EndianParse>(
+        &mut self,
+        ehdr: &FileHeader<E>,
+        data: &[u8],
+    ) -> Result<(), ParseError> {
         let mut offset = 0;
-        let data = reader.read_bytes(shoff, end)?;
         let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        shnum = shdr0.sh_size.try_into()?;
-    }
-
-    let size = entsize
-        .checked_mul(shnum)
-        .ok_or(ParseError::IntegerOverflow)?;
-    let end = shoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(shoff, end)?;
-    let shdr_vec = SectionHeaderTable::new(ehdr.endianness, ehdr.class, buf)
-        .iter()
-        .collect();
-    Ok(shdr_vec)
+        self.count = match self.kind {
+            HeaderTableKind::Sections => shdr0.sh_size.try_into()?,
+            HeaderTableKind::Segments => shdr0.sh_info.try_into()?,
+        };
+        Ok(())
+    }
+
+    /// Check the entry count against the options' limits, and get the table's file range.
+    pub(crate) fn table_range<E: EndianParse>(
+        &mut self,
+        ehdr: &FileHeader<E>,
+        options: &OpenOptions,
+    ) -> Result<Range<usize>, ParseError> {
+        match self.kind {
+            HeaderTableKind::Sections => options.check_shnum(self.count)?,
+            HeaderTableKind::Segments => {
+                options.check_phnum(self.count)?;
+                // Validate phentsize before trying to read the table so that we can error early for corrupted files
+                self.entsize = ProgramHeader::validate_stride(ehdr.class, self.entsize)?;
+            }
+        }
+
+        let size = self
+            .entsize
+            .checked_mul(self.count)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let end = self
+            .offset
+            .checked_add(size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        Ok(self.offset..end)
+    }
+
+    /// Parse all the entries of the table from its bytes.
+    pub(crate) fn parse<E: EndianParse, P: ParseAt>(
+        &self,
+        ehdr: &FileHeader<E>,
+        buf: &[u8],
+    ) -> Vec<P> {
+        ParsingTable::<E, P>::with_entsize(ehdr.endianness, ehdr.class, self.entsize, buf)
+            .iter()
+            .collect()
+    }
 }
 
-fn parse_program_headers<E: EndianParse, S: Read + Seek>(
+/// Read the stream bytes backing the section or program headers table and parse them all into
+/// their Rust native type.
+///
+/// Returns a [ParseError] if the data bytes for the table cannot be read.
+/// i.e. if the ELF [FileHeader]'s e_shnum, e_shoff, e_shentsize (or the e_ph* equivalents) are
+/// invalid and point to a range in the file data that does not actually exist, or if any of the
+/// headers failed to parse.
+fn parse_header_table<E: EndianParse, S: Read + Seek, P: ParseAt>(
+    kind: HeaderTableKind,
     ehdr: &FileHeader<E>,
     reader: &mut CachingReader<S>,
-) -> Result<Vec<ProgramHeader>, ParseError> {
-    // It's Ok to have no program headers
-    if ehdr.e_phoff == 0 {
-        return Ok(Vec::default());
-    }
-
-    // If the number of segments is greater than or equal to PN_XNUM (0xffff),
-    // e_phnum is set to PN_XNUM, and the actual number of program header table
-    // entries is contained in the sh_info field of the section header at index 0.
-    let mut phnum = ehdr.e_phnum as usize;
-    if phnum == abi::PN_XNUM as usize {
-        let shoff: usize = ehdr.e_shoff.try_into()?;
-        let end = shoff
-            .checked_add(SectionHeader::size_for(ehdr.class))
-            .ok_or(ParseError::IntegerOverflow)?;
-        let data = reader.read_bytes(shoff, end)?;
-        let mut offset = 0;
-        let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        phnum = shdr0.sh_info.try_into()?;
-    }
-
-    // Validate phentsize before trying to read the table so that we can error early for corrupted files
-    let entsize = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize)?;
-
-    let phoff: usize = ehdr.e_phoff.try_into()?;
-    let size = entsize
-        .checked_mul(phnum)
-        .ok_or(ParseError::IntegerOverflow)?;
-    let end = phoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(phoff, end)?;
-    let phdrs_vec = SegmentTable::new(ehdr.endianness, ehdr.class, buf)
-        .iter()
-        .collect();
-    Ok(phdrs_vec)
+) -> Result<Vec<P>, ParseError> {
+    let mut table = match HeaderTable::new(kind, ehdr)? {
+        Some(table) => table,
+        None => {
+            return Ok(Vec::default());
+        }
+    };
+    if let Some(range) = table.shdr0_range() {
+        table.set_shdr0(ehdr, reader.read_bytes(range.start, range.end)?)?;
+    }
+    let range = table.table_range(ehdr, &reader.options)?;
+    let buf = reader.read_bytes(range.start, range.end)?;
+    Ok(table.parse(ehdr, buf))
 }
 
 impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
@@ -118,8 +230,18 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     ///
     /// This parses the ELF [FileHeader], [SectionHeader] table, and [ProgramHeader] (segments) table.
     /// All other file data (section data, segment data) is left unread and unparsed.
+    ///
+    /// This opens the stream with the default [OpenOptions]. See [OpenOptions::open_stream]
+    /// for opening it with other options.
     pub fn open_stream(reader: S) -> Result<ElfStream<E, S>, ParseError> {
-        let mut cr = CachingReader::new(reader)?;
+        OpenOptions::new().open_stream(reader)
+    }
+
+    pub(crate) fn open_stream_with_options(
+        reader: S,
+        options: &OpenOptions,
+    ) -> Result<ElfStream<E, S>, ParseError> {
+        let mut cr = CachingReader::new(reader, options.clone())?;
         let ident_buf = cr.read_bytes(0, abi::EI_NIDENT)?;
         let ident = parse_ident(ident_buf)?;
 
@@ -132,8 +254,8 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
 
         let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
 
-        let shdrs = parse_section_headers(&ehdr, &mut cr)?;
-        let phdrs = parse_program_headers(&ehdr, &mut cr)?;
+        let shdrs = parse_header_table(HeaderTableKind::Sections, &ehdr, &mut cr)?;
+        let phdrs = parse_header_table(HeaderTableKind::Segments, &ehdr, &mut cr)?;
 
         // We parsed out the ehdr and shdrs into their own allocated containers, so there's no need to keep
         // around their backing data anymore.
@@ -219,7 +341,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
     /// let io = std::fs::File::open(path).expect("Could not open file.");
     /// let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
-
+    ///
     /// let shdr: SectionHeader = *file
     ///     .section_header_by_name(".note.ABI-tag")
     ///     .expect("section table should be parseable")
@@ -262,6 +384,78 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         }))
     }
 
+    /// Get the given section's name from the section header string table.
+    ///
+    /// The string table is read from the stream on first use and cached, so looking up the
+    /// names of many sections only reads it once. Returns Ok(None) if the object has no section
+    /// header string table, and a [ParseError] if e_shstrndx or the section's
+    /// [sh_name](SectionHeader#structfield.sh_name) is out of range.
+    pub fn section_name(&mut self, shdr: &SectionHeader) -> Result<Option<&str>, ParseError> {
+        match self.section_headers_with_strtab()? {
+            (_, Some(strtab)) => Ok(Some(strtab.get(shdr.sh_name as usize)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get an iterator over the [SectionHeader]s with the given [sh_type](SectionHeader#structfield.sh_type).
+    ///
+    /// This is useful for finding sections such as [SHT_NOTE](abi::SHT_NOTE) in objects whose
+    /// section names were stripped or renamed.
+    pub fn section_headers_by_type(
+        &self,
+        sh_type: u32,
+    ) -> impl Iterator<Item = &SectionHeader> + '_ {
+        self.shdrs
+            .iter()
+            .filter(move |shdr| shdr.sh_type == sh_type)
+    }
+
+    /// Get the first [SectionHeader] with the given [sh_type](SectionHeader#structfield.sh_type) (if any).
+    pub fn section_header_by_type(&self, sh_type: u32) -> Option<&SectionHeader> {
+        self.section_headers_by_type(sh_type).next()
+    }
+
+    /// Get an iterator over the [SectionHeader]s which lie within the given segment, as
+    /// determined by [ProgramHeader::contains_section].
+    pub fn sections_in_segment(
+        &self,
+        phdr: &ProgramHeader,
+    ) -> impl Iterator<Item = &SectionHeader> + '_ {
+        let phdr = *phdr;
+        self.shdrs
+            .iter()
+            .filter(move |shdr| phdr.contains_section(shdr))
+    }
+
+    /// Get the [PT_LOAD](abi::PT_LOAD) segment which the given section is loaded by (if any).
+    pub fn segment_for_section(&self, shdr: &SectionHeader) -> Option<&ProgramHeader> {
+        self.phdrs
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_LOAD && phdr.contains_section(shdr))
+    }
+
+    /// Translate a virtual address to its offset in the file, using the PT_LOAD segment which
+    /// maps it (see [ProgramHeader::vaddr_to_offset]).
+    ///
+    /// Returns None if no PT_LOAD segment's file data backs the address, e.g. for a `.bss`.
+    pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        self.phdrs
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .find_map(|phdr| phdr.vaddr_to_offset(vaddr))
+    }
+
+    /// Translate a file offset to the virtual address it gets loaded at, using the PT_LOAD
+    /// segment which contains it (see [ProgramHeader::offset_to_vaddr]).
+    ///
+    /// Returns None if the offset isn't part of any PT_LOAD segment's file data.
+    pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+        self.phdrs
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .find_map(|phdr| phdr.offset_to_vaddr(offset))
+    }
+
     /// Read the section data for the given [SectionHeader](SectionHeader).
     /// Returns both the secion data and an optional CompressionHeader.
     ///
@@ -273,9 +467,12 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     /// CompressionHeader.
     ///
     /// It is up to the user to perform the decompression themselves with the compression library of
-    /// their choosing.
+    /// their choosing, or to enable the `zlib`/`zstd` cargo features and use [ElfStream::section_data_decompressed].
     ///
     /// SHT_NOBITS sections yield an empty slice.
+    ///
+    /// The bytes are read from the stream on first access and cached, so asking for the same
+    /// section again does not re-read it. See [ElfStream::clear_cache] for releasing that memory.
     pub fn section_data(
         &mut self,
         shdr: &SectionHeader,
@@ -305,6 +502,43 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         }
     }
 
+    /// Get the section data for a given [SectionHeader], decompressing it if it is compressed.
+    ///
+    /// Uncompressed section data is returned as-is, without being copied. Returns
+    /// [ParseError::UnsupportedCompression] for compression types whose cargo feature
+    /// (`zlib` or `zstd`) isn't enabled. See [compression::decompress](crate::compression::decompress).
+    ///
+    /// The decompressed size comes from the file's [CompressionHeader], so it is checked against
+    /// the [OpenOptions::max_read_size] and [OpenOptions::max_total_bytes] limits before
+    /// decompressing, failing with [ParseError::ReadSizeLimitExceeded] or
+    /// [ParseError::TotalReadLimitExceeded].
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn section_data_decompressed(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<std::borrow::Cow<'_, [u8]>, ParseError> {
+        if let Some(chdr) = self.section_data(shdr)?.1 {
+            let size: usize = chdr.ch_size.try_into()?;
+            self.reader
+                .options
+                .check_read(size, self.reader.cached_bytes)?;
+        }
+        match self.section_data(shdr)? {
+            (buf, None) => Ok(std::borrow::Cow::Borrowed(buf)),
+            (buf, Some(chdr)) => Ok(std::borrow::Cow::Owned(crate::compression::decompress(
+                &chdr, buf,
+            )?)),
+        }
+    }
+
+    /// Drop all the section and segment data read from the stream so far.
+    ///
+    /// The parsed headers are kept, and any data asked for again is re-read from the stream.
+    /// This is useful for bounding memory use when walking many sections of a large object.
+    pub fn clear_cache(&mut self) {
+        self.reader.clear_cache();
+    }
+
     /// Read the section data for the given
     /// [SectionHeader](SectionHeader) and interpret it in-place as a
     /// [StringTable](StringTable).
@@ -385,6 +619,31 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         self.get_symbol_table_of_type(abi::SHT_DYNSYM)
     }
 
+    /// Get the path of the program interpreter (dynamic loader) requested by the object, if any.
+    ///
+    /// This reads the [abi::PT_INTERP] segment or, if there are no program headers, the
+    /// `.interp` section, and returns its contents without the terminating NUL.
+    pub fn interpreter(&mut self) -> Result<Option<&str>, ParseError> {
+        let (start, end) = if !self.phdrs.is_empty() {
+            match self.phdrs.iter().find(|phdr| phdr.p_type == abi::PT_INTERP) {
+                Some(phdr) => phdr.get_file_data_range()?,
+                None => return Ok(None),
+            }
+        } else {
+            match self.section_header_by_name(".interp")? {
+                Some(shdr) => shdr.get_data_range()?,
+                None => return Ok(None),
+            }
+        };
+
+        let buf = self.reader.read_bytes(start, end)?;
+        let len = buf
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+        Ok(Some(core::str::from_utf8(&buf[..len])?))
+    }
+
     /// Get the .dynamic section/segment contents.
     pub fn dynamic(&mut self) -> Result<Option<DynamicTable<'_, E>>, ParseError> {
         // If we have section headers, then look it up there
@@ -618,6 +877,56 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         ))
     }
 
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [RelrIterator](RelrIterator).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_RELR](abi::SHT_RELR).
+    pub fn section_data_as_relrs(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<RelrIterator<'_, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_RELR {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_RELR,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(RelrIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [SectionGroup](SectionGroup).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_GROUP](abi::SHT_GROUP).
+    pub fn section_data_as_group(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<SectionGroup<'_, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_GROUP {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_GROUP,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        SectionGroup::parse(self.ehdr.endianness, self.ehdr.class, shdr, buf)
+    }
+
     /// Read the section data for the given
     /// [SectionHeader](SectionHeader) and interpret it in-place as a
     /// [NoteIterator](NoteIterator).
@@ -646,6 +955,54 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         ))
     }
 
+    /// Read and parse the `.gnu_debuglink` section contents (if any): the file name of this
+    /// object's separate debug file and the CRC32 of that file's contents.
+    ///
+    /// See [DebugLink::verify_reader] for checking a candidate debug file against the CRC.
+    pub fn debuglink(&mut self) -> Result<Option<DebugLink<'_>>, ParseError> {
+        let shdr = match self.section_header_by_name(".gnu_debuglink")? {
+            Some(shdr) => *shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let endian = self.ehdr.endianness;
+        let (buf, _) = self.section_data(&shdr)?;
+        Ok(Some(DebugLink::parse(endian, buf)?))
+    }
+
+    /// Read the segment's file data for the given [ProgramHeader].
+    ///
+    /// This is the segment's data as found in the file, i.e. its first
+    /// [p_filesz](ProgramHeader#structfield.p_filesz) bytes.
+    pub fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError> {
+        let (start, end) = phdr.get_file_data_range()?;
+        self.reader.read_bytes(start, end)
+    }
+
+    /// Read the segment's data as it is laid out in memory for the given [ProgramHeader]: its
+    /// file data followed by zeros up to [p_memsz](ProgramHeader#structfield.p_memsz).
+    ///
+    /// Segments with no zero-filled part are returned as-is, without being copied. Otherwise,
+    /// this allocates p_memsz bytes, which counts towards the
+    /// [OpenOptions::max_total_bytes](crate::OpenOptions::max_total_bytes) limit along with the
+    /// cached file data. Returns [ParseError::AllocationFailed] if the allocation fails.
+    pub fn segment_data_zero_extended(
+        &mut self,
+        phdr: &ProgramHeader,
+    ) -> Result<std::borrow::Cow<'_, [u8]>, ParseError> {
+        let phdr = *phdr;
+        self.segment_data(&phdr)?;
+        if phdr.p_memsz > phdr.p_filesz {
+            let memsz: usize = phdr.p_memsz.try_into()?;
+            self.reader
+                .options
+                .check_total(memsz, self.reader.cached_bytes)?;
+        }
+        phdr.zero_extend(self.segment_data(&phdr)?)
+    }
+
     /// Read the segment data for the given
     /// [Segment](ProgramHeader) and interpret it in-place as a
     /// [NoteIterator](NoteIterator).
@@ -675,22 +1032,291 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<E: EndianParse> ElfStream<E, std::fs::File> {
+    /// Read the raw file contents of the sections at the given section header `indices` concurrently.
+    ///
+    /// This is meant for bulk extraction of many large sections at once (e.g. all the `.debug_*`
+    /// sections), where reading them sequentially from a single handle is i/o-bound.
+    ///
+    /// The ELF headers are parsed once from `path`, then the requested sections are distributed
+    /// round-robin across up to [std::thread::available_parallelism] worker threads. Each worker
+    /// opens its own file handle to `path`, so this uses at most that many threads and
+    /// additional open file descriptors at a time, all of which are released before returning.
+    ///
+    /// The returned buffers are in the same order as `indices`, and contain the same bytes as a
+    /// sequential read of each section's file range. Like [ElfStream::section_data], SHT_NOBITS
+    /// sections yield an empty buffer. Unlike [ElfStream::section_data], the buffers for
+    /// SHF_COMPRESSED sections are returned as-is, with the leading [CompressionHeader] included.
+    ///
+    /// The headers are parsed with the given [OpenOptions], and the sections' buffers are
+    /// checked against its [OpenOptions::max_read_size] and [OpenOptions::max_total_bytes]
+    /// limits before any of them are read, with the total covering all of the returned buffers.
+    ///
+    /// Returns a [ParseError] if an index is out of bounds of the section header table, if a
+    /// section's data range does not fit within the file, if the buffers exceed the limits, or
+    /// if any of the reads fail.
+    pub fn read_sections_parallel<P: AsRef<Path>>(
+        path: P,
+        indices: &[usize],
+        options: &OpenOptions,
+    ) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::read_sections_parallel_impl(path.as_ref(), indices, options, false)
+    }
+
+    /// Read the contents of the sections at the given section header `indices` concurrently,
+    /// decompressing any SHF_COMPRESSED sections on the worker threads as well.
+    ///
+    /// This works like [ElfStream::read_sections_parallel], except that the buffers of
+    /// compressed sections hold their decompressed contents, like
+    /// [ElfStream::section_data_decompressed] returns. Large compressed debug sections are
+    /// usually bound by decompression rather than i/o, so this is where parallelism helps most.
+    /// The decompressed sizes from the sections' [CompressionHeader]s are checked against the
+    /// `options` limits as well.
+    ///
+    /// Returns [ParseError::UnsupportedCompression] for compression types whose cargo feature
+    /// (`zlib` or `zstd`) isn't enabled, in addition to the errors of
+    /// [ElfStream::read_sections_parallel].
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn read_sections_parallel_decompressed<P: AsRef<Path>>(
+        path: P,
+        indices: &[usize],
+        options: &OpenOptions,
+    ) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::read_sections_parallel_impl(path.as_ref(), indices, options, true)
+    }
+
+    fn read_sections_parallel_impl(
+        path: &Path,
+        indices: &[usize],
+        options: &OpenOptions,
+        decompress: bool,
+    ) -> Result<Vec<Vec<u8>>, ParseError> {
+        let mut file =
+            ElfStream::<E, _>::open_stream_with_options(std::fs::File::open(path)?, options)?;
+
+        // The workers only need to know the byte order to parse compression headers
+        let endian = if file.ehdr.endianness.is_little() {
+            AnyEndian::Little
+        } else {
+            AnyEndian::Big
+        };
+        let class = file.ehdr.class;
+
+        // The total size of the buffers which will be returned
+        let mut total: usize = 0;
+        let mut ranges: Vec<(Range<usize>, bool)> = Vec::with_capacity(indices.len());
+        for &index in indices {
+            let shdr = *file
+                .shdrs
+                .get(index)
+                .ok_or(ParseError::BadOffset(index as u64))?;
+            if shdr.sh_type == abi::SHT_NOBITS {
+                ranges.push((0..0, false));
+                continue;
+            }
+
+            let (start, end) = shdr.get_data_range()?;
+            if file.reader.stream_len.map_or(false, |len| end as u64 > len) {
+                return Err(ParseError::BadOffset(end as u64));
+            }
+            options.check_read(end - start, total)?;
+            let decompress = decompress && shdr.sh_flags & abi::SHF_COMPRESSED as u64 != 0;
+            let size = if decompress {
+                // The decompressed size comes from the file, so check it before decompressing
+                let chdr_end = start
+                    .checked_add(CompressionHeader::size_for(class))
+                    .ok_or(ParseError::IntegerOverflow)?;
+                let mut offset = 0;
+                let chdr = CompressionHeader::parse_at(
+                    file.ehdr.endianness,
+                    class,
+                    &mut offset,
+                    file.reader.read_bytes(start, chdr_end)?,
+                )?;
+                let size: usize = chdr.ch_size.try_into()?;
+                options.check_read(size, total)?;
+                size
+            } else {
+                end - start
+            };
+            total = total.saturating_add(size);
+            ranges.push((start..end, decompress));
+        }
+
+        let workers = std::thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(ranges.len());
+        let handles: Vec<_> = (0..workers)
+            .map(|worker| {
+                let path = path.to_path_buf();
+                let work: Vec<(usize, (Range<usize>, bool))> = ranges
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .skip(worker)
+                    .step_by(workers)
+                    .collect();
+                std::thread::spawn(move || -> Result<Vec<(usize, Vec<u8>)>, ParseError> {
+                    let mut reader = std::fs::File::open(path)?;
+                    work.into_iter()
+                        .map(|(i, (range, decompress))| {
+                            reader.seek(SeekFrom::Start(range.start as u64))?;
+                            let mut buf = vec![0; range.len()];
+                            reader.read_exact(&mut buf)?;
+                            if decompress {
+                                let mut offset = 0;
+                                let chdr =
+                                    CompressionHeader::parse_at(endian, class, &mut offset, &buf)?;
+                                buf = decompress_section(&chdr, &buf[offset..])?;
+                            }
+                            Ok((i, buf))
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+
+        let mut datas = vec![Vec::new(); ranges.len()];
+        for handle in handles {
+            let results = handle
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))?;
+            for (i, buf) in results {
+                datas[i] = buf;
+            }
+        }
+        Ok(datas)
+    }
+}
+
+/// Stands in for [crate::compression::decompress] when no decompression features are enabled,
+/// in which case [ElfStream::read_sections_parallel] never asks for decompression.
+#[cfg(all(feature = "parallel", not(any(feature = "zlib", feature = "zstd"))))]
+fn decompress_section(chdr: &CompressionHeader, _: &[u8]) -> Result<Vec<u8>, ParseError> {
+    Err(ParseError::UnsupportedCompression(chdr.ch_type))
+}
+
+/// Adapts a plain forward-only [Read], such as stdin, a pipe, or a decompression stream, into the
+/// `Read + Seek` needed by [ElfStream].
+///
+/// Bytes are pulled from the underlying reader only as far as the furthest offset requested so
+/// far, and are kept in memory so that earlier offsets can be read again. Since ELF objects
+/// usually end with their section header table, opening an [ElfStream] will generally spool
+/// most of the object.
+///
+/// Seeking relative to the end of the stream isn't supported, as that would require reading the
+/// whole stream up front. [ElfStream] works without knowing the stream's length, except that
+/// ranges which run past the end of the stream are only detected once they are read. Use
+/// [OpenOptions::max_read_size] to bound the size of each read from untrusted input.
+///
+/// Example for parsing an object from stdin:
+/// ```no_run
+/// use elf::endian::AnyEndian;
+/// use elf::{ElfStream, SpooledReader};
+///
+/// let reader = SpooledReader::new(std::io::stdin());
+/// let file = ElfStream::<AnyEndian, _>::open_stream(reader).expect("should parse");
+/// println!("{:?}", file.ehdr);
+/// ```
+#[derive(Debug)]
+pub struct SpooledReader<R: Read> {
+    reader: R,
+    spool: Vec<u8>,
+    pos: u64,
+    eof: bool,
+}
+
+impl<R: Read> SpooledReader<R> {
+    /// Wrap the given reader, which will be read from its current position onwards.
+    pub fn new(reader: R) -> Self {
+        SpooledReader {
+            reader,
+            spool: Vec::new(),
+            pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Read from the underlying reader until at least `end` bytes have been spooled, or until
+    /// the end of the stream.
+    fn fill_to(&mut self, end: u64) -> std::io::Result<()> {
+        let spooled = self.spool.len() as u64;
+        if self.eof || end <= spooled {
+            return Ok(());
+        }
+        let wanted = end - spooled;
+        let read = (&mut self.reader)
+            .take(wanted)
+            .read_to_end(&mut self.spool)?;
+        if (read as u64) < wanted {
+            self.eof = true;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for SpooledReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.fill_to(self.pos.saturating_add(buf.len() as u64))?;
+        let start = (self.pos as usize).min(self.spool.len());
+        let available = &self.spool[start..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Read> Seek for SpooledReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(delta) if delta >= 0 => self.pos.checked_add(delta as u64),
+            SeekFrom::Current(delta) => self.pos.checked_sub(delta.unsigned_abs()),
+            SeekFrom::End(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "can't seek relative to the end of a forward-only stream",
+                ))
+            }
+        };
+        self.pos = new_pos.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
+
 #[derive(Debug)]
 struct CachingReader<R: Read + Seek> {
     reader: R,
-    stream_len: u64,
+    /// The stream's length, if it's seekable from the end (see [SpooledReader])
+    stream_len: Option<u64>,
+    options: OpenOptions,
     bufs: HashMap<(usize, usize), Box<[u8]>>,
+    /// The total size of the buffers in `bufs`
+    cached_bytes: usize,
 }
 
 impl<R: Read + Seek> CachingReader<R> {
-    fn new(mut reader: R) -> Result<Self, ParseError> {
+    fn new(mut reader: R, options: OpenOptions) -> Result<Self, ParseError> {
         // Cache the size of the stream so that we can err (rather than OOM) on invalid
         // huge read requests.
-        let stream_len = reader.seek(SeekFrom::End(0))?;
+        let stream_len = match reader.seek(SeekFrom::End(0)) {
+            Ok(len) => Some(len),
+            Err(err) if err.kind() == std::io::ErrorKind::Unsupported => None,
+            Err(err) => return Err(err.into()),
+        };
         Ok(CachingReader {
             reader,
             stream_len,
+            options,
             bufs: HashMap::<(usize, usize), Box<[u8]>>::default(),
+            cached_bytes: 0,
         })
     }
 
@@ -714,19 +1340,41 @@ impl<R: Read + Seek> CachingReader<R> {
 
         // Verify that the read range doesn't go past the end of the stream (corrupted files)
         let end = range.end as u64;
-        if end > self.stream_len {
+        if self.stream_len.map_or(false, |len| end > len) {
             return Err(ParseError::BadOffset(end));
         }
 
+        self.options.check_read(range.len(), self.cached_bytes)?;
+
         self.reader.seek(SeekFrom::Start(range.start as u64))?;
-        let mut bytes = vec![0; range.len()].into_boxed_slice();
-        self.reader.read_exact(&mut bytes)?;
+        let bytes = match self.stream_len {
+            Some(_) => {
+                let mut bytes = vec![0; range.len()].into_boxed_slice();
+                self.reader.read_exact(&mut bytes)?;
+                bytes
+            }
+            None => {
+                // Without a length to check the range against up front, read incrementally so
+                // that a bogus huge range fails at the end of the stream instead of allocating
+                // the whole range.
+                let mut bytes = Vec::new();
+                (&mut self.reader)
+                    .take(range.len() as u64)
+                    .read_to_end(&mut bytes)?;
+                if bytes.len() != range.len() {
+                    return Err(ParseError::BadOffset(end));
+                }
+                bytes.into_boxed_slice()
+            }
+        };
+        self.cached_bytes += bytes.len();
         self.bufs.insert((range.start, range.end), bytes);
         Ok(())
     }
 
     fn clear_cache(&mut self) {
-        self.bufs.clear()
+        self.bufs.clear();
+        self.cached_bytes = 0;
     }
 }
 
@@ -739,6 +1387,70 @@ mod interface_tests {
     use crate::note::{Note, NoteGnuAbiTag, NoteGnuBuildId};
     use crate::relocation::Rela;
 
+    #[test]
+    fn open_forward_only_stream() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        // Chain only implements Read, like a pipe would
+        let io = SpooledReader::new(file_data.as_slice().chain(std::io::empty()));
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let seekable = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+        assert_eq!(file.ehdr, seekable.ehdr);
+        assert_eq!(file.section_headers(), seekable.section_headers());
+        assert_eq!(file.segments(), seekable.segments());
+
+        let shdr = *file
+            .section_header_by_name(".text")
+            .expect("section table should be parseable")
+            .expect("file should have .text");
+        let start = shdr.sh_offset as usize;
+        let end = start + shdr.sh_size as usize;
+        let (data, chdr) = file.section_data(&shdr).expect("should read .text");
+        assert!(chdr.is_none());
+        assert_eq!(data, &file_data[start..end]);
+
+        // Ranges past the end of the stream are caught when they're read
+        let mut bad_shdr = shdr;
+        bad_shdr.sh_offset = file_data.len() as u64 - 4;
+        let result = file.section_data(&bad_shdr).expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::BadOffset(_)),
+            "Unexpected Error type found: {result}"
+        );
+    }
+
+    #[test]
+    fn spooled_reader_seeks() {
+        let data: Vec<u8> = (0..16).collect();
+        let mut reader = SpooledReader::new(data.as_slice().chain(std::io::empty()));
+
+        let mut buf = [0u8; 4];
+        reader.seek(SeekFrom::Start(8)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [8, 9, 10, 11]);
+        assert_eq!(reader.spool.len(), 12);
+
+        reader.seek(SeekFrom::Current(-10)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [2, 3, 4, 5]);
+        assert_eq!(reader.spool.len(), 12);
+
+        reader.seek(SeekFrom::Start(14)).unwrap();
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        let err = reader
+            .seek(SeekFrom::End(0))
+            .expect_err("Expected an error");
+        assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+        let err = reader
+            .seek(SeekFrom::Current(-100))
+            .expect_err("Expected an error");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
     #[test]
     fn test_open_stream() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -747,6 +1459,331 @@ mod interface_tests {
         assert_eq!(file.ehdr.e_type, abi::ET_EXEC);
     }
 
+    #[test]
+    fn open_with_max_read_size() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+
+        // The section header table is the largest read at 31 * 64 = 0x7c0 bytes
+        let mut file = OpenOptions::new()
+            .max_read_size(0x7c0)
+            .open_path::<AnyEndian, _>(&path)
+            .expect("Open test1");
+        let shdr = file.section_headers()[29];
+        file.section_data(&shdr)
+            .expect("read should be under the limit");
+
+        let err = OpenOptions::new()
+            .max_read_size(0x400)
+            .open_path::<AnyEndian, _>(&path)
+            .expect_err("Open should exceed the limit");
+        assert!(
+            matches!(err, ParseError::ReadSizeLimitExceeded((0x7c0, 0x400))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn open_with_max_total_bytes() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+
+        // Opening reads the ehdr (0x40), shdrs (0x7c0) and phdrs (0x1c0), then drops them
+        let mut file = OpenOptions::new()
+            .max_total_bytes(0x9c0)
+            .open_path::<AnyEndian, _>(&path)
+            .expect("Open test1");
+        let symtab = file.section_headers()[29];
+        let text_segment = file.segments()[2];
+        file.section_data(&symtab)
+            .expect("read should be under the limit");
+        // Cached data doesn't count twice
+        file.section_data(&symtab)
+            .expect("read should be under the limit");
+
+        let err = file
+            .segment_data(&text_segment)
+            .expect_err("read should exceed the limit");
+        assert!(
+            matches!(err, ParseError::TotalReadLimitExceeded((0xd34, 0x9c0))),
+            "Unexpected Error type found: {err}"
+        );
+
+        // Releasing the cache makes room again
+        file.clear_cache();
+        file.segment_data(&text_segment)
+            .expect("read should be under the limit");
+
+        // Zero-extended segment data counts towards the limit too
+        file.clear_cache();
+        let data_segment = file.segments()[3];
+        let err = file
+            .segment_data_zero_extended(&data_segment)
+            .expect_err("zero extending should exceed the limit");
+        assert!(
+            matches!(err, ParseError::TotalReadLimitExceeded((0x40488, 0x9c0))),
+            "Unexpected Error type found: {err}"
+        );
+
+        let err = OpenOptions::new()
+            .max_total_bytes(0x9bf)
+            .open_path::<AnyEndian, _>(&path)
+            .expect_err("Open should exceed the limit");
+        assert!(
+            matches!(err, ParseError::TotalReadLimitExceeded((0x9c0, 0x9bf))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn open_with_max_shnum_and_phnum() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(&path).expect("Could not read file.");
+
+        let mut options = OpenOptions::new();
+        options.max_shnum(31).max_phnum(8);
+        options
+            .open_path::<AnyEndian, _>(&path)
+            .expect("Open test1");
+        options
+            .open_slice::<AnyEndian>(&file_data)
+            .expect("Open test1");
+
+        let err = OpenOptions::new()
+            .max_shnum(30)
+            .open_path::<AnyEndian, _>(&path)
+            .expect_err("Open should exceed the limit");
+        assert!(
+            matches!(err, ParseError::EntryCountLimitExceeded((31, 30))),
+            "Unexpected Error type found: {err}"
+        );
+
+        let err = OpenOptions::new()
+            .max_phnum(7)
+            .open_slice::<AnyEndian>(&file_data)
+            .expect_err("Open should exceed the limit");
+        assert!(
+            matches!(err, ParseError::EntryCountLimitExceeded((8, 7))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn header_table_offsets_into_ehdr_error() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+
+        // e_phoff = 0, e_phnum = 3
+        let mut data = file_data.clone();
+        data[32..40].copy_from_slice(&0u64.to_le_bytes());
+        data[56..58].copy_from_slice(&3u16.to_le_bytes());
+        let err = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(data))
+            .expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // e_shoff = 16, pointing into the middle of the ehdr
+        let mut data = file_data;
+        data[40..48].copy_from_slice(&16u64.to_le_bytes());
+        let err = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(data))
+            .expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(16)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn header_tables_with_padded_entsize() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+
+        // Copy both tables to the end of the file with 8 bytes of padding after each entry
+        let mut data = file_data.clone();
+        let phoff = data.len();
+        for chunk in file_data[64..64 + 8 * 56].chunks(56) {
+            data.extend_from_slice(chunk);
+            data.extend_from_slice(&[0xff; 8]);
+        }
+        let shoff = data.len();
+        for chunk in file_data[4800..4800 + 31 * 64].chunks(64) {
+            data.extend_from_slice(chunk);
+            data.extend_from_slice(&[0xff; 8]);
+        }
+        data[32..40].copy_from_slice(&(phoff as u64).to_le_bytes());
+        data[40..48].copy_from_slice(&(shoff as u64).to_le_bytes());
+        data[54..56].copy_from_slice(&64u16.to_le_bytes());
+        data[58..60].copy_from_slice(&72u16.to_le_bytes());
+
+        let padded = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&data))
+            .expect("Open padded");
+        assert_eq!(padded.segments(), file.segments());
+        assert_eq!(padded.section_headers(), file.section_headers());
+
+        // An entsize smaller than a section header can't be parsed
+        data[58..60].copy_from_slice(&60u16.to_le_bytes());
+        let err = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&data))
+            .expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadEntsize((60, 64))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn read_sections_parallel_matches_sequential() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(&path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        // Request every section (in reverse, with a repeat) to check that ordering is preserved
+        let mut indices: Vec<usize> = (0..file.section_headers().len()).rev().collect();
+        indices.push(1);
+        let datas = ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel(
+            &path,
+            &indices,
+            &OpenOptions::new(),
+        )
+        .expect("Failed to read sections");
+        assert_eq!(datas.len(), indices.len());
+
+        for (index, data) in indices.iter().zip(datas.iter()) {
+            let shdr = file.section_headers()[*index];
+            let (expected, chdr) = file.section_data(&shdr).expect("Failed to read section");
+            assert!(chdr.is_none());
+            assert_eq!(data.as_slice(), expected);
+        }
+    }
+
+    #[cfg(all(feature = "parallel", feature = "zlib"))]
+    #[test]
+    fn read_sections_parallel_decompressed() {
+        let path = std::path::PathBuf::from("sample-objects/zlib.x86_64.o");
+        let io = std::fs::File::open(&path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        // .debug_info and .debug_abbrev are compressed, .text isn't
+        let indices = [7, 1, 9];
+        let datas = ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel_decompressed(
+            &path,
+            &indices,
+            &OpenOptions::new(),
+        )
+        .expect("Failed to read sections");
+
+        for (index, data) in indices.iter().zip(datas.iter()) {
+            let shdr = file.section_headers()[*index];
+            let expected = file
+                .section_data_decompressed(&shdr)
+                .expect("Failed to read section");
+            assert_eq!(data.as_slice(), &*expected);
+        }
+        assert_eq!(datas[0].len(), 239);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn read_sections_parallel_bad_index() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let err = ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel(
+            path,
+            &[1, 9999],
+            &OpenOptions::new(),
+        )
+        .expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(9999)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn read_sections_parallel_limits() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+
+        // Opening needs 0x9c0 bytes for the headers. .symtab and .strtab (0x678 + 0x21c bytes)
+        // fit in that, but not along with .dynamic (0x1d0 bytes).
+        let mut options = OpenOptions::new();
+        options.max_total_bytes(0x9c0);
+        ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel(&path, &[29, 30], &options)
+            .expect("Failed to read sections");
+        let err = ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel(
+            &path,
+            &[29, 30, 22],
+            &options,
+        )
+        .expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::TotalReadLimitExceeded((0xa64, 0x9c0))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    /// Get zlib.x86_64.o with its .debug_info's ch_size claiming ~4 EiB, and that section's index
+    #[cfg(feature = "zlib")]
+    fn zlib_with_huge_ch_size() -> (Vec<u8>, usize) {
+        let path = std::path::PathBuf::from("sample-objects/zlib.x86_64.o");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let io = std::io::Cursor::new(file_data.as_slice());
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        let shdr: SectionHeader = *file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+        let index = file
+            .section_headers()
+            .iter()
+            .position(|candidate| *candidate == shdr)
+            .unwrap();
+
+        let ch_size_offset = shdr.sh_offset as usize + 8;
+        file_data[ch_size_offset..ch_size_offset + 8]
+            .copy_from_slice(&0x3fff_ffff_ffff_ffefu64.to_le_bytes());
+        (file_data, index)
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn section_data_decompressed_huge_ch_size_errors() {
+        let (file_data, index) = zlib_with_huge_ch_size();
+        let mut file = OpenOptions::new()
+            .max_total_bytes(1 << 20)
+            .open_stream::<AnyEndian, _>(std::io::Cursor::new(file_data))
+            .expect("Open test1");
+        let shdr = file.section_headers()[index];
+        let err = file
+            .section_data_decompressed(&shdr)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::TotalReadLimitExceeded((total, 0x100000)) if total > 1 << 61),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[cfg(all(feature = "parallel", feature = "zlib"))]
+    #[test]
+    fn read_sections_parallel_decompressed_huge_ch_size_errors() {
+        let (file_data, index) = zlib_with_huge_ch_size();
+        let path =
+            std::env::temp_dir().join(format!("elf-parallel-huge-ch-size-{}", std::process::id()));
+        std::fs::write(&path, file_data).expect("Could not write file.");
+        let result = ElfStream::<AnyEndian, std::fs::File>::read_sections_parallel_decompressed(
+            &path,
+            &[index],
+            OpenOptions::new().max_read_size(1 << 20),
+        );
+        std::fs::remove_file(&path).expect("should remove file");
+        let err = result.expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::ReadSizeLimitExceeded((size, 0x100000)) if size > 1 << 61),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
     #[test]
     fn section_headers_with_strtab() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -790,6 +1827,23 @@ mod interface_tests {
         assert_eq!(shdr.sh_type, abi::SHT_STRTAB);
     }
 
+    #[test]
+    fn phnum_in_missing_shdr0_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut data = std::fs::read(path).expect("Could not read file.");
+        // e_phnum says to look in shdr[0], but there's no section header table to look in
+        data[0x38..0x3A].copy_from_slice(&abi::PN_XNUM.to_le_bytes());
+        data[0x28..0x30].copy_from_slice(&0u64.to_le_bytes());
+        data[0x3C..0x3E].copy_from_slice(&0u16.to_le_bytes());
+
+        let io = std::io::Cursor::new(data);
+        let err = ElfStream::<AnyEndian, _>::open_stream(io).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
     #[test]
     fn section_header_by_name() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -810,6 +1864,30 @@ mod interface_tests {
         assert_eq!(shdr, None);
     }
 
+    #[test]
+    fn section_name() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+
+        let shdr = file.section_headers()[3];
+        assert_eq!(
+            file.section_name(&shdr).expect("name should be parseable"),
+            Some(".note.gnu.build-id")
+        );
+
+        // A corrupted e_shstrndx is an error rather than a panic
+        file_data[62..64].copy_from_slice(&99u16.to_le_bytes());
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+        let err = file.section_name(&shdr).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::BadOffset(99)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
     #[test]
     fn section_data_for_nobits() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -840,6 +1918,182 @@ mod interface_tests {
         assert_eq!(data, [0, 0, 2, 0, 2, 0, 0, 0]);
     }
 
+    #[test]
+    fn section_to_segment_mapping() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        // Matches readelf -l's "Section to Segment mapping"
+        let phdrs = file.segments().clone();
+        let mapping: Vec<Vec<usize>> = phdrs
+            .iter()
+            .map(|phdr| {
+                file.section_headers()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, shdr)| phdr.contains_section(shdr))
+                    .map(|(i, _)| i)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            mapping,
+            vec![
+                vec![],
+                vec![1],
+                (1..=18).collect(),
+                (19..=26).collect(),
+                vec![22],
+                vec![2, 3],
+                vec![17],
+                vec![],
+            ]
+        );
+        assert_eq!(file.sections_in_segment(&phdrs[5]).count(), 2);
+
+        let bss = *file
+            .section_header_by_name(".bss")
+            .expect("section table should be parseable")
+            .expect("file should have a .bss");
+        assert_eq!(file.segment_for_section(&bss), Some(&phdrs[3]));
+        let symtab = *file.section_header_by_type(abi::SHT_SYMTAB).unwrap();
+        assert_eq!(file.segment_for_section(&symtab), None);
+    }
+
+    #[test]
+    fn segment_data() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        // The second PT_LOAD holds .data followed by .bss
+        let phdr = file.segments()[3];
+        assert_eq!(phdr.p_type, abi::PT_LOAD);
+        let data = file
+            .segment_data(&phdr)
+            .expect("Failed to get segment data");
+        assert_eq!(data.len() as u64, phdr.p_filesz);
+
+        let image = file
+            .segment_data_zero_extended(&phdr)
+            .expect("Failed to get segment data");
+        assert!(matches!(image, std::borrow::Cow::Owned(_)));
+        assert_eq!(image.len() as u64, phdr.p_memsz);
+        assert!(image[phdr.p_filesz as usize..].iter().all(|b| *b == 0));
+
+        let phdr = file.segments()[0];
+        let image = file
+            .segment_data_zero_extended(&phdr)
+            .expect("Failed to get segment data");
+        assert!(matches!(image, std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn section_headers_by_type() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let notes: Vec<u64> = file
+            .section_headers_by_type(abi::SHT_NOTE)
+            .map(|shdr| shdr.sh_addr)
+            .collect();
+        assert_eq!(notes, [0x40021c, 0x40023c]);
+        assert_eq!(
+            file.section_header_by_type(abi::SHT_SYMTAB)
+                .map(|shdr| shdr.sh_link),
+            Some(30)
+        );
+        assert_eq!(file.section_header_by_type(abi::SHT_GROUP), None);
+    }
+
+    struct CountingReader<R> {
+        inner: R,
+        reads: usize,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reads += 1;
+            self.inner.read(buf)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn section_data_is_cached() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = CountingReader {
+            inner: std::fs::File::open(path).expect("Could not open file."),
+            reads: 0,
+        };
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        let shdr = file.section_headers()[7];
+
+        let opened = file.reader.reader.reads;
+        file.section_data(&shdr)
+            .expect("Failed to get section data");
+        let first = file.reader.reader.reads;
+        assert!(first > opened);
+
+        file.section_data(&shdr)
+            .expect("Failed to get section data");
+        assert_eq!(file.reader.reader.reads, first);
+
+        file.clear_cache();
+        let (data, _) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+        assert_eq!(data, [0, 0, 2, 0, 2, 0, 0, 0]);
+        assert!(file.reader.reader.reads > first);
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn section_data_decompressed() {
+        let path = std::path::PathBuf::from("sample-objects/zlib.x86_64.o");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr: SectionHeader = *file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+        assert_ne!(shdr.sh_flags & abi::SHF_COMPRESSED as u64, 0);
+        let data = file
+            .section_data_decompressed(&shdr)
+            .expect("Failed to decompress section data");
+        assert_eq!(data.len(), 0xef);
+        // DWARF 5 compilation unit header: unit_length, version
+        assert_eq!(&data[..6], [0xeb, 0, 0, 0, 5, 0]);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn section_data_decompressed_zstd() {
+        let path = std::path::PathBuf::from("sample-objects/zstd.x86_64.o");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr: SectionHeader = *file
+            .section_header_by_name(".debug_info")
+            .expect("section table should be parseable")
+            .expect("file should have .debug_info");
+        assert_ne!(shdr.sh_flags & abi::SHF_COMPRESSED as u64, 0);
+        let data = file
+            .section_data_decompressed(&shdr)
+            .expect("Failed to decompress section data");
+        assert_eq!(data.len(), 0xef);
+        // DWARF 5 compilation unit header: unit_length, version
+        assert_eq!(&data[..6], [0xeb, 0, 0, 0, 5, 0]);
+    }
+
     #[test]
     fn section_data_as_strtab() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1028,6 +2282,39 @@ mod interface_tests {
         assert!(relas.next().is_none());
     }
 
+    #[test]
+    fn section_data_as_relrs() {
+        let path = std::path::PathBuf::from("sample-objects/relr.x86_64.so");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr = file.section_headers()[6];
+        let relrs: Vec<u64> = file
+            .section_data_as_relrs(&shdr)
+            .expect("Failed to read relrs section")
+            .collect();
+        let expected: Vec<u64> = [0x3e30, 0x3e38, 0x4000, 0x4020]
+            .into_iter()
+            .chain((0x4040..=0x4088).step_by(8))
+            .collect();
+        assert_eq!(relrs, expected);
+    }
+
+    #[test]
+    fn section_data_as_group() {
+        let path = std::path::PathBuf::from("sample-objects/group.x86_64.o");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr = file.section_headers()[3];
+        let group = file
+            .section_data_as_group(&shdr)
+            .expect("Failed to read group section");
+        assert!(group.is_comdat());
+        assert_eq!(group.signature_symbol, 10);
+        assert_eq!(group.members.iter().collect::<Vec<u32>>(), [10]);
+    }
+
     #[test]
     fn section_data_as_notes() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1080,6 +2367,52 @@ mod interface_tests {
         assert!(notes.next().is_none());
     }
 
+    #[test]
+    fn interpreter() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        assert_eq!(
+            file.interpreter().expect("Failed to parse interp"),
+            Some("/lib64/ld-linux-x86-64.so.2")
+        );
+
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        assert_eq!(file.interpreter().expect("Failed to parse interp"), None);
+    }
+
+    #[test]
+    fn debuglink() {
+        let path = std::path::PathBuf::from("sample-objects/debuglink.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let link = file
+            .debuglink()
+            .expect("Failed to parse .gnu_debuglink")
+            .expect("Failed to find .gnu_debuglink");
+        assert_eq!(link.file_name, b"basic.x86_64.debug");
+        assert_eq!(link.crc, 0x42FD95F6);
+
+        let debug =
+            std::fs::File::open("sample-objects/basic.x86_64.debug").expect("Could not open file.");
+        assert!(link
+            .verify_reader(debug)
+            .expect("Failed to read debug file"));
+        let not_debug =
+            std::fs::File::open("sample-objects/basic.x86_64").expect("Could not open file.");
+        assert!(!link
+            .verify_reader(not_debug)
+            .expect("Failed to read debug file"));
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        assert!(file.debuglink().expect("Failed to parse").is_none());
+    }
+
     #[test]
     fn symbol_version_table() {
         let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
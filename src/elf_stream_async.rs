@@ -0,0 +1,526 @@
+use core::ops::Range;
+use std::collections::HashMap;
+use std::io::SeekFrom;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::abi;
+use crate::compression::CompressionHeader;
+use crate::elf_stream::{HeaderTable, HeaderTableKind};
+use crate::endian::EndianParse;
+use crate::file::{parse_ident, Class, FileHeader};
+use crate::note::NoteIterator;
+use crate::options::OpenOptions;
+use crate::parse::{ParseAt, ParseError};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolTable};
+
+/// This type encapsulates the async stream-oriented interface for parsing ELF objects from
+/// a tokio `AsyncRead + AsyncSeek`, such as a `tokio::fs::File`.
+///
+/// It works like [ElfStream](crate::ElfStream): the file header and the section and program
+/// header tables are parsed when opening the stream, and all other data is read lazily and
+/// cached on demand. The methods that read from the stream are `async`, so a server parsing
+/// uploaded binaries doesn't need to block a thread per parse.
+///
+/// It only supports a subset of [ElfStream](crate::ElfStream)'s accessors: the header tables,
+/// looking up sections by name or type, raw section and segment data, string tables, the
+/// symbol tables, and section notes. Other tables, such as the dynamic table, relocations,
+/// and symbol versions, can be parsed from [AsyncElfStream::section_data] with their
+/// parsing types (e.g. [DynamicTable::new](crate::dynamic::DynamicTable::new) or
+/// [RelaIterator::new](crate::relocation::RelaIterator::new)).
+///
+/// This type is only available with the `tokio` cargo feature.
+///
+/// ```
+/// use elf::AsyncElfStream;
+/// use elf::endian::AnyEndian;
+/// use tokio::io::{AsyncRead, AsyncSeek};
+///
+/// async fn print_build_id<S>(io: S) -> Result<(), elf::ParseError>
+/// where
+///     S: AsyncRead + AsyncSeek + Unpin,
+/// {
+///     let mut file = AsyncElfStream::<AnyEndian, _>::open_stream(io).await?;
+///
+///     let shdr = *file
+///         .section_header_by_name(".note.gnu.build-id")
+///         .await?
+///         .expect("file should have a build id");
+///     let notes: Vec<_> = file.section_data_as_notes(&shdr).await?.collect();
+///     println!("{notes:?}");
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsyncElfStream<E: EndianParse, S: AsyncRead + AsyncSeek + Unpin> {
+    pub ehdr: FileHeader<E>,
+    shdrs: Vec<SectionHeader>,
+    phdrs: Vec<ProgramHeader>,
+    reader: AsyncCachingReader<S>,
+}
+
+/// Read the stream bytes backing the section or program headers table and parse them all into
+/// their Rust native type.
+///
+/// See the sync version used by [ElfStream](crate::ElfStream) for details. Both share
+/// [HeaderTable] for everything but the reads.
+async fn parse_header_table<E: EndianParse, S: AsyncRead + AsyncSeek + Unpin, P: ParseAt>(
+    kind: HeaderTableKind,
+    ehdr: &FileHeader<E>,
+    reader: &mut AsyncCachingReader<S>,
+) -> Result<Vec<P>, ParseError> {
+    let mut table = match HeaderTable::new(kind, ehdr)? {
+        Some(table) => table,
+        None => {
+            return Ok(Vec::default());
+        }
+    };
+    if let Some(range) = table.shdr0_range() {
+        table.set_shdr0(ehdr, reader.read_bytes(range.start, range.end).await?)?;
+    }
+    let range = table.table_range(ehdr, &reader.options)?;
+    let buf = reader.read_bytes(range.start, range.end).await?;
+    Ok(table.parse(ehdr, buf))
+}
+
+impl<E: EndianParse, S: AsyncRead + AsyncSeek + Unpin> AsyncElfStream<E, S> {
+    /// Do a minimal amount of parsing work to open an [AsyncElfStream] handle from an
+    /// AsyncRead+AsyncSeek containing an ELF object.
+    ///
+    /// This parses the ELF [FileHeader], [SectionHeader] table, and [ProgramHeader] (segments) table.
+    /// All other file data (section data, segment data) is left unread and unparsed.
+    ///
+    /// This opens the stream with the default [OpenOptions]. See [OpenOptions::open_stream_async]
+    /// for opening it with other options.
+    pub async fn open_stream(reader: S) -> Result<AsyncElfStream<E, S>, ParseError> {
+        Self::open_stream_with_options(reader, &OpenOptions::new()).await
+    }
+
+    pub(crate) async fn open_stream_with_options(
+        reader: S,
+        options: &OpenOptions,
+    ) -> Result<AsyncElfStream<E, S>, ParseError> {
+        let mut cr = AsyncCachingReader::new(reader, options.clone()).await?;
+        let ident_buf = cr.read_bytes(0, abi::EI_NIDENT).await?;
+        let ident = parse_ident(ident_buf)?;
+
+        let tail_start = abi::EI_NIDENT;
+        let tail_end = match ident.1 {
+            Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
+            Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
+        };
+        let tail_buf = cr.read_bytes(tail_start, tail_end).await?;
+
+        let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
+
+        let shdrs = parse_header_table(HeaderTableKind::Sections, &ehdr, &mut cr).await?;
+        let phdrs = parse_header_table(HeaderTableKind::Segments, &ehdr, &mut cr).await?;
+
+        // We parsed out the ehdr and shdrs into their own allocated containers, so there's no need to keep
+        // around their backing data anymore.
+        cr.clear_cache();
+
+        Ok(AsyncElfStream {
+            ehdr,
+            shdrs,
+            phdrs,
+            reader: cr,
+        })
+    }
+
+    /// Get the parsed program headers table
+    pub fn segments(&self) -> &Vec<ProgramHeader> {
+        &self.phdrs
+    }
+
+    /// Get the parsed section headers table
+    pub fn section_headers(&self) -> &Vec<SectionHeader> {
+        &self.shdrs
+    }
+
+    /// Get the parsed section headers table alongside the section header string table.
+    ///
+    /// See [ElfStream::section_headers_with_strtab](crate::ElfStream::section_headers_with_strtab).
+    pub async fn section_headers_with_strtab(
+        &mut self,
+    ) -> Result<(&Vec<SectionHeader>, Option<StringTable<'_>>), ParseError> {
+        // It's Ok to have no section headers or no string table
+        if self.shdrs.is_empty() || self.ehdr.e_shstrndx == abi::SHN_UNDEF {
+            return Ok((&self.shdrs, None));
+        }
+
+        // If the section name string table section index is greater than or
+        // equal to SHN_LORESERVE (0xff00), e_shstrndx has the value SHN_XINDEX
+        // (0xffff) and the actual index of the section name string table section
+        // is contained in the sh_link field of the section header at index 0.
+        let mut shstrndx = self.ehdr.e_shstrndx as usize;
+        if self.ehdr.e_shstrndx == abi::SHN_XINDEX {
+            shstrndx = self.shdrs[0].sh_link as usize;
+        }
+
+        let strtab = self
+            .shdrs
+            .get(shstrndx)
+            .ok_or(ParseError::BadOffset(shstrndx as u64))?;
+        let (strtab_start, strtab_end) = strtab.get_data_range()?;
+        let strtab_buf = self.reader.read_bytes(strtab_start, strtab_end).await?;
+        Ok((&self.shdrs, Some(StringTable::new(strtab_buf))))
+    }
+
+    /// Find the parsed section header with the given name (if any).
+    ///
+    /// Returns a ParseError if the section headers string table can't be read
+    pub async fn section_header_by_name(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<&SectionHeader>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab().await? {
+            (shdr, Some(strtab)) => (shdr, strtab),
+            // We can't look up shdrs by name if there's no strtab.
+            _ => {
+                return Ok(None);
+            }
+        };
+
+        Ok(shdrs.iter().find(
+            |shdr| matches!(strtab.get(shdr.sh_name as usize), Ok(sh_name) if sh_name == name),
+        ))
+    }
+
+    /// Get the first [SectionHeader] with the given [sh_type](SectionHeader#structfield.sh_type) (if any).
+    pub fn section_header_by_type(&self, sh_type: u32) -> Option<&SectionHeader> {
+        self.shdrs.iter().find(|shdr| shdr.sh_type == sh_type)
+    }
+
+    /// Read the section data for the given [SectionHeader](SectionHeader).
+    /// Returns both the secion data and an optional CompressionHeader.
+    ///
+    /// See [ElfStream::section_data](crate::ElfStream::section_data). SHT_NOBITS sections
+    /// yield an empty slice.
+    pub async fn section_data(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(&[u8], Option<CompressionHeader>), ParseError> {
+        if shdr.sh_type == abi::SHT_NOBITS {
+            return Ok((&[], None));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end).await?;
+
+        if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
+            Ok((buf, None))
+        } else {
+            let mut offset = 0;
+            let chdr = CompressionHeader::parse_at(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                &mut offset,
+                buf,
+            )?;
+            let compressed_buf = buf.get(offset..).ok_or(ParseError::SliceReadError((
+                offset,
+                shdr.sh_size.try_into()?,
+            )))?;
+            Ok((compressed_buf, Some(chdr)))
+        }
+    }
+
+    /// Drop all the section and segment data read from the stream so far.
+    ///
+    /// See [ElfStream::clear_cache](crate::ElfStream::clear_cache).
+    pub fn clear_cache(&mut self) {
+        self.reader.clear_cache();
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [StringTable](StringTable).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_STRTAB](abi::SHT_STRTAB).
+    pub async fn section_data_as_strtab(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<StringTable<'_>, ParseError> {
+        if shdr.sh_type != abi::SHT_STRTAB {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_STRTAB,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end).await?;
+        Ok(StringTable::new(buf))
+    }
+
+    async fn get_symbol_table_of_type(
+        &mut self,
+        symtab_type: u32,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        // Get the symtab header for the symtab. The gABI states there can be zero or one per ELF file.
+        let shdr = match self.section_header_by_type(symtab_type) {
+            Some(shdr) => *shdr,
+            None => return Ok(None),
+        };
+
+        // Load the section bytes for both the symtab and its strtab
+        // (we want immutable references to both the symtab and its strtab concurrently)
+        let (symtab_start, symtab_end) = shdr.get_data_range()?;
+        self.reader.load_bytes(symtab_start..symtab_end).await?;
+
+        let strtab = self
+            .shdrs
+            .get(shdr.sh_link as usize)
+            .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
+        let (strtab_start, strtab_end) = strtab.get_data_range()?;
+        self.reader.load_bytes(strtab_start..strtab_end).await?;
+
+        // Validate entsize before trying to read the table so that we can error early for corrupted files
+        Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
+        let symtab = SymbolTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            self.reader.get_bytes(symtab_start..symtab_end),
+        );
+        let strtab = StringTable::new(self.reader.get_bytes(strtab_start..strtab_end));
+        Ok(Some((symtab, strtab)))
+    }
+
+    /// Get the symbol table (section of type SHT_SYMTAB) and its associated string table.
+    ///
+    /// The gABI specifies that ELF object files may have zero or one sections of type SHT_SYMTAB.
+    pub async fn symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        self.get_symbol_table_of_type(abi::SHT_SYMTAB).await
+    }
+
+    /// Get the dynamic symbol table (section of type SHT_DYNSYM) and its associated string table.
+    ///
+    /// The gABI specifies that ELF object files may have zero or one sections of type SHT_DYNSYM.
+    pub async fn dynamic_symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        self.get_symbol_table_of_type(abi::SHT_DYNSYM).await
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [NoteIterator](NoteIterator).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_NOTE](abi::SHT_NOTE).
+    pub async fn section_data_as_notes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_NOTE {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_NOTE,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end).await?;
+        Ok(NoteIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_addralign as usize,
+            buf,
+        ))
+    }
+
+    /// Read the segment's file data for the given [ProgramHeader].
+    ///
+    /// This is the segment's data as found in the file, i.e. its first
+    /// [p_filesz](ProgramHeader#structfield.p_filesz) bytes.
+    pub async fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError> {
+        let (start, end) = phdr.get_file_data_range()?;
+        self.reader.read_bytes(start, end).await
+    }
+}
+
+#[derive(Debug)]
+struct AsyncCachingReader<R: AsyncRead + AsyncSeek + Unpin> {
+    reader: R,
+    stream_len: u64,
+    options: OpenOptions,
+    bufs: HashMap<(usize, usize), Box<[u8]>>,
+    /// The total size of the buffers in `bufs`
+    cached_bytes: usize,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncCachingReader<R> {
+    async fn new(mut reader: R, options: OpenOptions) -> Result<Self, ParseError> {
+        // Cache the size of the stream so that we can err (rather than OOM) on invalid
+        // huge read requests.
+        let stream_len = reader.seek(SeekFrom::End(0)).await?;
+        Ok(AsyncCachingReader {
+            reader,
+            stream_len,
+            options,
+            bufs: HashMap::<(usize, usize), Box<[u8]>>::default(),
+            cached_bytes: 0,
+        })
+    }
+
+    async fn read_bytes(&mut self, start: usize, end: usize) -> Result<&[u8], ParseError> {
+        self.load_bytes(start..end).await?;
+        Ok(self.get_bytes(start..end))
+    }
+
+    fn get_bytes(&self, range: Range<usize>) -> &[u8] {
+        // It's a programmer error to call get_bytes without first calling load_bytes, so
+        // we want to panic here.
+        self.bufs
+            .get(&(range.start, range.end))
+            .expect("load_bytes must be called before get_bytes for every range")
+    }
+
+    async fn load_bytes(&mut self, range: Range<usize>) -> Result<(), ParseError> {
+        if self.bufs.contains_key(&(range.start, range.end)) {
+            return Ok(());
+        }
+
+        // Verify that the read range doesn't go past the end of the stream (corrupted files)
+        let end = range.end as u64;
+        if end > self.stream_len {
+            return Err(ParseError::BadOffset(end));
+        }
+
+        self.options.check_read(range.len(), self.cached_bytes)?;
+
+        self.reader
+            .seek(SeekFrom::Start(range.start as u64))
+            .await?;
+        let mut bytes = vec![0; range.len()].into_boxed_slice();
+        self.reader.read_exact(&mut bytes).await?;
+        self.cached_bytes += bytes.len();
+        self.bufs.insert((range.start, range.end), bytes);
+        Ok(())
+    }
+
+    fn clear_cache(&mut self) {
+        self.bufs.clear();
+        self.cached_bytes = 0;
+    }
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::note::{Note, NoteGnuBuildId};
+    use crate::ElfStream;
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    /// Run a future to completion on the current thread. The readers used in these tests never
+    /// return Pending, so this doesn't need a real executor.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct NoopWaker;
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn basic() -> Vec<u8> {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    #[test]
+    fn open_stream_async_matches_sync() {
+        let file_data = basic();
+        let sync = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+        block_on(async {
+            let file =
+                AsyncElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+                    .await
+                    .expect("Open test1");
+            assert_eq!(file.ehdr, sync.ehdr);
+            assert_eq!(file.section_headers(), sync.section_headers());
+            assert_eq!(file.segments(), sync.segments());
+        });
+    }
+
+    #[test]
+    fn section_data_async() {
+        let file_data = basic();
+        block_on(async {
+            let mut file =
+                AsyncElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+                    .await
+                    .expect("Open test1");
+
+            let shdr = *file
+                .section_header_by_name(".note.gnu.build-id")
+                .await
+                .expect("section table should be parseable")
+                .expect("file should have a build id");
+            let notes: Vec<Note<'_>> = file
+                .section_data_as_notes(&shdr)
+                .await
+                .expect("Should be able to get note section data")
+                .collect();
+            assert_eq!(
+                notes,
+                [Note::GnuBuildId(NoteGnuBuildId(&[
+                    0x77, 0x41, 0x9f, 0x0d, 0xa5, 0x10, 0x83, 0x0c, 0x57, 0xa7, 0xc8, 0xcc, 0xb0,
+                    0xee, 0x85, 0x5f, 0xee, 0xd3, 0x76, 0xa3,
+                ]))]
+            );
+
+            let (symtab, strtab) = file
+                .dynamic_symbol_table()
+                .await
+                .expect("Failed to read symbol table")
+                .expect("Failed to find symbol table");
+            let symbol = symtab.get(2).expect("Failed to get symbol");
+            assert_eq!(
+                strtab
+                    .get(symbol.st_name as usize)
+                    .expect("Failed to get name"),
+                "__libc_start_main"
+            );
+
+            let phdr = file.segments()[1];
+            let interp = file.segment_data(&phdr).await.expect("should read segment");
+            assert_eq!(interp, b"/lib64/ld-linux-x86-64.so.2\0");
+        });
+    }
+
+    #[test]
+    fn open_stream_async_with_max_read_size() {
+        let file_data = basic();
+        let result = block_on(
+            OpenOptions::new()
+                .max_read_size(64)
+                .open_stream_async::<AnyEndian, _>(std::io::Cursor::new(&file_data)),
+        );
+        let err = result.expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::ReadSizeLimitExceeded((1984, 64))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -101,6 +101,7 @@ pub trait EndianParse: Clone + Copy + Default + PartialEq + Eq {
 /// This is useful for scenarios where a single compiled binary wants to dynamically
 /// interpret ELF files of any byte order.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnyEndian {
     /// Used for a little-endian ELF structures that have been parsed with AnyEndian
     #[default]
@@ -114,6 +115,7 @@ pub enum AnyEndian {
 /// little-endian ELF files and doesn't want the performance penalty of evaluating a match
 /// each time it parses an integer.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LittleEndian;
 
 /// A zero-sized type that always parses integers as if they're in big-endian order.
@@ -121,6 +123,7 @@ pub struct LittleEndian;
 /// big-endian ELF files and doesn't want the performance penalty of evaluating a match
 /// each time it parses an integer.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BigEndian;
 
 /// A zero-sized type that always parses integers as if they're in the compilation target's native-endian order.
@@ -1,15 +1,316 @@
 //! Parsing the ELF File Header
 use crate::abi;
+use crate::arm::ArmFlags;
+use crate::avr::AvrFlags;
 use crate::endian::EndianParse;
-use crate::parse::ParseError;
+use crate::loongarch::LoongArchFlags;
+use crate::mips::MipsFlags;
+use crate::msp430::Msp430Flags;
+use crate::parse::{ParseAt, ParseError};
+use crate::ppc64::Ppc64Abi;
+use crate::riscv::RiscvFlags;
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::xtensa::XtensaFlags;
 
 /// Represents the ELF file word size (32-bit vs 64-bit)
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Class {
     ELF32,
     ELF64,
 }
 
+impl TryFrom<u8> for Class {
+    type Error = ParseError;
+
+    /// Convert an `e_ident[EI_CLASS]` value into a [Class]
+    fn try_from(ei_class: u8) -> Result<Self, Self::Error> {
+        match ei_class {
+            abi::ELFCLASS32 => Ok(Class::ELF32),
+            abi::ELFCLASS64 => Ok(Class::ELF64),
+            _ => Err(ParseError::UnsupportedElfClass(ei_class)),
+        }
+    }
+}
+
+impl From<Class> for u8 {
+    fn from(class: Class) -> Self {
+        match class {
+            Class::ELF32 => abi::ELFCLASS32,
+            Class::ELF64 => abi::ELFCLASS64,
+        }
+    }
+}
+
+/// The ELF file type from [FileHeader::e_type](FileHeader#structfield.e_type)
+///
+/// Values without a variant of their own (including the OS and processor-specific ranges)
+/// are kept as `Unknown`, so converting to and from the raw `u16` is lossless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectFileType {
+    /// [ET_NONE](abi::ET_NONE)
+    None,
+    /// [ET_REL](abi::ET_REL)
+    Relocatable,
+    /// [ET_EXEC](abi::ET_EXEC)
+    Executable,
+    /// [ET_DYN](abi::ET_DYN)
+    SharedObject,
+    /// [ET_CORE](abi::ET_CORE)
+    Core,
+    Unknown(u16),
+}
+
+impl From<u16> for ObjectFileType {
+    fn from(e_type: u16) -> Self {
+        match e_type {
+            abi::ET_NONE => ObjectFileType::None,
+            abi::ET_REL => ObjectFileType::Relocatable,
+            abi::ET_EXEC => ObjectFileType::Executable,
+            abi::ET_DYN => ObjectFileType::SharedObject,
+            abi::ET_CORE => ObjectFileType::Core,
+            _ => ObjectFileType::Unknown(e_type),
+        }
+    }
+}
+
+impl From<ObjectFileType> for u16 {
+    fn from(file_type: ObjectFileType) -> Self {
+        match file_type {
+            ObjectFileType::None => abi::ET_NONE,
+            ObjectFileType::Relocatable => abi::ET_REL,
+            ObjectFileType::Executable => abi::ET_EXEC,
+            ObjectFileType::SharedObject => abi::ET_DYN,
+            ObjectFileType::Core => abi::ET_CORE,
+            ObjectFileType::Unknown(e_type) => e_type,
+        }
+    }
+}
+
+/// The target architecture from [FileHeader::e_machine](FileHeader#structfield.e_machine)
+///
+/// Only the commonly encountered machines have their own variants. All other values are kept
+/// as `Unknown`, so converting to and from the raw `u16` is lossless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Architecture {
+    /// [EM_NONE](abi::EM_NONE)
+    None,
+    /// [EM_SPARC](abi::EM_SPARC)
+    Sparc,
+    /// [EM_386](abi::EM_386)
+    X86,
+    /// [EM_68K](abi::EM_68K)
+    M68k,
+    /// [EM_MIPS](abi::EM_MIPS)
+    Mips,
+    /// [EM_PPC](abi::EM_PPC)
+    PowerPc,
+    /// [EM_PPC64](abi::EM_PPC64)
+    PowerPc64,
+    /// [EM_S390](abi::EM_S390)
+    S390,
+    /// [EM_ARM](abi::EM_ARM)
+    Arm,
+    /// [EM_SPARCV9](abi::EM_SPARCV9)
+    SparcV9,
+    /// [EM_X86_64](abi::EM_X86_64)
+    X86_64,
+    /// [EM_AVR](abi::EM_AVR)
+    Avr,
+    /// [EM_XTENSA](abi::EM_XTENSA)
+    Xtensa,
+    /// [EM_MSP430](abi::EM_MSP430)
+    Msp430,
+    /// [EM_AARCH64](abi::EM_AARCH64)
+    Aarch64,
+    /// [EM_RISCV](abi::EM_RISCV)
+    RiscV,
+    /// [EM_LOONGARCH](abi::EM_LOONGARCH)
+    LoongArch,
+    Unknown(u16),
+}
+
+impl From<u16> for Architecture {
+    fn from(e_machine: u16) -> Self {
+        match e_machine {
+            abi::EM_NONE => Architecture::None,
+            abi::EM_SPARC => Architecture::Sparc,
+            abi::EM_386 => Architecture::X86,
+            abi::EM_68K => Architecture::M68k,
+            abi::EM_MIPS => Architecture::Mips,
+            abi::EM_PPC => Architecture::PowerPc,
+            abi::EM_PPC64 => Architecture::PowerPc64,
+            abi::EM_S390 => Architecture::S390,
+            abi::EM_ARM => Architecture::Arm,
+            abi::EM_SPARCV9 => Architecture::SparcV9,
+            abi::EM_X86_64 => Architecture::X86_64,
+            abi::EM_AVR => Architecture::Avr,
+            abi::EM_XTENSA => Architecture::Xtensa,
+            abi::EM_MSP430 => Architecture::Msp430,
+            abi::EM_AARCH64 => Architecture::Aarch64,
+            abi::EM_RISCV => Architecture::RiscV,
+            abi::EM_LOONGARCH => Architecture::LoongArch,
+            _ => Architecture::Unknown(e_machine),
+        }
+    }
+}
+
+impl From<Architecture> for u16 {
+    fn from(arch: Architecture) -> Self {
+        match arch {
+            Architecture::None => abi::EM_NONE,
+            Architecture::Sparc => abi::EM_SPARC,
+            Architecture::X86 => abi::EM_386,
+            Architecture::M68k => abi::EM_68K,
+            Architecture::Mips => abi::EM_MIPS,
+            Architecture::PowerPc => abi::EM_PPC,
+            Architecture::PowerPc64 => abi::EM_PPC64,
+            Architecture::S390 => abi::EM_S390,
+            Architecture::Arm => abi::EM_ARM,
+            Architecture::SparcV9 => abi::EM_SPARCV9,
+            Architecture::X86_64 => abi::EM_X86_64,
+            Architecture::Avr => abi::EM_AVR,
+            Architecture::Xtensa => abi::EM_XTENSA,
+            Architecture::Msp430 => abi::EM_MSP430,
+            Architecture::Aarch64 => abi::EM_AARCH64,
+            Architecture::RiscV => abi::EM_RISCV,
+            Architecture::LoongArch => abi::EM_LOONGARCH,
+            Architecture::Unknown(e_machine) => e_machine,
+        }
+    }
+}
+
+/// What kind of object a file is, as classified by [ElfBytes::kind](crate::ElfBytes::kind)
+///
+/// Unlike [ObjectFileType], this tells position-independent executables apart from shared
+/// libraries (both are [ET_DYN](abi::ET_DYN)) and static executables from dynamically linked
+/// ones.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FileKind {
+    /// A relocatable object ([ET_REL](abi::ET_REL)), e.g. a `.o` file
+    Relocatable,
+    /// A position-dependent executable ([ET_EXEC](abi::ET_EXEC)) without a program
+    /// interpreter, which the kernel runs directly
+    StaticExecutable,
+    /// A position-dependent executable ([ET_EXEC](abi::ET_EXEC)) which gets loaded by the
+    /// program interpreter (dynamic linker) named by its [PT_INTERP](abi::PT_INTERP) segment
+    DynamicExecutable,
+    /// A position-independent executable ([ET_DYN](abi::ET_DYN)) which gets loaded by its
+    /// program interpreter
+    Pie,
+    /// A position-independent executable ([ET_DYN](abi::ET_DYN)) without a program
+    /// interpreter, which relocates itself (e.g. built with `-static-pie`)
+    StaticPie,
+    /// A shared library ([ET_DYN](abi::ET_DYN))
+    SharedObject,
+    /// A core dump ([ET_CORE](abi::ET_CORE))
+    Core,
+    /// Any other file type, i.e. [ET_NONE](abi::ET_NONE) or an OS or processor-specific one
+    Other(ObjectFileType),
+}
+
+/// The OS/ABI identification from [FileHeader::osabi](FileHeader#structfield.osabi)
+///
+/// The architecture-specific values (e.g. [ELFOSABI_ARM](abi::ELFOSABI_ARM)), values which
+/// aren't assigned yet, and those used by custom toolchains are kept as `Unknown`, so
+/// converting to and from the raw `u8` is lossless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OsAbi {
+    /// [ELFOSABI_NONE](abi::ELFOSABI_NONE)
+    None,
+    /// [ELFOSABI_HPUX](abi::ELFOSABI_HPUX)
+    HpUx,
+    /// [ELFOSABI_NETBSD](abi::ELFOSABI_NETBSD)
+    NetBsd,
+    /// [ELFOSABI_GNU](abi::ELFOSABI_GNU)
+    Gnu,
+    /// [ELFOSABI_SOLARIS](abi::ELFOSABI_SOLARIS)
+    Solaris,
+    /// [ELFOSABI_AIX](abi::ELFOSABI_AIX)
+    Aix,
+    /// [ELFOSABI_IRIX](abi::ELFOSABI_IRIX)
+    Irix,
+    /// [ELFOSABI_FREEBSD](abi::ELFOSABI_FREEBSD)
+    FreeBsd,
+    /// [ELFOSABI_TRU64](abi::ELFOSABI_TRU64)
+    Tru64,
+    /// [ELFOSABI_MODESTO](abi::ELFOSABI_MODESTO)
+    Modesto,
+    /// [ELFOSABI_OPENBSD](abi::ELFOSABI_OPENBSD)
+    OpenBsd,
+    /// [ELFOSABI_OPENVMS](abi::ELFOSABI_OPENVMS)
+    OpenVms,
+    /// [ELFOSABI_NSK](abi::ELFOSABI_NSK)
+    Nsk,
+    /// [ELFOSABI_AROS](abi::ELFOSABI_AROS)
+    Aros,
+    /// [ELFOSABI_FENIXOS](abi::ELFOSABI_FENIXOS)
+    FenixOs,
+    /// [ELFOSABI_CLOUDABI](abi::ELFOSABI_CLOUDABI)
+    CloudAbi,
+    /// [ELFOSABI_OPENVOS](abi::ELFOSABI_OPENVOS)
+    OpenVos,
+    /// [ELFOSABI_STANDALONE](abi::ELFOSABI_STANDALONE)
+    Standalone,
+    Unknown(u8),
+}
+
+impl From<u8> for OsAbi {
+    fn from(osabi: u8) -> Self {
+        match osabi {
+            abi::ELFOSABI_NONE => OsAbi::None,
+            abi::ELFOSABI_HPUX => OsAbi::HpUx,
+            abi::ELFOSABI_NETBSD => OsAbi::NetBsd,
+            abi::ELFOSABI_GNU => OsAbi::Gnu,
+            abi::ELFOSABI_SOLARIS => OsAbi::Solaris,
+            abi::ELFOSABI_AIX => OsAbi::Aix,
+            abi::ELFOSABI_IRIX => OsAbi::Irix,
+            abi::ELFOSABI_FREEBSD => OsAbi::FreeBsd,
+            abi::ELFOSABI_TRU64 => OsAbi::Tru64,
+            abi::ELFOSABI_MODESTO => OsAbi::Modesto,
+            abi::ELFOSABI_OPENBSD => OsAbi::OpenBsd,
+            abi::ELFOSABI_OPENVMS => OsAbi::OpenVms,
+            abi::ELFOSABI_NSK => OsAbi::Nsk,
+            abi::ELFOSABI_AROS => OsAbi::Aros,
+            abi::ELFOSABI_FENIXOS => OsAbi::FenixOs,
+            abi::ELFOSABI_CLOUDABI => OsAbi::CloudAbi,
+            abi::ELFOSABI_OPENVOS => OsAbi::OpenVos,
+            abi::ELFOSABI_STANDALONE => OsAbi::Standalone,
+            _ => OsAbi::Unknown(osabi),
+        }
+    }
+}
+
+impl From<OsAbi> for u8 {
+    fn from(osabi: OsAbi) -> Self {
+        match osabi {
+            OsAbi::None => abi::ELFOSABI_NONE,
+            OsAbi::HpUx => abi::ELFOSABI_HPUX,
+            OsAbi::NetBsd => abi::ELFOSABI_NETBSD,
+            OsAbi::Gnu => abi::ELFOSABI_GNU,
+            OsAbi::Solaris => abi::ELFOSABI_SOLARIS,
+            OsAbi::Aix => abi::ELFOSABI_AIX,
+            OsAbi::Irix => abi::ELFOSABI_IRIX,
+            OsAbi::FreeBsd => abi::ELFOSABI_FREEBSD,
+            OsAbi::Tru64 => abi::ELFOSABI_TRU64,
+            OsAbi::Modesto => abi::ELFOSABI_MODESTO,
+            OsAbi::OpenBsd => abi::ELFOSABI_OPENBSD,
+            OsAbi::OpenVms => abi::ELFOSABI_OPENVMS,
+            OsAbi::Nsk => abi::ELFOSABI_NSK,
+            OsAbi::Aros => abi::ELFOSABI_AROS,
+            OsAbi::FenixOs => abi::ELFOSABI_FENIXOS,
+            OsAbi::CloudAbi => abi::ELFOSABI_CLOUDABI,
+            OsAbi::OpenVos => abi::ELFOSABI_OPENVOS,
+            OsAbi::Standalone => abi::ELFOSABI_STANDALONE,
+            OsAbi::Unknown(osabi) => osabi,
+        }
+    }
+}
+
 /// C-style 32-bit ELF File Header definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -61,6 +362,7 @@ pub struct Elf64_Ehdr {
 /// the width of certain fields (32-bit vs 64-bit), the data endianness, the
 /// file type, and more.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileHeader<E: EndianParse> {
     /// 32-bit vs 64-bit
     pub class: Class,
@@ -72,6 +374,8 @@ pub struct FileHeader<E: EndianParse> {
     pub osabi: u8,
     /// Version of the OS ABI
     pub abiversion: u8,
+    /// The reserved `e_ident[EI_PAD..EI_NIDENT]` bytes, which should all be zero
+    pub ident_pad: [u8; abi::EI_NIDENT - abi::EI_PAD],
     /// ELF file type
     pub e_type: u16,
     /// Target machine architecture
@@ -118,6 +422,107 @@ pub struct FileHeader<E: EndianParse> {
     pub e_shstrndx: u16,
 }
 
+/// Decodes the processor-specific [e_flags](FileHeader#structfield.e_flags) of one machine's
+/// objects into structured flag information.
+///
+/// This crate implements it for the machines whose flags it knows how to decode (see
+/// [DecodedFlags]). Other crates can implement it for custom machines and decode their flags
+/// with [FileHeader::flags_as].
+pub trait EFlagsDecoder: Sized {
+    /// The [e_machine](FileHeader#structfield.e_machine) of the objects whose flags this decodes
+    const E_MACHINE: u16;
+
+    /// Decode the flags of the given file header, which is for [E_MACHINE](Self::E_MACHINE).
+    ///
+    /// The whole header is given since some machines' flags depend on the class or OS ABI.
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self;
+}
+
+/// The structured [e_flags](FileHeader#structfield.e_flags) of an object, as returned by
+/// [FileHeader::decoded_flags]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecodedFlags {
+    Arm(ArmFlags),
+    Avr(AvrFlags),
+    LoongArch(LoongArchFlags),
+    Mips(MipsFlags),
+    Msp430(Msp430Flags),
+    Ppc64(Ppc64Abi),
+    Riscv(RiscvFlags),
+    Xtensa(XtensaFlags),
+    /// The flags of a machine whose flags this crate doesn't decode
+    Other(u32),
+}
+
+/// Formats the decoded flags like readelf, or the hex value of flags which aren't decoded
+impl core::fmt::Display for DecodedFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodedFlags::Arm(flags) => write!(f, "{flags}"),
+            DecodedFlags::Avr(flags) => write!(f, "{flags}"),
+            DecodedFlags::LoongArch(flags) => write!(f, "{flags}"),
+            DecodedFlags::Mips(flags) => write!(f, "{flags}"),
+            DecodedFlags::Msp430(flags) => write!(f, "{flags}"),
+            DecodedFlags::Ppc64(abi) => write!(f, "{abi}"),
+            DecodedFlags::Riscv(flags) => write!(f, "{flags}"),
+            DecodedFlags::Xtensa(flags) => write!(f, "{flags}"),
+            DecodedFlags::Other(e_flags) => write!(f, "{e_flags:#x}"),
+        }
+    }
+}
+
+/// A way in which a [FileHeader] doesn't conform to the ELF spec, as found by
+/// [FileHeader::validate]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HeaderViolation {
+    /// e_ehsize wasn't the size of the file header for the file's class.
+    /// Contains the (found, expected) sizes.
+    BadEhsize((u16, u16)),
+    /// e_phentsize wasn't the size of a program header for the file's class.
+    /// Contains the (found, expected) sizes.
+    BadPhentsize((u16, u16)),
+    /// e_shentsize wasn't the size of a section header for the file's class.
+    /// Contains the (found, expected) sizes.
+    BadShentsize((u16, u16)),
+    /// e_shstrndx wasn't the index of an entry in the section header table.
+    /// Contains the (e_shstrndx, e_shnum) values.
+    ShstrndxOutOfRange((u16, u16)),
+    /// One of the reserved `e_ident[EI_PAD..EI_NIDENT]` bytes wasn't zero.
+    /// Contains the (index, value) of the first such byte.
+    NonZeroIdentPadding((usize, u8)),
+}
+
+impl core::fmt::Display for HeaderViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            HeaderViolation::BadEhsize((found, expected)) => {
+                write!(f, "Invalid e_ehsize. Expected: {expected}, Found: {found}")
+            }
+            HeaderViolation::BadPhentsize((found, expected)) => {
+                write!(
+                    f,
+                    "Invalid e_phentsize. Expected: {expected}, Found: {found}"
+                )
+            }
+            HeaderViolation::BadShentsize((found, expected)) => {
+                write!(
+                    f,
+                    "Invalid e_shentsize. Expected: {expected}, Found: {found}"
+                )
+            }
+            HeaderViolation::ShstrndxOutOfRange((shstrndx, shnum)) => {
+                write!(
+                    f,
+                    "e_shstrndx {shstrndx} is out of range for a section header table with {shnum} entries"
+                )
+            }
+            HeaderViolation::NonZeroIdentPadding((index, value)) => {
+                write!(f, "Reserved e_ident[{index}] is {value:#x} instead of zero")
+            }
+        }
+    }
+}
+
 pub const ELF32_EHDR_TAILSIZE: usize = 36;
 pub const ELF64_EHDR_TAILSIZE: usize = 48;
 
@@ -142,17 +547,13 @@ fn verify_ident(buf: &[u8]) -> Result<(), ParseError> {
     Ok(())
 }
 
-pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<(E, Class, u8, u8), ParseError> {
+/// The values parsed out of `e_ident`: the endianness, class, osabi, abiversion, and padding bytes
+pub type Ident<E> = (E, Class, u8, u8, [u8; abi::EI_NIDENT - abi::EI_PAD]);
+
+pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<Ident<E>, ParseError> {
     verify_ident(data)?;
 
-    let e_class = data[abi::EI_CLASS];
-    let class = match e_class {
-        abi::ELFCLASS32 => Class::ELF32,
-        abi::ELFCLASS64 => Class::ELF64,
-        _ => {
-            return Err(ParseError::UnsupportedElfClass(e_class));
-        }
-    };
+    let class = Class::try_from(data[abi::EI_CLASS])?;
 
     // Verify endianness is something we know how to parse
     let file_endian = E::from_ei_data(data[abi::EI_DATA])?;
@@ -162,12 +563,13 @@ pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<(E, Class, u8, u8), Pa
         class,
         data[abi::EI_OSABI],
         data[abi::EI_ABIVERSION],
+        data[abi::EI_PAD..abi::EI_NIDENT].try_into()?,
     ))
 }
 
 impl<E: EndianParse> FileHeader<E> {
-    pub fn parse_tail(ident: (E, Class, u8, u8), data: &[u8]) -> Result<FileHeader<E>, ParseError> {
-        let (file_endian, class, osabi, abiversion) = ident;
+    pub fn parse_tail(ident: Ident<E>, data: &[u8]) -> Result<FileHeader<E>, ParseError> {
+        let (file_endian, class, osabi, abiversion, ident_pad) = ident;
 
         let mut offset = 0;
         let e_type = file_endian.parse_u16_at(&mut offset, data)?;
@@ -204,6 +606,7 @@ impl<E: EndianParse> FileHeader<E> {
             e_machine,
             osabi,
             abiversion,
+            ident_pad,
             e_entry,
             e_phoff,
             e_shoff,
@@ -216,6 +619,252 @@ impl<E: EndianParse> FileHeader<E> {
             e_shstrndx,
         })
     }
+
+    /// The OS/ABI identification ([osabi](FileHeader#structfield.osabi)) as an [OsAbi]
+    pub fn os_abi(&self) -> OsAbi {
+        OsAbi::from(self.osabi)
+    }
+
+    /// The file type ([e_type](FileHeader#structfield.e_type)) as an [ObjectFileType]
+    pub fn file_type(&self) -> ObjectFileType {
+        ObjectFileType::from(self.e_type)
+    }
+
+    /// The target machine ([e_machine](FileHeader#structfield.e_machine)) as an [Architecture]
+    pub fn architecture(&self) -> Architecture {
+        Architecture::from(self.e_machine)
+    }
+
+    /// The [e_flags](FileHeader#structfield.e_flags) decoded for the target machine, or
+    /// [DecodedFlags::Other] with the raw value for machines whose flags aren't decoded.
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::file::DecodedFlags;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// let flags = file.ehdr.decoded_flags();
+    /// assert!(matches!(flags, DecodedFlags::Riscv(_)));
+    /// assert_eq!(flags.to_string(), "RVC, double-float ABI");
+    /// ```
+    pub fn decoded_flags(&self) -> DecodedFlags {
+        match self.architecture() {
+            Architecture::Arm => DecodedFlags::Arm(ArmFlags::decode(self)),
+            Architecture::Avr => DecodedFlags::Avr(AvrFlags::decode(self)),
+            Architecture::LoongArch => DecodedFlags::LoongArch(LoongArchFlags::decode(self)),
+            Architecture::Mips => DecodedFlags::Mips(MipsFlags::decode(self)),
+            Architecture::Msp430 => DecodedFlags::Msp430(Msp430Flags::decode(self)),
+            Architecture::PowerPc64 => DecodedFlags::Ppc64(Ppc64Abi::decode(self)),
+            Architecture::RiscV => DecodedFlags::Riscv(RiscvFlags::decode(self)),
+            Architecture::Xtensa => DecodedFlags::Xtensa(XtensaFlags::decode(self)),
+            _ => DecodedFlags::Other(self.e_flags),
+        }
+    }
+
+    /// The [e_flags](FileHeader#structfield.e_flags) decoded with the given [EFlagsDecoder], or
+    /// None if the file isn't for the decoder's machine.
+    pub fn flags_as<D: EFlagsDecoder>(&self) -> Option<D> {
+        (self.e_machine == D::E_MACHINE).then(|| D::decode(self))
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of a 32-bit ARM object, or None if
+    /// the file isn't for [EM_ARM](abi::EM_ARM).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::arm::ArmFloatAbi;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// let flags = file.ehdr.arm_flags().expect("file should be for ARM");
+    /// assert_eq!(flags.eabi_version, 5);
+    /// assert_eq!(flags.float_abi, Some(ArmFloatAbi::Hard));
+    /// ```
+    pub fn arm_flags(&self) -> Option<ArmFlags> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of an AVR object, or None if the
+    /// file isn't for [EM_AVR](abi::EM_AVR).
+    pub fn avr_flags(&self) -> Option<AvrFlags> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of a LoongArch object, or None if
+    /// the file isn't for [EM_LOONGARCH](abi::EM_LOONGARCH).
+    ///
+    /// ```
+    /// use elf::abi;
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::loongarch::LoongArchAbi;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let mut file_data = std::fs::read(path).expect("Could not read file.");
+    /// // Pretend the file was built for LP64D with version 1 of the object file ABI
+    /// file_data[18..20].copy_from_slice(&abi::EM_LOONGARCH.to_le_bytes());
+    /// file_data[48..52].copy_from_slice(&0x43u32.to_le_bytes());
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// let flags = file.ehdr.loongarch_flags().expect("file should be for LoongArch");
+    /// assert_eq!(flags.abi, LoongArchAbi::Lp64D);
+    /// assert_eq!(flags.obj_abi_version, 1);
+    /// ```
+    pub fn loongarch_flags(&self) -> Option<LoongArchFlags> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of a MIPS object, or None if the
+    /// file isn't for [EM_MIPS](abi::EM_MIPS).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::mips::{MipsAbi, MipsArch};
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/abiflags.mips.o");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// let flags = file.ehdr.mips_flags().expect("file should be for MIPS");
+    /// assert_eq!(flags.arch, MipsArch::Mips32r2);
+    /// assert_eq!(flags.abi, MipsAbi::O32);
+    /// ```
+    pub fn mips_flags(&self) -> Option<MipsFlags> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of an MSP430 object, or None if the
+    /// file isn't for [EM_MSP430](abi::EM_MSP430).
+    pub fn msp430_flags(&self) -> Option<Msp430Flags> {
+        self.flags_as()
+    }
+
+    /// The PowerPC64 ABI version from the [e_flags](FileHeader#structfield.e_flags), or None if
+    /// the file isn't for [EM_PPC64](abi::EM_PPC64).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::ppc64::Ppc64Abi;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.powerpc64le.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// assert_eq!(file.ehdr.ppc64_abi(), Some(Ppc64Abi::ElfV2));
+    /// ```
+    pub fn ppc64_abi(&self) -> Option<Ppc64Abi> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of a RISC-V object, or None if the
+    /// file isn't for [EM_RISCV](abi::EM_RISCV).
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    /// use elf::riscv::RiscvFloatAbi;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// let flags = file.ehdr.riscv_flags().expect("file should be for RISC-V");
+    /// assert!(flags.rvc);
+    /// assert_eq!(flags.float_abi, RiscvFloatAbi::Double);
+    /// ```
+    pub fn riscv_flags(&self) -> Option<RiscvFlags> {
+        self.flags_as()
+    }
+
+    /// The decoded [e_flags](FileHeader#structfield.e_flags) of an Xtensa object, or None if the
+    /// file isn't for [EM_XTENSA](abi::EM_XTENSA).
+    pub fn xtensa_flags(&self) -> Option<XtensaFlags> {
+        self.flags_as()
+    }
+
+    /// Check the header's fields for consistency with the ELF spec, returning each violation found.
+    ///
+    /// This checks that e_ehsize, e_phentsize, and e_shentsize are the sizes of the structures
+    /// for the file's class (the latter two only when the file has that table), that e_shstrndx
+    /// is in range of the section header table, and that the reserved e_ident bytes are zero.
+    /// Parsing doesn't enforce these, so this lets users reject malformed inputs up front:
+    ///
+    /// ```
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    /// assert_eq!(file.ehdr.validate().next(), None);
+    /// ```
+    pub fn validate(&self) -> impl Iterator<Item = HeaderViolation> {
+        let ehsize = (abi::EI_NIDENT
+            + match self.class {
+                Class::ELF32 => ELF32_EHDR_TAILSIZE,
+                Class::ELF64 => ELF64_EHDR_TAILSIZE,
+            }) as u16;
+        let phentsize = ProgramHeader::size_for(self.class) as u16;
+        let shentsize = SectionHeader::size_for(self.class) as u16;
+
+        let bad_ehsize = (self.e_ehsize != ehsize)
+            .then_some(HeaderViolation::BadEhsize((self.e_ehsize, ehsize)));
+
+        let bad_phentsize = (self.e_phnum != 0 && self.e_phentsize != phentsize)
+            .then_some(HeaderViolation::BadPhentsize((self.e_phentsize, phentsize)));
+
+        // A zero e_shnum with a non-zero e_shoff means the real count is stored in the first
+        // section header
+        let has_shdrs = self.e_shnum != 0 || self.e_shoff != 0;
+        let bad_shentsize = (has_shdrs && self.e_shentsize != shentsize)
+            .then_some(HeaderViolation::BadShentsize((self.e_shentsize, shentsize)));
+
+        let shnum_known = self.e_shnum != 0 || self.e_shoff == 0;
+        let bad_shstrndx = (self.e_shstrndx != abi::SHN_UNDEF
+            && self.e_shstrndx != abi::SHN_XINDEX
+            && shnum_known
+            && self.e_shstrndx >= self.e_shnum)
+            .then_some(HeaderViolation::ShstrndxOutOfRange((
+                self.e_shstrndx,
+                self.e_shnum,
+            )));
+
+        let bad_pad = self
+            .ident_pad
+            .iter()
+            .position(|byte| *byte != 0)
+            .map(|index| {
+                HeaderViolation::NonZeroIdentPadding((abi::EI_PAD + index, self.ident_pad[index]))
+            });
+
+        [
+            bad_ehsize,
+            bad_phentsize,
+            bad_shentsize,
+            bad_shstrndx,
+            bad_pad,
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Check that the file offset of a non-empty header table (e_phoff or e_shoff) doesn't point
+    /// back into the file header itself, as the ELF header bytes would then get misparsed as
+    /// table entries.
+    pub(crate) fn validate_table_offset(&self, offset: u64) -> Result<(), ParseError> {
+        let ehdr_size = abi::EI_NIDENT
+            + match self.class {
+                Class::ELF32 => ELF32_EHDR_TAILSIZE,
+                Class::ELF64 => ELF64_EHDR_TAILSIZE,
+            };
+        if offset < ehdr_size as u64 || offset < self.e_ehsize as u64 {
+            return Err(ParseError::BadOffset(offset));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -384,7 +1033,13 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr32_works() {
-        let ident = (AnyEndian::Little, Class::ELF32, abi::ELFOSABI_LINUX, 7u8);
+        let ident = (
+            AnyEndian::Little,
+            Class::ELF32,
+            abi::ELFOSABI_LINUX,
+            7u8,
+            [0u8; 7],
+        );
         let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
         for (n, elem) in tail.iter_mut().enumerate().take(ELF64_EHDR_TAILSIZE) {
             *elem = n as u8;
@@ -398,6 +1053,7 @@ mod parse_tests {
                 version: 0x7060504,
                 osabi: abi::ELFOSABI_LINUX,
                 abiversion: 7,
+                ident_pad: [0u8; 7],
                 e_type: 0x100,
                 e_machine: 0x302,
                 e_entry: 0x0B0A0908,
@@ -416,7 +1072,13 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr32_fuzz_too_short() {
-        let ident = (AnyEndian::Little, Class::ELF32, abi::ELFOSABI_LINUX, 7u8);
+        let ident = (
+            AnyEndian::Little,
+            Class::ELF32,
+            abi::ELFOSABI_LINUX,
+            7u8,
+            [0u8; 7],
+        );
         let tail = [0u8; ELF32_EHDR_TAILSIZE];
 
         for n in 0..ELF32_EHDR_TAILSIZE {
@@ -431,7 +1093,13 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr64_works() {
-        let ident = (AnyEndian::Big, Class::ELF64, abi::ELFOSABI_LINUX, 7u8);
+        let ident = (
+            AnyEndian::Big,
+            Class::ELF64,
+            abi::ELFOSABI_LINUX,
+            7u8,
+            [0u8; 7],
+        );
         let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
         for (n, elem) in tail.iter_mut().enumerate().take(ELF64_EHDR_TAILSIZE) {
             *elem = n as u8;
@@ -445,6 +1113,7 @@ mod parse_tests {
                 version: 0x04050607,
                 osabi: abi::ELFOSABI_LINUX,
                 abiversion: 7,
+                ident_pad: [0u8; 7],
                 e_type: 0x0001,
                 e_machine: 0x0203,
                 e_entry: 0x08090A0B0C0D0E0F,
@@ -461,9 +1130,90 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn parse_ehdr_with_nonstandard_values() {
+        // A custom toolchain's object: an unassigned OS/ABI, an OS-specific file type, and a
+        // machine without an EM_* constant
+        let data: [u8; abi::EI_NIDENT] = [
+            abi::ELFMAG0,
+            abi::ELFMAG1,
+            abi::ELFMAG2,
+            abi::ELFMAG3,
+            abi::ELFCLASS64,
+            abi::ELFDATA2LSB,
+            abi::EV_CURRENT,
+            0x42,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let ident = parse_ident::<AnyEndian>(&data).unwrap();
+        let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
+        tail[0..2].copy_from_slice(&(abi::ET_LOOS + 1).to_le_bytes());
+        tail[2..4].copy_from_slice(&0x4000u16.to_le_bytes());
+
+        let ehdr = FileHeader::parse_tail(ident, &tail).unwrap();
+        assert_eq!(ehdr.os_abi(), OsAbi::Unknown(0x42));
+        assert_eq!(ehdr.file_type(), ObjectFileType::Unknown(0xfe01));
+        assert_eq!(ehdr.architecture(), Architecture::Unknown(0x4000));
+    }
+
+    #[test]
+    fn flags_with_custom_decoder() {
+        #[derive(Debug, PartialEq)]
+        struct CustomFlags(u32);
+
+        impl EFlagsDecoder for CustomFlags {
+            const E_MACHINE: u16 = 0x0203;
+
+            fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+                CustomFlags(ehdr.e_flags & 0xff)
+            }
+        }
+
+        let ident = (
+            AnyEndian::Big,
+            Class::ELF64,
+            abi::ELFOSABI_LINUX,
+            7u8,
+            [0u8; 7],
+        );
+        let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
+        for (n, elem) in tail.iter_mut().enumerate() {
+            *elem = n as u8;
+        }
+        let mut ehdr = FileHeader::parse_tail(ident, &tail).unwrap();
+        assert_eq!(ehdr.flags_as::<CustomFlags>(), Some(CustomFlags(0x23)));
+        assert_eq!(ehdr.decoded_flags(), DecodedFlags::Other(0x20212223));
+        assert_eq!(ehdr.decoded_flags().to_string(), "0x20212223");
+
+        ehdr.e_machine = abi::EM_RISCV;
+        assert_eq!(ehdr.flags_as::<CustomFlags>(), None);
+        assert_eq!(
+            ehdr.decoded_flags(),
+            DecodedFlags::Riscv(RiscvFlags::from_e_flags(0x20212223))
+        );
+
+        ehdr.e_machine = abi::EM_XTENSA;
+        ehdr.e_flags = abi::EF_XTENSA_XT_INSN;
+        assert_eq!(ehdr.xtensa_flags(), Some(XtensaFlags::from_e_flags(0x100)));
+        assert_eq!(ehdr.decoded_flags().to_string(), "Xtensa, xt.insn");
+    }
+
     #[test]
     fn test_parse_ehdr64_fuzz_too_short() {
-        let ident = (AnyEndian::Little, Class::ELF64, abi::ELFOSABI_LINUX, 7u8);
+        let ident = (
+            AnyEndian::Little,
+            Class::ELF64,
+            abi::ELFOSABI_LINUX,
+            7u8,
+            [0u8; 7],
+        );
         let tail = [0u8; ELF64_EHDR_TAILSIZE];
 
         for n in 0..ELF64_EHDR_TAILSIZE {
@@ -475,4 +1225,134 @@ mod parse_tests {
             );
         }
     }
+
+    #[test]
+    fn validate_accepts_well_formed_header() {
+        let ident = (
+            AnyEndian::Little,
+            Class::ELF64,
+            abi::ELFOSABI_NONE,
+            0u8,
+            [0u8; 7],
+        );
+        let mut ehdr = FileHeader::parse_tail(ident, &[0u8; ELF64_EHDR_TAILSIZE]).unwrap();
+        ehdr.e_ehsize = 64;
+        assert_eq!(ehdr.validate().next(), None);
+
+        ehdr.e_phnum = 2;
+        ehdr.e_phentsize = 56;
+        ehdr.e_shoff = 0x1000;
+        ehdr.e_shnum = 10;
+        ehdr.e_shentsize = 64;
+        ehdr.e_shstrndx = 9;
+        assert_eq!(ehdr.validate().next(), None);
+
+        // More sections than fit in e_shnum, so the count lives in the first section header
+        ehdr.e_shnum = 0;
+        ehdr.e_shstrndx = abi::SHN_XINDEX;
+        assert_eq!(ehdr.validate().next(), None);
+    }
+
+    #[test]
+    fn validate_reports_each_violation() {
+        let ident = (
+            AnyEndian::Big,
+            Class::ELF32,
+            abi::ELFOSABI_NONE,
+            0u8,
+            [0, 0, 0, 0, 0xAB, 0, 0xCD],
+        );
+        let mut ehdr = FileHeader::parse_tail(ident, &[0u8; ELF32_EHDR_TAILSIZE]).unwrap();
+        ehdr.e_ehsize = 64;
+        ehdr.e_phnum = 1;
+        ehdr.e_phentsize = 56;
+        ehdr.e_shoff = 0x1000;
+        ehdr.e_shnum = 4;
+        ehdr.e_shentsize = 64;
+        ehdr.e_shstrndx = 4;
+
+        let violations: Vec<HeaderViolation> = ehdr.validate().collect();
+        assert_eq!(
+            violations,
+            [
+                HeaderViolation::BadEhsize((64, 52)),
+                HeaderViolation::BadPhentsize((56, 32)),
+                HeaderViolation::BadShentsize((64, 40)),
+                HeaderViolation::ShstrndxOutOfRange((4, 4)),
+                HeaderViolation::NonZeroIdentPadding((13, 0xAB)),
+            ]
+        );
+        assert_eq!(
+            violations[3].to_string(),
+            "e_shstrndx 4 is out of range for a section header table with 4 entries"
+        );
+    }
+
+    #[test]
+    fn class_raw_value_conversions() {
+        assert_eq!(Class::try_from(abi::ELFCLASS32).unwrap(), Class::ELF32);
+        assert_eq!(u8::from(Class::ELF64), abi::ELFCLASS64);
+        let err = Class::try_from(3).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedElfClass(3)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn file_type_and_architecture_raw_value_conversions() {
+        assert_eq!(
+            ObjectFileType::from(abi::ET_DYN),
+            ObjectFileType::SharedObject
+        );
+        assert_eq!(
+            ObjectFileType::from(0xfe00),
+            ObjectFileType::Unknown(0xfe00)
+        );
+        assert_eq!(u16::from(ObjectFileType::Unknown(0xfe00)), 0xfe00);
+        for e_type in 0..=abi::ET_CORE {
+            assert_eq!(u16::from(ObjectFileType::from(e_type)), e_type);
+        }
+
+        assert_eq!(Architecture::from(abi::EM_AARCH64), Architecture::Aarch64);
+        assert_eq!(
+            Architecture::from(abi::EM_BPF),
+            Architecture::Unknown(abi::EM_BPF)
+        );
+        for e_machine in 0..=abi::EM_LOONGARCH {
+            assert_eq!(u16::from(Architecture::from(e_machine)), e_machine);
+        }
+    }
+
+    #[test]
+    fn os_abi_raw_value_conversions() {
+        assert_eq!(OsAbi::from(abi::ELFOSABI_LINUX), OsAbi::Gnu);
+        assert_eq!(OsAbi::from(abi::ELFOSABI_STANDALONE), OsAbi::Standalone);
+        assert_eq!(
+            OsAbi::from(abi::ELFOSABI_ARM),
+            OsAbi::Unknown(abi::ELFOSABI_ARM)
+        );
+        for osabi in 0..=u8::MAX {
+            assert_eq!(u8::from(OsAbi::from(osabi)), osabi);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parsed_types_implement_serde() {
+        fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+        assert_serde::<FileHeader<AnyEndian>>();
+        assert_serde::<FileHeader<crate::endian::LittleEndian>>();
+        assert_serde::<ObjectFileType>();
+        assert_serde::<Architecture>();
+        assert_serde::<OsAbi>();
+        assert_serde::<crate::section::SectionHeader>();
+        assert_serde::<crate::segment::ProgramHeader>();
+        assert_serde::<crate::symbol::Symbol>();
+        assert_serde::<crate::symbol::SymbolType>();
+        assert_serde::<crate::symbol::SymbolBind>();
+        assert_serde::<crate::dynamic::Dyn>();
+        assert_serde::<crate::relocation::Rela>();
+        assert_serde::<crate::compression::CompressionHeader>();
+    }
 }
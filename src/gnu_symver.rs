@@ -4,6 +4,7 @@ use crate::endian::EndianParse;
 use crate::file::Class;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
 use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolTable};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct SymbolRequirement<'data> {
@@ -14,6 +15,17 @@ pub struct SymbolRequirement<'data> {
     pub hidden: bool,
 }
 
+/// The version assigned to a dynamic symbol, whether it's a version required from
+/// another object or one defined by this object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolVersion<'data> {
+    /// The version name, e.g. `GLIBC_2.2.5`
+    pub name: &'data str,
+    /// Whether the symbol is hidden from this version, i.e. it's an older, non-default
+    /// version of the symbol (printed as `name@VERSION` rather than `name@@VERSION`).
+    pub hidden: bool,
+}
+
 #[derive(Debug)]
 pub struct SymbolDefinition<'data, E: EndianParse> {
     pub hash: u32,
@@ -66,10 +78,48 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
         }
     }
 
+    /// Get the version name and hidden flag for the dynamic symbol at `sym_idx`.
+    ///
+    /// This looks up the symbol's version in either the version requirements or the
+    /// version definitions, as appropriate. Returns `None` for symbols which are
+    /// unversioned ([VER_NDX_LOCAL](abi::VER_NDX_LOCAL) or
+    /// [VER_NDX_GLOBAL](abi::VER_NDX_GLOBAL)), or whose version index has no entry.
+    pub fn get_version(&self, sym_idx: usize) -> Result<Option<SymbolVersion<'data>>, ParseError> {
+        let ver_ndx = self.version_ids.get(sym_idx)?;
+        if ver_ndx.is_local() || ver_ndx.is_global() {
+            return Ok(None);
+        }
+
+        if let Some(req) = self.get_requirement(sym_idx)? {
+            return Ok(Some(SymbolVersion {
+                name: req.name,
+                hidden: req.hidden,
+            }));
+        }
+
+        if let Some((verdefs, verdef_strs)) = self.verdefs {
+            for (vd, mut vda_iter) in verdefs {
+                if vd.vd_ndx != ver_ndx.index() {
+                    continue;
+                }
+
+                // The first name is the version being defined, any others are its predecessors
+                if let Some(vda) = vda_iter.next() {
+                    return Ok(Some(SymbolVersion {
+                        name: verdef_strs.get(vda.vda_name as usize)?,
+                        hidden: ver_ndx.is_hidden(),
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     pub fn get_requirement(
         &self,
         sym_idx: usize,
-    ) -> Result<Option<SymbolRequirement<'_>>, ParseError> {
+    ) -> Result<Option<SymbolRequirement<'data>>, ParseError> {
         let (verneeds, verneed_strs) = match self.verneeds {
             Some(verneeds) => verneeds,
             None => {
@@ -146,6 +196,185 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
     }
 }
 
+/// A dynamic symbol joined with its name and its GNU symbol version (if any).
+///
+/// This implements [Display](core::fmt::Display) in the same style as binutils: `name@VERSION`
+/// for version requirements and hidden versions, and `name@@VERSION` for a defined symbol's
+/// default version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedSymbol<'data> {
+    /// The symbol's index in the dynamic symbol table
+    pub index: usize,
+    /// The symbol itself
+    pub symbol: Symbol,
+    /// The symbol's name
+    pub name: &'data str,
+    /// The symbol's version, or None if it's unversioned or the object doesn't use symbol
+    /// versioning
+    pub version: Option<SymbolVersion<'data>>,
+}
+
+impl<'data> VersionedSymbol<'data> {
+    /// Returns true if the symbol is imported from another object
+    pub fn is_undefined(&self) -> bool {
+        self.symbol.is_undefined()
+    }
+
+    /// Returns true if the symbol has [STB_WEAK](abi::STB_WEAK) binding
+    pub fn is_weak(&self) -> bool {
+        self.symbol.st_bind() == abi::STB_WEAK
+    }
+
+    /// Returns true if the symbol is one which this object needs another object to define, i.e.
+    /// it's undefined and not [STB_LOCAL](abi::STB_LOCAL). This skips the null symbol at index 0.
+    pub fn is_imported(&self) -> bool {
+        self.is_undefined() && self.symbol.st_bind() != abi::STB_LOCAL && !self.name.is_empty()
+    }
+
+    /// Returns true if the symbol is defined by this object and visible to other objects, i.e.
+    /// it's not [STB_LOCAL](abi::STB_LOCAL) and doesn't have [STV_HIDDEN](abi::STV_HIDDEN) or
+    /// [STV_INTERNAL](abi::STV_INTERNAL) visibility.
+    ///
+    /// The absolute symbols which the linker adds to name each version definition (e.g. a
+    /// `VERS_1.0` symbol for the `VERS_1.0` version) aren't counted as exported.
+    pub fn is_exported(&self) -> bool {
+        let is_version_name = self.symbol.st_shndx == abi::SHN_ABS
+            && matches!(self.version, Some(version) if version.name == self.name);
+        !self.is_undefined()
+            && self.symbol.st_bind() != abi::STB_LOCAL
+            && !matches!(self.symbol.st_vis(), abi::STV_HIDDEN | abi::STV_INTERNAL)
+            && !is_version_name
+    }
+
+    /// Returns true if this is the default version of a defined symbol, i.e. the one which new
+    /// links against this object will bind to.
+    pub fn is_default_version(&self) -> bool {
+        match self.version {
+            Some(version) => !self.is_undefined() && !version.hidden,
+            None => false,
+        }
+    }
+}
+
+impl<'data> core::fmt::Display for VersionedSymbol<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.version {
+            Some(version) if self.is_default_version() => {
+                write!(f, "{}@@{}", self.name, version.name)
+            }
+            Some(version) => write!(f, "{}@{}", self.name, version.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Iterator over the [VersionedSymbol]s in a dynamic symbol table.
+///
+/// See [ElfBytes::dynamic_symbols](crate::ElfBytes::dynamic_symbols).
+#[derive(Debug)]
+pub struct VersionedSymbolIterator<'data, E: EndianParse> {
+    symtab: SymbolTable<'data, E>,
+    strtab: StringTable<'data>,
+    versions: Option<SymbolVersionTable<'data, E>>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> VersionedSymbolIterator<'data, E> {
+    pub fn new(
+        symtab: SymbolTable<'data, E>,
+        strtab: StringTable<'data>,
+        versions: Option<SymbolVersionTable<'data, E>>,
+    ) -> Self {
+        VersionedSymbolIterator {
+            symtab,
+            strtab,
+            versions,
+            index: 0,
+        }
+    }
+
+    fn versioned_symbol(&self, index: usize) -> Result<VersionedSymbol<'data>, ParseError> {
+        let symbol = self.symtab.get(index)?;
+        let name = self.strtab.get(symbol.st_name as usize)?;
+        let version = match self.versions {
+            Some(ref versions) => versions.get_version(index)?,
+            None => None,
+        };
+        Ok(VersionedSymbol {
+            index,
+            symbol,
+            name,
+            version,
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for VersionedSymbolIterator<'data, E> {
+    type Item = Result<VersionedSymbol<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.symtab.len() {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(self.versioned_symbol(index))
+    }
+}
+
+/// Iterator over the exported [VersionedSymbol]s in a dynamic symbol table, i.e. those for
+/// which [VersionedSymbol::is_exported] is true.
+///
+/// See [ElfBytes::exports](crate::ElfBytes::exports).
+#[derive(Debug)]
+pub struct ExportIterator<'data, E: EndianParse> {
+    symbols: VersionedSymbolIterator<'data, E>,
+}
+
+impl<'data, E: EndianParse> ExportIterator<'data, E> {
+    pub fn new(symbols: VersionedSymbolIterator<'data, E>) -> Self {
+        ExportIterator { symbols }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for ExportIterator<'data, E> {
+    type Item = Result<VersionedSymbol<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.symbols.next()? {
+                Ok(symbol) if !symbol.is_exported() => continue,
+                result => return Some(result),
+            }
+        }
+    }
+}
+
+/// Iterator over the imported [VersionedSymbol]s in a dynamic symbol table, i.e. those for
+/// which [VersionedSymbol::is_imported] is true.
+///
+/// See [ElfBytes::imports](crate::ElfBytes::imports).
+#[derive(Debug)]
+pub struct ImportIterator<'data, E: EndianParse> {
+    symbols: VersionedSymbolIterator<'data, E>,
+}
+
+impl<'data, E: EndianParse> ImportIterator<'data, E> {
+    pub fn new(symbols: VersionedSymbolIterator<'data, E>) -> Self {
+        ImportIterator { symbols }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for ImportIterator<'data, E> {
+    type Item = Result<VersionedSymbol<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.symbols.next()? {
+                Ok(symbol) if !symbol.is_imported() => continue,
+                result => return Some(result),
+            }
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////
 //                                                 _              //
 //       __ _ _ __  _   _      __   _____ _ __ ___(_) ___  _ __   //
@@ -1589,4 +1818,53 @@ mod version_index_tests {
         assert_eq!(idx.index(), 42);
         assert!(idx.is_hidden());
     }
+
+    #[test]
+    fn versioned_symbol_is_exported_or_imported() {
+        let defined = VersionedSymbol {
+            index: 1,
+            symbol: Symbol {
+                st_name: 1,
+                st_shndx: 10,
+                st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+                st_other: abi::STV_DEFAULT,
+                st_value: 0x1000,
+                st_size: 8,
+            },
+            name: "func",
+            version: Some(SymbolVersion {
+                name: "VERS_1",
+                hidden: false,
+            }),
+        };
+        assert!(defined.is_exported());
+
+        let mut sym = defined.clone();
+        sym.symbol.st_other = abi::STV_PROTECTED;
+        assert!(sym.is_exported());
+        sym.symbol.st_other = abi::STV_HIDDEN;
+        assert!(!sym.is_exported());
+
+        let mut sym = defined.clone();
+        sym.symbol.st_info = (abi::STB_LOCAL << 4) | abi::STT_FUNC;
+        assert!(!sym.is_exported());
+        sym.symbol.st_shndx = abi::SHN_UNDEF;
+        assert!(!sym.is_imported());
+
+        let mut sym = defined.clone();
+        sym.symbol.st_shndx = abi::SHN_UNDEF;
+        assert!(!sym.is_exported());
+        assert!(sym.is_imported());
+        sym.symbol.st_info = (abi::STB_WEAK << 4) | abi::STT_FUNC;
+        assert!(sym.is_imported());
+        // The null symbol
+        sym.name = "";
+        assert!(!sym.is_imported());
+
+        // The symbol naming a version definition
+        let mut sym = defined;
+        sym.symbol.st_shndx = abi::SHN_ABS;
+        sym.name = "VERS_1";
+        assert!(!sym.is_exported());
+    }
 }
@@ -0,0 +1,335 @@
+//! Parsing Go's function table: `.gopclntab`
+//!
+//! The Go runtime keeps a table of every function's entry address and name (plus line number
+//! info) for its own use in stack traces and profiling. Since the runtime needs it, this table
+//! survives stripping, which makes it the way to symbolize Go binaries that have no `.symtab`.
+//!
+//! This supports the table formats used by Go 1.16 and later.
+//!
+//! (see: <https://go.dev/src/runtime/symtab.go> and <https://go.dev/src/debug/gosym/pclntab.go>)
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseError, ReadBytesExt};
+use crate::string_table::StringTable;
+use core::str::from_utf8;
+
+/// `.gopclntab` header magic for Go 1.16 and 1.17
+pub const GO_PCLNTAB_MAGIC_1_16: u32 = 0xFFFFFFFA;
+/// `.gopclntab` header magic for Go 1.18 and 1.19
+pub const GO_PCLNTAB_MAGIC_1_18: u32 = 0xFFFFFFF0;
+/// `.gopclntab` header magic for Go 1.20 and later
+pub const GO_PCLNTAB_MAGIC_1_20: u32 = 0xFFFFFFF1;
+
+/// The Go release which introduced a given `.gopclntab` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoPcLnTabVersion {
+    Go1_16,
+    Go1_18,
+    Go1_20,
+}
+
+/// A lazy-parsing view of the function table in a `.gopclntab` section.
+#[derive(Debug, Clone, Copy)]
+pub struct GoPcLnTab<'data, E: EndianParse> {
+    endian: E,
+    /// The table format, as identified by the header magic
+    pub version: GoPcLnTabVersion,
+    /// The instruction size quantum (minimum instruction length) for the target
+    pub quantum: u8,
+    /// The size of a target pointer (`uintptr`) in bytes
+    pub ptr_size: u8,
+    /// The number of functions in the table
+    pub nfunc: usize,
+    /// The address which function entries are relative to (zero for Go 1.16 tables,
+    /// whose entries hold absolute addresses)
+    pub text_start: u64,
+    funcnames: StringTable<'data>,
+    pcln: &'data [u8],
+}
+
+/// A function entry from a [GoPcLnTab].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoFunc<'data> {
+    /// The function's entry point address
+    pub entry: u64,
+    /// The function's fully-qualified name, e.g. `main.main` or `runtime.gcStart`
+    pub name: &'data [u8],
+}
+
+impl<'data> GoFunc<'data> {
+    /// Parses the function name bytes as a utf8 sequence.
+    pub fn name_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.name)?)
+    }
+}
+
+impl<'data, E: EndianParse> GoPcLnTab<'data, E> {
+    /// Parse the header of a `.gopclntab` section's contents.
+    ///
+    /// Returns [ParseError::BadMagic] if the header magic isn't for one of the supported
+    /// formats, and [ParseError::BadEntsize] if the table's pointer size doesn't match the
+    /// ELF object's `class`.
+    pub fn parse(endian: E, class: Class, data: &'data [u8]) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let magic = endian.parse_u32_at(&mut offset, data)?;
+        let version = match magic {
+            GO_PCLNTAB_MAGIC_1_16 => GoPcLnTabVersion::Go1_16,
+            GO_PCLNTAB_MAGIC_1_18 => GoPcLnTabVersion::Go1_18,
+            GO_PCLNTAB_MAGIC_1_20 => GoPcLnTabVersion::Go1_20,
+            _ => {
+                let magic = data.get_bytes(0..4)?;
+                return Err(ParseError::BadMagic([
+                    magic[0], magic[1], magic[2], magic[3],
+                ]));
+            }
+        };
+
+        // Skip over the two padding bytes
+        offset += 2;
+        let quantum = endian.parse_u8_at(&mut offset, data)?;
+        let ptr_size = endian.parse_u8_at(&mut offset, data)?;
+        let expected_ptr_size = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        if ptr_size != expected_ptr_size {
+            return Err(ParseError::BadEntsize((
+                ptr_size as u64,
+                expected_ptr_size as u64,
+            )));
+        }
+
+        let nfunc: usize = parse_uintptr(endian, class, &mut offset, data)?.try_into()?;
+        let _nfiles = parse_uintptr(endian, class, &mut offset, data)?;
+        let text_start = match version {
+            GoPcLnTabVersion::Go1_16 => 0,
+            _ => parse_uintptr(endian, class, &mut offset, data)?,
+        };
+        let funcname_offset: usize = parse_uintptr(endian, class, &mut offset, data)?.try_into()?;
+        let _cu_offset = parse_uintptr(endian, class, &mut offset, data)?;
+        let _filetab_offset = parse_uintptr(endian, class, &mut offset, data)?;
+        let _pctab_offset = parse_uintptr(endian, class, &mut offset, data)?;
+        let pcln_offset: usize = parse_uintptr(endian, class, &mut offset, data)?.try_into()?;
+
+        let funcnames = data
+            .get(funcname_offset..)
+            .ok_or(ParseError::BadOffset(funcname_offset as u64))?;
+        let pcln = data
+            .get(pcln_offset..)
+            .ok_or(ParseError::BadOffset(pcln_offset as u64))?;
+
+        Ok(GoPcLnTab {
+            endian,
+            version,
+            quantum,
+            ptr_size,
+            nfunc,
+            text_start,
+            funcnames: StringTable::new(funcnames),
+            pcln,
+        })
+    }
+
+    fn class(&self) -> Class {
+        match self.ptr_size {
+            4 => Class::ELF32,
+            _ => Class::ELF64,
+        }
+    }
+
+    /// Get the function at the given index in the table.
+    pub fn get(&self, index: usize) -> Result<GoFunc<'data>, ParseError> {
+        if index >= self.nfunc {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        // The functab is an array of (entry, funcoff) pairs, where funcoff is the offset of the
+        // function's _func struct from the start of the functab. The _func struct begins with
+        // the entry again and then the offset of the function's name in the name table.
+        let class = self.class();
+        let (entry, name_offset) = match self.version {
+            GoPcLnTabVersion::Go1_16 => {
+                let mut offset = index
+                    .checked_mul(2 * self.ptr_size as usize)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                let entry = parse_uintptr(self.endian, class, &mut offset, self.pcln)?;
+                let mut func_offset: usize =
+                    parse_uintptr(self.endian, class, &mut offset, self.pcln)?.try_into()?;
+                parse_uintptr(self.endian, class, &mut func_offset, self.pcln)?;
+                let name_offset = self.endian.parse_i32_at(&mut func_offset, self.pcln)?;
+                (entry, name_offset)
+            }
+            GoPcLnTabVersion::Go1_18 | GoPcLnTabVersion::Go1_20 => {
+                let mut offset = index.checked_mul(8).ok_or(ParseError::IntegerOverflow)?;
+                let entry_offset = self.endian.parse_u32_at(&mut offset, self.pcln)?;
+                let mut func_offset = self.endian.parse_u32_at(&mut offset, self.pcln)? as usize;
+                self.endian.parse_u32_at(&mut func_offset, self.pcln)?;
+                let name_offset = self.endian.parse_i32_at(&mut func_offset, self.pcln)?;
+                let entry = self
+                    .text_start
+                    .checked_add(entry_offset as u64)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                (entry, name_offset)
+            }
+        };
+
+        let name = self.funcnames.get_raw(name_offset.try_into()?)?;
+        Ok(GoFunc { entry, name })
+    }
+
+    /// Get an iterator over the functions in the table, in table (address) order.
+    ///
+    /// Iteration stops early if a function entry can't be parsed.
+    pub fn iter(&self) -> GoFuncIterator<'data, E> {
+        GoFuncIterator {
+            table: *self,
+            index: 0,
+        }
+    }
+}
+
+fn parse_uintptr<E: EndianParse>(
+    endian: E,
+    class: Class,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    match class {
+        Class::ELF32 => Ok(endian.parse_u32_at(offset, data)? as u64),
+        Class::ELF64 => endian.parse_u64_at(offset, data),
+    }
+}
+
+/// Iterator over the functions in a [GoPcLnTab].
+#[derive(Debug)]
+pub struct GoFuncIterator<'data, E: EndianParse> {
+    table: GoPcLnTab<'data, E>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for GoFuncIterator<'data, E> {
+    type Item = GoFunc<'data>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let func = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(func)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    /// Build a minimal Go 1.18+ style table for a 64-bit LSB target with two functions.
+    fn go118_lsb_table(magic: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&magic.to_le_bytes());
+        data.extend_from_slice(&[0, 0, 1, 8]);
+        for field in [2u64, 0, 0x401000, 72, 96, 96, 96, 96] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        // funcnametab at 72
+        data.extend_from_slice(b"main.main\0runtime.main\0\0");
+        // functab at 96: (entryoff, funcoff) for each func, plus the end entry
+        for field in [0x0u32, 24, 0x100, 32, 0x200, 0] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        // _func structs: (entryoff, nameoff)
+        for field in [0x0u32, 0, 0x100, 10] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn parse_go118_lsb() {
+        let data = go118_lsb_table(GO_PCLNTAB_MAGIC_1_20);
+        let table = GoPcLnTab::parse(LittleEndian, Class::ELF64, &data).expect("should parse");
+        assert_eq!(table.version, GoPcLnTabVersion::Go1_20);
+        assert_eq!(table.quantum, 1);
+        assert_eq!(table.nfunc, 2);
+        assert_eq!(table.text_start, 0x401000);
+
+        let funcs: Vec<GoFunc<'_>> = table.iter().collect();
+        assert_eq!(
+            funcs,
+            [
+                GoFunc {
+                    entry: 0x401000,
+                    name: b"main.main"
+                },
+                GoFunc {
+                    entry: 0x401100,
+                    name: b"runtime.main"
+                },
+            ]
+        );
+        assert_eq!(funcs[1].name_str().unwrap(), "runtime.main");
+
+        let err = table.get(2).expect_err("should be out of bounds");
+        assert!(
+            matches!(err, ParseError::BadOffset(2)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_go116_msb() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&GO_PCLNTAB_MAGIC_1_16.to_be_bytes());
+        data.extend_from_slice(&[0, 0, 4, 4]);
+        for field in [1u32, 0, 36, 48, 48, 48, 48] {
+            data.extend_from_slice(&field.to_be_bytes());
+        }
+        // funcnametab at 36
+        data.extend_from_slice(b"main.init\0\0\0");
+        // functab at 48: (entry, funcoff), plus the end entry
+        for field in [0x10000u32, 16, 0x10040, 0] {
+            data.extend_from_slice(&field.to_be_bytes());
+        }
+        // _func struct: (entry, nameoff)
+        for field in [0x10000u32, 0] {
+            data.extend_from_slice(&field.to_be_bytes());
+        }
+
+        let table = GoPcLnTab::parse(BigEndian, Class::ELF32, &data).expect("should parse");
+        assert_eq!(table.version, GoPcLnTabVersion::Go1_16);
+        let funcs: Vec<GoFunc<'_>> = table.iter().collect();
+        assert_eq!(
+            funcs,
+            [GoFunc {
+                entry: 0x10000,
+                name: b"main.init"
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_bad_magic_errors() {
+        let data = go118_lsb_table(0xFFFFFFFB);
+        let err = GoPcLnTab::parse(LittleEndian, Class::ELF64, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadMagic([0xFB, 0xFF, 0xFF, 0xFF])),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_ptr_size_class_mismatch_errors() {
+        let data = go118_lsb_table(GO_PCLNTAB_MAGIC_1_18);
+        let err = GoPcLnTab::parse(LittleEndian, Class::ELF32, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadEntsize((8, 4))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn truncated_func_stops_iteration() {
+        let mut data = go118_lsb_table(GO_PCLNTAB_MAGIC_1_18);
+        data.truncate(data.len() - 8);
+        let table = GoPcLnTab::parse(LittleEndian, Class::ELF64, &data).expect("should parse");
+        assert_eq!(table.iter().count(), 1);
+    }
+}
@@ -0,0 +1,146 @@
+//! Parsing section groups: [SHT_GROUP](crate::abi::SHT_GROUP) sections such as COMDAT groups
+//!
+//! Relocatable objects use section groups to tie together sections which must be kept or
+//! discarded as a unit by the linker. Most commonly these are COMDAT groups holding C++
+//! template instantiations and inline functions, which the linker deduplicates across
+//! objects by the group's signature.
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseError, ParsingIterator, ParsingTable, ReadBytesExt};
+use crate::section::SectionHeader;
+
+/// The contents of a section group.
+///
+/// The section data is an array of 32-bit words: a flags word followed by the section header
+/// indexes of the group's members. The group's signature symbol is named by the group
+/// section's header rather than its data.
+#[derive(Debug)]
+pub struct SectionGroup<'data, E: EndianParse> {
+    /// The group's flags, e.g. [GRP_COMDAT](abi::GRP_COMDAT)
+    pub flags: u32,
+    /// The section header index of the symbol table which holds the signature symbol
+    /// (the group section's sh_link)
+    pub symtab_index: u32,
+    /// The index of the signature symbol in its symbol table (the group section's sh_info).
+    /// The symbol's name identifies the group, e.g. for COMDAT deduplication.
+    pub signature_symbol: u32,
+    /// The section header indexes of the sections in the group
+    pub members: ParsingTable<'data, E, u32>,
+}
+
+impl<'data, E: EndianParse> SectionGroup<'data, E> {
+    /// Parse a section group from the given group section header and its section data.
+    pub fn parse(
+        endian: E,
+        class: Class,
+        shdr: &SectionHeader,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let flags = endian.parse_u32_at(&mut offset, data)?;
+        let members = ParsingTable::new(endian, class, data.get_bytes(offset..data.len())?);
+        Ok(SectionGroup {
+            flags,
+            symtab_index: shdr.sh_link,
+            signature_symbol: shdr.sh_info,
+            members,
+        })
+    }
+
+    /// Returns true if this is a COMDAT group, which the linker keeps only one copy of
+    /// amongst all the groups with the same signature.
+    pub fn is_comdat(&self) -> bool {
+        self.flags & abi::GRP_COMDAT != 0
+    }
+}
+
+/// Iterator over the section groups in an object's section header table.
+///
+/// See [ElfBytes::section_groups](crate::ElfBytes::section_groups).
+#[derive(Debug)]
+pub struct SectionGroupIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    shdrs: ParsingIterator<'data, E, SectionHeader>,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> SectionGroupIterator<'data, E> {
+    pub(crate) fn new(
+        endian: E,
+        class: Class,
+        shdrs: ParsingIterator<'data, E, SectionHeader>,
+        data: &'data [u8],
+    ) -> Self {
+        SectionGroupIterator {
+            endian,
+            class,
+            shdrs,
+            data,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for SectionGroupIterator<'data, E> {
+    type Item = Result<SectionGroup<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let shdr = self.shdrs.find(|shdr| shdr.sh_type == abi::SHT_GROUP)?;
+        Some(shdr.get_data_range().and_then(|(start, end)| {
+            let buf = self.data.get_bytes(start..end)?;
+            SectionGroup::parse(self.endian, self.class, &shdr, buf)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    fn group_shdr() -> SectionHeader {
+        SectionHeader {
+            sh_name: 0,
+            sh_type: abi::SHT_GROUP,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: 12,
+            sh_link: 15,
+            sh_info: 6,
+            sh_addralign: 4,
+            sh_entsize: 4,
+        }
+    }
+
+    #[test]
+    fn parse_group_lsb() {
+        let data = [1u8, 0, 0, 0, 8, 0, 0, 0, 9, 0, 0, 0];
+        let group = SectionGroup::parse(LittleEndian, Class::ELF64, &group_shdr(), &data)
+            .expect("should parse");
+        assert!(group.is_comdat());
+        assert_eq!(group.symtab_index, 15);
+        assert_eq!(group.signature_symbol, 6);
+        assert_eq!(group.members.iter().collect::<Vec<u32>>(), [8, 9]);
+    }
+
+    #[test]
+    fn parse_group_msb() {
+        let data = [0u8, 0, 0, 0, 0, 0, 0, 8];
+        let group = SectionGroup::parse(BigEndian, Class::ELF32, &group_shdr(), &data)
+            .expect("should parse");
+        assert!(!group.is_comdat());
+        assert_eq!(group.members.iter().collect::<Vec<u32>>(), [8]);
+    }
+
+    #[test]
+    fn parse_group_missing_flags_errors() {
+        let data = [1u8, 0];
+        let err = SectionGroup::parse(LittleEndian, Class::ELF64, &group_shdr(), &data)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((0, 4))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -23,7 +23,7 @@ impl ParseAt for u32 {
     }
 }
 
-type U32Table<'data, E> = ParsingTable<'data, E, u32>;
+pub type U32Table<'data, E> = ParsingTable<'data, E, u32>;
 
 /// Header at the start of SysV Hash Table sections of type [SHT_HASH](crate::abi::SHT_HASH).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -97,6 +97,19 @@ impl<'data, E: EndianParse> SysVHashTable<'data, E> {
         Ok(SysVHashTable { buckets, chains })
     }
 
+    /// Get the table's `nbucket` bucket entries, each of which holds the symbol table index
+    /// of the first symbol in the chain for names whose hash falls in that bucket.
+    pub fn buckets(&self) -> U32Table<'data, E> {
+        self.buckets
+    }
+
+    /// Get the table's `nchain` chain entries, one for each symbol in the symbol table,
+    /// each of which holds the symbol table index of the next symbol in the same chain
+    /// (or zero, the undefined symbol, at the end of a chain).
+    pub fn chains(&self) -> U32Table<'data, E> {
+        self.chains
+    }
+
     /// Use the hash table to find the symbol table entry with the given name and hash.
     pub fn find(
         &self,
@@ -0,0 +1,53 @@
+//! Laying out an object's loadable segments as a flat memory image: [MemoryImage]
+//!
+//! Emulators, bootloaders, and zkVM loaders which don't have an MMU to map segments with
+//! need the bytes an object occupies in memory as one contiguous buffer. A [MemoryImage]
+//! holds every [PT_LOAD](crate::abi::PT_LOAD) segment's file data at its virtual address
+//! relative to the image's base, with zeros for the parts between p_filesz and p_memsz (e.g.
+//! a `.bss`) and for any gaps between segments.
+//!
+//! See [ElfBytes::memory_image](crate::ElfBytes::memory_image).
+//!
+//! This module is only available with the `std` cargo feature.
+//!
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let range = file.loaded_range().expect("file should have PT_LOAD segments");
+//! let image = file.memory_image(range).expect("segments should be parseable");
+//! assert_eq!(image.base, 0x400000);
+//! assert_eq!(image.entry, 0x4003f0);
+//! // The first PT_LOAD starts with the file header
+//! assert_eq!(image.get(0x400000, 4), Some(b"\x7fELF".as_slice()));
+//! ```
+use core::ops::Range;
+
+/// The loaded contents of an object's PT_LOAD segments within a range of virtual addresses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryImage {
+    /// The virtual address of the first byte of `data`
+    pub base: u64,
+    /// The memory contents from `base` onwards
+    pub data: Vec<u8>,
+    /// The object's entry point ([e_entry](crate::file::FileHeader#structfield.e_entry))
+    pub entry: u64,
+}
+
+impl MemoryImage {
+    /// The range of virtual addresses the image covers
+    pub fn range(&self) -> Range<u64> {
+        self.base..self.base + self.data.len() as u64
+    }
+
+    /// Get the `len` bytes of the image at the given virtual address, or None if they aren't
+    /// all within the image.
+    pub fn get(&self, vaddr: u64, len: usize) -> Option<&[u8]> {
+        let start: usize = vaddr.checked_sub(self.base)?.try_into().ok()?;
+        self.data.get(start..start.checked_add(len)?)
+    }
+}
@@ -0,0 +1,140 @@
+//! Parsing the constructor and destructor arrays: [SHT_INIT_ARRAY](crate::abi::SHT_INIT_ARRAY),
+//! [SHT_FINI_ARRAY](crate::abi::SHT_FINI_ARRAY), and
+//! [SHT_PREINIT_ARRAY](crate::abi::SHT_PREINIT_ARRAY)
+//!
+//! Each of these sections holds an array of native-word sized function pointers, which the
+//! dynamic linker (or the C runtime for static executables) calls on startup or exit.
+//!
+//! Note that position independent objects often leave these entries as 0 in the file and rely
+//! on relative relocations (e.g. R_RISCV_RELATIVE) to fill in the pointers at load time, in
+//! which case the real addresses are the relocations' addends.
+//!
+//! Example for listing an object's constructors:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let init_array = file
+//!     .init_array()
+//!     .expect("section table should be parseable")
+//!     .expect("file should have a .init_array section");
+//! let (symtab, strtab) = file
+//!     .symbol_table()
+//!     .expect("symtab should be parseable")
+//!     .expect("file should have a symtab");
+//!
+//! for func in init_array.iter() {
+//!     let name = func
+//!         .resolve(&symtab, &strtab)
+//!         .expect("symbols should be parseable")
+//!         .map(|(_, name)| name);
+//!     println!("{:#x}: {:?}", func.addr, name);
+//! }
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolTable};
+
+pub type FunctionArray<'data, E> = ParsingTable<'data, E, FunctionPointer>;
+
+/// A function pointer from a `.init_array`, `.fini_array`, or `.preinit_array` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionPointer {
+    /// The function's virtual address
+    pub addr: u64,
+}
+
+impl FunctionPointer {
+    /// Find the name of the defined function symbol (if any) at this function pointer's
+    /// address in the given symbol table.
+    ///
+    /// Function pointers with the special values 0 and -1, which are sometimes used as
+    /// placeholders or terminators, are never resolved.
+    pub fn resolve<'data, E: EndianParse>(
+        &self,
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+    ) -> Result<Option<(Symbol, &'data str)>, ParseError> {
+        if self.addr == 0 || self.addr == u64::MAX || self.addr == u32::MAX as u64 {
+            return Ok(None);
+        }
+
+        for sym in symtab.iter() {
+            if sym.st_value != self.addr
+                || sym.is_undefined()
+                || !matches!(sym.st_symtype(), abi::STT_FUNC | abi::STT_GNU_IFUNC)
+            {
+                continue;
+            }
+
+            let name = strtab.get(sym.st_name as usize)?;
+            if !name.is_empty() {
+                return Ok(Some((sym, name)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl ParseAt for FunctionPointer {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let addr = match class {
+            Class::ELF32 => endian.parse_u32_at(offset, data)? as u64,
+            Class::ELF64 => endian.parse_u64_at(offset, data)?,
+        };
+        Ok(FunctionPointer { addr })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
+
+    #[test]
+    fn parse_function_pointer32_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF32,
+            FunctionPointer { addr: 0x03020100 },
+        );
+    }
+
+    #[test]
+    fn parse_function_pointer64_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF64,
+            FunctionPointer {
+                addr: 0x0001020304050607,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_function_pointer_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, FunctionPointer>(LittleEndian, Class::ELF32);
+        test_parse_fuzz_too_short::<_, FunctionPointer>(BigEndian, Class::ELF64);
+    }
+}
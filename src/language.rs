@@ -0,0 +1,133 @@
+//! Heuristically guessing which source language an ELF object was compiled from
+//!
+//! See [ElfBytes::guess_language](crate::ElfBytes::guess_language).
+
+/// The likely source language of an ELF object.
+///
+/// This is only a heuristic based on toolchain-specific section names and symbol
+/// mangling schemes, so it can be fooled by objects that mix languages or have been
+/// stripped. The variants are ordered by precedence: when signals for several languages
+/// are present, the greater variant wins (e.g. Rust binaries commonly link in C and C++
+/// code, but C code rarely contains Rust-mangled symbols).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LanguageGuess {
+    /// No signal was found, e.g. because the object is stripped of all symbols.
+    Unknown,
+    /// C, or another language which doesn't leave a distinctive signal (such as assembly).
+    ///
+    /// Signal: named function symbols, none of which are mangled.
+    C,
+    /// C++
+    ///
+    /// Signals: Itanium ABI mangled symbols (`_Z`), or a `.gcc_except_table` section.
+    Cpp,
+    /// Rust
+    ///
+    /// Signals: a `.note.rustc` or `.rustc` section, v0 mangled symbols (`_R`), legacy
+    /// mangled symbols with a trailing hash (`_ZN...17h<16 hex digits>E`), or well-known
+    /// runtime symbols like `rust_begin_unwind`.
+    Rust,
+    /// Go
+    ///
+    /// Signals: a `.note.go.buildid`, `.go.buildinfo`, or `.gopclntab` section.
+    Go,
+}
+
+/// Guess the language signalled by a section's name.
+pub(crate) fn guess_from_section_name(name: &[u8]) -> LanguageGuess {
+    match name {
+        b".note.go.buildid" | b".go.buildinfo" | b".gopclntab" => LanguageGuess::Go,
+        b".note.rustc" | b".rustc" => LanguageGuess::Rust,
+        b".gcc_except_table" => LanguageGuess::Cpp,
+        _ => LanguageGuess::Unknown,
+    }
+}
+
+/// Guess the language signalled by a symbol's name.
+///
+/// Names without a language-specific mangling are not a signal on their own, so this
+/// returns [LanguageGuess::Unknown] for them and leaves it to the caller to decide if
+/// the symbol is a plain C function.
+pub(crate) fn guess_from_symbol_name(name: &[u8]) -> LanguageGuess {
+    if is_rust_v0_mangled(name)
+        || is_rust_legacy_mangled(name)
+        || name == b"rust_begin_unwind"
+        || name == b"rust_eh_personality"
+        || name.starts_with(b"__rust_")
+    {
+        LanguageGuess::Rust
+    } else if name.starts_with(b"_Z") {
+        LanguageGuess::Cpp
+    } else {
+        LanguageGuess::Unknown
+    }
+}
+
+fn is_rust_v0_mangled(name: &[u8]) -> bool {
+    // _R [<decimal-number>] <path>, where all paths start with an uppercase tag
+    match name.strip_prefix(b"_R") {
+        Some([first, ..]) => first.is_ascii_uppercase() || first.is_ascii_digit(),
+        _ => false,
+    }
+}
+
+fn is_rust_legacy_mangled(name: &[u8]) -> bool {
+    // Legacy rust symbols are itanium-style nested names whose last component is a hash:
+    // _ZN <components> 17h <16 hex digits> E
+    if !name.starts_with(b"_ZN") || name.len() < 3 + 20 {
+        return false;
+    }
+    let hash = &name[name.len() - 20..];
+    hash.starts_with(b"17h")
+        && hash.ends_with(b"E")
+        && hash[3..19].iter().all(|b| b.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_names() {
+        assert_eq!(guess_from_section_name(b".gopclntab"), LanguageGuess::Go);
+        assert_eq!(
+            guess_from_section_name(b".note.go.buildid"),
+            LanguageGuess::Go
+        );
+        assert_eq!(guess_from_section_name(b".note.rustc"), LanguageGuess::Rust);
+        assert_eq!(
+            guess_from_section_name(b".gcc_except_table"),
+            LanguageGuess::Cpp
+        );
+        assert_eq!(guess_from_section_name(b".text"), LanguageGuess::Unknown);
+    }
+
+    #[test]
+    fn symbol_names() {
+        assert_eq!(
+            guess_from_symbol_name(b"_RNvCs1234_7mycrate4main"),
+            LanguageGuess::Rust
+        );
+        assert_eq!(
+            guess_from_symbol_name(b"_ZN3std2rt10lang_start17h0123456789abcdefE"),
+            LanguageGuess::Rust
+        );
+        assert_eq!(guess_from_symbol_name(b"__rust_alloc"), LanguageGuess::Rust);
+        assert_eq!(
+            guess_from_symbol_name(b"_ZNSt6vectorIiSaIiEE9push_backERKi"),
+            LanguageGuess::Cpp
+        );
+        assert_eq!(guess_from_symbol_name(b"_Z3fooi"), LanguageGuess::Cpp);
+        assert_eq!(guess_from_symbol_name(b"_R"), LanguageGuess::Unknown);
+        assert_eq!(guess_from_symbol_name(b"memset"), LanguageGuess::Unknown);
+        assert_eq!(guess_from_symbol_name(b""), LanguageGuess::Unknown);
+    }
+
+    #[test]
+    fn precedence() {
+        assert!(LanguageGuess::Go > LanguageGuess::Rust);
+        assert!(LanguageGuess::Rust > LanguageGuess::Cpp);
+        assert!(LanguageGuess::Cpp > LanguageGuess::C);
+        assert!(LanguageGuess::C > LanguageGuess::Unknown);
+    }
+}
@@ -51,6 +51,9 @@
 //! ### ✨ Some zero-copy interfaces ✨
 //! The StringTable, for instance, yields `&[u8]` and `&str` backed by the raw string table bytes.
 //!
+//! Likewise, [ElfBytes::section_data] and [ElfBytes::segment_data] return `&'data [u8]` slices
+//! into the caller's input buffer, so parsing a large object never duplicates its contents.
+//!
 //! The [ElfBytes] parser type also does not make raw copies of the underlying file data to back
 //! the parser lazy parser interfaces `ParsingIterator` and `ParsingTable`. They merely wrap byte slices
 //! internally, and yield rust repr values on demand, which does entail copying of the bytes into the
@@ -67,6 +70,7 @@
 //! ### ✨ Stream-based lazy i/o interface ✨
 //! The [ElfStream] parser type takes a `std:: Read + Seek` (such as `std::fs::File`) where ranges of
 //! file contents are read lazily on-demand based on what the user wants to parse.
+//! Forward-only readers like stdin or a pipe can be parsed by wrapping them in a [SpooledReader].
 //!
 //! This, alongside the bytes-oriented interface, allow you to decide which tradeoffs
 //! you want to make. If you're going to be working with the whole file contents,
@@ -76,9 +80,42 @@
 //! overhead of reading a bunch of unused file data just to parse out a few things, (like
 //! grabbing the `.gnu.note.build-id`)
 //!
-//! ### ✨ Tiny library with no dependencies and fast compilation times ✨
+//! Memory-mapped files are also supported by way of the bytes-oriented interface: since [ElfBytes]
+//! parses any `&[u8]`, a mapping such as a `memmap2::Mmap` (which derefs to `[u8]`) can be passed
+//! straight to [ElfBytes::minimal_parse], and section data is then served from the mapping
+//! without copies.
+//!
+//! There is deliberately no `mmap` feature or `open_mmap` constructor: this crate is
+//! `#![forbid(unsafe_code)]`, and creating a mapping is unsafe since the file can be changed
+//! out from under it. Instead, map the file in the calling code, where that guarantee can be
+//! upheld, and parse the mapped bytes:
+//! ```ignore
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let file = std::fs::File::open("sample-objects/basic.x86_64").expect("Could not open file.");
+//! // Safety: the caller must ensure the file isn't modified while it's mapped
+//! let mmap = unsafe { memmap2::Mmap::map(&file) }.expect("Could not map file.");
+//! let elf = ElfBytes::<AnyEndian>::minimal_parse(&mmap).expect("Open test1");
+//! let text = elf.section_header_by_name(".text").expect("should parse").unwrap();
+//! // This is a subslice of the mapping, not a copy
+//! let (text_data, _) = elf.section_data(&text).expect("should read .text");
+//! ```
+//!
+//! ### ✨ Writing modified objects ✨
+//! With the `std` feature, the [writer::ElfFile] type copies a parsed object into an owned,
+//! mutable representation which can be serialized back to a `std:: Write + Seek`, and the
+//! [builder::ElfBuilder] type constructs new objects from scratch.
+//!
+//! ### ✨ Tiny library with no required dependencies and fast compilation times ✨
 //! Release-target compilation times on this developer's 2021 m1 macbook are sub-second.
 //!
+//! The only (optional) dependencies are `miniz_oxide`, `ruzstd` and `lzma-rs`, which are pulled in by
+//! the `zlib`, `zstd` and `xz` features for decompressing compressed sections and MiniDebugInfo;
+//! `rustc-demangle` and `cpp_demangle`, which are pulled in by the `demangle` feature; `serde`,
+//! which the `serde` feature pulls in for serializing the parsed headers, symbols and relocations;
+//! and `tokio`, which the `tokio` feature pulls in for parsing streams asynchronously with `AsyncElfStream`.
+//!
 //! Example using [ElfBytes]:
 //! ```
 //! use elf::ElfBytes;
@@ -133,18 +170,57 @@
 
 pub mod abi;
 
+pub mod arm;
+pub mod attributes;
+pub mod avr;
+#[cfg(feature = "std")]
+pub mod builder;
 pub mod compression;
+pub mod debug;
+pub mod debuglink;
+#[cfg(feature = "demangle")]
+pub mod demangle;
+#[cfg(feature = "std")]
+pub mod deps;
 pub mod dynamic;
+pub mod ehframe;
 pub mod file;
 pub mod gnu_symver;
+pub mod gopclntab;
+pub mod group;
 pub mod hash;
+#[cfg(feature = "std")]
+pub mod image;
+pub mod init_array;
+pub mod language;
+#[cfg(feature = "std")]
+pub mod lint;
+pub mod loongarch;
+#[cfg(feature = "xz")]
+pub mod minidebuginfo;
+pub mod mips;
+pub mod msp430;
 pub mod note;
+pub mod plt;
+pub mod ppc64;
 pub mod relocation;
+#[cfg(feature = "std")]
+pub mod report;
+pub mod riscv;
 pub mod section;
+#[cfg(feature = "std")]
+pub mod security;
 pub mod segment;
 pub mod string_table;
 pub mod symbol;
+#[cfg(feature = "std")]
+pub mod writer;
+pub mod xtensa;
 
+#[cfg(feature = "to_str")]
+pub mod display;
+#[cfg(feature = "to_str")]
+pub mod machine;
 #[cfg(feature = "to_str")]
 pub mod to_str;
 
@@ -159,5 +235,15 @@ pub use elf_bytes::ElfBytes;
 mod elf_stream;
 #[cfg(feature = "std")]
 pub use elf_stream::ElfStream;
+#[cfg(feature = "std")]
+pub use elf_stream::SpooledReader;
+
+#[cfg(feature = "tokio")]
+mod elf_stream_async;
+#[cfg(feature = "tokio")]
+pub use elf_stream_async::AsyncElfStream;
 
 pub use parse::ParseError;
+
+mod options;
+pub use options::OpenOptions;
@@ -0,0 +1,256 @@
+//! Sanity-checking an ELF object for structural problems
+//!
+//! [check] looks for inconsistencies which the parser tolerates but which a loader or linker
+//! would trip over: PT_LOAD segments whose memory images overlap, sections or segments whose
+//! data extends past the end of the file, sh_link fields which don't name a section, loadable
+//! segments whose p_vaddr and p_offset aren't congruent modulo p_align, and symbol, relocation,
+//! and dynamic tables with a zero sh_entsize. Violations in the [FileHeader](crate::file::FileHeader)
+//! itself are included as well (see [FileHeader::validate](crate::file::FileHeader::validate)).
+//!
+//! Each problem is reported as a typed [Finding], so it can be both matched on by downstream
+//! loaders that want to reject such inputs and printed as a human readable message.
+//!
+//! This module is only available with the `std` cargo feature.
+//!
+//! Example for rejecting malformed objects:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let findings = elf::lint::check(&file).expect("tables should be parseable");
+//! for finding in &findings {
+//!     println!("{finding}");
+//! }
+//! assert!(findings.is_empty());
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::HeaderViolation;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+
+/// A structural problem found by [check]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// The file header has a problem.
+    Header(HeaderViolation),
+    /// Two PT_LOAD segments' memory images overlap. Contains the two segments' indices.
+    OverlappingLoadSegments((usize, usize)),
+    /// A loadable segment's p_vaddr and p_offset aren't congruent modulo its p_align, so it
+    /// can't be mapped. Contains the segment's index.
+    MisalignedSegment(usize),
+    /// A loadable segment's p_filesz is larger than its p_memsz. Contains the segment's index.
+    FileszExceedsMemsz(usize),
+    /// A segment's file data extends past the end of the file.
+    /// Contains the segment's index and the end offset of its data.
+    SegmentPastEof((usize, u64)),
+    /// A section's file data extends past the end of the file.
+    /// Contains the section's index and the end offset of its data.
+    SectionPastEof((usize, u64)),
+    /// A section's sh_link isn't the index of a section.
+    /// Contains the section's index and its sh_link.
+    SectionLinkOutOfRange((usize, u32)),
+    /// A non-empty section holding a table of fixed size entries (e.g. SHT_SYMTAB or SHT_RELA)
+    /// has a zero sh_entsize. Contains the section's index.
+    ZeroEntsize(usize),
+}
+
+impl core::fmt::Display for Finding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Finding::Header(violation) => write!(f, "{violation}"),
+            Finding::OverlappingLoadSegments((first, second)) => {
+                write!(f, "PT_LOAD segments {first} and {second} overlap in memory")
+            }
+            Finding::MisalignedSegment(index) => {
+                write!(
+                    f,
+                    "Segment {index} has p_vaddr and p_offset which aren't congruent modulo p_align"
+                )
+            }
+            Finding::FileszExceedsMemsz(index) => {
+                write!(f, "Segment {index} has a p_filesz larger than its p_memsz")
+            }
+            Finding::SegmentPastEof((index, end)) => {
+                write!(
+                    f,
+                    "Segment {index} extends past the end of the file to {end:#X}"
+                )
+            }
+            Finding::SectionPastEof((index, end)) => {
+                write!(
+                    f,
+                    "Section {index} extends past the end of the file to {end:#X}"
+                )
+            }
+            Finding::SectionLinkOutOfRange((index, link)) => {
+                write!(f, "Section {index} has an out of range sh_link: {link}")
+            }
+            Finding::ZeroEntsize(index) => {
+                write!(f, "Section {index} holds a table but has a zero sh_entsize")
+            }
+        }
+    }
+}
+
+/// Section types which hold a table of fixed size entries, and so need a non-zero sh_entsize
+const TABLE_SECTION_TYPES: [u32; 7] = [
+    abi::SHT_SYMTAB,
+    abi::SHT_DYNSYM,
+    abi::SHT_REL,
+    abi::SHT_RELA,
+    abi::SHT_DYNAMIC,
+    abi::SHT_SYMTAB_SHNDX,
+    abi::SHT_GNU_VERSYM,
+];
+
+/// Check the given object for structural problems, returning every [Finding] in the order of
+/// the file header, then segments, then sections.
+///
+/// Returns an error if the segment or section header tables themselves can't be parsed.
+pub fn check<E: EndianParse>(file: &ElfBytes<'_, E>) -> Result<Vec<Finding>, ParseError> {
+    let file_size = file.data().len() as u64;
+    let mut findings: Vec<Finding> = file.ehdr.validate().map(Finding::Header).collect();
+
+    if let Some(phdrs) = file.segments() {
+        let loads: Vec<(usize, u64, u64)> = phdrs
+            .iter()
+            .enumerate()
+            .filter(|(_, phdr)| phdr.p_type == abi::PT_LOAD && phdr.p_memsz != 0)
+            .map(|(index, phdr)| {
+                let end = phdr.p_vaddr.saturating_add(phdr.p_memsz);
+                (index, phdr.p_vaddr, end)
+            })
+            .collect();
+        for (i, (first, start, end)) in loads.iter().enumerate() {
+            for (second, other_start, other_end) in &loads[i + 1..] {
+                if start < other_end && other_start < end {
+                    findings.push(Finding::OverlappingLoadSegments((*first, *second)));
+                }
+            }
+        }
+
+        for (index, phdr) in phdrs.iter().enumerate() {
+            if phdr.p_type == abi::PT_LOAD {
+                if phdr.p_align > 1 && phdr.p_vaddr % phdr.p_align != phdr.p_offset % phdr.p_align {
+                    findings.push(Finding::MisalignedSegment(index));
+                }
+                if phdr.p_filesz > phdr.p_memsz {
+                    findings.push(Finding::FileszExceedsMemsz(index));
+                }
+            }
+            match phdr.p_offset.checked_add(phdr.p_filesz) {
+                Some(end) if end <= file_size => {}
+                end => findings.push(Finding::SegmentPastEof((index, end.unwrap_or(u64::MAX)))),
+            }
+        }
+    }
+
+    if let Some(shdrs) = file.section_headers() {
+        let shnum = shdrs.len();
+        for (index, shdr) in shdrs.iter().enumerate() {
+            if shdr.sh_type != abi::SHT_NOBITS && shdr.sh_type != abi::SHT_NULL {
+                match shdr.sh_offset.checked_add(shdr.sh_size) {
+                    Some(end) if end <= file_size => {}
+                    end => findings.push(Finding::SectionPastEof((index, end.unwrap_or(u64::MAX)))),
+                }
+            }
+            if shdr.sh_link as usize >= shnum {
+                findings.push(Finding::SectionLinkOutOfRange((index, shdr.sh_link)));
+            }
+            if shdr.sh_size != 0
+                && shdr.sh_entsize == 0
+                && TABLE_SECTION_TYPES.contains(&shdr.sh_type)
+            {
+                findings.push(Finding::ZeroEntsize(index));
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn basic() -> Vec<u8> {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    #[test]
+    fn check_sample_objects() {
+        for name in [
+            "basic.x86_64",
+            "group.x86_64.o",
+            "symver.armhf.so",
+            "tls.x86_64.so",
+            "core.x86_64",
+            "shnum.x86_64",
+        ] {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            let file_data = std::fs::read(path).expect("Could not read file.");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+            assert_eq!(check(&file).expect("should check"), [], "{name}");
+        }
+    }
+
+    #[test]
+    fn check_finds_segment_problems() {
+        let mut file_data = basic();
+        // Move the second PT_LOAD (phdr 3) onto the first one's p_vaddr
+        let phdr3 = 64 + 3 * 56;
+        file_data[phdr3 + 16..phdr3 + 24].copy_from_slice(&0x400000u64.to_le_bytes());
+        // And give the PT_INTERP (phdr 1) a p_filesz running off the end of the file
+        let phdr1 = 64 + 56;
+        file_data[phdr1 + 32..phdr1 + 40].copy_from_slice(&0x100000u64.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let findings = check(&file).expect("should check");
+        assert_eq!(
+            findings,
+            [
+                Finding::OverlappingLoadSegments((2, 3)),
+                Finding::SegmentPastEof((1, 0x100200)),
+                Finding::MisalignedSegment(3),
+            ]
+        );
+        assert_eq!(
+            findings[0].to_string(),
+            "PT_LOAD segments 2 and 3 overlap in memory"
+        );
+    }
+
+    #[test]
+    fn check_finds_section_problems() {
+        let mut file_data = basic();
+        // Point .symtab (section 29) at a non-existent string table and zero its entsize
+        let shdr29 = 4800 + 29 * 64;
+        file_data[shdr29 + 40..shdr29 + 44].copy_from_slice(&99u32.to_le_bytes());
+        file_data[shdr29 + 56..shdr29 + 64].copy_from_slice(&0u64.to_le_bytes());
+        // And make .comment (section 27) run off the end of the file
+        let shdr27 = 4800 + 27 * 64;
+        file_data[shdr27 + 32..shdr27 + 40].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let findings = check(&file).expect("should check");
+        assert_eq!(
+            findings,
+            [
+                Finding::SectionPastEof((27, u64::MAX)),
+                Finding::SectionLinkOutOfRange((29, 99)),
+                Finding::ZeroEntsize(29),
+            ]
+        );
+        assert_eq!(
+            findings[1].to_string(),
+            "Section 29 has an out of range sh_link: 99"
+        );
+    }
+}
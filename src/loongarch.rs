@@ -0,0 +1,132 @@
+//! Decoding LoongArch specific header data: the
+//! [e_flags](crate::file::FileHeader#structfield.e_flags) of objects built for
+//! [EM_LOONGARCH](crate::abi::EM_LOONGARCH)
+//!
+//! The flags record the base ABI's floating point calling convention and the version of the
+//! object file ABI. Together with the ELF class they name the base ABI, e.g. LP64D for the
+//! 64-bit double-float ABI that general purpose Linux distributions use. Objects built for
+//! version 0 of the object file ABI use stack-based relocations which newer toolchains no
+//! longer understand.
+//!
+//! See [FileHeader::loongarch_flags](crate::file::FileHeader::loongarch_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{Class, EFlagsDecoder, FileHeader};
+
+/// The base ABI, named by the integer data model (from the ELF class) and the
+/// [EF_LARCH_ABI_MODIFIER_MASK](abi::EF_LARCH_ABI_MODIFIER_MASK) bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoongArchAbi {
+    /// 32-bit soft-float ABI
+    Ilp32S,
+    /// 32-bit single-float ABI
+    Ilp32F,
+    /// 32-bit double-float ABI
+    Ilp32D,
+    /// 64-bit soft-float ABI
+    Lp64S,
+    /// 64-bit single-float ABI
+    Lp64F,
+    /// 64-bit double-float ABI
+    Lp64D,
+    /// A reserved ABI modifier. Contains the EF_LARCH_ABI_MODIFIER_MASK bits.
+    Unknown(u32),
+}
+
+/// The decoded e_flags of a LoongArch object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoongArchFlags {
+    /// The base ABI the object was built for
+    pub abi: LoongArchAbi,
+    /// The version of the object file ABI, from the
+    /// [EF_LARCH_OBJABI_MASK](abi::EF_LARCH_OBJABI_MASK) bits
+    pub obj_abi_version: u8,
+}
+
+impl LoongArchFlags {
+    /// Decode the given e_flags value for an object of the given class. Bits which aren't
+    /// defined by the psABI are ignored.
+    pub fn from_e_flags(class: Class, e_flags: u32) -> Self {
+        let abi = match (e_flags & abi::EF_LARCH_ABI_MODIFIER_MASK, class) {
+            (abi::EF_LARCH_ABI_SOFT_FLOAT, Class::ELF32) => LoongArchAbi::Ilp32S,
+            (abi::EF_LARCH_ABI_SINGLE_FLOAT, Class::ELF32) => LoongArchAbi::Ilp32F,
+            (abi::EF_LARCH_ABI_DOUBLE_FLOAT, Class::ELF32) => LoongArchAbi::Ilp32D,
+            (abi::EF_LARCH_ABI_SOFT_FLOAT, Class::ELF64) => LoongArchAbi::Lp64S,
+            (abi::EF_LARCH_ABI_SINGLE_FLOAT, Class::ELF64) => LoongArchAbi::Lp64F,
+            (abi::EF_LARCH_ABI_DOUBLE_FLOAT, Class::ELF64) => LoongArchAbi::Lp64D,
+            (modifier, _) => LoongArchAbi::Unknown(modifier),
+        };
+        LoongArchFlags {
+            abi,
+            obj_abi_version: ((e_flags & abi::EF_LARCH_OBJABI_MASK) >> 6) as u8,
+        }
+    }
+}
+
+/// Formats the ABI like the psABI names it, e.g. "LP64D"
+impl core::fmt::Display for LoongArchAbi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LoongArchAbi::Ilp32S => write!(f, "ILP32S"),
+            LoongArchAbi::Ilp32F => write!(f, "ILP32F"),
+            LoongArchAbi::Ilp32D => write!(f, "ILP32D"),
+            LoongArchAbi::Lp64S => write!(f, "LP64S"),
+            LoongArchAbi::Lp64F => write!(f, "LP64F"),
+            LoongArchAbi::Lp64D => write!(f, "LP64D"),
+            LoongArchAbi::Unknown(modifier) => write!(f, "unknown ABI modifier {modifier:#x}"),
+        }
+    }
+}
+
+impl EFlagsDecoder for LoongArchFlags {
+    const E_MACHINE: u16 = abi::EM_LOONGARCH;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        LoongArchFlags::from_e_flags(ehdr.class, ehdr.e_flags)
+    }
+}
+
+/// Formats the flags, e.g. "LP64D, OBJ-v1"
+impl core::fmt::Display for LoongArchFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}, OBJ-v{}", self.abi, self.obj_abi_version)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            LoongArchFlags::from_e_flags(
+                Class::ELF64,
+                abi::EF_LARCH_ABI_DOUBLE_FLOAT | abi::EF_LARCH_OBJABI_V1
+            ),
+            LoongArchFlags {
+                abi: LoongArchAbi::Lp64D,
+                obj_abi_version: 1,
+            }
+        );
+        assert_eq!(
+            LoongArchFlags::from_e_flags(Class::ELF32, abi::EF_LARCH_ABI_SOFT_FLOAT),
+            LoongArchFlags {
+                abi: LoongArchAbi::Ilp32S,
+                obj_abi_version: 0,
+            }
+        );
+        assert_eq!(
+            LoongArchFlags::from_e_flags(Class::ELF64, 0x45).abi,
+            LoongArchAbi::Unknown(5)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let flags = LoongArchFlags::from_e_flags(Class::ELF64, 0x43);
+        assert_eq!(flags.to_string(), "LP64D, OBJ-v1");
+        let flags = LoongArchFlags::from_e_flags(Class::ELF32, abi::EF_LARCH_ABI_SINGLE_FLOAT);
+        assert_eq!(flags.to_string(), "ILP32F, OBJ-v0");
+    }
+}
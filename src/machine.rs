@@ -0,0 +1,187 @@
+//! Naming machine types which this crate doesn't know about: [MachineRegistry]
+//!
+//! Forks and downstream tools targeting a non-standard ISA (e.g. a zkVM with its own e_machine
+//! value) can register names for their machine and its relocation types, and then look names up
+//! through the registry instead of the [to_str] functions. Machines which aren't
+//! registered fall back to the names this crate knows.
+//!
+//! A registry can be carried with the rest of the options for opening an object through
+//! [OpenOptions::machines](crate::OpenOptions::machines), and then passed to the display code,
+//! e.g. [write_relas_with_machines](crate::display::write_relas_with_machines).
+//!
+//! This module is only available with the `to_str` cargo feature.
+//!
+//! ```
+//! use elf::machine::{Machine, MachineRegistry};
+//!
+//! const EM_VALIDA: u16 = 0x4000;
+//!
+//! let mut machines = MachineRegistry::new();
+//! machines.register(
+//!     EM_VALIDA,
+//!     Machine {
+//!         name: "EM_VALIDA",
+//!         description: "Valida zkVM",
+//!         relocations: &[(0, "R_VALIDA_NONE"), (1, "R_VALIDA_32")],
+//!     },
+//! );
+//!
+//! assert_eq!(machines.e_machine_to_human_str(EM_VALIDA), Some("Valida zkVM"));
+//! assert_eq!(machines.r_type_to_string(EM_VALIDA, 1), "R_VALIDA_32");
+//! assert_eq!(machines.r_type_to_string(EM_VALIDA, 2), "r_type(0x2)");
+//! // Machines this crate knows about still get their names
+//! assert_eq!(machines.e_machine_to_str(elf::abi::EM_X86_64), Some("EM_X86_64"));
+//! ```
+use crate::to_str;
+
+/// The names for a machine type and its relocation types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    /// The name of the machine's e_machine constant, e.g. "EM_X86_64"
+    pub name: &'static str,
+    /// A human readable description of the machine, e.g. "AMD x86-64 architecture"
+    pub description: &'static str,
+    /// The names of the machine's relocation types as (r_type, name) pairs
+    pub relocations: &'static [(u32, &'static str)],
+}
+
+/// A set of [Machine]s registered for e_machine values
+///
+/// Registered machines take precedence over the ones this crate knows about, so a standard
+/// machine can also be renamed or have its relocation names filled in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MachineRegistry {
+    machines: Vec<(u16, Machine)>,
+}
+
+impl MachineRegistry {
+    /// Create an empty registry, which names everything like the [to_str]
+    /// functions do.
+    pub fn new() -> Self {
+        MachineRegistry::default()
+    }
+
+    /// Register the names for the given e_machine value, replacing any earlier registration.
+    pub fn register(&mut self, e_machine: u16, machine: Machine) -> &mut Self {
+        match self.machines.iter_mut().find(|(em, _)| *em == e_machine) {
+            Some((_, registered)) => *registered = machine,
+            None => self.machines.push((e_machine, machine)),
+        }
+        self
+    }
+
+    /// Get the [Machine] registered for the given e_machine value, if any.
+    pub fn get(&self, e_machine: u16) -> Option<&Machine> {
+        self.machines
+            .iter()
+            .find(|(em, _)| *em == e_machine)
+            .map(|(_, machine)| machine)
+    }
+
+    /// Like [e_machine_to_str](to_str::e_machine_to_str), but checking the registered machines
+    /// first.
+    pub fn e_machine_to_str(&self, e_machine: u16) -> Option<&'static str> {
+        match self.get(e_machine) {
+            Some(machine) => Some(machine.name),
+            None => to_str::e_machine_to_str(e_machine),
+        }
+    }
+
+    /// Like [e_machine_to_human_str](to_str::e_machine_to_human_str), but checking the
+    /// registered machines first.
+    pub fn e_machine_to_human_str(&self, e_machine: u16) -> Option<&'static str> {
+        match self.get(e_machine) {
+            Some(machine) => Some(machine.description),
+            None => to_str::e_machine_to_human_str(e_machine),
+        }
+    }
+
+    /// Like [e_machine_to_string](to_str::e_machine_to_string), but checking the registered
+    /// machines first.
+    pub fn e_machine_to_string(&self, e_machine: u16) -> String {
+        match self.e_machine_to_str(e_machine) {
+            Some(s) => s.to_string(),
+            None => format!("e_machine({e_machine:#x})"),
+        }
+    }
+
+    /// Like [r_type_to_str](to_str::r_type_to_str), but checking the registered machine's
+    /// relocation names first.
+    pub fn r_type_to_str(&self, e_machine: u16, r_type: u32) -> Option<&'static str> {
+        self.get(e_machine)
+            .and_then(|machine| machine.relocations.iter().find(|(ty, _)| *ty == r_type))
+            .map(|(_, name)| *name)
+            .or_else(|| to_str::r_type_to_str(e_machine, r_type))
+    }
+
+    /// Like [r_type_to_string](to_str::r_type_to_string), but checking the registered machine's
+    /// relocation names first.
+    pub fn r_type_to_string(&self, e_machine: u16, r_type: u32) -> String {
+        match self.r_type_to_str(e_machine, r_type) {
+            Some(s) => s.to_string(),
+            None => format!("r_type({r_type:#x})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::abi;
+
+    const EM_TEST: u16 = 0x4000;
+
+    fn test_machine() -> Machine {
+        Machine {
+            name: "EM_TEST",
+            description: "Test machine",
+            relocations: &[(0, "R_TEST_NONE"), (7, "R_TEST_RELATIVE")],
+        }
+    }
+
+    #[test]
+    fn registered_machine_names() {
+        let mut machines = MachineRegistry::new();
+        machines.register(EM_TEST, test_machine());
+        assert_eq!(machines.e_machine_to_str(EM_TEST), Some("EM_TEST"));
+        assert_eq!(
+            machines.e_machine_to_human_str(EM_TEST),
+            Some("Test machine")
+        );
+        assert_eq!(machines.r_type_to_str(EM_TEST, 7), Some("R_TEST_RELATIVE"));
+        assert_eq!(machines.r_type_to_string(EM_TEST, 8), "r_type(0x8)");
+        assert_eq!(
+            machines.e_machine_to_string(EM_TEST + 1),
+            "e_machine(0x4001)"
+        );
+    }
+
+    #[test]
+    fn register_replaces_and_overrides() {
+        let mut machines = MachineRegistry::new();
+        machines
+            .register(EM_TEST, test_machine())
+            .register(
+                EM_TEST,
+                Machine {
+                    name: "EM_TEST2",
+                    ..test_machine()
+                },
+            )
+            .register(
+                abi::EM_X86_64,
+                Machine {
+                    name: "EM_AMD64",
+                    description: "AMD64",
+                    relocations: &[],
+                },
+            );
+        assert_eq!(machines.e_machine_to_str(EM_TEST), Some("EM_TEST2"));
+        assert_eq!(machines.e_machine_to_str(abi::EM_X86_64), Some("EM_AMD64"));
+        // Relocations which the registered machine doesn't name fall back to the builtin names
+        assert_eq!(
+            machines.r_type_to_str(abi::EM_X86_64, abi::R_X86_64_RELATIVE),
+            Some("R_X86_64_RELATIVE")
+        );
+    }
+}
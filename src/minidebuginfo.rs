@@ -0,0 +1,130 @@
+//! Extracting MiniDebugInfo: the xz-compressed ELF object embedded in a `.gnu_debugdata` section
+//!
+//! Some distributions (e.g. Fedora and RHEL) strip their binaries down to just the `.dynsym`, but
+//! embed a small xz-compressed ELF object in a `.gnu_debugdata` section whose `.symtab` contains
+//! the remaining function symbols, so that backtraces can still name local functions.
+//!
+//! This module is only available with the `xz` cargo feature.
+//!
+//! (see: <https://sourceware.org/gdb/current/onlinedocs/gdb.html/MiniDebugInfo.html>)
+use crate::endian::AnyEndian;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+
+/// Decompress xz-compressed data, such as the contents of a `.gnu_debugdata` section.
+///
+/// The compressed data comes from the file, so the decompressed size is bounded by `max_size`
+/// to guard against decompression bombs. Returns [ParseError::DecompressionError] if the data
+/// is corrupt or decompresses to more than `max_size` bytes.
+pub fn decompress_xz(compressed: &[u8], max_size: usize) -> Result<Vec<u8>, ParseError> {
+    let mut reader = compressed;
+    let mut writer = LimitedWriter {
+        buf: Vec::new(),
+        max_size,
+    };
+    lzma_rs::xz_decompress(&mut reader, &mut writer).map_err(|_| ParseError::DecompressionError)?;
+    Ok(writer.buf)
+}
+
+/// A writer which collects its output into a buffer, failing writes past `max_size` bytes.
+struct LimitedWriter {
+    buf: Vec<u8>,
+    max_size: usize,
+}
+
+impl std::io::Write for LimitedWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        if data.len() > self.max_size - self.buf.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "decompressed data exceeds the size limit",
+            ));
+        }
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The decompressed ELF object from a `.gnu_debugdata` section.
+///
+/// This owns the decompressed bytes, which can then be parsed with [MiniDebugInfo::elf].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiniDebugInfo {
+    data: Vec<u8>,
+}
+
+impl MiniDebugInfo {
+    /// Decompress the contents of a `.gnu_debugdata` section, checking that it holds an ELF object.
+    ///
+    /// Fails with [ParseError::DecompressionError] if it decompresses to more than `max_size`
+    /// bytes (see [decompress_xz]).
+    pub fn new(compressed: &[u8], max_size: usize) -> Result<Self, ParseError> {
+        let data = decompress_xz(compressed, max_size)?;
+        ElfBytes::<AnyEndian>::minimal_parse(&data)?;
+        Ok(MiniDebugInfo { data })
+    }
+
+    /// The decompressed ELF object's bytes
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Parse the embedded ELF object
+    pub fn elf(&self) -> Result<ElfBytes<'_, AnyEndian>, ParseError> {
+        ElfBytes::minimal_parse(&self.data)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    // "hello\n" compressed by `xz --check=crc32`
+    const HELLO_XZ: [u8; 68] = [
+        0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00, 0x00, 0x01, 0x69, 0x22, 0xde, 0x36, 0x04, 0xc0, 0x0a,
+        0x06, 0x21, 0x01, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x30,
+        0x8e, 0xa6, 0x01, 0x00, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x0a, 0x00, 0x00, 0x00, 0x20,
+        0x30, 0x3a, 0x36, 0x00, 0x01, 0x22, 0x06, 0x3e, 0x56, 0x57, 0x6e, 0x90, 0x42, 0x99, 0x0d,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x59, 0x5a,
+    ];
+
+    #[test]
+    fn decompress_xz_corrupt_data_errors() {
+        let err = decompress_xz(&[0xfd, b'7', b'z', b'X', b'Z', 0x00, 0x01], 1024)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn decompress_xz_over_max_size_errors() {
+        assert_eq!(
+            decompress_xz(&HELLO_XZ, 6).expect("should decompress"),
+            b"hello\n"
+        );
+        let err = decompress_xz(&HELLO_XZ, 5).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::DecompressionError),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn new_rejects_non_elf_data() {
+        assert_eq!(
+            decompress_xz(&HELLO_XZ, 1024).expect("should decompress"),
+            b"hello\n"
+        );
+        let err = MiniDebugInfo::new(&HELLO_XZ, 1024).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::SliceReadError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
@@ -0,0 +1,452 @@
+//! Parsing MIPS-specific sections: [SHT_MIPS_ABIFLAGS](crate::abi::SHT_MIPS_ABIFLAGS) and
+//! [SHT_MIPS_REGINFO](crate::abi::SHT_MIPS_REGINFO), and decoding the MIPS
+//! [e_flags](crate::file::FileHeader#structfield.e_flags)
+//!
+//! The `.MIPS.abiflags` section records the ISA level, floating point ABI, and application
+//! specific extensions (ASEs) that an object was built for, which is usually the quickest way
+//! to tell which flavor of MIPS a firmware image targets. The `.reginfo` section records which
+//! registers the object uses along with its initial `$gp` value.
+//!
+//! The e_flags are present even when those sections have been stripped, and record the
+//! architecture level, calling convention ABI (o32, n32, n64, ...), and ASEs the object needs.
+//!
+//! See [ElfBytes::mips_abiflags](crate::ElfBytes::mips_abiflags),
+//! [ElfBytes::mips_reginfo](crate::ElfBytes::mips_reginfo), and
+//! [FileHeader::mips_flags](crate::file::FileHeader::mips_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{Class, EFlagsDecoder, FileHeader};
+use crate::parse::{ParseAt, ParseError};
+
+/// The contents of a `.MIPS.abiflags` section (Elf_MIPS_ABIFlags_v0).
+///
+/// The structure has the same layout for ELF32 and ELF64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipsAbiFlags {
+    /// Version of the structure, see [MIPS_ABIFLAGS_VERSION](abi::MIPS_ABIFLAGS_VERSION)
+    pub version: u16,
+    /// The ISA level, e.g. 32 for MIPS32 or 64 for MIPS64
+    pub isa_level: u8,
+    /// The ISA revision, e.g. 2 for MIPS32r2
+    pub isa_rev: u8,
+    /// The size of the general purpose registers, see [MIPS_AFL_REG_32](abi::MIPS_AFL_REG_32)
+    pub gpr_size: u8,
+    /// The size of the co-processor 1 (floating point) registers
+    pub cpr1_size: u8,
+    /// The size of the co-processor 2 registers
+    pub cpr2_size: u8,
+    /// The floating point ABI, see [VAL_GNU_MIPS_ABI_FP_DOUBLE](abi::VAL_GNU_MIPS_ABI_FP_DOUBLE)
+    pub fp_abi: u8,
+    /// A processor-specific extension, see [MIPS_AFL_EXT_OCTEON](abi::MIPS_AFL_EXT_OCTEON)
+    pub isa_ext: u32,
+    /// A bitmask of the ASEs used, see [MIPS_AFL_ASE_DSP](abi::MIPS_AFL_ASE_DSP)
+    pub ases: u32,
+    /// See [MIPS_AFL_FLAGS1_ODDSPREG](abi::MIPS_AFL_FLAGS1_ODDSPREG)
+    pub flags1: u32,
+    pub flags2: u32,
+}
+
+impl MipsAbiFlags {
+    /// Parse the contents of a `.MIPS.abiflags` section.
+    ///
+    /// Returns [ParseError::UnsupportedVersion] if the structure isn't version
+    /// [MIPS_ABIFLAGS_VERSION](abi::MIPS_ABIFLAGS_VERSION).
+    pub fn parse<E: EndianParse>(endian: E, data: &[u8]) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        // The layout doesn't depend on the class
+        let flags = MipsAbiFlags::parse_at(endian, Class::ELF32, &mut offset, data)?;
+        if flags.version != abi::MIPS_ABIFLAGS_VERSION {
+            return Err(ParseError::UnsupportedVersion((
+                flags.version as u64,
+                abi::MIPS_ABIFLAGS_VERSION as u64,
+            )));
+        }
+        Ok(flags)
+    }
+
+    /// Returns true if all of the given [MIPS_AFL_ASE_*](abi::MIPS_AFL_ASE_DSP) bits are set
+    pub fn has_ases(&self, ases: u32) -> bool {
+        self.ases & ases == ases
+    }
+}
+
+impl ParseAt for MipsAbiFlags {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        Ok(MipsAbiFlags {
+            version: endian.parse_u16_at(offset, data)?,
+            isa_level: endian.parse_u8_at(offset, data)?,
+            isa_rev: endian.parse_u8_at(offset, data)?,
+            gpr_size: endian.parse_u8_at(offset, data)?,
+            cpr1_size: endian.parse_u8_at(offset, data)?,
+            cpr2_size: endian.parse_u8_at(offset, data)?,
+            fp_abi: endian.parse_u8_at(offset, data)?,
+            isa_ext: endian.parse_u32_at(offset, data)?,
+            ases: endian.parse_u32_at(offset, data)?,
+            flags1: endian.parse_u32_at(offset, data)?,
+            flags2: endian.parse_u32_at(offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(_class: Class) -> usize {
+        24
+    }
+}
+
+/// The contents of a `.reginfo` section (Elf32_RegInfo or Elf64_RegInfo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipsRegInfo {
+    /// A bitmask of the general purpose registers used by the object
+    pub gprmask: u32,
+    /// Bitmasks of the registers used by the object for each of co-processors 0-3
+    pub cprmask: [u32; 4],
+    /// The initial value of the `$gp` register
+    pub gp_value: i64,
+}
+
+impl ParseAt for MipsRegInfo {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let gprmask = endian.parse_u32_at(offset, data)?;
+        if class == Class::ELF64 {
+            // ri_pad
+            endian.parse_u32_at(offset, data)?;
+        }
+
+        let mut cprmask = [0u32; 4];
+        for mask in cprmask.iter_mut() {
+            *mask = endian.parse_u32_at(offset, data)?;
+        }
+
+        let gp_value = match class {
+            Class::ELF32 => endian.parse_i32_at(offset, data)? as i64,
+            Class::ELF64 => endian.parse_i64_at(offset, data)?,
+        };
+        Ok(MipsRegInfo {
+            gprmask,
+            cprmask,
+            gp_value,
+        })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 24,
+            Class::ELF64 => 32,
+        }
+    }
+}
+
+/// The architecture level selected by the [EF_MIPS_ARCH](abi::EF_MIPS_ARCH) bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MipsArch {
+    Mips1,
+    Mips2,
+    Mips3,
+    Mips4,
+    Mips5,
+    Mips32,
+    Mips64,
+    Mips32r2,
+    Mips64r2,
+    Mips32r6,
+    Mips64r6,
+    /// An architecture level not known to this crate. Contains the EF_MIPS_ARCH bits.
+    Unknown(u32),
+}
+
+/// The calling convention ABI of a MIPS object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MipsAbi {
+    /// The original 32-bit ABI ([E_MIPS_ABI_O32](abi::E_MIPS_ABI_O32))
+    O32,
+    /// The new 32-bit ABI for 64-bit processors ([EF_MIPS_ABI2](abi::EF_MIPS_ABI2))
+    N32,
+    /// The 64-bit ABI, used by ELFCLASS64 objects
+    N64,
+    /// o32 extended for 64-bit registers ([E_MIPS_ABI_O64](abi::E_MIPS_ABI_O64))
+    O64,
+    /// The 32-bit embedded ABI ([E_MIPS_ABI_EABI32](abi::E_MIPS_ABI_EABI32))
+    Eabi32,
+    /// The 64-bit embedded ABI ([E_MIPS_ABI_EABI64](abi::E_MIPS_ABI_EABI64))
+    Eabi64,
+    /// An ABI not known to this crate. Contains the [EF_MIPS_ABI](abi::EF_MIPS_ABI) bits.
+    Unknown(u32),
+}
+
+/// The decoded e_flags of a MIPS object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipsFlags {
+    /// The architecture level the object was built for
+    pub arch: MipsArch,
+    /// The calling convention ABI the object was built for
+    pub abi: MipsAbi,
+    /// A bitmask of the ASEs used, see [EF_MIPS_ARCH_ASE_M16](abi::EF_MIPS_ARCH_ASE_M16)
+    pub ases: u32,
+    /// A .noreorder directive was used ([EF_MIPS_NOREORDER](abi::EF_MIPS_NOREORDER))
+    pub noreorder: bool,
+    /// Contains position independent code ([EF_MIPS_PIC](abi::EF_MIPS_PIC))
+    pub pic: bool,
+    /// Uses PIC calling sequences ([EF_MIPS_CPIC](abi::EF_MIPS_CPIC))
+    pub cpic: bool,
+    /// Uses 64-bit floating point registers in a 32-bit ABI ([EF_MIPS_FP64](abi::EF_MIPS_FP64))
+    pub fp64: bool,
+    /// Uses IEEE 754-2008 NaN encoding ([EF_MIPS_NAN2008](abi::EF_MIPS_NAN2008))
+    pub nan2008: bool,
+}
+
+impl MipsFlags {
+    /// Decode the given e_flags value for an object of the given class.
+    ///
+    /// The class is needed because n64 objects don't set any ABI bits. ELFCLASS32 objects
+    /// from older toolchains don't either, and are decoded as [MipsAbi::O32].
+    pub fn from_e_flags(class: Class, e_flags: u32) -> Self {
+        let arch = match e_flags & abi::EF_MIPS_ARCH {
+            abi::EF_MIPS_ARCH_1 => MipsArch::Mips1,
+            abi::EF_MIPS_ARCH_2 => MipsArch::Mips2,
+            abi::EF_MIPS_ARCH_3 => MipsArch::Mips3,
+            abi::EF_MIPS_ARCH_4 => MipsArch::Mips4,
+            abi::EF_MIPS_ARCH_5 => MipsArch::Mips5,
+            abi::EF_MIPS_ARCH_32 => MipsArch::Mips32,
+            abi::EF_MIPS_ARCH_64 => MipsArch::Mips64,
+            abi::EF_MIPS_ARCH_32R2 => MipsArch::Mips32r2,
+            abi::EF_MIPS_ARCH_64R2 => MipsArch::Mips64r2,
+            abi::EF_MIPS_ARCH_32R6 => MipsArch::Mips32r6,
+            abi::EF_MIPS_ARCH_64R6 => MipsArch::Mips64r6,
+            arch => MipsArch::Unknown(arch),
+        };
+        let abi = match (e_flags & abi::EF_MIPS_ABI, class) {
+            _ if e_flags & abi::EF_MIPS_ABI2 != 0 => MipsAbi::N32,
+            (0, Class::ELF32) | (abi::E_MIPS_ABI_O32, _) => MipsAbi::O32,
+            (0, Class::ELF64) => MipsAbi::N64,
+            (abi::E_MIPS_ABI_O64, _) => MipsAbi::O64,
+            (abi::E_MIPS_ABI_EABI32, _) => MipsAbi::Eabi32,
+            (abi::E_MIPS_ABI_EABI64, _) => MipsAbi::Eabi64,
+            (abi, _) => MipsAbi::Unknown(abi),
+        };
+        MipsFlags {
+            arch,
+            abi,
+            ases: e_flags & abi::EF_MIPS_ARCH_ASE,
+            noreorder: e_flags & abi::EF_MIPS_NOREORDER != 0,
+            pic: e_flags & abi::EF_MIPS_PIC != 0,
+            cpic: e_flags & abi::EF_MIPS_CPIC != 0,
+            fp64: e_flags & abi::EF_MIPS_FP64 != 0,
+            nan2008: e_flags & abi::EF_MIPS_NAN2008 != 0,
+        }
+    }
+
+    /// Returns true if all of the given [EF_MIPS_ARCH_ASE_*](abi::EF_MIPS_ARCH_ASE_M16) bits are set
+    pub fn has_ases(&self, ases: u32) -> bool {
+        self.ases & ases == ases
+    }
+}
+
+impl EFlagsDecoder for MipsFlags {
+    const E_MACHINE: u16 = abi::EM_MIPS;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        MipsFlags::from_e_flags(ehdr.class, ehdr.e_flags)
+    }
+}
+
+/// Formats the flags like readelf, e.g. "noreorder, pic, cpic, o32, mips32r2"
+impl core::fmt::Display for MipsFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let flags = [
+            (self.noreorder, "noreorder"),
+            (self.pic, "pic"),
+            (self.cpic, "cpic"),
+            (self.nan2008, "nan2008"),
+            (self.fp64, "fp64"),
+        ];
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            write!(f, "{name}, ")?;
+        }
+        match self.abi {
+            MipsAbi::O32 => write!(f, "o32")?,
+            MipsAbi::N32 => write!(f, "n32")?,
+            MipsAbi::N64 => write!(f, "n64")?,
+            MipsAbi::O64 => write!(f, "o64")?,
+            MipsAbi::Eabi32 => write!(f, "eabi32")?,
+            MipsAbi::Eabi64 => write!(f, "eabi64")?,
+            MipsAbi::Unknown(abi) => write!(f, "unknown ABI {abi:#x}")?,
+        }
+        let ases = [
+            (abi::EF_MIPS_ARCH_ASE_MDMX, "mdmx"),
+            (abi::EF_MIPS_ARCH_ASE_M16, "mips16"),
+            (abi::EF_MIPS_ARCH_ASE_MICROMIPS, "micromips"),
+        ];
+        for (_, name) in ases.iter().filter(|(ase, _)| self.has_ases(*ase)) {
+            write!(f, ", {name}")?;
+        }
+        match self.arch {
+            MipsArch::Mips1 => write!(f, ", mips1"),
+            MipsArch::Mips2 => write!(f, ", mips2"),
+            MipsArch::Mips3 => write!(f, ", mips3"),
+            MipsArch::Mips4 => write!(f, ", mips4"),
+            MipsArch::Mips5 => write!(f, ", mips5"),
+            MipsArch::Mips32 => write!(f, ", mips32"),
+            MipsArch::Mips64 => write!(f, ", mips64"),
+            MipsArch::Mips32r2 => write!(f, ", mips32r2"),
+            MipsArch::Mips64r2 => write!(f, ", mips64r2"),
+            MipsArch::Mips32r6 => write!(f, ", mips32r6"),
+            MipsArch::Mips64r6 => write!(f, ", mips64r6"),
+            MipsArch::Unknown(arch) => write!(f, ", unknown ISA {arch:#x}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
+
+    #[test]
+    fn parse_abiflags_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF32,
+            MipsAbiFlags {
+                version: 0x0001,
+                isa_level: 0x02,
+                isa_rev: 0x03,
+                gpr_size: 0x04,
+                cpr1_size: 0x05,
+                cpr2_size: 0x06,
+                fp_abi: 0x07,
+                isa_ext: 0x08090A0B,
+                ases: 0x0C0D0E0F,
+                flags1: 0x10111213,
+                flags2: 0x14151617,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_abiflags_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsAbiFlags>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn parse_abiflags_checks_version() {
+        #[rustfmt::skip]
+        let mut data = [
+            0x00, 0x00, 32, 2, abi::MIPS_AFL_REG_32, abi::MIPS_AFL_REG_64, abi::MIPS_AFL_REG_NONE,
+            abi::VAL_GNU_MIPS_ABI_FP_XX,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x02, 0x01,
+            0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let flags = MipsAbiFlags::parse(BigEndian, &data).expect("should parse");
+        assert_eq!(flags.isa_level, 32);
+        assert_eq!(flags.isa_rev, 2);
+        assert_eq!(flags.fp_abi, abi::VAL_GNU_MIPS_ABI_FP_XX);
+        assert!(flags.has_ases(abi::MIPS_AFL_ASE_DSP | abi::MIPS_AFL_ASE_MSA));
+        assert!(!flags.has_ases(abi::MIPS_AFL_ASE_MIPS16));
+
+        data[1] = 1;
+        let err = MipsAbiFlags::parse(BigEndian, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnsupportedVersion((1, 0))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_reginfo32_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF32,
+            MipsRegInfo {
+                gprmask: 0x03020100,
+                cprmask: [0x07060504, 0x0B0A0908, 0x0F0E0D0C, 0x13121110],
+                gp_value: 0x17161514,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_reginfo64_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF64,
+            MipsRegInfo {
+                gprmask: 0x00010203,
+                cprmask: [0x08090A0B, 0x0C0D0E0F, 0x10111213, 0x14151617],
+                gp_value: 0x18191A1B1C1D1E1F,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_reginfo32_sign_extends_gp_value() {
+        let mut data = [0u8; 24];
+        data[20..].copy_from_slice(&[0xff, 0xff, 0x80, 0x00]);
+        let mut offset = 0;
+        let reginfo = MipsRegInfo::parse_at(BigEndian, Class::ELF32, &mut offset, &data)
+            .expect("should parse");
+        assert_eq!(reginfo.gp_value, -0x8000);
+    }
+
+    #[test]
+    fn parse_reginfo_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsRegInfo>(LittleEndian, Class::ELF32);
+        test_parse_fuzz_too_short::<_, MipsRegInfo>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn flags_from_e_flags() {
+        let flags = MipsFlags::from_e_flags(Class::ELF32, 0x70001007);
+        assert_eq!(
+            flags,
+            MipsFlags {
+                arch: MipsArch::Mips32r2,
+                abi: MipsAbi::O32,
+                ases: 0,
+                noreorder: true,
+                pic: true,
+                cpic: true,
+                fp64: false,
+                nan2008: false,
+            }
+        );
+        assert_eq!(flags.to_string(), "noreorder, pic, cpic, o32, mips32r2");
+
+        let flags = MipsFlags::from_e_flags(
+            Class::ELF32,
+            abi::EF_MIPS_ARCH_64R2 | abi::EF_MIPS_ABI2 | abi::EF_MIPS_ARCH_ASE_MICROMIPS,
+        );
+        assert_eq!(flags.arch, MipsArch::Mips64r2);
+        assert_eq!(flags.abi, MipsAbi::N32);
+        assert!(flags.has_ases(abi::EF_MIPS_ARCH_ASE_MICROMIPS));
+        assert!(!flags.has_ases(abi::EF_MIPS_ARCH_ASE_M16));
+        assert_eq!(flags.to_string(), "n32, micromips, mips64r2");
+    }
+
+    #[test]
+    fn flags_abi_depends_on_class() {
+        let flags = MipsFlags::from_e_flags(Class::ELF64, abi::EF_MIPS_ARCH_64R6);
+        assert_eq!(flags.abi, MipsAbi::N64);
+        assert_eq!(flags.arch, MipsArch::Mips64r6);
+
+        let flags = MipsFlags::from_e_flags(Class::ELF32, abi::EF_MIPS_ARCH_2);
+        assert_eq!(flags.abi, MipsAbi::O32);
+
+        let flags = MipsFlags::from_e_flags(Class::ELF32, 0xb0005000);
+        assert_eq!(flags.abi, MipsAbi::Unknown(0x5000));
+        assert_eq!(flags.arch, MipsArch::Unknown(0xb0000000));
+    }
+}
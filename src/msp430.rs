@@ -0,0 +1,90 @@
+//! Decoding MSP430 specific header data: the
+//! [e_flags](crate::file::FileHeader#structfield.e_flags) of objects built for
+//! [EM_MSP430](crate::abi::EM_MSP430)
+//!
+//! The flags record the MCU family the object was built for. Objects for the MSP430X
+//! architecture, which extends the address space to 20 bits, use a different set of relocation
+//! types (R_MSP430X_*) than those for the original architecture (R_MSP430_*). Objects marked
+//! with the [ELFOSABI_STANDALONE](crate::abi::ELFOSABI_STANDALONE) OS ABI use the MSP430X set
+//! as well, regardless of their MCU family.
+//!
+//! See [FileHeader::msp430_flags](crate::file::FileHeader::msp430_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The decoded e_flags of an MSP430 object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Msp430Flags {
+    /// The MCU family, from the [EF_MSP430_MACH](abi::EF_MSP430_MACH) bits, e.g.
+    /// [E_MSP430_MACH_MSP430X](abi::E_MSP430_MACH_MSP430X). Zero means unspecified.
+    pub mach: u32,
+    /// The object's relocations are of the MSP430X set, e.g.
+    /// [R_MSP430X_ABS16](abi::R_MSP430X_ABS16), rather than the original set
+    pub msp430x_relocs: bool,
+}
+
+impl Msp430Flags {
+    /// Decode the given e_flags value for an object with the given
+    /// [OS ABI](crate::file::FileHeader#structfield.osabi). Bits outside of
+    /// [EF_MSP430_MACH](abi::EF_MSP430_MACH) are ignored.
+    pub fn from_e_flags(osabi: u8, e_flags: u32) -> Self {
+        let mach = e_flags & abi::EF_MSP430_MACH;
+        Msp430Flags {
+            mach,
+            msp430x_relocs: mach == abi::E_MSP430_MACH_MSP430X || osabi == abi::ELFOSABI_STANDALONE,
+        }
+    }
+}
+
+impl EFlagsDecoder for Msp430Flags {
+    const E_MACHINE: u16 = abi::EM_MSP430;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        Msp430Flags::from_e_flags(ehdr.osabi, ehdr.e_flags)
+    }
+}
+
+/// Formats the MCU family, e.g. "MSP430X" or "MSP430x14"
+impl core::fmt::Display for Msp430Flags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.mach {
+            0 => write!(f, "MSP430"),
+            abi::E_MSP430_MACH_MSP430X => write!(f, "MSP430X"),
+            abi::E_MSP430_MACH_MSP430X11X1 => write!(f, "MSP430x11x1"),
+            mach => write!(f, "MSP430x{mach}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            Msp430Flags::from_e_flags(abi::ELFOSABI_NONE, abi::E_MSP430_MACH_MSP430X14),
+            Msp430Flags {
+                mach: 14,
+                msp430x_relocs: false,
+            }
+        );
+        assert_eq!(
+            Msp430Flags::from_e_flags(abi::ELFOSABI_NONE, abi::E_MSP430_MACH_MSP430X | 0x100),
+            Msp430Flags {
+                mach: 45,
+                msp430x_relocs: true,
+            }
+        );
+        assert!(Msp430Flags::from_e_flags(abi::ELFOSABI_STANDALONE, 0).msp430x_relocs);
+    }
+
+    #[test]
+    fn display() {
+        let flags = Msp430Flags::from_e_flags(abi::ELFOSABI_NONE, 110);
+        assert_eq!(flags.to_string(), "MSP430x11x1");
+        let flags = Msp430Flags::from_e_flags(abi::ELFOSABI_NONE, 45);
+        assert_eq!(flags.to_string(), "MSP430X");
+    }
+}
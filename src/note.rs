@@ -34,7 +34,7 @@
 use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ReadBytesExt};
+use crate::parse::{ParseAt, ParseError, ParsingIterator, ReadBytesExt};
 use core::mem::size_of;
 use core::str::from_utf8;
 
@@ -45,6 +45,16 @@ pub enum Note<'data> {
     GnuAbiTag(NoteGnuAbiTag),
     /// (name: [abi::ELF_NOTE_GNU], n_type: [abi::NT_GNU_BUILD_ID])
     GnuBuildId(NoteGnuBuildId<'data>),
+    /// (name: [abi::ELF_NOTE_GNU], n_type: [abi::NT_GNU_PROPERTY_TYPE_0])
+    GnuProperty(NoteGnuProperty<'data>),
+    /// (name: [abi::ELF_NOTE_CORE], n_type: [abi::NT_PRSTATUS])
+    PrStatus(NotePrStatus<'data>),
+    /// (name: [abi::ELF_NOTE_CORE], n_type: [abi::NT_PRPSINFO])
+    PrPsInfo(NotePrPsInfo<'data>),
+    /// (name: [abi::ELF_NOTE_CORE], n_type: [abi::NT_FILE])
+    File(NoteFile<'data>),
+    /// (name: [abi::ELF_NOTE_CORE], n_type: [abi::NT_AUXV])
+    Auxv(NoteAuxv<'data>),
     /// All other notes that we don't know how to parse
     Unknown(NoteAny<'data>),
 }
@@ -52,72 +62,60 @@ pub enum Note<'data> {
 impl<'data> Note<'data> {
     fn parse_at<E: EndianParse>(
         endian: E,
-        _class: Class,
+        class: Class,
         align: usize,
         offset: &mut usize,
         data: &'data [u8],
     ) -> Result<Self, ParseError> {
-        // We don't know what to do if the section or segment header specified a zero alignment, so error
-        // (this is likely a file corruption)
-        if align == 0 {
-            return Err(ParseError::UnexpectedAlignment(align));
-        }
-
-        // It looks like clang and gcc emit 32-bit notes for 64-bit files, so we
-        // currently always parse all note headers as 32-bit.
-        let nhdr = NoteHeader::parse_at(endian, Class::ELF32, offset, data)?;
-
-        let name_start = *offset;
-        let name_size: usize = nhdr.n_namesz.try_into()?;
-        let name_end = name_start
-            .checked_add(name_size)
-            .ok_or(ParseError::IntegerOverflow)?;
-        let name = data.get_bytes(name_start..name_end)?;
-        *offset = name_end;
-
-        // skip over padding if needed to get back to 4-byte alignment
-        if *offset % align > 0 {
-            *offset = (*offset)
-                .checked_add(align - *offset % align)
-                .ok_or(ParseError::IntegerOverflow)?;
-        }
-
-        let desc_start = *offset;
-        let desc_size: usize = nhdr.n_descsz.try_into()?;
-        let desc_end = desc_start
-            .checked_add(desc_size)
-            .ok_or(ParseError::IntegerOverflow)?;
-        let raw_desc = data.get_bytes(desc_start..desc_end)?;
-        *offset = desc_end;
-
-        // skip over padding if needed to get back to 4-byte alignment
-        if *offset % align > 0 {
-            *offset = (*offset)
-                .checked_add(align - *offset % align)
-                .ok_or(ParseError::IntegerOverflow)?;
-        }
+        let NoteAny {
+            n_type,
+            name,
+            desc: raw_desc,
+        } = NoteAny::parse_at(endian, align, offset, data)?;
 
         // Interpret the note contents to try to return a known note variant
         match name {
-            abi::ELF_NOTE_GNU => match nhdr.n_type {
+            abi::ELF_NOTE_GNU => match n_type {
                 abi::NT_GNU_ABI_TAG => {
                     let mut offset = 0;
                     Ok(Note::GnuAbiTag(NoteGnuAbiTag::parse_at(
                         endian,
-                        _class,
+                        class,
                         &mut offset,
                         raw_desc,
                     )?))
                 }
                 abi::NT_GNU_BUILD_ID => Ok(Note::GnuBuildId(NoteGnuBuildId(raw_desc))),
+                abi::NT_GNU_PROPERTY_TYPE_0 => Ok(Note::GnuProperty(NoteGnuProperty {
+                    class,
+                    data: raw_desc,
+                })),
+                _ => Ok(Note::Unknown(NoteAny {
+                    n_type,
+                    name,
+                    desc: raw_desc,
+                })),
+            },
+            abi::ELF_NOTE_CORE => match n_type {
+                abi::NT_PRSTATUS => Ok(Note::PrStatus(NotePrStatus::parse(
+                    endian, class, raw_desc,
+                )?)),
+                abi::NT_PRPSINFO => Ok(Note::PrPsInfo(NotePrPsInfo::parse(
+                    endian, class, raw_desc,
+                )?)),
+                abi::NT_FILE => Ok(Note::File(NoteFile::parse(endian, class, raw_desc)?)),
+                abi::NT_AUXV => Ok(Note::Auxv(NoteAuxv {
+                    class,
+                    data: raw_desc,
+                })),
                 _ => Ok(Note::Unknown(NoteAny {
-                    n_type: nhdr.n_type,
+                    n_type,
                     name,
                     desc: raw_desc,
                 })),
             },
             _ => Ok(Note::Unknown(NoteAny {
-                n_type: nhdr.n_type,
+                n_type,
                 name,
                 desc: raw_desc,
             })),
@@ -126,7 +124,8 @@ impl<'data> Note<'data> {
 }
 
 /// Contains four 4-byte integers.
-/// The first 4-byte integer specifies the os. The second, third, and fourth
+/// The first 4-byte integer specifies the os, one of the `ELF_NOTE_GNU_ABI_TAG_OS_*` constants
+/// in [abi] (e.g. Linux, GNU/Hurd, or Solaris). The second, third, and fourth
 /// 4-byte integers contain the earliest compatible kernel version.
 /// For example, if the 3 integers are 6, 0, and 7, this signifies a 6.0.7 kernel.
 ///
@@ -139,6 +138,13 @@ pub struct NoteGnuAbiTag {
     pub subminor: u32,
 }
 
+impl NoteGnuAbiTag {
+    /// The earliest compatible kernel version, as (major, minor, subminor)
+    pub fn kernel_version(&self) -> (u32, u32, u32) {
+        (self.major, self.minor, self.subminor)
+    }
+}
+
 impl ParseAt for NoteGnuAbiTag {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -167,6 +173,742 @@ impl ParseAt for NoteGnuAbiTag {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NoteGnuBuildId<'data>(pub &'data [u8]);
 
+/// Formats the build-id as a lowercase hex string, as used by e.g. `file` and the
+/// `.build-id/xx/yyyy.debug` debug file directory layout.
+impl<'data> core::fmt::Display for NoteGnuBuildId<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Contains the program properties from a `.note.gnu.property` section (or the
+/// [PT_GNU_PROPERTY](abi::PT_GNU_PROPERTY) segment), which describe special handling
+/// requirements for the linker and run-time loader, such as whether the object is
+/// compatible with x86 CET or AArch64 BTI/PAC.
+///
+/// Use [NoteGnuProperty::iter] to iterate over the properties, or [NoteGnuProperty::get]
+/// to look up a property by its type.
+///
+/// (see: <https://raw.githubusercontent.com/wiki/hjl-tools/linux-abi/linux-abi-draft.pdf>)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteGnuProperty<'data> {
+    class: Class,
+    data: &'data [u8],
+}
+
+impl<'data> NoteGnuProperty<'data> {
+    /// Iterate over the properties in the note, which are sorted by their pr_type
+    pub fn iter<E: EndianParse>(&self, endian: E) -> GnuPropertyIterator<'data, E> {
+        GnuPropertyIterator {
+            endian,
+            class: self.class,
+            data: self.data,
+            offset: 0,
+        }
+    }
+
+    /// Find the property with the given type, e.g. [GNU_PROPERTY_X86_FEATURE_1_AND](abi::GNU_PROPERTY_X86_FEATURE_1_AND)
+    pub fn get<E: EndianParse>(&self, endian: E, pr_type: u32) -> Option<GnuProperty<'data>> {
+        self.iter(endian)
+            .find(|property| property.pr_type == pr_type)
+    }
+
+    /// Get the bitmask value of the given 4-byte bitmask property, or 0 if the note doesn't
+    /// contain it.
+    ///
+    /// This is handy for checking feature bits, e.g. for whether an x86 object was built
+    /// with CET shadow stack support:
+    /// ```
+    /// use elf::abi;
+    /// use elf::ElfBytes;
+    /// use elf::endian::AnyEndian;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+    ///
+    /// let property = file.gnu_property().expect("notes should parse").unwrap();
+    /// let features = property
+    ///     .get_u32(file.ehdr.endianness, abi::GNU_PROPERTY_X86_FEATURE_1_AND)
+    ///     .expect("property should parse");
+    /// assert!(features & abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0);
+    /// ```
+    pub fn get_u32<E: EndianParse>(&self, endian: E, pr_type: u32) -> Result<u32, ParseError> {
+        match self.get(endian, pr_type) {
+            Some(property) => property.value_u32(endian),
+            None => Ok(0),
+        }
+    }
+}
+
+/// A single program property from a [NoteGnuProperty]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GnuProperty<'data> {
+    /// The property type, one of the `GNU_PROPERTY_*` constants in [abi]
+    pub pr_type: u32,
+    /// The property's data, whose format depends on its type
+    pub pr_data: &'data [u8],
+}
+
+impl<'data> GnuProperty<'data> {
+    /// Parse the data of a property whose value is a 4-byte word, such as the
+    /// `*_FEATURE_1_AND` and `*_ISA_1_*` bitmasks.
+    pub fn value_u32<E: EndianParse>(&self, endian: E) -> Result<u32, ParseError> {
+        if self.pr_data.len() != size_of::<u32>() {
+            return Err(ParseError::BadEntsize((
+                self.pr_data.len() as u64,
+                size_of::<u32>() as u64,
+            )));
+        }
+        endian.parse_u32_at(&mut 0, self.pr_data)
+    }
+}
+
+/// Iterator over the properties in a [NoteGnuProperty]
+#[derive(Debug)]
+pub struct GnuPropertyIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for GnuPropertyIterator<'data, E> {
+    type Item = GnuProperty<'data>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let pr_type = self.endian.parse_u32_at(&mut self.offset, self.data).ok()?;
+        let pr_datasz = self.endian.parse_u32_at(&mut self.offset, self.data).ok()?;
+        let data_end = self.offset.checked_add(pr_datasz as usize)?;
+        let pr_data = self.data.get_bytes(self.offset..data_end).ok()?;
+
+        // Each property's data is padded to 8 bytes in ELF64 objects and 4 bytes in ELF32 objects
+        let align = match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        self.offset = data_end.checked_add(align - 1)? & !(align - 1);
+        Some(GnuProperty { pr_type, pr_data })
+    }
+}
+
+/// A `struct timeval` time value, as found in core file notes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteTimeval {
+    pub sec: i64,
+    pub usec: i64,
+}
+
+impl ParseAt for NoteTimeval {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        match class {
+            Class::ELF32 => Ok(NoteTimeval {
+                sec: endian.parse_i32_at(offset, data)? as i64,
+                usec: endian.parse_i32_at(offset, data)? as i64,
+            }),
+            Class::ELF64 => Ok(NoteTimeval {
+                sec: endian.parse_i64_at(offset, data)?,
+                usec: endian.parse_i64_at(offset, data)?,
+            }),
+        }
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 8,
+            Class::ELF64 => 16,
+        }
+    }
+}
+
+/// Contains a thread's status at the time a core file was dumped: the signal which
+/// stopped it, its process ids, its cpu times, and its general-purpose registers.
+/// This is the Linux `struct elf_prstatus`.
+///
+/// The layout of the registers depends on the target machine, so they're kept as raw bytes
+/// which can be interpreted with [NotePrStatus::registers], e.g. as [X86_64Registers] or
+/// [Aarch64Registers]. Core files have one of these notes for each thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotePrStatus<'data> {
+    /// The number of the signal which caused the dump
+    pub signo: i32,
+    pub code: i32,
+    pub errno: i32,
+    /// The signal the thread was stopped for
+    pub cursig: u16,
+    /// Set of pending signals
+    pub sigpend: u64,
+    /// Set of held (blocked) signals
+    pub sighold: u64,
+    pub pid: i32,
+    pub ppid: i32,
+    pub pgrp: i32,
+    pub sid: i32,
+    /// User time
+    pub utime: NoteTimeval,
+    /// System time
+    pub stime: NoteTimeval,
+    /// Cumulative user time
+    pub cutime: NoteTimeval,
+    /// Cumulative system time
+    pub cstime: NoteTimeval,
+    /// The raw bytes of the general-purpose registers (`elf_gregset_t`)
+    pub pr_reg: &'data [u8],
+    /// True if the floating point registers were saved (in a NT_PRFPREG note)
+    pub fpvalid: bool,
+    class: Class,
+}
+
+impl<'data> NotePrStatus<'data> {
+    /// Create a zeroed status for a thread of a `class` object with the given raw
+    /// general-purpose registers (e.g. an encoded [X86_64Registers]), for writing out to a core
+    /// file. The rest of the fields can then be filled in.
+    pub fn new(class: Class, pr_reg: &'data [u8]) -> Self {
+        let time = NoteTimeval { sec: 0, usec: 0 };
+        NotePrStatus {
+            signo: 0,
+            code: 0,
+            errno: 0,
+            cursig: 0,
+            sigpend: 0,
+            sighold: 0,
+            pid: 0,
+            ppid: 0,
+            pgrp: 0,
+            sid: 0,
+            utime: time,
+            stime: time,
+            cutime: time,
+            cstime: time,
+            pr_reg,
+            fpvalid: false,
+            class,
+        }
+    }
+
+    /// Parse the contents (desc) of a NT_PRSTATUS note
+    pub fn parse<E: EndianParse>(
+        endian: E,
+        class: Class,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let signo = endian.parse_i32_at(&mut offset, data)?;
+        let code = endian.parse_i32_at(&mut offset, data)?;
+        let errno = endian.parse_i32_at(&mut offset, data)?;
+        let cursig = endian.parse_u16_at(&mut offset, data)?;
+        // skip padding to align the following native longs
+        offset += 2;
+        let (sigpend, sighold) = match class {
+            Class::ELF32 => (
+                endian.parse_u32_at(&mut offset, data)? as u64,
+                endian.parse_u32_at(&mut offset, data)? as u64,
+            ),
+            Class::ELF64 => (
+                endian.parse_u64_at(&mut offset, data)?,
+                endian.parse_u64_at(&mut offset, data)?,
+            ),
+        };
+        let pid = endian.parse_i32_at(&mut offset, data)?;
+        let ppid = endian.parse_i32_at(&mut offset, data)?;
+        let pgrp = endian.parse_i32_at(&mut offset, data)?;
+        let sid = endian.parse_i32_at(&mut offset, data)?;
+        let utime = NoteTimeval::parse_at(endian, class, &mut offset, data)?;
+        let stime = NoteTimeval::parse_at(endian, class, &mut offset, data)?;
+        let cutime = NoteTimeval::parse_at(endian, class, &mut offset, data)?;
+        let cstime = NoteTimeval::parse_at(endian, class, &mut offset, data)?;
+
+        // The registers fill the space up until the trailing pr_fpvalid int, which
+        // is followed by padding up to the struct's native long alignment.
+        let trailer_size = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let reg_end = data
+            .len()
+            .checked_sub(trailer_size)
+            .filter(|reg_end| *reg_end >= offset)
+            .ok_or(ParseError::SliceReadError((offset, offset + trailer_size)))?;
+        let pr_reg = data.get_bytes(offset..reg_end)?;
+        let mut offset = reg_end;
+        let fpvalid = endian.parse_i32_at(&mut offset, data)? != 0;
+
+        Ok(NotePrStatus {
+            signo,
+            code,
+            errno,
+            cursig,
+            sigpend,
+            sighold,
+            pid,
+            ppid,
+            pgrp,
+            sid,
+            utime,
+            stime,
+            cutime,
+            cstime,
+            pr_reg,
+            fpvalid,
+            class,
+        })
+    }
+
+    /// Interpret the general-purpose registers as the given machine's register set.
+    ///
+    /// Returns [ParseError::BadEntsize] if the register set's size doesn't match
+    /// `pr_reg`'s size, e.g. because it's the wrong type for the core file's e_machine.
+    pub fn registers<R: ParseAt, E: EndianParse>(&self, endian: E) -> Result<R, ParseError> {
+        let size = R::size_for(self.class);
+        if self.pr_reg.len() != size {
+            return Err(ParseError::BadEntsize((
+                self.pr_reg.len() as u64,
+                size as u64,
+            )));
+        }
+        R::parse_at(endian, self.class, &mut 0, self.pr_reg)
+    }
+}
+
+/// The general-purpose registers of an [EM_X86_64](abi::EM_X86_64) thread
+/// (`struct user_regs_struct`), as found in [NotePrStatus::pr_reg].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct X86_64Registers {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+impl ParseAt for X86_64Registers {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        Ok(X86_64Registers {
+            r15: endian.parse_u64_at(offset, data)?,
+            r14: endian.parse_u64_at(offset, data)?,
+            r13: endian.parse_u64_at(offset, data)?,
+            r12: endian.parse_u64_at(offset, data)?,
+            rbp: endian.parse_u64_at(offset, data)?,
+            rbx: endian.parse_u64_at(offset, data)?,
+            r11: endian.parse_u64_at(offset, data)?,
+            r10: endian.parse_u64_at(offset, data)?,
+            r9: endian.parse_u64_at(offset, data)?,
+            r8: endian.parse_u64_at(offset, data)?,
+            rax: endian.parse_u64_at(offset, data)?,
+            rcx: endian.parse_u64_at(offset, data)?,
+            rdx: endian.parse_u64_at(offset, data)?,
+            rsi: endian.parse_u64_at(offset, data)?,
+            rdi: endian.parse_u64_at(offset, data)?,
+            orig_rax: endian.parse_u64_at(offset, data)?,
+            rip: endian.parse_u64_at(offset, data)?,
+            cs: endian.parse_u64_at(offset, data)?,
+            eflags: endian.parse_u64_at(offset, data)?,
+            rsp: endian.parse_u64_at(offset, data)?,
+            ss: endian.parse_u64_at(offset, data)?,
+            fs_base: endian.parse_u64_at(offset, data)?,
+            gs_base: endian.parse_u64_at(offset, data)?,
+            ds: endian.parse_u64_at(offset, data)?,
+            es: endian.parse_u64_at(offset, data)?,
+            fs: endian.parse_u64_at(offset, data)?,
+            gs: endian.parse_u64_at(offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(_class: Class) -> usize {
+        size_of::<u64>() * 27
+    }
+}
+
+/// The general-purpose registers of an [EM_AARCH64](abi::EM_AARCH64) thread
+/// (`struct user_pt_regs`), as found in [NotePrStatus::pr_reg].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Aarch64Registers {
+    /// x0 through x30 (x29 is the frame pointer and x30 is the link register)
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+impl ParseAt for Aarch64Registers {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let mut regs = [0u64; 31];
+        for reg in regs.iter_mut() {
+            *reg = endian.parse_u64_at(offset, data)?;
+        }
+        Ok(Aarch64Registers {
+            regs,
+            sp: endian.parse_u64_at(offset, data)?,
+            pc: endian.parse_u64_at(offset, data)?,
+            pstate: endian.parse_u64_at(offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(_class: Class) -> usize {
+        size_of::<u64>() * 34
+    }
+}
+
+/// Contains information about the process a core file was dumped from: its state, ids,
+/// executable name, and command line arguments. This is the Linux `struct elf_prpsinfo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotePrPsInfo<'data> {
+    /// Numeric process state
+    pub state: u8,
+    /// Character for the process state, as shown by ps (e.g. b'R' for running)
+    pub sname: u8,
+    /// True if the process is a zombie
+    pub zomb: bool,
+    /// Nice value
+    pub nice: i8,
+    /// Process flags
+    pub flag: u64,
+    pub uid: u32,
+    pub gid: u32,
+    pub pid: i32,
+    pub ppid: i32,
+    pub pgrp: i32,
+    pub sid: i32,
+    /// Executable file name (truncated to 16 bytes), without trailing NULs
+    pub fname: &'data [u8],
+    /// Initial part of the command line arguments (truncated to 80 bytes), without trailing NULs
+    pub psargs: &'data [u8],
+}
+
+impl<'data> NotePrPsInfo<'data> {
+    /// Parse the contents (desc) of a NT_PRPSINFO note
+    pub fn parse<E: EndianParse>(
+        endian: E,
+        class: Class,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let state = endian.parse_u8_at(&mut offset, data)?;
+        let sname = endian.parse_u8_at(&mut offset, data)?;
+        let zomb = endian.parse_u8_at(&mut offset, data)? != 0;
+        let nice = endian.parse_u8_at(&mut offset, data)? as i8;
+        let (flag, uid, gid) = match class {
+            Class::ELF32 => {
+                let flag = endian.parse_u32_at(&mut offset, data)? as u64;
+                // Some 32-bit architectures (i386, arm) have 16-bit uids here, which
+                // shrinks the struct from 128 to 124 bytes
+                if data.len() == 124 {
+                    let uid = endian.parse_u16_at(&mut offset, data)? as u32;
+                    let gid = endian.parse_u16_at(&mut offset, data)? as u32;
+                    (flag, uid, gid)
+                } else {
+                    let uid = endian.parse_u32_at(&mut offset, data)?;
+                    let gid = endian.parse_u32_at(&mut offset, data)?;
+                    (flag, uid, gid)
+                }
+            }
+            Class::ELF64 => {
+                // skip padding to align the native long
+                offset += 4;
+                let flag = endian.parse_u64_at(&mut offset, data)?;
+                let uid = endian.parse_u32_at(&mut offset, data)?;
+                let gid = endian.parse_u32_at(&mut offset, data)?;
+                (flag, uid, gid)
+            }
+        };
+        let pid = endian.parse_i32_at(&mut offset, data)?;
+        let ppid = endian.parse_i32_at(&mut offset, data)?;
+        let pgrp = endian.parse_i32_at(&mut offset, data)?;
+        let sid = endian.parse_i32_at(&mut offset, data)?;
+        let fname = trim_nuls(data.get_bytes(offset..offset + 16)?);
+        let psargs = trim_nuls(data.get_bytes(offset + 16..offset + 96)?);
+
+        Ok(NotePrPsInfo {
+            state,
+            sname,
+            zomb,
+            nice,
+            flag,
+            uid,
+            gid,
+            pid,
+            ppid,
+            pgrp,
+            sid,
+            fname,
+            psargs,
+        })
+    }
+
+    /// Parses the executable file name bytes as a utf8 sequence
+    pub fn fname_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.fname)?)
+    }
+
+    /// Parses the command line arguments bytes as a utf8 sequence
+    pub fn psargs_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.psargs)?)
+    }
+}
+
+/// Contains the list of files which were memory-mapped into a core file's process,
+/// which can be used to find the shared objects that were loaded and where.
+///
+/// The note's contents are a count and page size followed by a table of
+/// `(start, end, page_offset)` native longs and then a NUL-terminated path for each
+/// table entry. Use [NoteFile::mappings] to iterate over them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteFile<'data> {
+    /// The number of mapped file ranges
+    pub count: u64,
+    /// The unit for each mapping's page_offset
+    pub page_size: u64,
+    class: Class,
+    table: &'data [u8],
+    paths: &'data [u8],
+}
+
+impl<'data> NoteFile<'data> {
+    /// Parse the contents (desc) of a NT_FILE note
+    pub fn parse<E: EndianParse>(
+        endian: E,
+        class: Class,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let count = parse_native_long_at(endian, class, &mut offset, data)?;
+        let page_size = parse_native_long_at(endian, class, &mut offset, data)?;
+
+        let entry_size = NoteFileMapping::size_for(class);
+        let table_size = usize::try_from(count)?
+            .checked_mul(entry_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let table_end = offset
+            .checked_add(table_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let table = data.get_bytes(offset..table_end)?;
+        let paths = data.get_bytes(table_end..data.len())?;
+
+        // Check up front that there's a path for every mapping so that iteration can't fail
+        let num_paths = paths.iter().filter(|&&b| b == 0).count();
+        if (num_paths as u64) < count {
+            let unterminated = paths.iter().rposition(|&b| b == 0).map_or(0, |idx| idx + 1);
+            return Err(ParseError::StringTableMissingNul(
+                (table_end + unterminated) as u64,
+            ));
+        }
+
+        Ok(NoteFile {
+            count,
+            page_size,
+            class,
+            table,
+            paths,
+        })
+    }
+
+    /// Iterate over the mapped file ranges, in the order they appear in the note
+    pub fn mappings<E: EndianParse>(&self, endian: E) -> NoteFileIterator<'data, E> {
+        NoteFileIterator {
+            endian,
+            class: self.class,
+            table: self.table,
+            table_offset: 0,
+            paths: self.paths,
+        }
+    }
+}
+
+/// A file-backed memory mapping from a [NoteFile]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteFileMapping<'data> {
+    /// The virtual address where the mapping starts
+    pub start: u64,
+    /// The virtual address where the mapping ends (exclusive)
+    pub end: u64,
+    /// The offset in the file where the mapping starts, in units of [NoteFile::page_size]
+    pub page_offset: u64,
+    /// The path of the mapped file, without its NUL terminator
+    pub path: &'data [u8],
+}
+
+impl<'data> NoteFileMapping<'data> {
+    /// Parses the mapped file path bytes as a utf8 sequence
+    pub fn path_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.path)?)
+    }
+
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 12,
+            Class::ELF64 => 24,
+        }
+    }
+}
+
+/// Iterator over the file mappings in a [NoteFile]
+#[derive(Debug)]
+pub struct NoteFileIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    table: &'data [u8],
+    table_offset: usize,
+    paths: &'data [u8],
+}
+
+impl<'data, E: EndianParse> Iterator for NoteFileIterator<'data, E> {
+    type Item = NoteFileMapping<'data>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.table_offset >= self.table.len() {
+            return None;
+        }
+
+        let offset = &mut self.table_offset;
+        let start = parse_native_long_at(self.endian, self.class, offset, self.table).ok()?;
+        let end = parse_native_long_at(self.endian, self.class, offset, self.table).ok()?;
+        let page_offset = parse_native_long_at(self.endian, self.class, offset, self.table).ok()?;
+
+        let path_len = self.paths.iter().position(|&b| b == 0)?;
+        let path = &self.paths[..path_len];
+        self.paths = &self.paths[path_len + 1..];
+
+        Some(NoteFileMapping {
+            start,
+            end,
+            page_offset,
+            path,
+        })
+    }
+}
+
+/// Contains a copy of the auxiliary vector which the kernel passed to a core file's process,
+/// which says things like where the program was loaded ([AT_PHDR](abi::AT_PHDR),
+/// [AT_ENTRY](abi::AT_ENTRY)) and where its interpreter was loaded ([AT_BASE](abi::AT_BASE)).
+///
+/// Use [NoteAuxv::iter] to iterate over its `(a_type, a_val)` entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteAuxv<'data> {
+    class: Class,
+    data: &'data [u8],
+}
+
+impl<'data> NoteAuxv<'data> {
+    /// Iterate over the auxiliary vector's entries, stopping at the terminating
+    /// [AT_NULL](abi::AT_NULL) entry
+    pub fn iter<E: EndianParse>(&self, endian: E) -> AuxvIterator<'data, E> {
+        AuxvIterator(ParsingIterator::new(endian, self.class, self.data))
+    }
+
+    /// Get the value of the first entry with the given type, e.g. [AT_ENTRY](abi::AT_ENTRY)
+    pub fn get<E: EndianParse>(&self, endian: E, a_type: u64) -> Option<u64> {
+        self.iter(endian)
+            .find(|entry| entry.a_type == a_type)
+            .map(|entry| entry.a_val)
+    }
+}
+
+/// An entry in the auxiliary vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuxvEntry {
+    /// The entry's type, one of the `AT_*` constants in [abi]
+    pub a_type: u64,
+    /// The entry's value, whose meaning depends on its type
+    pub a_val: u64,
+}
+
+impl ParseAt for AuxvEntry {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        Ok(AuxvEntry {
+            a_type: parse_native_long_at(endian, class, offset, data)?,
+            a_val: parse_native_long_at(endian, class, offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 8,
+            Class::ELF64 => 16,
+        }
+    }
+}
+
+/// Iterator over the entries in a [NoteAuxv]
+#[derive(Debug)]
+pub struct AuxvIterator<'data, E: EndianParse>(ParsingIterator<'data, E, AuxvEntry>);
+
+impl<'data, E: EndianParse> Iterator for AuxvIterator<'data, E> {
+    type Item = AuxvEntry;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().filter(|entry| entry.a_type != abi::AT_NULL)
+    }
+}
+
+/// Parse a C `long`, which is the size of the class's native word
+fn parse_native_long_at<E: EndianParse>(
+    endian: E,
+    class: Class,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    match class {
+        Class::ELF32 => Ok(endian.parse_u32_at(offset, data)? as u64),
+        Class::ELF64 => endian.parse_u64_at(offset, data),
+    }
+}
+
+/// Strip the NUL padding from the end of a fixed-size char array
+fn trim_nuls(data: &[u8]) -> &[u8] {
+    let len = data.iter().rposition(|&b| b != 0).map_or(0, |idx| idx + 1);
+    &data[..len]
+}
+
 /// Contains the raw fields found in any ELF note. Used for notes that we don't know
 /// how to parse into more specific types.
 #[derive(Debug, PartialEq, Eq)]
@@ -177,6 +919,60 @@ pub struct NoteAny<'data> {
 }
 
 impl<'data> NoteAny<'data> {
+    /// Parse the raw fields of the note at `offset`, advancing it past the note and its padding.
+    pub(crate) fn parse_at<E: EndianParse>(
+        endian: E,
+        align: usize,
+        offset: &mut usize,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        // We don't know what to do if the section or segment header specified a zero alignment, so error
+        // (this is likely a file corruption)
+        if align == 0 {
+            return Err(ParseError::UnexpectedAlignment(align));
+        }
+
+        // It looks like clang and gcc emit 32-bit notes for 64-bit files, so we
+        // currently always parse all note headers as 32-bit.
+        let nhdr = NoteHeader::parse_at(endian, Class::ELF32, offset, data)?;
+
+        let name_start = *offset;
+        let name_size: usize = nhdr.n_namesz.try_into()?;
+        let name_end = name_start
+            .checked_add(name_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let name = data.get_bytes(name_start..name_end)?;
+        *offset = name_end;
+
+        // skip over padding if needed to get back to 4-byte alignment
+        if *offset % align > 0 {
+            *offset = (*offset)
+                .checked_add(align - *offset % align)
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+
+        let desc_start = *offset;
+        let desc_size: usize = nhdr.n_descsz.try_into()?;
+        let desc_end = desc_start
+            .checked_add(desc_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let raw_desc = data.get_bytes(desc_start..desc_end)?;
+        *offset = desc_end;
+
+        // skip over padding if needed to get back to 4-byte alignment
+        if *offset % align > 0 {
+            *offset = (*offset)
+                .checked_add(align - *offset % align)
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+
+        Ok(NoteAny {
+            n_type: nhdr.n_type,
+            name,
+            desc: raw_desc,
+        })
+    }
+
     /// Parses the note's name bytes as a utf8 sequence, with any trailing NUL bytes removed
     pub fn name_str(&self) -> Result<&str, ParseError> {
         let name = from_utf8(self.name)?;
@@ -311,6 +1107,276 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn gnu_build_id_display_hex() {
+        let build_id = NoteGnuBuildId(&[0x00, 0x0f, 0xa0, 0xff]);
+        assert_eq!(format!("{build_id}"), "000fa0ff");
+        assert_eq!(format!("{}", NoteGnuBuildId(&[])), "");
+    }
+
+    #[test]
+    fn parse_prstatus_aarch64_registers() {
+        // elf_prstatus header up through the times, then the registers and pr_fpvalid
+        let mut data = [0u8; 112 + 272 + 8];
+        data[0] = 11; // si_signo
+        data[12] = 11; // pr_cursig
+        data[32] = 42; // pr_pid
+        for (i, reg) in data[112..112 + 272].chunks_exact_mut(8).enumerate() {
+            reg[0] = i as u8;
+        }
+
+        let prstatus =
+            NotePrStatus::parse(LittleEndian, Class::ELF64, &data).expect("Failed to parse");
+        assert_eq!(prstatus.signo, 11);
+        assert_eq!(prstatus.cursig, 11);
+        assert_eq!(prstatus.pid, 42);
+        assert!(!prstatus.fpvalid);
+        assert_eq!(prstatus.pr_reg.len(), 272);
+
+        let regs: Aarch64Registers = prstatus
+            .registers(LittleEndian)
+            .expect("Failed to parse registers");
+        assert_eq!(regs.regs[30], 30);
+        assert_eq!(regs.sp, 31);
+        assert_eq!(regs.pc, 32);
+        assert_eq!(regs.pstate, 33);
+
+        let err = prstatus
+            .registers::<X86_64Registers, _>(LittleEndian)
+            .expect_err("Expected a size mismatch");
+        assert!(
+            matches!(err, ParseError::BadEntsize((272, 216))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_prstatus_too_short_errors() {
+        let data = [0u8; 112];
+        let err =
+            NotePrStatus::parse(LittleEndian, Class::ELF64, &data).expect_err("Expected an error");
+        assert!(
+            matches!(err, ParseError::SliceReadError((112, 120))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_prpsinfo_32_with_16_bit_uids() {
+        let mut data = [0u8; 124];
+        data[1] = b'S'; // pr_sname
+        data[3] = 0xff; // pr_nice
+        data[4..8].copy_from_slice(&0x400600u32.to_be_bytes()); // pr_flag
+        data[8..10].copy_from_slice(&1000u16.to_be_bytes()); // pr_uid
+        data[10..12].copy_from_slice(&100u16.to_be_bytes()); // pr_gid
+        data[12..16].copy_from_slice(&7i32.to_be_bytes()); // pr_pid
+        data[28..32].copy_from_slice(b"init");
+        data[44..51].copy_from_slice(b"init -s");
+
+        let prpsinfo =
+            NotePrPsInfo::parse(BigEndian, Class::ELF32, &data).expect("Failed to parse");
+        assert_eq!(prpsinfo.sname, b'S');
+        assert_eq!(prpsinfo.nice, -1);
+        assert_eq!(prpsinfo.flag, 0x400600);
+        assert_eq!(prpsinfo.uid, 1000);
+        assert_eq!(prpsinfo.gid, 100);
+        assert_eq!(prpsinfo.pid, 7);
+        assert_eq!(prpsinfo.fname_str().expect("should be utf8"), "init");
+        assert_eq!(prpsinfo.psargs_str().expect("should be utf8"), "init -s");
+    }
+
+    #[test]
+    fn parse_prpsinfo_32_with_32_bit_uids() {
+        let mut data = [0u8; 128];
+        data[8..12].copy_from_slice(&1000u32.to_le_bytes()); // pr_uid
+        data[12..16].copy_from_slice(&100u32.to_le_bytes()); // pr_gid
+        data[16..20].copy_from_slice(&7i32.to_le_bytes()); // pr_pid
+        data[32..36].copy_from_slice(b"init");
+
+        let prpsinfo =
+            NotePrPsInfo::parse(LittleEndian, Class::ELF32, &data).expect("Failed to parse");
+        assert_eq!(prpsinfo.uid, 1000);
+        assert_eq!(prpsinfo.gid, 100);
+        assert_eq!(prpsinfo.pid, 7);
+        assert_eq!(prpsinfo.fname, b"init");
+        assert_eq!(prpsinfo.psargs, b"");
+    }
+
+    #[test]
+    fn parse_nt_file_32_msb() {
+        #[rustfmt::skip]
+        let data = [
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x10, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x20, 0x00, 0x00, 0x01, 0x30, 0x00, 0x00, 0x00, 0x00, 0x02,
+            b'/', b'a', 0x00, b'/', b'l', b'i', b'b', b'c', 0x00,
+        ];
+
+        let file = NoteFile::parse(BigEndian, Class::ELF32, &data).expect("Failed to parse");
+        assert_eq!(file.count, 2);
+        assert_eq!(file.page_size, 0x1000);
+        let mappings: Vec<NoteFileMapping<'_>> = file.mappings(BigEndian).collect();
+        assert_eq!(
+            mappings,
+            [
+                NoteFileMapping {
+                    start: 0x10000,
+                    end: 0x12000,
+                    page_offset: 0,
+                    path: b"/a",
+                },
+                NoteFileMapping {
+                    start: 0x12000,
+                    end: 0x13000,
+                    page_offset: 2,
+                    path: b"/libc",
+                },
+            ]
+        );
+        assert_eq!(mappings[1].path_str().expect("should be utf8"), "/libc");
+    }
+
+    #[test]
+    fn parse_nt_file_missing_path_errors() {
+        #[rustfmt::skip]
+        let data = [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            b'/', b'a',
+        ];
+
+        let err = NoteFile::parse(LittleEndian, Class::ELF32, &data).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(20)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_nt_file_huge_count_errors() {
+        let data = [0xffu8; 16];
+        let err = NoteFile::parse(LittleEndian, Class::ELF64, &data).expect_err("should error");
+        assert!(
+            matches!(
+                err,
+                ParseError::IntegerOverflow | ParseError::TryFromIntError(_)
+            ),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_nt_auxv_stops_at_at_null() {
+        #[rustfmt::skip]
+        let data = [
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x00, 0x09, 0x00, 0x01, 0x02, 0x34,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let auxv = NoteAuxv {
+            class: Class::ELF32,
+            data: &data,
+        };
+        assert_eq!(
+            auxv.iter(BigEndian).collect::<Vec<AuxvEntry>>(),
+            [
+                AuxvEntry {
+                    a_type: abi::AT_PAGESZ,
+                    a_val: 0x1000
+                },
+                AuxvEntry {
+                    a_type: abi::AT_ENTRY,
+                    a_val: 0x10234
+                },
+            ]
+        );
+        assert_eq!(auxv.get(BigEndian, abi::AT_ENTRY), Some(0x10234));
+        assert_eq!(auxv.get(BigEndian, abi::AT_BASE), None);
+    }
+
+    #[test]
+    fn parse_gnu_property_64() {
+        #[rustfmt::skip]
+        let data = [
+            // GNU_PROPERTY_AARCH64_FEATURE_1_AND: BTI | PAC
+            0x00, 0x00, 0x00, 0xc0, 0x04, 0x00, 0x00, 0x00,
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // GNU_PROPERTY_NO_COPY_ON_PROTECTED with no data
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // GNU_PROPERTY_STACK_SIZE with 8-byte data
+            0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let note = NoteGnuProperty {
+            class: Class::ELF64,
+            data: &data,
+        };
+
+        let properties: Vec<GnuProperty<'_>> = note.iter(LittleEndian).collect();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(
+            properties[1].pr_type,
+            abi::GNU_PROPERTY_NO_COPY_ON_PROTECTED
+        );
+        assert_eq!(properties[1].pr_data, &[]);
+        assert_eq!(properties[2].pr_data, &[0, 0, 0x10, 0, 0, 0, 0, 0]);
+
+        let features = note
+            .get_u32(LittleEndian, abi::GNU_PROPERTY_AARCH64_FEATURE_1_AND)
+            .expect("should parse");
+        assert_eq!(
+            features,
+            abi::GNU_PROPERTY_AARCH64_FEATURE_1_BTI | abi::GNU_PROPERTY_AARCH64_FEATURE_1_PAC
+        );
+        assert_eq!(
+            note.get_u32(LittleEndian, abi::GNU_PROPERTY_1_NEEDED)
+                .expect("should parse"),
+            0
+        );
+
+        let err = properties[2]
+            .value_u32(LittleEndian)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadEntsize((8, 4))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_gnu_property_32() {
+        #[rustfmt::skip]
+        let data = [
+            // GNU_PROPERTY_X86_ISA_1_NEEDED with 2-byte data, padded to 4
+            0xc0, 0x00, 0x80, 0x02, 0x00, 0x00, 0x00, 0x02,
+            0x00, 0x01, 0x00, 0x00,
+            // GNU_PROPERTY_X86_FEATURE_1_AND: IBT
+            0xc0, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04,
+            0x00, 0x00, 0x00, 0x01,
+        ];
+        let note = NoteGnuProperty {
+            class: Class::ELF32,
+            data: &data,
+        };
+
+        let properties: Vec<GnuProperty<'_>> = note.iter(BigEndian).collect();
+        assert_eq!(
+            properties,
+            [
+                GnuProperty {
+                    pr_type: abi::GNU_PROPERTY_X86_ISA_1_NEEDED,
+                    pr_data: &[0x00, 0x01],
+                },
+                GnuProperty {
+                    pr_type: abi::GNU_PROPERTY_X86_FEATURE_1_AND,
+                    pr_data: &[0x00, 0x00, 0x00, 0x01],
+                },
+            ]
+        );
+    }
+
     #[test]
     fn parse_note_errors_with_zero_alignment() {
         // This is a .note.gnu.property section
@@ -356,10 +1422,9 @@ mod parse_tests {
             .expect("Failed to parse");
         assert_eq!(
             note,
-            Note::Unknown(NoteAny {
-                n_type: 5,
-                name: abi::ELF_NOTE_GNU,
-                desc: &[
+            Note::GnuProperty(NoteGnuProperty {
+                class: Class::ELF64,
+                data: &[
                     0x2, 0x0, 0x0, 0xc0, 0x4, 0x0, 0x0, 0x0, 0x3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0
                 ]
             })
@@ -0,0 +1,209 @@
+//! Configuring how ELF objects get opened: [OpenOptions]
+use crate::endian::EndianParse;
+#[cfg(feature = "to_str")]
+use crate::machine::MachineRegistry;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+
+#[cfg(feature = "tokio")]
+use crate::AsyncElfStream;
+#[cfg(feature = "std")]
+use crate::ElfStream;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Options and flags which can be used to configure how an ELF object is opened.
+///
+/// This is the single configuration surface for opening an object with either of the
+/// parser types. Create one with [OpenOptions::new], chain calls to the option setters, and
+/// then finish with one of the `open_*` methods. The simple entry points ([ElfBytes::minimal_parse],
+/// [ElfStream::open_stream]) are equivalent to opening with the default options.
+///
+/// ```
+/// use elf::OpenOptions;
+/// use elf::endian::AnyEndian;
+///
+/// let file = OpenOptions::new()
+///     .max_read_size(1 << 20)
+///     .open_path::<AnyEndian, _>("sample-objects/basic.x86_64")
+///     .expect("Open test1");
+/// assert_eq!(file.ehdr.e_type, elf::abi::ET_EXEC);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenOptions {
+    pub(crate) max_read_size: Option<usize>,
+    max_total_bytes: Option<usize>,
+    max_shnum: Option<usize>,
+    max_phnum: Option<usize>,
+    #[cfg(feature = "to_str")]
+    machines: MachineRegistry,
+}
+
+impl OpenOptions {
+    /// Create a new set of options with everything set to its default.
+    pub fn new() -> Self {
+        OpenOptions::default()
+    }
+
+    /// Limit the size of any single read of file data done by an [ElfStream], such as
+    /// reading a header table or a section's data, or decompressing a section.
+    ///
+    /// Reads larger than the limit fail with [ParseError::ReadSizeLimitExceeded] instead of
+    /// allocating a buffer for them. This guards against corrupted size fields in large
+    /// files. [ElfBytes] never copies the file data, so this doesn't apply to it.
+    ///
+    /// Default: no limit (reads are only bounded by the size of the stream)
+    pub fn max_read_size(&mut self, size: usize) -> &mut Self {
+        self.max_read_size = Some(size);
+        self
+    }
+
+    /// Limit the total size of the file data an [ElfStream] holds in memory at once, i.e. the
+    /// sum of all the reads it has cached.
+    ///
+    /// Reads which would exceed the limit fail with [ParseError::TotalReadLimitExceeded]. This
+    /// bounds the memory used for many moderately sized sections, which [OpenOptions::max_read_size]
+    /// alone doesn't. [ElfStream::clear_cache] releases the cached data. [ElfBytes] never copies
+    /// the file data, so this doesn't apply to it.
+    ///
+    /// Default: no limit
+    pub fn max_total_bytes(&mut self, size: usize) -> &mut Self {
+        self.max_total_bytes = Some(size);
+        self
+    }
+
+    /// Limit the number of entries in the section header table.
+    ///
+    /// Objects with more section headers fail to open with [ParseError::EntryCountLimitExceeded].
+    /// This bounds the work done (and, for an [ElfStream], the memory allocated) for a corrupted
+    /// or crafted e_shnum, or section count in the first section header.
+    ///
+    /// Default: no limit
+    pub fn max_shnum(&mut self, count: usize) -> &mut Self {
+        self.max_shnum = Some(count);
+        self
+    }
+
+    /// Limit the number of entries in the program header table.
+    ///
+    /// Objects with more program headers fail to open with [ParseError::EntryCountLimitExceeded].
+    ///
+    /// Default: no limit
+    pub fn max_phnum(&mut self, count: usize) -> &mut Self {
+        self.max_phnum = Some(count);
+        self
+    }
+
+    /// Name custom machine types and their relocation types with the given [MachineRegistry].
+    ///
+    /// The parsers don't need machine names, so this doesn't change how objects get opened. It
+    /// carries the registry to the code which displays them, which looks it up with
+    /// [OpenOptions::machine_registry].
+    ///
+    /// Default: an empty registry, which only names the machines this crate knows about
+    #[cfg(feature = "to_str")]
+    pub fn machines(&mut self, machines: MachineRegistry) -> &mut Self {
+        self.machines = machines;
+        self
+    }
+
+    /// Get the [MachineRegistry] set with [OpenOptions::machines].
+    #[cfg(feature = "to_str")]
+    pub fn machine_registry(&self) -> &MachineRegistry {
+        &self.machines
+    }
+
+    pub(crate) fn check_shnum(&self, shnum: usize) -> Result<(), ParseError> {
+        match self.max_shnum {
+            Some(limit) if shnum > limit => {
+                Err(ParseError::EntryCountLimitExceeded((shnum, limit)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_phnum(&self, phnum: usize) -> Result<(), ParseError> {
+        match self.max_phnum {
+            Some(limit) if phnum > limit => {
+                Err(ParseError::EntryCountLimitExceeded((phnum, limit)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check a read of `len` bytes of file data, when `cached` bytes are already held in memory.
+    #[cfg(feature = "std")]
+    pub(crate) fn check_read(&self, len: usize, cached: usize) -> Result<(), ParseError> {
+        if let Some(limit) = self.max_read_size {
+            if len > limit {
+                return Err(ParseError::ReadSizeLimitExceeded((len, limit)));
+            }
+        }
+        self.check_total(len, cached)
+    }
+
+    /// Check an allocation of `len` bytes, when `cached` bytes of file data are already held in
+    /// memory.
+    #[cfg(feature = "std")]
+    pub(crate) fn check_total(&self, len: usize, cached: usize) -> Result<(), ParseError> {
+        if let Some(limit) = self.max_total_bytes {
+            let total = cached.saturating_add(len);
+            if total > limit {
+                return Err(ParseError::TotalReadLimitExceeded((total, limit)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Open an ELF object from a byte slice containing its contents with these options.
+    ///
+    /// See [ElfBytes::minimal_parse].
+    pub fn open_slice<'data, E: EndianParse>(
+        &self,
+        data: &'data [u8],
+    ) -> Result<ElfBytes<'data, E>, ParseError> {
+        let file = ElfBytes::minimal_parse(data)?;
+        self.check_shnum(file.section_headers().map_or(0, |shdrs| shdrs.len()))?;
+        self.check_phnum(file.segments().map_or(0, |phdrs| phdrs.len()))?;
+        Ok(file)
+    }
+
+    /// Open an ELF object from a `Read + Seek` with these options.
+    ///
+    /// See [ElfStream::open_stream].
+    #[cfg(feature = "std")]
+    pub fn open_stream<E: EndianParse, S: Read + Seek>(
+        &self,
+        reader: S,
+    ) -> Result<ElfStream<E, S>, ParseError> {
+        ElfStream::open_stream_with_options(reader, self)
+    }
+
+    /// Open an ELF object from a tokio `AsyncRead + AsyncSeek` with these options.
+    ///
+    /// See [AsyncElfStream::open_stream].
+    #[cfg(feature = "tokio")]
+    pub async fn open_stream_async<E, S>(
+        &self,
+        reader: S,
+    ) -> Result<AsyncElfStream<E, S>, ParseError>
+    where
+        E: EndianParse,
+        S: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+    {
+        AsyncElfStream::open_stream_with_options(reader, self).await
+    }
+
+    /// Open the ELF object file at the given path with these options.
+    ///
+    /// The file is parsed as a stream, see [ElfStream::open_stream].
+    #[cfg(feature = "std")]
+    pub fn open_path<E: EndianParse, P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<ElfStream<E, std::fs::File>, ParseError> {
+        self.open_stream(std::fs::File::open(path)?)
+    }
+}
@@ -1,9 +1,14 @@
 //! Utilities to drive safe and lazy parsing of ELF structures.
 use core::{marker::PhantomData, ops::Range};
 
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
 
+/// The error type returned by this crate's parsing interfaces.
+///
+/// Each variant describes one kind of failure along with the values involved (offsets, sizes,
+/// unexpected field values), so callers can match on them instead of on the Display message.
 #[derive(Debug)]
 pub enum ParseError {
     /// Returned when the ELF File Header's magic bytes weren't ELF's defined
@@ -43,6 +48,35 @@ pub enum ParseError {
     SliceReadError((usize, usize)),
     /// Returned when doing math with parsed elf fields that resulted in integer overflow.
     IntegerOverflow,
+    /// Returned when asked to decompress a section whose compression type (ch_type) isn't
+    /// supported, or whose support wasn't enabled via its cargo feature.
+    UnsupportedCompression(u32),
+    /// Returned when decompressing section data failed, either because the compressed data
+    /// was corrupt or because it didn't match the size given in its compression header.
+    DecompressionError,
+    /// Returned when parsing a pointer whose DW_EH_PE_* encoding isn't supported, such as
+    /// one that is relative to a base address which isn't known.
+    UnsupportedPointerEncoding(u8),
+    /// Returned when a single read of file data would have been larger than the limit
+    /// configured via [OpenOptions::max_read_size](crate::OpenOptions::max_read_size).
+    /// Contains the (requested, limit) sizes.
+    ReadSizeLimitExceeded((usize, usize)),
+    /// Returned when a section or program header table has more entries than the limit
+    /// configured via [OpenOptions::max_shnum](crate::OpenOptions::max_shnum) or
+    /// [OpenOptions::max_phnum](crate::OpenOptions::max_phnum).
+    /// Contains the (count, limit) number of entries.
+    EntryCountLimitExceeded((usize, usize)),
+    /// Returned when a read of file data would have made the total amount of data held in
+    /// memory larger than the limit configured via
+    /// [OpenOptions::max_total_bytes](crate::OpenOptions::max_total_bytes).
+    /// Contains the (total, limit) sizes.
+    TotalReadLimitExceeded((usize, usize)),
+    /// Returned when setting a file header field to a value which isn't valid for the file's
+    /// class or machine. Contains the field's name and the rejected value.
+    InvalidHeaderValue((&'static str, u64)),
+    /// Returned when a buffer couldn't be allocated for data of the given size, which usually
+    /// comes from a size or offset field in the file.
+    AllocationFailed(u64),
     /// Returned when parsing a string out of a StringTable that contained
     /// invalid Utf8
     Utf8Error(core::str::Utf8Error),
@@ -75,6 +109,14 @@ impl std::error::Error for ParseError {
             ParseError::UnexpectedAlignment(_) => None,
             ParseError::SliceReadError(_) => None,
             ParseError::IntegerOverflow => None,
+            ParseError::UnsupportedCompression(_) => None,
+            ParseError::DecompressionError => None,
+            ParseError::UnsupportedPointerEncoding(_) => None,
+            ParseError::ReadSizeLimitExceeded(_) => None,
+            ParseError::EntryCountLimitExceeded(_) => None,
+            ParseError::TotalReadLimitExceeded(_) => None,
+            ParseError::InvalidHeaderValue(_) => None,
+            ParseError::AllocationFailed(_) => None,
             ParseError::Utf8Error(ref err) => Some(err),
             ParseError::TryFromSliceError(ref err) => Some(err),
             ParseError::TryFromIntError(ref err) => Some(err),
@@ -99,6 +141,14 @@ impl core::error::Error for ParseError {
             ParseError::UnexpectedAlignment(_) => None,
             ParseError::SliceReadError(_) => None,
             ParseError::IntegerOverflow => None,
+            ParseError::UnsupportedCompression(_) => None,
+            ParseError::DecompressionError => None,
+            ParseError::UnsupportedPointerEncoding(_) => None,
+            ParseError::ReadSizeLimitExceeded(_) => None,
+            ParseError::EntryCountLimitExceeded(_) => None,
+            ParseError::TotalReadLimitExceeded(_) => None,
+            ParseError::InvalidHeaderValue(_) => None,
+            ParseError::AllocationFailed(_) => None,
             ParseError::Utf8Error(ref err) => Some(err),
             ParseError::TryFromSliceError(ref err) => Some(err),
             ParseError::TryFromIntError(ref err) => Some(err),
@@ -163,6 +213,47 @@ impl core::fmt::Display for ParseError {
             ParseError::IntegerOverflow => {
                 write!(f, "Integer overflow detected")
             }
+            ParseError::UnsupportedCompression(ch_type) => match ch_type {
+                abi::ELFCOMPRESS_ZLIB => write!(
+                    f,
+                    "Decompressing ELFCOMPRESS_ZLIB sections requires the `zlib` feature"
+                ),
+                abi::ELFCOMPRESS_ZSTD => write!(
+                    f,
+                    "Decompressing ELFCOMPRESS_ZSTD sections requires the `zstd` feature"
+                ),
+                _ => write!(f, "Unknown section compression type: {ch_type:#X}"),
+            },
+            ParseError::DecompressionError => {
+                write!(f, "Failed to decompress section data")
+            }
+            ParseError::UnsupportedPointerEncoding(encoding) => {
+                write!(f, "Unsupported pointer encoding: {encoding:#X}")
+            }
+            ParseError::ReadSizeLimitExceeded((requested, limit)) => {
+                write!(
+                    f,
+                    "Read of {requested:#X} bytes exceeds the configured limit of {limit:#X} bytes"
+                )
+            }
+            ParseError::EntryCountLimitExceeded((count, limit)) => {
+                write!(
+                    f,
+                    "Table of {count} entries exceeds the configured limit of {limit} entries"
+                )
+            }
+            ParseError::TotalReadLimitExceeded((total, limit)) => {
+                write!(
+                    f,
+                    "Reading a total of {total:#X} bytes exceeds the configured limit of {limit:#X} bytes"
+                )
+            }
+            ParseError::InvalidHeaderValue((field, value)) => {
+                write!(f, "Invalid value for {field}: {value:#X}")
+            }
+            ParseError::AllocationFailed(size) => {
+                write!(f, "Failed to allocate {size:#X} bytes")
+            }
             ParseError::Utf8Error(ref err) => err.fmt(f),
             ParseError::TryFromSliceError(ref err) => err.fmt(f),
             ParseError::TryFromIntError(ref err) => err.fmt(f),
@@ -227,6 +318,19 @@ pub trait ParseAt: Sized {
             false => Err(ParseError::BadEntsize((entsize as u64, expected as u64))),
         }
     }
+
+    /// Checks whether the given entsize is usable as the stride between entries of this type
+    ///
+    /// Unlike [validate_entsize](ParseAt::validate_entsize), this accepts entsizes larger than
+    /// what this type parses, as the trailing bytes of each entry can be skipped over. Returns a
+    /// ParseError for entsizes which are too small to hold this type.
+    fn validate_stride(class: Class, entsize: usize) -> Result<usize, ParseError> {
+        let expected = Self::size_for(class);
+        match entsize >= expected {
+            true => Ok(entsize),
+            false => Err(ParseError::BadEntsize((entsize as u64, expected as u64))),
+        }
+    }
 }
 
 /// Lazy-parsing iterator which wraps bytes and parses out a `P: ParseAt` on each `next()`
@@ -236,6 +340,7 @@ pub struct ParsingIterator<'data, E: EndianParse, P: ParseAt> {
     class: Class,
     data: &'data [u8],
     offset: usize,
+    entsize: usize,
     // This struct doesn't technically own a P, but it yields them
     // as it iterates
     pd: PhantomData<&'data P>,
@@ -243,11 +348,19 @@ pub struct ParsingIterator<'data, E: EndianParse, P: ParseAt> {
 
 impl<'data, E: EndianParse, P: ParseAt> ParsingIterator<'data, E, P> {
     pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        Self::with_entsize(endian, class, P::size_for(class), data)
+    }
+
+    /// Create an iterator which yields a `P` from the start of every `entsize` bytes of `data`.
+    ///
+    /// `entsize` is expected to have already been checked with [ParseAt::validate_stride].
+    pub fn with_entsize(endian: E, class: Class, entsize: usize, data: &'data [u8]) -> Self {
         ParsingIterator {
             endian,
             class,
             data,
             offset: 0,
+            entsize,
             pd: PhantomData,
         }
     }
@@ -260,7 +373,11 @@ impl<'data, E: EndianParse, P: ParseAt> Iterator for ParsingIterator<'data, E, P
             return None;
         }
 
-        Self::Item::parse_at(self.endian, self.class, &mut self.offset, self.data).ok()
+        let mut offset = self.offset;
+        let item = Self::Item::parse_at(self.endian, self.class, &mut offset, self.data).ok()?;
+        // Skip over any trailing bytes in entries which are larger than what P parses
+        self.offset = self.offset.checked_add(self.entsize)?;
+        Some(item)
     }
 }
 
@@ -271,28 +388,39 @@ pub struct ParsingTable<'data, E: EndianParse, P: ParseAt> {
     endian: E,
     class: Class,
     data: &'data [u8],
+    entsize: usize,
     // This struct doesn't technically own a P, but it yields them
     pd: PhantomData<&'data P>,
 }
 
 impl<'data, E: EndianParse, P: ParseAt> ParsingTable<'data, E, P> {
     pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        Self::with_entsize(endian, class, P::size_for(class), data)
+    }
+
+    /// Create a table whose entries are `entsize` bytes apart, such as a section header table
+    /// with an e_shentsize larger than the size of a section header. Each entry's trailing bytes
+    /// beyond what `P` parses are ignored.
+    ///
+    /// `entsize` is expected to have already been checked with [ParseAt::validate_stride].
+    pub fn with_entsize(endian: E, class: Class, entsize: usize, data: &'data [u8]) -> Self {
         ParsingTable {
             endian,
             class,
             data,
+            entsize,
             pd: PhantomData,
         }
     }
 
     /// Get a lazy-parsing iterator for the table's bytes
     pub fn iter(&self) -> ParsingIterator<'data, E, P> {
-        ParsingIterator::new(self.endian, self.class, self.data)
+        ParsingIterator::with_entsize(self.endian, self.class, self.entsize, self.data)
     }
 
     /// Returns the number of elements of type P in the table.
     pub fn len(&self) -> usize {
-        self.data.len() / P::size_for(self.class)
+        self.data.len().checked_div(self.entsize).unwrap_or(0)
     }
 
     /// Returns whether the table is empty (contains zero elements).
@@ -306,9 +434,8 @@ impl<'data, E: EndianParse, P: ParseAt> ParsingTable<'data, E, P> {
             return Err(ParseError::BadOffset(index as u64));
         }
 
-        let entsize = P::size_for(self.class);
         let mut start = index
-            .checked_mul(entsize)
+            .checked_mul(self.entsize)
             .ok_or(ParseError::IntegerOverflow)?;
         if start > self.data.len() {
             return Err(ParseError::BadOffset(index as u64));
@@ -323,7 +450,7 @@ impl<'data, E: EndianParse, P: ParseAt> IntoIterator for ParsingTable<'data, E,
     type Item = P;
 
     fn into_iter(self) -> Self::IntoIter {
-        ParsingIterator::new(self.endian, self.class, self.data)
+        ParsingIterator::with_entsize(self.endian, self.class, self.entsize, self.data)
     }
 }
 
@@ -418,6 +545,16 @@ mod parsing_table_tests {
         ));
     }
 
+    #[test]
+    fn test_u32_validate_stride() {
+        assert!(matches!(u32::validate_stride(Class::ELF32, 4), Ok(4)));
+        assert!(matches!(u32::validate_stride(Class::ELF32, 8), Ok(8)));
+        assert!(matches!(
+            u32::validate_stride(Class::ELF32, 2),
+            Err(ParseError::BadEntsize((2, 4)))
+        ));
+    }
+
     #[test]
     fn test_u32_parse_at() {
         let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7];
@@ -499,4 +636,15 @@ mod parsing_table_tests {
         let table = U32Table::new(LittleEndian, Class::ELF32, data.get(1..).unwrap());
         assert!(matches!(table.get(0), Ok(0x04030201)));
     }
+
+    #[test]
+    fn test_u32_table_with_entsize() {
+        let data = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let table = U32Table::with_entsize(LittleEndian, Class::ELF32, 8, data.as_ref());
+        assert_eq!(table.len(), 2);
+        assert!(matches!(table.get(0), Ok(0x03020100)));
+        assert!(matches!(table.get(1), Ok(0x0b0a0908)));
+        assert!(matches!(table.get(3), Err(ParseError::BadOffset(3))));
+        assert_eq!(table.iter().collect::<Vec<u32>>(), [0x03020100, 0x0b0a0908]);
+    }
 }
@@ -0,0 +1,235 @@
+//! Mapping Procedure Linkage Table (`.plt`) stubs to the imported symbols they call
+//!
+//! Each PLT stub jumps through a GOT slot which the dynamic linker fills in using the
+//! corresponding `.rela.plt` or `.rel.plt` jump slot relocation. The stubs are laid out in the
+//! same order as the relocations, after an architecture-specific header, so the stub for the
+//! i-th relocation lives at `plt_start + header_size + i * entry_size`. This is the same
+//! heuristic that tools like objdump use to synthesize `name@plt` labels.
+//!
+//! Example for labeling an object's PLT stubs:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let stubs: Vec<(u64, &str)> = file
+//!     .plt_entries()
+//!     .expect("section table should be parseable")
+//!     .expect("file should have a .plt")
+//!     .map(|entry| entry.map(|entry| (entry.addr, entry.name)))
+//!     .collect::<Result<_, _>>()
+//!     .expect("plt entries should be parseable");
+//! assert_eq!(stubs, [(0x4003c0, "memset"), (0x4003d0, "__libc_start_main")]);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::ParseError;
+use crate::relocation::{RelIterator, RelaIterator};
+use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolTable};
+
+/// The size of the header and of each stub in a PLT section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltLayout {
+    /// Size of the PLT's header (the resolver trampoline) before the first stub
+    pub header_size: u64,
+    /// Size of each stub
+    pub entry_size: u64,
+}
+
+impl PltLayout {
+    /// Get the standard lazy-binding `.plt` layout for the given machine (if known).
+    ///
+    /// On x86 with IBT enabled, the stubs which are actually called live in a `.plt.sec` section
+    /// with no header, see [PltLayout::PLT_SEC].
+    pub fn for_machine(e_machine: u16) -> Option<Self> {
+        let (header_size, entry_size) = match e_machine {
+            abi::EM_386 | abi::EM_X86_64 => (16, 16),
+            abi::EM_AARCH64 => (32, 16),
+            abi::EM_ARM => (20, 12),
+            abi::EM_RISCV => (32, 16),
+            abi::EM_68K => (20, 20),
+            _ => return None,
+        };
+        Some(PltLayout {
+            header_size,
+            entry_size,
+        })
+    }
+
+    /// The layout of the x86 `.plt.sec` section: 16-byte stubs with no header
+    pub const PLT_SEC: PltLayout = PltLayout {
+        header_size: 0,
+        entry_size: 16,
+    };
+}
+
+/// A PLT stub and the imported symbol it calls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PltEntry<'data> {
+    /// Virtual address of the PLT stub
+    pub addr: u64,
+    /// The index of the symbol in the dynamic symbol table
+    pub sym_idx: u32,
+    /// The imported symbol
+    pub symbol: Symbol,
+    /// The imported symbol's name, which is empty for relocations without a symbol
+    /// (e.g. IRELATIVE relocations in static executables)
+    pub name: &'data str,
+}
+
+#[derive(Debug)]
+enum PltRelocs<'data, E: EndianParse> {
+    Rel(RelIterator<'data, E>),
+    Rela(RelaIterator<'data, E>),
+}
+
+/// Iterator over the [PltEntry]s of a PLT section.
+///
+/// See [ElfBytes::plt_entries](crate::ElfBytes::plt_entries).
+#[derive(Debug)]
+pub struct PltEntryIterator<'data, E: EndianParse> {
+    relocs: PltRelocs<'data, E>,
+    next_addr: u64,
+    entry_size: u64,
+    symtab: SymbolTable<'data, E>,
+    strtab: StringTable<'data>,
+}
+
+impl<'data, E: EndianParse> PltEntryIterator<'data, E> {
+    fn new(
+        relocs: PltRelocs<'data, E>,
+        plt_addr: u64,
+        layout: PltLayout,
+        symtab: SymbolTable<'data, E>,
+        strtab: StringTable<'data>,
+    ) -> Self {
+        PltEntryIterator {
+            relocs,
+            next_addr: plt_addr.wrapping_add(layout.header_size),
+            entry_size: layout.entry_size,
+            symtab,
+            strtab,
+        }
+    }
+
+    /// Create an iterator which pairs up the given `.rel.plt` relocations with the stubs in a
+    /// PLT section at `plt_addr`.
+    pub fn with_rels(
+        rels: RelIterator<'data, E>,
+        plt_addr: u64,
+        layout: PltLayout,
+        symtab: SymbolTable<'data, E>,
+        strtab: StringTable<'data>,
+    ) -> Self {
+        Self::new(PltRelocs::Rel(rels), plt_addr, layout, symtab, strtab)
+    }
+
+    /// Create an iterator which pairs up the given `.rela.plt` relocations with the stubs in a
+    /// PLT section at `plt_addr`.
+    pub fn with_relas(
+        relas: RelaIterator<'data, E>,
+        plt_addr: u64,
+        layout: PltLayout,
+        symtab: SymbolTable<'data, E>,
+        strtab: StringTable<'data>,
+    ) -> Self {
+        Self::new(PltRelocs::Rela(relas), plt_addr, layout, symtab, strtab)
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for PltEntryIterator<'data, E> {
+    type Item = Result<PltEntry<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let sym_idx = match &mut self.relocs {
+            PltRelocs::Rel(rels) => rels.next()?.r_sym,
+            PltRelocs::Rela(relas) => relas.next()?.r_sym,
+        };
+        let addr = self.next_addr;
+        self.next_addr = self.next_addr.wrapping_add(self.entry_size);
+
+        Some(self.symtab.get(sym_idx as usize).and_then(|symbol| {
+            let name = self.strtab.get(symbol.st_name as usize)?;
+            Ok(PltEntry {
+                addr,
+                sym_idx,
+                symbol,
+                name,
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+    use crate::file::Class;
+
+    #[test]
+    fn plt_layout_for_machine() {
+        assert_eq!(
+            PltLayout::for_machine(abi::EM_ARM),
+            Some(PltLayout {
+                header_size: 20,
+                entry_size: 12
+            })
+        );
+        assert_eq!(PltLayout::for_machine(abi::EM_PPC64), None);
+    }
+
+    #[test]
+    fn plt_entries_from_rels() {
+        #[rustfmt::skip]
+        let rels = [
+            0x0c, 0x10, 0x01, 0x00, 0x16, 0x01, 0x00, 0x00,
+            0x10, 0x10, 0x01, 0x00, 0x16, 0x02, 0x00, 0x00,
+        ];
+        #[rustfmt::skip]
+        let syms = [
+            // null symbol
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // "foo"
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+            // "bar"
+            0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        ];
+        let entries: Vec<(u64, u32, &str)> = PltEntryIterator::with_rels(
+            RelIterator::new(LittleEndian, Class::ELF32, &rels),
+            0x3dc,
+            PltLayout::for_machine(abi::EM_ARM).unwrap(),
+            SymbolTable::new(LittleEndian, Class::ELF32, &syms),
+            StringTable::new(b"\0foo\0bar\0"),
+        )
+        .map(|entry| entry.map(|entry| (entry.addr, entry.sym_idx, entry.name)))
+        .collect::<Result<_, _>>()
+        .expect("should parse");
+        assert_eq!(entries, [(0x3f0, 1, "foo"), (0x3fc, 2, "bar")]);
+    }
+
+    #[test]
+    fn plt_entries_bad_symbol_index_errors() {
+        let rels = [0x0c, 0x10, 0x01, 0x00, 0x16, 0x05, 0x00, 0x00];
+        let syms = [0u8; 16];
+        let err = PltEntryIterator::with_rels(
+            RelIterator::new(LittleEndian, Class::ELF32, &rels),
+            0x3dc,
+            PltLayout::for_machine(abi::EM_ARM).unwrap(),
+            SymbolTable::new(LittleEndian, Class::ELF32, &syms),
+            StringTable::new(b"\0"),
+        )
+        .next()
+        .expect("should have an entry")
+        .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(5)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
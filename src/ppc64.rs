@@ -0,0 +1,92 @@
+//! Decoding PowerPC64 specific header data: the ABI version in the
+//! [e_flags](crate::file::FileHeader#structfield.e_flags) of objects built for
+//! [EM_PPC64](crate::abi::EM_PPC64)
+//!
+//! There are two incompatible PowerPC64 ABIs. ELFv1 (traditionally big-endian) calls functions
+//! through function descriptors in the `.opd` section, so a function symbol's st_value is the
+//! address of its descriptor rather than of its code. ELFv2 (traditionally little-endian) has no
+//! descriptors, and function symbols point at the code like on other architectures.
+//!
+//! See [FileHeader::ppc64_abi](crate::file::FileHeader::ppc64_abi).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The ABI version selected by the [EF_PPC64_ABI](abi::EF_PPC64_ABI) bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ppc64Abi {
+    /// The object doesn't say, either because it was built by an older toolchain or because it
+    /// doesn't use any features affected by the differences between the ABIs
+    Unspecified,
+    /// The original ABI, which uses function descriptors
+    ElfV1,
+    /// The revised ABI, which doesn't use function descriptors
+    ElfV2,
+    /// An ABI version not known to this crate. Contains the EF_PPC64_ABI bits.
+    Unknown(u32),
+}
+
+impl Ppc64Abi {
+    /// Decode the given e_flags value. Bits outside of [EF_PPC64_ABI](abi::EF_PPC64_ABI) are ignored.
+    pub fn from_e_flags(e_flags: u32) -> Self {
+        match e_flags & abi::EF_PPC64_ABI {
+            0 => Ppc64Abi::Unspecified,
+            1 => Ppc64Abi::ElfV1,
+            2 => Ppc64Abi::ElfV2,
+            abi => Ppc64Abi::Unknown(abi),
+        }
+    }
+
+    /// Returns true if function symbols point at function descriptors rather than code.
+    ///
+    /// Objects with an [Unspecified](Ppc64Abi::Unspecified) ABI are assumed to follow ELFv1
+    /// when big-endian, since that's what toolchains which predate ELFv2 produced.
+    pub fn uses_function_descriptors(&self, big_endian: bool) -> bool {
+        match self {
+            Ppc64Abi::ElfV1 => true,
+            Ppc64Abi::Unspecified => big_endian,
+            Ppc64Abi::ElfV2 | Ppc64Abi::Unknown(_) => false,
+        }
+    }
+}
+
+impl EFlagsDecoder for Ppc64Abi {
+    const E_MACHINE: u16 = abi::EM_PPC64;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        Ppc64Abi::from_e_flags(ehdr.e_flags)
+    }
+}
+
+/// Formats the ABI like readelf, e.g. "abiv2"
+impl core::fmt::Display for Ppc64Abi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ppc64Abi::Unspecified => write!(f, "unspecified ABI"),
+            Ppc64Abi::ElfV1 => write!(f, "abiv1"),
+            Ppc64Abi::ElfV2 => write!(f, "abiv2"),
+            Ppc64Abi::Unknown(abi) => write!(f, "abiv{abi}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(Ppc64Abi::from_e_flags(0), Ppc64Abi::Unspecified);
+        assert_eq!(Ppc64Abi::from_e_flags(0x1), Ppc64Abi::ElfV1);
+        assert_eq!(Ppc64Abi::from_e_flags(0xff00_0002), Ppc64Abi::ElfV2);
+        assert_eq!(Ppc64Abi::from_e_flags(0x3), Ppc64Abi::Unknown(3));
+    }
+
+    #[test]
+    fn uses_function_descriptors() {
+        assert!(Ppc64Abi::ElfV1.uses_function_descriptors(false));
+        assert!(!Ppc64Abi::ElfV2.uses_function_descriptors(true));
+        assert!(Ppc64Abi::Unspecified.uses_function_descriptors(true));
+        assert!(!Ppc64Abi::Unspecified.uses_function_descriptors(false));
+    }
+}
@@ -1,4 +1,4 @@
-//! Parsing relocation sections: `.rel.*`, `.rela.*`, [SHT_REL](crate::abi::SHT_REL), [SHT_RELA](crate::abi::SHT_RELA)
+//! Parsing relocation sections: `.rel.*`, `.rela.*`, `.relr.*`, [SHT_REL](crate::abi::SHT_REL), [SHT_RELA](crate::abi::SHT_RELA), [SHT_RELR](crate::abi::SHT_RELR)
 use crate::endian::EndianParse;
 use crate::file::Class;
 use crate::parse::{ParseAt, ParseError, ParsingIterator};
@@ -27,6 +27,7 @@ pub struct Elf64_Rel {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rel {
     pub r_offset: u64,
     pub r_sym: u32,
@@ -94,6 +95,7 @@ pub struct Elf64_Rela {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rela {
     pub r_offset: u64,
     pub r_sym: u32,
@@ -143,6 +145,81 @@ impl ParseAt for Rela {
     }
 }
 
+/// Lazy-decoding iterator over the relocated addresses in a compact relative relocation
+/// table ([SHT_RELR](crate::abi::SHT_RELR), `.relr.dyn`, [DT_RELR](crate::abi::DT_RELR)).
+///
+/// RELR tables are a sequence of address-sized words which encode the offsets of relative
+/// relocations (the kind which only add the load base to the word at the offset):
+///
+/// * An entry with its least significant bit cleared is an address: it is itself a relocated
+///   offset, and the next word after it becomes the base for the following bitmap entries.
+/// * An entry with its least significant bit set is a bitmap: each of its remaining 31 (or 63)
+///   bits, from least to most significant, flags whether the word at `base + i * word_size`
+///   is relocated. The base then advances past all the words that the bitmap described.
+///
+/// This yields the decoded offsets in order, as u64s for both ELF32 and ELF64 objects.
+#[derive(Debug)]
+pub struct RelrIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'data [u8],
+    offset: usize,
+    // The address of the word following the previous address entry (or previous bitmap's span)
+    base: u64,
+    // The remaining set bits of the current bitmap entry, shifted down to exclude the marker bit
+    bitmap: u64,
+    // The address described by bit 0 of `bitmap`
+    bitmap_base: u64,
+}
+
+impl<'data, E: EndianParse> RelrIterator<'data, E> {
+    /// Construct a RelrIterator over the given RELR table bytes. Keeps a reference to the data
+    /// for lazy decoding.
+    pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        RelrIterator {
+            endian,
+            class,
+            data,
+            offset: 0,
+            base: 0,
+            bitmap: 0,
+            bitmap_base: 0,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for RelrIterator<'data, E> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (word_size, word_bits) = match self.class {
+            Class::ELF32 => (4u64, 32u64),
+            Class::ELF64 => (8u64, 64u64),
+        };
+
+        loop {
+            if self.bitmap != 0 {
+                let bit = self.bitmap.trailing_zeros() as u64;
+                self.bitmap &= self.bitmap - 1;
+                return Some(self.bitmap_base.wrapping_add(bit * word_size));
+            }
+
+            let entry = match self.class {
+                Class::ELF32 => self.endian.parse_u32_at(&mut self.offset, self.data).ok()? as u64,
+                Class::ELF64 => self.endian.parse_u64_at(&mut self.offset, self.data).ok()?,
+            };
+
+            if entry & 1 == 0 {
+                self.base = entry.wrapping_add(word_size);
+                return Some(entry);
+            }
+
+            self.bitmap = entry >> 1;
+            self.bitmap_base = self.base;
+            self.base = self.base.wrapping_add((word_bits - 1) * word_size);
+        }
+    }
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
@@ -296,4 +373,53 @@ mod parse_tests {
     fn parse_rela64_msb_fuzz_too_short() {
         test_parse_fuzz_too_short::<_, Rela>(BigEndian, Class::ELF64);
     }
+
+    #[test]
+    fn relr64_lsb_decode() {
+        #[rustfmt::skip]
+        let data = [
+            // address entry: 0x10000
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // bitmap entry: 0b1011 (marker bit + words 0 and 2 after 0x10000)
+            0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // bitmap entry: top bit set, describing the last word in the next 63 word span
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+            // address entry: 0x20000
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let relrs: Vec<u64> = RelrIterator::new(LittleEndian, Class::ELF64, &data).collect();
+        assert_eq!(
+            relrs,
+            [
+                0x10000,
+                0x10008,
+                0x10018,
+                0x10000 + 8 + 63 * 8 + 62 * 8,
+                0x20000
+            ]
+        );
+    }
+
+    #[test]
+    fn relr32_msb_decode() {
+        #[rustfmt::skip]
+        let data = [
+            // address entry: 0x1000
+            0x00, 0x00, 0x10, 0x00,
+            // bitmap entry: marker bit + every bit describing the next 31 words
+            0xff, 0xff, 0xff, 0xff,
+        ];
+        let relrs: Vec<u64> = RelrIterator::new(BigEndian, Class::ELF32, &data).collect();
+        let expected: Vec<u64> = core::iter::once(0x1000)
+            .chain((0..31).map(|i| 0x1004 + i * 4))
+            .collect();
+        assert_eq!(relrs, expected);
+    }
+
+    #[test]
+    fn relr_decode_stops_on_truncated_entry() {
+        let data = [0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00];
+        let relrs: Vec<u64> = RelrIterator::new(LittleEndian, Class::ELF64, &data).collect();
+        assert_eq!(relrs, [0x10000]);
+    }
 }
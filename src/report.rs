@@ -0,0 +1,327 @@
+//! Dumping a complete, machine-readable description of an ELF object as JSON
+//!
+//! [to_json] describes the file header, segments, sections, symbols, dynamic entries, and notes
+//! using the raw values of their fields, so the output can be diffed or asserted on in CI
+//! without scraping readelf's text output. Each segment, section, symbol, dynamic entry, and
+//! note is written as a single line, which keeps diffs between two reports readable.
+//!
+//! This module is only available with the `std` cargo feature.
+//!
+//! Example for checking an object's entry point:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let json = elf::report::to_json(&file).expect("file should be parseable");
+//! assert!(json.contains(r#""e_entry": 4195312"#));
+//! ```
+use core::fmt::Write;
+
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::note::NoteAny;
+use crate::parse::ParseError;
+use crate::string_table::StringTable;
+use crate::symbol::SymbolTable;
+use crate::ElfBytes;
+
+/// A JSON value for one of the fields written by [to_json]
+#[derive(Clone)]
+enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    Str(String),
+}
+
+/// Write `s` as a JSON string literal
+fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Write the given fields as a single-line JSON object
+fn write_object(out: &mut String, fields: &[(&str, Value)]) {
+    out.push('{');
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_str(out, key);
+        out.push_str(": ");
+        match value {
+            Value::Unsigned(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Value::Signed(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Value::Str(value) => write_str(out, value),
+        }
+    }
+    out.push('}');
+}
+
+/// Write a top-level `"key": [...]` array with one object per line
+fn write_array(out: &mut String, key: &str, objects: Vec<Vec<(&str, Value)>>) {
+    out.push_str(",\n  ");
+    write_str(out, key);
+    out.push_str(": [");
+    for (i, fields) in objects.iter().enumerate() {
+        out.push_str(if i > 0 { ",\n    " } else { "\n    " });
+        write_object(out, fields);
+    }
+    out.push_str(if objects.is_empty() { "]" } else { "\n  ]" });
+}
+
+/// Get a string from a string table, replacing any invalid utf8
+fn get_name(strtab: &StringTable<'_>, offset: u32) -> Result<String, ParseError> {
+    let raw = strtab.get_raw(offset as usize)?;
+    Ok(String::from_utf8_lossy(raw).into_owned())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(s, "{byte:02x}");
+    }
+    s
+}
+
+fn symbols<'data, E: EndianParse>(
+    tables: Option<(SymbolTable<'data, E>, StringTable<'data>)>,
+) -> Result<Vec<Vec<(&'static str, Value)>>, ParseError> {
+    let (symtab, strtab) = match tables {
+        Some(tables) => tables,
+        None => return Ok(Vec::new()),
+    };
+    symtab
+        .iter()
+        .map(|sym| {
+            Ok(vec![
+                ("name", Value::Str(get_name(&strtab, sym.st_name)?)),
+                ("st_value", Value::Unsigned(sym.st_value)),
+                ("st_size", Value::Unsigned(sym.st_size)),
+                ("st_info", Value::Unsigned(sym.st_info as u64)),
+                ("st_other", Value::Unsigned(sym.st_other as u64)),
+                ("st_shndx", Value::Unsigned(sym.st_shndx as u64)),
+            ])
+        })
+        .collect()
+}
+
+fn notes<E: EndianParse>(
+    endian: E,
+    location: (&'static str, Value),
+    align: u64,
+    data: &[u8],
+    objects: &mut Vec<Vec<(&'static str, Value)>>,
+) -> Result<(), ParseError> {
+    let mut offset = 0;
+    while offset < data.len() {
+        let note = NoteAny::parse_at(endian, align.try_into()?, &mut offset, data)?;
+        objects.push(vec![
+            location.clone(),
+            (
+                "name",
+                Value::Str(
+                    String::from_utf8_lossy(note.name)
+                        .trim_end_matches('\0')
+                        .to_string(),
+                ),
+            ),
+            ("n_type", Value::Unsigned(note.n_type)),
+            ("desc", Value::Str(hex(note.desc))),
+        ]);
+    }
+    Ok(())
+}
+
+/// Describe the whole ELF object as a JSON document.
+///
+/// The document is an object with the keys `header`, `segments`, `sections`, `symbols`,
+/// `dynamic_symbols`, `dynamic`, and `notes`. All fields hold the raw values from the file,
+/// except for names, which are resolved through the appropriate string table, and note
+/// descriptors, which are hex strings. Notes are read from the SHT_NOTE sections, or from the
+/// PT_NOTE segments if the object has no section headers.
+pub fn to_json<E: EndianParse>(file: &ElfBytes<'_, E>) -> Result<String, ParseError> {
+    let ehdr = &file.ehdr;
+    let mut out = String::from("{\n  \"header\": ");
+    write_object(
+        &mut out,
+        &[
+            (
+                "class",
+                Value::Unsigned(match ehdr.class {
+                    Class::ELF32 => 32,
+                    Class::ELF64 => 64,
+                }),
+            ),
+            (
+                "endianness",
+                Value::Str(
+                    if ehdr.endianness.is_little() {
+                        "little"
+                    } else {
+                        "big"
+                    }
+                    .to_string(),
+                ),
+            ),
+            ("version", Value::Unsigned(ehdr.version as u64)),
+            ("osabi", Value::Unsigned(ehdr.osabi as u64)),
+            ("abiversion", Value::Unsigned(ehdr.abiversion as u64)),
+            ("e_type", Value::Unsigned(ehdr.e_type as u64)),
+            ("e_machine", Value::Unsigned(ehdr.e_machine as u64)),
+            ("e_entry", Value::Unsigned(ehdr.e_entry)),
+            ("e_phoff", Value::Unsigned(ehdr.e_phoff)),
+            ("e_shoff", Value::Unsigned(ehdr.e_shoff)),
+            ("e_flags", Value::Unsigned(ehdr.e_flags as u64)),
+            ("e_ehsize", Value::Unsigned(ehdr.e_ehsize as u64)),
+            ("e_phentsize", Value::Unsigned(ehdr.e_phentsize as u64)),
+            ("e_phnum", Value::Unsigned(ehdr.e_phnum as u64)),
+            ("e_shentsize", Value::Unsigned(ehdr.e_shentsize as u64)),
+            ("e_shnum", Value::Unsigned(ehdr.e_shnum as u64)),
+            ("e_shstrndx", Value::Unsigned(ehdr.e_shstrndx as u64)),
+        ],
+    );
+
+    let mut segments = Vec::new();
+    let mut notes_list = Vec::new();
+    for (index, phdr) in file.segments().into_iter().flatten().enumerate() {
+        segments.push(vec![
+            ("p_type", Value::Unsigned(phdr.p_type as u64)),
+            ("p_flags", Value::Unsigned(phdr.p_flags as u64)),
+            ("p_offset", Value::Unsigned(phdr.p_offset)),
+            ("p_vaddr", Value::Unsigned(phdr.p_vaddr)),
+            ("p_paddr", Value::Unsigned(phdr.p_paddr)),
+            ("p_filesz", Value::Unsigned(phdr.p_filesz)),
+            ("p_memsz", Value::Unsigned(phdr.p_memsz)),
+            ("p_align", Value::Unsigned(phdr.p_align)),
+        ]);
+        if file.section_headers().is_none() && phdr.p_type == abi::PT_NOTE {
+            notes(
+                ehdr.endianness,
+                ("segment", Value::Unsigned(index as u64)),
+                phdr.p_align,
+                file.segment_data(&phdr)?,
+                &mut notes_list,
+            )?;
+        }
+    }
+    write_array(&mut out, "segments", segments);
+
+    let mut sections = Vec::new();
+    if let (Some(shdrs), strtab) = file.section_headers_with_strtab()? {
+        for shdr in shdrs.iter() {
+            let name = match &strtab {
+                Some(strtab) => get_name(strtab, shdr.sh_name)?,
+                None => String::new(),
+            };
+            if shdr.sh_type == abi::SHT_NOTE {
+                notes(
+                    ehdr.endianness,
+                    ("section", Value::Str(name.clone())),
+                    shdr.sh_addralign.max(1),
+                    file.section_data(&shdr)?.0,
+                    &mut notes_list,
+                )?;
+            }
+            sections.push(vec![
+                ("name", Value::Str(name)),
+                ("sh_type", Value::Unsigned(shdr.sh_type as u64)),
+                ("sh_flags", Value::Unsigned(shdr.sh_flags)),
+                ("sh_addr", Value::Unsigned(shdr.sh_addr)),
+                ("sh_offset", Value::Unsigned(shdr.sh_offset)),
+                ("sh_size", Value::Unsigned(shdr.sh_size)),
+                ("sh_link", Value::Unsigned(shdr.sh_link as u64)),
+                ("sh_info", Value::Unsigned(shdr.sh_info as u64)),
+                ("sh_addralign", Value::Unsigned(shdr.sh_addralign)),
+                ("sh_entsize", Value::Unsigned(shdr.sh_entsize)),
+            ]);
+        }
+    }
+    write_array(&mut out, "sections", sections);
+    write_array(&mut out, "symbols", symbols(file.symbol_table()?)?);
+    write_array(
+        &mut out,
+        "dynamic_symbols",
+        symbols(file.dynamic_symbol_table()?)?,
+    );
+
+    let dynamic = file
+        .dynamic()?
+        .into_iter()
+        .flat_map(|table| table.iter())
+        .map(|dyn_| {
+            vec![
+                ("d_tag", Value::Signed(dyn_.d_tag)),
+                ("d_val", Value::Unsigned(dyn_.d_val())),
+            ]
+        })
+        .collect();
+    write_array(&mut out, "dynamic", dynamic);
+    write_array(&mut out, "notes", notes_list);
+    out.push_str("\n}\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn json_string_escaping() {
+        let mut out = String::new();
+        write_str(&mut out, "a\"b\\c\n\u{1}");
+        assert_eq!(out, r#""a\"b\\c\n\u0001""#);
+    }
+
+    #[test]
+    fn to_json_basic() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let json = to_json(&file).expect("should describe the file");
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(lines[0], "{");
+        assert!(lines[1].starts_with(
+            r#"  "header": {"class": 64, "endianness": "little", "version": 1, "osabi": 0,"#
+        ));
+        assert_eq!(lines[2], r#"  "segments": ["#);
+        assert_eq!(
+            lines[3],
+            r#"    {"p_type": 6, "p_flags": 5, "p_offset": 64, "p_vaddr": 4194368, "p_paddr": 4194368, "p_filesz": 448, "p_memsz": 448, "p_align": 8},"#
+        );
+        assert!(json.contains(
+            r#"    {"name": ".interp", "sh_type": 1, "sh_flags": 2, "sh_addr": 4194816, "sh_offset": 512, "sh_size": 28, "sh_link": 0, "sh_info": 0, "sh_addralign": 1, "sh_entsize": 0},"#
+        ));
+        assert!(json.contains(
+            r#"    {"name": "memset", "st_value": 0, "st_size": 0, "st_info": 18, "st_other": 0, "st_shndx": 0},"#
+        ));
+        assert!(json.contains(r#"    {"d_tag": 1, "d_val": 1},"#));
+        assert!(json.contains(
+            r#"    {"section": ".note.ABI-tag", "name": "GNU", "n_type": 1, "desc": "00000000020000000600000020000000"},"#
+        ));
+        assert!(json.ends_with("  ]\n}\n"));
+    }
+}
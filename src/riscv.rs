@@ -0,0 +1,133 @@
+//! Decoding RISC-V specific header data: the [e_flags](crate::file::FileHeader#structfield.e_flags)
+//! of objects built for [EM_RISCV](crate::abi::EM_RISCV)
+//!
+//! The flags record whether the object may contain compressed instructions, which floating
+//! point calling convention it was built for, whether it targets the reduced RV32E/RV64E base
+//! ISA, and whether it requires the total store ordering memory model. Linkers refuse to mix
+//! objects whose float ABIs or RVE bits differ, so these are the first thing to check when a
+//! zkVM or embedded target rejects a binary.
+//!
+//! See [FileHeader::riscv_flags](crate::file::FileHeader::riscv_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The floating point calling convention selected by the
+/// [EF_RISCV_FLOAT_ABI_MASK](abi::EF_RISCV_FLOAT_ABI_MASK) bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiscvFloatAbi {
+    /// Floating point arguments are passed in integer registers
+    /// ([EF_RISCV_FLOAT_ABI_SOFT](abi::EF_RISCV_FLOAT_ABI_SOFT))
+    Soft,
+    /// 32-bit floating point arguments are passed in float registers
+    /// ([EF_RISCV_FLOAT_ABI_SINGLE](abi::EF_RISCV_FLOAT_ABI_SINGLE))
+    Single,
+    /// Floating point arguments up to 64 bits are passed in float registers
+    /// ([EF_RISCV_FLOAT_ABI_DOUBLE](abi::EF_RISCV_FLOAT_ABI_DOUBLE))
+    Double,
+    /// Floating point arguments up to 128 bits are passed in float registers
+    /// ([EF_RISCV_FLOAT_ABI_QUAD](abi::EF_RISCV_FLOAT_ABI_QUAD))
+    Quad,
+}
+
+/// The decoded e_flags of a RISC-V object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RiscvFlags {
+    /// The object may contain compressed instructions ([EF_RISCV_RVC](abi::EF_RISCV_RVC))
+    pub rvc: bool,
+    /// The floating point calling convention the object was built for
+    pub float_abi: RiscvFloatAbi,
+    /// The object targets the RV32E/RV64E base ISA, which only has 16 integer registers
+    /// ([EF_RISCV_RVE](abi::EF_RISCV_RVE))
+    pub rve: bool,
+    /// The object requires the RVTSO memory consistency model ([EF_RISCV_TSO](abi::EF_RISCV_TSO))
+    pub tso: bool,
+}
+
+impl RiscvFlags {
+    /// Decode the given e_flags value. Bits which aren't defined by the psABI are ignored.
+    pub fn from_e_flags(e_flags: u32) -> Self {
+        let float_abi = match e_flags & abi::EF_RISCV_FLOAT_ABI_MASK {
+            abi::EF_RISCV_FLOAT_ABI_SINGLE => RiscvFloatAbi::Single,
+            abi::EF_RISCV_FLOAT_ABI_DOUBLE => RiscvFloatAbi::Double,
+            abi::EF_RISCV_FLOAT_ABI_QUAD => RiscvFloatAbi::Quad,
+            _ => RiscvFloatAbi::Soft,
+        };
+        RiscvFlags {
+            rvc: e_flags & abi::EF_RISCV_RVC != 0,
+            float_abi,
+            rve: e_flags & abi::EF_RISCV_RVE != 0,
+            tso: e_flags & abi::EF_RISCV_TSO != 0,
+        }
+    }
+}
+
+impl EFlagsDecoder for RiscvFlags {
+    const E_MACHINE: u16 = abi::EM_RISCV;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        RiscvFlags::from_e_flags(ehdr.e_flags)
+    }
+}
+
+/// Formats the flags like readelf, e.g. "RVC, double-float ABI"
+impl core::fmt::Display for RiscvFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.rvc {
+            write!(f, "RVC, ")?;
+        }
+        let float_abi = match self.float_abi {
+            RiscvFloatAbi::Soft => "soft-float ABI",
+            RiscvFloatAbi::Single => "single-float ABI",
+            RiscvFloatAbi::Double => "double-float ABI",
+            RiscvFloatAbi::Quad => "quad-float ABI",
+        };
+        write!(f, "{float_abi}")?;
+        if self.rve {
+            write!(f, ", RVE")?;
+        }
+        if self.tso {
+            write!(f, ", TSO")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            RiscvFlags::from_e_flags(abi::EF_RISCV_RVC | abi::EF_RISCV_FLOAT_ABI_DOUBLE),
+            RiscvFlags {
+                rvc: true,
+                float_abi: RiscvFloatAbi::Double,
+                rve: false,
+                tso: false,
+            }
+        );
+        assert_eq!(
+            RiscvFlags::from_e_flags(abi::EF_RISCV_RVE | abi::EF_RISCV_TSO | 0xff000000),
+            RiscvFlags {
+                rvc: false,
+                float_abi: RiscvFloatAbi::Soft,
+                rve: true,
+                tso: true,
+            }
+        );
+        assert_eq!(
+            RiscvFlags::from_e_flags(abi::EF_RISCV_FLOAT_ABI_QUAD).float_abi,
+            RiscvFloatAbi::Quad
+        );
+    }
+
+    #[test]
+    fn display_matches_readelf() {
+        let flags = RiscvFlags::from_e_flags(0x5);
+        assert_eq!(flags.to_string(), "RVC, double-float ABI");
+        let flags = RiscvFlags::from_e_flags(abi::EF_RISCV_FLOAT_ABI_SINGLE | abi::EF_RISCV_TSO);
+        assert_eq!(flags.to_string(), "single-float ABI, TSO");
+    }
+}
@@ -45,6 +45,7 @@ pub struct Elf64_Shdr {
 ///
 /// This is a Rust-native type that represents a Section Header that is bit-width-agnostic.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionHeader {
     /// Section Name
     pub sh_name: u32,
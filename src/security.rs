@@ -0,0 +1,310 @@
+//! Reporting the exploit mitigations an object was built with, like `checksec`: [report]
+//!
+//! The [SecurityReport] covers the properties security scanners usually look at:
+//!
+//! * NX: whether the stack is non-executable, from the [PT_GNU_STACK](crate::abi::PT_GNU_STACK)
+//!   segment's flags, see [ElfBytes::stack_is_executable](crate::ElfBytes::stack_is_executable).
+//! * RELRO: whether the [PT_GNU_RELRO](crate::abi::PT_GNU_RELRO) region gets made read-only
+//!   after relocation, and whether that includes the GOT (full RELRO, which needs BIND_NOW).
+//! * PIE: whether the executable is position-independent, see
+//!   [ElfBytes::kind](crate::ElfBytes::kind).
+//! * Stack canaries: whether the object references `__stack_chk_fail` or `__stack_chk_guard`.
+//! * FORTIFY_SOURCE: which fortified `__*_chk` libc functions the object references.
+//! * BIND_NOW: whether all symbols get bound at load time rather than lazily.
+//! * Control-flow protection: x86 CET (IBT and SHSTK) and AArch64 BTI and PAC, from the
+//!   object's GNU property note.
+//!
+//! Symbols are looked up in the dynamic symbol table and, if the object isn't stripped, the
+//! symbol table, so statically linked objects get reported on as well.
+//!
+//! This module is only available with the `std` cargo feature.
+//!
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//! use elf::security::Relro;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let report = elf::security::report(&file).expect("object should be parseable");
+//! assert!(report.nx);
+//! assert!(report.is_pie());
+//! assert_eq!(report.relro, Relro::Partial);
+//! assert!(!report.canary);
+//! println!("{report}");
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::FileKind;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+use std::collections::BTreeSet;
+
+/// How much of the object's relocated data gets made read-only after relocation
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Relro {
+    /// There's no [PT_GNU_RELRO](abi::PT_GNU_RELRO) segment.
+    None,
+    /// The PT_GNU_RELRO region is made read-only, but the GOT entries for lazily bound
+    /// functions stay writable.
+    Partial,
+    /// The PT_GNU_RELRO region is made read-only, and all symbols are bound at load time so
+    /// that includes the whole GOT.
+    Full,
+}
+
+impl core::fmt::Display for Relro {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Relro::None => write!(f, "No RELRO"),
+            Relro::Partial => write!(f, "Partial RELRO"),
+            Relro::Full => write!(f, "Full RELRO"),
+        }
+    }
+}
+
+/// The exploit mitigations found by [report]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityReport {
+    /// The kind of object, which tells whether it's position-independent
+    pub kind: FileKind,
+    /// The stack isn't executable, see
+    /// [ElfBytes::stack_is_executable](crate::ElfBytes::stack_is_executable)
+    pub nx: bool,
+    /// The RELRO level
+    pub relro: Relro,
+    /// All symbols get bound at load time: the object has [DT_BIND_NOW](abi::DT_BIND_NOW),
+    /// [DF_BIND_NOW](abi::DF_BIND_NOW), or [DF_1_NOW](abi::DF_1_NOW).
+    pub bind_now: bool,
+    /// The object was built with stack canaries: it references `__stack_chk_fail` or
+    /// `__stack_chk_guard`.
+    pub canary: bool,
+    /// The fortified functions the object references, e.g. `__memcpy_chk`, in sorted order.
+    /// This is non-empty for objects built with `-D_FORTIFY_SOURCE` which call any of the
+    /// functions it covers.
+    pub fortified: Vec<String>,
+    /// Built for x86 CET indirect branch tracking
+    /// ([GNU_PROPERTY_X86_FEATURE_1_IBT](abi::GNU_PROPERTY_X86_FEATURE_1_IBT))
+    pub ibt: bool,
+    /// Built for x86 CET shadow stacks
+    /// ([GNU_PROPERTY_X86_FEATURE_1_SHSTK](abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK))
+    pub shstk: bool,
+    /// Built for AArch64 branch target identification
+    /// ([GNU_PROPERTY_AARCH64_FEATURE_1_BTI](abi::GNU_PROPERTY_AARCH64_FEATURE_1_BTI))
+    pub bti: bool,
+    /// Built for AArch64 pointer authentication
+    /// ([GNU_PROPERTY_AARCH64_FEATURE_1_PAC](abi::GNU_PROPERTY_AARCH64_FEATURE_1_PAC))
+    pub pac: bool,
+}
+
+impl SecurityReport {
+    /// Returns true if the object is a position-independent executable, static or not
+    pub fn is_pie(&self) -> bool {
+        matches!(self.kind, FileKind::Pie | FileKind::StaticPie)
+    }
+}
+
+/// Formats the report as one `name: value` line per mitigation
+impl core::fmt::Display for SecurityReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let yes_no = |enabled: bool| if enabled { "yes" } else { "no" };
+        writeln!(f, "RELRO: {}", self.relro)?;
+        writeln!(f, "Stack canary: {}", yes_no(self.canary))?;
+        writeln!(f, "NX: {}", yes_no(self.nx))?;
+        let pie = match self.kind {
+            FileKind::Pie | FileKind::StaticPie => "PIE",
+            FileKind::SharedObject => "DSO",
+            _ => "No PIE",
+        };
+        writeln!(f, "PIE: {pie}")?;
+        writeln!(f, "BIND_NOW: {}", yes_no(self.bind_now))?;
+        writeln!(f, "Fortified: {}", self.fortified.len())?;
+        writeln!(f, "IBT: {}", yes_no(self.ibt))?;
+        writeln!(f, "SHSTK: {}", yes_no(self.shstk))?;
+        writeln!(f, "BTI: {}", yes_no(self.bti))?;
+        write!(f, "PAC: {}", yes_no(self.pac))
+    }
+}
+
+/// Report on the exploit mitigations the given object was built with.
+///
+/// Returns an error if the tables and notes the report is based on can't be parsed.
+pub fn report<E: EndianParse>(file: &ElfBytes<'_, E>) -> Result<SecurityReport, ParseError> {
+    let has_relro = file.segments().map_or(false, |phdrs| {
+        phdrs.iter().any(|phdr| phdr.p_type == abi::PT_GNU_RELRO)
+    });
+
+    let mut bind_now = false;
+    if let Some(dynamic) = file.dynamic()? {
+        for entry in dynamic.iter_until_null() {
+            bind_now |= match entry.d_tag {
+                abi::DT_BIND_NOW => true,
+                abi::DT_FLAGS => entry.d_val() & abi::DF_BIND_NOW as u64 != 0,
+                abi::DT_FLAGS_1 => entry.d_val() & abi::DF_1_NOW as u64 != 0,
+                _ => false,
+            };
+        }
+    }
+    let relro = match (has_relro, bind_now) {
+        (false, _) => Relro::None,
+        (true, false) => Relro::Partial,
+        (true, true) => Relro::Full,
+    };
+
+    let mut canary = false;
+    let mut fortified = BTreeSet::new();
+    let mut check_symbol = |name: &str| {
+        if is_canary_symbol(name) {
+            canary = true;
+        } else if is_fortified_function(name) {
+            fortified.insert(name.to_string());
+        }
+    };
+    if let Some((symtab, strtab)) = file.dynamic_symbol_table()? {
+        for symbol in symtab.iter() {
+            check_symbol(strtab.get(symbol.st_name as usize)?);
+        }
+    }
+    if let Some((symtab, strtab)) = file.symbol_table()? {
+        for symbol in symtab.iter() {
+            check_symbol(strtab.get(symbol.st_name as usize)?);
+        }
+    }
+
+    let (mut x86_features, mut aarch64_features) = (0, 0);
+    if let Some(property) = file.gnu_property()? {
+        let endian = file.ehdr.endianness;
+        match file.ehdr.e_machine {
+            abi::EM_386 | abi::EM_X86_64 => {
+                x86_features = property.get_u32(endian, abi::GNU_PROPERTY_X86_FEATURE_1_AND)?;
+            }
+            abi::EM_AARCH64 => {
+                aarch64_features =
+                    property.get_u32(endian, abi::GNU_PROPERTY_AARCH64_FEATURE_1_AND)?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(SecurityReport {
+        kind: file.kind()?,
+        nx: !file.stack_is_executable()?,
+        relro,
+        bind_now,
+        canary,
+        fortified: fortified.into_iter().collect(),
+        ibt: x86_features & abi::GNU_PROPERTY_X86_FEATURE_1_IBT != 0,
+        shstk: x86_features & abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0,
+        bti: aarch64_features & abi::GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0,
+        pac: aarch64_features & abi::GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0,
+    })
+}
+
+/// Returns true for the symbols which code built with stack canaries references
+fn is_canary_symbol(name: &str) -> bool {
+    matches!(
+        name,
+        "__stack_chk_fail" | "__stack_chk_fail_local" | "__stack_chk_guard"
+    )
+}
+
+/// Returns true for the names of the fortified variants of libc functions, which have the
+/// form `__<function>_chk`, e.g. `__memcpy_chk` or `__printf_chk`
+fn is_fortified_function(name: &str) -> bool {
+    match name
+        .strip_prefix("__")
+        .and_then(|name| name.strip_suffix("_chk"))
+    {
+        Some(function) => !function.is_empty(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn report_for(name: &str) -> SecurityReport {
+        let path = std::path::PathBuf::from("sample-objects").join(name);
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        report(&file).expect("should report")
+    }
+
+    #[test]
+    fn report_sample_objects() {
+        let basic = report_for("basic.x86_64");
+        assert_eq!(basic.kind, FileKind::DynamicExecutable);
+        assert!(basic.nx);
+        assert_eq!(basic.relro, Relro::None);
+        assert!(!basic.bind_now);
+        assert!(!basic.canary);
+        assert!(basic.fortified.is_empty());
+
+        let symver = report_for("symver.x86_64.so");
+        assert_eq!(symver.kind, FileKind::SharedObject);
+        assert_eq!(symver.relro, Relro::Partial);
+        assert!(symver.ibt);
+        assert!(symver.shstk);
+        assert!(!symver.bti);
+
+        let aarch64 = report_for("symver.aarch64.so");
+        assert!(!aarch64.bti);
+        assert!(!aarch64.ibt);
+    }
+
+    #[test]
+    fn executable_stack() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let index = file
+            .segments()
+            .expect("Failed to find segments")
+            .iter()
+            .position(|phdr| phdr.p_type == abi::PT_GNU_STACK)
+            .expect("Failed to find PT_GNU_STACK");
+        // p_flags follows p_type in an ELF64 program header
+        let offset = file.ehdr.e_phoff as usize + index * file.ehdr.e_phentsize as usize + 4;
+        let flags = abi::PF_R | abi::PF_W | abi::PF_X;
+        file_data[offset..offset + 4].copy_from_slice(&flags.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(!report(&file).expect("should report").nx);
+    }
+
+    #[test]
+    fn display() {
+        let report = report_for("minidebuginfo.x86_64");
+        assert_eq!(
+            report.to_string(),
+            "RELRO: Partial RELRO\n\
+             Stack canary: no\n\
+             NX: yes\n\
+             PIE: PIE\n\
+             BIND_NOW: no\n\
+             Fortified: 0\n\
+             IBT: no\n\
+             SHSTK: no\n\
+             BTI: no\n\
+             PAC: no"
+        );
+    }
+
+    #[test]
+    fn symbol_names() {
+        assert!(is_canary_symbol("__stack_chk_fail"));
+        assert!(is_canary_symbol("__stack_chk_guard"));
+        assert!(!is_canary_symbol("stack_chk_fail"));
+
+        assert!(is_fortified_function("__memcpy_chk"));
+        assert!(is_fortified_function("__printf_chk"));
+        assert!(!is_fortified_function("__stack_chk_fail"));
+        assert!(!is_fortified_function("memcpy"));
+        assert!(!is_fortified_function("___chk"));
+        assert!(!is_fortified_function("__memcpy_chk_impl"));
+    }
+}
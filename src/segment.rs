@@ -1,7 +1,10 @@
 //! Parsing the Program Header table aka Segment table aka `Elf_Phdr`
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::section::SectionHeader;
+use crate::symbol::Symbol;
 
 pub type SegmentTable<'data, E> = ParsingTable<'data, E, ProgramHeader>;
 
@@ -42,6 +45,7 @@ pub struct Elf64_Phdr {
 /// The program header table is an array of program header structures describing
 /// the various segments for program execution.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramHeader {
     /// Program segment type
     pub p_type: u32,
@@ -112,6 +116,102 @@ impl ParseAt for ProgramHeader {
 }
 
 impl ProgramHeader {
+    /// Translate a virtual address within this segment to its offset in the file.
+    ///
+    /// Returns None if the address isn't backed by the segment's file data, including
+    /// addresses in the zero-filled part between p_filesz and p_memsz.
+    pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        let delta = vaddr.checked_sub(self.p_vaddr)?;
+        if delta >= self.p_filesz {
+            return None;
+        }
+        self.p_offset.checked_add(delta)
+    }
+
+    /// Translate a file offset within this segment's file data to its virtual address.
+    ///
+    /// Returns None if the offset isn't within the segment's file data.
+    pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+        let delta = offset.checked_sub(self.p_offset)?;
+        if delta >= self.p_filesz {
+            return None;
+        }
+        self.p_vaddr.checked_add(delta)
+    }
+
+    /// Returns true if the given section lies within this segment, using the same rules as
+    /// `readelf -l`'s section to segment mapping.
+    ///
+    /// Sections with file data must lie within the segment's file data, and
+    /// [SHF_ALLOC](abi::SHF_ALLOC) sections must also lie within its memory image.
+    /// [SHF_TLS](abi::SHF_TLS) sections only belong to PT_TLS, PT_LOAD and PT_GNU_RELRO
+    /// segments, and a `.tbss` doesn't take up space in the PT_LOAD containing it.
+    pub fn contains_section(&self, shdr: &SectionHeader) -> bool {
+        if shdr.sh_type == abi::SHT_NULL {
+            return false;
+        }
+
+        let is_tls = shdr.sh_flags & abi::SHF_TLS as u64 != 0;
+        let is_alloc = shdr.sh_flags & abi::SHF_ALLOC as u64 != 0;
+        let is_nobits = shdr.sh_type == abi::SHT_NOBITS;
+
+        // .tbss only occupies space in the PT_TLS segment's memory image
+        if is_tls && is_nobits && self.p_type != abi::PT_TLS {
+            return false;
+        }
+
+        let type_ok = if is_tls {
+            matches!(self.p_type, abi::PT_TLS | abi::PT_GNU_RELRO | abi::PT_LOAD)
+        } else {
+            self.p_type != abi::PT_TLS && self.p_type != abi::PT_PHDR
+        };
+        if !type_ok {
+            return false;
+        }
+
+        // Loadable segments and their sub-segments only hold SHF_ALLOC sections
+        if !is_alloc
+            && matches!(
+                self.p_type,
+                abi::PT_LOAD
+                    | abi::PT_DYNAMIC
+                    | abi::PT_GNU_EH_FRAME
+                    | abi::PT_GNU_STACK
+                    | abi::PT_GNU_RELRO
+            )
+        {
+            return false;
+        }
+
+        if !is_nobits && !range_within(shdr.sh_offset, shdr.sh_size, self.p_offset, self.p_filesz) {
+            return false;
+        }
+
+        if is_alloc && !range_within(shdr.sh_addr, shdr.sh_size, self.p_vaddr, self.p_memsz) {
+            return false;
+        }
+
+        // Empty sections at the very start or end of a PT_DYNAMIC or PT_NOTE don't count
+        if matches!(self.p_type, abi::PT_DYNAMIC | abi::PT_NOTE)
+            && shdr.sh_size == 0
+            && self.p_memsz != 0
+        {
+            let offset_inside = is_nobits
+                || (shdr.sh_offset > self.p_offset
+                    && shdr.sh_offset - self.p_offset < self.p_filesz);
+            let addr_inside = !is_alloc
+                || (shdr.sh_addr > self.p_vaddr && shdr.sh_addr - self.p_vaddr < self.p_memsz);
+            return offset_inside && addr_inside;
+        }
+
+        true
+    }
+
+    /// The permissions the segment is mapped with, from its p_flags
+    pub fn perms(&self) -> Perms {
+        Perms::from_p_flags(self.p_flags)
+    }
+
     /// Helper method which uses checked integer math to get a tuple of (start, end) for
     /// the location in bytes for this ProgramHeader's data in the file.
     /// i.e. (p_offset, p_offset + p_filesz)
@@ -121,6 +221,182 @@ impl ProgramHeader {
         let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
         Ok((start, end))
     }
+
+    /// Helper method which zero-extends this segment's file data (`buf`) out to p_memsz bytes,
+    /// or returns it as-is if there is nothing to extend.
+    ///
+    /// p_memsz comes from the file, so this returns [ParseError::AllocationFailed] rather than
+    /// aborting if the buffer can't be allocated.
+    #[cfg(feature = "std")]
+    pub(crate) fn zero_extend<'buf>(
+        &self,
+        buf: &'buf [u8],
+    ) -> Result<std::borrow::Cow<'buf, [u8]>, ParseError> {
+        zero_extend(buf, self.p_memsz)
+    }
+}
+
+/// The read, write, and execute permissions a segment is mapped with, from its
+/// [p_flags](ProgramHeader#structfield.p_flags)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Perms {
+    /// [PF_R](abi::PF_R)
+    pub r: bool,
+    /// [PF_W](abi::PF_W)
+    pub w: bool,
+    /// [PF_X](abi::PF_X)
+    pub x: bool,
+}
+
+impl Perms {
+    /// Decode the permission bits of the given p_flags value. Other bits are ignored.
+    pub fn from_p_flags(p_flags: u32) -> Self {
+        Perms {
+            r: p_flags & abi::PF_R != 0,
+            w: p_flags & abi::PF_W != 0,
+            x: p_flags & abi::PF_X != 0,
+        }
+    }
+}
+
+/// Formats the permissions like `ls` and `/proc/<pid>/maps`, e.g. "r-x"
+impl core::fmt::Display for Perms {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let r = if self.r { "r" } else { "-" };
+        let w = if self.w { "w" } else { "-" };
+        let x = if self.x { "x" } else { "-" };
+        write!(f, "{r}{w}{x}")
+    }
+}
+
+/// A [PT_LOAD](abi::PT_LOAD) segment with everything a loader needs to map it
+///
+/// The segment occupies `memsz` bytes at `vaddr`, initialized from `data` followed by zeros
+/// (e.g. for a `.bss`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadableSegment<'data> {
+    /// Virtual address the segment is loaded at
+    pub vaddr: u64,
+    /// Physical address the segment is loaded at, for systems where that's relevant
+    pub paddr: u64,
+    /// The segment's file data. This is never longer than `memsz`.
+    pub data: &'data [u8],
+    /// Size of the segment in memory
+    pub memsz: u64,
+    /// The permissions the segment is mapped with
+    pub perms: Perms,
+    /// Required alignment of the segment
+    pub align: u64,
+}
+
+impl<'data> LoadableSegment<'data> {
+    /// Create a LoadableSegment from a PT_LOAD program header and its segment data.
+    ///
+    /// If the segment's p_filesz exceeds its p_memsz, the data is cut off at p_memsz bytes.
+    pub fn new(phdr: &ProgramHeader, data: &'data [u8]) -> Self {
+        let len = (data.len() as u64).min(phdr.p_memsz) as usize;
+        LoadableSegment {
+            vaddr: phdr.p_vaddr,
+            paddr: phdr.p_paddr,
+            data: &data[..len],
+            memsz: phdr.p_memsz,
+            perms: Perms::from_p_flags(phdr.p_flags),
+            align: phdr.p_align,
+        }
+    }
+
+    /// Size of the zero-initialized part of the segment which follows its file data.
+    pub fn zero_fill_size(&self) -> u64 {
+        self.memsz.saturating_sub(self.data.len() as u64)
+    }
+
+    /// Get the segment's contents as laid out in memory: its file data followed by zeros up
+    /// to `memsz`.
+    ///
+    /// Segments with no zero-filled part are returned as-is, without being copied. Otherwise,
+    /// this allocates `memsz` bytes, and returns [ParseError::AllocationFailed] if that fails.
+    #[cfg(feature = "std")]
+    pub fn data_zero_extended(&self) -> Result<std::borrow::Cow<'data, [u8]>, ParseError> {
+        zero_extend(self.data, self.memsz)
+    }
+}
+
+/// Zero-extend `buf` out to `memsz` bytes, or return it as-is if there is nothing to extend.
+#[cfg(feature = "std")]
+fn zero_extend(buf: &[u8], memsz: u64) -> Result<std::borrow::Cow<'_, [u8]>, ParseError> {
+    let size: usize = memsz.try_into()?;
+    if size <= buf.len() {
+        return Ok(std::borrow::Cow::Borrowed(buf));
+    }
+    let mut data = Vec::new();
+    data.try_reserve_exact(size)
+        .map_err(|_| ParseError::AllocationFailed(memsz))?;
+    data.extend_from_slice(buf);
+    data.resize(size, 0);
+    Ok(std::borrow::Cow::Owned(data))
+}
+
+/// Returns true if the `size` bytes at `start` begin within, and don't run past the end of,
+/// the `outer_size` bytes at `outer_start`.
+fn range_within(start: u64, size: u64, outer_start: u64, outer_size: u64) -> bool {
+    if start < outer_start {
+        return false;
+    }
+    let offset = start - outer_start;
+    offset <= outer_size.wrapping_sub(1)
+        && offset
+            .checked_add(size)
+            .map_or(false, |end| end <= outer_size)
+}
+
+/// The thread-local storage initialization template described by a
+/// [PT_TLS](crate::abi::PT_TLS) segment.
+///
+/// Each thread gets its own copy of the TLS block, which is initialized from the template's
+/// image (the `.tdata` section) followed by zeros up to `memsz` (the `.tbss` section). The
+/// template itself lives at `vaddr`, but that is not where any thread's variables live at
+/// runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsTemplate<'data> {
+    /// Virtual address of the initialization image
+    pub vaddr: u64,
+    /// The initialization image, i.e. the initial contents of the TLS block's `.tdata` part
+    pub image: &'data [u8],
+    /// Total size of the TLS block, including the zero-initialized `.tbss` part
+    pub memsz: u64,
+    /// Required alignment of the TLS block
+    pub align: u64,
+}
+
+impl<'data> TlsTemplate<'data> {
+    /// Create a TlsTemplate from a PT_TLS program header and its segment data.
+    pub fn new(phdr: &ProgramHeader, image: &'data [u8]) -> Self {
+        TlsTemplate {
+            vaddr: phdr.p_vaddr,
+            image,
+            memsz: phdr.p_memsz,
+            align: phdr.p_align,
+        }
+    }
+
+    /// Size of the zero-initialized part of the TLS block which follows the image.
+    pub fn zero_fill_size(&self) -> u64 {
+        self.memsz.saturating_sub(self.image.len() as u64)
+    }
+
+    /// Get the initial value of a defined [STT_TLS](crate::abi::STT_TLS) symbol from the
+    /// image, using its st_value as an offset into the TLS block.
+    ///
+    /// Returns None if the symbol isn't a defined TLS symbol or it doesn't lie within the image,
+    /// e.g. because it's zero-initialized.
+    pub fn symbol_image(&self, sym: &Symbol) -> Option<&'data [u8]> {
+        if !sym.is_tls() || sym.is_undefined() {
+            return None;
+        }
+        let start = usize::try_from(sym.st_value).ok()?;
+        let end = start.checked_add(usize::try_from(sym.st_size).ok()?)?;
+        self.image.get(start..end)
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +405,29 @@ mod parse_tests {
     use crate::endian::{BigEndian, LittleEndian};
     use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
 
+    #[test]
+    fn vaddr_offset_translation() {
+        let phdr = ProgramHeader {
+            p_type: abi::PT_LOAD,
+            p_offset: 0x6c0,
+            p_vaddr: 0x6006c0,
+            p_paddr: 0x6006c0,
+            p_filesz: 0x228,
+            p_memsz: 0x40260,
+            p_flags: abi::PF_R | abi::PF_W,
+            p_align: 0x200000,
+        };
+        assert_eq!(phdr.vaddr_to_offset(0x6006c0), Some(0x6c0));
+        assert_eq!(phdr.vaddr_to_offset(0x6008e7), Some(0x8e7));
+        // .bss, and addresses outside of the segment altogether
+        assert_eq!(phdr.vaddr_to_offset(0x6008e8), None);
+        assert_eq!(phdr.vaddr_to_offset(0x6006bf), None);
+
+        assert_eq!(phdr.offset_to_vaddr(0x700), Some(0x600700));
+        assert_eq!(phdr.offset_to_vaddr(0x8e8), None);
+        assert_eq!(phdr.offset_to_vaddr(0), None);
+    }
+
     #[test]
     fn parse_phdr32_lsb() {
         test_parse_for(
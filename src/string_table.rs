@@ -33,6 +33,85 @@ impl<'data> StringTable<'data> {
         let raw_data = self.get_raw(offset)?;
         Ok(from_utf8(raw_data)?)
     }
+
+    /// Lazily resolve a batch of string offsets (e.g. `st_name`s, DT_NEEDED values, `vd_name`s)
+    /// through this table.
+    ///
+    /// Each offset is bounds-checked and resolved independently with [StringTable::get],
+    /// so the iterator yields one `Result` per offset and keeps going past bad ones. Collect
+    /// into a `Result<Vec<_>, _>` to instead bail out on the first error.
+    pub fn resolve<I: IntoIterator<Item = usize>>(
+        &self,
+        offsets: I,
+    ) -> StringTableResolver<'data, I::IntoIter> {
+        StringTableResolver {
+            strtab: *self,
+            offsets: offsets.into_iter(),
+        }
+    }
+
+    /// Iterate over the non-empty strings in the table, in the order they appear.
+    ///
+    /// This is useful for sections which are just a list of NUL-terminated strings, like `.comment`.
+    /// If the table doesn't end with a NUL, the iterator yields a
+    /// [ParseError::StringTableMissingNul] for the trailing bytes.
+    pub fn iter(&self) -> StringTableIterator<'data> {
+        StringTableIterator {
+            data: self.data,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over the non-empty strings in a [StringTable].
+///
+/// See [StringTable::iter].
+#[derive(Debug)]
+pub struct StringTableIterator<'data> {
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data> Iterator for StringTableIterator<'data> {
+    type Item = Result<&'data str, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.offset;
+            let rest = self.data.get(start..).filter(|rest| !rest.is_empty())?;
+            let len = match rest.iter().position(|&b| b == 0u8) {
+                Some(len) => len,
+                None => {
+                    self.offset = self.data.len();
+                    return Some(Err(ParseError::StringTableMissingNul(start as u64)));
+                }
+            };
+            self.offset = start + len + 1;
+            if len != 0 {
+                return Some(from_utf8(&rest[..len]).map_err(ParseError::from));
+            }
+        }
+    }
+}
+
+/// Iterator which resolves a sequence of offsets into strings from a [StringTable].
+///
+/// See [StringTable::resolve].
+#[derive(Debug)]
+pub struct StringTableResolver<'data, I: Iterator<Item = usize>> {
+    strtab: StringTable<'data>,
+    offsets: I,
+}
+
+impl<'data, I: Iterator<Item = usize>> Iterator for StringTableResolver<'data, I> {
+    type Item = Result<&'data str, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offsets.next()?;
+        Some(self.strtab.get(offset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
 }
 
 #[cfg(test)]
@@ -49,6 +128,32 @@ mod tests {
     /// Note: ELF string tables are defined to always start with a NUL and use
     /// index 0 to give an empty string, so getting a string starting at a NUL
     /// should properly give an empty string.
+    #[test]
+    fn test_iter_skips_empty_strings() {
+        let data = b"\0GCC: (GNU) 12.2.0\0\0Linker: LLD 17\0";
+        let st = StringTable::new(data);
+        let strings: Result<Vec<&str>, ParseError> = st.iter().collect();
+        assert_eq!(
+            strings.expect("should parse"),
+            ["GCC: (GNU) 12.2.0", "Linker: LLD 17"]
+        );
+        assert!(StringTable::default().iter().next().is_none());
+    }
+
+    #[test]
+    fn test_iter_missing_trailing_nul_errors() {
+        let data = b"ELF\0abc";
+        let st = StringTable::new(data);
+        let mut iter = st.iter();
+        assert_eq!(iter.next().unwrap().unwrap(), "ELF");
+        let err = iter.next().unwrap().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(4)),
+            "Unexpected Error type found: {err}"
+        );
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn test_get_index_0_gives_empty_string() {
         let data = [0u8, 42u8, 0u8];
@@ -113,4 +218,33 @@ mod tests {
             "Unexpected Error type found: {result:?}"
         );
     }
+
+    #[test]
+    fn test_resolve_yields_result_per_offset() {
+        let data = [0u8, 0x45, 0x4C, 0x46, 0u8, 0x41, 0u8];
+        let st = StringTable::new(&data);
+        let offsets: [u32; 4] = [1, 5, 9, 0];
+        let mut strs = st.resolve(offsets.iter().map(|&o| o as usize));
+        assert_eq!(strs.size_hint(), (4, Some(4)));
+        assert_eq!(strs.next().unwrap().unwrap(), "ELF");
+        assert_eq!(strs.next().unwrap().unwrap(), "A");
+        let result = strs.next().unwrap();
+        assert!(
+            matches!(result, Err(ParseError::BadOffset(9))),
+            "Unexpected Error type found: {result:?}"
+        );
+        assert_eq!(strs.next().unwrap().unwrap(), "");
+        assert!(strs.next().is_none());
+    }
+
+    #[test]
+    fn test_resolve_collect_bails_on_first_error() {
+        let data = [0u8, 0x45, 0x4C, 0x46];
+        let st = StringTable::new(&data);
+        let result: Result<Vec<&str>, ParseError> = st.resolve([0, 1]).collect();
+        assert!(
+            matches!(result, Err(ParseError::StringTableMissingNul(1))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
 }
@@ -3,9 +3,87 @@ use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::string_table::StringTable;
 
 pub type SymbolTable<'data, E> = ParsingTable<'data, E, Symbol>;
 
+impl<'data, E: EndianParse> SymbolTable<'data, E> {
+    /// Find the first symbol table entry with the given name, returning its index and symbol.
+    ///
+    /// Each call is a linear scan over the table, so it costs O(n) in the number of symbols.
+    /// For repeated lookups in a `.dynsym`, prefer the object's hash table (see
+    /// [SysVHashTable::find](crate::hash::SysVHashTable::find) and
+    /// [GnuHashTable::find](crate::hash::GnuHashTable::find)), or build a map from names to
+    /// indexes once.
+    pub fn find_by_name(
+        &self,
+        name: &str,
+        strtab: &StringTable<'data>,
+    ) -> Result<Option<(usize, Symbol)>, ParseError> {
+        for (index, symbol) in self.iter().enumerate() {
+            // Compare the raw bytes so that other symbols' non-UTF-8 names don't cause errors
+            if strtab.get_raw(symbol.st_name as usize)? == name.as_bytes() {
+                return Ok(Some((index, symbol)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the symbol which the given address belongs to, returning the symbol, its name, and
+    /// the address's offset from the symbol's start.
+    ///
+    /// Named, defined symbols whose `[st_value, st_value + st_size)` range contains the address
+    /// are preferred, with [STT_FUNC](abi::STT_FUNC) symbols winning over other types. If no
+    /// symbol's range contains the address, the nearest function, object or untyped symbol
+    /// before it is used instead, since hand-written assembly often has symbols without sizes.
+    pub fn symbolize(
+        &self,
+        addr: u64,
+        strtab: &StringTable<'data>,
+    ) -> Result<Option<(Symbol, &'data str, u64)>, ParseError> {
+        // Candidates are ranked by (contains addr, function containing addr, st_value, is a
+        // function), where higher is better
+        let mut best: Option<((bool, bool, u64, bool), Symbol)> = None;
+        for sym in self.iter() {
+            if sym.is_undefined()
+                || sym.st_shndx == abi::SHN_ABS
+                || sym.st_value > addr
+                || !matches!(
+                    sym.st_symtype(),
+                    abi::STT_NOTYPE | abi::STT_OBJECT | abi::STT_FUNC | abi::STT_GNU_IFUNC
+                )
+            {
+                continue;
+            }
+
+            let offset = addr - sym.st_value;
+            let is_func = matches!(sym.st_symtype(), abi::STT_FUNC | abi::STT_GNU_IFUNC);
+            let contains = offset < sym.st_size;
+            let rank = (contains, contains && is_func, sym.st_value, is_func);
+            if best
+                .as_ref()
+                .map_or(true, |(best_rank, _)| rank > *best_rank)
+                && !strtab.get_raw(sym.st_name as usize)?.is_empty()
+            {
+                best = Some((rank, sym));
+            }
+        }
+
+        match best {
+            Some((_, sym)) => {
+                let name = strtab.get(sym.st_name as usize)?;
+                let offset = addr - sym.st_value;
+                Ok(Some((sym, name, offset)))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// The contents of a [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX) section: one extended section
+/// index per entry of its associated symbol table. See [Symbol::section_index].
+pub type SymbolShndxTable<'data, E> = ParsingTable<'data, E, u32>;
+
 /// C-style 32-bit ELF Symbol definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -34,7 +112,110 @@ pub struct Elf64_Sym {
     pub st_size: u64,
 }
 
+/// The symbol type from a [Symbol]'s [st_symtype](Symbol::st_symtype)
+///
+/// Values without a variant of their own (including the other OS and processor-specific ones)
+/// are kept as `Unknown`, so converting to and from the raw `u8` is lossless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymbolType {
+    /// [STT_NOTYPE](abi::STT_NOTYPE)
+    NoType,
+    /// [STT_OBJECT](abi::STT_OBJECT)
+    Object,
+    /// [STT_FUNC](abi::STT_FUNC)
+    Func,
+    /// [STT_SECTION](abi::STT_SECTION)
+    Section,
+    /// [STT_FILE](abi::STT_FILE)
+    File,
+    /// [STT_COMMON](abi::STT_COMMON)
+    Common,
+    /// [STT_TLS](abi::STT_TLS)
+    Tls,
+    /// [STT_GNU_IFUNC](abi::STT_GNU_IFUNC), see [Symbol::is_ifunc]
+    GnuIfunc,
+    Unknown(u8),
+}
+
+impl From<u8> for SymbolType {
+    fn from(st_symtype: u8) -> Self {
+        match st_symtype {
+            abi::STT_NOTYPE => SymbolType::NoType,
+            abi::STT_OBJECT => SymbolType::Object,
+            abi::STT_FUNC => SymbolType::Func,
+            abi::STT_SECTION => SymbolType::Section,
+            abi::STT_FILE => SymbolType::File,
+            abi::STT_COMMON => SymbolType::Common,
+            abi::STT_TLS => SymbolType::Tls,
+            abi::STT_GNU_IFUNC => SymbolType::GnuIfunc,
+            _ => SymbolType::Unknown(st_symtype),
+        }
+    }
+}
+
+impl From<SymbolType> for u8 {
+    fn from(symtype: SymbolType) -> Self {
+        match symtype {
+            SymbolType::NoType => abi::STT_NOTYPE,
+            SymbolType::Object => abi::STT_OBJECT,
+            SymbolType::Func => abi::STT_FUNC,
+            SymbolType::Section => abi::STT_SECTION,
+            SymbolType::File => abi::STT_FILE,
+            SymbolType::Common => abi::STT_COMMON,
+            SymbolType::Tls => abi::STT_TLS,
+            SymbolType::GnuIfunc => abi::STT_GNU_IFUNC,
+            SymbolType::Unknown(st_symtype) => st_symtype,
+        }
+    }
+}
+
+/// The symbol binding from a [Symbol]'s [st_bind](Symbol::st_bind)
+///
+/// Values without a variant of their own (including the other OS and processor-specific ones)
+/// are kept as `Unknown`, so converting to and from the raw `u8` is lossless.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymbolBind {
+    /// [STB_LOCAL](abi::STB_LOCAL)
+    Local,
+    /// [STB_GLOBAL](abi::STB_GLOBAL)
+    Global,
+    /// [STB_WEAK](abi::STB_WEAK)
+    Weak,
+    /// [STB_GNU_UNIQUE](abi::STB_GNU_UNIQUE): a global symbol which the dynamic linker makes
+    /// sure only one definition of is in use in the whole process, even across objects loaded
+    /// with `RTLD_LOCAL`
+    GnuUnique,
+    Unknown(u8),
+}
+
+impl From<u8> for SymbolBind {
+    fn from(st_bind: u8) -> Self {
+        match st_bind {
+            abi::STB_LOCAL => SymbolBind::Local,
+            abi::STB_GLOBAL => SymbolBind::Global,
+            abi::STB_WEAK => SymbolBind::Weak,
+            abi::STB_GNU_UNIQUE => SymbolBind::GnuUnique,
+            _ => SymbolBind::Unknown(st_bind),
+        }
+    }
+}
+
+impl From<SymbolBind> for u8 {
+    fn from(bind: SymbolBind) -> Self {
+        match bind {
+            SymbolBind::Local => abi::STB_LOCAL,
+            SymbolBind::Global => abi::STB_GLOBAL,
+            SymbolBind::Weak => abi::STB_WEAK,
+            SymbolBind::GnuUnique => abi::STB_GNU_UNIQUE,
+            SymbolBind::Unknown(st_bind) => st_bind,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Symbol {
     /// This member holds an index into the symbol table's string table,
     /// which holds the character representations of the symbol names. If the
@@ -89,6 +270,63 @@ impl Symbol {
         self.st_shndx == abi::SHN_UNDEF
     }
 
+    /// Get the full 32-bit section header index for this symbol.
+    ///
+    /// This is [st_shndx](Symbol#structfield.st_shndx) unless it holds
+    /// [SHN_XINDEX](abi::SHN_XINDEX), in which case the real index is looked up at this
+    /// symbol's index (`sym_idx`) in its symbol table's associated [SymbolShndxTable].
+    pub fn section_index<E: EndianParse>(
+        &self,
+        sym_idx: usize,
+        shndx_table: &SymbolShndxTable<'_, E>,
+    ) -> Result<u32, ParseError> {
+        if self.st_shndx == abi::SHN_XINDEX {
+            shndx_table.get(sym_idx)
+        } else {
+            Ok(self.st_shndx as u32)
+        }
+    }
+
+    /// Returns true if this is a thread-local storage symbol ([STT_TLS](abi::STT_TLS)).
+    ///
+    /// A TLS symbol's st_value is not a virtual address. In executables and shared objects it
+    /// is the symbol's offset into the module's TLS block (see
+    /// [TlsTemplate](crate::segment::TlsTemplate)), and in relocatable objects it is the
+    /// symbol's offset into its section.
+    pub fn is_tls(&self) -> bool {
+        self.st_symtype() == abi::STT_TLS
+    }
+
+    /// Returns true if this is a GNU indirect function symbol ([STT_GNU_IFUNC](abi::STT_GNU_IFUNC)).
+    ///
+    /// An indirect function's st_value is the address of its resolver rather than of the
+    /// function itself. The dynamic linker calls the resolver (e.g. to pick an implementation
+    /// for the CPU's features) and binds references to the symbol to the address it returns.
+    pub fn is_ifunc(&self) -> bool {
+        self.st_symtype() == abi::STT_GNU_IFUNC
+    }
+
+    /// The symbol's type as a [SymbolType]
+    pub fn symbol_type(&self) -> SymbolType {
+        SymbolType::from(self.st_symtype())
+    }
+
+    /// The symbol's binding as a [SymbolBind]
+    pub fn symbol_bind(&self) -> SymbolBind {
+        SymbolBind::from(self.st_bind())
+    }
+
+    /// Get this symbol's name from its string table, demangled if it is a mangled Rust or C++
+    /// name. See [demangle](crate::demangle::demangle).
+    #[cfg(feature = "demangle")]
+    pub fn demangled_name<'data>(
+        &self,
+        strtab: &StringTable<'data>,
+    ) -> Result<std::borrow::Cow<'data, str>, ParseError> {
+        let name = strtab.get(self.st_name as usize)?;
+        Ok(crate::demangle::demangle(name))
+    }
+
     pub fn st_symtype(&self) -> u8 {
         self.st_info & 0xf
     }
@@ -177,6 +415,67 @@ mod symbol_tests {
         };
         assert!(!def_sym.is_undefined());
     }
+
+    #[test]
+    fn symbol_type_and_bind() {
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0x1000,
+            st_size: 0,
+            st_shndx: 1,
+            st_info: (abi::STB_GNU_UNIQUE << 4) | abi::STT_GNU_IFUNC,
+            st_other: 0,
+        };
+        assert!(sym.is_ifunc());
+        assert_eq!(sym.symbol_type(), SymbolType::GnuIfunc);
+        assert_eq!(sym.symbol_bind(), SymbolBind::GnuUnique);
+
+        let sym = Symbol {
+            st_info: (abi::STB_LOPROC << 4) | abi::STT_FUNC,
+            ..sym
+        };
+        assert!(!sym.is_ifunc());
+        assert_eq!(sym.symbol_type(), SymbolType::Func);
+        assert_eq!(sym.symbol_bind(), SymbolBind::Unknown(abi::STB_LOPROC));
+
+        for value in 0..16 {
+            assert_eq!(u8::from(SymbolType::from(value)), value);
+            assert_eq!(u8::from(SymbolBind::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn symbol_section_index() {
+        use crate::endian::LittleEndian;
+
+        // Extended section indexes for two symbols
+        let data = [0u8, 0, 0, 0, 0x01, 0x00, 0x01, 0x00];
+        let shndx_table = SymbolShndxTable::new(LittleEndian, Class::ELF64, &data);
+
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: 42,
+            st_info: 0,
+            st_other: 0,
+        };
+        assert_eq!(sym.section_index(0, &shndx_table).unwrap(), 42);
+
+        let sym = Symbol {
+            st_shndx: abi::SHN_XINDEX,
+            ..sym
+        };
+        assert_eq!(sym.section_index(1, &shndx_table).unwrap(), 0x10001);
+
+        let err = sym
+            .section_index(3, &shndx_table)
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(3)),
+            "Unexpected Error type found: {err}"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -268,4 +567,77 @@ mod parse_tests {
     fn parse_sym64_msb_fuzz_too_short() {
         test_parse_fuzz_too_short::<_, Symbol>(BigEndian, Class::ELF64);
     }
+
+    #[test]
+    fn symbol_table_find_by_name() {
+        #[rustfmt::skip]
+        let syms = [
+            // null symbol
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // "foo"
+            0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x01, 0x00,
+            // "bar"
+            0x05, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x01, 0x00,
+        ];
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &syms);
+        let strtab = StringTable::new(b"\0foo\0bar\0");
+
+        let (index, symbol) = symtab
+            .find_by_name("bar", &strtab)
+            .expect("should parse")
+            .expect("should find bar");
+        assert_eq!(index, 2);
+        assert_eq!(symbol.st_value, 0x20);
+        assert_eq!(
+            symtab.find_by_name("baz", &strtab).expect("should parse"),
+            None
+        );
+
+        let err = symtab
+            .find_by_name("baz", &StringTable::new(b"\0foo"))
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::StringTableMissingNul(1)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn symbol_table_symbolize() {
+        #[rustfmt::skip]
+        let syms = [
+            // null symbol
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // "data" object at 0x100, size 0x20
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x20, 0x00, 0x00, 0x00, 0x11, 0x00, 0x01, 0x00,
+            // "func" function at 0x100, size 0x10
+            0x06, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x10, 0x00, 0x00, 0x00, 0x12, 0x00, 0x01, 0x00,
+            // "asm" untyped at 0x140, no size
+            0x0b, 0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x00,
+            // "undef" undefined function
+            0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        ];
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &syms);
+        let strtab = StringTable::new(b"\0data\0func\0asm\0undef\0");
+        let symbolize = |addr| {
+            symtab
+                .symbolize(addr, &strtab)
+                .expect("should parse")
+                .map(|(_, name, offset)| (name, offset))
+        };
+
+        assert_eq!(symbolize(0x104), Some(("func", 4)));
+        assert_eq!(symbolize(0x118), Some(("data", 0x18)));
+        assert_eq!(symbolize(0x130), Some(("func", 0x30)));
+        assert_eq!(symbolize(0x150), Some(("asm", 0x10)));
+        assert_eq!(symbolize(0x10), None);
+    }
 }
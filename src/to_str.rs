@@ -251,6 +251,7 @@ pub fn e_machine_to_human_str(e_machine: u16) -> Option<&'static str> {
         abi::EM_AMDGPU => Some("AMD GPU architecture"),
         abi::EM_RISCV => Some("RISC-V"),
         abi::EM_BPF => Some("Linux BPF"),
+        abi::EM_LOONGARCH => Some("LoongArch"),
         _ => None,
     }
 }
@@ -440,6 +441,7 @@ pub fn e_machine_to_str(e_machine: u16) -> Option<&'static str> {
         abi::EM_AMDGPU => Some("EM_AMDGPU"),
         abi::EM_RISCV => Some("RISC-V"),
         abi::EM_BPF => Some("EM_BPF"),
+        abi::EM_LOONGARCH => Some("EM_LOONGARCH"),
         _ => None,
     }
 }
@@ -470,6 +472,7 @@ pub fn sh_type_to_str(sh_type: u32) -> Option<&'static str> {
         abi::SHT_PREINIT_ARRAY => Some("SHT_PREINIT_ARRAY"),
         abi::SHT_GROUP => Some("SHT_GROUP"),
         abi::SHT_SYMTAB_SHNDX => Some("SHT_SYMTAB_SHNDX"),
+        abi::SHT_RELR => Some("SHT_RELR"),
         abi::SHT_GNU_ATTRIBUTES => Some("SHT_GNU_ATTRIBUTES"),
         abi::SHT_GNU_HASH => Some("SHT_GNU_HASH"),
         abi::SHT_GNU_LIBLIST => Some("SHT_GNU_LIBLIST"),
@@ -487,6 +490,44 @@ pub fn sh_type_to_string(sh_type: u32) -> String {
     }
 }
 
+/// Get readelf's single-letter key for each of the section's flags, e.g. "WAX".
+///
+/// Unknown bits in the OS and processor-specific ranges are shown as 'o' and 'p', and any
+/// other unknown bits as 'x'.
+pub fn sh_flags_to_string(sh_flags: u64) -> String {
+    const KEYS: [(u32, char); 11] = [
+        (abi::SHF_WRITE, 'W'),
+        (abi::SHF_ALLOC, 'A'),
+        (abi::SHF_EXECINSTR, 'X'),
+        (abi::SHF_MERGE, 'M'),
+        (abi::SHF_STRINGS, 'S'),
+        (abi::SHF_INFO_LINK, 'I'),
+        (abi::SHF_LINK_ORDER, 'L'),
+        (abi::SHF_OS_NONCONFORMING, 'O'),
+        (abi::SHF_GROUP, 'G'),
+        (abi::SHF_TLS, 'T'),
+        (abi::SHF_COMPRESSED, 'C'),
+    ];
+    let mut flags = String::new();
+    let mut unknown = sh_flags;
+    for (flag, key) in KEYS {
+        if sh_flags & flag as u64 != 0 {
+            flags.push(key);
+            unknown &= !(flag as u64);
+        }
+    }
+    if unknown & abi::SHF_MASKOS as u64 != 0 {
+        flags.push('o');
+    }
+    if unknown & abi::SHF_MASKPROC as u64 != 0 {
+        flags.push('p');
+    }
+    if unknown & !(abi::SHF_MASKOS as u64 | abi::SHF_MASKPROC as u64) != 0 {
+        flags.push('x');
+    }
+    flags
+}
+
 pub fn p_flags_to_string(p_flags: u32) -> String {
     match p_flags < 8 {
         true => {
@@ -582,7 +623,7 @@ pub fn st_vis_to_string(st_vis: u8) -> String {
 pub fn ch_type_to_str(ch_type: u32) -> Option<&'static str> {
     match ch_type {
         abi::ELFCOMPRESS_ZLIB => Some("ELFCOMPRESS_ZLIB"),
-        abi::ELFCOMPRESS_ZSTD => Some("ELFCOMPRESS_ZSTD "),
+        abi::ELFCOMPRESS_ZSTD => Some("ELFCOMPRESS_ZSTD"),
         _ => None,
     }
 }
@@ -597,6 +638,47 @@ pub fn note_abi_tag_os_to_str(os: u32) -> Option<&'static str> {
     }
 }
 
+pub fn at_type_to_str(a_type: u64) -> Option<&'static str> {
+    match a_type {
+        abi::AT_NULL => Some("AT_NULL"),
+        abi::AT_IGNORE => Some("AT_IGNORE"),
+        abi::AT_EXECFD => Some("AT_EXECFD"),
+        abi::AT_PHDR => Some("AT_PHDR"),
+        abi::AT_PHENT => Some("AT_PHENT"),
+        abi::AT_PHNUM => Some("AT_PHNUM"),
+        abi::AT_PAGESZ => Some("AT_PAGESZ"),
+        abi::AT_BASE => Some("AT_BASE"),
+        abi::AT_FLAGS => Some("AT_FLAGS"),
+        abi::AT_ENTRY => Some("AT_ENTRY"),
+        abi::AT_NOTELF => Some("AT_NOTELF"),
+        abi::AT_UID => Some("AT_UID"),
+        abi::AT_EUID => Some("AT_EUID"),
+        abi::AT_GID => Some("AT_GID"),
+        abi::AT_EGID => Some("AT_EGID"),
+        abi::AT_PLATFORM => Some("AT_PLATFORM"),
+        abi::AT_HWCAP => Some("AT_HWCAP"),
+        abi::AT_CLKTCK => Some("AT_CLKTCK"),
+        abi::AT_FPUCW => Some("AT_FPUCW"),
+        abi::AT_DCACHEBSIZE => Some("AT_DCACHEBSIZE"),
+        abi::AT_ICACHEBSIZE => Some("AT_ICACHEBSIZE"),
+        abi::AT_UCACHEBSIZE => Some("AT_UCACHEBSIZE"),
+        abi::AT_IGNOREPPC => Some("AT_IGNOREPPC"),
+        abi::AT_SECURE => Some("AT_SECURE"),
+        abi::AT_BASE_PLATFORM => Some("AT_BASE_PLATFORM"),
+        abi::AT_RANDOM => Some("AT_RANDOM"),
+        abi::AT_HWCAP2 => Some("AT_HWCAP2"),
+        abi::AT_RSEQ_FEATURE_SIZE => Some("AT_RSEQ_FEATURE_SIZE"),
+        abi::AT_RSEQ_ALIGN => Some("AT_RSEQ_ALIGN"),
+        abi::AT_HWCAP3 => Some("AT_HWCAP3"),
+        abi::AT_HWCAP4 => Some("AT_HWCAP4"),
+        abi::AT_EXECFN => Some("AT_EXECFN"),
+        abi::AT_SYSINFO => Some("AT_SYSINFO"),
+        abi::AT_SYSINFO_EHDR => Some("AT_SYSINFO_EHDR"),
+        abi::AT_MINSIGSTKSZ => Some("AT_MINSIGSTKSZ"),
+        _ => None,
+    }
+}
+
 pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
     match d_tag {
         abi::DT_NULL => Some("DT_NULL"),
@@ -633,6 +715,9 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         abi::DT_PREINIT_ARRAY => Some("DT_PREINIT_ARRAY"),
         abi::DT_PREINIT_ARRAYSZ => Some("DT_PREINIT_ARRAYSZ"),
         abi::DT_SYMTAB_SHNDX => Some("DT_SYMTAB_SHNDX"),
+        abi::DT_RELRSZ => Some("DT_RELRSZ"),
+        abi::DT_RELR => Some("DT_RELR"),
+        abi::DT_RELRENT => Some("DT_RELRENT"),
         abi::DT_GUILE_GC_ROOT => Some("DT_GUILE_GC_ROOT"),
         abi::DT_GUILE_GC_ROOT_SZ => Some("DT_GUILE_GC_ROOT_SZ"),
         abi::DT_GUILE_ENTRY => Some("DT_GUILE_ENTRY"),
@@ -675,3 +760,1062 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// Get the name of a relocation type, which depends on the machine (e_machine) it is for.
+///
+/// Returns None for unknown relocation types and machines whose relocation types aren't named yet.
+pub fn r_type_to_str(e_machine: u16, r_type: u32) -> Option<&'static str> {
+    match e_machine {
+        abi::EM_ARM => r_arm_type_to_str(r_type),
+        abi::EM_MIPS => r_mips_type_to_str(r_type),
+        abi::EM_PPC => r_ppc_type_to_str(r_type),
+        abi::EM_PPC64 => r_ppc64_type_to_str(r_type),
+        abi::EM_S390 => r_s390_type_to_str(r_type),
+        abi::EM_X86_64 => r_x86_64_type_to_str(r_type),
+        abi::EM_AVR => r_avr_type_to_str(r_type),
+        abi::EM_XTENSA => r_xtensa_type_to_str(r_type),
+        abi::EM_MSP430 => r_msp430_type_to_str(r_type),
+        abi::EM_AARCH64 => r_aarch64_type_to_str(r_type),
+        abi::EM_RISCV => r_riscv_type_to_str(r_type),
+        abi::EM_LOONGARCH => r_loongarch_type_to_str(r_type),
+        _ => None,
+    }
+}
+
+pub fn r_type_to_string(e_machine: u16, r_type: u32) -> String {
+    match r_type_to_str(e_machine, r_type) {
+        Some(s) => s.to_string(),
+        None => format!("r_type({r_type:#x})"),
+    }
+}
+
+/// Get the name of an MSP430 relocation type from the MSP430X set, which objects for the MSP430X
+/// architecture use (see [Msp430Flags](crate::msp430::Msp430Flags)).
+///
+/// [r_type_to_str] names EM_MSP430 relocation types from the original set.
+pub fn r_msp430x_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_MSP430_NONE => Some("R_MSP430_NONE"),
+        abi::R_MSP430_ABS32 => Some("R_MSP430_ABS32"),
+        abi::R_MSP430_ABS16 => Some("R_MSP430_ABS16"),
+        abi::R_MSP430_ABS8 => Some("R_MSP430_ABS8"),
+        abi::R_MSP430_PCR16 => Some("R_MSP430_PCR16"),
+        abi::R_MSP430X_PCR20_EXT_SRC => Some("R_MSP430X_PCR20_EXT_SRC"),
+        abi::R_MSP430X_PCR20_EXT_DST => Some("R_MSP430X_PCR20_EXT_DST"),
+        abi::R_MSP430X_PCR20_EXT_ODST => Some("R_MSP430X_PCR20_EXT_ODST"),
+        abi::R_MSP430X_ABS20_EXT_SRC => Some("R_MSP430X_ABS20_EXT_SRC"),
+        abi::R_MSP430X_ABS20_EXT_DST => Some("R_MSP430X_ABS20_EXT_DST"),
+        abi::R_MSP430X_ABS20_EXT_ODST => Some("R_MSP430X_ABS20_EXT_ODST"),
+        abi::R_MSP430X_ABS20_ADR_SRC => Some("R_MSP430X_ABS20_ADR_SRC"),
+        abi::R_MSP430X_ABS20_ADR_DST => Some("R_MSP430X_ABS20_ADR_DST"),
+        abi::R_MSP430X_PCR16 => Some("R_MSP430X_PCR16"),
+        abi::R_MSP430X_PCR20_CALL => Some("R_MSP430X_PCR20_CALL"),
+        abi::R_MSP430X_ABS16 => Some("R_MSP430X_ABS16"),
+        abi::R_MSP430_ABS_HI16 => Some("R_MSP430_ABS_HI16"),
+        abi::R_MSP430_PREL31 => Some("R_MSP430_PREL31"),
+        abi::R_MSP430_EHTYPE => Some("R_MSP430_EHTYPE"),
+        abi::R_MSP430X_10_PCREL => Some("R_MSP430X_10_PCREL"),
+        abi::R_MSP430X_2X_PCREL => Some("R_MSP430X_2X_PCREL"),
+        abi::R_MSP430X_SYM_DIFF => Some("R_MSP430X_SYM_DIFF"),
+        abi::R_MSP430X_GNU_SET_ULEB128 => Some("R_MSP430X_GNU_SET_ULEB128"),
+        abi::R_MSP430X_GNU_SUB_ULEB128 => Some("R_MSP430X_GNU_SUB_ULEB128"),
+        _ => None,
+    }
+}
+
+fn r_x86_64_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_X86_64_NONE => Some("R_X86_64_NONE"),
+        abi::R_X86_64_64 => Some("R_X86_64_64"),
+        abi::R_X86_64_PC32 => Some("R_X86_64_PC32"),
+        abi::R_X86_64_GOT32 => Some("R_X86_64_GOT32"),
+        abi::R_X86_64_PLT32 => Some("R_X86_64_PLT32"),
+        abi::R_X86_64_COPY => Some("R_X86_64_COPY"),
+        abi::R_X86_64_GLOB_DAT => Some("R_X86_64_GLOB_DAT"),
+        abi::R_X86_64_JUMP_SLOT => Some("R_X86_64_JUMP_SLOT"),
+        abi::R_X86_64_RELATIVE => Some("R_X86_64_RELATIVE"),
+        abi::R_X86_64_GOTPCREL => Some("R_X86_64_GOTPCREL"),
+        abi::R_X86_64_32 => Some("R_X86_64_32"),
+        abi::R_X86_64_32S => Some("R_X86_64_32S"),
+        abi::R_X86_64_16 => Some("R_X86_64_16"),
+        abi::R_X86_64_PC16 => Some("R_X86_64_PC16"),
+        abi::R_X86_64_8 => Some("R_X86_64_8"),
+        abi::R_X86_64_PC8 => Some("R_X86_64_PC8"),
+        abi::R_X86_64_DTPMOD64 => Some("R_X86_64_DTPMOD64"),
+        abi::R_X86_64_DTPOFF64 => Some("R_X86_64_DTPOFF64"),
+        abi::R_X86_64_TPOFF64 => Some("R_X86_64_TPOFF64"),
+        abi::R_X86_64_TLSGD => Some("R_X86_64_TLSGD"),
+        abi::R_X86_64_TLSLD => Some("R_X86_64_TLSLD"),
+        abi::R_X86_64_DTPOFF32 => Some("R_X86_64_DTPOFF32"),
+        abi::R_X86_64_GOTTPOFF => Some("R_X86_64_GOTTPOFF"),
+        abi::R_X86_64_TPOFF32 => Some("R_X86_64_TPOFF32"),
+        abi::R_X86_64_PC64 => Some("R_X86_64_PC64"),
+        abi::R_X86_64_GOTOFF64 => Some("R_X86_64_GOTOFF64"),
+        abi::R_X86_64_GOTPC32 => Some("R_X86_64_GOTPC32"),
+        abi::R_X86_64_GOT64 => Some("R_X86_64_GOT64"),
+        abi::R_X86_64_GOTPCREL64 => Some("R_X86_64_GOTPCREL64"),
+        abi::R_X86_64_GOTPC64 => Some("R_X86_64_GOTPC64"),
+        abi::R_X86_64_GOTPLT64 => Some("R_X86_64_GOTPLT64"),
+        abi::R_X86_64_PLTOFF64 => Some("R_X86_64_PLTOFF64"),
+        abi::R_X86_64_SIZE32 => Some("R_X86_64_SIZE32"),
+        abi::R_X86_64_SIZE64 => Some("R_X86_64_SIZE64"),
+        abi::R_X86_64_GOTPC32_TLSDESC => Some("R_X86_64_GOTPC32_TLSDESC"),
+        abi::R_X86_64_TLSDESC_CALL => Some("R_X86_64_TLSDESC_CALL"),
+        abi::R_X86_64_TLSDESC => Some("R_X86_64_TLSDESC"),
+        abi::R_X86_64_IRELATIVE => Some("R_X86_64_IRELATIVE"),
+        abi::R_X86_64_RELATIVE64 => Some("R_X86_64_RELATIVE64"),
+        abi::R_X86_64_GOTPCRELX => Some("R_X86_64_GOTPCRELX"),
+        abi::R_X86_64_REX_GOTPCRELX => Some("R_X86_64_REX_GOTPCRELX"),
+        abi::R_X86_64_CODE_4_GOTPCRELX => Some("R_X86_64_CODE_4_GOTPCRELX"),
+        abi::R_X86_64_CODE_4_GOTTPOFF => Some("R_X86_64_CODE_4_GOTTPOFF"),
+        abi::R_X86_64_CODE_4_GOTPC32_TLSDESC => Some("R_X86_64_CODE_4_GOTPC32_TLSDESC"),
+        _ => None,
+    }
+}
+
+fn r_aarch64_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_AARCH64_NONE => Some("R_AARCH64_NONE"),
+        abi::R_AARCH64_P32_ABS32 => Some("R_AARCH64_P32_ABS32"),
+        abi::R_AARCH64_P32_COPY => Some("R_AARCH64_P32_COPY"),
+        abi::R_AARCH64_P32_GLOB_DAT => Some("R_AARCH64_P32_GLOB_DAT"),
+        abi::R_AARCH64_P32_JUMP_SLOT => Some("R_AARCH64_P32_JUMP_SLOT"),
+        abi::R_AARCH64_P32_RELATIVE => Some("R_AARCH64_P32_RELATIVE"),
+        abi::R_AARCH64_P32_TLS_DTPMOD => Some("R_AARCH64_P32_TLS_DTPMOD"),
+        abi::R_AARCH64_P32_TLS_DTPREL => Some("R_AARCH64_P32_TLS_DTPREL"),
+        abi::R_AARCH64_P32_TLS_TPREL => Some("R_AARCH64_P32_TLS_TPREL"),
+        abi::R_AARCH64_P32_TLSDESC => Some("R_AARCH64_P32_TLSDESC"),
+        abi::R_AARCH64_P32_IRELATIVE => Some("R_AARCH64_P32_IRELATIVE"),
+        abi::R_AARCH64_ABS64 => Some("R_AARCH64_ABS64"),
+        abi::R_AARCH64_ABS32 => Some("R_AARCH64_ABS32"),
+        abi::R_AARCH64_ABS16 => Some("R_AARCH64_ABS16"),
+        abi::R_AARCH64_PREL64 => Some("R_AARCH64_PREL64"),
+        abi::R_AARCH64_PREL32 => Some("R_AARCH64_PREL32"),
+        abi::R_AARCH64_PREL16 => Some("R_AARCH64_PREL16"),
+        abi::R_AARCH64_MOVW_UABS_G0 => Some("R_AARCH64_MOVW_UABS_G0"),
+        abi::R_AARCH64_MOVW_UABS_G0_NC => Some("R_AARCH64_MOVW_UABS_G0_NC"),
+        abi::R_AARCH64_MOVW_UABS_G1 => Some("R_AARCH64_MOVW_UABS_G1"),
+        abi::R_AARCH64_MOVW_UABS_G1_NC => Some("R_AARCH64_MOVW_UABS_G1_NC"),
+        abi::R_AARCH64_MOVW_UABS_G2 => Some("R_AARCH64_MOVW_UABS_G2"),
+        abi::R_AARCH64_MOVW_UABS_G2_NC => Some("R_AARCH64_MOVW_UABS_G2_NC"),
+        abi::R_AARCH64_MOVW_UABS_G3 => Some("R_AARCH64_MOVW_UABS_G3"),
+        abi::R_AARCH64_MOVW_SABS_G0 => Some("R_AARCH64_MOVW_SABS_G0"),
+        abi::R_AARCH64_MOVW_SABS_G1 => Some("R_AARCH64_MOVW_SABS_G1"),
+        abi::R_AARCH64_MOVW_SABS_G2 => Some("R_AARCH64_MOVW_SABS_G2"),
+        abi::R_AARCH64_LD_PREL_LO19 => Some("R_AARCH64_LD_PREL_LO19"),
+        abi::R_AARCH64_ADR_PREL_LO21 => Some("R_AARCH64_ADR_PREL_LO21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21 => Some("R_AARCH64_ADR_PREL_PG_HI21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21_NC => Some("R_AARCH64_ADR_PREL_PG_HI21_NC"),
+        abi::R_AARCH64_ADD_ABS_LO12_NC => Some("R_AARCH64_ADD_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST8_ABS_LO12_NC => Some("R_AARCH64_LDST8_ABS_LO12_NC"),
+        abi::R_AARCH64_TSTBR14 => Some("R_AARCH64_TSTBR14"),
+        abi::R_AARCH64_CONDBR19 => Some("R_AARCH64_CONDBR19"),
+        abi::R_AARCH64_JUMP26 => Some("R_AARCH64_JUMP26"),
+        abi::R_AARCH64_CALL26 => Some("R_AARCH64_CALL26"),
+        abi::R_AARCH64_LDST16_ABS_LO12_NC => Some("R_AARCH64_LDST16_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST32_ABS_LO12_NC => Some("R_AARCH64_LDST32_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST64_ABS_LO12_NC => Some("R_AARCH64_LDST64_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_PREL_G0 => Some("R_AARCH64_MOVW_PREL_G0"),
+        abi::R_AARCH64_MOVW_PREL_G0_NC => Some("R_AARCH64_MOVW_PREL_G0_NC"),
+        abi::R_AARCH64_MOVW_PREL_G1 => Some("R_AARCH64_MOVW_PREL_G1"),
+        abi::R_AARCH64_MOVW_PREL_G1_NC => Some("R_AARCH64_MOVW_PREL_G1_NC"),
+        abi::R_AARCH64_MOVW_PREL_G2 => Some("R_AARCH64_MOVW_PREL_G2"),
+        abi::R_AARCH64_MOVW_PREL_G2_NC => Some("R_AARCH64_MOVW_PREL_G2_NC"),
+        abi::R_AARCH64_MOVW_PREL_G3 => Some("R_AARCH64_MOVW_PREL_G3"),
+        abi::R_AARCH64_LDST128_ABS_LO12_NC => Some("R_AARCH64_LDST128_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0 => Some("R_AARCH64_MOVW_GOTOFF_G0"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0_NC => Some("R_AARCH64_MOVW_GOTOFF_G0_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1 => Some("R_AARCH64_MOVW_GOTOFF_G1"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1_NC => Some("R_AARCH64_MOVW_GOTOFF_G1_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2 => Some("R_AARCH64_MOVW_GOTOFF_G2"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2_NC => Some("R_AARCH64_MOVW_GOTOFF_G2_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G3 => Some("R_AARCH64_MOVW_GOTOFF_G3"),
+        abi::R_AARCH64_GOTREL64 => Some("R_AARCH64_GOTREL64"),
+        abi::R_AARCH64_GOTREL32 => Some("R_AARCH64_GOTREL32"),
+        abi::R_AARCH64_GOT_LD_PREL19 => Some("R_AARCH64_GOT_LD_PREL19"),
+        abi::R_AARCH64_LD64_GOTOFF_LO15 => Some("R_AARCH64_LD64_GOTOFF_LO15"),
+        abi::R_AARCH64_ADR_GOT_PAGE => Some("R_AARCH64_ADR_GOT_PAGE"),
+        abi::R_AARCH64_LD64_GOT_LO12_NC => Some("R_AARCH64_LD64_GOT_LO12_NC"),
+        abi::R_AARCH64_LD64_GOTPAGE_LO15 => Some("R_AARCH64_LD64_GOTPAGE_LO15"),
+        abi::R_AARCH64_TLSGD_ADR_PREL21 => Some("R_AARCH64_TLSGD_ADR_PREL21"),
+        abi::R_AARCH64_TLSGD_ADR_PAGE21 => Some("R_AARCH64_TLSGD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSGD_ADD_LO12_NC => Some("R_AARCH64_TLSGD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSGD_MOVW_G1 => Some("R_AARCH64_TLSGD_MOVW_G1"),
+        abi::R_AARCH64_TLSGD_MOVW_G0_NC => Some("R_AARCH64_TLSGD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADR_PREL21 => Some("R_AARCH64_TLSLD_ADR_PREL21"),
+        abi::R_AARCH64_TLSLD_ADR_PAGE21 => Some("R_AARCH64_TLSLD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSLD_ADD_LO12_NC => Some("R_AARCH64_TLSLD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_G1 => Some("R_AARCH64_TLSLD_MOVW_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_G0_NC => Some("R_AARCH64_TLSLD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_LD_PREL19 => Some("R_AARCH64_TLSLD_LD_PREL19"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G2 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G2"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_HI12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_HI12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G1 => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G1"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC"),
+        abi::R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21 => Some("R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21"),
+        abi::R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC => Some("R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_LD_GOTTPREL_PREL19 => Some("R_AARCH64_TLSIE_LD_GOTTPREL_PREL19"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G2 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G2"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1_NC"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0_NC"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_HI12 => Some("R_AARCH64_TLSLE_ADD_TPREL_HI12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12 => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSDESC_LD_PREL19 => Some("R_AARCH64_TLSDESC_LD_PREL19"),
+        abi::R_AARCH64_TLSDESC_ADR_PREL21 => Some("R_AARCH64_TLSDESC_ADR_PREL21"),
+        abi::R_AARCH64_TLSDESC_ADR_PAGE21 => Some("R_AARCH64_TLSDESC_ADR_PAGE21"),
+        abi::R_AARCH64_TLSDESC_LD64_LO12 => Some("R_AARCH64_TLSDESC_LD64_LO12"),
+        abi::R_AARCH64_TLSDESC_ADD_LO12 => Some("R_AARCH64_TLSDESC_ADD_LO12"),
+        abi::R_AARCH64_TLSDESC_OFF_G1 => Some("R_AARCH64_TLSDESC_OFF_G1"),
+        abi::R_AARCH64_TLSDESC_OFF_G0_NC => Some("R_AARCH64_TLSDESC_OFF_G0_NC"),
+        abi::R_AARCH64_TLSDESC_LDR => Some("R_AARCH64_TLSDESC_LDR"),
+        abi::R_AARCH64_TLSDESC_ADD => Some("R_AARCH64_TLSDESC_ADD"),
+        abi::R_AARCH64_TLSDESC_CALL => Some("R_AARCH64_TLSDESC_CALL"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC => {
+            Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC")
+        }
+        abi::R_AARCH64_COPY => Some("R_AARCH64_COPY"),
+        abi::R_AARCH64_GLOB_DAT => Some("R_AARCH64_GLOB_DAT"),
+        abi::R_AARCH64_JUMP_SLOT => Some("R_AARCH64_JUMP_SLOT"),
+        abi::R_AARCH64_RELATIVE => Some("R_AARCH64_RELATIVE"),
+        abi::R_AARCH64_TLS_DTPMOD => Some("R_AARCH64_TLS_DTPMOD"),
+        abi::R_AARCH64_TLS_DTPREL => Some("R_AARCH64_TLS_DTPREL"),
+        abi::R_AARCH64_TLS_TPREL => Some("R_AARCH64_TLS_TPREL"),
+        abi::R_AARCH64_TLSDESC => Some("R_AARCH64_TLSDESC"),
+        abi::R_AARCH64_IRELATIVE => Some("R_AARCH64_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn r_riscv_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_RISCV_NONE => Some("R_RISCV_NONE"),
+        abi::R_RISCV_32 => Some("R_RISCV_32"),
+        abi::R_RISCV_64 => Some("R_RISCV_64"),
+        abi::R_RISCV_RELATIVE => Some("R_RISCV_RELATIVE"),
+        abi::R_RISCV_COPY => Some("R_RISCV_COPY"),
+        abi::R_RISCV_JUMP_SLOT => Some("R_RISCV_JUMP_SLOT"),
+        abi::R_RISCV_TLS_DTPMOD32 => Some("R_RISCV_TLS_DTPMOD32"),
+        abi::R_RISCV_TLS_DTPMOD64 => Some("R_RISCV_TLS_DTPMOD64"),
+        abi::R_RISCV_TLS_DTPREL32 => Some("R_RISCV_TLS_DTPREL32"),
+        abi::R_RISCV_TLS_DTPREL64 => Some("R_RISCV_TLS_DTPREL64"),
+        abi::R_RISCV_TLS_TPREL32 => Some("R_RISCV_TLS_TPREL32"),
+        abi::R_RISCV_TLS_TPREL64 => Some("R_RISCV_TLS_TPREL64"),
+        abi::R_RISCV_TLSDESC => Some("R_RISCV_TLSDESC"),
+        abi::R_RISCV_BRANCH => Some("R_RISCV_BRANCH"),
+        abi::R_RISCV_JAL => Some("R_RISCV_JAL"),
+        abi::R_RISCV_CALL => Some("R_RISCV_CALL"),
+        abi::R_RISCV_CALL_PLT => Some("R_RISCV_CALL_PLT"),
+        abi::R_RISCV_GOT_HI20 => Some("R_RISCV_GOT_HI20"),
+        abi::R_RISCV_TLS_GOT_HI20 => Some("R_RISCV_TLS_GOT_HI20"),
+        abi::R_RISCV_TLS_GD_HI20 => Some("R_RISCV_TLS_GD_HI20"),
+        abi::R_RISCV_PCREL_HI20 => Some("R_RISCV_PCREL_HI20"),
+        abi::R_RISCV_PCREL_LO12_I => Some("R_RISCV_PCREL_LO12_I"),
+        abi::R_RISCV_PCREL_LO12_S => Some("R_RISCV_PCREL_LO12_S"),
+        abi::R_RISCV_HI20 => Some("R_RISCV_HI20"),
+        abi::R_RISCV_LO12_I => Some("R_RISCV_LO12_I"),
+        abi::R_RISCV_LO12_S => Some("R_RISCV_LO12_S"),
+        abi::R_RISCV_TPREL_HI20 => Some("R_RISCV_TPREL_HI20"),
+        abi::R_RISCV_TPREL_LO12_I => Some("R_RISCV_TPREL_LO12_I"),
+        abi::R_RISCV_TPREL_LO12_S => Some("R_RISCV_TPREL_LO12_S"),
+        abi::R_RISCV_TPREL_ADD => Some("R_RISCV_TPREL_ADD"),
+        abi::R_RISCV_ADD8 => Some("R_RISCV_ADD8"),
+        abi::R_RISCV_ADD16 => Some("R_RISCV_ADD16"),
+        abi::R_RISCV_ADD32 => Some("R_RISCV_ADD32"),
+        abi::R_RISCV_ADD64 => Some("R_RISCV_ADD64"),
+        abi::R_RISCV_SUB8 => Some("R_RISCV_SUB8"),
+        abi::R_RISCV_SUB16 => Some("R_RISCV_SUB16"),
+        abi::R_RISCV_SUB32 => Some("R_RISCV_SUB32"),
+        abi::R_RISCV_SUB64 => Some("R_RISCV_SUB64"),
+        abi::R_RISCV_GNU_VTINHERIT => Some("R_RISCV_GNU_VTINHERIT"),
+        abi::R_RISCV_GNU_VTENTRY => Some("R_RISCV_GNU_VTENTRY"),
+        abi::R_RISCV_ALIGN => Some("R_RISCV_ALIGN"),
+        abi::R_RISCV_RVC_BRANCH => Some("R_RISCV_RVC_BRANCH"),
+        abi::R_RISCV_RVC_JUMP => Some("R_RISCV_RVC_JUMP"),
+        abi::R_RISCV_RVC_LUI => Some("R_RISCV_RVC_LUI"),
+        abi::R_RISCV_RELAX => Some("R_RISCV_RELAX"),
+        abi::R_RISCV_SUB6 => Some("R_RISCV_SUB6"),
+        abi::R_RISCV_SET6 => Some("R_RISCV_SET6"),
+        abi::R_RISCV_SET8 => Some("R_RISCV_SET8"),
+        abi::R_RISCV_SET16 => Some("R_RISCV_SET16"),
+        abi::R_RISCV_SET32 => Some("R_RISCV_SET32"),
+        abi::R_RISCV_32_PCREL => Some("R_RISCV_32_PCREL"),
+        abi::R_RISCV_IRELATIVE => Some("R_RISCV_IRELATIVE"),
+        abi::R_RISCV_PLT32 => Some("R_RISCV_PLT32"),
+        abi::R_RISCV_SET_ULEB128 => Some("R_RISCV_SET_ULEB128"),
+        abi::R_RISCV_SUB_ULEB128 => Some("R_RISCV_SUB_ULEB128"),
+        abi::R_RISCV_TLSDESC_HI20 => Some("R_RISCV_TLSDESC_HI20"),
+        abi::R_RISCV_TLSDESC_LOAD_LO12 => Some("R_RISCV_TLSDESC_LOAD_LO12"),
+        abi::R_RISCV_TLSDESC_ADD_LO12 => Some("R_RISCV_TLSDESC_ADD_LO12"),
+        abi::R_RISCV_TLSDESC_CALL => Some("R_RISCV_TLSDESC_CALL"),
+        _ => None,
+    }
+}
+
+fn r_arm_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_ARM_NONE => Some("R_ARM_NONE"),
+        abi::R_ARM_PC24 => Some("R_ARM_PC24"),
+        abi::R_ARM_ABS32 => Some("R_ARM_ABS32"),
+        abi::R_ARM_REL32 => Some("R_ARM_REL32"),
+        abi::R_ARM_LDR_PC_G0 => Some("R_ARM_LDR_PC_G0"),
+        abi::R_ARM_ABS16 => Some("R_ARM_ABS16"),
+        abi::R_ARM_ABS12 => Some("R_ARM_ABS12"),
+        abi::R_ARM_THM_ABS5 => Some("R_ARM_THM_ABS5"),
+        abi::R_ARM_ABS8 => Some("R_ARM_ABS8"),
+        abi::R_ARM_SBREL32 => Some("R_ARM_SBREL32"),
+        abi::R_ARM_THM_CALL => Some("R_ARM_THM_CALL"),
+        abi::R_ARM_THM_PC8 => Some("R_ARM_THM_PC8"),
+        abi::R_ARM_BREL_ADJ => Some("R_ARM_BREL_ADJ"),
+        abi::R_ARM_TLS_DESC => Some("R_ARM_TLS_DESC"),
+        abi::R_ARM_THM_SWI8 => Some("R_ARM_THM_SWI8"),
+        abi::R_ARM_XPC25 => Some("R_ARM_XPC25"),
+        abi::R_ARM_THM_XPC22 => Some("R_ARM_THM_XPC22"),
+        abi::R_ARM_TLS_DTPMOD32 => Some("R_ARM_TLS_DTPMOD32"),
+        abi::R_ARM_TLS_DTPOFF32 => Some("R_ARM_TLS_DTPOFF32"),
+        abi::R_ARM_TLS_TPOFF32 => Some("R_ARM_TLS_TPOFF32"),
+        abi::R_ARM_COPY => Some("R_ARM_COPY"),
+        abi::R_ARM_GLOB_DAT => Some("R_ARM_GLOB_DAT"),
+        abi::R_ARM_JUMP_SLOT => Some("R_ARM_JUMP_SLOT"),
+        abi::R_ARM_RELATIVE => Some("R_ARM_RELATIVE"),
+        abi::R_ARM_GOTOFF32 => Some("R_ARM_GOTOFF32"),
+        abi::R_ARM_BASE_PREL => Some("R_ARM_BASE_PREL"),
+        abi::R_ARM_BASE_BREL => Some("R_ARM_BASE_BREL"),
+        abi::R_ARM_PLT32 => Some("R_ARM_PLT32"),
+        abi::R_ARM_CALL => Some("R_ARM_CALL"),
+        abi::R_ARM_JUMP24 => Some("R_ARM_JUMP24"),
+        abi::R_ARM_THM_JUMP24 => Some("R_ARM_THM_JUMP24"),
+        abi::R_ARM_BASE_ABS => Some("R_ARM_BASE_ABS"),
+        abi::R_ARM_ALU_PCREL_7_0 => Some("R_ARM_ALU_PCREL_7_0"),
+        abi::R_ARM_ALU_PCREL_15_8 => Some("R_ARM_ALU_PCREL_15_8"),
+        abi::R_ARM_ALU_PCREL_23_15 => Some("R_ARM_ALU_PCREL_23_15"),
+        abi::R_ARM_LDR_SBREL_11_0 => Some("R_ARM_LDR_SBREL_11_0"),
+        abi::R_ARM_ALU_SBREL_19_12 => Some("R_ARM_ALU_SBREL_19_12"),
+        abi::R_ARM_ALU_SBREL_27_20 => Some("R_ARM_ALU_SBREL_27_20"),
+        abi::R_ARM_TARGET1 => Some("R_ARM_TARGET1"),
+        abi::R_ARM_SBREL31 => Some("R_ARM_SBREL31"),
+        abi::R_ARM_V4BX => Some("R_ARM_V4BX"),
+        abi::R_ARM_TARGET2 => Some("R_ARM_TARGET2"),
+        abi::R_ARM_PREL31 => Some("R_ARM_PREL31"),
+        abi::R_ARM_MOVW_ABS_NC => Some("R_ARM_MOVW_ABS_NC"),
+        abi::R_ARM_MOVT_ABS => Some("R_ARM_MOVT_ABS"),
+        abi::R_ARM_MOVW_PREL_NC => Some("R_ARM_MOVW_PREL_NC"),
+        abi::R_ARM_MOVT_PREL => Some("R_ARM_MOVT_PREL"),
+        abi::R_ARM_THM_MOVW_ABS_NC => Some("R_ARM_THM_MOVW_ABS_NC"),
+        abi::R_ARM_THM_MOVT_ABS => Some("R_ARM_THM_MOVT_ABS"),
+        abi::R_ARM_THM_MOVW_PREL_NC => Some("R_ARM_THM_MOVW_PREL_NC"),
+        abi::R_ARM_THM_MOVT_PREL => Some("R_ARM_THM_MOVT_PREL"),
+        abi::R_ARM_THM_JUMP19 => Some("R_ARM_THM_JUMP19"),
+        abi::R_ARM_THM_JUMP6 => Some("R_ARM_THM_JUMP6"),
+        abi::R_ARM_THM_ALU_PREL_11_0 => Some("R_ARM_THM_ALU_PREL_11_0"),
+        abi::R_ARM_THM_PC12 => Some("R_ARM_THM_PC12"),
+        abi::R_ARM_ABS32_NOI => Some("R_ARM_ABS32_NOI"),
+        abi::R_ARM_REL32_NOI => Some("R_ARM_REL32_NOI"),
+        abi::R_ARM_ALU_PC_G0_NC => Some("R_ARM_ALU_PC_G0_NC"),
+        abi::R_ARM_ALU_PC_G0 => Some("R_ARM_ALU_PC_G0"),
+        abi::R_ARM_ALU_PC_G1_NC => Some("R_ARM_ALU_PC_G1_NC"),
+        abi::R_ARM_ALU_PC_G1 => Some("R_ARM_ALU_PC_G1"),
+        abi::R_ARM_ALU_PC_G2 => Some("R_ARM_ALU_PC_G2"),
+        abi::R_ARM_LDR_PC_G1 => Some("R_ARM_LDR_PC_G1"),
+        abi::R_ARM_LDR_PC_G2 => Some("R_ARM_LDR_PC_G2"),
+        abi::R_ARM_LDRS_PC_G0 => Some("R_ARM_LDRS_PC_G0"),
+        abi::R_ARM_LDRS_PC_G1 => Some("R_ARM_LDRS_PC_G1"),
+        abi::R_ARM_LDRS_PC_G2 => Some("R_ARM_LDRS_PC_G2"),
+        abi::R_ARM_LDC_PC_G0 => Some("R_ARM_LDC_PC_G0"),
+        abi::R_ARM_LDC_PC_G1 => Some("R_ARM_LDC_PC_G1"),
+        abi::R_ARM_LDC_PC_G2 => Some("R_ARM_LDC_PC_G2"),
+        abi::R_ARM_ALU_SB_G0_NC => Some("R_ARM_ALU_SB_G0_NC"),
+        abi::R_ARM_ALU_SB_G0 => Some("R_ARM_ALU_SB_G0"),
+        abi::R_ARM_ALU_SB_G1_NC => Some("R_ARM_ALU_SB_G1_NC"),
+        abi::R_ARM_ALU_SB_G1 => Some("R_ARM_ALU_SB_G1"),
+        abi::R_ARM_ALU_SB_G2 => Some("R_ARM_ALU_SB_G2"),
+        abi::R_ARM_LDR_SB_G0 => Some("R_ARM_LDR_SB_G0"),
+        abi::R_ARM_LDR_SB_G1 => Some("R_ARM_LDR_SB_G1"),
+        abi::R_ARM_LDR_SB_G2 => Some("R_ARM_LDR_SB_G2"),
+        abi::R_ARM_LDRS_SB_G0 => Some("R_ARM_LDRS_SB_G0"),
+        abi::R_ARM_LDRS_SB_G1 => Some("R_ARM_LDRS_SB_G1"),
+        abi::R_ARM_LDRS_SB_G2 => Some("R_ARM_LDRS_SB_G2"),
+        abi::R_ARM_LDC_SB_G0 => Some("R_ARM_LDC_SB_G0"),
+        abi::R_ARM_LDC_SB_G1 => Some("R_ARM_LDC_SB_G1"),
+        abi::R_ARM_LDC_SB_G2 => Some("R_ARM_LDC_SB_G2"),
+        abi::R_ARM_MOVW_BREL_NC => Some("R_ARM_MOVW_BREL_NC"),
+        abi::R_ARM_MOVT_BREL => Some("R_ARM_MOVT_BREL"),
+        abi::R_ARM_MOVW_BREL => Some("R_ARM_MOVW_BREL"),
+        abi::R_ARM_THM_MOVW_BREL_NC => Some("R_ARM_THM_MOVW_BREL_NC"),
+        abi::R_ARM_THM_MOVT_BREL => Some("R_ARM_THM_MOVT_BREL"),
+        abi::R_ARM_THM_MOVW_BREL => Some("R_ARM_THM_MOVW_BREL"),
+        abi::R_ARM_TLS_GOTDESC => Some("R_ARM_TLS_GOTDESC"),
+        abi::R_ARM_TLS_CALL => Some("R_ARM_TLS_CALL"),
+        abi::R_ARM_TLS_DESCSEQ => Some("R_ARM_TLS_DESCSEQ"),
+        abi::R_ARM_THM_TLS_CALL => Some("R_ARM_THM_TLS_CALL"),
+        abi::R_ARM_PLT32_ABS => Some("R_ARM_PLT32_ABS"),
+        abi::R_ARM_GOT_ABS => Some("R_ARM_GOT_ABS"),
+        abi::R_ARM_GOT_PREL => Some("R_ARM_GOT_PREL"),
+        abi::R_ARM_GOT_BREL12 => Some("R_ARM_GOT_BREL12"),
+        abi::R_ARM_GOTOFF12 => Some("R_ARM_GOTOFF12"),
+        abi::R_ARM_GOTRELAX => Some("R_ARM_GOTRELAX"),
+        abi::R_ARM_GNU_VTENTRY => Some("R_ARM_GNU_VTENTRY"),
+        abi::R_ARM_GNU_VTINHERIT => Some("R_ARM_GNU_VTINHERIT"),
+        abi::R_ARM_THM_JUMP11 => Some("R_ARM_THM_JUMP11"),
+        abi::R_ARM_THM_JUMP8 => Some("R_ARM_THM_JUMP8"),
+        abi::R_ARM_TLS_GD32 => Some("R_ARM_TLS_GD32"),
+        abi::R_ARM_TLS_LDM32 => Some("R_ARM_TLS_LDM32"),
+        abi::R_ARM_TLS_LDO32 => Some("R_ARM_TLS_LDO32"),
+        abi::R_ARM_TLS_IE32 => Some("R_ARM_TLS_IE32"),
+        abi::R_ARM_TLS_LE32 => Some("R_ARM_TLS_LE32"),
+        abi::R_ARM_TLS_LDO12 => Some("R_ARM_TLS_LDO12"),
+        abi::R_ARM_TLS_LE12 => Some("R_ARM_TLS_LE12"),
+        abi::R_ARM_TLS_IE12GP => Some("R_ARM_TLS_IE12GP"),
+        abi::R_ARM_ME_TOO => Some("R_ARM_ME_TOO"),
+        abi::R_ARM_THM_TLS_DESCSEQ16 => Some("R_ARM_THM_TLS_DESCSEQ16"),
+        abi::R_ARM_THM_TLS_DESCSEQ32 => Some("R_ARM_THM_TLS_DESCSEQ32"),
+        abi::R_ARM_THM_GOT_BREL12 => Some("R_ARM_THM_GOT_BREL12"),
+        abi::R_ARM_THM_ALU_ABS_G0_NC => Some("R_ARM_THM_ALU_ABS_G0_NC"),
+        abi::R_ARM_THM_ALU_ABS_G1_NC => Some("R_ARM_THM_ALU_ABS_G1_NC"),
+        abi::R_ARM_THM_ALU_ABS_G2_NC => Some("R_ARM_THM_ALU_ABS_G2_NC"),
+        abi::R_ARM_THM_ALU_ABS_G3 => Some("R_ARM_THM_ALU_ABS_G3"),
+        abi::R_ARM_THM_BF16 => Some("R_ARM_THM_BF16"),
+        abi::R_ARM_THM_BF12 => Some("R_ARM_THM_BF12"),
+        abi::R_ARM_THM_BF18 => Some("R_ARM_THM_BF18"),
+        abi::R_ARM_IRELATIVE => Some("R_ARM_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn r_mips_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_MIPS_NONE => Some("R_MIPS_NONE"),
+        abi::R_MIPS_16 => Some("R_MIPS_16"),
+        abi::R_MIPS_32 => Some("R_MIPS_32"),
+        abi::R_MIPS_REL32 => Some("R_MIPS_REL32"),
+        abi::R_MIPS_26 => Some("R_MIPS_26"),
+        abi::R_MIPS_HI16 => Some("R_MIPS_HI16"),
+        abi::R_MIPS_LO16 => Some("R_MIPS_LO16"),
+        abi::R_MIPS_GPREL16 => Some("R_MIPS_GPREL16"),
+        abi::R_MIPS_LITERAL => Some("R_MIPS_LITERAL"),
+        abi::R_MIPS_GOT16 => Some("R_MIPS_GOT16"),
+        abi::R_MIPS_PC16 => Some("R_MIPS_PC16"),
+        abi::R_MIPS_CALL16 => Some("R_MIPS_CALL16"),
+        abi::R_MIPS_GPREL32 => Some("R_MIPS_GPREL32"),
+        abi::R_MIPS_SHIFT5 => Some("R_MIPS_SHIFT5"),
+        abi::R_MIPS_SHIFT6 => Some("R_MIPS_SHIFT6"),
+        abi::R_MIPS_64 => Some("R_MIPS_64"),
+        abi::R_MIPS_GOT_DISP => Some("R_MIPS_GOT_DISP"),
+        abi::R_MIPS_GOT_PAGE => Some("R_MIPS_GOT_PAGE"),
+        abi::R_MIPS_GOT_OFST => Some("R_MIPS_GOT_OFST"),
+        abi::R_MIPS_GOT_HI16 => Some("R_MIPS_GOT_HI16"),
+        abi::R_MIPS_GOT_LO16 => Some("R_MIPS_GOT_LO16"),
+        abi::R_MIPS_SUB => Some("R_MIPS_SUB"),
+        abi::R_MIPS_INSERT_A => Some("R_MIPS_INSERT_A"),
+        abi::R_MIPS_INSERT_B => Some("R_MIPS_INSERT_B"),
+        abi::R_MIPS_DELETE => Some("R_MIPS_DELETE"),
+        abi::R_MIPS_HIGHER => Some("R_MIPS_HIGHER"),
+        abi::R_MIPS_HIGHEST => Some("R_MIPS_HIGHEST"),
+        abi::R_MIPS_CALL_HI16 => Some("R_MIPS_CALL_HI16"),
+        abi::R_MIPS_CALL_LO16 => Some("R_MIPS_CALL_LO16"),
+        abi::R_MIPS_SCN_DISP => Some("R_MIPS_SCN_DISP"),
+        abi::R_MIPS_REL16 => Some("R_MIPS_REL16"),
+        abi::R_MIPS_ADD_IMMEDIATE => Some("R_MIPS_ADD_IMMEDIATE"),
+        abi::R_MIPS_PJUMP => Some("R_MIPS_PJUMP"),
+        abi::R_MIPS_RELGOT => Some("R_MIPS_RELGOT"),
+        abi::R_MIPS_JALR => Some("R_MIPS_JALR"),
+        abi::R_MIPS_TLS_DTPMOD32 => Some("R_MIPS_TLS_DTPMOD32"),
+        abi::R_MIPS_TLS_DTPREL32 => Some("R_MIPS_TLS_DTPREL32"),
+        abi::R_MIPS_TLS_DTPMOD64 => Some("R_MIPS_TLS_DTPMOD64"),
+        abi::R_MIPS_TLS_DTPREL64 => Some("R_MIPS_TLS_DTPREL64"),
+        abi::R_MIPS_TLS_GD => Some("R_MIPS_TLS_GD"),
+        abi::R_MIPS_TLS_LDM => Some("R_MIPS_TLS_LDM"),
+        abi::R_MIPS_TLS_DTPREL_HI16 => Some("R_MIPS_TLS_DTPREL_HI16"),
+        abi::R_MIPS_TLS_DTPREL_LO16 => Some("R_MIPS_TLS_DTPREL_LO16"),
+        abi::R_MIPS_TLS_GOTTPREL => Some("R_MIPS_TLS_GOTTPREL"),
+        abi::R_MIPS_TLS_TPREL32 => Some("R_MIPS_TLS_TPREL32"),
+        abi::R_MIPS_TLS_TPREL64 => Some("R_MIPS_TLS_TPREL64"),
+        abi::R_MIPS_TLS_TPREL_HI16 => Some("R_MIPS_TLS_TPREL_HI16"),
+        abi::R_MIPS_TLS_TPREL_LO16 => Some("R_MIPS_TLS_TPREL_LO16"),
+        abi::R_MIPS_GLOB_DAT => Some("R_MIPS_GLOB_DAT"),
+        abi::R_MIPS_PC21_S2 => Some("R_MIPS_PC21_S2"),
+        abi::R_MIPS_PC26_S2 => Some("R_MIPS_PC26_S2"),
+        abi::R_MIPS_PC18_S3 => Some("R_MIPS_PC18_S3"),
+        abi::R_MIPS_PC19_S2 => Some("R_MIPS_PC19_S2"),
+        abi::R_MIPS_PCHI16 => Some("R_MIPS_PCHI16"),
+        abi::R_MIPS_PCLO16 => Some("R_MIPS_PCLO16"),
+        abi::R_MIPS_COPY => Some("R_MIPS_COPY"),
+        abi::R_MIPS_JUMP_SLOT => Some("R_MIPS_JUMP_SLOT"),
+        _ => None,
+    }
+}
+
+fn r_ppc_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC_NONE => Some("R_PPC_NONE"),
+        abi::R_PPC_ADDR32 => Some("R_PPC_ADDR32"),
+        abi::R_PPC_ADDR24 => Some("R_PPC_ADDR24"),
+        abi::R_PPC_ADDR16 => Some("R_PPC_ADDR16"),
+        abi::R_PPC_ADDR16_LO => Some("R_PPC_ADDR16_LO"),
+        abi::R_PPC_ADDR16_HI => Some("R_PPC_ADDR16_HI"),
+        abi::R_PPC_ADDR16_HA => Some("R_PPC_ADDR16_HA"),
+        abi::R_PPC_ADDR14 => Some("R_PPC_ADDR14"),
+        abi::R_PPC_ADDR14_BRTAKEN => Some("R_PPC_ADDR14_BRTAKEN"),
+        abi::R_PPC_ADDR14_BRNTAKEN => Some("R_PPC_ADDR14_BRNTAKEN"),
+        abi::R_PPC_REL24 => Some("R_PPC_REL24"),
+        abi::R_PPC_REL14 => Some("R_PPC_REL14"),
+        abi::R_PPC_REL14_BRTAKEN => Some("R_PPC_REL14_BRTAKEN"),
+        abi::R_PPC_REL14_BRNTAKEN => Some("R_PPC_REL14_BRNTAKEN"),
+        abi::R_PPC_GOT16 => Some("R_PPC_GOT16"),
+        abi::R_PPC_GOT16_LO => Some("R_PPC_GOT16_LO"),
+        abi::R_PPC_GOT16_HI => Some("R_PPC_GOT16_HI"),
+        abi::R_PPC_GOT16_HA => Some("R_PPC_GOT16_HA"),
+        abi::R_PPC_PLTREL24 => Some("R_PPC_PLTREL24"),
+        abi::R_PPC_COPY => Some("R_PPC_COPY"),
+        abi::R_PPC_GLOB_DAT => Some("R_PPC_GLOB_DAT"),
+        abi::R_PPC_JMP_SLOT => Some("R_PPC_JMP_SLOT"),
+        abi::R_PPC_RELATIVE => Some("R_PPC_RELATIVE"),
+        abi::R_PPC_LOCAL24PC => Some("R_PPC_LOCAL24PC"),
+        abi::R_PPC_UADDR32 => Some("R_PPC_UADDR32"),
+        abi::R_PPC_UADDR16 => Some("R_PPC_UADDR16"),
+        abi::R_PPC_REL32 => Some("R_PPC_REL32"),
+        abi::R_PPC_PLT32 => Some("R_PPC_PLT32"),
+        abi::R_PPC_PLTREL32 => Some("R_PPC_PLTREL32"),
+        abi::R_PPC_PLT16_LO => Some("R_PPC_PLT16_LO"),
+        abi::R_PPC_PLT16_HI => Some("R_PPC_PLT16_HI"),
+        abi::R_PPC_PLT16_HA => Some("R_PPC_PLT16_HA"),
+        abi::R_PPC_SDAREL16 => Some("R_PPC_SDAREL16"),
+        abi::R_PPC_SECTOFF => Some("R_PPC_SECTOFF"),
+        abi::R_PPC_SECTOFF_LO => Some("R_PPC_SECTOFF_LO"),
+        abi::R_PPC_SECTOFF_HI => Some("R_PPC_SECTOFF_HI"),
+        abi::R_PPC_SECTOFF_HA => Some("R_PPC_SECTOFF_HA"),
+        abi::R_PPC_TLS => Some("R_PPC_TLS"),
+        abi::R_PPC_DTPMOD32 => Some("R_PPC_DTPMOD32"),
+        abi::R_PPC_TPREL16 => Some("R_PPC_TPREL16"),
+        abi::R_PPC_TPREL16_LO => Some("R_PPC_TPREL16_LO"),
+        abi::R_PPC_TPREL16_HI => Some("R_PPC_TPREL16_HI"),
+        abi::R_PPC_TPREL16_HA => Some("R_PPC_TPREL16_HA"),
+        abi::R_PPC_TPREL32 => Some("R_PPC_TPREL32"),
+        abi::R_PPC_DTPREL16 => Some("R_PPC_DTPREL16"),
+        abi::R_PPC_DTPREL16_LO => Some("R_PPC_DTPREL16_LO"),
+        abi::R_PPC_DTPREL16_HI => Some("R_PPC_DTPREL16_HI"),
+        abi::R_PPC_DTPREL16_HA => Some("R_PPC_DTPREL16_HA"),
+        abi::R_PPC_DTPREL32 => Some("R_PPC_DTPREL32"),
+        abi::R_PPC_GOT_TLSGD16 => Some("R_PPC_GOT_TLSGD16"),
+        abi::R_PPC_GOT_TLSGD16_LO => Some("R_PPC_GOT_TLSGD16_LO"),
+        abi::R_PPC_GOT_TLSGD16_HI => Some("R_PPC_GOT_TLSGD16_HI"),
+        abi::R_PPC_GOT_TLSGD16_HA => Some("R_PPC_GOT_TLSGD16_HA"),
+        abi::R_PPC_GOT_TLSLD16 => Some("R_PPC_GOT_TLSLD16"),
+        abi::R_PPC_GOT_TLSLD16_LO => Some("R_PPC_GOT_TLSLD16_LO"),
+        abi::R_PPC_GOT_TLSLD16_HI => Some("R_PPC_GOT_TLSLD16_HI"),
+        abi::R_PPC_GOT_TLSLD16_HA => Some("R_PPC_GOT_TLSLD16_HA"),
+        abi::R_PPC_GOT_TPREL16 => Some("R_PPC_GOT_TPREL16"),
+        abi::R_PPC_GOT_TPREL16_LO => Some("R_PPC_GOT_TPREL16_LO"),
+        abi::R_PPC_GOT_TPREL16_HI => Some("R_PPC_GOT_TPREL16_HI"),
+        abi::R_PPC_GOT_TPREL16_HA => Some("R_PPC_GOT_TPREL16_HA"),
+        abi::R_PPC_GOT_DTPREL16 => Some("R_PPC_GOT_DTPREL16"),
+        abi::R_PPC_GOT_DTPREL16_LO => Some("R_PPC_GOT_DTPREL16_LO"),
+        abi::R_PPC_GOT_DTPREL16_HI => Some("R_PPC_GOT_DTPREL16_HI"),
+        abi::R_PPC_GOT_DTPREL16_HA => Some("R_PPC_GOT_DTPREL16_HA"),
+        abi::R_PPC_TLSGD => Some("R_PPC_TLSGD"),
+        abi::R_PPC_TLSLD => Some("R_PPC_TLSLD"),
+        abi::R_PPC_EMB_NADDR32 => Some("R_PPC_EMB_NADDR32"),
+        abi::R_PPC_EMB_NADDR16 => Some("R_PPC_EMB_NADDR16"),
+        abi::R_PPC_EMB_NADDR16_LO => Some("R_PPC_EMB_NADDR16_LO"),
+        abi::R_PPC_EMB_NADDR16_HI => Some("R_PPC_EMB_NADDR16_HI"),
+        abi::R_PPC_EMB_NADDR16_HA => Some("R_PPC_EMB_NADDR16_HA"),
+        abi::R_PPC_EMB_SDAI16 => Some("R_PPC_EMB_SDAI16"),
+        abi::R_PPC_EMB_SDA2I16 => Some("R_PPC_EMB_SDA2I16"),
+        abi::R_PPC_EMB_SDA2REL => Some("R_PPC_EMB_SDA2REL"),
+        abi::R_PPC_EMB_SDA21 => Some("R_PPC_EMB_SDA21"),
+        abi::R_PPC_EMB_MRKREF => Some("R_PPC_EMB_MRKREF"),
+        abi::R_PPC_EMB_RELSEC16 => Some("R_PPC_EMB_RELSEC16"),
+        abi::R_PPC_EMB_RELST_LO => Some("R_PPC_EMB_RELST_LO"),
+        abi::R_PPC_EMB_RELST_HI => Some("R_PPC_EMB_RELST_HI"),
+        abi::R_PPC_EMB_RELST_HA => Some("R_PPC_EMB_RELST_HA"),
+        abi::R_PPC_EMB_BIT_FLD => Some("R_PPC_EMB_BIT_FLD"),
+        abi::R_PPC_EMB_RELSDA => Some("R_PPC_EMB_RELSDA"),
+        abi::R_PPC_DIAB_SDA21_LO => Some("R_PPC_DIAB_SDA21_LO"),
+        abi::R_PPC_DIAB_SDA21_HI => Some("R_PPC_DIAB_SDA21_HI"),
+        abi::R_PPC_DIAB_SDA21_HA => Some("R_PPC_DIAB_SDA21_HA"),
+        abi::R_PPC_DIAB_RELSDA_LO => Some("R_PPC_DIAB_RELSDA_LO"),
+        abi::R_PPC_DIAB_RELSDA_HI => Some("R_PPC_DIAB_RELSDA_HI"),
+        abi::R_PPC_DIAB_RELSDA_HA => Some("R_PPC_DIAB_RELSDA_HA"),
+        abi::R_PPC_IRELATIVE => Some("R_PPC_IRELATIVE"),
+        abi::R_PPC_REL16 => Some("R_PPC_REL16"),
+        abi::R_PPC_REL16_LO => Some("R_PPC_REL16_LO"),
+        abi::R_PPC_REL16_HI => Some("R_PPC_REL16_HI"),
+        abi::R_PPC_REL16_HA => Some("R_PPC_REL16_HA"),
+        abi::R_PPC_TOC16 => Some("R_PPC_TOC16"),
+        _ => None,
+    }
+}
+
+fn r_ppc64_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC64_NONE => Some("R_PPC64_NONE"),
+        abi::R_PPC64_ADDR32 => Some("R_PPC64_ADDR32"),
+        abi::R_PPC64_ADDR24 => Some("R_PPC64_ADDR24"),
+        abi::R_PPC64_ADDR16 => Some("R_PPC64_ADDR16"),
+        abi::R_PPC64_ADDR16_LO => Some("R_PPC64_ADDR16_LO"),
+        abi::R_PPC64_ADDR16_HI => Some("R_PPC64_ADDR16_HI"),
+        abi::R_PPC64_ADDR16_HA => Some("R_PPC64_ADDR16_HA"),
+        abi::R_PPC64_ADDR14 => Some("R_PPC64_ADDR14"),
+        abi::R_PPC64_ADDR14_BRTAKEN => Some("R_PPC64_ADDR14_BRTAKEN"),
+        abi::R_PPC64_ADDR14_BRNTAKEN => Some("R_PPC64_ADDR14_BRNTAKEN"),
+        abi::R_PPC64_REL24 => Some("R_PPC64_REL24"),
+        abi::R_PPC64_REL14 => Some("R_PPC64_REL14"),
+        abi::R_PPC64_REL14_BRTAKEN => Some("R_PPC64_REL14_BRTAKEN"),
+        abi::R_PPC64_REL14_BRNTAKEN => Some("R_PPC64_REL14_BRNTAKEN"),
+        abi::R_PPC64_GOT16 => Some("R_PPC64_GOT16"),
+        abi::R_PPC64_GOT16_LO => Some("R_PPC64_GOT16_LO"),
+        abi::R_PPC64_GOT16_HI => Some("R_PPC64_GOT16_HI"),
+        abi::R_PPC64_GOT16_HA => Some("R_PPC64_GOT16_HA"),
+        abi::R_PPC64_COPY => Some("R_PPC64_COPY"),
+        abi::R_PPC64_GLOB_DAT => Some("R_PPC64_GLOB_DAT"),
+        abi::R_PPC64_JMP_SLOT => Some("R_PPC64_JMP_SLOT"),
+        abi::R_PPC64_RELATIVE => Some("R_PPC64_RELATIVE"),
+        abi::R_PPC64_UADDR32 => Some("R_PPC64_UADDR32"),
+        abi::R_PPC64_UADDR16 => Some("R_PPC64_UADDR16"),
+        abi::R_PPC64_REL32 => Some("R_PPC64_REL32"),
+        abi::R_PPC64_PLT32 => Some("R_PPC64_PLT32"),
+        abi::R_PPC64_PLTREL32 => Some("R_PPC64_PLTREL32"),
+        abi::R_PPC64_PLT16_LO => Some("R_PPC64_PLT16_LO"),
+        abi::R_PPC64_PLT16_HI => Some("R_PPC64_PLT16_HI"),
+        abi::R_PPC64_PLT16_HA => Some("R_PPC64_PLT16_HA"),
+        abi::R_PPC64_SECTOFF => Some("R_PPC64_SECTOFF"),
+        abi::R_PPC64_SECTOFF_LO => Some("R_PPC64_SECTOFF_LO"),
+        abi::R_PPC64_SECTOFF_HI => Some("R_PPC64_SECTOFF_HI"),
+        abi::R_PPC64_SECTOFF_HA => Some("R_PPC64_SECTOFF_HA"),
+        abi::R_PPC64_ADDR30 => Some("R_PPC64_ADDR30"),
+        abi::R_PPC64_ADDR64 => Some("R_PPC64_ADDR64"),
+        abi::R_PPC64_ADDR16_HIGHER => Some("R_PPC64_ADDR16_HIGHER"),
+        abi::R_PPC64_ADDR16_HIGHERA => Some("R_PPC64_ADDR16_HIGHERA"),
+        abi::R_PPC64_ADDR16_HIGHEST => Some("R_PPC64_ADDR16_HIGHEST"),
+        abi::R_PPC64_ADDR16_HIGHESTA => Some("R_PPC64_ADDR16_HIGHESTA"),
+        abi::R_PPC64_UADDR64 => Some("R_PPC64_UADDR64"),
+        abi::R_PPC64_REL64 => Some("R_PPC64_REL64"),
+        abi::R_PPC64_PLT64 => Some("R_PPC64_PLT64"),
+        abi::R_PPC64_PLTREL64 => Some("R_PPC64_PLTREL64"),
+        abi::R_PPC64_TOC16 => Some("R_PPC64_TOC16"),
+        abi::R_PPC64_TOC16_LO => Some("R_PPC64_TOC16_LO"),
+        abi::R_PPC64_TOC16_HI => Some("R_PPC64_TOC16_HI"),
+        abi::R_PPC64_TOC16_HA => Some("R_PPC64_TOC16_HA"),
+        abi::R_PPC64_TOC => Some("R_PPC64_TOC"),
+        abi::R_PPC64_PLTGOT16 => Some("R_PPC64_PLTGOT16"),
+        abi::R_PPC64_PLTGOT16_LO => Some("R_PPC64_PLTGOT16_LO"),
+        abi::R_PPC64_PLTGOT16_HI => Some("R_PPC64_PLTGOT16_HI"),
+        abi::R_PPC64_PLTGOT16_HA => Some("R_PPC64_PLTGOT16_HA"),
+        abi::R_PPC64_ADDR16_DS => Some("R_PPC64_ADDR16_DS"),
+        abi::R_PPC64_ADDR16_LO_DS => Some("R_PPC64_ADDR16_LO_DS"),
+        abi::R_PPC64_GOT16_DS => Some("R_PPC64_GOT16_DS"),
+        abi::R_PPC64_GOT16_LO_DS => Some("R_PPC64_GOT16_LO_DS"),
+        abi::R_PPC64_PLT16_LO_DS => Some("R_PPC64_PLT16_LO_DS"),
+        abi::R_PPC64_SECTOFF_DS => Some("R_PPC64_SECTOFF_DS"),
+        abi::R_PPC64_SECTOFF_LO_DS => Some("R_PPC64_SECTOFF_LO_DS"),
+        abi::R_PPC64_TOC16_DS => Some("R_PPC64_TOC16_DS"),
+        abi::R_PPC64_TOC16_LO_DS => Some("R_PPC64_TOC16_LO_DS"),
+        abi::R_PPC64_PLTGOT16_DS => Some("R_PPC64_PLTGOT16_DS"),
+        abi::R_PPC64_PLTGOT16_LO_DS => Some("R_PPC64_PLTGOT16_LO_DS"),
+        abi::R_PPC64_TLS => Some("R_PPC64_TLS"),
+        abi::R_PPC64_DTPMOD64 => Some("R_PPC64_DTPMOD64"),
+        abi::R_PPC64_TPREL16 => Some("R_PPC64_TPREL16"),
+        abi::R_PPC64_TPREL16_LO => Some("R_PPC64_TPREL16_LO"),
+        abi::R_PPC64_TPREL16_HI => Some("R_PPC64_TPREL16_HI"),
+        abi::R_PPC64_TPREL16_HA => Some("R_PPC64_TPREL16_HA"),
+        abi::R_PPC64_TPREL64 => Some("R_PPC64_TPREL64"),
+        abi::R_PPC64_DTPREL16 => Some("R_PPC64_DTPREL16"),
+        abi::R_PPC64_DTPREL16_LO => Some("R_PPC64_DTPREL16_LO"),
+        abi::R_PPC64_DTPREL16_HI => Some("R_PPC64_DTPREL16_HI"),
+        abi::R_PPC64_DTPREL16_HA => Some("R_PPC64_DTPREL16_HA"),
+        abi::R_PPC64_DTPREL64 => Some("R_PPC64_DTPREL64"),
+        abi::R_PPC64_GOT_TLSGD16 => Some("R_PPC64_GOT_TLSGD16"),
+        abi::R_PPC64_GOT_TLSGD16_LO => Some("R_PPC64_GOT_TLSGD16_LO"),
+        abi::R_PPC64_GOT_TLSGD16_HI => Some("R_PPC64_GOT_TLSGD16_HI"),
+        abi::R_PPC64_GOT_TLSGD16_HA => Some("R_PPC64_GOT_TLSGD16_HA"),
+        abi::R_PPC64_GOT_TLSLD16 => Some("R_PPC64_GOT_TLSLD16"),
+        abi::R_PPC64_GOT_TLSLD16_LO => Some("R_PPC64_GOT_TLSLD16_LO"),
+        abi::R_PPC64_GOT_TLSLD16_HI => Some("R_PPC64_GOT_TLSLD16_HI"),
+        abi::R_PPC64_GOT_TLSLD16_HA => Some("R_PPC64_GOT_TLSLD16_HA"),
+        abi::R_PPC64_GOT_TPREL16_DS => Some("R_PPC64_GOT_TPREL16_DS"),
+        abi::R_PPC64_GOT_TPREL16_LO_DS => Some("R_PPC64_GOT_TPREL16_LO_DS"),
+        abi::R_PPC64_GOT_TPREL16_HI => Some("R_PPC64_GOT_TPREL16_HI"),
+        abi::R_PPC64_GOT_TPREL16_HA => Some("R_PPC64_GOT_TPREL16_HA"),
+        abi::R_PPC64_GOT_DTPREL16_DS => Some("R_PPC64_GOT_DTPREL16_DS"),
+        abi::R_PPC64_GOT_DTPREL16_LO_DS => Some("R_PPC64_GOT_DTPREL16_LO_DS"),
+        abi::R_PPC64_GOT_DTPREL16_HI => Some("R_PPC64_GOT_DTPREL16_HI"),
+        abi::R_PPC64_GOT_DTPREL16_HA => Some("R_PPC64_GOT_DTPREL16_HA"),
+        abi::R_PPC64_TPREL16_DS => Some("R_PPC64_TPREL16_DS"),
+        abi::R_PPC64_TPREL16_LO_DS => Some("R_PPC64_TPREL16_LO_DS"),
+        abi::R_PPC64_TPREL16_HIGHER => Some("R_PPC64_TPREL16_HIGHER"),
+        abi::R_PPC64_TPREL16_HIGHERA => Some("R_PPC64_TPREL16_HIGHERA"),
+        abi::R_PPC64_TPREL16_HIGHEST => Some("R_PPC64_TPREL16_HIGHEST"),
+        abi::R_PPC64_TPREL16_HIGHESTA => Some("R_PPC64_TPREL16_HIGHESTA"),
+        abi::R_PPC64_DTPREL16_DS => Some("R_PPC64_DTPREL16_DS"),
+        abi::R_PPC64_DTPREL16_LO_DS => Some("R_PPC64_DTPREL16_LO_DS"),
+        abi::R_PPC64_DTPREL16_HIGHER => Some("R_PPC64_DTPREL16_HIGHER"),
+        abi::R_PPC64_DTPREL16_HIGHERA => Some("R_PPC64_DTPREL16_HIGHERA"),
+        abi::R_PPC64_DTPREL16_HIGHEST => Some("R_PPC64_DTPREL16_HIGHEST"),
+        abi::R_PPC64_DTPREL16_HIGHESTA => Some("R_PPC64_DTPREL16_HIGHESTA"),
+        abi::R_PPC64_TLSGD => Some("R_PPC64_TLSGD"),
+        abi::R_PPC64_TLSLD => Some("R_PPC64_TLSLD"),
+        abi::R_PPC64_TOCSAVE => Some("R_PPC64_TOCSAVE"),
+        abi::R_PPC64_ADDR16_HIGH => Some("R_PPC64_ADDR16_HIGH"),
+        abi::R_PPC64_ADDR16_HIGHA => Some("R_PPC64_ADDR16_HIGHA"),
+        abi::R_PPC64_TPREL16_HIGH => Some("R_PPC64_TPREL16_HIGH"),
+        abi::R_PPC64_TPREL16_HIGHA => Some("R_PPC64_TPREL16_HIGHA"),
+        abi::R_PPC64_DTPREL16_HIGH => Some("R_PPC64_DTPREL16_HIGH"),
+        abi::R_PPC64_DTPREL16_HIGHA => Some("R_PPC64_DTPREL16_HIGHA"),
+        abi::R_PPC64_JMP_IREL => Some("R_PPC64_JMP_IREL"),
+        abi::R_PPC64_IRELATIVE => Some("R_PPC64_IRELATIVE"),
+        abi::R_PPC64_REL16 => Some("R_PPC64_REL16"),
+        abi::R_PPC64_REL16_LO => Some("R_PPC64_REL16_LO"),
+        abi::R_PPC64_REL16_HI => Some("R_PPC64_REL16_HI"),
+        abi::R_PPC64_REL16_HA => Some("R_PPC64_REL16_HA"),
+        _ => None,
+    }
+}
+
+fn r_s390_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_390_NONE => Some("R_390_NONE"),
+        abi::R_390_8 => Some("R_390_8"),
+        abi::R_390_12 => Some("R_390_12"),
+        abi::R_390_16 => Some("R_390_16"),
+        abi::R_390_32 => Some("R_390_32"),
+        abi::R_390_PC32 => Some("R_390_PC32"),
+        abi::R_390_GOT12 => Some("R_390_GOT12"),
+        abi::R_390_GOT32 => Some("R_390_GOT32"),
+        abi::R_390_PLT32 => Some("R_390_PLT32"),
+        abi::R_390_COPY => Some("R_390_COPY"),
+        abi::R_390_GLOB_DAT => Some("R_390_GLOB_DAT"),
+        abi::R_390_JMP_SLOT => Some("R_390_JMP_SLOT"),
+        abi::R_390_RELATIVE => Some("R_390_RELATIVE"),
+        abi::R_390_GOTOFF32 => Some("R_390_GOTOFF32"),
+        abi::R_390_GOTPC => Some("R_390_GOTPC"),
+        abi::R_390_GOT16 => Some("R_390_GOT16"),
+        abi::R_390_PC16 => Some("R_390_PC16"),
+        abi::R_390_PC16DBL => Some("R_390_PC16DBL"),
+        abi::R_390_PLT16DBL => Some("R_390_PLT16DBL"),
+        abi::R_390_PC32DBL => Some("R_390_PC32DBL"),
+        abi::R_390_PLT32DBL => Some("R_390_PLT32DBL"),
+        abi::R_390_GOTPCDBL => Some("R_390_GOTPCDBL"),
+        abi::R_390_64 => Some("R_390_64"),
+        abi::R_390_PC64 => Some("R_390_PC64"),
+        abi::R_390_GOT64 => Some("R_390_GOT64"),
+        abi::R_390_PLT64 => Some("R_390_PLT64"),
+        abi::R_390_GOTENT => Some("R_390_GOTENT"),
+        abi::R_390_GOTOFF16 => Some("R_390_GOTOFF16"),
+        abi::R_390_GOTOFF64 => Some("R_390_GOTOFF64"),
+        abi::R_390_GOTPLT12 => Some("R_390_GOTPLT12"),
+        abi::R_390_GOTPLT16 => Some("R_390_GOTPLT16"),
+        abi::R_390_GOTPLT32 => Some("R_390_GOTPLT32"),
+        abi::R_390_GOTPLT64 => Some("R_390_GOTPLT64"),
+        abi::R_390_GOTPLTENT => Some("R_390_GOTPLTENT"),
+        abi::R_390_PLTOFF16 => Some("R_390_PLTOFF16"),
+        abi::R_390_PLTOFF32 => Some("R_390_PLTOFF32"),
+        abi::R_390_PLTOFF64 => Some("R_390_PLTOFF64"),
+        abi::R_390_TLS_LOAD => Some("R_390_TLS_LOAD"),
+        abi::R_390_TLS_GDCALL => Some("R_390_TLS_GDCALL"),
+        abi::R_390_TLS_LDCALL => Some("R_390_TLS_LDCALL"),
+        abi::R_390_TLS_GD32 => Some("R_390_TLS_GD32"),
+        abi::R_390_TLS_GD64 => Some("R_390_TLS_GD64"),
+        abi::R_390_TLS_GOTIE12 => Some("R_390_TLS_GOTIE12"),
+        abi::R_390_TLS_GOTIE32 => Some("R_390_TLS_GOTIE32"),
+        abi::R_390_TLS_GOTIE64 => Some("R_390_TLS_GOTIE64"),
+        abi::R_390_TLS_LDM32 => Some("R_390_TLS_LDM32"),
+        abi::R_390_TLS_LDM64 => Some("R_390_TLS_LDM64"),
+        abi::R_390_TLS_IE32 => Some("R_390_TLS_IE32"),
+        abi::R_390_TLS_IE64 => Some("R_390_TLS_IE64"),
+        abi::R_390_TLS_IEENT => Some("R_390_TLS_IEENT"),
+        abi::R_390_TLS_LE32 => Some("R_390_TLS_LE32"),
+        abi::R_390_TLS_LE64 => Some("R_390_TLS_LE64"),
+        abi::R_390_TLS_LDO32 => Some("R_390_TLS_LDO32"),
+        abi::R_390_TLS_LDO64 => Some("R_390_TLS_LDO64"),
+        abi::R_390_TLS_DTPMOD => Some("R_390_TLS_DTPMOD"),
+        abi::R_390_TLS_DTPOFF => Some("R_390_TLS_DTPOFF"),
+        abi::R_390_TLS_TPOFF => Some("R_390_TLS_TPOFF"),
+        abi::R_390_20 => Some("R_390_20"),
+        abi::R_390_GOT20 => Some("R_390_GOT20"),
+        abi::R_390_GOTPLT20 => Some("R_390_GOTPLT20"),
+        abi::R_390_TLS_GOTIE20 => Some("R_390_TLS_GOTIE20"),
+        abi::R_390_IRELATIVE => Some("R_390_IRELATIVE"),
+        abi::R_390_PC12DBL => Some("R_390_PC12DBL"),
+        abi::R_390_PLT12DBL => Some("R_390_PLT12DBL"),
+        abi::R_390_PC24DBL => Some("R_390_PC24DBL"),
+        abi::R_390_PLT24DBL => Some("R_390_PLT24DBL"),
+        _ => None,
+    }
+}
+
+fn r_loongarch_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_LARCH_NONE => Some("R_LARCH_NONE"),
+        abi::R_LARCH_32 => Some("R_LARCH_32"),
+        abi::R_LARCH_64 => Some("R_LARCH_64"),
+        abi::R_LARCH_RELATIVE => Some("R_LARCH_RELATIVE"),
+        abi::R_LARCH_COPY => Some("R_LARCH_COPY"),
+        abi::R_LARCH_JUMP_SLOT => Some("R_LARCH_JUMP_SLOT"),
+        abi::R_LARCH_TLS_DTPMOD32 => Some("R_LARCH_TLS_DTPMOD32"),
+        abi::R_LARCH_TLS_DTPMOD64 => Some("R_LARCH_TLS_DTPMOD64"),
+        abi::R_LARCH_TLS_DTPREL32 => Some("R_LARCH_TLS_DTPREL32"),
+        abi::R_LARCH_TLS_DTPREL64 => Some("R_LARCH_TLS_DTPREL64"),
+        abi::R_LARCH_TLS_TPREL32 => Some("R_LARCH_TLS_TPREL32"),
+        abi::R_LARCH_TLS_TPREL64 => Some("R_LARCH_TLS_TPREL64"),
+        abi::R_LARCH_IRELATIVE => Some("R_LARCH_IRELATIVE"),
+        abi::R_LARCH_TLS_DESC32 => Some("R_LARCH_TLS_DESC32"),
+        abi::R_LARCH_TLS_DESC64 => Some("R_LARCH_TLS_DESC64"),
+        abi::R_LARCH_MARK_LA => Some("R_LARCH_MARK_LA"),
+        abi::R_LARCH_MARK_PCREL => Some("R_LARCH_MARK_PCREL"),
+        abi::R_LARCH_SOP_PUSH_PCREL => Some("R_LARCH_SOP_PUSH_PCREL"),
+        abi::R_LARCH_SOP_PUSH_ABSOLUTE => Some("R_LARCH_SOP_PUSH_ABSOLUTE"),
+        abi::R_LARCH_SOP_PUSH_DUP => Some("R_LARCH_SOP_PUSH_DUP"),
+        abi::R_LARCH_SOP_PUSH_GPREL => Some("R_LARCH_SOP_PUSH_GPREL"),
+        abi::R_LARCH_SOP_PUSH_TLS_TPREL => Some("R_LARCH_SOP_PUSH_TLS_TPREL"),
+        abi::R_LARCH_SOP_PUSH_TLS_GOT => Some("R_LARCH_SOP_PUSH_TLS_GOT"),
+        abi::R_LARCH_SOP_PUSH_TLS_GD => Some("R_LARCH_SOP_PUSH_TLS_GD"),
+        abi::R_LARCH_SOP_PUSH_PLT_PCREL => Some("R_LARCH_SOP_PUSH_PLT_PCREL"),
+        abi::R_LARCH_SOP_ASSERT => Some("R_LARCH_SOP_ASSERT"),
+        abi::R_LARCH_SOP_NOT => Some("R_LARCH_SOP_NOT"),
+        abi::R_LARCH_SOP_SUB => Some("R_LARCH_SOP_SUB"),
+        abi::R_LARCH_SOP_SL => Some("R_LARCH_SOP_SL"),
+        abi::R_LARCH_SOP_SR => Some("R_LARCH_SOP_SR"),
+        abi::R_LARCH_SOP_ADD => Some("R_LARCH_SOP_ADD"),
+        abi::R_LARCH_SOP_AND => Some("R_LARCH_SOP_AND"),
+        abi::R_LARCH_SOP_IF_ELSE => Some("R_LARCH_SOP_IF_ELSE"),
+        abi::R_LARCH_SOP_POP_32_S_10_5 => Some("R_LARCH_SOP_POP_32_S_10_5"),
+        abi::R_LARCH_SOP_POP_32_U_10_12 => Some("R_LARCH_SOP_POP_32_U_10_12"),
+        abi::R_LARCH_SOP_POP_32_S_10_12 => Some("R_LARCH_SOP_POP_32_S_10_12"),
+        abi::R_LARCH_SOP_POP_32_S_10_16 => Some("R_LARCH_SOP_POP_32_S_10_16"),
+        abi::R_LARCH_SOP_POP_32_S_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_S_5_20 => Some("R_LARCH_SOP_POP_32_S_5_20"),
+        abi::R_LARCH_SOP_POP_32_S_0_5_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_0_5_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_S_0_10_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_0_10_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_U => Some("R_LARCH_SOP_POP_32_U"),
+        abi::R_LARCH_ADD8 => Some("R_LARCH_ADD8"),
+        abi::R_LARCH_ADD16 => Some("R_LARCH_ADD16"),
+        abi::R_LARCH_ADD24 => Some("R_LARCH_ADD24"),
+        abi::R_LARCH_ADD32 => Some("R_LARCH_ADD32"),
+        abi::R_LARCH_ADD64 => Some("R_LARCH_ADD64"),
+        abi::R_LARCH_SUB8 => Some("R_LARCH_SUB8"),
+        abi::R_LARCH_SUB16 => Some("R_LARCH_SUB16"),
+        abi::R_LARCH_SUB24 => Some("R_LARCH_SUB24"),
+        abi::R_LARCH_SUB32 => Some("R_LARCH_SUB32"),
+        abi::R_LARCH_SUB64 => Some("R_LARCH_SUB64"),
+        abi::R_LARCH_GNU_VTINHERIT => Some("R_LARCH_GNU_VTINHERIT"),
+        abi::R_LARCH_GNU_VTENTRY => Some("R_LARCH_GNU_VTENTRY"),
+        abi::R_LARCH_B16 => Some("R_LARCH_B16"),
+        abi::R_LARCH_B21 => Some("R_LARCH_B21"),
+        abi::R_LARCH_B26 => Some("R_LARCH_B26"),
+        abi::R_LARCH_ABS_HI20 => Some("R_LARCH_ABS_HI20"),
+        abi::R_LARCH_ABS_LO12 => Some("R_LARCH_ABS_LO12"),
+        abi::R_LARCH_ABS64_LO20 => Some("R_LARCH_ABS64_LO20"),
+        abi::R_LARCH_ABS64_HI12 => Some("R_LARCH_ABS64_HI12"),
+        abi::R_LARCH_PCALA_HI20 => Some("R_LARCH_PCALA_HI20"),
+        abi::R_LARCH_PCALA_LO12 => Some("R_LARCH_PCALA_LO12"),
+        abi::R_LARCH_PCALA64_LO20 => Some("R_LARCH_PCALA64_LO20"),
+        abi::R_LARCH_PCALA64_HI12 => Some("R_LARCH_PCALA64_HI12"),
+        abi::R_LARCH_GOT_PC_HI20 => Some("R_LARCH_GOT_PC_HI20"),
+        abi::R_LARCH_GOT_PC_LO12 => Some("R_LARCH_GOT_PC_LO12"),
+        abi::R_LARCH_GOT64_PC_LO20 => Some("R_LARCH_GOT64_PC_LO20"),
+        abi::R_LARCH_GOT64_PC_HI12 => Some("R_LARCH_GOT64_PC_HI12"),
+        abi::R_LARCH_GOT_HI20 => Some("R_LARCH_GOT_HI20"),
+        abi::R_LARCH_GOT_LO12 => Some("R_LARCH_GOT_LO12"),
+        abi::R_LARCH_GOT64_LO20 => Some("R_LARCH_GOT64_LO20"),
+        abi::R_LARCH_GOT64_HI12 => Some("R_LARCH_GOT64_HI12"),
+        abi::R_LARCH_TLS_LE_HI20 => Some("R_LARCH_TLS_LE_HI20"),
+        abi::R_LARCH_TLS_LE_LO12 => Some("R_LARCH_TLS_LE_LO12"),
+        abi::R_LARCH_TLS_LE64_LO20 => Some("R_LARCH_TLS_LE64_LO20"),
+        abi::R_LARCH_TLS_LE64_HI12 => Some("R_LARCH_TLS_LE64_HI12"),
+        abi::R_LARCH_TLS_IE_PC_HI20 => Some("R_LARCH_TLS_IE_PC_HI20"),
+        abi::R_LARCH_TLS_IE_PC_LO12 => Some("R_LARCH_TLS_IE_PC_LO12"),
+        abi::R_LARCH_TLS_IE64_PC_LO20 => Some("R_LARCH_TLS_IE64_PC_LO20"),
+        abi::R_LARCH_TLS_IE64_PC_HI12 => Some("R_LARCH_TLS_IE64_PC_HI12"),
+        abi::R_LARCH_TLS_IE_HI20 => Some("R_LARCH_TLS_IE_HI20"),
+        abi::R_LARCH_TLS_IE_LO12 => Some("R_LARCH_TLS_IE_LO12"),
+        abi::R_LARCH_TLS_IE64_LO20 => Some("R_LARCH_TLS_IE64_LO20"),
+        abi::R_LARCH_TLS_IE64_HI12 => Some("R_LARCH_TLS_IE64_HI12"),
+        abi::R_LARCH_TLS_LD_PC_HI20 => Some("R_LARCH_TLS_LD_PC_HI20"),
+        abi::R_LARCH_TLS_LD_HI20 => Some("R_LARCH_TLS_LD_HI20"),
+        abi::R_LARCH_TLS_GD_PC_HI20 => Some("R_LARCH_TLS_GD_PC_HI20"),
+        abi::R_LARCH_TLS_GD_HI20 => Some("R_LARCH_TLS_GD_HI20"),
+        abi::R_LARCH_32_PCREL => Some("R_LARCH_32_PCREL"),
+        abi::R_LARCH_RELAX => Some("R_LARCH_RELAX"),
+        abi::R_LARCH_ALIGN => Some("R_LARCH_ALIGN"),
+        abi::R_LARCH_PCREL20_S2 => Some("R_LARCH_PCREL20_S2"),
+        abi::R_LARCH_ADD6 => Some("R_LARCH_ADD6"),
+        abi::R_LARCH_SUB6 => Some("R_LARCH_SUB6"),
+        abi::R_LARCH_ADD_ULEB128 => Some("R_LARCH_ADD_ULEB128"),
+        abi::R_LARCH_SUB_ULEB128 => Some("R_LARCH_SUB_ULEB128"),
+        abi::R_LARCH_64_PCREL => Some("R_LARCH_64_PCREL"),
+        abi::R_LARCH_CALL36 => Some("R_LARCH_CALL36"),
+        abi::R_LARCH_TLS_DESC_PC_HI20 => Some("R_LARCH_TLS_DESC_PC_HI20"),
+        abi::R_LARCH_TLS_DESC_PC_LO12 => Some("R_LARCH_TLS_DESC_PC_LO12"),
+        abi::R_LARCH_TLS_DESC64_PC_LO20 => Some("R_LARCH_TLS_DESC64_PC_LO20"),
+        abi::R_LARCH_TLS_DESC64_PC_HI12 => Some("R_LARCH_TLS_DESC64_PC_HI12"),
+        abi::R_LARCH_TLS_DESC_HI20 => Some("R_LARCH_TLS_DESC_HI20"),
+        abi::R_LARCH_TLS_DESC_LO12 => Some("R_LARCH_TLS_DESC_LO12"),
+        abi::R_LARCH_TLS_DESC64_LO20 => Some("R_LARCH_TLS_DESC64_LO20"),
+        abi::R_LARCH_TLS_DESC64_HI12 => Some("R_LARCH_TLS_DESC64_HI12"),
+        abi::R_LARCH_TLS_DESC_LD => Some("R_LARCH_TLS_DESC_LD"),
+        abi::R_LARCH_TLS_DESC_CALL => Some("R_LARCH_TLS_DESC_CALL"),
+        abi::R_LARCH_TLS_LE_HI20_R => Some("R_LARCH_TLS_LE_HI20_R"),
+        abi::R_LARCH_TLS_LE_ADD_R => Some("R_LARCH_TLS_LE_ADD_R"),
+        abi::R_LARCH_TLS_LE_LO12_R => Some("R_LARCH_TLS_LE_LO12_R"),
+        abi::R_LARCH_TLS_LD_PCREL20_S2 => Some("R_LARCH_TLS_LD_PCREL20_S2"),
+        abi::R_LARCH_TLS_GD_PCREL20_S2 => Some("R_LARCH_TLS_GD_PCREL20_S2"),
+        abi::R_LARCH_TLS_DESC_PCREL20_S2 => Some("R_LARCH_TLS_DESC_PCREL20_S2"),
+        _ => None,
+    }
+}
+
+fn r_avr_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_AVR_NONE => Some("R_AVR_NONE"),
+        abi::R_AVR_32 => Some("R_AVR_32"),
+        abi::R_AVR_7_PCREL => Some("R_AVR_7_PCREL"),
+        abi::R_AVR_13_PCREL => Some("R_AVR_13_PCREL"),
+        abi::R_AVR_16 => Some("R_AVR_16"),
+        abi::R_AVR_16_PM => Some("R_AVR_16_PM"),
+        abi::R_AVR_LO8_LDI => Some("R_AVR_LO8_LDI"),
+        abi::R_AVR_HI8_LDI => Some("R_AVR_HI8_LDI"),
+        abi::R_AVR_HH8_LDI => Some("R_AVR_HH8_LDI"),
+        abi::R_AVR_LO8_LDI_NEG => Some("R_AVR_LO8_LDI_NEG"),
+        abi::R_AVR_HI8_LDI_NEG => Some("R_AVR_HI8_LDI_NEG"),
+        abi::R_AVR_HH8_LDI_NEG => Some("R_AVR_HH8_LDI_NEG"),
+        abi::R_AVR_LO8_LDI_PM => Some("R_AVR_LO8_LDI_PM"),
+        abi::R_AVR_HI8_LDI_PM => Some("R_AVR_HI8_LDI_PM"),
+        abi::R_AVR_HH8_LDI_PM => Some("R_AVR_HH8_LDI_PM"),
+        abi::R_AVR_LO8_LDI_PM_NEG => Some("R_AVR_LO8_LDI_PM_NEG"),
+        abi::R_AVR_HI8_LDI_PM_NEG => Some("R_AVR_HI8_LDI_PM_NEG"),
+        abi::R_AVR_HH8_LDI_PM_NEG => Some("R_AVR_HH8_LDI_PM_NEG"),
+        abi::R_AVR_CALL => Some("R_AVR_CALL"),
+        abi::R_AVR_LDI => Some("R_AVR_LDI"),
+        abi::R_AVR_6 => Some("R_AVR_6"),
+        abi::R_AVR_6_ADIW => Some("R_AVR_6_ADIW"),
+        abi::R_AVR_MS8_LDI => Some("R_AVR_MS8_LDI"),
+        abi::R_AVR_MS8_LDI_NEG => Some("R_AVR_MS8_LDI_NEG"),
+        abi::R_AVR_LO8_LDI_GS => Some("R_AVR_LO8_LDI_GS"),
+        abi::R_AVR_HI8_LDI_GS => Some("R_AVR_HI8_LDI_GS"),
+        abi::R_AVR_8 => Some("R_AVR_8"),
+        abi::R_AVR_8_LO8 => Some("R_AVR_8_LO8"),
+        abi::R_AVR_8_HI8 => Some("R_AVR_8_HI8"),
+        abi::R_AVR_8_HLO8 => Some("R_AVR_8_HLO8"),
+        abi::R_AVR_DIFF8 => Some("R_AVR_DIFF8"),
+        abi::R_AVR_DIFF16 => Some("R_AVR_DIFF16"),
+        abi::R_AVR_DIFF32 => Some("R_AVR_DIFF32"),
+        abi::R_AVR_LDS_STS_16 => Some("R_AVR_LDS_STS_16"),
+        abi::R_AVR_PORT6 => Some("R_AVR_PORT6"),
+        abi::R_AVR_PORT5 => Some("R_AVR_PORT5"),
+        abi::R_AVR_32_PCREL => Some("R_AVR_32_PCREL"),
+        _ => None,
+    }
+}
+
+fn r_msp430_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_MSP430_NONE => Some("R_MSP430_NONE"),
+        abi::R_MSP430_32 => Some("R_MSP430_32"),
+        abi::R_MSP430_10_PCREL => Some("R_MSP430_10_PCREL"),
+        abi::R_MSP430_16 => Some("R_MSP430_16"),
+        abi::R_MSP430_16_PCREL => Some("R_MSP430_16_PCREL"),
+        abi::R_MSP430_16_BYTE => Some("R_MSP430_16_BYTE"),
+        abi::R_MSP430_16_PCREL_BYTE => Some("R_MSP430_16_PCREL_BYTE"),
+        abi::R_MSP430_2X_PCREL => Some("R_MSP430_2X_PCREL"),
+        abi::R_MSP430_RL_PCREL => Some("R_MSP430_RL_PCREL"),
+        abi::R_MSP430_8 => Some("R_MSP430_8"),
+        abi::R_MSP430_SYM_DIFF => Some("R_MSP430_SYM_DIFF"),
+        abi::R_MSP430_GNU_SET_ULEB128 => Some("R_MSP430_GNU_SET_ULEB128"),
+        abi::R_MSP430_GNU_SUB_ULEB128 => Some("R_MSP430_GNU_SUB_ULEB128"),
+        _ => None,
+    }
+}
+
+fn r_xtensa_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_XTENSA_NONE => Some("R_XTENSA_NONE"),
+        abi::R_XTENSA_32 => Some("R_XTENSA_32"),
+        abi::R_XTENSA_RTLD => Some("R_XTENSA_RTLD"),
+        abi::R_XTENSA_GLOB_DAT => Some("R_XTENSA_GLOB_DAT"),
+        abi::R_XTENSA_JMP_SLOT => Some("R_XTENSA_JMP_SLOT"),
+        abi::R_XTENSA_RELATIVE => Some("R_XTENSA_RELATIVE"),
+        abi::R_XTENSA_PLT => Some("R_XTENSA_PLT"),
+        abi::R_XTENSA_OP0 => Some("R_XTENSA_OP0"),
+        abi::R_XTENSA_OP1 => Some("R_XTENSA_OP1"),
+        abi::R_XTENSA_OP2 => Some("R_XTENSA_OP2"),
+        abi::R_XTENSA_ASM_EXPAND => Some("R_XTENSA_ASM_EXPAND"),
+        abi::R_XTENSA_ASM_SIMPLIFY => Some("R_XTENSA_ASM_SIMPLIFY"),
+        abi::R_XTENSA_32_PCREL => Some("R_XTENSA_32_PCREL"),
+        abi::R_XTENSA_GNU_VTINHERIT => Some("R_XTENSA_GNU_VTINHERIT"),
+        abi::R_XTENSA_GNU_VTENTRY => Some("R_XTENSA_GNU_VTENTRY"),
+        abi::R_XTENSA_DIFF8 => Some("R_XTENSA_DIFF8"),
+        abi::R_XTENSA_DIFF16 => Some("R_XTENSA_DIFF16"),
+        abi::R_XTENSA_DIFF32 => Some("R_XTENSA_DIFF32"),
+        abi::R_XTENSA_SLOT0_OP => Some("R_XTENSA_SLOT0_OP"),
+        abi::R_XTENSA_SLOT1_OP => Some("R_XTENSA_SLOT1_OP"),
+        abi::R_XTENSA_SLOT2_OP => Some("R_XTENSA_SLOT2_OP"),
+        abi::R_XTENSA_SLOT3_OP => Some("R_XTENSA_SLOT3_OP"),
+        abi::R_XTENSA_SLOT4_OP => Some("R_XTENSA_SLOT4_OP"),
+        abi::R_XTENSA_SLOT5_OP => Some("R_XTENSA_SLOT5_OP"),
+        abi::R_XTENSA_SLOT6_OP => Some("R_XTENSA_SLOT6_OP"),
+        abi::R_XTENSA_SLOT7_OP => Some("R_XTENSA_SLOT7_OP"),
+        abi::R_XTENSA_SLOT8_OP => Some("R_XTENSA_SLOT8_OP"),
+        abi::R_XTENSA_SLOT9_OP => Some("R_XTENSA_SLOT9_OP"),
+        abi::R_XTENSA_SLOT10_OP => Some("R_XTENSA_SLOT10_OP"),
+        abi::R_XTENSA_SLOT11_OP => Some("R_XTENSA_SLOT11_OP"),
+        abi::R_XTENSA_SLOT12_OP => Some("R_XTENSA_SLOT12_OP"),
+        abi::R_XTENSA_SLOT13_OP => Some("R_XTENSA_SLOT13_OP"),
+        abi::R_XTENSA_SLOT14_OP => Some("R_XTENSA_SLOT14_OP"),
+        abi::R_XTENSA_SLOT0_ALT => Some("R_XTENSA_SLOT0_ALT"),
+        abi::R_XTENSA_SLOT1_ALT => Some("R_XTENSA_SLOT1_ALT"),
+        abi::R_XTENSA_SLOT2_ALT => Some("R_XTENSA_SLOT2_ALT"),
+        abi::R_XTENSA_SLOT3_ALT => Some("R_XTENSA_SLOT3_ALT"),
+        abi::R_XTENSA_SLOT4_ALT => Some("R_XTENSA_SLOT4_ALT"),
+        abi::R_XTENSA_SLOT5_ALT => Some("R_XTENSA_SLOT5_ALT"),
+        abi::R_XTENSA_SLOT6_ALT => Some("R_XTENSA_SLOT6_ALT"),
+        abi::R_XTENSA_SLOT7_ALT => Some("R_XTENSA_SLOT7_ALT"),
+        abi::R_XTENSA_SLOT8_ALT => Some("R_XTENSA_SLOT8_ALT"),
+        abi::R_XTENSA_SLOT9_ALT => Some("R_XTENSA_SLOT9_ALT"),
+        abi::R_XTENSA_SLOT10_ALT => Some("R_XTENSA_SLOT10_ALT"),
+        abi::R_XTENSA_SLOT11_ALT => Some("R_XTENSA_SLOT11_ALT"),
+        abi::R_XTENSA_SLOT12_ALT => Some("R_XTENSA_SLOT12_ALT"),
+        abi::R_XTENSA_SLOT13_ALT => Some("R_XTENSA_SLOT13_ALT"),
+        abi::R_XTENSA_SLOT14_ALT => Some("R_XTENSA_SLOT14_ALT"),
+        abi::R_XTENSA_TLSDESC_FN => Some("R_XTENSA_TLSDESC_FN"),
+        abi::R_XTENSA_TLSDESC_ARG => Some("R_XTENSA_TLSDESC_ARG"),
+        abi::R_XTENSA_TLS_DTPOFF => Some("R_XTENSA_TLS_DTPOFF"),
+        abi::R_XTENSA_TLS_TPOFF => Some("R_XTENSA_TLS_TPOFF"),
+        abi::R_XTENSA_TLS_FUNC => Some("R_XTENSA_TLS_FUNC"),
+        abi::R_XTENSA_TLS_ARG => Some("R_XTENSA_TLS_ARG"),
+        abi::R_XTENSA_TLS_CALL => Some("R_XTENSA_TLS_CALL"),
+        abi::R_XTENSA_PDIFF8 => Some("R_XTENSA_PDIFF8"),
+        abi::R_XTENSA_PDIFF16 => Some("R_XTENSA_PDIFF16"),
+        abi::R_XTENSA_PDIFF32 => Some("R_XTENSA_PDIFF32"),
+        abi::R_XTENSA_NDIFF8 => Some("R_XTENSA_NDIFF8"),
+        abi::R_XTENSA_NDIFF16 => Some("R_XTENSA_NDIFF16"),
+        abi::R_XTENSA_NDIFF32 => Some("R_XTENSA_NDIFF32"),
+        _ => None,
+    }
+}
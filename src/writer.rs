@@ -0,0 +1,2935 @@
+//! Serializing ELF objects back to bytes
+//!
+//! [ElfFile] is an owned, mutable representation of an ELF object: its file header, its
+//! segments, and its sections along with their data. It can be created from a parsed
+//! [ElfBytes], modified, and then written back out with [ElfFile::write].
+//!
+//! The writer emits exactly what's described by the headers in the model: each segment's data
+//! and each section's data is written at its file offset, followed by the file header, the
+//! program header table at `e_phoff`, and the section header table at `e_shoff`. All of these
+//! are encoded with the endianness and class of the model's [FileHeader].
+//!
+//! Example for round-tripping an object through the writer:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//! use elf::writer::ElfFile;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+//! elf_file.set_entry(0x400420).expect("entry should be in .text");
+//!
+//! let mut out = std::io::Cursor::new(Vec::new());
+//! elf_file.write(&mut out).expect("file should be writable");
+//!
+//! let out = out.into_inner();
+//! let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+//! assert_eq!(written.ehdr.e_entry, 0x400420);
+//! ```
+use std::collections::HashMap;
+use std::io::{Cursor, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::abi;
+use crate::debug::debug_section_id;
+use crate::endian::EndianParse;
+use crate::file::{Class, FileHeader, ELF32_EHDR_TAILSIZE, ELF64_EHDR_TAILSIZE};
+use crate::note::{Aarch64Registers, AuxvEntry, NotePrStatus, NoteTimeval, X86_64Registers};
+use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::relocation::{Rel, RelIterator, Rela, RelaIterator};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::symbol::{Symbol, SymbolTable};
+use crate::ElfBytes;
+
+/// Trait for encoding an ELF structure with a given class (32/64 bit) layout and byte order.
+///
+/// This is the inverse of [ParseAt]: a structure that's encoded and then parsed with the same
+/// endianness and class comes back out unchanged.
+pub trait Encode {
+    /// Append this structure's bytes to `buf`.
+    ///
+    /// Returns a [ParseError::TryFromIntError] if a field's value doesn't fit in its
+    /// on-disk representation, e.g. an address above 4GiB in an ELF32 structure.
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError>;
+}
+
+pub(crate) fn encode_u16<E: EndianParse>(endian: E, buf: &mut Vec<u8>, val: u16) {
+    if endian.is_little() {
+        buf.extend_from_slice(&val.to_le_bytes());
+    } else {
+        buf.extend_from_slice(&val.to_be_bytes());
+    }
+}
+
+pub(crate) fn encode_u32<E: EndianParse>(endian: E, buf: &mut Vec<u8>, val: u32) {
+    if endian.is_little() {
+        buf.extend_from_slice(&val.to_le_bytes());
+    } else {
+        buf.extend_from_slice(&val.to_be_bytes());
+    }
+}
+
+pub(crate) fn encode_u64<E: EndianParse>(endian: E, buf: &mut Vec<u8>, val: u64) {
+    if endian.is_little() {
+        buf.extend_from_slice(&val.to_le_bytes());
+    } else {
+        buf.extend_from_slice(&val.to_be_bytes());
+    }
+}
+
+/// Encode a native word sized field, e.g. an Elf32_Addr or Elf64_Addr
+pub(crate) fn encode_word<E: EndianParse>(
+    endian: E,
+    class: Class,
+    buf: &mut Vec<u8>,
+    val: u64,
+) -> Result<(), ParseError> {
+    match class {
+        Class::ELF32 => encode_u32(endian, buf, u32::try_from(val)?),
+        Class::ELF64 => encode_u64(endian, buf, val),
+    }
+    Ok(())
+}
+
+impl<E2: EndianParse> Encode for FileHeader<E2> {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        let mut ident = [0u8; abi::EI_NIDENT];
+        ident[..abi::EI_CLASS].copy_from_slice(&abi::ELFMAGIC);
+        ident[abi::EI_CLASS] = match class {
+            Class::ELF32 => abi::ELFCLASS32,
+            Class::ELF64 => abi::ELFCLASS64,
+        };
+        ident[abi::EI_DATA] = if endian.is_little() {
+            abi::ELFDATA2LSB
+        } else {
+            abi::ELFDATA2MSB
+        };
+        ident[abi::EI_VERSION] = abi::EV_CURRENT;
+        ident[abi::EI_OSABI] = self.osabi;
+        ident[abi::EI_ABIVERSION] = self.abiversion;
+        ident[abi::EI_PAD..].copy_from_slice(&self.ident_pad);
+        buf.extend_from_slice(&ident);
+
+        encode_u16(endian, buf, self.e_type);
+        encode_u16(endian, buf, self.e_machine);
+        encode_u32(endian, buf, self.version);
+        encode_word(endian, class, buf, self.e_entry)?;
+        encode_word(endian, class, buf, self.e_phoff)?;
+        encode_word(endian, class, buf, self.e_shoff)?;
+        encode_u32(endian, buf, self.e_flags);
+        encode_u16(endian, buf, self.e_ehsize);
+        encode_u16(endian, buf, self.e_phentsize);
+        encode_u16(endian, buf, self.e_phnum);
+        encode_u16(endian, buf, self.e_shentsize);
+        encode_u16(endian, buf, self.e_shnum);
+        encode_u16(endian, buf, self.e_shstrndx);
+        Ok(())
+    }
+}
+
+impl Encode for ProgramHeader {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_u32(endian, buf, self.p_type);
+        if class == Class::ELF64 {
+            // Note: 64-bit fields are in a different order
+            encode_u32(endian, buf, self.p_flags);
+        }
+        encode_word(endian, class, buf, self.p_offset)?;
+        encode_word(endian, class, buf, self.p_vaddr)?;
+        encode_word(endian, class, buf, self.p_paddr)?;
+        encode_word(endian, class, buf, self.p_filesz)?;
+        encode_word(endian, class, buf, self.p_memsz)?;
+        if class == Class::ELF32 {
+            encode_u32(endian, buf, self.p_flags);
+        }
+        encode_word(endian, class, buf, self.p_align)
+    }
+}
+
+impl Encode for SectionHeader {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_u32(endian, buf, self.sh_name);
+        encode_u32(endian, buf, self.sh_type);
+        encode_word(endian, class, buf, self.sh_flags)?;
+        encode_word(endian, class, buf, self.sh_addr)?;
+        encode_word(endian, class, buf, self.sh_offset)?;
+        encode_word(endian, class, buf, self.sh_size)?;
+        encode_u32(endian, buf, self.sh_link);
+        encode_u32(endian, buf, self.sh_info);
+        encode_word(endian, class, buf, self.sh_addralign)?;
+        encode_word(endian, class, buf, self.sh_entsize)
+    }
+}
+
+/// Builds the contents of a string table section, such as `.shstrtab` or `.strtab`.
+///
+/// The table starts with the empty string at offset 0, and each distinct string is only
+/// stored once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringTableBuilder {
+    data: Vec<u8>,
+    offsets: HashMap<String, u32>,
+}
+
+impl Default for StringTableBuilder {
+    fn default() -> Self {
+        StringTableBuilder {
+            data: vec![0],
+            offsets: HashMap::new(),
+        }
+    }
+}
+
+impl StringTableBuilder {
+    /// Create a string table holding only the empty string.
+    pub fn new() -> Self {
+        StringTableBuilder::default()
+    }
+
+    /// Add a string to the table (if it isn't already there) and get its offset.
+    pub fn add(&mut self, name: &str) -> u32 {
+        if name.is_empty() {
+            return 0;
+        }
+        if let Some(offset) = self.offsets.get(name) {
+            return *offset;
+        }
+
+        let offset = self.data.len() as u32;
+        self.data.extend_from_slice(name.as_bytes());
+        self.data.push(0);
+        self.offsets.insert(name.to_string(), offset);
+        offset
+    }
+
+    /// The string table section's data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Take the string table section's data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Round `offset` up to the next multiple of `align`, where alignments of 0 and 1 both mean
+/// no alignment constraint.
+pub(crate) fn align_up(offset: u64, align: u64) -> Result<u64, ParseError> {
+    if align <= 1 {
+        return Ok(offset);
+    }
+    let rem = offset % align;
+    if rem == 0 {
+        return Ok(offset);
+    }
+    offset
+        .checked_add(align - rem)
+        .ok_or(ParseError::IntegerOverflow)
+}
+
+impl Encode for Symbol {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_u32(endian, buf, self.st_name);
+        if class == Class::ELF32 {
+            encode_word(endian, class, buf, self.st_value)?;
+            encode_word(endian, class, buf, self.st_size)?;
+            buf.push(self.st_info);
+            buf.push(self.st_other);
+            encode_u16(endian, buf, self.st_shndx);
+        } else {
+            buf.push(self.st_info);
+            buf.push(self.st_other);
+            encode_u16(endian, buf, self.st_shndx);
+            encode_word(endian, class, buf, self.st_value)?;
+            encode_word(endian, class, buf, self.st_size)?;
+        }
+        Ok(())
+    }
+}
+
+impl Encode for Rel {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_word(endian, class, buf, self.r_offset)?;
+        encode_r_info(endian, class, buf, self.r_sym, self.r_type)
+    }
+}
+
+impl Encode for Rela {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_word(endian, class, buf, self.r_offset)?;
+        encode_r_info(endian, class, buf, self.r_sym, self.r_type)?;
+        match class {
+            Class::ELF32 => encode_u32(endian, buf, i32::try_from(self.r_addend)? as u32),
+            Class::ELF64 => encode_u64(endian, buf, self.r_addend as u64),
+        }
+        Ok(())
+    }
+}
+
+fn encode_r_info<E: EndianParse>(
+    endian: E,
+    class: Class,
+    buf: &mut Vec<u8>,
+    r_sym: u32,
+    r_type: u32,
+) -> Result<(), ParseError> {
+    match class {
+        Class::ELF32 => {
+            if r_sym > 0xFFFFFF || r_type > 0xFF {
+                return Err(ParseError::IntegerOverflow);
+            }
+            encode_u32(endian, buf, (r_sym << 8) | r_type);
+        }
+        Class::ELF64 => encode_u64(endian, buf, ((r_sym as u64) << 32) | r_type as u64),
+    }
+    Ok(())
+}
+
+/// Append a note with the given name (including its NUL terminator), type, and contents, with
+/// the name and contents each padded to 4-byte alignment.
+pub(crate) fn encode_note<E: EndianParse>(
+    endian: E,
+    name: &[u8],
+    n_type: u32,
+    desc: &[u8],
+    buf: &mut Vec<u8>,
+) -> Result<(), ParseError> {
+    encode_u32(endian, buf, u32::try_from(name.len())?);
+    encode_u32(endian, buf, u32::try_from(desc.len())?);
+    encode_u32(endian, buf, n_type);
+    for field in [name, desc] {
+        buf.extend_from_slice(field);
+        buf.resize(align_up(buf.len() as u64, 4)? as usize, 0);
+    }
+    Ok(())
+}
+
+impl Encode for NoteTimeval {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                encode_u32(endian, buf, i32::try_from(self.sec)? as u32);
+                encode_u32(endian, buf, i32::try_from(self.usec)? as u32);
+            }
+            Class::ELF64 => {
+                encode_u64(endian, buf, self.sec as u64);
+                encode_u64(endian, buf, self.usec as u64);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Encode for NotePrStatus<'_> {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_u32(endian, buf, self.signo as u32);
+        encode_u32(endian, buf, self.code as u32);
+        encode_u32(endian, buf, self.errno as u32);
+        encode_u16(endian, buf, self.cursig);
+        // padding to align the following native longs
+        encode_u16(endian, buf, 0);
+        encode_word(endian, class, buf, self.sigpend)?;
+        encode_word(endian, class, buf, self.sighold)?;
+        for id in [self.pid, self.ppid, self.pgrp, self.sid] {
+            encode_u32(endian, buf, id as u32);
+        }
+        for time in [self.utime, self.stime, self.cutime, self.cstime] {
+            time.encode(endian, class, buf)?;
+        }
+        buf.extend_from_slice(self.pr_reg);
+        encode_u32(endian, buf, self.fpvalid as u32);
+        if class == Class::ELF64 {
+            // padding up to the struct's native long alignment
+            encode_u32(endian, buf, 0);
+        }
+        Ok(())
+    }
+}
+
+impl Encode for AuxvEntry {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        encode_word(endian, class, buf, self.a_type)?;
+        encode_word(endian, class, buf, self.a_val)
+    }
+}
+
+impl Encode for X86_64Registers {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        for reg in [
+            self.r15,
+            self.r14,
+            self.r13,
+            self.r12,
+            self.rbp,
+            self.rbx,
+            self.r11,
+            self.r10,
+            self.r9,
+            self.r8,
+            self.rax,
+            self.rcx,
+            self.rdx,
+            self.rsi,
+            self.rdi,
+            self.orig_rax,
+            self.rip,
+            self.cs,
+            self.eflags,
+            self.rsp,
+            self.ss,
+            self.fs_base,
+            self.gs_base,
+            self.ds,
+            self.es,
+            self.fs,
+            self.gs,
+        ] {
+            encode_u64(endian, buf, reg);
+        }
+        Ok(())
+    }
+}
+
+impl Encode for Aarch64Registers {
+    fn encode<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), ParseError> {
+        for reg in self
+            .regs
+            .iter()
+            .chain([self.sp, self.pc, self.pstate].iter())
+        {
+            encode_u64(endian, buf, *reg);
+        }
+        Ok(())
+    }
+}
+
+/// A segment in an [ElfFile]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// The segment's program header
+    pub phdr: ProgramHeader,
+    /// The segment's file data, which is written at `p_offset`.
+    ///
+    /// Section data is written over this, so it only needs to hold the bytes of the segment
+    /// which aren't part of any section (and can be empty).
+    pub data: Vec<u8>,
+}
+
+/// A section in an [ElfFile]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The section's name
+    pub name: String,
+    /// The section's header. The header's `sh_name` is what gets written out.
+    pub shdr: SectionHeader,
+    /// The section's file data as-is (including any compression header), which is written at
+    /// `sh_offset`. This is empty for SHT_NOBITS sections.
+    pub data: Vec<u8>,
+}
+
+impl Section {
+    /// Create a section with the given name, type, flags, and contents.
+    ///
+    /// The section has no alignment constraint, and the rest of its header is zeroed.
+    pub fn new(name: &str, sh_type: u32, sh_flags: u64, data: Vec<u8>) -> Self {
+        Section {
+            name: name.to_string(),
+            shdr: SectionHeader {
+                sh_name: 0,
+                sh_type,
+                sh_flags,
+                sh_addr: 0,
+                sh_offset: 0,
+                sh_size: data.len() as u64,
+                sh_link: 0,
+                sh_info: 0,
+                sh_addralign: 1,
+                sh_entsize: 0,
+            },
+            data,
+        }
+    }
+
+    /// Write the section's data as-is to a new file at `path`, like
+    /// `objcopy --dump-section`. [SHT_NOBITS](abi::SHT_NOBITS) sections produce an empty file.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), ParseError> {
+        std::fs::write(path, &self.data)?;
+        Ok(())
+    }
+}
+
+/// Options for [ElfFile::strip], which mirror the `strip` tool's.
+///
+/// ```
+/// use elf::writer::StripOptions;
+///
+/// // Like `strip --strip-all --keep-file-symbols`
+/// let mut options = StripOptions::strip_all();
+/// options.keep_file_symbols(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StripOptions {
+    debug: bool,
+    symbols: bool,
+    keep_file_symbols: bool,
+    keep_section_symbols: bool,
+}
+
+impl StripOptions {
+    /// Like `strip --strip-debug`: remove the debug sections (`.debug_*`, `.zdebug_*`, and
+    /// `.gdb_index`), their relocations, and any symbols defined in them.
+    pub fn strip_debug() -> Self {
+        StripOptions {
+            debug: true,
+            symbols: false,
+            keep_file_symbols: false,
+            keep_section_symbols: false,
+        }
+    }
+
+    /// Like `strip --strip-all`: remove the debug sections along with the `.symtab` symbol
+    /// table and its string table.
+    ///
+    /// Symbols which are still needed by relocations or section groups are kept (as in
+    /// relocatable objects), in which case the symbol table is kept with just those symbols.
+    pub fn strip_all() -> Self {
+        StripOptions {
+            debug: true,
+            symbols: true,
+            keep_file_symbols: false,
+            keep_section_symbols: false,
+        }
+    }
+
+    /// Keep [STT_FILE](abi::STT_FILE) symbols when stripping all symbols.
+    ///
+    /// Default: false
+    pub fn keep_file_symbols(&mut self, keep: bool) -> &mut Self {
+        self.keep_file_symbols = keep;
+        self
+    }
+
+    /// Keep [STT_SECTION](abi::STT_SECTION) symbols when stripping all symbols.
+    ///
+    /// Default: false
+    pub fn keep_section_symbols(&mut self, keep: bool) -> &mut Self {
+        self.keep_section_symbols = keep;
+        self
+    }
+}
+
+/// The name of the section holding the GNU build-id note
+const BUILD_ID_SECTION_NAME: &str = ".note.gnu.build-id";
+
+/// Returns true if the section name is one that `strip --strip-debug` removes
+fn is_debug_section_name(name: &str) -> bool {
+    debug_section_id(name).is_some() || name == ".gdb_index"
+}
+
+/// An owned ELF object which can be modified and written back out.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfFile<E: EndianParse> {
+    /// The file header. The table entry sizes and counts are derived from the
+    /// segments and sections when writing.
+    ///
+    /// Fields can be modified directly, or via setters like [ElfFile::set_entry] which check
+    /// that the new value makes sense for the object.
+    pub ehdr: FileHeader<E>,
+    /// The segments described by the program header table
+    pub segments: Vec<Segment>,
+    /// The sections described by the section header table, including the null section at index 0
+    pub sections: Vec<Section>,
+}
+
+impl<E: EndianParse> ElfFile<E> {
+    /// Copy the headers, segment data, and section data out of a parsed [ElfBytes].
+    pub fn from_elf_bytes(file: &ElfBytes<'_, E>) -> Result<Self, ParseError> {
+        let mut segments = Vec::new();
+        if let Some(phdrs) = file.segments() {
+            for phdr in phdrs {
+                segments.push(Segment {
+                    phdr,
+                    data: file.segment_data(&phdr)?.to_vec(),
+                });
+            }
+        }
+
+        let mut sections = Vec::new();
+        let (shdrs, strtab) = file.section_headers_with_strtab()?;
+        if let Some(shdrs) = shdrs {
+            for shdr in shdrs {
+                let name = match &strtab {
+                    Some(strtab) => strtab.get(shdr.sh_name as usize)?,
+                    None => "",
+                };
+                sections.push(Section {
+                    name: name.to_string(),
+                    shdr,
+                    data: file.section_raw_data(&shdr)?.to_vec(),
+                });
+            }
+        }
+
+        Ok(ElfFile {
+            ehdr: file.ehdr,
+            segments,
+            sections,
+        })
+    }
+
+    /// Set the entry point address (`e_entry`).
+    ///
+    /// Returns [ParseError::TryFromIntError] if the address doesn't fit in an ELF32 word. If
+    /// this object has [PT_LOAD](abi::PT_LOAD) segments, a non-zero entry point must also lie
+    /// in an executable one, otherwise this returns [ParseError::InvalidHeaderValue].
+    pub fn set_entry(&mut self, e_entry: u64) -> Result<(), ParseError> {
+        if self.ehdr.class == Class::ELF32 {
+            u32::try_from(e_entry)?;
+        }
+        let mut loads = self
+            .segments
+            .iter()
+            .map(|segment| &segment.phdr)
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .peekable();
+        if e_entry != 0
+            && loads.peek().is_some()
+            && !loads.any(|phdr| {
+                phdr.p_flags & abi::PF_X != 0
+                    && e_entry >= phdr.p_vaddr
+                    && e_entry - phdr.p_vaddr < phdr.p_memsz
+            })
+        {
+            return Err(ParseError::InvalidHeaderValue(("e_entry", e_entry)));
+        }
+        self.ehdr.e_entry = e_entry;
+        Ok(())
+    }
+
+    /// Set the object file type (`e_type`), e.g. [ET_DYN](abi::ET_DYN).
+    ///
+    /// Returns [ParseError::InvalidHeaderValue] for values which are neither one of the
+    /// `ET_*` constants nor in the OS or processor-specific ranges.
+    pub fn set_type(&mut self, e_type: u16) -> Result<(), ParseError> {
+        if e_type > abi::ET_CORE && e_type < abi::ET_LOOS {
+            return Err(ParseError::InvalidHeaderValue(("e_type", e_type as u64)));
+        }
+        self.ehdr.e_type = e_type;
+        Ok(())
+    }
+
+    /// Set the processor-specific flags (`e_flags`).
+    ///
+    /// The meaning of the flags depends on the machine. Returns
+    /// [ParseError::InvalidHeaderValue] for non-zero flags on machines whose psABI doesn't
+    /// define any (x86 and x86-64).
+    pub fn set_flags(&mut self, e_flags: u32) -> Result<(), ParseError> {
+        let has_flags = !matches!(
+            self.ehdr.e_machine,
+            abi::EM_386 | abi::EM_IAMCU | abi::EM_X86_64
+        );
+        if e_flags != 0 && !has_flags {
+            return Err(ParseError::InvalidHeaderValue(("e_flags", e_flags as u64)));
+        }
+        self.ehdr.e_flags = e_flags;
+        Ok(())
+    }
+
+    /// Set the OS/ABI identification (`e_ident[EI_OSABI]`) and its ABI version
+    /// (`e_ident[EI_ABIVERSION]`).
+    ///
+    /// Returns [ParseError::InvalidHeaderValue] for OS/ABI values in the unassigned range
+    /// between [ELFOSABI_OPENVOS](abi::ELFOSABI_OPENVOS) and the architecture-specific values,
+    /// and for the ARM-specific values on other machines.
+    pub fn set_osabi(&mut self, osabi: u8, abiversion: u8) -> Result<(), ParseError> {
+        let valid = match osabi {
+            abi::ELFOSABI_NONE..=abi::ELFOSABI_OPENVOS => true,
+            abi::ELFOSABI_ARM_AEABI | abi::ELFOSABI_ARM => self.ehdr.e_machine == abi::EM_ARM,
+            osabi => osabi > abi::ELFOSABI_ARM_AEABI,
+        };
+        if !valid {
+            return Err(ParseError::InvalidHeaderValue(("EI_OSABI", osabi as u64)));
+        }
+        self.ehdr.osabi = osabi;
+        self.ehdr.abiversion = abiversion;
+        Ok(())
+    }
+
+    /// Get the first section with the given name (if any).
+    pub fn section_by_name(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Get the first section with the given name (if any) for modification.
+    pub fn section_by_name_mut(&mut self, name: &str) -> Option<&mut Section> {
+        self.sections
+            .iter_mut()
+            .find(|section| section.name == name)
+    }
+
+    /// Write the data of the first section with the given name as-is to `w`, including any
+    /// compression header.
+    ///
+    /// Returns Ok(false) if there's no section with that name.
+    pub fn extract_section<W: Write>(&self, name: &str, mut w: W) -> Result<bool, ParseError> {
+        let section = match self.section_by_name(name) {
+            Some(section) => section,
+            None => return Ok(false),
+        };
+        w.write_all(&section.data)?;
+        w.flush()?;
+        Ok(true)
+    }
+
+    /// Write the data of the first section with the given name to `w`, decompressing it if
+    /// it's compressed. This handles both [SHF_COMPRESSED](abi::SHF_COMPRESSED) sections and
+    /// GNU-style `.zdebug_*` sections.
+    ///
+    /// Returns Ok(false) if there's no section with that name, or
+    /// [ParseError::UnsupportedCompression] for compression types whose cargo feature (`zlib`
+    /// or `zstd`) isn't enabled.
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn extract_section_decompressed<W: Write>(
+        &self,
+        name: &str,
+        mut w: W,
+    ) -> Result<bool, ParseError> {
+        let section = match self.section_by_name(name) {
+            Some(section) => section,
+            None => return Ok(false),
+        };
+        let debug_section = crate::debug::DebugSection::parse(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            &section.name,
+            section.shdr,
+            &section.data,
+        )?;
+        w.write_all(&debug_section.decompressed()?)?;
+        w.flush()?;
+        Ok(true)
+    }
+
+    /// Get the section header table index of the first section with the given name (if any).
+    pub fn section_index(&self, name: &str) -> Option<usize> {
+        self.sections
+            .iter()
+            .position(|section| section.name == name)
+    }
+
+    /// The index of the section name string table (if any), taking
+    /// [SHN_XINDEX](abi::SHN_XINDEX) into account.
+    fn shstrndx(&self) -> Option<usize> {
+        let index = match self.ehdr.e_shstrndx {
+            abi::SHN_UNDEF => return None,
+            abi::SHN_XINDEX => self.sections.first()?.shdr.sh_link as usize,
+            index => index as usize,
+        };
+        (index < self.sections.len()).then_some(index)
+    }
+
+    /// Append a section and get its section header table index.
+    ///
+    /// The section's name, offset, and size are filled in by [ElfFile::update_layout]. Its
+    /// address and the rest of its header are kept as given.
+    pub fn add_section(&mut self, section: Section) -> Result<usize, ParseError> {
+        self.sections.push(section);
+        self.update_layout()?;
+        Ok(self.sections.len() - 1)
+    }
+
+    /// Append a section along with a new [PT_LOAD](abi::PT_LOAD) segment which loads it, and
+    /// get its section header table index. This is the equivalent of `objcopy --add-section`
+    /// followed by `--set-section-flags name=alloc,load`.
+    ///
+    /// The section gets the [SHF_ALLOC](abi::SHF_ALLOC) flag and is placed at the first
+    /// suitably aligned address after all of the existing loadable segments. The segment's
+    /// permissions follow the section's [SHF_WRITE](abi::SHF_WRITE) and
+    /// [SHF_EXECINSTR](abi::SHF_EXECINSTR) flags.
+    ///
+    /// If there's no room to grow the program header table in place, it is moved to the start
+    /// of the new segment and the [PT_PHDR](abi::PT_PHDR) segment (if any) is updated to match.
+    pub fn add_loaded_section(&mut self, section: Section) -> Result<usize, ParseError> {
+        let (index, _) = self.insert_loaded_section(section)?;
+        Ok(index)
+    }
+
+    /// Internal helper for [ElfFile::add_loaded_section] which also gets the index of the new
+    /// PT_LOAD segment in the program header table.
+    fn insert_loaded_section(
+        &mut self,
+        mut section: Section,
+    ) -> Result<(usize, usize), ParseError> {
+        let class = self.ehdr.class;
+        let entsize = ProgramHeader::size_for(class) as u64;
+        let table_size = entsize * (self.segments.len() as u64 + 1);
+        let word_align = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        let mut load_end = 0;
+        let mut page_align = 0x1000;
+        for segment in self.segments.iter() {
+            let phdr = &segment.phdr;
+            if phdr.p_type == abi::PT_LOAD {
+                load_end = load_end.max(phdr.p_vaddr.saturating_add(phdr.p_memsz));
+                page_align = page_align.max(phdr.p_align);
+            }
+        }
+        let align = page_align.max(section.shdr.sh_addralign);
+
+        // The new segment goes where update_layout() would start placing unloaded sections
+        let mut offset = self.file_header().e_ehsize as u64;
+        if !self.segments.is_empty() {
+            offset = offset.max(self.ehdr.e_phoff + entsize * self.segments.len() as u64);
+        }
+        for segment in self.segments.iter() {
+            offset = offset.max(segment.phdr.p_offset + segment.phdr.p_filesz);
+        }
+        for section in self.sections.iter() {
+            if self.is_loaded(&section.shdr) && section.shdr.sh_type != abi::SHT_NOBITS {
+                offset = offset.max(section.shdr.sh_offset + section.shdr.sh_size);
+            }
+        }
+
+        let move_table = !self.phdr_table_can_grow(table_size);
+        let seg_offset = if move_table {
+            align_up(offset, word_align)?
+        } else {
+            align_up(offset, section.shdr.sh_addralign)?
+        };
+        let seg_vaddr = align_up(load_end, align)?
+            .checked_add(seg_offset % align)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let data_offset = if move_table {
+            align_up(seg_offset + table_size, section.shdr.sh_addralign)?
+        } else {
+            seg_offset
+        };
+        let size = section.data.len() as u64;
+
+        section.shdr.sh_flags |= abi::SHF_ALLOC as u64;
+        section.shdr.sh_offset = data_offset;
+        section.shdr.sh_addr = seg_vaddr + (data_offset - seg_offset);
+        section.shdr.sh_size = size;
+
+        let mut p_flags = abi::PF_R;
+        if section.shdr.sh_flags & abi::SHF_WRITE as u64 != 0 {
+            p_flags |= abi::PF_W;
+        }
+        if section.shdr.sh_flags & abi::SHF_EXECINSTR as u64 != 0 {
+            p_flags |= abi::PF_X;
+        }
+        let filesz = data_offset - seg_offset + size;
+        let segment = Segment {
+            phdr: ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_offset: seg_offset,
+                p_vaddr: seg_vaddr,
+                p_paddr: seg_vaddr,
+                p_filesz: filesz,
+                p_memsz: filesz,
+                p_flags,
+                p_align: align,
+            },
+            data: Vec::new(),
+        };
+
+        // Keep the PT_LOAD segments sorted by address
+        let position = self
+            .segments
+            .iter()
+            .rposition(|segment| segment.phdr.p_type == abi::PT_LOAD)
+            .map_or(self.segments.len(), |index| index + 1);
+        self.segments.insert(position, segment);
+
+        if move_table {
+            self.ehdr.e_phoff = seg_offset;
+        }
+        for segment in self.segments.iter_mut() {
+            let phdr = &mut segment.phdr;
+            if phdr.p_type != abi::PT_PHDR {
+                continue;
+            }
+            if move_table {
+                phdr.p_offset = seg_offset;
+                phdr.p_vaddr = seg_vaddr;
+                phdr.p_paddr = seg_vaddr;
+            }
+            phdr.p_filesz = table_size;
+            phdr.p_memsz = table_size;
+        }
+
+        self.sections.push(section);
+        self.update_layout()?;
+        Ok((self.sections.len() - 1, position))
+    }
+
+    /// Stamp this object with the given GNU build-id, as `ld --build-id=0x<hex>` would.
+    ///
+    /// An existing `.note.gnu.build-id` section is overwritten in place. Otherwise a new
+    /// one is added: in objects with segments it gets its own [PT_LOAD](abi::PT_LOAD) segment
+    /// (see [ElfFile::add_loaded_section]) along with a [PT_NOTE](abi::PT_NOTE) segment which
+    /// covers it, so that it can be found at runtime and in core dumps.
+    ///
+    /// Returns the `.note.gnu.build-id` section's index. Returns
+    /// [ParseError::InvalidHeaderValue] if the object's existing build-id is loaded and has a
+    /// different size, since it can't be resized in place.
+    pub fn set_build_id(&mut self, build_id: &[u8]) -> Result<usize, ParseError> {
+        let mut data = Vec::new();
+        encode_note(
+            self.ehdr.endianness,
+            abi::ELF_NOTE_GNU,
+            abi::NT_GNU_BUILD_ID as u32,
+            build_id,
+            &mut data,
+        )?;
+
+        if let Some(index) = self.section_index(BUILD_ID_SECTION_NAME) {
+            let section = &mut self.sections[index];
+            if section.data.len() == data.len() {
+                section.data = data;
+                return Ok(index);
+            }
+            if self.is_loaded(&self.sections[index].shdr) {
+                return Err(ParseError::InvalidHeaderValue((
+                    "build-id size",
+                    build_id.len() as u64,
+                )));
+            }
+            self.sections[index].data = data;
+            self.update_layout()?;
+            return Ok(index);
+        }
+
+        let mut section = Section::new(
+            BUILD_ID_SECTION_NAME,
+            abi::SHT_NOTE,
+            abi::SHF_ALLOC as u64,
+            data,
+        );
+        section.shdr.sh_addralign = 4;
+        if self.segments.is_empty() {
+            return self.add_section(section);
+        }
+
+        // Reserve the PT_NOTE's program header before the table gets laid out
+        let note_index = self.segments.len();
+        self.segments.push(Segment {
+            phdr: ProgramHeader {
+                p_type: abi::PT_NOTE,
+                p_offset: 0,
+                p_vaddr: 0,
+                p_paddr: 0,
+                p_filesz: 0,
+                p_memsz: 0,
+                p_flags: abi::PF_R,
+                p_align: 4,
+            },
+            data: Vec::new(),
+        });
+        let (index, load_index) = self.insert_loaded_section(section)?;
+        // The new PT_LOAD goes after the last existing PT_LOAD, which may be before the PT_NOTE
+        let note_index = if load_index <= note_index {
+            note_index + 1
+        } else {
+            note_index
+        };
+        let shdr = self.sections[index].shdr;
+        let phdr = &mut self.segments[note_index].phdr;
+        phdr.p_offset = shdr.sh_offset;
+        phdr.p_vaddr = shdr.sh_addr;
+        phdr.p_paddr = shdr.sh_addr;
+        phdr.p_filesz = shdr.sh_size;
+        phdr.p_memsz = shdr.sh_size;
+        Ok(index)
+    }
+
+    /// Stamp this object with a GNU build-id computed from its contents, as
+    /// `ld --build-id=sha1` would, and get the build-id.
+    ///
+    /// The build-id is the SHA-1 hash of the written object with the build-id itself zeroed.
+    /// See [ElfFile::set_build_id] for how the note is added.
+    pub fn compute_build_id(&mut self) -> Result<[u8; 20], ParseError> {
+        self.set_build_id(&[0; 20])?;
+        let build_id = sha1(&self.to_bytes()?);
+        self.set_build_id(&build_id)?;
+        Ok(build_id)
+    }
+
+    /// Returns true if the program header table can grow to `table_size` bytes in place,
+    /// without overlapping any section or segment data or leaving its PT_LOAD segment.
+    fn phdr_table_can_grow(&self, table_size: u64) -> bool {
+        let start = self.ehdr.e_phoff;
+        let end = start.saturating_add(table_size);
+        if self.segments.is_empty() || start < self.file_header().e_ehsize as u64 {
+            return false;
+        }
+
+        let overlaps_section = self.sections.iter().any(|section| {
+            let shdr = &section.shdr;
+            shdr.sh_type != abi::SHT_NOBITS
+                && shdr.sh_size > 0
+                && shdr.sh_offset < end
+                && shdr.sh_offset.saturating_add(shdr.sh_size) > start
+        });
+        // Segments which start after the table, rather than containing it
+        let overlaps_segment = self.segments.iter().any(|segment| {
+            let phdr = &segment.phdr;
+            phdr.p_filesz > 0 && phdr.p_offset > start && phdr.p_offset < end
+        });
+        let leaves_load = self.segments.iter().any(|segment| {
+            let phdr = &segment.phdr;
+            let load_end = phdr.p_offset.saturating_add(phdr.p_filesz);
+            phdr.p_type == abi::PT_LOAD
+                && phdr.p_offset <= start
+                && start < load_end
+                && end > load_end
+        });
+        !overlaps_section && !overlaps_segment && !leaves_load
+    }
+
+    /// Rename the section at the given index, regenerating the section name string table.
+    ///
+    /// Returns [ParseError::BadOffset] if there's no section at that index.
+    pub fn rename_section(&mut self, index: usize, name: &str) -> Result<(), ParseError> {
+        let section = self
+            .sections
+            .get_mut(index)
+            .ok_or(ParseError::BadOffset(index as u64))?;
+        section.name = name.to_string();
+        self.update_layout()
+    }
+
+    /// Remove the section at the given index and get it back.
+    ///
+    /// All of the section indexes which refer to sections after it are shifted down to match:
+    /// `e_shstrndx`, the `sh_link` of every section, the `sh_info` of relocation sections
+    /// (and others with [SHF_INFO_LINK](abi::SHF_INFO_LINK)), the members of section groups,
+    /// and the `st_shndx` of symbols in [SHT_SYMTAB](abi::SHT_SYMTAB) and
+    /// [SHT_DYNSYM](abi::SHT_DYNSYM) sections (and their
+    /// [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX) extended indexes). References to the
+    /// removed section itself become [SHN_UNDEF](abi::SHN_UNDEF), and it's dropped from any
+    /// groups it was a member of.
+    ///
+    /// Returns [ParseError::BadOffset] if there's no section at that index, or if it's the null
+    /// section at index 0.
+    pub fn remove_section(&mut self, index: usize) -> Result<Section, ParseError> {
+        if index == 0 || index >= self.sections.len() {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        let shstrndx = self.shstrndx();
+        let removed = self.sections.remove(index);
+        let endian = self.ehdr.endianness;
+        let class = self.ehdr.class;
+
+        let fix_index = |old: u32| -> u32 {
+            match (old as usize).cmp(&index) {
+                core::cmp::Ordering::Less => old,
+                core::cmp::Ordering::Equal => 0,
+                core::cmp::Ordering::Greater => old - 1,
+            }
+        };
+
+        for section in self.sections.iter_mut().skip(1) {
+            let shdr = &mut section.shdr;
+            shdr.sh_link = fix_index(shdr.sh_link);
+            if matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA)
+                || shdr.sh_flags & abi::SHF_INFO_LINK as u64 != 0
+            {
+                shdr.sh_info = fix_index(shdr.sh_info);
+            }
+
+            match shdr.sh_type {
+                abi::SHT_GROUP => {
+                    let mut words = ParsingTable::<'_, E, u32>::new(endian, class, &section.data)
+                        .iter()
+                        .collect::<Vec<u32>>();
+                    if words.is_empty() {
+                        continue;
+                    }
+                    // The first word is the group's flags, and the rest are its members
+                    let flags = words.remove(0);
+                    let mut data = Vec::with_capacity(section.data.len());
+                    encode_u32(endian, &mut data, flags);
+                    for member in words {
+                        if member as usize != index {
+                            encode_u32(endian, &mut data, fix_index(member));
+                        }
+                    }
+                    shdr.sh_size = data.len() as u64;
+                    section.data = data;
+                }
+                abi::SHT_SYMTAB | abi::SHT_DYNSYM => {
+                    let symtab = SymbolTable::new(endian, class, &section.data);
+                    let mut data = Vec::with_capacity(section.data.len());
+                    for mut sym in symtab.iter() {
+                        if sym.st_shndx < abi::SHN_LORESERVE {
+                            sym.st_shndx = fix_index(sym.st_shndx as u32) as u16;
+                        }
+                        sym.encode(endian, class, &mut data)?;
+                    }
+                    section.data = data;
+                }
+                abi::SHT_SYMTAB_SHNDX => {
+                    let shndxs = ParsingTable::<'_, E, u32>::new(endian, class, &section.data);
+                    let mut data = Vec::with_capacity(section.data.len());
+                    for shndx in shndxs.iter() {
+                        encode_u32(endian, &mut data, fix_index(shndx));
+                    }
+                    section.data = data;
+                }
+                _ => {}
+            }
+        }
+
+        match shstrndx {
+            Some(shstrndx) if shstrndx == index => self.ehdr.e_shstrndx = abi::SHN_UNDEF,
+            Some(shstrndx) => self.set_shstrndx(fix_index(shstrndx as u32) as usize),
+            None => {}
+        }
+
+        self.update_layout()?;
+        Ok(removed)
+    }
+
+    /// Overwrite the loaded bytes at virtual address `addr` with `bytes`.
+    ///
+    /// The address range is translated to a file offset through the [PT_LOAD](abi::PT_LOAD)
+    /// segment which maps it, and the data of every section and segment which holds those file
+    /// bytes is patched so that the change is written out.
+    ///
+    /// Returns [ParseError::BadOffset] with `addr` if the range isn't mapped by a single PT_LOAD
+    /// segment, or with the first address it has in a segment's zero-filled memory (e.g. an
+    /// [SHT_NOBITS](abi::SHT_NOBITS) `.bss` section) if any of it lies there rather than in the
+    /// file.
+    pub fn write_bytes_at_vaddr(&mut self, addr: u64, bytes: &[u8]) -> Result<(), ParseError> {
+        let end = addr
+            .checked_add(bytes.len() as u64)
+            .ok_or(ParseError::IntegerOverflow)?;
+
+        let segment = self
+            .segments
+            .iter_mut()
+            .find(|segment| {
+                let phdr = &segment.phdr;
+                phdr.p_type == abi::PT_LOAD
+                    && addr >= phdr.p_vaddr
+                    && end <= phdr.p_vaddr.saturating_add(phdr.p_memsz)
+            })
+            .ok_or(ParseError::BadOffset(addr))?;
+        let file_end = segment.phdr.p_vaddr.saturating_add(segment.phdr.p_filesz);
+        if end > file_end {
+            return Err(ParseError::BadOffset(addr.max(file_end)));
+        }
+
+        // Make sure the segment holds the patched bytes even if no section does
+        let offset = segment.phdr.p_offset + (addr - segment.phdr.p_vaddr);
+        let data_end = (end - segment.phdr.p_vaddr) as usize;
+        if segment.data.len() < data_end {
+            segment.data.resize(data_end, 0);
+        }
+
+        for segment in self.segments.iter_mut() {
+            patch_at(&mut segment.data, segment.phdr.p_offset, offset, bytes);
+        }
+        for section in self.sections.iter_mut() {
+            if section.shdr.sh_type != abi::SHT_NOBITS {
+                patch_at(&mut section.data, section.shdr.sh_offset, offset, bytes);
+            }
+        }
+        Ok(())
+    }
+
+    /// Strip debug sections and/or symbols from this object, see [StripOptions].
+    ///
+    /// Symbols are only ever removed from the [SHT_SYMTAB](abi::SHT_SYMTAB) symbol table, never
+    /// from the dynamic symbol table. When symbols are removed, the relocations and section
+    /// groups which refer to the remaining symbols are updated to match their new indexes.
+    pub fn strip(&mut self, options: &StripOptions) -> Result<(), ParseError> {
+        let mut remove = vec![false; self.sections.len()];
+        if options.debug {
+            for (section, remove) in self.sections.iter().zip(remove.iter_mut()) {
+                *remove = is_debug_section_name(&section.name);
+            }
+        }
+
+        // Relocations for removed sections go with them
+        for index in 0..self.sections.len() {
+            let shdr = &self.sections[index].shdr;
+            if matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA)
+                && shdr.sh_flags & abi::SHF_ALLOC as u64 == 0
+                && remove.get(shdr.sh_info as usize) == Some(&true)
+            {
+                remove[index] = true;
+            }
+        }
+
+        for index in 0..self.sections.len() {
+            if self.sections[index].shdr.sh_type == abi::SHT_SYMTAB && !remove[index] {
+                self.strip_symbols(index, &mut remove, options)?;
+            }
+        }
+
+        // Remove from the back so that the indexes of the rest don't shift
+        for index in (1..self.sections.len()).rev() {
+            if remove[index] {
+                self.remove_section(index)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Filter the symbols in the symbol table at `symtab_index`, marking it (and its string
+    /// table) for removal if no symbols are left.
+    fn strip_symbols(
+        &mut self,
+        symtab_index: usize,
+        remove: &mut [bool],
+        options: &StripOptions,
+    ) -> Result<(), ParseError> {
+        let endian = self.ehdr.endianness;
+        let class = self.ehdr.class;
+
+        let shndx_index = self.sections.iter().position(|section| {
+            section.shdr.sh_type == abi::SHT_SYMTAB_SHNDX
+                && section.shdr.sh_link as usize == symtab_index
+        });
+        let shndxs: Vec<u32> = match shndx_index {
+            Some(shndx_index) => {
+                ParsingTable::<'_, E, u32>::new(endian, class, &self.sections[shndx_index].data)
+                    .iter()
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        let symtab = &self.sections[symtab_index];
+        let symbols: Vec<Symbol> = SymbolTable::new(endian, class, &symtab.data)
+            .iter()
+            .collect();
+        let mut keep: Vec<bool> = symbols
+            .iter()
+            .enumerate()
+            .map(|(sym_idx, sym)| {
+                let shndx = match sym.st_shndx {
+                    abi::SHN_XINDEX => shndxs.get(sym_idx).copied().unwrap_or(0) as usize,
+                    shndx if shndx >= abi::SHN_LORESERVE => 0,
+                    shndx => shndx as usize,
+                };
+                if sym_idx == 0 {
+                    true
+                } else if shndx != 0 && remove.get(shndx) == Some(&true) {
+                    false
+                } else if options.symbols {
+                    (sym.st_symtype() == abi::STT_FILE && options.keep_file_symbols)
+                        || (sym.st_symtype() == abi::STT_SECTION && options.keep_section_symbols)
+                } else {
+                    true
+                }
+            })
+            .collect();
+
+        // Symbols used by the remaining relocations and groups have to stay
+        let mut referenced = false;
+        for (index, section) in self.sections.iter().enumerate() {
+            if remove[index] || section.shdr.sh_link as usize != symtab_index {
+                continue;
+            }
+            let mut mark = |sym_idx: u32| {
+                if let Some(keep) = keep.get_mut(sym_idx as usize) {
+                    *keep = true;
+                }
+            };
+            match section.shdr.sh_type {
+                abi::SHT_REL => {
+                    referenced = true;
+                    RelIterator::new(endian, class, &section.data).for_each(|rel| mark(rel.r_sym));
+                }
+                abi::SHT_RELA => {
+                    referenced = true;
+                    RelaIterator::new(endian, class, &section.data)
+                        .for_each(|rela| mark(rela.r_sym));
+                }
+                abi::SHT_GROUP => {
+                    referenced = true;
+                    mark(section.shdr.sh_info);
+                }
+                _ => {}
+            }
+        }
+
+        if !referenced && keep.iter().filter(|keep| **keep).count() <= 1 {
+            remove[symtab_index] = true;
+            if let Some(shndx_index) = shndx_index {
+                remove[shndx_index] = true;
+            }
+            let strtab_index = symtab.shdr.sh_link as usize;
+            let strtab_used = self.sections.iter().enumerate().any(|(index, section)| {
+                !remove[index] && section.shdr.sh_link as usize == strtab_index
+            });
+            if strtab_index != 0 && !strtab_used && self.shstrndx() != Some(strtab_index) {
+                if let Some(remove) = remove.get_mut(strtab_index) {
+                    *remove = true;
+                }
+            }
+            return Ok(());
+        }
+
+        // Map the old symbol indexes to the new ones
+        let mut new_indexes = Vec::with_capacity(symbols.len() + 1);
+        let mut next = 0u32;
+        for keep in keep.iter() {
+            new_indexes.push(next);
+            if *keep {
+                next += 1;
+            }
+        }
+        // ...including the one past the end, for an sh_info with no global symbols
+        new_indexes.push(next);
+        let new_index = |sym_idx: u32| new_indexes.get(sym_idx as usize).copied().unwrap_or(0);
+
+        let mut data = Vec::with_capacity(symtab.data.len());
+        let mut shndx_data = Vec::new();
+        for (sym_idx, sym) in symbols.iter().enumerate() {
+            if keep[sym_idx] {
+                sym.encode(endian, class, &mut data)?;
+                if let Some(shndx) = shndxs.get(sym_idx) {
+                    encode_u32(endian, &mut shndx_data, *shndx);
+                }
+            }
+        }
+        // sh_info is one greater than the index of the last local symbol
+        let sh_info = new_index(symtab.shdr.sh_info);
+
+        let symtab = &mut self.sections[symtab_index];
+        symtab.data = data;
+        symtab.shdr.sh_info = sh_info;
+        if let Some(shndx_index) = shndx_index {
+            self.sections[shndx_index].data = shndx_data;
+        }
+
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            if remove[index] || section.shdr.sh_link as usize != symtab_index {
+                continue;
+            }
+            match section.shdr.sh_type {
+                abi::SHT_REL => {
+                    let mut data = Vec::with_capacity(section.data.len());
+                    for mut rel in RelIterator::new(endian, class, &section.data) {
+                        rel.r_sym = new_index(rel.r_sym);
+                        rel.encode(endian, class, &mut data)?;
+                    }
+                    section.data = data;
+                }
+                abi::SHT_RELA => {
+                    let mut data = Vec::with_capacity(section.data.len());
+                    for mut rela in RelaIterator::new(endian, class, &section.data) {
+                        rela.r_sym = new_index(rela.r_sym);
+                        rela.encode(endian, class, &mut data)?;
+                    }
+                    section.data = data;
+                }
+                abi::SHT_GROUP => section.shdr.sh_info = new_index(section.shdr.sh_info),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Point `e_shstrndx` at the given section, using the null section's `sh_link` if the
+    /// index doesn't fit in the file header.
+    fn set_shstrndx(&mut self, index: usize) {
+        if index >= abi::SHN_LORESERVE as usize {
+            self.ehdr.e_shstrndx = abi::SHN_XINDEX;
+            self.sections[0].shdr.sh_link = index as u32;
+        } else {
+            self.ehdr.e_shstrndx = index as u16;
+            if let Some(null) = self.sections.first_mut() {
+                null.shdr.sh_link = 0;
+            }
+        }
+    }
+
+    /// Recompute the parts of the headers which are derived from the sections: the section
+    /// name string table, the sizes and file offsets of the sections, `e_shoff`, and
+    /// `e_shstrndx`. Section and segment counts too large for the file header are stored in the
+    /// null section, which is an error if there are no sections.
+    ///
+    /// Sections which are loaded by a [PT_LOAD](abi::PT_LOAD) segment keep their location,
+    /// since moving them would break the segment. All other sections are packed, in order,
+    /// after the end of the headers, segments, and loaded sections, followed by the section
+    /// header table. This is called by the section mutation methods, and should also be called
+    /// after changing a section's name or the size of its data by hand.
+    pub fn update_layout(&mut self) -> Result<(), ParseError> {
+        let class = self.ehdr.class;
+        let word_align = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        let phnum = self.segments.len();
+        if self.sections.is_empty() {
+            // Segment counts that don't fit in the file header need a null section to hold them
+            if phnum >= abi::PN_XNUM as usize {
+                return Err(ParseError::InvalidHeaderValue(("e_phnum", phnum as u64)));
+            }
+            self.ehdr.e_shoff = 0;
+            self.ehdr.e_shstrndx = abi::SHN_UNDEF;
+            return Ok(());
+        }
+
+        if let Some(shstrndx) = self.shstrndx() {
+            let mut names = StringTableBuilder::new();
+            for section in self.sections.iter_mut() {
+                section.shdr.sh_name = names.add(&section.name);
+            }
+            self.sections[shstrndx].data = names.into_data();
+            self.set_shstrndx(shstrndx);
+        }
+
+        for section in self.sections.iter_mut().skip(1) {
+            if section.shdr.sh_type != abi::SHT_NOBITS {
+                section.shdr.sh_size = section.data.len() as u64;
+            }
+        }
+        let shnum = self.sections.len();
+        self.sections[0].shdr.sh_size = if shnum >= abi::SHN_LORESERVE as usize {
+            shnum as u64
+        } else {
+            0
+        };
+        self.sections[0].shdr.sh_info = if phnum >= abi::PN_XNUM as usize {
+            phnum.try_into()?
+        } else {
+            0
+        };
+
+        let mut offset = self.file_header().e_ehsize as u64;
+        if !self.segments.is_empty() {
+            let table_size = ProgramHeader::size_for(class) * self.segments.len();
+            let table_end = self
+                .ehdr
+                .e_phoff
+                .checked_add(table_size as u64)
+                .ok_or(ParseError::IntegerOverflow)?;
+            offset = offset.max(table_end);
+        }
+        for segment in self.segments.iter() {
+            let segment_end = segment
+                .phdr
+                .p_offset
+                .checked_add(segment.phdr.p_filesz)
+                .ok_or(ParseError::IntegerOverflow)?;
+            offset = offset.max(segment_end);
+        }
+
+        let loaded: Vec<bool> = self
+            .sections
+            .iter()
+            .map(|section| self.is_loaded(&section.shdr))
+            .collect();
+        for (section, loaded) in self.sections.iter().zip(loaded.iter()) {
+            if *loaded && section.shdr.sh_type != abi::SHT_NOBITS {
+                let section_end = section
+                    .shdr
+                    .sh_offset
+                    .checked_add(section.shdr.sh_size)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                offset = offset.max(section_end);
+            }
+        }
+
+        for (section, loaded) in self.sections.iter_mut().skip(1).zip(loaded.iter().skip(1)) {
+            if *loaded {
+                continue;
+            }
+            let shdr = &mut section.shdr;
+            if shdr.sh_addralign > 1 && !shdr.sh_addralign.is_power_of_two() {
+                return Err(ParseError::UnexpectedAlignment(shdr.sh_addralign as usize));
+            }
+            shdr.sh_offset = align_up(offset, shdr.sh_addralign)?;
+            if shdr.sh_type != abi::SHT_NOBITS {
+                offset = shdr
+                    .sh_offset
+                    .checked_add(shdr.sh_size)
+                    .ok_or(ParseError::IntegerOverflow)?;
+            }
+        }
+
+        self.ehdr.e_shoff = align_up(offset, word_align)?;
+        Ok(())
+    }
+
+    /// Returns true if the section's memory image lies within a [PT_LOAD](abi::PT_LOAD) segment.
+    fn is_loaded(&self, shdr: &SectionHeader) -> bool {
+        if shdr.sh_flags & abi::SHF_ALLOC as u64 == 0 {
+            return false;
+        }
+        self.segments.iter().any(|segment| {
+            let phdr = &segment.phdr;
+            phdr.p_type == abi::PT_LOAD
+                && shdr.sh_addr >= phdr.p_vaddr
+                && shdr.sh_addr.saturating_add(shdr.sh_size)
+                    <= phdr.p_vaddr.saturating_add(phdr.p_memsz)
+        })
+    }
+
+    /// The file header as it gets written, with the header and table entry sizes and the
+    /// table entry counts filled in.
+    fn file_header(&self) -> FileHeader<E> {
+        let class = self.ehdr.class;
+        let tail_size = match class {
+            Class::ELF32 => ELF32_EHDR_TAILSIZE,
+            Class::ELF64 => ELF64_EHDR_TAILSIZE,
+        };
+
+        let mut ehdr = self.ehdr;
+        ehdr.e_ehsize = (abi::EI_NIDENT + tail_size) as u16;
+        // Counts that don't fit are stored in the null section's header instead
+        ehdr.e_phnum = if self.segments.len() >= abi::PN_XNUM as usize {
+            abi::PN_XNUM
+        } else {
+            self.segments.len() as u16
+        };
+        ehdr.e_shnum = if self.sections.len() >= abi::SHN_LORESERVE as usize {
+            0
+        } else {
+            self.sections.len() as u16
+        };
+        // The entry sizes of empty tables are left as-is, since producers disagree on them
+        if self.segments.is_empty() {
+            ehdr.e_phoff = 0;
+        } else {
+            ehdr.e_phentsize = ProgramHeader::size_for(class) as u16;
+        }
+        if self.sections.is_empty() {
+            ehdr.e_shoff = 0;
+        } else {
+            ehdr.e_shentsize = SectionHeader::size_for(class) as u16;
+        }
+        ehdr
+    }
+
+    /// Write this object to `w`.
+    ///
+    /// Segment data is written first, then section data, then the file header and the program
+    /// and section header tables, so headers take precedence over any overlapping data. Any
+    /// gaps between the written ranges are left as-is, which for a fresh writer means zeroes.
+    pub fn write<W: Write + Seek>(&self, mut w: W) -> Result<(), ParseError> {
+        let endian = self.ehdr.endianness;
+        let class = self.ehdr.class;
+
+        for segment in &self.segments {
+            write_at(&mut w, segment.phdr.p_offset, &segment.data)?;
+        }
+
+        for section in &self.sections {
+            if section.shdr.sh_type != abi::SHT_NOBITS {
+                write_at(&mut w, section.shdr.sh_offset, &section.data)?;
+            }
+        }
+
+        let ehdr = self.file_header();
+        let mut buf = Vec::new();
+        ehdr.encode(endian, class, &mut buf)?;
+        write_at(&mut w, 0, &buf)?;
+
+        if !self.segments.is_empty() {
+            buf.clear();
+            for segment in &self.segments {
+                segment.phdr.encode(endian, class, &mut buf)?;
+            }
+            write_at(&mut w, ehdr.e_phoff, &buf)?;
+        }
+
+        if !self.sections.is_empty() {
+            buf.clear();
+            for section in &self.sections {
+                section.shdr.encode(endian, class, &mut buf)?;
+            }
+            write_at(&mut w, ehdr.e_shoff, &buf)?;
+        }
+
+        w.flush()?;
+        Ok(())
+    }
+
+    /// Write this object to a new byte buffer.
+    ///
+    /// Returns [ParseError::AllocationFailed] if the buffer for the written file can't be
+    /// allocated, e.g. because a header places data at a huge offset.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ParseError> {
+        let size = self.file_size();
+        let mut buf = Vec::new();
+        buf.try_reserve_exact(size.try_into()?)
+            .map_err(|_| ParseError::AllocationFailed(size))?;
+        let mut out = Cursor::new(buf);
+        self.write(&mut out)?;
+        Ok(out.into_inner())
+    }
+
+    /// The size of the file that [ElfFile::write] writes: the end of the furthest data or
+    /// header table.
+    fn file_size(&self) -> u64 {
+        let class = self.ehdr.class;
+        let ehdr = self.file_header();
+        let mut size = ehdr.e_ehsize as u64;
+        if !self.segments.is_empty() {
+            let table_size = ProgramHeader::size_for(class) * self.segments.len();
+            size = size.max(ehdr.e_phoff.saturating_add(table_size as u64));
+        }
+        if !self.sections.is_empty() {
+            let table_size = SectionHeader::size_for(class) * self.sections.len();
+            size = size.max(ehdr.e_shoff.saturating_add(table_size as u64));
+        }
+        for segment in &self.segments {
+            let end = segment
+                .phdr
+                .p_offset
+                .saturating_add(segment.data.len() as u64);
+            size = size.max(end);
+        }
+        for section in &self.sections {
+            if section.shdr.sh_type != abi::SHT_NOBITS {
+                let end = section
+                    .shdr
+                    .sh_offset
+                    .saturating_add(section.data.len() as u64);
+                size = size.max(end);
+            }
+        }
+        size
+    }
+}
+
+/// Compute the SHA-1 digest of `data`.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, w) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*w);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (h, v) in h.iter_mut().zip([a, b, c, d, e]) {
+            *h = h.wrapping_add(v);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (out, h) in digest.chunks_exact_mut(4).zip(h.iter()) {
+        out.copy_from_slice(&h.to_be_bytes());
+    }
+    digest
+}
+
+/// Copy the part of `bytes` (to be written at file offset `offset`) which overlaps `data` (which
+/// is written at file offset `data_offset`) into `data`.
+fn patch_at(data: &mut [u8], data_offset: u64, offset: u64, bytes: &[u8]) {
+    let data_end = data_offset.saturating_add(data.len() as u64);
+    let end = offset.saturating_add(bytes.len() as u64);
+    let start = offset.max(data_offset);
+    if start >= end.min(data_end) {
+        return;
+    }
+    let len = (end.min(data_end) - start) as usize;
+    let src = (start - offset) as usize;
+    let dst = (start - data_offset) as usize;
+    data[dst..dst + len].copy_from_slice(&bytes[src..src + len]);
+}
+
+fn write_at<W: Write + Seek>(w: &mut W, offset: u64, data: &[u8]) -> Result<(), ParseError> {
+    if data.is_empty() {
+        return Ok(());
+    }
+    w.seek(SeekFrom::Start(offset))?;
+    w.write_all(data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod encode_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+
+    fn roundtrip<T: Encode + ParseAt + PartialEq + core::fmt::Debug, E: EndianParse>(
+        endian: E,
+        class: Class,
+        val: T,
+    ) {
+        let mut buf = Vec::new();
+        val.encode(endian, class, &mut buf).expect("should encode");
+        assert_eq!(buf.len(), T::size_for(class));
+
+        let mut offset = 0;
+        let parsed = T::parse_at(endian, class, &mut offset, &buf).expect("should parse");
+        assert_eq!(parsed, val);
+    }
+
+    fn test_phdr() -> ProgramHeader {
+        ProgramHeader {
+            p_type: abi::PT_LOAD,
+            p_offset: 0x1000,
+            p_vaddr: 0x401000,
+            p_paddr: 0x401000,
+            p_filesz: 0x234,
+            p_memsz: 0x300,
+            p_flags: abi::PF_R | abi::PF_X,
+            p_align: 0x1000,
+        }
+    }
+
+    fn test_shdr() -> SectionHeader {
+        SectionHeader {
+            sh_name: 0x1b,
+            sh_type: abi::SHT_PROGBITS,
+            sh_flags: (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            sh_addr: 0x401000,
+            sh_offset: 0x1000,
+            sh_size: 0x234,
+            sh_link: 1,
+            sh_info: 2,
+            sh_addralign: 16,
+            sh_entsize: 0,
+        }
+    }
+
+    #[test]
+    fn encode_phdr_roundtrips() {
+        roundtrip(LittleEndian, Class::ELF32, test_phdr());
+        roundtrip(BigEndian, Class::ELF64, test_phdr());
+    }
+
+    #[test]
+    fn encode_shdr_roundtrips() {
+        roundtrip(BigEndian, Class::ELF32, test_shdr());
+        roundtrip(LittleEndian, Class::ELF64, test_shdr());
+    }
+
+    #[test]
+    fn encode_file_header_roundtrips() {
+        let ehdr = FileHeader {
+            class: Class::ELF32,
+            endianness: AnyEndian::Big,
+            version: abi::EV_CURRENT as u32,
+            osabi: abi::ELFOSABI_LINUX,
+            abiversion: 1,
+            ident_pad: [0u8; 7],
+            e_type: abi::ET_EXEC,
+            e_machine: abi::EM_PPC,
+            e_entry: 0x10000000,
+            e_phoff: 52,
+            e_shoff: 0x2000,
+            e_flags: 0x8000,
+            e_ehsize: 52,
+            e_phentsize: 32,
+            e_phnum: 2,
+            e_shentsize: 40,
+            e_shnum: 10,
+            e_shstrndx: 9,
+        };
+        let mut buf = Vec::new();
+        ehdr.encode(AnyEndian::Big, Class::ELF32, &mut buf)
+            .expect("should encode");
+        assert_eq!(buf.len(), 52);
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&buf);
+        // The header's tables point past the end of the buffer
+        assert!(file.is_err());
+
+        let ident = crate::file::parse_ident::<AnyEndian>(&buf).expect("should parse ident");
+        let parsed = FileHeader::parse_tail(ident, &buf[abi::EI_NIDENT..]).expect("should parse");
+        assert_eq!(parsed, ehdr);
+    }
+
+    #[test]
+    fn string_table_builder_dedups() {
+        let mut strtab = StringTableBuilder::new();
+        assert_eq!(strtab.add(""), 0);
+        assert_eq!(strtab.add(".text"), 1);
+        assert_eq!(strtab.add(".data"), 7);
+        assert_eq!(strtab.add(".text"), 1);
+        assert_eq!(strtab.data(), b"\0.text\0.data\0");
+    }
+
+    #[test]
+    fn align_up_offsets() {
+        assert_eq!(align_up(0x41, 0).unwrap(), 0x41);
+        assert_eq!(align_up(0x41, 1).unwrap(), 0x41);
+        assert_eq!(align_up(0x41, 8).unwrap(), 0x48);
+        assert_eq!(align_up(0x48, 8).unwrap(), 0x48);
+        assert!(matches!(
+            align_up(u64::MAX, 16),
+            Err(ParseError::IntegerOverflow)
+        ));
+    }
+
+    #[test]
+    fn encode_symbol_roundtrips() {
+        let sym = Symbol {
+            st_name: 0x12,
+            st_value: 0x401000,
+            st_size: 0x20,
+            st_shndx: 14,
+            st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+            st_other: abi::STV_HIDDEN,
+        };
+        roundtrip(BigEndian, Class::ELF32, sym.clone());
+        roundtrip(LittleEndian, Class::ELF64, sym);
+    }
+
+    #[test]
+    fn encode_rel_roundtrips() {
+        let rel = Rel {
+            r_offset: 0x1000,
+            r_sym: 0x123,
+            r_type: abi::R_X86_64_PLT32,
+        };
+        roundtrip(LittleEndian, Class::ELF32, rel.clone());
+        roundtrip(BigEndian, Class::ELF64, rel);
+    }
+
+    #[test]
+    fn encode_rela_roundtrips() {
+        let rela = Rela {
+            r_offset: 0x1000,
+            r_sym: 0x123,
+            r_type: abi::R_X86_64_PLT32,
+            r_addend: -4,
+        };
+        roundtrip(BigEndian, Class::ELF32, rela.clone());
+        roundtrip(LittleEndian, Class::ELF64, rela);
+    }
+
+    #[test]
+    fn encode_elf32_r_info_overflow_errors() {
+        let rel = Rel {
+            r_offset: 0,
+            r_sym: 0x1000000,
+            r_type: 0,
+        };
+        let err = rel
+            .encode(LittleEndian, Class::ELF32, &mut Vec::new())
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn sha1_digests() {
+        // FIPS 180 test vectors, including a message which needs an extra padding block
+        let cases: [(&[u8], [u8; 20]); 3] = [
+            (
+                b"",
+                [
+                    0xda, 0x39, 0xa3, 0xee, 0x5e, 0x6b, 0x4b, 0x0d, 0x32, 0x55, 0xbf, 0xef, 0x95,
+                    0x60, 0x18, 0x90, 0xaf, 0xd8, 0x07, 0x09,
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e, 0x25, 0x71, 0x78,
+                    0x50, 0xc2, 0x6c, 0x9c, 0xd0, 0xd8, 0x9d,
+                ],
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                [
+                    0x84, 0x98, 0x3e, 0x44, 0x1c, 0x3b, 0xd2, 0x6e, 0xba, 0xae, 0x4a, 0xa1, 0xf9,
+                    0x51, 0x29, 0xe5, 0xe5, 0x46, 0x70, 0xf1,
+                ],
+            ),
+        ];
+        for (message, digest) in cases {
+            assert_eq!(sha1(message), digest);
+        }
+    }
+
+    #[test]
+    fn encode_core_registers_roundtrip() {
+        let regs = X86_64Registers {
+            rip: 0x401000,
+            rsp: 0x7ffff000,
+            gs: 0x2b,
+            ..Default::default()
+        };
+        roundtrip(LittleEndian, Class::ELF64, regs);
+
+        let mut gprs = [0; 31];
+        gprs[30] = 0x400500;
+        let regs = Aarch64Registers {
+            regs: gprs,
+            sp: 0,
+            pc: 0x400800,
+            pstate: 0x60000000,
+        };
+        roundtrip(BigEndian, Class::ELF64, regs);
+    }
+
+    #[test]
+    fn encode_auxv_entry_roundtrips() {
+        let entry = AuxvEntry {
+            a_type: abi::AT_ENTRY,
+            a_val: 0x401000,
+        };
+        roundtrip(LittleEndian, Class::ELF32, entry);
+        roundtrip(BigEndian, Class::ELF64, entry);
+    }
+
+    #[test]
+    fn encode_prstatus_roundtrips() {
+        for class in [Class::ELF32, Class::ELF64] {
+            let pr_reg = [7u8; 72];
+            let mut prstatus = NotePrStatus::new(class, &pr_reg);
+            prstatus.signo = 11;
+            prstatus.cursig = 11;
+            prstatus.sigpend = 0x100;
+            prstatus.pid = 1234;
+            prstatus.ppid = 1;
+            prstatus.utime = NoteTimeval { sec: 3, usec: 500 };
+            prstatus.fpvalid = true;
+
+            let mut buf = Vec::new();
+            prstatus
+                .encode(LittleEndian, class, &mut buf)
+                .expect("should encode");
+            let parsed = NotePrStatus::parse(LittleEndian, class, &buf).expect("should parse");
+            assert_eq!(parsed, prstatus);
+        }
+    }
+
+    #[test]
+    fn encode_elf32_word_overflow_errors() {
+        let mut phdr = test_phdr();
+        phdr.p_vaddr = 0x1_0000_0000;
+        let err = phdr
+            .encode(LittleEndian, Class::ELF32, &mut Vec::new())
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::TryFromIntError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn roundtrip_sample(path: &str) {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let out = elf_file.to_bytes().expect("file should be writable");
+        assert!(out == file_data, "{path} should round-trip byte-for-byte");
+    }
+
+    #[test]
+    fn roundtrip_samples() {
+        roundtrip_sample("sample-objects/basic.x86_64");
+        roundtrip_sample("sample-objects/symver.m68k.so");
+        roundtrip_sample("sample-objects/symver.powerpc64.so");
+        roundtrip_sample("sample-objects/group.x86_64.o");
+        roundtrip_sample("sample-objects/shnum.x86_64");
+        roundtrip_sample("sample-objects/stripped.x86_64.so");
+    }
+
+    #[test]
+    fn roundtrip_without_section_headers() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file.sections.clear();
+        elf_file.ehdr.e_shstrndx = abi::SHN_UNDEF;
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(written.ehdr.e_shnum, 0);
+        assert_eq!(written.ehdr.e_shoff, 0);
+        assert!(written.section_headers().is_none());
+
+        // The section data is still there since it's part of the loaded segments
+        let text = file
+            .section_header_by_name(".text")
+            .expect("section table should be parseable")
+            .expect("should have a .text section");
+        let (start, end) = text.get_data_range().expect("should have a data range");
+        assert_eq!(out[start..end], file_data[start..end]);
+    }
+
+    #[test]
+    fn write_modified_section_data() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let comment = elf_file
+            .section_by_name_mut(".comment")
+            .expect("should have a .comment section");
+        comment.data.iter_mut().for_each(|b| *b = 0);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let shdr = written
+            .section_header_by_name(".comment")
+            .expect("section table should be parseable")
+            .expect("should have a .comment section");
+        let (data, _) = written.section_data(&shdr).expect("should have data");
+        assert!(data.iter().all(|b| *b == 0));
+        assert_eq!(out.len(), file_data.len());
+    }
+
+    /// Get each section's name along with the names of the sections its sh_link and sh_info
+    /// refer to, and the section names of the symbols in its .symtab
+    fn section_references(
+        file: &ElfBytes<'_, AnyEndian>,
+    ) -> (Vec<(String, String, String)>, Vec<String>) {
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("section table should be parseable");
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let name = |index: usize| -> String {
+            shdrs
+                .get(index)
+                .map(|shdr| strtab.get(shdr.sh_name as usize).unwrap().to_string())
+                .unwrap_or_default()
+        };
+
+        let sections = shdrs
+            .iter()
+            .map(|shdr| {
+                let info = if shdr.sh_type == abi::SHT_RELA {
+                    name(shdr.sh_info as usize)
+                } else {
+                    shdr.sh_info.to_string()
+                };
+                (
+                    strtab.get(shdr.sh_name as usize).unwrap().to_string(),
+                    name(shdr.sh_link as usize),
+                    info,
+                )
+            })
+            .collect();
+
+        let (symtab, _) = file
+            .symbol_table()
+            .expect("symtab should be parseable")
+            .expect("should have a symtab");
+        let symbols = symtab
+            .iter()
+            .map(|sym| match sym.st_shndx {
+                abi::SHN_UNDEF => String::new(),
+                shndx if shndx >= abi::SHN_LORESERVE => shndx.to_string(),
+                shndx => name(shndx as usize),
+            })
+            .collect();
+        (sections, symbols)
+    }
+
+    #[test]
+    fn rename_section_rebuilds_shstrtab() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let index = elf_file.section_index(".comment").unwrap();
+        elf_file
+            .rename_section(index, ".comment.with.a.much.longer.name")
+            .expect("should rename");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let (mut sections, mut symbols) = section_references(&file);
+        sections[index].0 = ".comment.with.a.much.longer.name".to_string();
+        for symbol in symbols.iter_mut() {
+            if symbol == ".comment" {
+                *symbol = ".comment.with.a.much.longer.name".to_string();
+            }
+        }
+        assert_eq!(section_references(&written), (sections, symbols));
+
+        let shdr = written
+            .section_header_by_name(".comment.with.a.much.longer.name")
+            .expect("section table should be parseable")
+            .expect("should have the renamed section");
+        assert_eq!(
+            written.section_data(&shdr).unwrap().0,
+            elf_file.sections[index].data.as_slice()
+        );
+        // The loaded sections are right where they were
+        let text = written.section_header_by_name(".text").unwrap().unwrap();
+        assert_eq!(text.sh_offset, 0x3f0);
+        assert_eq!(
+            written.section_data(&text).unwrap().0,
+            &file_data[0x3f0..0x582]
+        );
+
+        let err = elf_file
+            .rename_section(31, ".nope")
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(31)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn remove_section_fixes_indexes() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let index = elf_file.section_index(".note.ABI-tag").unwrap();
+        let removed = elf_file.remove_section(index).expect("should remove");
+        assert_eq!(removed.name, ".note.ABI-tag");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(written.ehdr.e_shnum, 30);
+        assert_eq!(written.ehdr.e_shstrndx, 27);
+
+        let (mut sections, mut symbols) = section_references(&file);
+        sections.remove(index);
+        for symbol in symbols.iter_mut() {
+            if symbol == ".note.ABI-tag" {
+                symbol.clear();
+            }
+        }
+        assert_eq!(section_references(&written), (sections, symbols));
+
+        let err = elf_file.remove_section(0).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn remove_section_fixes_groups() {
+        let file_data =
+            std::fs::read("sample-objects/group.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let index = elf_file.section_index(".text._Z6answerv").unwrap();
+        elf_file.remove_section(index).expect("should remove");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let members: Vec<Vec<u32>> = written
+            .section_groups()
+            .expect("should have groups")
+            .map(|group| group.expect("group should parse").members.iter().collect())
+            .collect();
+        assert_eq!(members, [vec![], vec![8], vec![9]]);
+
+        let (shdrs, strtab) = written.section_headers_with_strtab().unwrap();
+        let shdr = shdrs.unwrap().get(8).unwrap();
+        assert_eq!(
+            strtab.unwrap().get(shdr.sh_name as usize).unwrap(),
+            ".text._Z5twiceIiET_S0_"
+        );
+
+        // Relocatable objects have no segments, so everything gets packed after the header
+        let (sections, _) = section_references(&written);
+        assert_eq!(sections.len(), 17);
+        assert_eq!(shdrs.unwrap().get(1).unwrap().sh_offset, 64);
+    }
+
+    #[test]
+    fn add_section_places_it_after_the_loaded_data() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let index = elf_file
+            .add_section(Section {
+                name: ".note.custom".to_string(),
+                shdr: SectionHeader {
+                    sh_name: 0,
+                    sh_type: abi::SHT_PROGBITS,
+                    sh_flags: 0,
+                    sh_addr: 0,
+                    sh_offset: 0,
+                    sh_size: 0,
+                    sh_link: 0,
+                    sh_info: 0,
+                    sh_addralign: 8,
+                    sh_entsize: 0,
+                },
+                data: b"custom data".to_vec(),
+            })
+            .expect("should add");
+        assert_eq!(index, 31);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let shdr = written
+            .section_header_by_name(".note.custom")
+            .expect("section table should be parseable")
+            .expect("should have the new section");
+        assert_eq!(shdr.sh_size, 11);
+        assert_eq!(shdr.sh_offset % 8, 0);
+        assert!(shdr.sh_offset >= 0x8e8);
+        assert_eq!(written.section_data(&shdr).unwrap().0, b"custom data");
+        assert_eq!(written.ehdr.e_shnum, 32);
+    }
+
+    fn section_names(file: &ElfBytes<'_, AnyEndian>) -> Vec<String> {
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("section table should be parseable");
+        let strtab = strtab.unwrap();
+        shdrs
+            .unwrap()
+            .iter()
+            .map(|shdr| strtab.get(shdr.sh_name as usize).unwrap().to_string())
+            .collect()
+    }
+
+    /// Get the (offset, symbol name) of each relocation in the given section
+    fn relocation_symbols(file: &ElfBytes<'_, AnyEndian>, name: &str) -> Vec<(u64, String)> {
+        let shdr = file.section_header_by_name(name).unwrap().unwrap();
+        let (symtab, strtab) = file.symbol_table().unwrap().unwrap();
+        file.section_data_as_relas(&shdr)
+            .unwrap()
+            .map(|rela| {
+                let sym = symtab.get(rela.r_sym as usize).unwrap();
+                (
+                    rela.r_offset,
+                    strtab.get(sym.st_name as usize).unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn strip_debug_relocatable() {
+        let file_data =
+            std::fs::read("sample-objects/zdebug.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file
+            .strip(&StripOptions::strip_debug())
+            .expect("should strip");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(
+            section_names(&written),
+            [
+                "",
+                ".text",
+                ".data",
+                ".bss",
+                ".comment",
+                ".note.GNU-stack",
+                ".eh_frame",
+                ".rela.eh_frame",
+                ".symtab",
+                ".strtab",
+                ".shstrtab"
+            ]
+        );
+
+        let symtab_shdr = written.section_header_by_name(".symtab").unwrap().unwrap();
+        assert_eq!(symtab_shdr.sh_info, 3);
+        let (symtab, strtab) = written.symbol_table().unwrap().unwrap();
+        let symbols: Vec<(&str, u8, u16)> = symtab
+            .iter()
+            .map(|sym| {
+                (
+                    strtab.get(sym.st_name as usize).unwrap(),
+                    sym.st_symtype(),
+                    sym.st_shndx,
+                )
+            })
+            .collect();
+        assert_eq!(
+            symbols,
+            [
+                ("", abi::STT_NOTYPE, abi::SHN_UNDEF),
+                ("zd.c", abi::STT_FILE, abi::SHN_ABS),
+                ("", abi::STT_SECTION, 1),
+                ("add", abi::STT_FUNC, 1),
+            ]
+        );
+        assert_eq!(
+            relocation_symbols(&written, ".rela.eh_frame"),
+            relocation_symbols(&file, ".rela.eh_frame")
+        );
+    }
+
+    #[test]
+    fn strip_all_relocatable_keeps_relocation_symbols() {
+        let file_data =
+            std::fs::read("sample-objects/zdebug.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let mut options = StripOptions::strip_all();
+        options.keep_file_symbols(true);
+        elf_file.strip(&options).expect("should strip");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let (symtab, _) = written.symbol_table().unwrap().unwrap();
+        let symbols: Vec<u8> = symtab.iter().map(|sym| sym.st_symtype()).collect();
+        assert_eq!(symbols, [abi::STT_NOTYPE, abi::STT_FILE, abi::STT_SECTION]);
+        let symtab_shdr = written.section_header_by_name(".symtab").unwrap().unwrap();
+        assert_eq!(symtab_shdr.sh_info, 3);
+
+        let shdr = written
+            .section_header_by_name(".rela.eh_frame")
+            .unwrap()
+            .unwrap();
+        let r_syms: Vec<u32> = written
+            .section_data_as_relas(&shdr)
+            .unwrap()
+            .map(|rela| rela.r_sym)
+            .collect();
+        assert_eq!(r_syms, [2]);
+    }
+
+    #[test]
+    fn strip_all_executable() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file
+            .strip(&StripOptions::strip_all())
+            .expect("should strip");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let names = section_names(&written);
+        assert_eq!(names.len(), 29);
+        assert_eq!(names.last().unwrap(), ".shstrtab");
+        assert!(written.symbol_table().unwrap().is_none());
+        assert!(written.dynamic_symbol_table().unwrap().is_some());
+        assert!(out.len() < file_data.len());
+    }
+
+    #[test]
+    fn write_pn_xnum_segments() {
+        let file_data =
+            std::fs::read("sample-objects/group.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file.ehdr.e_phoff = elf_file.ehdr.e_ehsize as u64;
+
+        let null = Segment {
+            phdr: ProgramHeader {
+                p_type: abi::PT_NULL,
+                p_offset: 0,
+                p_vaddr: 0,
+                p_paddr: 0,
+                p_filesz: 0,
+                p_memsz: 0,
+                p_flags: 0,
+                p_align: 0,
+            },
+            data: Vec::new(),
+        };
+        for (phnum, e_phnum) in [
+            (0xfffe, 0xfffe),
+            (0xffff, abi::PN_XNUM),
+            (0x10000, abi::PN_XNUM),
+        ] {
+            elf_file.segments.resize(phnum, null.clone());
+            elf_file.update_layout().expect("should lay out");
+            let out = elf_file.to_bytes().expect("file should be writable");
+
+            let written =
+                ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+            assert_eq!(written.ehdr.e_phnum, e_phnum);
+            assert_eq!(
+                written.segments().expect("should have segments").len(),
+                phnum
+            );
+            assert_eq!(section_names(&written), section_names(&file));
+        }
+
+        // Without a null section there's nowhere to put the count
+        elf_file.sections.clear();
+        let err = elf_file.update_layout().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::InvalidHeaderValue(("e_phnum", 0x10000))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    /// Set the sh_addralign of basic.x86_64's .shstrtab, which gets moved by stripping
+    fn with_shstrtab_alignment(file_data: &mut [u8], align: u64) {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&*file_data).expect("Open file");
+        let offset = file.ehdr.e_shoff as usize
+            + file.ehdr.e_shstrndx as usize * file.ehdr.e_shentsize as usize
+            + 48;
+        file_data[offset..offset + 8].copy_from_slice(&align.to_le_bytes());
+    }
+
+    #[test]
+    fn strip_bad_alignment_errors() {
+        let mut file_data =
+            std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        with_shstrtab_alignment(&mut file_data, 0x4f000000000001);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let err = elf_file
+            .strip(&StripOptions::strip_all())
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::UnexpectedAlignment(0x4f000000000001)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn to_bytes_huge_offset_errors() {
+        let mut file_data =
+            std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        with_shstrtab_alignment(&mut file_data, 1 << 54);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file
+            .strip(&StripOptions::strip_all())
+            .expect("should strip");
+        let err = elf_file.to_bytes().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::AllocationFailed(size) if size > 1 << 54),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn update_layout_hand_edited_offset_overflow_errors() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file.ehdr.e_phoff = u64::MAX;
+        let err = elf_file.update_layout().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file.segments[2].phdr.p_offset = u64::MAX;
+        let err = elf_file.update_layout().expect_err("should error");
+        assert!(
+            matches!(err, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn write_bytes_at_vaddr_patches_sections_and_segments() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        // The first entry of .text, and the first .dynamic entry which is also in PT_DYNAMIC
+        elf_file
+            .write_bytes_at_vaddr(0x4003f0, &[0xcc, 0x90])
+            .expect("should patch .text");
+        elf_file
+            .write_bytes_at_vaddr(0x6006d8, &[0xff; 4])
+            .expect("should patch .dynamic");
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        assert_eq!(out.len(), file_data.len());
+        assert_eq!(out[0x3f0..0x3f2], [0xcc, 0x90]);
+        assert_eq!(out[0x6d8..0x6dc], [0xff; 4]);
+
+        let mut expected = file_data.clone();
+        expected[0x3f0..0x3f2].copy_from_slice(&[0xcc, 0x90]);
+        expected[0x6d8..0x6dc].copy_from_slice(&[0xff; 4]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn write_bytes_at_vaddr_unmapped_errors() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let err = elf_file
+            .write_bytes_at_vaddr(0x1000, &[0])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x1000)),
+            "Unexpected Error type found: {err}"
+        );
+
+        // Spans the end of the first PT_LOAD
+        let err = elf_file
+            .write_bytes_at_vaddr(0x4006bb, &[0, 0])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x4006bb)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn write_bytes_at_vaddr_nobits_errors() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        // The last byte of .data and the first byte of .bss's part of the segment
+        let err = elf_file
+            .write_bytes_at_vaddr(0x6008e7, &[0, 0])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::BadOffset(0x6008e8)),
+            "Unexpected Error type found: {err}"
+        );
+        assert_eq!(elf_file.to_bytes().unwrap(), file_data);
+    }
+
+    #[test]
+    fn add_loaded_section_moves_phdr_table() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let mut blob = Section::new(".blob", abi::SHT_PROGBITS, 0, b"config".to_vec());
+        blob.shdr.sh_addralign = 8;
+        let index = elf_file
+            .add_loaded_section(blob)
+            .expect("should add section");
+        assert_eq!(index, 31);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let phdrs: Vec<ProgramHeader> = written.segments().unwrap().iter().collect();
+        assert_eq!(phdrs.len(), 9);
+        // The table no longer fits before .interp, so it moved into the new segment
+        assert_eq!(written.ehdr.e_phoff, 0x8e8);
+        assert_eq!(
+            phdrs[0],
+            ProgramHeader {
+                p_type: abi::PT_PHDR,
+                p_offset: 0x8e8,
+                p_vaddr: 0x8008e8,
+                p_paddr: 0x8008e8,
+                p_filesz: 0x1f8,
+                p_memsz: 0x1f8,
+                p_flags: abi::PF_R | abi::PF_X,
+                p_align: 8,
+            }
+        );
+        assert_eq!(
+            phdrs[4],
+            ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_offset: 0x8e8,
+                p_vaddr: 0x8008e8,
+                p_paddr: 0x8008e8,
+                p_filesz: 0x1fe,
+                p_memsz: 0x1fe,
+                p_flags: abi::PF_R,
+                p_align: 0x200000,
+            }
+        );
+
+        let shdr = written.section_header_by_name(".blob").unwrap().unwrap();
+        assert_eq!(shdr.sh_addr, 0x800ae0);
+        assert_eq!(shdr.sh_flags, abi::SHF_ALLOC as u64);
+        let (data, _) = written.section_data(&shdr).unwrap();
+        assert_eq!(data, b"config");
+
+        // The existing loaded data is untouched
+        let text = written.section_header_by_name(".text").unwrap().unwrap();
+        assert_eq!(
+            written.section_data(&text).unwrap().0,
+            file.section_data(&file.section_header_by_name(".text").unwrap().unwrap())
+                .unwrap()
+                .0
+        );
+    }
+
+    #[test]
+    fn add_loaded_section_grows_phdr_table_in_place() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let text = builder.add_section(
+            ".text",
+            abi::SHT_PROGBITS,
+            (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            vec![0xc3; 0x10],
+        );
+        builder.add_segment(
+            abi::PT_LOAD,
+            abi::PF_R | abi::PF_X,
+            0x401000,
+            0x1000,
+            &[text],
+        );
+        let mut elf_file = builder.build().expect("layout should succeed");
+        let e_phoff = elf_file.ehdr.e_phoff;
+
+        let index = elf_file
+            .add_loaded_section(Section::new(
+                ".data",
+                abi::SHT_PROGBITS,
+                abi::SHF_WRITE as u64,
+                vec![1, 2, 3, 4],
+            ))
+            .expect("should add section");
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(written.ehdr.e_phoff, e_phoff);
+        let phdrs: Vec<ProgramHeader> = written.segments().unwrap().iter().collect();
+        assert_eq!(phdrs.len(), 2);
+        assert_eq!(phdrs[1].p_type, abi::PT_LOAD);
+        assert_eq!(phdrs[1].p_flags, abi::PF_R | abi::PF_W);
+        assert_eq!(phdrs[1].p_filesz, 4);
+        assert_eq!(phdrs[1].p_vaddr % 0x1000, phdrs[1].p_offset % 0x1000);
+        assert!(phdrs[1].p_vaddr >= 0x402000);
+
+        let shdr = written.section_headers().unwrap().get(index).unwrap();
+        assert_eq!(shdr.sh_addr, phdrs[1].p_vaddr);
+        assert_eq!(written.section_data(&shdr).unwrap().0, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn set_entry_validates_address() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        elf_file.set_entry(0x400500).expect("should be in .text");
+        assert_eq!(elf_file.ehdr.e_entry, 0x400500);
+
+        // In the writable PT_LOAD
+        let err = elf_file.set_entry(0x6008d8).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::InvalidHeaderValue(("e_entry", 0x6008d8))),
+            "Unexpected Error type found: {err}"
+        );
+        assert_eq!(elf_file.ehdr.e_entry, 0x400500);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(written.ehdr.e_entry, 0x400500);
+    }
+
+    #[test]
+    fn set_entry_elf32_overflow_errors() {
+        let builder = ElfBuilder::new(Class::ELF32, LittleEndian, abi::ET_REL, abi::EM_386);
+        let mut elf_file = builder.build().expect("layout should succeed");
+        let err = elf_file.set_entry(0x100000000).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::TryFromIntError(_)),
+            "Unexpected Error type found: {err}"
+        );
+        // No segments to check against
+        elf_file.set_entry(0x1000).expect("should fit");
+    }
+
+    #[test]
+    fn set_type_flags_and_osabi() {
+        let builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let mut elf_file = builder.build().expect("layout should succeed");
+
+        elf_file.set_type(abi::ET_DYN).expect("should set type");
+        elf_file.set_type(abi::ET_LOPROC).expect("should set type");
+        let err = elf_file.set_type(5).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::InvalidHeaderValue(("e_type", 5))),
+            "Unexpected Error type found: {err}"
+        );
+        assert_eq!(elf_file.ehdr.e_type, abi::ET_LOPROC);
+
+        elf_file.set_flags(0).expect("should set flags");
+        let err = elf_file.set_flags(1).expect_err("should error");
+        assert!(
+            matches!(err, ParseError::InvalidHeaderValue(("e_flags", 1))),
+            "Unexpected Error type found: {err}"
+        );
+
+        elf_file
+            .set_osabi(abi::ELFOSABI_FREEBSD, 1)
+            .expect("should set osabi");
+        assert_eq!(
+            (elf_file.ehdr.osabi, elf_file.ehdr.abiversion),
+            (abi::ELFOSABI_FREEBSD, 1)
+        );
+        for osabi in [19, abi::ELFOSABI_ARM] {
+            let err = elf_file.set_osabi(osabi, 0).expect_err("should error");
+            assert!(
+                matches!(err, ParseError::InvalidHeaderValue(("EI_OSABI", value)) if value == osabi as u64),
+                "Unexpected Error type found: {err}"
+            );
+        }
+
+        elf_file.ehdr.e_machine = abi::EM_ARM;
+        elf_file
+            .set_osabi(abi::ELFOSABI_ARM, 0)
+            .expect("should set osabi");
+        elf_file.set_flags(0x05000000).expect("should set flags");
+    }
+
+    #[test]
+    fn set_build_id_overwrites_existing_note() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let mut elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+        let index = elf_file
+            .set_build_id(&[0xab; 20])
+            .expect("should set build-id");
+        assert_eq!(index, 3);
+        assert_eq!(elf_file.segments.len(), 8);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        assert_eq!(out.len(), file_data.len());
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let build_id = written.build_id().unwrap().unwrap();
+        assert_eq!(build_id.0, [0xab; 20]);
+
+        let err = elf_file
+            .set_build_id(&[0xab; 16])
+            .expect_err("should error");
+        assert!(
+            matches!(err, ParseError::InvalidHeaderValue(("build-id size", 16))),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
+    #[test]
+    fn compute_build_id_adds_note_segment() {
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        let text = builder.add_section(
+            ".text",
+            abi::SHT_PROGBITS,
+            (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            vec![0xc3; 0x10],
+        );
+        builder.add_segment(
+            abi::PT_LOAD,
+            abi::PF_R | abi::PF_X,
+            0x401000,
+            0x1000,
+            &[text],
+        );
+        let mut elf_file = builder.build().expect("layout should succeed");
+
+        let build_id = elf_file.compute_build_id().expect("should set build-id");
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        assert_eq!(written.build_id().unwrap().unwrap().0, build_id);
+
+        let phdrs: Vec<ProgramHeader> = written.segments().unwrap().iter().collect();
+        let types: Vec<u32> = phdrs.iter().map(|phdr| phdr.p_type).collect();
+        assert_eq!(types, [abi::PT_LOAD, abi::PT_LOAD, abi::PT_NOTE]);
+        let shdr = written
+            .section_header_by_name(".note.gnu.build-id")
+            .unwrap()
+            .unwrap();
+        assert_eq!(shdr.sh_addr % 4, 0);
+        assert_eq!(
+            (phdrs[2].p_offset, phdrs[2].p_vaddr, phdrs[2].p_filesz),
+            (shdr.sh_offset, shdr.sh_addr, 0x24)
+        );
+        assert!(phdrs[1].p_vaddr <= shdr.sh_addr);
+
+        // The build-id is the hash of the output with the build-id zeroed
+        let mut zeroed = out.clone();
+        let desc = shdr.sh_offset as usize + 16;
+        zeroed[desc..desc + 20].fill(0);
+        assert_eq!(sha1(&zeroed), build_id);
+    }
+
+    #[test]
+    fn set_build_id_without_load_segments() {
+        use crate::note::{Note, NoteGnuBuildId};
+
+        let mut builder = ElfBuilder::new(Class::ELF64, LittleEndian, abi::ET_EXEC, abi::EM_X86_64);
+        builder.add_section(".text", abi::SHT_PROGBITS, 0, vec![0xc3; 0x10]);
+        builder.add_segment(abi::PT_GNU_STACK, abi::PF_R | abi::PF_W, 0, 16, &[]);
+        let mut elf_file = builder.build().expect("layout should succeed");
+
+        let index = elf_file
+            .set_build_id(&[0xab; 20])
+            .expect("should set build-id");
+        let shdr = elf_file.sections[index].shdr;
+        let phdrs: Vec<ProgramHeader> = elf_file
+            .segments
+            .iter()
+            .map(|segment| segment.phdr)
+            .collect();
+        let types: Vec<u32> = phdrs.iter().map(|phdr| phdr.p_type).collect();
+        assert_eq!(types, [abi::PT_GNU_STACK, abi::PT_NOTE, abi::PT_LOAD]);
+        assert_eq!(
+            (phdrs[1].p_offset, phdrs[1].p_vaddr, phdrs[1].p_filesz),
+            (shdr.sh_offset, shdr.sh_addr, 0x24)
+        );
+        assert_eq!(phdrs[1].p_flags, abi::PF_R);
+        assert!(phdrs[2].p_offset <= shdr.sh_offset);
+        assert!(phdrs[2].p_offset + phdrs[2].p_filesz >= shdr.sh_offset + shdr.sh_size);
+        assert_eq!(phdrs[2].p_flags, abi::PF_R);
+
+        let out = elf_file.to_bytes().expect("file should be writable");
+        let written = ElfBytes::<AnyEndian>::minimal_parse(out.as_slice()).expect("Open output");
+        let note_phdr = written.segments().unwrap().get(1).unwrap();
+        let notes: Vec<Note<'_>> = written
+            .segment_data_as_notes(&note_phdr)
+            .expect("should read notes")
+            .collect();
+        assert_eq!(notes, [Note::GnuBuildId(NoteGnuBuildId(&[0xab; 20]))]);
+    }
+
+    #[test]
+    fn extract_section() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+
+        let mut out = Vec::new();
+        assert!(elf_file
+            .extract_section(".interp", &mut out)
+            .expect("should extract"));
+        assert_eq!(out, b"/lib64/ld-linux-x86-64.so.2\0");
+
+        let mut out = Vec::new();
+        assert!(!elf_file
+            .extract_section(".nonexistent", &mut out)
+            .expect("should extract"));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn section_write_to() {
+        let section = Section::new(".blob", abi::SHT_PROGBITS, 0, vec![1, 2, 3]);
+        let path =
+            std::env::temp_dir().join(format!("elf-section-write-to-{}", std::process::id()));
+        section.write_to(&path).expect("should write");
+        let data = std::fs::read(&path).expect("Could not read file.");
+        std::fs::remove_file(&path).expect("should remove file");
+        assert_eq!(data, [1, 2, 3]);
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn extract_section_decompressed() {
+        let file_data =
+            std::fs::read("sample-objects/zdebug.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open file");
+        let elf_file = ElfFile::from_elf_bytes(&file).expect("file should be parseable");
+
+        let mut raw = Vec::new();
+        elf_file
+            .extract_section(".zdebug_line", &mut raw)
+            .expect("should extract");
+        assert!(raw.starts_with(b"ZLIB"));
+
+        let mut out = Vec::new();
+        assert!(elf_file
+            .extract_section_decompressed(".zdebug_line", &mut out)
+            .expect("should extract"));
+        assert_eq!(out.len(), 79);
+
+        // Uncompressed sections are written as-is
+        let mut out = Vec::new();
+        elf_file
+            .extract_section_decompressed(".text", &mut out)
+            .expect("should extract");
+        assert_eq!(out, elf_file.section_by_name(".text").unwrap().data);
+    }
+}
@@ -0,0 +1,93 @@
+//! Decoding Xtensa specific header data: the
+//! [e_flags](crate::file::FileHeader#structfield.e_flags) of objects built for
+//! [EM_XTENSA](crate::abi::EM_XTENSA)
+//!
+//! Xtensa processors are configured per design, so the flags say little about the target: they
+//! hold a machine number, of which only one is defined, and record whether the object describes
+//! where its instructions and literals are with `.xt.insn` and `.xt.lit` property tables.
+//!
+//! See [FileHeader::xtensa_flags](crate::file::FileHeader::xtensa_flags).
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{EFlagsDecoder, FileHeader};
+
+/// The decoded e_flags of an Xtensa object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XtensaFlags {
+    /// The machine, from the [EF_XTENSA_MACH](abi::EF_XTENSA_MACH) bits. The only one defined
+    /// is [E_XTENSA_MACH](abi::E_XTENSA_MACH).
+    pub mach: u32,
+    /// The object has `.xt.insn` property tables ([EF_XTENSA_XT_INSN](abi::EF_XTENSA_XT_INSN))
+    pub xt_insn: bool,
+    /// The object has `.xt.lit` property tables ([EF_XTENSA_XT_LIT](abi::EF_XTENSA_XT_LIT))
+    pub xt_lit: bool,
+}
+
+impl XtensaFlags {
+    /// Decode the given e_flags value. Bits which aren't defined by binutils are ignored.
+    pub fn from_e_flags(e_flags: u32) -> Self {
+        XtensaFlags {
+            mach: e_flags & abi::EF_XTENSA_MACH,
+            xt_insn: e_flags & abi::EF_XTENSA_XT_INSN != 0,
+            xt_lit: e_flags & abi::EF_XTENSA_XT_LIT != 0,
+        }
+    }
+}
+
+impl EFlagsDecoder for XtensaFlags {
+    const E_MACHINE: u16 = abi::EM_XTENSA;
+
+    fn decode<E: EndianParse>(ehdr: &FileHeader<E>) -> Self {
+        XtensaFlags::from_e_flags(ehdr.e_flags)
+    }
+}
+
+/// Formats the flags, e.g. "Xtensa, xt.insn, xt.lit"
+impl core::fmt::Display for XtensaFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.mach {
+            abi::E_XTENSA_MACH => write!(f, "Xtensa")?,
+            mach => write!(f, "unknown machine {mach:#x}")?,
+        }
+        if self.xt_insn {
+            write!(f, ", xt.insn")?;
+        }
+        if self.xt_lit {
+            write!(f, ", xt.lit")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn from_e_flags() {
+        assert_eq!(
+            XtensaFlags::from_e_flags(abi::EF_XTENSA_XT_INSN | abi::EF_XTENSA_XT_LIT),
+            XtensaFlags {
+                mach: abi::E_XTENSA_MACH,
+                xt_insn: true,
+                xt_lit: true,
+            }
+        );
+        assert_eq!(
+            XtensaFlags::from_e_flags(0xf000_0003),
+            XtensaFlags {
+                mach: 3,
+                xt_insn: false,
+                xt_lit: false,
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        let flags = XtensaFlags::from_e_flags(0x300);
+        assert_eq!(flags.to_string(), "Xtensa, xt.insn, xt.lit");
+        let flags = XtensaFlags::from_e_flags(0x201);
+        assert_eq!(flags.to_string(), "unknown machine 0x1, xt.lit");
+    }
+}